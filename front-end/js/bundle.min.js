@@ -1 +1 @@
-!function(e,t){if("object"==typeof exports&&"object"==typeof module)module.exports=t();else if("function"==typeof define&&define.amd)define([],t);else{var n=t();for(var r in n)("object"==typeof exports?exports:e)[r]=n[r]}}(self,(()=>(()=>{var e={252:function(e,t,n){var r,o,i,a;function s(e){return s="function"==typeof Symbol&&"symbol"==typeof Symbol.iterator?function(e){return typeof e}:function(e){return e&&"function"==typeof Symbol&&e.constructor===Symbol&&e!==Symbol.prototype?"symbol":typeof e},s(e)}a=function(e,t){"use strict";function n(e){return e&&"object"===s(e)&&"default"in e?e:{default:e}}var r=n(e),o=n(t);function i(e,t){for(var n=0;n<t.length;n++){var r=t[n];r.enumerable=r.enumerable||!1,r.configurable=!0,"value"in r&&(r.writable=!0),Object.defineProperty(e,r.key,r)}}function a(){return a=Object.assign?Object.assign.bind():function(e){for(var t=1;t<arguments.length;t++){var n=arguments[t];for(var r in n)Object.prototype.hasOwnProperty.call(n,r)&&(e[r]=n[r])}return e},a.apply(this,arguments)}var c="collapse",l="bs.collapse",u="."+l,f=r.default.fn[c],d="show",p="collapse",h="collapsing",v="collapsed",m="width",g="show"+u,y="shown"+u,b="hide"+u,_="hidden"+u,x="click"+u+".data-api",w='[data-toggle="collapse"]',C={toggle:!0,parent:""},T={toggle:"boolean",parent:"(string|element)"},k=function(){function e(e,t){this._isTransitioning=!1,this._element=e,this._config=this._getConfig(t),this._triggerArray=[].slice.call(document.querySelectorAll('[data-toggle="collapse"][href="#'+e.id+'"],[data-toggle="collapse"][data-target="#'+e.id+'"]'));for(var n=[].slice.call(document.querySelectorAll(w)),r=0,i=n.length;r<i;r++){var a=n[r],s=o.default.getSelectorFromElement(a),c=[].slice.call(document.querySelectorAll(s)).filter((function(t){return t===e}));null!==s&&c.length>0&&(this._selector=s,this._triggerArray.push(a))}this._parent=this._config.parent?this._getParent():null,this._config.parent||this._addAriaAndCollapsedClass(this._element,this._triggerArray),this._config.toggle&&this.toggle()}var t,n,u,f=e.prototype;return f.toggle=function(){r.default(this._element).hasClass(d)?this.hide():this.show()},f.show=function(){var t,n,i=this;if(!(this._isTransitioning||r.default(this._element).hasClass(d)||(this._parent&&0===(t=[].slice.call(this._parent.querySelectorAll(".show, .collapsing")).filter((function(e){return"string"==typeof i._config.parent?e.getAttribute("data-parent")===i._config.parent:e.classList.contains(p)}))).length&&(t=null),t&&(n=r.default(t).not(this._selector).data(l))&&n._isTransitioning))){var a=r.default.Event(g);if(r.default(this._element).trigger(a),!a.isDefaultPrevented()){t&&(e._jQueryInterface.call(r.default(t).not(this._selector),"hide"),n||r.default(t).data(l,null));var s=this._getDimension();r.default(this._element).removeClass(p).addClass(h),this._element.style[s]=0,this._triggerArray.length&&r.default(this._triggerArray).removeClass(v).attr("aria-expanded",!0),this.setTransitioning(!0);var c="scroll"+(s[0].toUpperCase()+s.slice(1)),u=o.default.getTransitionDurationFromElement(this._element);r.default(this._element).one(o.default.TRANSITION_END,(function(){r.default(i._element).removeClass(h).addClass(p+" "+d),i._element.style[s]="",i.setTransitioning(!1),r.default(i._element).trigger(y)})).emulateTransitionEnd(u),this._element.style[s]=this._element[c]+"px"}}},f.hide=function(){var e=this;if(!this._isTransitioning&&r.default(this._element).hasClass(d)){var t=r.default.Event(b);if(r.default(this._element).trigger(t),!t.isDefaultPrevented()){var n=this._getDimension();this._element.style[n]=this._element.getBoundingClientRect()[n]+"px",o.default.reflow(this._element),r.default(this._element).addClass(h).removeClass(p+" "+d);var i=this._triggerArray.length;if(i>0)for(var a=0;a<i;a++){var s=this._triggerArray[a],c=o.default.getSelectorFromElement(s);null!==c&&(r.default([].slice.call(document.querySelectorAll(c))).hasClass(d)||r.default(s).addClass(v).attr("aria-expanded",!1))}this.setTransitioning(!0),this._element.style[n]="";var l=o.default.getTransitionDurationFromElement(this._element);r.default(this._element).one(o.default.TRANSITION_END,(function(){e.setTransitioning(!1),r.default(e._element).removeClass(h).addClass(p).trigger(_)})).emulateTransitionEnd(l)}}},f.setTransitioning=function(e){this._isTransitioning=e},f.dispose=function(){r.default.removeData(this._element,l),this._config=null,this._parent=null,this._element=null,this._triggerArray=null,this._isTransitioning=null},f._getConfig=function(e){return(e=a({},C,e)).toggle=Boolean(e.toggle),o.default.typeCheckConfig(c,e,T),e},f._getDimension=function(){return r.default(this._element).hasClass(m)?m:"height"},f._getParent=function(){var t,n=this;o.default.isElement(this._config.parent)?(t=this._config.parent,void 0!==this._config.parent.jquery&&(t=this._config.parent[0])):t=document.querySelector(this._config.parent);var i='[data-toggle="collapse"][data-parent="'+this._config.parent+'"]',a=[].slice.call(t.querySelectorAll(i));return r.default(a).each((function(t,r){n._addAriaAndCollapsedClass(e._getTargetFromElement(r),[r])})),t},f._addAriaAndCollapsedClass=function(e,t){var n=r.default(e).hasClass(d);t.length&&r.default(t).toggleClass(v,!n).attr("aria-expanded",n)},e._getTargetFromElement=function(e){var t=o.default.getSelectorFromElement(e);return t?document.querySelector(t):null},e._jQueryInterface=function(t){return this.each((function(){var n=r.default(this),o=n.data(l),i=a({},C,n.data(),"object"===s(t)&&t?t:{});if(!o&&i.toggle&&"string"==typeof t&&/show|hide/.test(t)&&(i.toggle=!1),o||(o=new e(this,i),n.data(l,o)),"string"==typeof t){if(void 0===o[t])throw new TypeError('No method named "'+t+'"');o[t]()}}))},t=e,u=[{key:"VERSION",get:function(){return"4.6.2"}},{key:"Default",get:function(){return C}}],(n=null)&&i(t.prototype,n),u&&i(t,u),Object.defineProperty(t,"prototype",{writable:!1}),e}();return r.default(document).on(x,w,(function(e){"A"===e.currentTarget.tagName&&e.preventDefault();var t=r.default(this),n=o.default.getSelectorFromElement(this),i=[].slice.call(document.querySelectorAll(n));r.default(i).each((function(){var e=r.default(this),n=e.data(l)?"toggle":t.data();k._jQueryInterface.call(e,n)}))})),r.default.fn[c]=k._jQueryInterface,r.default.fn[c].Constructor=k,r.default.fn[c].noConflict=function(){return r.default.fn[c]=f,k._jQueryInterface},k},"object"===s(t)?e.exports=a(n(25),n(555)):(o=[n(25),n(555)],void 0===(i="function"==typeof(r=a)?r.apply(t,o):r)||(e.exports=i))},202:function(e,t,n){var r,o,i,a;function s(e){return s="function"==typeof Symbol&&"symbol"==typeof Symbol.iterator?function(e){return typeof e}:function(e){return e&&"function"==typeof Symbol&&e.constructor===Symbol&&e!==Symbol.prototype?"symbol":typeof e},s(e)}a=function(e,t){"use strict";function n(e){return e&&"object"===s(e)&&"default"in e?e:{default:e}}var r=n(e),o=n(t);function i(e,t){for(var n=0;n<t.length;n++){var r=t[n];r.enumerable=r.enumerable||!1,r.configurable=!0,"value"in r&&(r.writable=!0),Object.defineProperty(e,r.key,r)}}function a(){return a=Object.assign?Object.assign.bind():function(e){for(var t=1;t<arguments.length;t++){var n=arguments[t];for(var r in n)Object.prototype.hasOwnProperty.call(n,r)&&(e[r]=n[r])}return e},a.apply(this,arguments)}var c="modal",l="bs.modal",u="."+l,f=r.default.fn[c],d="modal-open",p="fade",h="show",v="modal-static",m="hide"+u,g="hidePrevented"+u,y="hidden"+u,b="show"+u,_="shown"+u,x="focusin"+u,w="resize"+u,C="click.dismiss"+u,T="keydown.dismiss"+u,k="mouseup.dismiss"+u,S="mousedown.dismiss"+u,E="click"+u+".data-api",$=".fixed-top, .fixed-bottom, .is-fixed, .sticky-top",A=".sticky-top",O={backdrop:!0,keyboard:!0,focus:!0,show:!0},j={backdrop:"(boolean|string)",keyboard:"boolean",focus:"boolean",show:"boolean"},D=function(){function e(e,t){this._config=this._getConfig(t),this._element=e,this._dialog=e.querySelector(".modal-dialog"),this._backdrop=null,this._isShown=!1,this._isBodyOverflowing=!1,this._ignoreBackdropClick=!1,this._isTransitioning=!1,this._scrollbarWidth=0}var t,n,f,E=e.prototype;return E.toggle=function(e){return this._isShown?this.hide():this.show(e)},E.show=function(e){var t=this;if(!this._isShown&&!this._isTransitioning){var n=r.default.Event(b,{relatedTarget:e});r.default(this._element).trigger(n),n.isDefaultPrevented()||(this._isShown=!0,r.default(this._element).hasClass(p)&&(this._isTransitioning=!0),this._checkScrollbar(),this._setScrollbar(),this._adjustDialog(),this._setEscapeEvent(),this._setResizeEvent(),r.default(this._element).on(C,'[data-dismiss="modal"]',(function(e){return t.hide(e)})),r.default(this._dialog).on(S,(function(){r.default(t._element).one(k,(function(e){r.default(e.target).is(t._element)&&(t._ignoreBackdropClick=!0)}))})),this._showBackdrop((function(){return t._showElement(e)})))}},E.hide=function(e){var t=this;if(e&&e.preventDefault(),this._isShown&&!this._isTransitioning){var n=r.default.Event(m);if(r.default(this._element).trigger(n),this._isShown&&!n.isDefaultPrevented()){this._isShown=!1;var i=r.default(this._element).hasClass(p);if(i&&(this._isTransitioning=!0),this._setEscapeEvent(),this._setResizeEvent(),r.default(document).off(x),r.default(this._element).removeClass(h),r.default(this._element).off(C),r.default(this._dialog).off(S),i){var a=o.default.getTransitionDurationFromElement(this._element);r.default(this._element).one(o.default.TRANSITION_END,(function(e){return t._hideModal(e)})).emulateTransitionEnd(a)}else this._hideModal()}}},E.dispose=function(){[window,this._element,this._dialog].forEach((function(e){return r.default(e).off(u)})),r.default(document).off(x),r.default.removeData(this._element,l),this._config=null,this._element=null,this._dialog=null,this._backdrop=null,this._isShown=null,this._isBodyOverflowing=null,this._ignoreBackdropClick=null,this._isTransitioning=null,this._scrollbarWidth=null},E.handleUpdate=function(){this._adjustDialog()},E._getConfig=function(e){return e=a({},O,e),o.default.typeCheckConfig(c,e,j),e},E._triggerBackdropTransition=function(){var e=this,t=r.default.Event(g);if(r.default(this._element).trigger(t),!t.isDefaultPrevented()){var n=this._element.scrollHeight>document.documentElement.clientHeight;n||(this._element.style.overflowY="hidden"),this._element.classList.add(v);var i=o.default.getTransitionDurationFromElement(this._dialog);r.default(this._element).off(o.default.TRANSITION_END),r.default(this._element).one(o.default.TRANSITION_END,(function(){e._element.classList.remove(v),n||r.default(e._element).one(o.default.TRANSITION_END,(function(){e._element.style.overflowY=""})).emulateTransitionEnd(e._element,i)})).emulateTransitionEnd(i),this._element.focus()}},E._showElement=function(e){var t=this,n=r.default(this._element).hasClass(p),i=this._dialog?this._dialog.querySelector(".modal-body"):null;this._element.parentNode&&this._element.parentNode.nodeType===Node.ELEMENT_NODE||document.body.appendChild(this._element),this._element.style.display="block",this._element.removeAttribute("aria-hidden"),this._element.setAttribute("aria-modal",!0),this._element.setAttribute("role","dialog"),r.default(this._dialog).hasClass("modal-dialog-scrollable")&&i?i.scrollTop=0:this._element.scrollTop=0,n&&o.default.reflow(this._element),r.default(this._element).addClass(h),this._config.focus&&this._enforceFocus();var a=r.default.Event(_,{relatedTarget:e}),s=function(){t._config.focus&&t._element.focus(),t._isTransitioning=!1,r.default(t._element).trigger(a)};if(n){var c=o.default.getTransitionDurationFromElement(this._dialog);r.default(this._dialog).one(o.default.TRANSITION_END,s).emulateTransitionEnd(c)}else s()},E._enforceFocus=function(){var e=this;r.default(document).off(x).on(x,(function(t){document!==t.target&&e._element!==t.target&&0===r.default(e._element).has(t.target).length&&e._element.focus()}))},E._setEscapeEvent=function(){var e=this;this._isShown?r.default(this._element).on(T,(function(t){e._config.keyboard&&27===t.which?(t.preventDefault(),e.hide()):e._config.keyboard||27!==t.which||e._triggerBackdropTransition()})):this._isShown||r.default(this._element).off(T)},E._setResizeEvent=function(){var e=this;this._isShown?r.default(window).on(w,(function(t){return e.handleUpdate(t)})):r.default(window).off(w)},E._hideModal=function(){var e=this;this._element.style.display="none",this._element.setAttribute("aria-hidden",!0),this._element.removeAttribute("aria-modal"),this._element.removeAttribute("role"),this._isTransitioning=!1,this._showBackdrop((function(){r.default(document.body).removeClass(d),e._resetAdjustments(),e._resetScrollbar(),r.default(e._element).trigger(y)}))},E._removeBackdrop=function(){this._backdrop&&(r.default(this._backdrop).remove(),this._backdrop=null)},E._showBackdrop=function(e){var t=this,n=r.default(this._element).hasClass(p)?p:"";if(this._isShown&&this._config.backdrop){if(this._backdrop=document.createElement("div"),this._backdrop.className="modal-backdrop",n&&this._backdrop.classList.add(n),r.default(this._backdrop).appendTo(document.body),r.default(this._element).on(C,(function(e){t._ignoreBackdropClick?t._ignoreBackdropClick=!1:e.target===e.currentTarget&&("static"===t._config.backdrop?t._triggerBackdropTransition():t.hide())})),n&&o.default.reflow(this._backdrop),r.default(this._backdrop).addClass(h),!e)return;if(!n)return void e();var i=o.default.getTransitionDurationFromElement(this._backdrop);r.default(this._backdrop).one(o.default.TRANSITION_END,e).emulateTransitionEnd(i)}else if(!this._isShown&&this._backdrop){r.default(this._backdrop).removeClass(h);var a=function(){t._removeBackdrop(),e&&e()};if(r.default(this._element).hasClass(p)){var s=o.default.getTransitionDurationFromElement(this._backdrop);r.default(this._backdrop).one(o.default.TRANSITION_END,a).emulateTransitionEnd(s)}else a()}else e&&e()},E._adjustDialog=function(){var e=this._element.scrollHeight>document.documentElement.clientHeight;!this._isBodyOverflowing&&e&&(this._element.style.paddingLeft=this._scrollbarWidth+"px"),this._isBodyOverflowing&&!e&&(this._element.style.paddingRight=this._scrollbarWidth+"px")},E._resetAdjustments=function(){this._element.style.paddingLeft="",this._element.style.paddingRight=""},E._checkScrollbar=function(){var e=document.body.getBoundingClientRect();this._isBodyOverflowing=Math.round(e.left+e.right)<window.innerWidth,this._scrollbarWidth=this._getScrollbarWidth()},E._setScrollbar=function(){var e=this;if(this._isBodyOverflowing){var t=[].slice.call(document.querySelectorAll($)),n=[].slice.call(document.querySelectorAll(A));r.default(t).each((function(t,n){var o=n.style.paddingRight,i=r.default(n).css("padding-right");r.default(n).data("padding-right",o).css("padding-right",parseFloat(i)+e._scrollbarWidth+"px")})),r.default(n).each((function(t,n){var o=n.style.marginRight,i=r.default(n).css("margin-right");r.default(n).data("margin-right",o).css("margin-right",parseFloat(i)-e._scrollbarWidth+"px")}));var o=document.body.style.paddingRight,i=r.default(document.body).css("padding-right");r.default(document.body).data("padding-right",o).css("padding-right",parseFloat(i)+this._scrollbarWidth+"px")}r.default(document.body).addClass(d)},E._resetScrollbar=function(){var e=[].slice.call(document.querySelectorAll($));r.default(e).each((function(e,t){var n=r.default(t).data("padding-right");r.default(t).removeData("padding-right"),t.style.paddingRight=n||""}));var t=[].slice.call(document.querySelectorAll(""+A));r.default(t).each((function(e,t){var n=r.default(t).data("margin-right");void 0!==n&&r.default(t).css("margin-right",n).removeData("margin-right")}));var n=r.default(document.body).data("padding-right");r.default(document.body).removeData("padding-right"),document.body.style.paddingRight=n||""},E._getScrollbarWidth=function(){var e=document.createElement("div");e.className="modal-scrollbar-measure",document.body.appendChild(e);var t=e.getBoundingClientRect().width-e.clientWidth;return document.body.removeChild(e),t},e._jQueryInterface=function(t,n){return this.each((function(){var o=r.default(this).data(l),i=a({},O,r.default(this).data(),"object"===s(t)&&t?t:{});if(o||(o=new e(this,i),r.default(this).data(l,o)),"string"==typeof t){if(void 0===o[t])throw new TypeError('No method named "'+t+'"');o[t](n)}else i.show&&o.show(n)}))},t=e,f=[{key:"VERSION",get:function(){return"4.6.2"}},{key:"Default",get:function(){return O}}],(n=null)&&i(t.prototype,n),f&&i(t,f),Object.defineProperty(t,"prototype",{writable:!1}),e}();return r.default(document).on(E,'[data-toggle="modal"]',(function(e){var t,n=this,i=o.default.getSelectorFromElement(this);i&&(t=document.querySelector(i));var s=r.default(t).data(l)?"toggle":a({},r.default(t).data(),r.default(this).data());"A"!==this.tagName&&"AREA"!==this.tagName||e.preventDefault();var c=r.default(t).one(b,(function(e){e.isDefaultPrevented()||c.one(y,(function(){r.default(n).is(":visible")&&n.focus()}))}));D._jQueryInterface.call(r.default(t),s,this)})),r.default.fn[c]=D._jQueryInterface,r.default.fn[c].Constructor=D,r.default.fn[c].noConflict=function(){return r.default.fn[c]=f,D._jQueryInterface},D},"object"===s(t)?e.exports=a(n(25),n(555)):(o=[n(25),n(555)],void 0===(i="function"==typeof(r=a)?r.apply(t,o):r)||(e.exports=i))},555:function(e,t,n){var r,o,i,a;function s(e){return s="function"==typeof Symbol&&"symbol"==typeof Symbol.iterator?function(e){return typeof e}:function(e){return e&&"function"==typeof Symbol&&e.constructor===Symbol&&e!==Symbol.prototype?"symbol":typeof e},s(e)}a=function(e){"use strict";function t(e){return e&&"object"===s(e)&&"default"in e?e:{default:e}}var n=t(e),r="transitionend";function o(e){var t=this,r=!1;return n.default(this).one(i.TRANSITION_END,(function(){r=!0})),setTimeout((function(){r||i.triggerTransitionEnd(t)}),e),this}var i={TRANSITION_END:"bsTransitionEnd",getUID:function(e){do{e+=~~(1e6*Math.random())}while(document.getElementById(e));return e},getSelectorFromElement:function(e){var t=e.getAttribute("data-target");if(!t||"#"===t){var n=e.getAttribute("href");t=n&&"#"!==n?n.trim():""}try{return document.querySelector(t)?t:null}catch(e){return null}},getTransitionDurationFromElement:function(e){if(!e)return 0;var t=n.default(e).css("transition-duration"),r=n.default(e).css("transition-delay"),o=parseFloat(t),i=parseFloat(r);return o||i?(t=t.split(",")[0],r=r.split(",")[0],1e3*(parseFloat(t)+parseFloat(r))):0},reflow:function(e){return e.offsetHeight},triggerTransitionEnd:function(e){n.default(e).trigger(r)},supportsTransitionEnd:function(){return Boolean(r)},isElement:function(e){return(e[0]||e).nodeType},typeCheckConfig:function(e,t,n){for(var r in n)if(Object.prototype.hasOwnProperty.call(n,r)){var o=n[r],a=t[r],s=a&&i.isElement(a)?"element":null==(c=a)?""+c:{}.toString.call(c).match(/\s([a-z]+)/i)[1].toLowerCase();if(!new RegExp(o).test(s))throw new Error(e.toUpperCase()+': Option "'+r+'" provided type "'+s+'" but expected type "'+o+'".')}var c},findShadowRoot:function(e){if(!document.documentElement.attachShadow)return null;if("function"==typeof e.getRootNode){var t=e.getRootNode();return t instanceof ShadowRoot?t:null}return e instanceof ShadowRoot?e:e.parentNode?i.findShadowRoot(e.parentNode):null},jQueryDetection:function(){if(void 0===n.default)throw new TypeError("Bootstrap's JavaScript requires jQuery. jQuery must be included before Bootstrap's JavaScript.");var e=n.default.fn.jquery.split(" ")[0].split(".");if(e[0]<2&&e[1]<9||1===e[0]&&9===e[1]&&e[2]<1||e[0]>=4)throw new Error("Bootstrap's JavaScript requires at least jQuery v1.9.1 but less than v4.0.0")}};return i.jQueryDetection(),n.default.fn.emulateTransitionEnd=o,n.default.event.special[i.TRANSITION_END]={bindType:r,delegateType:r,handle:function(e){if(n.default(e.target).is(this))return e.handleObj.handler.apply(this,arguments)}},i},"object"===s(t)?e.exports=a(n(25)):(o=[n(25)],void 0===(i="function"==typeof(r=a)?r.apply(t,o):r)||(e.exports=i))},25:function(e,t,n){var r;function o(e){return o="function"==typeof Symbol&&"symbol"==typeof Symbol.iterator?function(e){return typeof e}:function(e){return e&&"function"==typeof Symbol&&e.constructor===Symbol&&e!==Symbol.prototype?"symbol":typeof e},o(e)}e=n.nmd(e),function(t,n){"use strict";"object"===o(e)&&"object"===o(e.exports)?e.exports=t.document?n(t,!0):function(e){if(!e.document)throw new Error("jQuery requires a window with a document");return n(e)}:n(t)}("undefined"!=typeof window?window:this,(function(n,i){"use strict";var a=[],s=Object.getPrototypeOf,c=a.slice,l=a.flat?function(e){return a.flat.call(e)}:function(e){return a.concat.apply([],e)},u=a.push,f=a.indexOf,d={},p=d.toString,h=d.hasOwnProperty,v=h.toString,m=v.call(Object),g={},y=function(e){return"function"==typeof e&&"number"!=typeof e.nodeType&&"function"!=typeof e.item},b=function(e){return null!=e&&e===e.window},_=n.document,x={type:!0,src:!0,nonce:!0,noModule:!0};function w(e,t,n){var r,o,i=(n=n||_).createElement("script");if(i.text=e,t)for(r in x)(o=t[r]||t.getAttribute&&t.getAttribute(r))&&i.setAttribute(r,o);n.head.appendChild(i).parentNode.removeChild(i)}function C(e){return null==e?e+"":"object"===o(e)||"function"==typeof e?d[p.call(e)]||"object":o(e)}var T="3.7.1",k=/HTML$/i,S=function e(t,n){return new e.fn.init(t,n)};function E(e){var t=!!e&&"length"in e&&e.length,n=C(e);return!y(e)&&!b(e)&&("array"===n||0===t||"number"==typeof t&&t>0&&t-1 in e)}function $(e,t){return e.nodeName&&e.nodeName.toLowerCase()===t.toLowerCase()}S.fn=S.prototype={jquery:T,constructor:S,length:0,toArray:function(){return c.call(this)},get:function(e){return null==e?c.call(this):e<0?this[e+this.length]:this[e]},pushStack:function(e){var t=S.merge(this.constructor(),e);return t.prevObject=this,t},each:function(e){return S.each(this,e)},map:function(e){return this.pushStack(S.map(this,(function(t,n){return e.call(t,n,t)})))},slice:function(){return this.pushStack(c.apply(this,arguments))},first:function(){return this.eq(0)},last:function(){return this.eq(-1)},even:function(){return this.pushStack(S.grep(this,(function(e,t){return(t+1)%2})))},odd:function(){return this.pushStack(S.grep(this,(function(e,t){return t%2})))},eq:function(e){var t=this.length,n=+e+(e<0?t:0);return this.pushStack(n>=0&&n<t?[this[n]]:[])},end:function(){return this.prevObject||this.constructor()},push:u,sort:a.sort,splice:a.splice},S.extend=S.fn.extend=function(){var e,t,n,r,i,a,s=arguments[0]||{},c=1,l=arguments.length,u=!1;for("boolean"==typeof s&&(u=s,s=arguments[c]||{},c++),"object"===o(s)||y(s)||(s={}),c===l&&(s=this,c--);c<l;c++)if(null!=(e=arguments[c]))for(t in e)r=e[t],"__proto__"!==t&&s!==r&&(u&&r&&(S.isPlainObject(r)||(i=Array.isArray(r)))?(n=s[t],a=i&&!Array.isArray(n)?[]:i||S.isPlainObject(n)?n:{},i=!1,s[t]=S.extend(u,a,r)):void 0!==r&&(s[t]=r));return s},S.extend({expando:"jQuery"+(T+Math.random()).replace(/\D/g,""),isReady:!0,error:function(e){throw new Error(e)},noop:function(){},isPlainObject:function(e){var t,n;return!(!e||"[object Object]"!==p.call(e))&&(!(t=s(e))||"function"==typeof(n=h.call(t,"constructor")&&t.constructor)&&v.call(n)===m)},isEmptyObject:function(e){var t;for(t in e)return!1;return!0},globalEval:function(e,t,n){w(e,{nonce:t&&t.nonce},n)},each:function(e,t){var n,r=0;if(E(e))for(n=e.length;r<n&&!1!==t.call(e[r],r,e[r]);r++);else for(r in e)if(!1===t.call(e[r],r,e[r]))break;return e},text:function(e){var t,n="",r=0,o=e.nodeType;if(!o)for(;t=e[r++];)n+=S.text(t);return 1===o||11===o?e.textContent:9===o?e.documentElement.textContent:3===o||4===o?e.nodeValue:n},makeArray:function(e,t){var n=t||[];return null!=e&&(E(Object(e))?S.merge(n,"string"==typeof e?[e]:e):u.call(n,e)),n},inArray:function(e,t,n){return null==t?-1:f.call(t,e,n)},isXMLDoc:function(e){var t=e&&e.namespaceURI,n=e&&(e.ownerDocument||e).documentElement;return!k.test(t||n&&n.nodeName||"HTML")},merge:function(e,t){for(var n=+t.length,r=0,o=e.length;r<n;r++)e[o++]=t[r];return e.length=o,e},grep:function(e,t,n){for(var r=[],o=0,i=e.length,a=!n;o<i;o++)!t(e[o],o)!==a&&r.push(e[o]);return r},map:function(e,t,n){var r,o,i=0,a=[];if(E(e))for(r=e.length;i<r;i++)null!=(o=t(e[i],i,n))&&a.push(o);else for(i in e)null!=(o=t(e[i],i,n))&&a.push(o);return l(a)},guid:1,support:g}),"function"==typeof Symbol&&(S.fn[Symbol.iterator]=a[Symbol.iterator]),S.each("Boolean Number String Function Array Date RegExp Object Error Symbol".split(" "),(function(e,t){d["[object "+t+"]"]=t.toLowerCase()}));var A=a.pop,O=a.sort,j=a.splice,D="[\\x20\\t\\r\\n\\f]",N=new RegExp("^"+D+"+|((?:^|[^\\\\])(?:\\\\.)*)"+D+"+$","g");S.contains=function(e,t){var n=t&&t.parentNode;return e===n||!(!n||1!==n.nodeType||!(e.contains?e.contains(n):e.compareDocumentPosition&&16&e.compareDocumentPosition(n)))};var L=/([\0-\x1f\x7f]|^-?\d)|^-$|[^\x80-\uFFFF\w-]/g;function P(e,t){return t?"\0"===e?"�":e.slice(0,-1)+"\\"+e.charCodeAt(e.length-1).toString(16)+" ":"\\"+e}S.escapeSelector=function(e){return(e+"").replace(L,P)};var I=_,M=u;!function(){var e,t,r,o,i,s,l,u,d,p,v=M,m=S.expando,y=0,b=0,_=ee(),x=ee(),w=ee(),C=ee(),T=function(e,t){return e===t&&(i=!0),0},k="checked|selected|async|autofocus|autoplay|controls|defer|disabled|hidden|ismap|loop|multiple|open|readonly|required|scoped",E="(?:\\\\[\\da-fA-F]{1,6}"+D+"?|\\\\[^\\r\\n\\f]|[\\w-]|[^\0-\\x7f])+",L="\\["+D+"*("+E+")(?:"+D+"*([*^$|!~]?=)"+D+"*(?:'((?:\\\\.|[^\\\\'])*)'|\"((?:\\\\.|[^\\\\\"])*)\"|("+E+"))|)"+D+"*\\]",P=":("+E+")(?:\\((('((?:\\\\.|[^\\\\'])*)'|\"((?:\\\\.|[^\\\\\"])*)\")|((?:\\\\.|[^\\\\()[\\]]|"+L+")*)|.*)\\)|)",R=new RegExp(D+"+","g"),F=new RegExp("^"+D+"*,"+D+"*"),H=new RegExp("^"+D+"*([>+~]|"+D+")"+D+"*"),q=new RegExp(D+"|>"),B=new RegExp(P),W=new RegExp("^"+E+"$"),U={ID:new RegExp("^#("+E+")"),CLASS:new RegExp("^\\.("+E+")"),TAG:new RegExp("^("+E+"|[*])"),ATTR:new RegExp("^"+L),PSEUDO:new RegExp("^"+P),CHILD:new RegExp("^:(only|first|last|nth|nth-last)-(child|of-type)(?:\\("+D+"*(even|odd|(([+-]|)(\\d*)n|)"+D+"*(?:([+-]|)"+D+"*(\\d+)|))"+D+"*\\)|)","i"),bool:new RegExp("^(?:"+k+")$","i"),needsContext:new RegExp("^"+D+"*[>+~]|:(even|odd|eq|gt|lt|nth|first|last)(?:\\("+D+"*((?:-\\d)?\\d*)"+D+"*\\)|)(?=[^-]|$)","i")},z=/^(?:input|select|textarea|button)$/i,V=/^h\d$/i,J=/^(?:#([\w-]+)|(\w+)|\.([\w-]+))$/,K=/[+~]/,X=new RegExp("\\\\[\\da-fA-F]{1,6}"+D+"?|\\\\([^\\r\\n\\f])","g"),Q=function(e,t){var n="0x"+e.slice(1)-65536;return t||(n<0?String.fromCharCode(n+65536):String.fromCharCode(n>>10|55296,1023&n|56320))},G=function(){ce()},Y=de((function(e){return!0===e.disabled&&$(e,"fieldset")}),{dir:"parentNode",next:"legend"});try{v.apply(a=c.call(I.childNodes),I.childNodes),a[I.childNodes.length].nodeType}catch(e){v={apply:function(e,t){M.apply(e,c.call(t))},call:function(e){M.apply(e,c.call(arguments,1))}}}function Z(e,t,n,r){var o,i,a,c,l,f,p,h=t&&t.ownerDocument,y=t?t.nodeType:9;if(n=n||[],"string"!=typeof e||!e||1!==y&&9!==y&&11!==y)return n;if(!r&&(ce(t),t=t||s,u)){if(11!==y&&(l=J.exec(e)))if(o=l[1]){if(9===y){if(!(a=t.getElementById(o)))return n;if(a.id===o)return v.call(n,a),n}else if(h&&(a=h.getElementById(o))&&Z.contains(t,a)&&a.id===o)return v.call(n,a),n}else{if(l[2])return v.apply(n,t.getElementsByTagName(e)),n;if((o=l[3])&&t.getElementsByClassName)return v.apply(n,t.getElementsByClassName(o)),n}if(!(C[e+" "]||d&&d.test(e))){if(p=e,h=t,1===y&&(q.test(e)||H.test(e))){for((h=K.test(e)&&se(t.parentNode)||t)==t&&g.scope||((c=t.getAttribute("id"))?c=S.escapeSelector(c):t.setAttribute("id",c=m)),i=(f=ue(e)).length;i--;)f[i]=(c?"#"+c:":scope")+" "+fe(f[i]);p=f.join(",")}try{return v.apply(n,h.querySelectorAll(p)),n}catch(t){C(e,!0)}finally{c===m&&t.removeAttribute("id")}}}return ye(e.replace(N,"$1"),t,n,r)}function ee(){var e=[];return function n(r,o){return e.push(r+" ")>t.cacheLength&&delete n[e.shift()],n[r+" "]=o}}function te(e){return e[m]=!0,e}function ne(e){var t=s.createElement("fieldset");try{return!!e(t)}catch(e){return!1}finally{t.parentNode&&t.parentNode.removeChild(t),t=null}}function re(e){return function(t){return $(t,"input")&&t.type===e}}function oe(e){return function(t){return($(t,"input")||$(t,"button"))&&t.type===e}}function ie(e){return function(t){return"form"in t?t.parentNode&&!1===t.disabled?"label"in t?"label"in t.parentNode?t.parentNode.disabled===e:t.disabled===e:t.isDisabled===e||t.isDisabled!==!e&&Y(t)===e:t.disabled===e:"label"in t&&t.disabled===e}}function ae(e){return te((function(t){return t=+t,te((function(n,r){for(var o,i=e([],n.length,t),a=i.length;a--;)n[o=i[a]]&&(n[o]=!(r[o]=n[o]))}))}))}function se(e){return e&&void 0!==e.getElementsByTagName&&e}function ce(e){var n,r=e?e.ownerDocument||e:I;return r!=s&&9===r.nodeType&&r.documentElement?(l=(s=r).documentElement,u=!S.isXMLDoc(s),p=l.matches||l.webkitMatchesSelector||l.msMatchesSelector,l.msMatchesSelector&&I!=s&&(n=s.defaultView)&&n.top!==n&&n.addEventListener("unload",G),g.getById=ne((function(e){return l.appendChild(e).id=S.expando,!s.getElementsByName||!s.getElementsByName(S.expando).length})),g.disconnectedMatch=ne((function(e){return p.call(e,"*")})),g.scope=ne((function(){return s.querySelectorAll(":scope")})),g.cssHas=ne((function(){try{return s.querySelector(":has(*,:jqfake)"),!1}catch(e){return!0}})),g.getById?(t.filter.ID=function(e){var t=e.replace(X,Q);return function(e){return e.getAttribute("id")===t}},t.find.ID=function(e,t){if(void 0!==t.getElementById&&u){var n=t.getElementById(e);return n?[n]:[]}}):(t.filter.ID=function(e){var t=e.replace(X,Q);return function(e){var n=void 0!==e.getAttributeNode&&e.getAttributeNode("id");return n&&n.value===t}},t.find.ID=function(e,t){if(void 0!==t.getElementById&&u){var n,r,o,i=t.getElementById(e);if(i){if((n=i.getAttributeNode("id"))&&n.value===e)return[i];for(o=t.getElementsByName(e),r=0;i=o[r++];)if((n=i.getAttributeNode("id"))&&n.value===e)return[i]}return[]}}),t.find.TAG=function(e,t){return void 0!==t.getElementsByTagName?t.getElementsByTagName(e):t.querySelectorAll(e)},t.find.CLASS=function(e,t){if(void 0!==t.getElementsByClassName&&u)return t.getElementsByClassName(e)},d=[],ne((function(e){var t;l.appendChild(e).innerHTML="<a id='"+m+"' href='' disabled='disabled'></a><select id='"+m+"-\r\\' disabled='disabled'><option selected=''></option></select>",e.querySelectorAll("[selected]").length||d.push("\\["+D+"*(?:value|"+k+")"),e.querySelectorAll("[id~="+m+"-]").length||d.push("~="),e.querySelectorAll("a#"+m+"+*").length||d.push(".#.+[+~]"),e.querySelectorAll(":checked").length||d.push(":checked"),(t=s.createElement("input")).setAttribute("type","hidden"),e.appendChild(t).setAttribute("name","D"),l.appendChild(e).disabled=!0,2!==e.querySelectorAll(":disabled").length&&d.push(":enabled",":disabled"),(t=s.createElement("input")).setAttribute("name",""),e.appendChild(t),e.querySelectorAll("[name='']").length||d.push("\\["+D+"*name"+D+"*="+D+"*(?:''|\"\")")})),g.cssHas||d.push(":has"),d=d.length&&new RegExp(d.join("|")),T=function(e,t){if(e===t)return i=!0,0;var n=!e.compareDocumentPosition-!t.compareDocumentPosition;return n||(1&(n=(e.ownerDocument||e)==(t.ownerDocument||t)?e.compareDocumentPosition(t):1)||!g.sortDetached&&t.compareDocumentPosition(e)===n?e===s||e.ownerDocument==I&&Z.contains(I,e)?-1:t===s||t.ownerDocument==I&&Z.contains(I,t)?1:o?f.call(o,e)-f.call(o,t):0:4&n?-1:1)},s):s}for(e in Z.matches=function(e,t){return Z(e,null,null,t)},Z.matchesSelector=function(e,t){if(ce(e),u&&!C[t+" "]&&(!d||!d.test(t)))try{var n=p.call(e,t);if(n||g.disconnectedMatch||e.document&&11!==e.document.nodeType)return n}catch(e){C(t,!0)}return Z(t,s,null,[e]).length>0},Z.contains=function(e,t){return(e.ownerDocument||e)!=s&&ce(e),S.contains(e,t)},Z.attr=function(e,n){(e.ownerDocument||e)!=s&&ce(e);var r=t.attrHandle[n.toLowerCase()],o=r&&h.call(t.attrHandle,n.toLowerCase())?r(e,n,!u):void 0;return void 0!==o?o:e.getAttribute(n)},Z.error=function(e){throw new Error("Syntax error, unrecognized expression: "+e)},S.uniqueSort=function(e){var t,n=[],r=0,a=0;if(i=!g.sortStable,o=!g.sortStable&&c.call(e,0),O.call(e,T),i){for(;t=e[a++];)t===e[a]&&(r=n.push(a));for(;r--;)j.call(e,n[r],1)}return o=null,e},S.fn.uniqueSort=function(){return this.pushStack(S.uniqueSort(c.apply(this)))},t=S.expr={cacheLength:50,createPseudo:te,match:U,attrHandle:{},find:{},relative:{">":{dir:"parentNode",first:!0}," ":{dir:"parentNode"},"+":{dir:"previousSibling",first:!0},"~":{dir:"previousSibling"}},preFilter:{ATTR:function(e){return e[1]=e[1].replace(X,Q),e[3]=(e[3]||e[4]||e[5]||"").replace(X,Q),"~="===e[2]&&(e[3]=" "+e[3]+" "),e.slice(0,4)},CHILD:function(e){return e[1]=e[1].toLowerCase(),"nth"===e[1].slice(0,3)?(e[3]||Z.error(e[0]),e[4]=+(e[4]?e[5]+(e[6]||1):2*("even"===e[3]||"odd"===e[3])),e[5]=+(e[7]+e[8]||"odd"===e[3])):e[3]&&Z.error(e[0]),e},PSEUDO:function(e){var t,n=!e[6]&&e[2];return U.CHILD.test(e[0])?null:(e[3]?e[2]=e[4]||e[5]||"":n&&B.test(n)&&(t=ue(n,!0))&&(t=n.indexOf(")",n.length-t)-n.length)&&(e[0]=e[0].slice(0,t),e[2]=n.slice(0,t)),e.slice(0,3))}},filter:{TAG:function(e){var t=e.replace(X,Q).toLowerCase();return"*"===e?function(){return!0}:function(e){return $(e,t)}},CLASS:function(e){var t=_[e+" "];return t||(t=new RegExp("(^|"+D+")"+e+"("+D+"|$)"))&&_(e,(function(e){return t.test("string"==typeof e.className&&e.className||void 0!==e.getAttribute&&e.getAttribute("class")||"")}))},ATTR:function(e,t,n){return function(r){var o=Z.attr(r,e);return null==o?"!="===t:!t||(o+="","="===t?o===n:"!="===t?o!==n:"^="===t?n&&0===o.indexOf(n):"*="===t?n&&o.indexOf(n)>-1:"$="===t?n&&o.slice(-n.length)===n:"~="===t?(" "+o.replace(R," ")+" ").indexOf(n)>-1:"|="===t&&(o===n||o.slice(0,n.length+1)===n+"-"))}},CHILD:function(e,t,n,r,o){var i="nth"!==e.slice(0,3),a="last"!==e.slice(-4),s="of-type"===t;return 1===r&&0===o?function(e){return!!e.parentNode}:function(t,n,c){var l,u,f,d,p,h=i!==a?"nextSibling":"previousSibling",v=t.parentNode,g=s&&t.nodeName.toLowerCase(),b=!c&&!s,_=!1;if(v){if(i){for(;h;){for(f=t;f=f[h];)if(s?$(f,g):1===f.nodeType)return!1;p=h="only"===e&&!p&&"nextSibling"}return!0}if(p=[a?v.firstChild:v.lastChild],a&&b){for(_=(d=(l=(u=v[m]||(v[m]={}))[e]||[])[0]===y&&l[1])&&l[2],f=d&&v.childNodes[d];f=++d&&f&&f[h]||(_=d=0)||p.pop();)if(1===f.nodeType&&++_&&f===t){u[e]=[y,d,_];break}}else if(b&&(_=d=(l=(u=t[m]||(t[m]={}))[e]||[])[0]===y&&l[1]),!1===_)for(;(f=++d&&f&&f[h]||(_=d=0)||p.pop())&&(!(s?$(f,g):1===f.nodeType)||!++_||(b&&((u=f[m]||(f[m]={}))[e]=[y,_]),f!==t)););return(_-=o)===r||_%r==0&&_/r>=0}}},PSEUDO:function(e,n){var r,o=t.pseudos[e]||t.setFilters[e.toLowerCase()]||Z.error("unsupported pseudo: "+e);return o[m]?o(n):o.length>1?(r=[e,e,"",n],t.setFilters.hasOwnProperty(e.toLowerCase())?te((function(e,t){for(var r,i=o(e,n),a=i.length;a--;)e[r=f.call(e,i[a])]=!(t[r]=i[a])})):function(e){return o(e,0,r)}):o}},pseudos:{not:te((function(e){var t=[],n=[],r=ge(e.replace(N,"$1"));return r[m]?te((function(e,t,n,o){for(var i,a=r(e,null,o,[]),s=e.length;s--;)(i=a[s])&&(e[s]=!(t[s]=i))})):function(e,o,i){return t[0]=e,r(t,null,i,n),t[0]=null,!n.pop()}})),has:te((function(e){return function(t){return Z(e,t).length>0}})),contains:te((function(e){return e=e.replace(X,Q),function(t){return(t.textContent||S.text(t)).indexOf(e)>-1}})),lang:te((function(e){return W.test(e||"")||Z.error("unsupported lang: "+e),e=e.replace(X,Q).toLowerCase(),function(t){var n;do{if(n=u?t.lang:t.getAttribute("xml:lang")||t.getAttribute("lang"))return(n=n.toLowerCase())===e||0===n.indexOf(e+"-")}while((t=t.parentNode)&&1===t.nodeType);return!1}})),target:function(e){var t=n.location&&n.location.hash;return t&&t.slice(1)===e.id},root:function(e){return e===l},focus:function(e){return e===function(){try{return s.activeElement}catch(e){}}()&&s.hasFocus()&&!!(e.type||e.href||~e.tabIndex)},enabled:ie(!1),disabled:ie(!0),checked:function(e){return $(e,"input")&&!!e.checked||$(e,"option")&&!!e.selected},selected:function(e){return e.parentNode&&e.parentNode.selectedIndex,!0===e.selected},empty:function(e){for(e=e.firstChild;e;e=e.nextSibling)if(e.nodeType<6)return!1;return!0},parent:function(e){return!t.pseudos.empty(e)},header:function(e){return V.test(e.nodeName)},input:function(e){return z.test(e.nodeName)},button:function(e){return $(e,"input")&&"button"===e.type||$(e,"button")},text:function(e){var t;return $(e,"input")&&"text"===e.type&&(null==(t=e.getAttribute("type"))||"text"===t.toLowerCase())},first:ae((function(){return[0]})),last:ae((function(e,t){return[t-1]})),eq:ae((function(e,t,n){return[n<0?n+t:n]})),even:ae((function(e,t){for(var n=0;n<t;n+=2)e.push(n);return e})),odd:ae((function(e,t){for(var n=1;n<t;n+=2)e.push(n);return e})),lt:ae((function(e,t,n){var r;for(r=n<0?n+t:n>t?t:n;--r>=0;)e.push(r);return e})),gt:ae((function(e,t,n){for(var r=n<0?n+t:n;++r<t;)e.push(r);return e}))}},t.pseudos.nth=t.pseudos.eq,{radio:!0,checkbox:!0,file:!0,password:!0,image:!0})t.pseudos[e]=re(e);for(e in{submit:!0,reset:!0})t.pseudos[e]=oe(e);function le(){}function ue(e,n){var r,o,i,a,s,c,l,u=x[e+" "];if(u)return n?0:u.slice(0);for(s=e,c=[],l=t.preFilter;s;){for(a in r&&!(o=F.exec(s))||(o&&(s=s.slice(o[0].length)||s),c.push(i=[])),r=!1,(o=H.exec(s))&&(r=o.shift(),i.push({value:r,type:o[0].replace(N," ")}),s=s.slice(r.length)),t.filter)!(o=U[a].exec(s))||l[a]&&!(o=l[a](o))||(r=o.shift(),i.push({value:r,type:a,matches:o}),s=s.slice(r.length));if(!r)break}return n?s.length:s?Z.error(e):x(e,c).slice(0)}function fe(e){for(var t=0,n=e.length,r="";t<n;t++)r+=e[t].value;return r}function de(e,t,n){var r=t.dir,o=t.next,i=o||r,a=n&&"parentNode"===i,s=b++;return t.first?function(t,n,o){for(;t=t[r];)if(1===t.nodeType||a)return e(t,n,o);return!1}:function(t,n,c){var l,u,f=[y,s];if(c){for(;t=t[r];)if((1===t.nodeType||a)&&e(t,n,c))return!0}else for(;t=t[r];)if(1===t.nodeType||a)if(u=t[m]||(t[m]={}),o&&$(t,o))t=t[r]||t;else{if((l=u[i])&&l[0]===y&&l[1]===s)return f[2]=l[2];if(u[i]=f,f[2]=e(t,n,c))return!0}return!1}}function pe(e){return e.length>1?function(t,n,r){for(var o=e.length;o--;)if(!e[o](t,n,r))return!1;return!0}:e[0]}function he(e,t,n,r,o){for(var i,a=[],s=0,c=e.length,l=null!=t;s<c;s++)(i=e[s])&&(n&&!n(i,r,o)||(a.push(i),l&&t.push(s)));return a}function ve(e,t,n,r,o,i){return r&&!r[m]&&(r=ve(r)),o&&!o[m]&&(o=ve(o,i)),te((function(i,a,s,c){var l,u,d,p,h=[],m=[],g=a.length,y=i||function(e,t,n){for(var r=0,o=t.length;r<o;r++)Z(e,t[r],n);return n}(t||"*",s.nodeType?[s]:s,[]),b=!e||!i&&t?y:he(y,h,e,s,c);if(n?n(b,p=o||(i?e:g||r)?[]:a,s,c):p=b,r)for(l=he(p,m),r(l,[],s,c),u=l.length;u--;)(d=l[u])&&(p[m[u]]=!(b[m[u]]=d));if(i){if(o||e){if(o){for(l=[],u=p.length;u--;)(d=p[u])&&l.push(b[u]=d);o(null,p=[],l,c)}for(u=p.length;u--;)(d=p[u])&&(l=o?f.call(i,d):h[u])>-1&&(i[l]=!(a[l]=d))}}else p=he(p===a?p.splice(g,p.length):p),o?o(null,a,p,c):v.apply(a,p)}))}function me(e){for(var n,o,i,a=e.length,s=t.relative[e[0].type],c=s||t.relative[" "],l=s?1:0,u=de((function(e){return e===n}),c,!0),d=de((function(e){return f.call(n,e)>-1}),c,!0),p=[function(e,t,o){var i=!s&&(o||t!=r)||((n=t).nodeType?u(e,t,o):d(e,t,o));return n=null,i}];l<a;l++)if(o=t.relative[e[l].type])p=[de(pe(p),o)];else{if((o=t.filter[e[l].type].apply(null,e[l].matches))[m]){for(i=++l;i<a&&!t.relative[e[i].type];i++);return ve(l>1&&pe(p),l>1&&fe(e.slice(0,l-1).concat({value:" "===e[l-2].type?"*":""})).replace(N,"$1"),o,l<i&&me(e.slice(l,i)),i<a&&me(e=e.slice(i)),i<a&&fe(e))}p.push(o)}return pe(p)}function ge(e,n){var o,i=[],a=[],c=w[e+" "];if(!c){for(n||(n=ue(e)),o=n.length;o--;)(c=me(n[o]))[m]?i.push(c):a.push(c);c=w(e,function(e,n){var o=n.length>0,i=e.length>0,a=function(a,c,l,f,d){var p,h,m,g=0,b="0",_=a&&[],x=[],w=r,C=a||i&&t.find.TAG("*",d),T=y+=null==w?1:Math.random()||.1,k=C.length;for(d&&(r=c==s||c||d);b!==k&&null!=(p=C[b]);b++){if(i&&p){for(h=0,c||p.ownerDocument==s||(ce(p),l=!u);m=e[h++];)if(m(p,c||s,l)){v.call(f,p);break}d&&(y=T)}o&&((p=!m&&p)&&g--,a&&_.push(p))}if(g+=b,o&&b!==g){for(h=0;m=n[h++];)m(_,x,c,l);if(a){if(g>0)for(;b--;)_[b]||x[b]||(x[b]=A.call(f));x=he(x)}v.apply(f,x),d&&!a&&x.length>0&&g+n.length>1&&S.uniqueSort(f)}return d&&(y=T,r=w),_};return o?te(a):a}(a,i)),c.selector=e}return c}function ye(e,n,r,o){var i,a,s,c,l,f="function"==typeof e&&e,d=!o&&ue(e=f.selector||e);if(r=r||[],1===d.length){if((a=d[0]=d[0].slice(0)).length>2&&"ID"===(s=a[0]).type&&9===n.nodeType&&u&&t.relative[a[1].type]){if(!(n=(t.find.ID(s.matches[0].replace(X,Q),n)||[])[0]))return r;f&&(n=n.parentNode),e=e.slice(a.shift().value.length)}for(i=U.needsContext.test(e)?0:a.length;i--&&(s=a[i],!t.relative[c=s.type]);)if((l=t.find[c])&&(o=l(s.matches[0].replace(X,Q),K.test(a[0].type)&&se(n.parentNode)||n))){if(a.splice(i,1),!(e=o.length&&fe(a)))return v.apply(r,o),r;break}}return(f||ge(e,d))(o,n,!u,r,!n||K.test(e)&&se(n.parentNode)||n),r}le.prototype=t.filters=t.pseudos,t.setFilters=new le,g.sortStable=m.split("").sort(T).join("")===m,ce(),g.sortDetached=ne((function(e){return 1&e.compareDocumentPosition(s.createElement("fieldset"))})),S.find=Z,S.expr[":"]=S.expr.pseudos,S.unique=S.uniqueSort,Z.compile=ge,Z.select=ye,Z.setDocument=ce,Z.tokenize=ue,Z.escape=S.escapeSelector,Z.getText=S.text,Z.isXML=S.isXMLDoc,Z.selectors=S.expr,Z.support=S.support,Z.uniqueSort=S.uniqueSort}();var R=function(e,t,n){for(var r=[],o=void 0!==n;(e=e[t])&&9!==e.nodeType;)if(1===e.nodeType){if(o&&S(e).is(n))break;r.push(e)}return r},F=function(e,t){for(var n=[];e;e=e.nextSibling)1===e.nodeType&&e!==t&&n.push(e);return n},H=S.expr.match.needsContext,q=/^<([a-z][^\/\0>:\x20\t\r\n\f]*)[\x20\t\r\n\f]*\/?>(?:<\/\1>|)$/i;function B(e,t,n){return y(t)?S.grep(e,(function(e,r){return!!t.call(e,r,e)!==n})):t.nodeType?S.grep(e,(function(e){return e===t!==n})):"string"!=typeof t?S.grep(e,(function(e){return f.call(t,e)>-1!==n})):S.filter(t,e,n)}S.filter=function(e,t,n){var r=t[0];return n&&(e=":not("+e+")"),1===t.length&&1===r.nodeType?S.find.matchesSelector(r,e)?[r]:[]:S.find.matches(e,S.grep(t,(function(e){return 1===e.nodeType})))},S.fn.extend({find:function(e){var t,n,r=this.length,o=this;if("string"!=typeof e)return this.pushStack(S(e).filter((function(){for(t=0;t<r;t++)if(S.contains(o[t],this))return!0})));for(n=this.pushStack([]),t=0;t<r;t++)S.find(e,o[t],n);return r>1?S.uniqueSort(n):n},filter:function(e){return this.pushStack(B(this,e||[],!1))},not:function(e){return this.pushStack(B(this,e||[],!0))},is:function(e){return!!B(this,"string"==typeof e&&H.test(e)?S(e):e||[],!1).length}});var W,U=/^(?:\s*(<[\w\W]+>)[^>]*|#([\w-]+))$/;(S.fn.init=function(e,t,n){var r,o;if(!e)return this;if(n=n||W,"string"==typeof e){if(!(r="<"===e[0]&&">"===e[e.length-1]&&e.length>=3?[null,e,null]:U.exec(e))||!r[1]&&t)return!t||t.jquery?(t||n).find(e):this.constructor(t).find(e);if(r[1]){if(t=t instanceof S?t[0]:t,S.merge(this,S.parseHTML(r[1],t&&t.nodeType?t.ownerDocument||t:_,!0)),q.test(r[1])&&S.isPlainObject(t))for(r in t)y(this[r])?this[r](t[r]):this.attr(r,t[r]);return this}return(o=_.getElementById(r[2]))&&(this[0]=o,this.length=1),this}return e.nodeType?(this[0]=e,this.length=1,this):y(e)?void 0!==n.ready?n.ready(e):e(S):S.makeArray(e,this)}).prototype=S.fn,W=S(_);var z=/^(?:parents|prev(?:Until|All))/,V={children:!0,contents:!0,next:!0,prev:!0};function J(e,t){for(;(e=e[t])&&1!==e.nodeType;);return e}S.fn.extend({has:function(e){var t=S(e,this),n=t.length;return this.filter((function(){for(var e=0;e<n;e++)if(S.contains(this,t[e]))return!0}))},closest:function(e,t){var n,r=0,o=this.length,i=[],a="string"!=typeof e&&S(e);if(!H.test(e))for(;r<o;r++)for(n=this[r];n&&n!==t;n=n.parentNode)if(n.nodeType<11&&(a?a.index(n)>-1:1===n.nodeType&&S.find.matchesSelector(n,e))){i.push(n);break}return this.pushStack(i.length>1?S.uniqueSort(i):i)},index:function(e){return e?"string"==typeof e?f.call(S(e),this[0]):f.call(this,e.jquery?e[0]:e):this[0]&&this[0].parentNode?this.first().prevAll().length:-1},add:function(e,t){return this.pushStack(S.uniqueSort(S.merge(this.get(),S(e,t))))},addBack:function(e){return this.add(null==e?this.prevObject:this.prevObject.filter(e))}}),S.each({parent:function(e){var t=e.parentNode;return t&&11!==t.nodeType?t:null},parents:function(e){return R(e,"parentNode")},parentsUntil:function(e,t,n){return R(e,"parentNode",n)},next:function(e){return J(e,"nextSibling")},prev:function(e){return J(e,"previousSibling")},nextAll:function(e){return R(e,"nextSibling")},prevAll:function(e){return R(e,"previousSibling")},nextUntil:function(e,t,n){return R(e,"nextSibling",n)},prevUntil:function(e,t,n){return R(e,"previousSibling",n)},siblings:function(e){return F((e.parentNode||{}).firstChild,e)},children:function(e){return F(e.firstChild)},contents:function(e){return null!=e.contentDocument&&s(e.contentDocument)?e.contentDocument:($(e,"template")&&(e=e.content||e),S.merge([],e.childNodes))}},(function(e,t){S.fn[e]=function(n,r){var o=S.map(this,t,n);return"Until"!==e.slice(-5)&&(r=n),r&&"string"==typeof r&&(o=S.filter(r,o)),this.length>1&&(V[e]||S.uniqueSort(o),z.test(e)&&o.reverse()),this.pushStack(o)}}));var K=/[^\x20\t\r\n\f]+/g;function X(e){return e}function Q(e){throw e}function G(e,t,n,r){var o;try{e&&y(o=e.promise)?o.call(e).done(t).fail(n):e&&y(o=e.then)?o.call(e,t,n):t.apply(void 0,[e].slice(r))}catch(e){n.apply(void 0,[e])}}S.Callbacks=function(e){e="string"==typeof e?function(e){var t={};return S.each(e.match(K)||[],(function(e,n){t[n]=!0})),t}(e):S.extend({},e);var t,n,r,o,i=[],a=[],s=-1,c=function(){for(o=o||e.once,r=t=!0;a.length;s=-1)for(n=a.shift();++s<i.length;)!1===i[s].apply(n[0],n[1])&&e.stopOnFalse&&(s=i.length,n=!1);e.memory||(n=!1),t=!1,o&&(i=n?[]:"")},l={add:function(){return i&&(n&&!t&&(s=i.length-1,a.push(n)),function t(n){S.each(n,(function(n,r){y(r)?e.unique&&l.has(r)||i.push(r):r&&r.length&&"string"!==C(r)&&t(r)}))}(arguments),n&&!t&&c()),this},remove:function(){return S.each(arguments,(function(e,t){for(var n;(n=S.inArray(t,i,n))>-1;)i.splice(n,1),n<=s&&s--})),this},has:function(e){return e?S.inArray(e,i)>-1:i.length>0},empty:function(){return i&&(i=[]),this},disable:function(){return o=a=[],i=n="",this},disabled:function(){return!i},lock:function(){return o=a=[],n||t||(i=n=""),this},locked:function(){return!!o},fireWith:function(e,n){return o||(n=[e,(n=n||[]).slice?n.slice():n],a.push(n),t||c()),this},fire:function(){return l.fireWith(this,arguments),this},fired:function(){return!!r}};return l},S.extend({Deferred:function(e){var t=[["notify","progress",S.Callbacks("memory"),S.Callbacks("memory"),2],["resolve","done",S.Callbacks("once memory"),S.Callbacks("once memory"),0,"resolved"],["reject","fail",S.Callbacks("once memory"),S.Callbacks("once memory"),1,"rejected"]],r="pending",i={state:function(){return r},always:function(){return a.done(arguments).fail(arguments),this},catch:function(e){return i.then(null,e)},pipe:function(){var e=arguments;return S.Deferred((function(n){S.each(t,(function(t,r){var o=y(e[r[4]])&&e[r[4]];a[r[1]]((function(){var e=o&&o.apply(this,arguments);e&&y(e.promise)?e.promise().progress(n.notify).done(n.resolve).fail(n.reject):n[r[0]+"With"](this,o?[e]:arguments)}))})),e=null})).promise()},then:function(e,r,i){var a=0;function s(e,t,r,i){return function(){var c=this,l=arguments,u=function(){var n,u;if(!(e<a)){if((n=r.apply(c,l))===t.promise())throw new TypeError("Thenable self-resolution");u=n&&("object"===o(n)||"function"==typeof n)&&n.then,y(u)?i?u.call(n,s(a,t,X,i),s(a,t,Q,i)):(a++,u.call(n,s(a,t,X,i),s(a,t,Q,i),s(a,t,X,t.notifyWith))):(r!==X&&(c=void 0,l=[n]),(i||t.resolveWith)(c,l))}},f=i?u:function(){try{u()}catch(n){S.Deferred.exceptionHook&&S.Deferred.exceptionHook(n,f.error),e+1>=a&&(r!==Q&&(c=void 0,l=[n]),t.rejectWith(c,l))}};e?f():(S.Deferred.getErrorHook?f.error=S.Deferred.getErrorHook():S.Deferred.getStackHook&&(f.error=S.Deferred.getStackHook()),n.setTimeout(f))}}return S.Deferred((function(n){t[0][3].add(s(0,n,y(i)?i:X,n.notifyWith)),t[1][3].add(s(0,n,y(e)?e:X)),t[2][3].add(s(0,n,y(r)?r:Q))})).promise()},promise:function(e){return null!=e?S.extend(e,i):i}},a={};return S.each(t,(function(e,n){var o=n[2],s=n[5];i[n[1]]=o.add,s&&o.add((function(){r=s}),t[3-e][2].disable,t[3-e][3].disable,t[0][2].lock,t[0][3].lock),o.add(n[3].fire),a[n[0]]=function(){return a[n[0]+"With"](this===a?void 0:this,arguments),this},a[n[0]+"With"]=o.fireWith})),i.promise(a),e&&e.call(a,a),a},when:function(e){var t=arguments.length,n=t,r=Array(n),o=c.call(arguments),i=S.Deferred(),a=function(e){return function(n){r[e]=this,o[e]=arguments.length>1?c.call(arguments):n,--t||i.resolveWith(r,o)}};if(t<=1&&(G(e,i.done(a(n)).resolve,i.reject,!t),"pending"===i.state()||y(o[n]&&o[n].then)))return i.then();for(;n--;)G(o[n],a(n),i.reject);return i.promise()}});var Y=/^(Eval|Internal|Range|Reference|Syntax|Type|URI)Error$/;S.Deferred.exceptionHook=function(e,t){n.console&&n.console.warn&&e&&Y.test(e.name)&&n.console.warn("jQuery.Deferred exception: "+e.message,e.stack,t)},S.readyException=function(e){n.setTimeout((function(){throw e}))};var Z=S.Deferred();function ee(){_.removeEventListener("DOMContentLoaded",ee),n.removeEventListener("load",ee),S.ready()}S.fn.ready=function(e){return Z.then(e).catch((function(e){S.readyException(e)})),this},S.extend({isReady:!1,readyWait:1,ready:function(e){(!0===e?--S.readyWait:S.isReady)||(S.isReady=!0,!0!==e&&--S.readyWait>0||Z.resolveWith(_,[S]))}}),S.ready.then=Z.then,"complete"===_.readyState||"loading"!==_.readyState&&!_.documentElement.doScroll?n.setTimeout(S.ready):(_.addEventListener("DOMContentLoaded",ee),n.addEventListener("load",ee));var te=function e(t,n,r,o,i,a,s){var c=0,l=t.length,u=null==r;if("object"===C(r))for(c in i=!0,r)e(t,n,c,r[c],!0,a,s);else if(void 0!==o&&(i=!0,y(o)||(s=!0),u&&(s?(n.call(t,o),n=null):(u=n,n=function(e,t,n){return u.call(S(e),n)})),n))for(;c<l;c++)n(t[c],r,s?o:o.call(t[c],c,n(t[c],r)));return i?t:u?n.call(t):l?n(t[0],r):a},ne=/^-ms-/,re=/-([a-z])/g;function oe(e,t){return t.toUpperCase()}function ie(e){return e.replace(ne,"ms-").replace(re,oe)}var ae=function(e){return 1===e.nodeType||9===e.nodeType||!+e.nodeType};function se(){this.expando=S.expando+se.uid++}se.uid=1,se.prototype={cache:function(e){var t=e[this.expando];return t||(t={},ae(e)&&(e.nodeType?e[this.expando]=t:Object.defineProperty(e,this.expando,{value:t,configurable:!0}))),t},set:function(e,t,n){var r,o=this.cache(e);if("string"==typeof t)o[ie(t)]=n;else for(r in t)o[ie(r)]=t[r];return o},get:function(e,t){return void 0===t?this.cache(e):e[this.expando]&&e[this.expando][ie(t)]},access:function(e,t,n){return void 0===t||t&&"string"==typeof t&&void 0===n?this.get(e,t):(this.set(e,t,n),void 0!==n?n:t)},remove:function(e,t){var n,r=e[this.expando];if(void 0!==r){if(void 0!==t){n=(t=Array.isArray(t)?t.map(ie):(t=ie(t))in r?[t]:t.match(K)||[]).length;for(;n--;)delete r[t[n]]}(void 0===t||S.isEmptyObject(r))&&(e.nodeType?e[this.expando]=void 0:delete e[this.expando])}},hasData:function(e){var t=e[this.expando];return void 0!==t&&!S.isEmptyObject(t)}};var ce=new se,le=new se,ue=/^(?:\{[\w\W]*\}|\[[\w\W]*\])$/,fe=/[A-Z]/g;function de(e,t,n){var r;if(void 0===n&&1===e.nodeType)if(r="data-"+t.replace(fe,"-$&").toLowerCase(),"string"==typeof(n=e.getAttribute(r))){try{n=function(e){return"true"===e||"false"!==e&&("null"===e?null:e===+e+""?+e:ue.test(e)?JSON.parse(e):e)}(n)}catch(e){}le.set(e,t,n)}else n=void 0;return n}S.extend({hasData:function(e){return le.hasData(e)||ce.hasData(e)},data:function(e,t,n){return le.access(e,t,n)},removeData:function(e,t){le.remove(e,t)},_data:function(e,t,n){return ce.access(e,t,n)},_removeData:function(e,t){ce.remove(e,t)}}),S.fn.extend({data:function(e,t){var n,r,i,a=this[0],s=a&&a.attributes;if(void 0===e){if(this.length&&(i=le.get(a),1===a.nodeType&&!ce.get(a,"hasDataAttrs"))){for(n=s.length;n--;)s[n]&&0===(r=s[n].name).indexOf("data-")&&(r=ie(r.slice(5)),de(a,r,i[r]));ce.set(a,"hasDataAttrs",!0)}return i}return"object"===o(e)?this.each((function(){le.set(this,e)})):te(this,(function(t){var n;if(a&&void 0===t)return void 0!==(n=le.get(a,e))||void 0!==(n=de(a,e))?n:void 0;this.each((function(){le.set(this,e,t)}))}),null,t,arguments.length>1,null,!0)},removeData:function(e){return this.each((function(){le.remove(this,e)}))}}),S.extend({queue:function(e,t,n){var r;if(e)return t=(t||"fx")+"queue",r=ce.get(e,t),n&&(!r||Array.isArray(n)?r=ce.access(e,t,S.makeArray(n)):r.push(n)),r||[]},dequeue:function(e,t){t=t||"fx";var n=S.queue(e,t),r=n.length,o=n.shift(),i=S._queueHooks(e,t);"inprogress"===o&&(o=n.shift(),r--),o&&("fx"===t&&n.unshift("inprogress"),delete i.stop,o.call(e,(function(){S.dequeue(e,t)}),i)),!r&&i&&i.empty.fire()},_queueHooks:function(e,t){var n=t+"queueHooks";return ce.get(e,n)||ce.access(e,n,{empty:S.Callbacks("once memory").add((function(){ce.remove(e,[t+"queue",n])}))})}}),S.fn.extend({queue:function(e,t){var n=2;return"string"!=typeof e&&(t=e,e="fx",n--),arguments.length<n?S.queue(this[0],e):void 0===t?this:this.each((function(){var n=S.queue(this,e,t);S._queueHooks(this,e),"fx"===e&&"inprogress"!==n[0]&&S.dequeue(this,e)}))},dequeue:function(e){return this.each((function(){S.dequeue(this,e)}))},clearQueue:function(e){return this.queue(e||"fx",[])},promise:function(e,t){var n,r=1,o=S.Deferred(),i=this,a=this.length,s=function(){--r||o.resolveWith(i,[i])};for("string"!=typeof e&&(t=e,e=void 0),e=e||"fx";a--;)(n=ce.get(i[a],e+"queueHooks"))&&n.empty&&(r++,n.empty.add(s));return s(),o.promise(t)}});var pe=/[+-]?(?:\d*\.|)\d+(?:[eE][+-]?\d+|)/.source,he=new RegExp("^(?:([+-])=|)("+pe+")([a-z%]*)$","i"),ve=["Top","Right","Bottom","Left"],me=_.documentElement,ge=function(e){return S.contains(e.ownerDocument,e)},ye={composed:!0};me.getRootNode&&(ge=function(e){return S.contains(e.ownerDocument,e)||e.getRootNode(ye)===e.ownerDocument});var be=function(e,t){return"none"===(e=t||e).style.display||""===e.style.display&&ge(e)&&"none"===S.css(e,"display")};function _e(e,t,n,r){var o,i,a=20,s=r?function(){return r.cur()}:function(){return S.css(e,t,"")},c=s(),l=n&&n[3]||(S.cssNumber[t]?"":"px"),u=e.nodeType&&(S.cssNumber[t]||"px"!==l&&+c)&&he.exec(S.css(e,t));if(u&&u[3]!==l){for(c/=2,l=l||u[3],u=+c||1;a--;)S.style(e,t,u+l),(1-i)*(1-(i=s()/c||.5))<=0&&(a=0),u/=i;u*=2,S.style(e,t,u+l),n=n||[]}return n&&(u=+u||+c||0,o=n[1]?u+(n[1]+1)*n[2]:+n[2],r&&(r.unit=l,r.start=u,r.end=o)),o}var xe={};function we(e){var t,n=e.ownerDocument,r=e.nodeName,o=xe[r];return o||(t=n.body.appendChild(n.createElement(r)),o=S.css(t,"display"),t.parentNode.removeChild(t),"none"===o&&(o="block"),xe[r]=o,o)}function Ce(e,t){for(var n,r,o=[],i=0,a=e.length;i<a;i++)(r=e[i]).style&&(n=r.style.display,t?("none"===n&&(o[i]=ce.get(r,"display")||null,o[i]||(r.style.display="")),""===r.style.display&&be(r)&&(o[i]=we(r))):"none"!==n&&(o[i]="none",ce.set(r,"display",n)));for(i=0;i<a;i++)null!=o[i]&&(e[i].style.display=o[i]);return e}S.fn.extend({show:function(){return Ce(this,!0)},hide:function(){return Ce(this)},toggle:function(e){return"boolean"==typeof e?e?this.show():this.hide():this.each((function(){be(this)?S(this).show():S(this).hide()}))}});var Te,ke,Se=/^(?:checkbox|radio)$/i,Ee=/<([a-z][^\/\0>\x20\t\r\n\f]*)/i,$e=/^$|^module$|\/(?:java|ecma)script/i;Te=_.createDocumentFragment().appendChild(_.createElement("div")),(ke=_.createElement("input")).setAttribute("type","radio"),ke.setAttribute("checked","checked"),ke.setAttribute("name","t"),Te.appendChild(ke),g.checkClone=Te.cloneNode(!0).cloneNode(!0).lastChild.checked,Te.innerHTML="<textarea>x</textarea>",g.noCloneChecked=!!Te.cloneNode(!0).lastChild.defaultValue,Te.innerHTML="<option></option>",g.option=!!Te.lastChild;var Ae={thead:[1,"<table>","</table>"],col:[2,"<table><colgroup>","</colgroup></table>"],tr:[2,"<table><tbody>","</tbody></table>"],td:[3,"<table><tbody><tr>","</tr></tbody></table>"],_default:[0,"",""]};function Oe(e,t){var n;return n=void 0!==e.getElementsByTagName?e.getElementsByTagName(t||"*"):void 0!==e.querySelectorAll?e.querySelectorAll(t||"*"):[],void 0===t||t&&$(e,t)?S.merge([e],n):n}function je(e,t){for(var n=0,r=e.length;n<r;n++)ce.set(e[n],"globalEval",!t||ce.get(t[n],"globalEval"))}Ae.tbody=Ae.tfoot=Ae.colgroup=Ae.caption=Ae.thead,Ae.th=Ae.td,g.option||(Ae.optgroup=Ae.option=[1,"<select multiple='multiple'>","</select>"]);var De=/<|&#?\w+;/;function Ne(e,t,n,r,o){for(var i,a,s,c,l,u,f=t.createDocumentFragment(),d=[],p=0,h=e.length;p<h;p++)if((i=e[p])||0===i)if("object"===C(i))S.merge(d,i.nodeType?[i]:i);else if(De.test(i)){for(a=a||f.appendChild(t.createElement("div")),s=(Ee.exec(i)||["",""])[1].toLowerCase(),c=Ae[s]||Ae._default,a.innerHTML=c[1]+S.htmlPrefilter(i)+c[2],u=c[0];u--;)a=a.lastChild;S.merge(d,a.childNodes),(a=f.firstChild).textContent=""}else d.push(t.createTextNode(i));for(f.textContent="",p=0;i=d[p++];)if(r&&S.inArray(i,r)>-1)o&&o.push(i);else if(l=ge(i),a=Oe(f.appendChild(i),"script"),l&&je(a),n)for(u=0;i=a[u++];)$e.test(i.type||"")&&n.push(i);return f}var Le=/^([^.]*)(?:\.(.+)|)/;function Pe(){return!0}function Ie(){return!1}function Me(e,t,n,r,i,a){var s,c;if("object"===o(t)){for(c in"string"!=typeof n&&(r=r||n,n=void 0),t)Me(e,c,n,r,t[c],a);return e}if(null==r&&null==i?(i=n,r=n=void 0):null==i&&("string"==typeof n?(i=r,r=void 0):(i=r,r=n,n=void 0)),!1===i)i=Ie;else if(!i)return e;return 1===a&&(s=i,i=function(e){return S().off(e),s.apply(this,arguments)},i.guid=s.guid||(s.guid=S.guid++)),e.each((function(){S.event.add(this,t,i,r,n)}))}function Re(e,t,n){n?(ce.set(e,t,!1),S.event.add(e,t,{namespace:!1,handler:function(e){var n,r=ce.get(this,t);if(1&e.isTrigger&&this[t]){if(r)(S.event.special[t]||{}).delegateType&&e.stopPropagation();else if(r=c.call(arguments),ce.set(this,t,r),this[t](),n=ce.get(this,t),ce.set(this,t,!1),r!==n)return e.stopImmediatePropagation(),e.preventDefault(),n}else r&&(ce.set(this,t,S.event.trigger(r[0],r.slice(1),this)),e.stopPropagation(),e.isImmediatePropagationStopped=Pe)}})):void 0===ce.get(e,t)&&S.event.add(e,t,Pe)}S.event={global:{},add:function(e,t,n,r,o){var i,a,s,c,l,u,f,d,p,h,v,m=ce.get(e);if(ae(e))for(n.handler&&(n=(i=n).handler,o=i.selector),o&&S.find.matchesSelector(me,o),n.guid||(n.guid=S.guid++),(c=m.events)||(c=m.events=Object.create(null)),(a=m.handle)||(a=m.handle=function(t){return void 0!==S&&S.event.triggered!==t.type?S.event.dispatch.apply(e,arguments):void 0}),l=(t=(t||"").match(K)||[""]).length;l--;)p=v=(s=Le.exec(t[l])||[])[1],h=(s[2]||"").split(".").sort(),p&&(f=S.event.special[p]||{},p=(o?f.delegateType:f.bindType)||p,f=S.event.special[p]||{},u=S.extend({type:p,origType:v,data:r,handler:n,guid:n.guid,selector:o,needsContext:o&&S.expr.match.needsContext.test(o),namespace:h.join(".")},i),(d=c[p])||((d=c[p]=[]).delegateCount=0,f.setup&&!1!==f.setup.call(e,r,h,a)||e.addEventListener&&e.addEventListener(p,a)),f.add&&(f.add.call(e,u),u.handler.guid||(u.handler.guid=n.guid)),o?d.splice(d.delegateCount++,0,u):d.push(u),S.event.global[p]=!0)},remove:function(e,t,n,r,o){var i,a,s,c,l,u,f,d,p,h,v,m=ce.hasData(e)&&ce.get(e);if(m&&(c=m.events)){for(l=(t=(t||"").match(K)||[""]).length;l--;)if(p=v=(s=Le.exec(t[l])||[])[1],h=(s[2]||"").split(".").sort(),p){for(f=S.event.special[p]||{},d=c[p=(r?f.delegateType:f.bindType)||p]||[],s=s[2]&&new RegExp("(^|\\.)"+h.join("\\.(?:.*\\.|)")+"(\\.|$)"),a=i=d.length;i--;)u=d[i],!o&&v!==u.origType||n&&n.guid!==u.guid||s&&!s.test(u.namespace)||r&&r!==u.selector&&("**"!==r||!u.selector)||(d.splice(i,1),u.selector&&d.delegateCount--,f.remove&&f.remove.call(e,u));a&&!d.length&&(f.teardown&&!1!==f.teardown.call(e,h,m.handle)||S.removeEvent(e,p,m.handle),delete c[p])}else for(p in c)S.event.remove(e,p+t[l],n,r,!0);S.isEmptyObject(c)&&ce.remove(e,"handle events")}},dispatch:function(e){var t,n,r,o,i,a,s=new Array(arguments.length),c=S.event.fix(e),l=(ce.get(this,"events")||Object.create(null))[c.type]||[],u=S.event.special[c.type]||{};for(s[0]=c,t=1;t<arguments.length;t++)s[t]=arguments[t];if(c.delegateTarget=this,!u.preDispatch||!1!==u.preDispatch.call(this,c)){for(a=S.event.handlers.call(this,c,l),t=0;(o=a[t++])&&!c.isPropagationStopped();)for(c.currentTarget=o.elem,n=0;(i=o.handlers[n++])&&!c.isImmediatePropagationStopped();)c.rnamespace&&!1!==i.namespace&&!c.rnamespace.test(i.namespace)||(c.handleObj=i,c.data=i.data,void 0!==(r=((S.event.special[i.origType]||{}).handle||i.handler).apply(o.elem,s))&&!1===(c.result=r)&&(c.preventDefault(),c.stopPropagation()));return u.postDispatch&&u.postDispatch.call(this,c),c.result}},handlers:function(e,t){var n,r,o,i,a,s=[],c=t.delegateCount,l=e.target;if(c&&l.nodeType&&!("click"===e.type&&e.button>=1))for(;l!==this;l=l.parentNode||this)if(1===l.nodeType&&("click"!==e.type||!0!==l.disabled)){for(i=[],a={},n=0;n<c;n++)void 0===a[o=(r=t[n]).selector+" "]&&(a[o]=r.needsContext?S(o,this).index(l)>-1:S.find(o,this,null,[l]).length),a[o]&&i.push(r);i.length&&s.push({elem:l,handlers:i})}return l=this,c<t.length&&s.push({elem:l,handlers:t.slice(c)}),s},addProp:function(e,t){Object.defineProperty(S.Event.prototype,e,{enumerable:!0,configurable:!0,get:y(t)?function(){if(this.originalEvent)return t(this.originalEvent)}:function(){if(this.originalEvent)return this.originalEvent[e]},set:function(t){Object.defineProperty(this,e,{enumerable:!0,configurable:!0,writable:!0,value:t})}})},fix:function(e){return e[S.expando]?e:new S.Event(e)},special:{load:{noBubble:!0},click:{setup:function(e){var t=this||e;return Se.test(t.type)&&t.click&&$(t,"input")&&Re(t,"click",!0),!1},trigger:function(e){var t=this||e;return Se.test(t.type)&&t.click&&$(t,"input")&&Re(t,"click"),!0},_default:function(e){var t=e.target;return Se.test(t.type)&&t.click&&$(t,"input")&&ce.get(t,"click")||$(t,"a")}},beforeunload:{postDispatch:function(e){void 0!==e.result&&e.originalEvent&&(e.originalEvent.returnValue=e.result)}}}},S.removeEvent=function(e,t,n){e.removeEventListener&&e.removeEventListener(t,n)},S.Event=function(e,t){if(!(this instanceof S.Event))return new S.Event(e,t);e&&e.type?(this.originalEvent=e,this.type=e.type,this.isDefaultPrevented=e.defaultPrevented||void 0===e.defaultPrevented&&!1===e.returnValue?Pe:Ie,this.target=e.target&&3===e.target.nodeType?e.target.parentNode:e.target,this.currentTarget=e.currentTarget,this.relatedTarget=e.relatedTarget):this.type=e,t&&S.extend(this,t),this.timeStamp=e&&e.timeStamp||Date.now(),this[S.expando]=!0},S.Event.prototype={constructor:S.Event,isDefaultPrevented:Ie,isPropagationStopped:Ie,isImmediatePropagationStopped:Ie,isSimulated:!1,preventDefault:function(){var e=this.originalEvent;this.isDefaultPrevented=Pe,e&&!this.isSimulated&&e.preventDefault()},stopPropagation:function(){var e=this.originalEvent;this.isPropagationStopped=Pe,e&&!this.isSimulated&&e.stopPropagation()},stopImmediatePropagation:function(){var e=this.originalEvent;this.isImmediatePropagationStopped=Pe,e&&!this.isSimulated&&e.stopImmediatePropagation(),this.stopPropagation()}},S.each({altKey:!0,bubbles:!0,cancelable:!0,changedTouches:!0,ctrlKey:!0,detail:!0,eventPhase:!0,metaKey:!0,pageX:!0,pageY:!0,shiftKey:!0,view:!0,char:!0,code:!0,charCode:!0,key:!0,keyCode:!0,button:!0,buttons:!0,clientX:!0,clientY:!0,offsetX:!0,offsetY:!0,pointerId:!0,pointerType:!0,screenX:!0,screenY:!0,targetTouches:!0,toElement:!0,touches:!0,which:!0},S.event.addProp),S.each({focus:"focusin",blur:"focusout"},(function(e,t){function n(e){if(_.documentMode){var n=ce.get(this,"handle"),r=S.event.fix(e);r.type="focusin"===e.type?"focus":"blur",r.isSimulated=!0,n(e),r.target===r.currentTarget&&n(r)}else S.event.simulate(t,e.target,S.event.fix(e))}S.event.special[e]={setup:function(){var r;if(Re(this,e,!0),!_.documentMode)return!1;(r=ce.get(this,t))||this.addEventListener(t,n),ce.set(this,t,(r||0)+1)},trigger:function(){return Re(this,e),!0},teardown:function(){var e;if(!_.documentMode)return!1;(e=ce.get(this,t)-1)?ce.set(this,t,e):(this.removeEventListener(t,n),ce.remove(this,t))},_default:function(t){return ce.get(t.target,e)},delegateType:t},S.event.special[t]={setup:function(){var r=this.ownerDocument||this.document||this,o=_.documentMode?this:r,i=ce.get(o,t);i||(_.documentMode?this.addEventListener(t,n):r.addEventListener(e,n,!0)),ce.set(o,t,(i||0)+1)},teardown:function(){var r=this.ownerDocument||this.document||this,o=_.documentMode?this:r,i=ce.get(o,t)-1;i?ce.set(o,t,i):(_.documentMode?this.removeEventListener(t,n):r.removeEventListener(e,n,!0),ce.remove(o,t))}}})),S.each({mouseenter:"mouseover",mouseleave:"mouseout",pointerenter:"pointerover",pointerleave:"pointerout"},(function(e,t){S.event.special[e]={delegateType:t,bindType:t,handle:function(e){var n,r=e.relatedTarget,o=e.handleObj;return r&&(r===this||S.contains(this,r))||(e.type=o.origType,n=o.handler.apply(this,arguments),e.type=t),n}}})),S.fn.extend({on:function(e,t,n,r){return Me(this,e,t,n,r)},one:function(e,t,n,r){return Me(this,e,t,n,r,1)},off:function(e,t,n){var r,i;if(e&&e.preventDefault&&e.handleObj)return r=e.handleObj,S(e.delegateTarget).off(r.namespace?r.origType+"."+r.namespace:r.origType,r.selector,r.handler),this;if("object"===o(e)){for(i in e)this.off(i,t,e[i]);return this}return!1!==t&&"function"!=typeof t||(n=t,t=void 0),!1===n&&(n=Ie),this.each((function(){S.event.remove(this,e,n,t)}))}});var Fe=/<script|<style|<link/i,He=/checked\s*(?:[^=]|=\s*.checked.)/i,qe=/^\s*<!\[CDATA\[|\]\]>\s*$/g;function Be(e,t){return $(e,"table")&&$(11!==t.nodeType?t:t.firstChild,"tr")&&S(e).children("tbody")[0]||e}function We(e){return e.type=(null!==e.getAttribute("type"))+"/"+e.type,e}function Ue(e){return"true/"===(e.type||"").slice(0,5)?e.type=e.type.slice(5):e.removeAttribute("type"),e}function ze(e,t){var n,r,o,i,a,s;if(1===t.nodeType){if(ce.hasData(e)&&(s=ce.get(e).events))for(o in ce.remove(t,"handle events"),s)for(n=0,r=s[o].length;n<r;n++)S.event.add(t,o,s[o][n]);le.hasData(e)&&(i=le.access(e),a=S.extend({},i),le.set(t,a))}}function Ve(e,t){var n=t.nodeName.toLowerCase();"input"===n&&Se.test(e.type)?t.checked=e.checked:"input"!==n&&"textarea"!==n||(t.defaultValue=e.defaultValue)}function Je(e,t,n,r){t=l(t);var o,i,a,s,c,u,f=0,d=e.length,p=d-1,h=t[0],v=y(h);if(v||d>1&&"string"==typeof h&&!g.checkClone&&He.test(h))return e.each((function(o){var i=e.eq(o);v&&(t[0]=h.call(this,o,i.html())),Je(i,t,n,r)}));if(d&&(i=(o=Ne(t,e[0].ownerDocument,!1,e,r)).firstChild,1===o.childNodes.length&&(o=i),i||r)){for(s=(a=S.map(Oe(o,"script"),We)).length;f<d;f++)c=o,f!==p&&(c=S.clone(c,!0,!0),s&&S.merge(a,Oe(c,"script"))),n.call(e[f],c,f);if(s)for(u=a[a.length-1].ownerDocument,S.map(a,Ue),f=0;f<s;f++)c=a[f],$e.test(c.type||"")&&!ce.access(c,"globalEval")&&S.contains(u,c)&&(c.src&&"module"!==(c.type||"").toLowerCase()?S._evalUrl&&!c.noModule&&S._evalUrl(c.src,{nonce:c.nonce||c.getAttribute("nonce")},u):w(c.textContent.replace(qe,""),c,u))}return e}function Ke(e,t,n){for(var r,o=t?S.filter(t,e):e,i=0;null!=(r=o[i]);i++)n||1!==r.nodeType||S.cleanData(Oe(r)),r.parentNode&&(n&&ge(r)&&je(Oe(r,"script")),r.parentNode.removeChild(r));return e}S.extend({htmlPrefilter:function(e){return e},clone:function(e,t,n){var r,o,i,a,s=e.cloneNode(!0),c=ge(e);if(!(g.noCloneChecked||1!==e.nodeType&&11!==e.nodeType||S.isXMLDoc(e)))for(a=Oe(s),r=0,o=(i=Oe(e)).length;r<o;r++)Ve(i[r],a[r]);if(t)if(n)for(i=i||Oe(e),a=a||Oe(s),r=0,o=i.length;r<o;r++)ze(i[r],a[r]);else ze(e,s);return(a=Oe(s,"script")).length>0&&je(a,!c&&Oe(e,"script")),s},cleanData:function(e){for(var t,n,r,o=S.event.special,i=0;void 0!==(n=e[i]);i++)if(ae(n)){if(t=n[ce.expando]){if(t.events)for(r in t.events)o[r]?S.event.remove(n,r):S.removeEvent(n,r,t.handle);n[ce.expando]=void 0}n[le.expando]&&(n[le.expando]=void 0)}}}),S.fn.extend({detach:function(e){return Ke(this,e,!0)},remove:function(e){return Ke(this,e)},text:function(e){return te(this,(function(e){return void 0===e?S.text(this):this.empty().each((function(){1!==this.nodeType&&11!==this.nodeType&&9!==this.nodeType||(this.textContent=e)}))}),null,e,arguments.length)},append:function(){return Je(this,arguments,(function(e){1!==this.nodeType&&11!==this.nodeType&&9!==this.nodeType||Be(this,e).appendChild(e)}))},prepend:function(){return Je(this,arguments,(function(e){if(1===this.nodeType||11===this.nodeType||9===this.nodeType){var t=Be(this,e);t.insertBefore(e,t.firstChild)}}))},before:function(){return Je(this,arguments,(function(e){this.parentNode&&this.parentNode.insertBefore(e,this)}))},after:function(){return Je(this,arguments,(function(e){this.parentNode&&this.parentNode.insertBefore(e,this.nextSibling)}))},empty:function(){for(var e,t=0;null!=(e=this[t]);t++)1===e.nodeType&&(S.cleanData(Oe(e,!1)),e.textContent="");return this},clone:function(e,t){return e=null!=e&&e,t=null==t?e:t,this.map((function(){return S.clone(this,e,t)}))},html:function(e){return te(this,(function(e){var t=this[0]||{},n=0,r=this.length;if(void 0===e&&1===t.nodeType)return t.innerHTML;if("string"==typeof e&&!Fe.test(e)&&!Ae[(Ee.exec(e)||["",""])[1].toLowerCase()]){e=S.htmlPrefilter(e);try{for(;n<r;n++)1===(t=this[n]||{}).nodeType&&(S.cleanData(Oe(t,!1)),t.innerHTML=e);t=0}catch(e){}}t&&this.empty().append(e)}),null,e,arguments.length)},replaceWith:function(){var e=[];return Je(this,arguments,(function(t){var n=this.parentNode;S.inArray(this,e)<0&&(S.cleanData(Oe(this)),n&&n.replaceChild(t,this))}),e)}}),S.each({appendTo:"append",prependTo:"prepend",insertBefore:"before",insertAfter:"after",replaceAll:"replaceWith"},(function(e,t){S.fn[e]=function(e){for(var n,r=[],o=S(e),i=o.length-1,a=0;a<=i;a++)n=a===i?this:this.clone(!0),S(o[a])[t](n),u.apply(r,n.get());return this.pushStack(r)}}));var Xe=new RegExp("^("+pe+")(?!px)[a-z%]+$","i"),Qe=/^--/,Ge=function(e){var t=e.ownerDocument.defaultView;return t&&t.opener||(t=n),t.getComputedStyle(e)},Ye=function(e,t,n){var r,o,i={};for(o in t)i[o]=e.style[o],e.style[o]=t[o];for(o in r=n.call(e),t)e.style[o]=i[o];return r},Ze=new RegExp(ve.join("|"),"i");function et(e,t,n){var r,o,i,a,s=Qe.test(t),c=e.style;return(n=n||Ge(e))&&(a=n.getPropertyValue(t)||n[t],s&&a&&(a=a.replace(N,"$1")||void 0),""!==a||ge(e)||(a=S.style(e,t)),!g.pixelBoxStyles()&&Xe.test(a)&&Ze.test(t)&&(r=c.width,o=c.minWidth,i=c.maxWidth,c.minWidth=c.maxWidth=c.width=a,a=n.width,c.width=r,c.minWidth=o,c.maxWidth=i)),void 0!==a?a+"":a}function tt(e,t){return{get:function(){if(!e())return(this.get=t).apply(this,arguments);delete this.get}}}!function(){function e(){if(u){l.style.cssText="position:absolute;left:-11111px;width:60px;margin-top:1px;padding:0;border:0",u.style.cssText="position:relative;display:block;box-sizing:border-box;overflow:scroll;margin:auto;border:1px;padding:1px;width:60%;top:1%",me.appendChild(l).appendChild(u);var e=n.getComputedStyle(u);r="1%"!==e.top,c=12===t(e.marginLeft),u.style.right="60%",a=36===t(e.right),o=36===t(e.width),u.style.position="absolute",i=12===t(u.offsetWidth/3),me.removeChild(l),u=null}}function t(e){return Math.round(parseFloat(e))}var r,o,i,a,s,c,l=_.createElement("div"),u=_.createElement("div");u.style&&(u.style.backgroundClip="content-box",u.cloneNode(!0).style.backgroundClip="",g.clearCloneStyle="content-box"===u.style.backgroundClip,S.extend(g,{boxSizingReliable:function(){return e(),o},pixelBoxStyles:function(){return e(),a},pixelPosition:function(){return e(),r},reliableMarginLeft:function(){return e(),c},scrollboxSize:function(){return e(),i},reliableTrDimensions:function(){var e,t,r,o;return null==s&&(e=_.createElement("table"),t=_.createElement("tr"),r=_.createElement("div"),e.style.cssText="position:absolute;left:-11111px;border-collapse:separate",t.style.cssText="box-sizing:content-box;border:1px solid",t.style.height="1px",r.style.height="9px",r.style.display="block",me.appendChild(e).appendChild(t).appendChild(r),o=n.getComputedStyle(t),s=parseInt(o.height,10)+parseInt(o.borderTopWidth,10)+parseInt(o.borderBottomWidth,10)===t.offsetHeight,me.removeChild(e)),s}}))}();var nt=["Webkit","Moz","ms"],rt=_.createElement("div").style,ot={};function it(e){var t=S.cssProps[e]||ot[e];return t||(e in rt?e:ot[e]=function(e){for(var t=e[0].toUpperCase()+e.slice(1),n=nt.length;n--;)if((e=nt[n]+t)in rt)return e}(e)||e)}var at=/^(none|table(?!-c[ea]).+)/,st={position:"absolute",visibility:"hidden",display:"block"},ct={letterSpacing:"0",fontWeight:"400"};function lt(e,t,n){var r=he.exec(t);return r?Math.max(0,r[2]-(n||0))+(r[3]||"px"):t}function ut(e,t,n,r,o,i){var a="width"===t?1:0,s=0,c=0,l=0;if(n===(r?"border":"content"))return 0;for(;a<4;a+=2)"margin"===n&&(l+=S.css(e,n+ve[a],!0,o)),r?("content"===n&&(c-=S.css(e,"padding"+ve[a],!0,o)),"margin"!==n&&(c-=S.css(e,"border"+ve[a]+"Width",!0,o))):(c+=S.css(e,"padding"+ve[a],!0,o),"padding"!==n?c+=S.css(e,"border"+ve[a]+"Width",!0,o):s+=S.css(e,"border"+ve[a]+"Width",!0,o));return!r&&i>=0&&(c+=Math.max(0,Math.ceil(e["offset"+t[0].toUpperCase()+t.slice(1)]-i-c-s-.5))||0),c+l}function ft(e,t,n){var r=Ge(e),o=(!g.boxSizingReliable()||n)&&"border-box"===S.css(e,"boxSizing",!1,r),i=o,a=et(e,t,r),s="offset"+t[0].toUpperCase()+t.slice(1);if(Xe.test(a)){if(!n)return a;a="auto"}return(!g.boxSizingReliable()&&o||!g.reliableTrDimensions()&&$(e,"tr")||"auto"===a||!parseFloat(a)&&"inline"===S.css(e,"display",!1,r))&&e.getClientRects().length&&(o="border-box"===S.css(e,"boxSizing",!1,r),(i=s in e)&&(a=e[s])),(a=parseFloat(a)||0)+ut(e,t,n||(o?"border":"content"),i,r,a)+"px"}function dt(e,t,n,r,o){return new dt.prototype.init(e,t,n,r,o)}S.extend({cssHooks:{opacity:{get:function(e,t){if(t){var n=et(e,"opacity");return""===n?"1":n}}}},cssNumber:{animationIterationCount:!0,aspectRatio:!0,borderImageSlice:!0,columnCount:!0,flexGrow:!0,flexShrink:!0,fontWeight:!0,gridArea:!0,gridColumn:!0,gridColumnEnd:!0,gridColumnStart:!0,gridRow:!0,gridRowEnd:!0,gridRowStart:!0,lineHeight:!0,opacity:!0,order:!0,orphans:!0,scale:!0,widows:!0,zIndex:!0,zoom:!0,fillOpacity:!0,floodOpacity:!0,stopOpacity:!0,strokeMiterlimit:!0,strokeOpacity:!0},cssProps:{},style:function(e,t,n,r){if(e&&3!==e.nodeType&&8!==e.nodeType&&e.style){var i,a,s,c=ie(t),l=Qe.test(t),u=e.style;if(l||(t=it(c)),s=S.cssHooks[t]||S.cssHooks[c],void 0===n)return s&&"get"in s&&void 0!==(i=s.get(e,!1,r))?i:u[t];"string"===(a=o(n))&&(i=he.exec(n))&&i[1]&&(n=_e(e,t,i),a="number"),null!=n&&n==n&&("number"!==a||l||(n+=i&&i[3]||(S.cssNumber[c]?"":"px")),g.clearCloneStyle||""!==n||0!==t.indexOf("background")||(u[t]="inherit"),s&&"set"in s&&void 0===(n=s.set(e,n,r))||(l?u.setProperty(t,n):u[t]=n))}},css:function(e,t,n,r){var o,i,a,s=ie(t);return Qe.test(t)||(t=it(s)),(a=S.cssHooks[t]||S.cssHooks[s])&&"get"in a&&(o=a.get(e,!0,n)),void 0===o&&(o=et(e,t,r)),"normal"===o&&t in ct&&(o=ct[t]),""===n||n?(i=parseFloat(o),!0===n||isFinite(i)?i||0:o):o}}),S.each(["height","width"],(function(e,t){S.cssHooks[t]={get:function(e,n,r){if(n)return!at.test(S.css(e,"display"))||e.getClientRects().length&&e.getBoundingClientRect().width?ft(e,t,r):Ye(e,st,(function(){return ft(e,t,r)}))},set:function(e,n,r){var o,i=Ge(e),a=!g.scrollboxSize()&&"absolute"===i.position,s=(a||r)&&"border-box"===S.css(e,"boxSizing",!1,i),c=r?ut(e,t,r,s,i):0;return s&&a&&(c-=Math.ceil(e["offset"+t[0].toUpperCase()+t.slice(1)]-parseFloat(i[t])-ut(e,t,"border",!1,i)-.5)),c&&(o=he.exec(n))&&"px"!==(o[3]||"px")&&(e.style[t]=n,n=S.css(e,t)),lt(0,n,c)}}})),S.cssHooks.marginLeft=tt(g.reliableMarginLeft,(function(e,t){if(t)return(parseFloat(et(e,"marginLeft"))||e.getBoundingClientRect().left-Ye(e,{marginLeft:0},(function(){return e.getBoundingClientRect().left})))+"px"})),S.each({margin:"",padding:"",border:"Width"},(function(e,t){S.cssHooks[e+t]={expand:function(n){for(var r=0,o={},i="string"==typeof n?n.split(" "):[n];r<4;r++)o[e+ve[r]+t]=i[r]||i[r-2]||i[0];return o}},"margin"!==e&&(S.cssHooks[e+t].set=lt)})),S.fn.extend({css:function(e,t){return te(this,(function(e,t,n){var r,o,i={},a=0;if(Array.isArray(t)){for(r=Ge(e),o=t.length;a<o;a++)i[t[a]]=S.css(e,t[a],!1,r);return i}return void 0!==n?S.style(e,t,n):S.css(e,t)}),e,t,arguments.length>1)}}),S.Tween=dt,dt.prototype={constructor:dt,init:function(e,t,n,r,o,i){this.elem=e,this.prop=n,this.easing=o||S.easing._default,this.options=t,this.start=this.now=this.cur(),this.end=r,this.unit=i||(S.cssNumber[n]?"":"px")},cur:function(){var e=dt.propHooks[this.prop];return e&&e.get?e.get(this):dt.propHooks._default.get(this)},run:function(e){var t,n=dt.propHooks[this.prop];return this.options.duration?this.pos=t=S.easing[this.easing](e,this.options.duration*e,0,1,this.options.duration):this.pos=t=e,this.now=(this.end-this.start)*t+this.start,this.options.step&&this.options.step.call(this.elem,this.now,this),n&&n.set?n.set(this):dt.propHooks._default.set(this),this}},dt.prototype.init.prototype=dt.prototype,dt.propHooks={_default:{get:function(e){var t;return 1!==e.elem.nodeType||null!=e.elem[e.prop]&&null==e.elem.style[e.prop]?e.elem[e.prop]:(t=S.css(e.elem,e.prop,""))&&"auto"!==t?t:0},set:function(e){S.fx.step[e.prop]?S.fx.step[e.prop](e):1!==e.elem.nodeType||!S.cssHooks[e.prop]&&null==e.elem.style[it(e.prop)]?e.elem[e.prop]=e.now:S.style(e.elem,e.prop,e.now+e.unit)}}},dt.propHooks.scrollTop=dt.propHooks.scrollLeft={set:function(e){e.elem.nodeType&&e.elem.parentNode&&(e.elem[e.prop]=e.now)}},S.easing={linear:function(e){return e},swing:function(e){return.5-Math.cos(e*Math.PI)/2},_default:"swing"},S.fx=dt.prototype.init,S.fx.step={};var pt,ht,vt=/^(?:toggle|show|hide)$/,mt=/queueHooks$/;function gt(){ht&&(!1===_.hidden&&n.requestAnimationFrame?n.requestAnimationFrame(gt):n.setTimeout(gt,S.fx.interval),S.fx.tick())}function yt(){return n.setTimeout((function(){pt=void 0})),pt=Date.now()}function bt(e,t){var n,r=0,o={height:e};for(t=t?1:0;r<4;r+=2-t)o["margin"+(n=ve[r])]=o["padding"+n]=e;return t&&(o.opacity=o.width=e),o}function _t(e,t,n){for(var r,o=(xt.tweeners[t]||[]).concat(xt.tweeners["*"]),i=0,a=o.length;i<a;i++)if(r=o[i].call(n,t,e))return r}function xt(e,t,n){var r,o,i=0,a=xt.prefilters.length,s=S.Deferred().always((function(){delete c.elem})),c=function(){if(o)return!1;for(var t=pt||yt(),n=Math.max(0,l.startTime+l.duration-t),r=1-(n/l.duration||0),i=0,a=l.tweens.length;i<a;i++)l.tweens[i].run(r);return s.notifyWith(e,[l,r,n]),r<1&&a?n:(a||s.notifyWith(e,[l,1,0]),s.resolveWith(e,[l]),!1)},l=s.promise({elem:e,props:S.extend({},t),opts:S.extend(!0,{specialEasing:{},easing:S.easing._default},n),originalProperties:t,originalOptions:n,startTime:pt||yt(),duration:n.duration,tweens:[],createTween:function(t,n){var r=S.Tween(e,l.opts,t,n,l.opts.specialEasing[t]||l.opts.easing);return l.tweens.push(r),r},stop:function(t){var n=0,r=t?l.tweens.length:0;if(o)return this;for(o=!0;n<r;n++)l.tweens[n].run(1);return t?(s.notifyWith(e,[l,1,0]),s.resolveWith(e,[l,t])):s.rejectWith(e,[l,t]),this}}),u=l.props;for(!function(e,t){var n,r,o,i,a;for(n in e)if(o=t[r=ie(n)],i=e[n],Array.isArray(i)&&(o=i[1],i=e[n]=i[0]),n!==r&&(e[r]=i,delete e[n]),(a=S.cssHooks[r])&&"expand"in a)for(n in i=a.expand(i),delete e[r],i)n in e||(e[n]=i[n],t[n]=o);else t[r]=o}(u,l.opts.specialEasing);i<a;i++)if(r=xt.prefilters[i].call(l,e,u,l.opts))return y(r.stop)&&(S._queueHooks(l.elem,l.opts.queue).stop=r.stop.bind(r)),r;return S.map(u,_t,l),y(l.opts.start)&&l.opts.start.call(e,l),l.progress(l.opts.progress).done(l.opts.done,l.opts.complete).fail(l.opts.fail).always(l.opts.always),S.fx.timer(S.extend(c,{elem:e,anim:l,queue:l.opts.queue})),l}S.Animation=S.extend(xt,{tweeners:{"*":[function(e,t){var n=this.createTween(e,t);return _e(n.elem,e,he.exec(t),n),n}]},tweener:function(e,t){y(e)?(t=e,e=["*"]):e=e.match(K);for(var n,r=0,o=e.length;r<o;r++)n=e[r],xt.tweeners[n]=xt.tweeners[n]||[],xt.tweeners[n].unshift(t)},prefilters:[function(e,t,n){var r,o,i,a,s,c,l,u,f="width"in t||"height"in t,d=this,p={},h=e.style,v=e.nodeType&&be(e),m=ce.get(e,"fxshow");for(r in n.queue||(null==(a=S._queueHooks(e,"fx")).unqueued&&(a.unqueued=0,s=a.empty.fire,a.empty.fire=function(){a.unqueued||s()}),a.unqueued++,d.always((function(){d.always((function(){a.unqueued--,S.queue(e,"fx").length||a.empty.fire()}))}))),t)if(o=t[r],vt.test(o)){if(delete t[r],i=i||"toggle"===o,o===(v?"hide":"show")){if("show"!==o||!m||void 0===m[r])continue;v=!0}p[r]=m&&m[r]||S.style(e,r)}if((c=!S.isEmptyObject(t))||!S.isEmptyObject(p))for(r in f&&1===e.nodeType&&(n.overflow=[h.overflow,h.overflowX,h.overflowY],null==(l=m&&m.display)&&(l=ce.get(e,"display")),"none"===(u=S.css(e,"display"))&&(l?u=l:(Ce([e],!0),l=e.style.display||l,u=S.css(e,"display"),Ce([e]))),("inline"===u||"inline-block"===u&&null!=l)&&"none"===S.css(e,"float")&&(c||(d.done((function(){h.display=l})),null==l&&(u=h.display,l="none"===u?"":u)),h.display="inline-block")),n.overflow&&(h.overflow="hidden",d.always((function(){h.overflow=n.overflow[0],h.overflowX=n.overflow[1],h.overflowY=n.overflow[2]}))),c=!1,p)c||(m?"hidden"in m&&(v=m.hidden):m=ce.access(e,"fxshow",{display:l}),i&&(m.hidden=!v),v&&Ce([e],!0),d.done((function(){for(r in v||Ce([e]),ce.remove(e,"fxshow"),p)S.style(e,r,p[r])}))),c=_t(v?m[r]:0,r,d),r in m||(m[r]=c.start,v&&(c.end=c.start,c.start=0))}],prefilter:function(e,t){t?xt.prefilters.unshift(e):xt.prefilters.push(e)}}),S.speed=function(e,t,n){var r=e&&"object"===o(e)?S.extend({},e):{complete:n||!n&&t||y(e)&&e,duration:e,easing:n&&t||t&&!y(t)&&t};return S.fx.off?r.duration=0:"number"!=typeof r.duration&&(r.duration in S.fx.speeds?r.duration=S.fx.speeds[r.duration]:r.duration=S.fx.speeds._default),null!=r.queue&&!0!==r.queue||(r.queue="fx"),r.old=r.complete,r.complete=function(){y(r.old)&&r.old.call(this),r.queue&&S.dequeue(this,r.queue)},r},S.fn.extend({fadeTo:function(e,t,n,r){return this.filter(be).css("opacity",0).show().end().animate({opacity:t},e,n,r)},animate:function(e,t,n,r){var o=S.isEmptyObject(e),i=S.speed(t,n,r),a=function(){var t=xt(this,S.extend({},e),i);(o||ce.get(this,"finish"))&&t.stop(!0)};return a.finish=a,o||!1===i.queue?this.each(a):this.queue(i.queue,a)},stop:function(e,t,n){var r=function(e){var t=e.stop;delete e.stop,t(n)};return"string"!=typeof e&&(n=t,t=e,e=void 0),t&&this.queue(e||"fx",[]),this.each((function(){var t=!0,o=null!=e&&e+"queueHooks",i=S.timers,a=ce.get(this);if(o)a[o]&&a[o].stop&&r(a[o]);else for(o in a)a[o]&&a[o].stop&&mt.test(o)&&r(a[o]);for(o=i.length;o--;)i[o].elem!==this||null!=e&&i[o].queue!==e||(i[o].anim.stop(n),t=!1,i.splice(o,1));!t&&n||S.dequeue(this,e)}))},finish:function(e){return!1!==e&&(e=e||"fx"),this.each((function(){var t,n=ce.get(this),r=n[e+"queue"],o=n[e+"queueHooks"],i=S.timers,a=r?r.length:0;for(n.finish=!0,S.queue(this,e,[]),o&&o.stop&&o.stop.call(this,!0),t=i.length;t--;)i[t].elem===this&&i[t].queue===e&&(i[t].anim.stop(!0),i.splice(t,1));for(t=0;t<a;t++)r[t]&&r[t].finish&&r[t].finish.call(this);delete n.finish}))}}),S.each(["toggle","show","hide"],(function(e,t){var n=S.fn[t];S.fn[t]=function(e,r,o){return null==e||"boolean"==typeof e?n.apply(this,arguments):this.animate(bt(t,!0),e,r,o)}})),S.each({slideDown:bt("show"),slideUp:bt("hide"),slideToggle:bt("toggle"),fadeIn:{opacity:"show"},fadeOut:{opacity:"hide"},fadeToggle:{opacity:"toggle"}},(function(e,t){S.fn[e]=function(e,n,r){return this.animate(t,e,n,r)}})),S.timers=[],S.fx.tick=function(){var e,t=0,n=S.timers;for(pt=Date.now();t<n.length;t++)(e=n[t])()||n[t]!==e||n.splice(t--,1);n.length||S.fx.stop(),pt=void 0},S.fx.timer=function(e){S.timers.push(e),S.fx.start()},S.fx.interval=13,S.fx.start=function(){ht||(ht=!0,gt())},S.fx.stop=function(){ht=null},S.fx.speeds={slow:600,fast:200,_default:400},S.fn.delay=function(e,t){return e=S.fx&&S.fx.speeds[e]||e,t=t||"fx",this.queue(t,(function(t,r){var o=n.setTimeout(t,e);r.stop=function(){n.clearTimeout(o)}}))},function(){var e=_.createElement("input"),t=_.createElement("select").appendChild(_.createElement("option"));e.type="checkbox",g.checkOn=""!==e.value,g.optSelected=t.selected,(e=_.createElement("input")).value="t",e.type="radio",g.radioValue="t"===e.value}();var wt,Ct=S.expr.attrHandle;S.fn.extend({attr:function(e,t){return te(this,S.attr,e,t,arguments.length>1)},removeAttr:function(e){return this.each((function(){S.removeAttr(this,e)}))}}),S.extend({attr:function(e,t,n){var r,o,i=e.nodeType;if(3!==i&&8!==i&&2!==i)return void 0===e.getAttribute?S.prop(e,t,n):(1===i&&S.isXMLDoc(e)||(o=S.attrHooks[t.toLowerCase()]||(S.expr.match.bool.test(t)?wt:void 0)),void 0!==n?null===n?void S.removeAttr(e,t):o&&"set"in o&&void 0!==(r=o.set(e,n,t))?r:(e.setAttribute(t,n+""),n):o&&"get"in o&&null!==(r=o.get(e,t))?r:null==(r=S.find.attr(e,t))?void 0:r)},attrHooks:{type:{set:function(e,t){if(!g.radioValue&&"radio"===t&&$(e,"input")){var n=e.value;return e.setAttribute("type",t),n&&(e.value=n),t}}}},removeAttr:function(e,t){var n,r=0,o=t&&t.match(K);if(o&&1===e.nodeType)for(;n=o[r++];)e.removeAttribute(n)}}),wt={set:function(e,t,n){return!1===t?S.removeAttr(e,n):e.setAttribute(n,n),n}},S.each(S.expr.match.bool.source.match(/\w+/g),(function(e,t){var n=Ct[t]||S.find.attr;Ct[t]=function(e,t,r){var o,i,a=t.toLowerCase();return r||(i=Ct[a],Ct[a]=o,o=null!=n(e,t,r)?a:null,Ct[a]=i),o}}));var Tt=/^(?:input|select|textarea|button)$/i,kt=/^(?:a|area)$/i;function St(e){return(e.match(K)||[]).join(" ")}function Et(e){return e.getAttribute&&e.getAttribute("class")||""}function $t(e){return Array.isArray(e)?e:"string"==typeof e&&e.match(K)||[]}S.fn.extend({prop:function(e,t){return te(this,S.prop,e,t,arguments.length>1)},removeProp:function(e){return this.each((function(){delete this[S.propFix[e]||e]}))}}),S.extend({prop:function(e,t,n){var r,o,i=e.nodeType;if(3!==i&&8!==i&&2!==i)return 1===i&&S.isXMLDoc(e)||(t=S.propFix[t]||t,o=S.propHooks[t]),void 0!==n?o&&"set"in o&&void 0!==(r=o.set(e,n,t))?r:e[t]=n:o&&"get"in o&&null!==(r=o.get(e,t))?r:e[t]},propHooks:{tabIndex:{get:function(e){var t=S.find.attr(e,"tabindex");return t?parseInt(t,10):Tt.test(e.nodeName)||kt.test(e.nodeName)&&e.href?0:-1}}},propFix:{for:"htmlFor",class:"className"}}),g.optSelected||(S.propHooks.selected={get:function(e){var t=e.parentNode;return t&&t.parentNode&&t.parentNode.selectedIndex,null},set:function(e){var t=e.parentNode;t&&(t.selectedIndex,t.parentNode&&t.parentNode.selectedIndex)}}),S.each(["tabIndex","readOnly","maxLength","cellSpacing","cellPadding","rowSpan","colSpan","useMap","frameBorder","contentEditable"],(function(){S.propFix[this.toLowerCase()]=this})),S.fn.extend({addClass:function(e){var t,n,r,o,i,a;return y(e)?this.each((function(t){S(this).addClass(e.call(this,t,Et(this)))})):(t=$t(e)).length?this.each((function(){if(r=Et(this),n=1===this.nodeType&&" "+St(r)+" "){for(i=0;i<t.length;i++)o=t[i],n.indexOf(" "+o+" ")<0&&(n+=o+" ");a=St(n),r!==a&&this.setAttribute("class",a)}})):this},removeClass:function(e){var t,n,r,o,i,a;return y(e)?this.each((function(t){S(this).removeClass(e.call(this,t,Et(this)))})):arguments.length?(t=$t(e)).length?this.each((function(){if(r=Et(this),n=1===this.nodeType&&" "+St(r)+" "){for(i=0;i<t.length;i++)for(o=t[i];n.indexOf(" "+o+" ")>-1;)n=n.replace(" "+o+" "," ");a=St(n),r!==a&&this.setAttribute("class",a)}})):this:this.attr("class","")},toggleClass:function(e,t){var n,r,i,a,s=o(e),c="string"===s||Array.isArray(e);return y(e)?this.each((function(n){S(this).toggleClass(e.call(this,n,Et(this),t),t)})):"boolean"==typeof t&&c?t?this.addClass(e):this.removeClass(e):(n=$t(e),this.each((function(){if(c)for(a=S(this),i=0;i<n.length;i++)r=n[i],a.hasClass(r)?a.removeClass(r):a.addClass(r);else void 0!==e&&"boolean"!==s||((r=Et(this))&&ce.set(this,"__className__",r),this.setAttribute&&this.setAttribute("class",r||!1===e?"":ce.get(this,"__className__")||""))})))},hasClass:function(e){var t,n,r=0;for(t=" "+e+" ";n=this[r++];)if(1===n.nodeType&&(" "+St(Et(n))+" ").indexOf(t)>-1)return!0;return!1}});var At=/\r/g;S.fn.extend({val:function(e){var t,n,r,o=this[0];return arguments.length?(r=y(e),this.each((function(n){var o;1===this.nodeType&&(null==(o=r?e.call(this,n,S(this).val()):e)?o="":"number"==typeof o?o+="":Array.isArray(o)&&(o=S.map(o,(function(e){return null==e?"":e+""}))),(t=S.valHooks[this.type]||S.valHooks[this.nodeName.toLowerCase()])&&"set"in t&&void 0!==t.set(this,o,"value")||(this.value=o))}))):o?(t=S.valHooks[o.type]||S.valHooks[o.nodeName.toLowerCase()])&&"get"in t&&void 0!==(n=t.get(o,"value"))?n:"string"==typeof(n=o.value)?n.replace(At,""):null==n?"":n:void 0}}),S.extend({valHooks:{option:{get:function(e){var t=S.find.attr(e,"value");return null!=t?t:St(S.text(e))}},select:{get:function(e){var t,n,r,o=e.options,i=e.selectedIndex,a="select-one"===e.type,s=a?null:[],c=a?i+1:o.length;for(r=i<0?c:a?i:0;r<c;r++)if(((n=o[r]).selected||r===i)&&!n.disabled&&(!n.parentNode.disabled||!$(n.parentNode,"optgroup"))){if(t=S(n).val(),a)return t;s.push(t)}return s},set:function(e,t){for(var n,r,o=e.options,i=S.makeArray(t),a=o.length;a--;)((r=o[a]).selected=S.inArray(S.valHooks.option.get(r),i)>-1)&&(n=!0);return n||(e.selectedIndex=-1),i}}}}),S.each(["radio","checkbox"],(function(){S.valHooks[this]={set:function(e,t){if(Array.isArray(t))return e.checked=S.inArray(S(e).val(),t)>-1}},g.checkOn||(S.valHooks[this].get=function(e){return null===e.getAttribute("value")?"on":e.value})}));var Ot=n.location,jt={guid:Date.now()},Dt=/\?/;S.parseXML=function(e){var t,r;if(!e||"string"!=typeof e)return null;try{t=(new n.DOMParser).parseFromString(e,"text/xml")}catch(e){}return r=t&&t.getElementsByTagName("parsererror")[0],t&&!r||S.error("Invalid XML: "+(r?S.map(r.childNodes,(function(e){return e.textContent})).join("\n"):e)),t};var Nt=/^(?:focusinfocus|focusoutblur)$/,Lt=function(e){e.stopPropagation()};S.extend(S.event,{trigger:function(e,t,r,i){var a,s,c,l,u,f,d,p,v=[r||_],m=h.call(e,"type")?e.type:e,g=h.call(e,"namespace")?e.namespace.split("."):[];if(s=p=c=r=r||_,3!==r.nodeType&&8!==r.nodeType&&!Nt.test(m+S.event.triggered)&&(m.indexOf(".")>-1&&(g=m.split("."),m=g.shift(),g.sort()),u=m.indexOf(":")<0&&"on"+m,(e=e[S.expando]?e:new S.Event(m,"object"===o(e)&&e)).isTrigger=i?2:3,e.namespace=g.join("."),e.rnamespace=e.namespace?new RegExp("(^|\\.)"+g.join("\\.(?:.*\\.|)")+"(\\.|$)"):null,e.result=void 0,e.target||(e.target=r),t=null==t?[e]:S.makeArray(t,[e]),d=S.event.special[m]||{},i||!d.trigger||!1!==d.trigger.apply(r,t))){if(!i&&!d.noBubble&&!b(r)){for(l=d.delegateType||m,Nt.test(l+m)||(s=s.parentNode);s;s=s.parentNode)v.push(s),c=s;c===(r.ownerDocument||_)&&v.push(c.defaultView||c.parentWindow||n)}for(a=0;(s=v[a++])&&!e.isPropagationStopped();)p=s,e.type=a>1?l:d.bindType||m,(f=(ce.get(s,"events")||Object.create(null))[e.type]&&ce.get(s,"handle"))&&f.apply(s,t),(f=u&&s[u])&&f.apply&&ae(s)&&(e.result=f.apply(s,t),!1===e.result&&e.preventDefault());return e.type=m,i||e.isDefaultPrevented()||d._default&&!1!==d._default.apply(v.pop(),t)||!ae(r)||u&&y(r[m])&&!b(r)&&((c=r[u])&&(r[u]=null),S.event.triggered=m,e.isPropagationStopped()&&p.addEventListener(m,Lt),r[m](),e.isPropagationStopped()&&p.removeEventListener(m,Lt),S.event.triggered=void 0,c&&(r[u]=c)),e.result}},simulate:function(e,t,n){var r=S.extend(new S.Event,n,{type:e,isSimulated:!0});S.event.trigger(r,null,t)}}),S.fn.extend({trigger:function(e,t){return this.each((function(){S.event.trigger(e,t,this)}))},triggerHandler:function(e,t){var n=this[0];if(n)return S.event.trigger(e,t,n,!0)}});var Pt=/\[\]$/,It=/\r?\n/g,Mt=/^(?:submit|button|image|reset|file)$/i,Rt=/^(?:input|select|textarea|keygen)/i;function Ft(e,t,n,r){var i;if(Array.isArray(t))S.each(t,(function(t,i){n||Pt.test(e)?r(e,i):Ft(e+"["+("object"===o(i)&&null!=i?t:"")+"]",i,n,r)}));else if(n||"object"!==C(t))r(e,t);else for(i in t)Ft(e+"["+i+"]",t[i],n,r)}S.param=function(e,t){var n,r=[],o=function(e,t){var n=y(t)?t():t;r[r.length]=encodeURIComponent(e)+"="+encodeURIComponent(null==n?"":n)};if(null==e)return"";if(Array.isArray(e)||e.jquery&&!S.isPlainObject(e))S.each(e,(function(){o(this.name,this.value)}));else for(n in e)Ft(n,e[n],t,o);return r.join("&")},S.fn.extend({serialize:function(){return S.param(this.serializeArray())},serializeArray:function(){return this.map((function(){var e=S.prop(this,"elements");return e?S.makeArray(e):this})).filter((function(){var e=this.type;return this.name&&!S(this).is(":disabled")&&Rt.test(this.nodeName)&&!Mt.test(e)&&(this.checked||!Se.test(e))})).map((function(e,t){var n=S(this).val();return null==n?null:Array.isArray(n)?S.map(n,(function(e){return{name:t.name,value:e.replace(It,"\r\n")}})):{name:t.name,value:n.replace(It,"\r\n")}})).get()}});var Ht=/%20/g,qt=/#.*$/,Bt=/([?&])_=[^&]*/,Wt=/^(.*?):[ \t]*([^\r\n]*)$/gm,Ut=/^(?:GET|HEAD)$/,zt=/^\/\//,Vt={},Jt={},Kt="*/".concat("*"),Xt=_.createElement("a");function Qt(e){return function(t,n){"string"!=typeof t&&(n=t,t="*");var r,o=0,i=t.toLowerCase().match(K)||[];if(y(n))for(;r=i[o++];)"+"===r[0]?(r=r.slice(1)||"*",(e[r]=e[r]||[]).unshift(n)):(e[r]=e[r]||[]).push(n)}}function Gt(e,t,n,r){var o={},i=e===Jt;function a(s){var c;return o[s]=!0,S.each(e[s]||[],(function(e,s){var l=s(t,n,r);return"string"!=typeof l||i||o[l]?i?!(c=l):void 0:(t.dataTypes.unshift(l),a(l),!1)})),c}return a(t.dataTypes[0])||!o["*"]&&a("*")}function Yt(e,t){var n,r,o=S.ajaxSettings.flatOptions||{};for(n in t)void 0!==t[n]&&((o[n]?e:r||(r={}))[n]=t[n]);return r&&S.extend(!0,e,r),e}Xt.href=Ot.href,S.extend({active:0,lastModified:{},etag:{},ajaxSettings:{url:Ot.href,type:"GET",isLocal:/^(?:about|app|app-storage|.+-extension|file|res|widget):$/.test(Ot.protocol),global:!0,processData:!0,async:!0,contentType:"application/x-www-form-urlencoded; charset=UTF-8",accepts:{"*":Kt,text:"text/plain",html:"text/html",xml:"application/xml, text/xml",json:"application/json, text/javascript"},contents:{xml:/\bxml\b/,html:/\bhtml/,json:/\bjson\b/},responseFields:{xml:"responseXML",text:"responseText",json:"responseJSON"},converters:{"* text":String,"text html":!0,"text json":JSON.parse,"text xml":S.parseXML},flatOptions:{url:!0,context:!0}},ajaxSetup:function(e,t){return t?Yt(Yt(e,S.ajaxSettings),t):Yt(S.ajaxSettings,e)},ajaxPrefilter:Qt(Vt),ajaxTransport:Qt(Jt),ajax:function(e,t){"object"===o(e)&&(t=e,e=void 0),t=t||{};var r,i,a,s,c,l,u,f,d,p,h=S.ajaxSetup({},t),v=h.context||h,m=h.context&&(v.nodeType||v.jquery)?S(v):S.event,g=S.Deferred(),y=S.Callbacks("once memory"),b=h.statusCode||{},x={},w={},C="canceled",T={readyState:0,getResponseHeader:function(e){var t;if(u){if(!s)for(s={};t=Wt.exec(a);)s[t[1].toLowerCase()+" "]=(s[t[1].toLowerCase()+" "]||[]).concat(t[2]);t=s[e.toLowerCase()+" "]}return null==t?null:t.join(", ")},getAllResponseHeaders:function(){return u?a:null},setRequestHeader:function(e,t){return null==u&&(e=w[e.toLowerCase()]=w[e.toLowerCase()]||e,x[e]=t),this},overrideMimeType:function(e){return null==u&&(h.mimeType=e),this},statusCode:function(e){var t;if(e)if(u)T.always(e[T.status]);else for(t in e)b[t]=[b[t],e[t]];return this},abort:function(e){var t=e||C;return r&&r.abort(t),k(0,t),this}};if(g.promise(T),h.url=((e||h.url||Ot.href)+"").replace(zt,Ot.protocol+"//"),h.type=t.method||t.type||h.method||h.type,h.dataTypes=(h.dataType||"*").toLowerCase().match(K)||[""],null==h.crossDomain){l=_.createElement("a");try{l.href=h.url,l.href=l.href,h.crossDomain=Xt.protocol+"//"+Xt.host!=l.protocol+"//"+l.host}catch(e){h.crossDomain=!0}}if(h.data&&h.processData&&"string"!=typeof h.data&&(h.data=S.param(h.data,h.traditional)),Gt(Vt,h,t,T),u)return T;for(d in(f=S.event&&h.global)&&0==S.active++&&S.event.trigger("ajaxStart"),h.type=h.type.toUpperCase(),h.hasContent=!Ut.test(h.type),i=h.url.replace(qt,""),h.hasContent?h.data&&h.processData&&0===(h.contentType||"").indexOf("application/x-www-form-urlencoded")&&(h.data=h.data.replace(Ht,"+")):(p=h.url.slice(i.length),h.data&&(h.processData||"string"==typeof h.data)&&(i+=(Dt.test(i)?"&":"?")+h.data,delete h.data),!1===h.cache&&(i=i.replace(Bt,"$1"),p=(Dt.test(i)?"&":"?")+"_="+jt.guid+++p),h.url=i+p),h.ifModified&&(S.lastModified[i]&&T.setRequestHeader("If-Modified-Since",S.lastModified[i]),S.etag[i]&&T.setRequestHeader("If-None-Match",S.etag[i])),(h.data&&h.hasContent&&!1!==h.contentType||t.contentType)&&T.setRequestHeader("Content-Type",h.contentType),T.setRequestHeader("Accept",h.dataTypes[0]&&h.accepts[h.dataTypes[0]]?h.accepts[h.dataTypes[0]]+("*"!==h.dataTypes[0]?", "+Kt+"; q=0.01":""):h.accepts["*"]),h.headers)T.setRequestHeader(d,h.headers[d]);if(h.beforeSend&&(!1===h.beforeSend.call(v,T,h)||u))return T.abort();if(C="abort",y.add(h.complete),T.done(h.success),T.fail(h.error),r=Gt(Jt,h,t,T)){if(T.readyState=1,f&&m.trigger("ajaxSend",[T,h]),u)return T;h.async&&h.timeout>0&&(c=n.setTimeout((function(){T.abort("timeout")}),h.timeout));try{u=!1,r.send(x,k)}catch(e){if(u)throw e;k(-1,e)}}else k(-1,"No Transport");function k(e,t,o,s){var l,d,p,_,x,w=t;u||(u=!0,c&&n.clearTimeout(c),r=void 0,a=s||"",T.readyState=e>0?4:0,l=e>=200&&e<300||304===e,o&&(_=function(e,t,n){for(var r,o,i,a,s=e.contents,c=e.dataTypes;"*"===c[0];)c.shift(),void 0===r&&(r=e.mimeType||t.getResponseHeader("Content-Type"));if(r)for(o in s)if(s[o]&&s[o].test(r)){c.unshift(o);break}if(c[0]in n)i=c[0];else{for(o in n){if(!c[0]||e.converters[o+" "+c[0]]){i=o;break}a||(a=o)}i=i||a}if(i)return i!==c[0]&&c.unshift(i),n[i]}(h,T,o)),!l&&S.inArray("script",h.dataTypes)>-1&&S.inArray("json",h.dataTypes)<0&&(h.converters["text script"]=function(){}),_=function(e,t,n,r){var o,i,a,s,c,l={},u=e.dataTypes.slice();if(u[1])for(a in e.converters)l[a.toLowerCase()]=e.converters[a];for(i=u.shift();i;)if(e.responseFields[i]&&(n[e.responseFields[i]]=t),!c&&r&&e.dataFilter&&(t=e.dataFilter(t,e.dataType)),c=i,i=u.shift())if("*"===i)i=c;else if("*"!==c&&c!==i){if(!(a=l[c+" "+i]||l["* "+i]))for(o in l)if((s=o.split(" "))[1]===i&&(a=l[c+" "+s[0]]||l["* "+s[0]])){!0===a?a=l[o]:!0!==l[o]&&(i=s[0],u.unshift(s[1]));break}if(!0!==a)if(a&&e.throws)t=a(t);else try{t=a(t)}catch(e){return{state:"parsererror",error:a?e:"No conversion from "+c+" to "+i}}}return{state:"success",data:t}}(h,_,T,l),l?(h.ifModified&&((x=T.getResponseHeader("Last-Modified"))&&(S.lastModified[i]=x),(x=T.getResponseHeader("etag"))&&(S.etag[i]=x)),204===e||"HEAD"===h.type?w="nocontent":304===e?w="notmodified":(w=_.state,d=_.data,l=!(p=_.error))):(p=w,!e&&w||(w="error",e<0&&(e=0))),T.status=e,T.statusText=(t||w)+"",l?g.resolveWith(v,[d,w,T]):g.rejectWith(v,[T,w,p]),T.statusCode(b),b=void 0,f&&m.trigger(l?"ajaxSuccess":"ajaxError",[T,h,l?d:p]),y.fireWith(v,[T,w]),f&&(m.trigger("ajaxComplete",[T,h]),--S.active||S.event.trigger("ajaxStop")))}return T},getJSON:function(e,t,n){return S.get(e,t,n,"json")},getScript:function(e,t){return S.get(e,void 0,t,"script")}}),S.each(["get","post"],(function(e,t){S[t]=function(e,n,r,o){return y(n)&&(o=o||r,r=n,n=void 0),S.ajax(S.extend({url:e,type:t,dataType:o,data:n,success:r},S.isPlainObject(e)&&e))}})),S.ajaxPrefilter((function(e){var t;for(t in e.headers)"content-type"===t.toLowerCase()&&(e.contentType=e.headers[t]||"")})),S._evalUrl=function(e,t,n){return S.ajax({url:e,type:"GET",dataType:"script",cache:!0,async:!1,global:!1,converters:{"text script":function(){}},dataFilter:function(e){S.globalEval(e,t,n)}})},S.fn.extend({wrapAll:function(e){var t;return this[0]&&(y(e)&&(e=e.call(this[0])),t=S(e,this[0].ownerDocument).eq(0).clone(!0),this[0].parentNode&&t.insertBefore(this[0]),t.map((function(){for(var e=this;e.firstElementChild;)e=e.firstElementChild;return e})).append(this)),this},wrapInner:function(e){return y(e)?this.each((function(t){S(this).wrapInner(e.call(this,t))})):this.each((function(){var t=S(this),n=t.contents();n.length?n.wrapAll(e):t.append(e)}))},wrap:function(e){var t=y(e);return this.each((function(n){S(this).wrapAll(t?e.call(this,n):e)}))},unwrap:function(e){return this.parent(e).not("body").each((function(){S(this).replaceWith(this.childNodes)})),this}}),S.expr.pseudos.hidden=function(e){return!S.expr.pseudos.visible(e)},S.expr.pseudos.visible=function(e){return!!(e.offsetWidth||e.offsetHeight||e.getClientRects().length)},S.ajaxSettings.xhr=function(){try{return new n.XMLHttpRequest}catch(e){}};var Zt={0:200,1223:204},en=S.ajaxSettings.xhr();g.cors=!!en&&"withCredentials"in en,g.ajax=en=!!en,S.ajaxTransport((function(e){var t,r;if(g.cors||en&&!e.crossDomain)return{send:function(o,i){var a,s=e.xhr();if(s.open(e.type,e.url,e.async,e.username,e.password),e.xhrFields)for(a in e.xhrFields)s[a]=e.xhrFields[a];for(a in e.mimeType&&s.overrideMimeType&&s.overrideMimeType(e.mimeType),e.crossDomain||o["X-Requested-With"]||(o["X-Requested-With"]="XMLHttpRequest"),o)s.setRequestHeader(a,o[a]);t=function(e){return function(){t&&(t=r=s.onload=s.onerror=s.onabort=s.ontimeout=s.onreadystatechange=null,"abort"===e?s.abort():"error"===e?"number"!=typeof s.status?i(0,"error"):i(s.status,s.statusText):i(Zt[s.status]||s.status,s.statusText,"text"!==(s.responseType||"text")||"string"!=typeof s.responseText?{binary:s.response}:{text:s.responseText},s.getAllResponseHeaders()))}},s.onload=t(),r=s.onerror=s.ontimeout=t("error"),void 0!==s.onabort?s.onabort=r:s.onreadystatechange=function(){4===s.readyState&&n.setTimeout((function(){t&&r()}))},t=t("abort");try{s.send(e.hasContent&&e.data||null)}catch(e){if(t)throw e}},abort:function(){t&&t()}}})),S.ajaxPrefilter((function(e){e.crossDomain&&(e.contents.script=!1)})),S.ajaxSetup({accepts:{script:"text/javascript, application/javascript, application/ecmascript, application/x-ecmascript"},contents:{script:/\b(?:java|ecma)script\b/},converters:{"text script":function(e){return S.globalEval(e),e}}}),S.ajaxPrefilter("script",(function(e){void 0===e.cache&&(e.cache=!1),e.crossDomain&&(e.type="GET")})),S.ajaxTransport("script",(function(e){var t,n;if(e.crossDomain||e.scriptAttrs)return{send:function(r,o){t=S("<script>").attr(e.scriptAttrs||{}).prop({charset:e.scriptCharset,src:e.url}).on("load error",n=function(e){t.remove(),n=null,e&&o("error"===e.type?404:200,e.type)}),_.head.appendChild(t[0])},abort:function(){n&&n()}}}));var tn,nn=[],rn=/(=)\?(?=&|$)|\?\?/;S.ajaxSetup({jsonp:"callback",jsonpCallback:function(){var e=nn.pop()||S.expando+"_"+jt.guid++;return this[e]=!0,e}}),S.ajaxPrefilter("json jsonp",(function(e,t,r){var o,i,a,s=!1!==e.jsonp&&(rn.test(e.url)?"url":"string"==typeof e.data&&0===(e.contentType||"").indexOf("application/x-www-form-urlencoded")&&rn.test(e.data)&&"data");if(s||"jsonp"===e.dataTypes[0])return o=e.jsonpCallback=y(e.jsonpCallback)?e.jsonpCallback():e.jsonpCallback,s?e[s]=e[s].replace(rn,"$1"+o):!1!==e.jsonp&&(e.url+=(Dt.test(e.url)?"&":"?")+e.jsonp+"="+o),e.converters["script json"]=function(){return a||S.error(o+" was not called"),a[0]},e.dataTypes[0]="json",i=n[o],n[o]=function(){a=arguments},r.always((function(){void 0===i?S(n).removeProp(o):n[o]=i,e[o]&&(e.jsonpCallback=t.jsonpCallback,nn.push(o)),a&&y(i)&&i(a[0]),a=i=void 0})),"script"})),g.createHTMLDocument=((tn=_.implementation.createHTMLDocument("").body).innerHTML="<form></form><form></form>",2===tn.childNodes.length),S.parseHTML=function(e,t,n){return"string"!=typeof e?[]:("boolean"==typeof t&&(n=t,t=!1),t||(g.createHTMLDocument?((r=(t=_.implementation.createHTMLDocument("")).createElement("base")).href=_.location.href,t.head.appendChild(r)):t=_),i=!n&&[],(o=q.exec(e))?[t.createElement(o[1])]:(o=Ne([e],t,i),i&&i.length&&S(i).remove(),S.merge([],o.childNodes)));var r,o,i},S.fn.load=function(e,t,n){var r,i,a,s=this,c=e.indexOf(" ");return c>-1&&(r=St(e.slice(c)),e=e.slice(0,c)),y(t)?(n=t,t=void 0):t&&"object"===o(t)&&(i="POST"),s.length>0&&S.ajax({url:e,type:i||"GET",dataType:"html",data:t}).done((function(e){a=arguments,s.html(r?S("<div>").append(S.parseHTML(e)).find(r):e)})).always(n&&function(e,t){s.each((function(){n.apply(this,a||[e.responseText,t,e])}))}),this},S.expr.pseudos.animated=function(e){return S.grep(S.timers,(function(t){return e===t.elem})).length},S.offset={setOffset:function(e,t,n){var r,o,i,a,s,c,l=S.css(e,"position"),u=S(e),f={};"static"===l&&(e.style.position="relative"),s=u.offset(),i=S.css(e,"top"),c=S.css(e,"left"),("absolute"===l||"fixed"===l)&&(i+c).indexOf("auto")>-1?(a=(r=u.position()).top,o=r.left):(a=parseFloat(i)||0,o=parseFloat(c)||0),y(t)&&(t=t.call(e,n,S.extend({},s))),null!=t.top&&(f.top=t.top-s.top+a),null!=t.left&&(f.left=t.left-s.left+o),"using"in t?t.using.call(e,f):u.css(f)}},S.fn.extend({offset:function(e){if(arguments.length)return void 0===e?this:this.each((function(t){S.offset.setOffset(this,e,t)}));var t,n,r=this[0];return r?r.getClientRects().length?(t=r.getBoundingClientRect(),n=r.ownerDocument.defaultView,{top:t.top+n.pageYOffset,left:t.left+n.pageXOffset}):{top:0,left:0}:void 0},position:function(){if(this[0]){var e,t,n,r=this[0],o={top:0,left:0};if("fixed"===S.css(r,"position"))t=r.getBoundingClientRect();else{for(t=this.offset(),n=r.ownerDocument,e=r.offsetParent||n.documentElement;e&&(e===n.body||e===n.documentElement)&&"static"===S.css(e,"position");)e=e.parentNode;e&&e!==r&&1===e.nodeType&&((o=S(e).offset()).top+=S.css(e,"borderTopWidth",!0),o.left+=S.css(e,"borderLeftWidth",!0))}return{top:t.top-o.top-S.css(r,"marginTop",!0),left:t.left-o.left-S.css(r,"marginLeft",!0)}}},offsetParent:function(){return this.map((function(){for(var e=this.offsetParent;e&&"static"===S.css(e,"position");)e=e.offsetParent;return e||me}))}}),S.each({scrollLeft:"pageXOffset",scrollTop:"pageYOffset"},(function(e,t){var n="pageYOffset"===t;S.fn[e]=function(r){return te(this,(function(e,r,o){var i;if(b(e)?i=e:9===e.nodeType&&(i=e.defaultView),void 0===o)return i?i[t]:e[r];i?i.scrollTo(n?i.pageXOffset:o,n?o:i.pageYOffset):e[r]=o}),e,r,arguments.length)}})),S.each(["top","left"],(function(e,t){S.cssHooks[t]=tt(g.pixelPosition,(function(e,n){if(n)return n=et(e,t),Xe.test(n)?S(e).position()[t]+"px":n}))})),S.each({Height:"height",Width:"width"},(function(e,t){S.each({padding:"inner"+e,content:t,"":"outer"+e},(function(n,r){S.fn[r]=function(o,i){var a=arguments.length&&(n||"boolean"!=typeof o),s=n||(!0===o||!0===i?"margin":"border");return te(this,(function(t,n,o){var i;return b(t)?0===r.indexOf("outer")?t["inner"+e]:t.document.documentElement["client"+e]:9===t.nodeType?(i=t.documentElement,Math.max(t.body["scroll"+e],i["scroll"+e],t.body["offset"+e],i["offset"+e],i["client"+e])):void 0===o?S.css(t,n,s):S.style(t,n,o,s)}),t,a?o:void 0,a)}}))})),S.each(["ajaxStart","ajaxStop","ajaxComplete","ajaxError","ajaxSuccess","ajaxSend"],(function(e,t){S.fn[t]=function(e){return this.on(t,e)}})),S.fn.extend({bind:function(e,t,n){return this.on(e,null,t,n)},unbind:function(e,t){return this.off(e,null,t)},delegate:function(e,t,n,r){return this.on(t,e,n,r)},undelegate:function(e,t,n){return 1===arguments.length?this.off(e,"**"):this.off(t,e||"**",n)},hover:function(e,t){return this.on("mouseenter",e).on("mouseleave",t||e)}}),S.each("blur focus focusin focusout resize scroll click dblclick mousedown mouseup mousemove mouseover mouseout mouseenter mouseleave change select submit keydown keypress keyup contextmenu".split(" "),(function(e,t){S.fn[t]=function(e,n){return arguments.length>0?this.on(t,null,e,n):this.trigger(t)}}));var on=/^[\s\uFEFF\xA0]+|([^\s\uFEFF\xA0])[\s\uFEFF\xA0]+$/g;S.proxy=function(e,t){var n,r,o;if("string"==typeof t&&(n=e[t],t=e,e=n),y(e))return r=c.call(arguments,2),o=function(){return e.apply(t||this,r.concat(c.call(arguments)))},o.guid=e.guid=e.guid||S.guid++,o},S.holdReady=function(e){e?S.readyWait++:S.ready(!0)},S.isArray=Array.isArray,S.parseJSON=JSON.parse,S.nodeName=$,S.isFunction=y,S.isWindow=b,S.camelCase=ie,S.type=C,S.now=Date.now,S.isNumeric=function(e){var t=S.type(e);return("number"===t||"string"===t)&&!isNaN(e-parseFloat(e))},S.trim=function(e){return null==e?"":(e+"").replace(on,"$1")},void 0===(r=function(){return S}.apply(t,[]))||(e.exports=r);var an=n.jQuery,sn=n.$;return S.noConflict=function(e){return n.$===S&&(n.$=sn),e&&n.jQuery===S&&(n.jQuery=an),S},void 0===i&&(n.jQuery=n.$=S),S}))},601:(e,t)=>{"use strict";function n(e,t){var n="undefined"!=typeof Symbol&&e[Symbol.iterator]||e["@@iterator"];if(!n){if(Array.isArray(e)||(n=function(e,t){if(!e)return;if("string"==typeof e)return r(e,t);var n=Object.prototype.toString.call(e).slice(8,-1);"Object"===n&&e.constructor&&(n=e.constructor.name);if("Map"===n||"Set"===n)return Array.from(e);if("Arguments"===n||/^(?:Ui|I)nt(?:8|16|32)(?:Clamped)?Array$/.test(n))return r(e,t)}(e))||t&&e&&"number"==typeof e.length){n&&(e=n);var o=0,i=function(){};return{s:i,n:function(){return o>=e.length?{done:!0}:{done:!1,value:e[o++]}},e:function(e){throw e},f:i}}throw new TypeError("Invalid attempt to iterate non-iterable instance.\nIn order to be iterable, non-array objects must have a [Symbol.iterator]() method.")}var a,s=!0,c=!1;return{s:function(){n=n.call(e)},n:function(){var e=n.next();return s=e.done,e},e:function(e){c=!0,a=e},f:function(){try{s||null==n.return||n.return()}finally{if(c)throw a}}}}function r(e,t){(null==t||t>e.length)&&(t=e.length);for(var n=0,r=new Array(t);n<t;n++)r[n]=e[n];return r}var o,i;Object.defineProperty(t,"__esModule",{value:!0}),t.apply=t.addFontCSS=t.getWebPageLang=t.Fallback=t.WebFontLang=void 0,function(e){e.English="en",e.Chinese="zh",e.TraditionChinese="zh-hant",e.SimpleChinese="zh-hans",e.Japanese="ja"}(o||(t.WebFontLang=o={})),function(e){e.Serif="serif",e.SansSerif="sans-serif",e.Monospace="monospace"}(i||(t.Fallback=i={}));t.getWebPageLang=function(){var e=document.documentElement.lang;if("en"===(e=e.trim().toLowerCase())||e.startsWith("en-"))return o.English;if("zh"===e)return o.Chinese;if(!e.startsWith("zh-"))return e.startsWith("ja-")?o.Japanese:e;switch(e.substring(3)){case"tw":case"cht":case"hk":case"hant":return o.TraditionChinese;case"cn":case"chs":case"hans":return o.SimpleChinese;default:return o.Chinese}};t.addFontCSS=function(e){for(var t=new URL("".concat(arguments.length>1&&void 0!==arguments[1]?arguments[1]:"css/","font-").concat(e,".min.css"),window.location.origin).href,n=document.getElementsByTagName("head")[0],r=n.children.length-1;r>=0;r--){var o=n.children[r];if("LINK"===o.tagName){var i=o;if("stylesheet"===i.rel&&t.length===i.href.length&&t.endsWith(i.href))return}}var a=document.createElement("link");a.href=t,a.rel="stylesheet",n.appendChild(a)};t.apply=function(){var e,r=arguments.length>0&&void 0!==arguments[0]?arguments[0]:document.body,o=arguments.length>1?arguments[1]:void 0,a=arguments.length>2?arguments[2]:void 0,s=arguments.length>3?arguments[3]:void 0,c=arguments.length>4&&void 0!==arguments[4]?arguments[4]:i.SansSerif,l=arguments.length>5?arguments[5]:void 0;if((0,t.addFontCSS)(a,o),e=void 0!==l?Array.isArray(l)?'"'.concat(s,'", "').concat(l.join('", "'),'", ').concat(c):'"'.concat(s,'", "').concat(l,'", ').concat(c):'"'.concat(s,'", ').concat(c),"string"==typeof r){var u=document.createElement("style");u.innerHTML="".concat(r," { font-family: ").concat(e," !important; }"),document.getElementsByTagName("head")[0].appendChild(u)}else{var f=[];if(r instanceof HTMLElement)f.push(r);else{var d,p=n(r);try{for(p.s();!(d=p.n()).done;){var h=d.value;f.push(h)}}catch(e){p.e(e)}finally{p.f()}}f.forEach((function(t){t.style.fontFamily=e}))}}},819:(e,t,n)=>{"use strict";t.P=void 0;var r=n(601);t.P=function(){var e=arguments.length>0&&void 0!==arguments[0]?arguments[0]:document.body,t=arguments.length>1?arguments[1]:void 0;(0,r.apply)(e,t,"roboto-mono","ROBOTO MONO",r.Fallback.Monospace)}}},t={};function n(r){var o=t[r];if(void 0!==o)return o.exports;var i=t[r]={id:r,loaded:!1,exports:{}};return e[r].call(i.exports,i,i.exports,n),i.loaded=!0,i.exports}n.d=(e,t)=>{for(var r in t)n.o(t,r)&&!n.o(e,r)&&Object.defineProperty(e,r,{enumerable:!0,get:t[r]})},n.g=function(){if("object"==typeof globalThis)return globalThis;try{return this||new Function("return this")()}catch(e){if("object"==typeof window)return window}}(),n.o=(e,t)=>Object.prototype.hasOwnProperty.call(e,t),n.r=e=>{"undefined"!=typeof Symbol&&Symbol.toStringTag&&Object.defineProperty(e,Symbol.toStringTag,{value:"Module"}),Object.defineProperty(e,"__esModule",{value:!0})},n.nmd=e=>(e.paths=[],e.children||(e.children=[]),e);var r={};return(()=>{"use strict";n.r(r),n.d(r,{monitorInit:()=>ac});var e=n(25);n(252),n(202);function t(e){return t="function"==typeof Symbol&&"symbol"==typeof Symbol.iterator?function(e){return typeof e}:function(e){return e&&"function"==typeof Symbol&&e.constructor===Symbol&&e!==Symbol.prototype?"symbol":typeof e},t(e)}var o=Object.freeze({}),i=Array.isArray;function a(e){return null==e}function s(e){return null!=e}function c(e){return!0===e}function l(e){return"string"==typeof e||"number"==typeof e||"symbol"===t(e)||"boolean"==typeof e}function u(e){return"function"==typeof e}function f(e){return null!==e&&"object"===t(e)}var d=Object.prototype.toString;function p(e){return"[object Object]"===d.call(e)}function h(e){return"[object RegExp]"===d.call(e)}function v(e){var t=parseFloat(String(e));return t>=0&&Math.floor(t)===t&&isFinite(e)}function m(e){return s(e)&&"function"==typeof e.then&&"function"==typeof e.catch}function g(e){return null==e?"":Array.isArray(e)||p(e)&&e.toString===d?JSON.stringify(e,null,2):String(e)}function y(e){var t=parseFloat(e);return isNaN(t)?e:t}function b(e,t){for(var n=Object.create(null),r=e.split(","),o=0;o<r.length;o++)n[r[o]]=!0;return t?function(e){return n[e.toLowerCase()]}:function(e){return n[e]}}var _=b("slot,component",!0),x=b("key,ref,slot,slot-scope,is");function w(e,t){var n=e.length;if(n){if(t===e[n-1])return void(e.length=n-1);var r=e.indexOf(t);if(r>-1)return e.splice(r,1)}}var C=Object.prototype.hasOwnProperty;function T(e,t){return C.call(e,t)}function k(e){var t=Object.create(null);return function(n){return t[n]||(t[n]=e(n))}}var S=/-(\w)/g,E=k((function(e){return e.replace(S,(function(e,t){return t?t.toUpperCase():""}))})),$=k((function(e){return e.charAt(0).toUpperCase()+e.slice(1)})),A=/\B([A-Z])/g,O=k((function(e){return e.replace(A,"-$1").toLowerCase()}));var j=Function.prototype.bind?function(e,t){return e.bind(t)}:function(e,t){function n(n){var r=arguments.length;return r?r>1?e.apply(t,arguments):e.call(t,n):e.call(t)}return n._length=e.length,n};function D(e,t){t=t||0;for(var n=e.length-t,r=new Array(n);n--;)r[n]=e[n+t];return r}function N(e,t){for(var n in t)e[n]=t[n];return e}function L(e){for(var t={},n=0;n<e.length;n++)e[n]&&N(t,e[n]);return t}function P(e,t,n){}var I=function(e,t,n){return!1},M=function(e){return e};function R(e,t){if(e===t)return!0;var n=f(e),r=f(t);if(!n||!r)return!n&&!r&&String(e)===String(t);try{var o=Array.isArray(e),i=Array.isArray(t);if(o&&i)return e.length===t.length&&e.every((function(e,n){return R(e,t[n])}));if(e instanceof Date&&t instanceof Date)return e.getTime()===t.getTime();if(o||i)return!1;var a=Object.keys(e),s=Object.keys(t);return a.length===s.length&&a.every((function(n){return R(e[n],t[n])}))}catch(e){return!1}}function F(e,t){for(var n=0;n<e.length;n++)if(R(e[n],t))return n;return-1}function H(e){var t=!1;return function(){t||(t=!0,e.apply(this,arguments))}}function q(e,t){return e===t?0===e&&1/e!=1/t:e==e||t==t}var B="data-server-rendered",W=["component","directive","filter"],U=["beforeCreate","created","beforeMount","mounted","beforeUpdate","updated","beforeDestroy","destroyed","activated","deactivated","errorCaptured","serverPrefetch","renderTracked","renderTriggered"],z={optionMergeStrategies:Object.create(null),silent:!1,productionTip:!1,devtools:!1,performance:!1,errorHandler:null,warnHandler:null,ignoredElements:[],keyCodes:Object.create(null),isReservedTag:I,isReservedAttr:I,isUnknownElement:I,getTagNamespace:P,parsePlatformTagName:M,mustUseProp:I,async:!0,_lifecycleHooks:U},V=/a-zA-Z\u00B7\u00C0-\u00D6\u00D8-\u00F6\u00F8-\u037D\u037F-\u1FFF\u200C-\u200D\u203F-\u2040\u2070-\u218F\u2C00-\u2FEF\u3001-\uD7FF\uF900-\uFDCF\uFDF0-\uFFFD/;function J(e){var t=(e+"").charCodeAt(0);return 36===t||95===t}function K(e,t,n,r){Object.defineProperty(e,t,{value:n,enumerable:!!r,writable:!0,configurable:!0})}var X=new RegExp("[^".concat(V.source,".$_\\d]"));var Q="__proto__"in{},G="undefined"!=typeof window,Y=G&&window.navigator.userAgent.toLowerCase(),Z=Y&&/msie|trident/.test(Y),ee=Y&&Y.indexOf("msie 9.0")>0,te=Y&&Y.indexOf("edge/")>0;Y&&Y.indexOf("android");var ne=Y&&/iphone|ipad|ipod|ios/.test(Y);Y&&/chrome\/\d+/.test(Y),Y&&/phantomjs/.test(Y);var re,oe=Y&&Y.match(/firefox\/(\d+)/),ie={}.watch,ae=!1;if(G)try{var se={};Object.defineProperty(se,"passive",{get:function(){ae=!0}}),window.addEventListener("test-passive",null,se)}catch(e){}var ce=function(){return void 0===re&&(re=!G&&void 0!==n.g&&(n.g.process&&"server"===n.g.process.env.VUE_ENV)),re},le=G&&window.__VUE_DEVTOOLS_GLOBAL_HOOK__;function ue(e){return"function"==typeof e&&/native code/.test(e.toString())}var fe,de="undefined"!=typeof Symbol&&ue(Symbol)&&"undefined"!=typeof Reflect&&ue(Reflect.ownKeys);fe="undefined"!=typeof Set&&ue(Set)?Set:function(){function e(){this.set=Object.create(null)}return e.prototype.has=function(e){return!0===this.set[e]},e.prototype.add=function(e){this.set[e]=!0},e.prototype.clear=function(){this.set=Object.create(null)},e}();var pe=null;function he(e){void 0===e&&(e=null),e||pe&&pe._scope.off(),pe=e,e&&e._scope.on()}var ve=function(){function e(e,t,n,r,o,i,a,s){this.tag=e,this.data=t,this.children=n,this.text=r,this.elm=o,this.ns=void 0,this.context=i,this.fnContext=void 0,this.fnOptions=void 0,this.fnScopeId=void 0,this.key=t&&t.key,this.componentOptions=a,this.componentInstance=void 0,this.parent=void 0,this.raw=!1,this.isStatic=!1,this.isRootInsert=!0,this.isComment=!1,this.isCloned=!1,this.isOnce=!1,this.asyncFactory=s,this.asyncMeta=void 0,this.isAsyncPlaceholder=!1}return Object.defineProperty(e.prototype,"child",{get:function(){return this.componentInstance},enumerable:!1,configurable:!0}),e}(),me=function(e){void 0===e&&(e="");var t=new ve;return t.text=e,t.isComment=!0,t};function ge(e){return new ve(void 0,void 0,void 0,String(e))}function ye(e){var t=new ve(e.tag,e.data,e.children&&e.children.slice(),e.text,e.elm,e.context,e.componentOptions,e.asyncFactory);return t.ns=e.ns,t.isStatic=e.isStatic,t.key=e.key,t.isComment=e.isComment,t.fnContext=e.fnContext,t.fnOptions=e.fnOptions,t.fnScopeId=e.fnScopeId,t.asyncMeta=e.asyncMeta,t.isCloned=!0,t}var be=0,_e=[],xe=function(){for(var e=0;e<_e.length;e++){var t=_e[e];t.subs=t.subs.filter((function(e){return e})),t._pending=!1}_e.length=0},we=function(){function e(){this._pending=!1,this.id=be++,this.subs=[]}return e.prototype.addSub=function(e){this.subs.push(e)},e.prototype.removeSub=function(e){this.subs[this.subs.indexOf(e)]=null,this._pending||(this._pending=!0,_e.push(this))},e.prototype.depend=function(t){e.target&&e.target.addDep(this)},e.prototype.notify=function(e){var t=this.subs.filter((function(e){return e}));for(var n=0,r=t.length;n<r;n++){0,t[n].update()}},e}();we.target=null;var Ce=[];function Te(e){Ce.push(e),we.target=e}function ke(){Ce.pop(),we.target=Ce[Ce.length-1]}var Se=Array.prototype,Ee=Object.create(Se);["push","pop","shift","unshift","splice","sort","reverse"].forEach((function(e){var t=Se[e];K(Ee,e,(function(){for(var n=[],r=0;r<arguments.length;r++)n[r]=arguments[r];var o,i=t.apply(this,n),a=this.__ob__;switch(e){case"push":case"unshift":o=n;break;case"splice":o=n.slice(2)}return o&&a.observeArray(o),a.dep.notify(),i}))}));var $e=Object.getOwnPropertyNames(Ee),Ae={},Oe=!0;function je(e){Oe=e}var De={notify:P,depend:P,addSub:P,removeSub:P},Ne=function(){function e(e,t,n){if(void 0===t&&(t=!1),void 0===n&&(n=!1),this.value=e,this.shallow=t,this.mock=n,this.dep=n?De:new we,this.vmCount=0,K(e,"__ob__",this),i(e)){if(!n)if(Q)e.__proto__=Ee;else for(var r=0,o=$e.length;r<o;r++){K(e,s=$e[r],Ee[s])}t||this.observeArray(e)}else{var a=Object.keys(e);for(r=0;r<a.length;r++){var s;Pe(e,s=a[r],Ae,void 0,t,n)}}}return e.prototype.observeArray=function(e){for(var t=0,n=e.length;t<n;t++)Le(e[t],!1,this.mock)},e}();function Le(e,t,n){return e&&T(e,"__ob__")&&e.__ob__ instanceof Ne?e.__ob__:!Oe||!n&&ce()||!i(e)&&!p(e)||!Object.isExtensible(e)||e.__v_skip||Be(e)||e instanceof ve?void 0:new Ne(e,t,n)}function Pe(e,t,n,r,o,a){var s=new we,c=Object.getOwnPropertyDescriptor(e,t);if(!c||!1!==c.configurable){var l=c&&c.get,u=c&&c.set;l&&!u||n!==Ae&&2!==arguments.length||(n=e[t]);var f=!o&&Le(n,!1,a);return Object.defineProperty(e,t,{enumerable:!0,configurable:!0,get:function(){var t=l?l.call(e):n;return we.target&&(s.depend(),f&&(f.dep.depend(),i(t)&&Re(t))),Be(t)&&!o?t.value:t},set:function(t){var r=l?l.call(e):n;if(q(r,t)){if(u)u.call(e,t);else{if(l)return;if(!o&&Be(r)&&!Be(t))return void(r.value=t);n=t}f=!o&&Le(t,!1,a),s.notify()}}}),s}}function Ie(e,t,n){if(!qe(e)){var r=e.__ob__;return i(e)&&v(t)?(e.length=Math.max(e.length,t),e.splice(t,1,n),r&&!r.shallow&&r.mock&&Le(n,!1,!0),n):t in e&&!(t in Object.prototype)?(e[t]=n,n):e._isVue||r&&r.vmCount?n:r?(Pe(r.value,t,n,void 0,r.shallow,r.mock),r.dep.notify(),n):(e[t]=n,n)}}function Me(e,t){if(i(e)&&v(t))e.splice(t,1);else{var n=e.__ob__;e._isVue||n&&n.vmCount||qe(e)||T(e,t)&&(delete e[t],n&&n.dep.notify())}}function Re(e){for(var t=void 0,n=0,r=e.length;n<r;n++)(t=e[n])&&t.__ob__&&t.__ob__.dep.depend(),i(t)&&Re(t)}function Fe(e){return He(e,!0),K(e,"__v_isShallow",!0),e}function He(e,t){if(!qe(e)){Le(e,t,ce());0}}function qe(e){return!(!e||!e.__v_isReadonly)}function Be(e){return!(!e||!0!==e.__v_isRef)}function We(e,t,n){Object.defineProperty(e,n,{enumerable:!0,configurable:!0,get:function(){var e=t[n];if(Be(e))return e.value;var r=e&&e.__ob__;return r&&r.dep.depend(),e},set:function(e){var r=t[n];Be(r)&&!Be(e)?r.value=e:t[n]=e}})}var Ue=k((function(e){var t="&"===e.charAt(0),n="~"===(e=t?e.slice(1):e).charAt(0),r="!"===(e=n?e.slice(1):e).charAt(0);return{name:e=r?e.slice(1):e,once:n,capture:r,passive:t}}));function ze(e,t){function n(){var e=n.fns;if(!i(e))return on(e,null,arguments,t,"v-on handler");for(var r=e.slice(),o=0;o<r.length;o++)on(r[o],null,arguments,t,"v-on handler")}return n.fns=e,n}function Ve(e,t,n,r,o,i){var s,l,u,f;for(s in e)l=e[s],u=t[s],f=Ue(s),a(l)||(a(u)?(a(l.fns)&&(l=e[s]=ze(l,i)),c(f.once)&&(l=e[s]=o(f.name,l,f.capture)),n(f.name,l,f.capture,f.passive,f.params)):l!==u&&(u.fns=l,e[s]=u));for(s in t)a(e[s])&&r((f=Ue(s)).name,t[s],f.capture)}function Je(e,t,n){var r;e instanceof ve&&(e=e.data.hook||(e.data.hook={}));var o=e[t];function i(){n.apply(this,arguments),w(r.fns,i)}a(o)?r=ze([i]):s(o.fns)&&c(o.merged)?(r=o).fns.push(i):r=ze([o,i]),r.merged=!0,e[t]=r}function Ke(e,t,n,r,o){if(s(t)){if(T(t,n))return e[n]=t[n],o||delete t[n],!0;if(T(t,r))return e[n]=t[r],o||delete t[r],!0}return!1}function Xe(e){return l(e)?[ge(e)]:i(e)?Ge(e):void 0}function Qe(e){return s(e)&&s(e.text)&&!1===e.isComment}function Ge(e,t){var n,r,o,u,f=[];for(n=0;n<e.length;n++)a(r=e[n])||"boolean"==typeof r||(u=f[o=f.length-1],i(r)?r.length>0&&(Qe((r=Ge(r,"".concat(t||"","_").concat(n)))[0])&&Qe(u)&&(f[o]=ge(u.text+r[0].text),r.shift()),f.push.apply(f,r)):l(r)?Qe(u)?f[o]=ge(u.text+r):""!==r&&f.push(ge(r)):Qe(r)&&Qe(u)?f[o]=ge(u.text+r.text):(c(e._isVList)&&s(r.tag)&&a(r.key)&&s(t)&&(r.key="__vlist".concat(t,"_").concat(n,"__")),f.push(r)));return f}var Ye=1,Ze=2;function et(e,t,n,r,o,a){return(i(n)||l(n))&&(o=r,r=n,n=void 0),c(a)&&(o=Ze),function(e,t,n,r,o){if(s(n)&&s(n.__ob__))return me();s(n)&&s(n.is)&&(t=n.is);if(!t)return me();0;i(r)&&u(r[0])&&((n=n||{}).scopedSlots={default:r[0]},r.length=0);o===Ze?r=Xe(r):o===Ye&&(r=function(e){for(var t=0;t<e.length;t++)if(i(e[t]))return Array.prototype.concat.apply([],e);return e}(r));var a,c;if("string"==typeof t){var l=void 0;c=e.$vnode&&e.$vnode.ns||z.getTagNamespace(t),a=z.isReservedTag(t)?new ve(z.parsePlatformTagName(t),n,r,void 0,void 0,e):n&&n.pre||!s(l=Gn(e.$options,"components",t))?new ve(t,n,r,void 0,void 0,e):qn(l,n,e,r,t)}else a=qn(t,n,e,r);return i(a)?a:s(a)?(s(c)&&tt(a,c),s(n)&&function(e){f(e.style)&&_n(e.style);f(e.class)&&_n(e.class)}(n),a):me()}(e,t,n,r,o)}function tt(e,t,n){if(e.ns=t,"foreignObject"===e.tag&&(t=void 0,n=!0),s(e.children))for(var r=0,o=e.children.length;r<o;r++){var i=e.children[r];s(i.tag)&&(a(i.ns)||c(n)&&"svg"!==i.tag)&&tt(i,t,n)}}function nt(e,t){var n,r,o,a,c=null;if(i(e)||"string"==typeof e)for(c=new Array(e.length),n=0,r=e.length;n<r;n++)c[n]=t(e[n],n);else if("number"==typeof e)for(c=new Array(e),n=0;n<e;n++)c[n]=t(n+1,n);else if(f(e))if(de&&e[Symbol.iterator]){c=[];for(var l=e[Symbol.iterator](),u=l.next();!u.done;)c.push(t(u.value,c.length)),u=l.next()}else for(o=Object.keys(e),c=new Array(o.length),n=0,r=o.length;n<r;n++)a=o[n],c[n]=t(e[a],a,n);return s(c)||(c=[]),c._isVList=!0,c}function rt(e,t,n,r){var o,i=this.$scopedSlots[e];i?(n=n||{},r&&(n=N(N({},r),n)),o=i(n)||(u(t)?t():t)):o=this.$slots[e]||(u(t)?t():t);var a=n&&n.slot;return a?this.$createElement("template",{slot:a},o):o}function ot(e){return Gn(this.$options,"filters",e,!0)||M}function it(e,t){return i(e)?-1===e.indexOf(t):e!==t}function at(e,t,n,r,o){var i=z.keyCodes[t]||n;return o&&r&&!z.keyCodes[t]?it(o,r):i?it(i,e):r?O(r)!==t:void 0===e}function st(e,t,n,r,o){if(n)if(f(n)){i(n)&&(n=L(n));var a=void 0,s=function(i){if("class"===i||"style"===i||x(i))a=e;else{var s=e.attrs&&e.attrs.type;a=r||z.mustUseProp(t,s,i)?e.domProps||(e.domProps={}):e.attrs||(e.attrs={})}var c=E(i),l=O(i);c in a||l in a||(a[i]=n[i],o&&((e.on||(e.on={}))["update:".concat(i)]=function(e){n[i]=e}))};for(var c in n)s(c)}else;return e}function ct(e,t){var n=this._staticTrees||(this._staticTrees=[]),r=n[e];return r&&!t||ut(r=n[e]=this.$options.staticRenderFns[e].call(this._renderProxy,this._c,this),"__static__".concat(e),!1),r}function lt(e,t,n){return ut(e,"__once__".concat(t).concat(n?"_".concat(n):""),!0),e}function ut(e,t,n){if(i(e))for(var r=0;r<e.length;r++)e[r]&&"string"!=typeof e[r]&&ft(e[r],"".concat(t,"_").concat(r),n);else ft(e,t,n)}function ft(e,t,n){e.isStatic=!0,e.key=t,e.isOnce=n}function dt(e,t){if(t)if(p(t)){var n=e.on=e.on?N({},e.on):{};for(var r in t){var o=n[r],i=t[r];n[r]=o?[].concat(o,i):i}}else;return e}function pt(e,t,n,r){t=t||{$stable:!n};for(var o=0;o<e.length;o++){var a=e[o];i(a)?pt(a,t,n):a&&(a.proxy&&(a.fn.proxy=!0),t[a.key]=a.fn)}return r&&(t.$key=r),t}function ht(e,t){for(var n=0;n<t.length;n+=2){var r=t[n];"string"==typeof r&&r&&(e[t[n]]=t[n+1])}return e}function vt(e,t){return"string"==typeof e?t+e:e}function mt(e){e._o=lt,e._n=y,e._s=g,e._l=nt,e._t=rt,e._q=R,e._i=F,e._m=ct,e._f=ot,e._k=at,e._b=st,e._v=ge,e._e=me,e._u=pt,e._g=dt,e._d=ht,e._p=vt}function gt(e,t){if(!e||!e.length)return{};for(var n={},r=0,o=e.length;r<o;r++){var i=e[r],a=i.data;if(a&&a.attrs&&a.attrs.slot&&delete a.attrs.slot,i.context!==t&&i.fnContext!==t||!a||null==a.slot)(n.default||(n.default=[])).push(i);else{var s=a.slot,c=n[s]||(n[s]=[]);"template"===i.tag?c.push.apply(c,i.children||[]):c.push(i)}}for(var l in n)n[l].every(yt)&&delete n[l];return n}function yt(e){return e.isComment&&!e.asyncFactory||" "===e.text}function bt(e){return e.isComment&&e.asyncFactory}function _t(e,t,n,r){var i,a=Object.keys(n).length>0,s=t?!!t.$stable:!a,c=t&&t.$key;if(t){if(t._normalized)return t._normalized;if(s&&r&&r!==o&&c===r.$key&&!a&&!r.$hasNormal)return r;for(var l in i={},t)t[l]&&"$"!==l[0]&&(i[l]=xt(e,n,l,t[l]))}else i={};for(var u in n)u in i||(i[u]=wt(n,u));return t&&Object.isExtensible(t)&&(t._normalized=i),K(i,"$stable",s),K(i,"$key",c),K(i,"$hasNormal",a),i}function xt(e,n,r,o){var a=function(){var n=pe;he(e);var r=arguments.length?o.apply(null,arguments):o({}),a=(r=r&&"object"===t(r)&&!i(r)?[r]:Xe(r))&&r[0];return he(n),r&&(!a||1===r.length&&a.isComment&&!bt(a))?void 0:r};return o.proxy&&Object.defineProperty(n,r,{get:a,enumerable:!0,configurable:!0}),a}function wt(e,t){return function(){return e[t]}}function Ct(e){return{get attrs(){if(!e._attrsProxy){var t=e._attrsProxy={};K(t,"_v_attr_proxy",!0),Tt(t,e.$attrs,o,e,"$attrs")}return e._attrsProxy},get listeners(){e._listenersProxy||Tt(e._listenersProxy={},e.$listeners,o,e,"$listeners");return e._listenersProxy},get slots(){return function(e){e._slotsProxy||St(e._slotsProxy={},e.$scopedSlots);return e._slotsProxy}(e)},emit:j(e.$emit,e),expose:function(t){t&&Object.keys(t).forEach((function(n){return We(e,t,n)}))}}}function Tt(e,t,n,r,o){var i=!1;for(var a in t)a in e?t[a]!==n[a]&&(i=!0):(i=!0,kt(e,a,r,o));for(var a in e)a in t||(i=!0,delete e[a]);return i}function kt(e,t,n,r){Object.defineProperty(e,t,{enumerable:!0,configurable:!0,get:function(){return n[r][t]}})}function St(e,t){for(var n in t)e[n]=t[n];for(var n in e)n in t||delete e[n]}var Et,$t,At=null;function Ot(e,t){return(e.__esModule||de&&"Module"===e[Symbol.toStringTag])&&(e=e.default),f(e)?t.extend(e):e}function jt(e){if(i(e))for(var t=0;t<e.length;t++){var n=e[t];if(s(n)&&(s(n.componentOptions)||bt(n)))return n}}function Dt(e,t){Et.$on(e,t)}function Nt(e,t){Et.$off(e,t)}function Lt(e,t){var n=Et;return function r(){null!==t.apply(null,arguments)&&n.$off(e,r)}}function Pt(e,t,n){Et=e,Ve(t,n||{},Dt,Nt,Lt,e),Et=void 0}var It=function(){function e(e){void 0===e&&(e=!1),this.detached=e,this.active=!0,this.effects=[],this.cleanups=[],this.parent=$t,!e&&$t&&(this.index=($t.scopes||($t.scopes=[])).push(this)-1)}return e.prototype.run=function(e){if(this.active){var t=$t;try{return $t=this,e()}finally{$t=t}}else 0},e.prototype.on=function(){$t=this},e.prototype.off=function(){$t=this.parent},e.prototype.stop=function(e){if(this.active){var t=void 0,n=void 0;for(t=0,n=this.effects.length;t<n;t++)this.effects[t].teardown();for(t=0,n=this.cleanups.length;t<n;t++)this.cleanups[t]();if(this.scopes)for(t=0,n=this.scopes.length;t<n;t++)this.scopes[t].stop(!0);if(!this.detached&&this.parent&&!e){var r=this.parent.scopes.pop();r&&r!==this&&(this.parent.scopes[this.index]=r,r.index=this.index)}this.parent=void 0,this.active=!1}},e}();var Mt=null;function Rt(e){var t=Mt;return Mt=e,function(){Mt=t}}function Ft(e){for(;e&&(e=e.$parent);)if(e._inactive)return!0;return!1}function Ht(e,t){if(t){if(e._directInactive=!1,Ft(e))return}else if(e._directInactive)return;if(e._inactive||null===e._inactive){e._inactive=!1;for(var n=0;n<e.$children.length;n++)Ht(e.$children[n]);Bt(e,"activated")}}function qt(e,t){if(!(t&&(e._directInactive=!0,Ft(e))||e._inactive)){e._inactive=!0;for(var n=0;n<e.$children.length;n++)qt(e.$children[n]);Bt(e,"deactivated")}}function Bt(e,t,n,r){void 0===r&&(r=!0),Te();var o=pe,i=$t;r&&he(e);var a=e.$options[t],s="".concat(t," hook");if(a)for(var c=0,l=a.length;c<l;c++)on(a[c],e,n||null,e,s);e._hasHookEvent&&e.$emit("hook:"+t),r&&(he(o),i&&i.on()),ke()}var Wt=[],Ut=[],zt={},Vt=!1,Jt=!1,Kt=0;var Xt=0,Qt=Date.now;if(G&&!Z){var Gt=window.performance;Gt&&"function"==typeof Gt.now&&Qt()>document.createEvent("Event").timeStamp&&(Qt=function(){return Gt.now()})}var Yt=function(e,t){if(e.post){if(!t.post)return 1}else if(t.post)return-1;return e.id-t.id};function Zt(){var e,t;for(Xt=Qt(),Jt=!0,Wt.sort(Yt),Kt=0;Kt<Wt.length;Kt++)(e=Wt[Kt]).before&&e.before(),t=e.id,zt[t]=null,e.run();var n=Ut.slice(),r=Wt.slice();Kt=Wt.length=Ut.length=0,zt={},Vt=Jt=!1,function(e){for(var t=0;t<e.length;t++)e[t]._inactive=!0,Ht(e[t],!0)}(n),function(e){var t=e.length;for(;t--;){var n=e[t],r=n.vm;r&&r._watcher===n&&r._isMounted&&!r._isDestroyed&&Bt(r,"updated")}}(r),xe(),le&&z.devtools&&le.emit("flush")}function en(e){var t=e.id;if(null==zt[t]&&(e!==we.target||!e.noRecurse)){if(zt[t]=!0,Jt){for(var n=Wt.length-1;n>Kt&&Wt[n].id>e.id;)n--;Wt.splice(n+1,0,e)}else Wt.push(e);Vt||(Vt=!0,gn(Zt))}}var tn="watcher";"".concat(tn," callback"),"".concat(tn," getter"),"".concat(tn," cleanup");function nn(e){var t=e._provided,n=e.$parent&&e.$parent._provided;return n===t?e._provided=Object.create(n):t}function rn(e,t,n){Te();try{if(t)for(var r=t;r=r.$parent;){var o=r.$options.errorCaptured;if(o)for(var i=0;i<o.length;i++)try{if(!1===o[i].call(r,e,t,n))return}catch(e){an(e,r,"errorCaptured hook")}}an(e,t,n)}finally{ke()}}function on(e,t,n,r,o){var i;try{(i=n?e.apply(t,n):e.call(t))&&!i._isVue&&m(i)&&!i._handled&&(i.catch((function(e){return rn(e,r,o+" (Promise/async)")})),i._handled=!0)}catch(e){rn(e,r,o)}return i}function an(e,t,n){if(z.errorHandler)try{return z.errorHandler.call(null,e,t,n)}catch(t){t!==e&&sn(t,null,"config.errorHandler")}sn(e,t,n)}function sn(e,t,n){if(!G||"undefined"==typeof console)throw e;console.error(e)}var cn,ln=!1,un=[],fn=!1;function dn(){fn=!1;var e=un.slice(0);un.length=0;for(var t=0;t<e.length;t++)e[t]()}if("undefined"!=typeof Promise&&ue(Promise)){var pn=Promise.resolve();cn=function(){pn.then(dn),ne&&setTimeout(P)},ln=!0}else if(Z||"undefined"==typeof MutationObserver||!ue(MutationObserver)&&"[object MutationObserverConstructor]"!==MutationObserver.toString())cn="undefined"!=typeof setImmediate&&ue(setImmediate)?function(){setImmediate(dn)}:function(){setTimeout(dn,0)};else{var hn=1,vn=new MutationObserver(dn),mn=document.createTextNode(String(hn));vn.observe(mn,{characterData:!0}),cn=function(){hn=(hn+1)%2,mn.data=String(hn)},ln=!0}function gn(e,t){var n;if(un.push((function(){if(e)try{e.call(t)}catch(e){rn(e,t,"nextTick")}else n&&n(t)})),fn||(fn=!0,cn()),!e&&"undefined"!=typeof Promise)return new Promise((function(e){n=e}))}function yn(e){return function(t,n){if(void 0===n&&(n=pe),n)return function(e,t,n){var r=e.$options;r[t]=Jn(r[t],n)}(n,e,t)}}yn("beforeMount"),yn("mounted"),yn("beforeUpdate"),yn("updated"),yn("beforeDestroy"),yn("destroyed"),yn("activated"),yn("deactivated"),yn("serverPrefetch"),yn("renderTracked"),yn("renderTriggered"),yn("errorCaptured");var bn=new fe;function _n(e){return xn(e,bn),bn.clear(),e}function xn(e,t){var n,r,o=i(e);if(!(!o&&!f(e)||e.__v_skip||Object.isFrozen(e)||e instanceof ve)){if(e.__ob__){var a=e.__ob__.dep.id;if(t.has(a))return;t.add(a)}if(o)for(n=e.length;n--;)xn(e[n],t);else if(Be(e))xn(e.value,t);else for(n=(r=Object.keys(e)).length;n--;)xn(e[r[n]],t)}}var wn=0,Cn=function(){function e(e,t,n,r,o){var i,a;i=this,void 0===(a=$t&&!$t._vm?$t:e?e._scope:void 0)&&(a=$t),a&&a.active&&a.effects.push(i),(this.vm=e)&&o&&(e._watcher=this),r?(this.deep=!!r.deep,this.user=!!r.user,this.lazy=!!r.lazy,this.sync=!!r.sync,this.before=r.before):this.deep=this.user=this.lazy=this.sync=!1,this.cb=n,this.id=++wn,this.active=!0,this.post=!1,this.dirty=this.lazy,this.deps=[],this.newDeps=[],this.depIds=new fe,this.newDepIds=new fe,this.expression="",u(t)?this.getter=t:(this.getter=function(e){if(!X.test(e)){var t=e.split(".");return function(e){for(var n=0;n<t.length;n++){if(!e)return;e=e[t[n]]}return e}}}(t),this.getter||(this.getter=P)),this.value=this.lazy?void 0:this.get()}return e.prototype.get=function(){var e;Te(this);var t=this.vm;try{e=this.getter.call(t,t)}catch(e){if(!this.user)throw e;rn(e,t,'getter for watcher "'.concat(this.expression,'"'))}finally{this.deep&&_n(e),ke(),this.cleanupDeps()}return e},e.prototype.addDep=function(e){var t=e.id;this.newDepIds.has(t)||(this.newDepIds.add(t),this.newDeps.push(e),this.depIds.has(t)||e.addSub(this))},e.prototype.cleanupDeps=function(){for(var e=this.deps.length;e--;){var t=this.deps[e];this.newDepIds.has(t.id)||t.removeSub(this)}var n=this.depIds;this.depIds=this.newDepIds,this.newDepIds=n,this.newDepIds.clear(),n=this.deps,this.deps=this.newDeps,this.newDeps=n,this.newDeps.length=0},e.prototype.update=function(){this.lazy?this.dirty=!0:this.sync?this.run():en(this)},e.prototype.run=function(){if(this.active){var e=this.get();if(e!==this.value||f(e)||this.deep){var t=this.value;if(this.value=e,this.user){var n='callback for watcher "'.concat(this.expression,'"');on(this.cb,this.vm,[e,t],this.vm,n)}else this.cb.call(this.vm,e,t)}}},e.prototype.evaluate=function(){this.value=this.get(),this.dirty=!1},e.prototype.depend=function(){for(var e=this.deps.length;e--;)this.deps[e].depend()},e.prototype.teardown=function(){if(this.vm&&!this.vm._isBeingDestroyed&&w(this.vm._scope.effects,this),this.active){for(var e=this.deps.length;e--;)this.deps[e].removeSub(this);this.active=!1,this.onStop&&this.onStop()}},e}(),Tn={enumerable:!0,configurable:!0,get:P,set:P};function kn(e,t,n){Tn.get=function(){return this[t][n]},Tn.set=function(e){this[t][n]=e},Object.defineProperty(e,n,Tn)}function Sn(e){var t=e.$options;if(t.props&&function(e,t){var n=e.$options.propsData||{},r=e._props=Fe({}),o=e.$options._propKeys=[],i=!e.$parent;i||je(!1);var a=function(i){o.push(i);var a=Yn(i,t,n,e);Pe(r,i,a),i in e||kn(e,"_props",i)};for(var s in t)a(s);je(!0)}(e,t.props),function(e){var t=e.$options,n=t.setup;if(n){var r=e._setupContext=Ct(e);he(e),Te();var o=on(n,null,[e._props||Fe({}),r],e,"setup");if(ke(),he(),u(o))t.render=o;else if(f(o))if(e._setupState=o,o.__sfc){var i=e._setupProxy={};for(var a in o)"__sfc"!==a&&We(i,o,a)}else for(var a in o)J(a)||We(e,o,a)}}(e),t.methods&&function(e,t){e.$options.props;for(var n in t)e[n]="function"!=typeof t[n]?P:j(t[n],e)}(e,t.methods),t.data)!function(e){var t=e.$options.data;t=e._data=u(t)?function(e,t){Te();try{return e.call(t,t)}catch(e){return rn(e,t,"data()"),{}}finally{ke()}}(t,e):t||{},p(t)||(t={});var n=Object.keys(t),r=e.$options.props,o=(e.$options.methods,n.length);for(;o--;){var i=n[o];0,r&&T(r,i)||J(i)||kn(e,"_data",i)}var a=Le(t);a&&a.vmCount++}(e);else{var n=Le(e._data={});n&&n.vmCount++}t.computed&&function(e,t){var n=e._computedWatchers=Object.create(null),r=ce();for(var o in t){var i=t[o],a=u(i)?i:i.get;0,r||(n[o]=new Cn(e,a||P,P,En)),o in e||$n(e,o,i)}}(e,t.computed),t.watch&&t.watch!==ie&&function(e,t){for(var n in t){var r=t[n];if(i(r))for(var o=0;o<r.length;o++)jn(e,n,r[o]);else jn(e,n,r)}}(e,t.watch)}var En={lazy:!0};function $n(e,t,n){var r=!ce();u(n)?(Tn.get=r?An(t):On(n),Tn.set=P):(Tn.get=n.get?r&&!1!==n.cache?An(t):On(n.get):P,Tn.set=n.set||P),Object.defineProperty(e,t,Tn)}function An(e){return function(){var t=this._computedWatchers&&this._computedWatchers[e];if(t)return t.dirty&&t.evaluate(),we.target&&t.depend(),t.value}}function On(e){return function(){return e.call(this,this)}}function jn(e,t,n,r){return p(n)&&(r=n,n=n.handler),"string"==typeof n&&(n=e[n]),e.$watch(t,n,r)}function Dn(e,t){if(e){for(var n=Object.create(null),r=de?Reflect.ownKeys(e):Object.keys(e),o=0;o<r.length;o++){var i=r[o];if("__ob__"!==i){var a=e[i].from;if(a in t._provided)n[i]=t._provided[a];else if("default"in e[i]){var s=e[i].default;n[i]=u(s)?s.call(t):s}else 0}}return n}}var Nn=0;function Ln(e){var t=e.options;if(e.super){var n=Ln(e.super);if(n!==e.superOptions){e.superOptions=n;var r=function(e){var t,n=e.options,r=e.sealedOptions;for(var o in n)n[o]!==r[o]&&(t||(t={}),t[o]=n[o]);return t}(e);r&&N(e.extendOptions,r),(t=e.options=Qn(n,e.extendOptions)).name&&(t.components[t.name]=e)}}return t}function Pn(e,t,n,r,a){var s,l=this,u=a.options;T(r,"_uid")?(s=Object.create(r))._original=r:(s=r,r=r._original);var f=c(u._compiled),d=!f;this.data=e,this.props=t,this.children=n,this.parent=r,this.listeners=e.on||o,this.injections=Dn(u.inject,r),this.slots=function(){return l.$slots||_t(r,e.scopedSlots,l.$slots=gt(n,r)),l.$slots},Object.defineProperty(this,"scopedSlots",{enumerable:!0,get:function(){return _t(r,e.scopedSlots,this.slots())}}),f&&(this.$options=u,this.$slots=this.slots(),this.$scopedSlots=_t(r,e.scopedSlots,this.$slots)),u._scopeId?this._c=function(e,t,n,o){var a=et(s,e,t,n,o,d);return a&&!i(a)&&(a.fnScopeId=u._scopeId,a.fnContext=r),a}:this._c=function(e,t,n,r){return et(s,e,t,n,r,d)}}function In(e,t,n,r,o){var i=ye(e);return i.fnContext=n,i.fnOptions=r,t.slot&&((i.data||(i.data={})).slot=t.slot),i}function Mn(e,t){for(var n in t)e[E(n)]=t[n]}function Rn(e){return e.name||e.__name||e._componentTag}mt(Pn.prototype);var Fn={init:function(e,t){if(e.componentInstance&&!e.componentInstance._isDestroyed&&e.data.keepAlive){var n=e;Fn.prepatch(n,n)}else{(e.componentInstance=function(e,t){var n={_isComponent:!0,_parentVnode:e,parent:t},r=e.data.inlineTemplate;s(r)&&(n.render=r.render,n.staticRenderFns=r.staticRenderFns);return new e.componentOptions.Ctor(n)}(e,Mt)).$mount(t?e.elm:void 0,t)}},prepatch:function(e,t){var n=t.componentOptions;!function(e,t,n,r,i){var a=r.data.scopedSlots,s=e.$scopedSlots,c=!!(a&&!a.$stable||s!==o&&!s.$stable||a&&e.$scopedSlots.$key!==a.$key||!a&&e.$scopedSlots.$key),l=!!(i||e.$options._renderChildren||c),u=e.$vnode;e.$options._parentVnode=r,e.$vnode=r,e._vnode&&(e._vnode.parent=r),e.$options._renderChildren=i;var f=r.data.attrs||o;e._attrsProxy&&Tt(e._attrsProxy,f,u.data&&u.data.attrs||o,e,"$attrs")&&(l=!0),e.$attrs=f,n=n||o;var d=e.$options._parentListeners;if(e._listenersProxy&&Tt(e._listenersProxy,n,d||o,e,"$listeners"),e.$listeners=e.$options._parentListeners=n,Pt(e,n,d),t&&e.$options.props){je(!1);for(var p=e._props,h=e.$options._propKeys||[],v=0;v<h.length;v++){var m=h[v],g=e.$options.props;p[m]=Yn(m,g,t,e)}je(!0),e.$options.propsData=t}l&&(e.$slots=gt(i,r.context),e.$forceUpdate())}(t.componentInstance=e.componentInstance,n.propsData,n.listeners,t,n.children)},insert:function(e){var t,n=e.context,r=e.componentInstance;r._isMounted||(r._isMounted=!0,Bt(r,"mounted")),e.data.keepAlive&&(n._isMounted?((t=r)._inactive=!1,Ut.push(t)):Ht(r,!0))},destroy:function(e){var t=e.componentInstance;t._isDestroyed||(e.data.keepAlive?qt(t,!0):t.$destroy())}},Hn=Object.keys(Fn);function qn(e,t,n,r,l){if(!a(e)){var u=n.$options._base;if(f(e)&&(e=u.extend(e)),"function"==typeof e){var d;if(a(e.cid)&&(e=function(e,t){if(c(e.error)&&s(e.errorComp))return e.errorComp;if(s(e.resolved))return e.resolved;var n=At;if(n&&s(e.owners)&&-1===e.owners.indexOf(n)&&e.owners.push(n),c(e.loading)&&s(e.loadingComp))return e.loadingComp;if(n&&!s(e.owners)){var r=e.owners=[n],o=!0,i=null,l=null;n.$on("hook:destroyed",(function(){return w(r,n)}));var u=function(e){for(var t=0,n=r.length;t<n;t++)r[t].$forceUpdate();e&&(r.length=0,null!==i&&(clearTimeout(i),i=null),null!==l&&(clearTimeout(l),l=null))},d=H((function(n){e.resolved=Ot(n,t),o?r.length=0:u(!0)})),p=H((function(t){s(e.errorComp)&&(e.error=!0,u(!0))})),h=e(d,p);return f(h)&&(m(h)?a(e.resolved)&&h.then(d,p):m(h.component)&&(h.component.then(d,p),s(h.error)&&(e.errorComp=Ot(h.error,t)),s(h.loading)&&(e.loadingComp=Ot(h.loading,t),0===h.delay?e.loading=!0:i=setTimeout((function(){i=null,a(e.resolved)&&a(e.error)&&(e.loading=!0,u(!1))}),h.delay||200)),s(h.timeout)&&(l=setTimeout((function(){l=null,a(e.resolved)&&p(null)}),h.timeout)))),o=!1,e.loading?e.loadingComp:e.resolved}}(d=e,u),void 0===e))return function(e,t,n,r,o){var i=me();return i.asyncFactory=e,i.asyncMeta={data:t,context:n,children:r,tag:o},i}(d,t,n,r,l);t=t||{},Ln(e),s(t.model)&&function(e,t){var n=e.model&&e.model.prop||"value",r=e.model&&e.model.event||"input";(t.attrs||(t.attrs={}))[n]=t.model.value;var o=t.on||(t.on={}),a=o[r],c=t.model.callback;s(a)?(i(a)?-1===a.indexOf(c):a!==c)&&(o[r]=[c].concat(a)):o[r]=c}(e.options,t);var p=function(e,t,n){var r=t.options.props;if(!a(r)){var o={},i=e.attrs,c=e.props;if(s(i)||s(c))for(var l in r){var u=O(l);Ke(o,c,l,u,!0)||Ke(o,i,l,u,!1)}return o}}(t,e);if(c(e.options.functional))return function(e,t,n,r,a){var c=e.options,l={},u=c.props;if(s(u))for(var f in u)l[f]=Yn(f,u,t||o);else s(n.attrs)&&Mn(l,n.attrs),s(n.props)&&Mn(l,n.props);var d=new Pn(n,l,a,r,e),p=c.render.call(null,d._c,d);if(p instanceof ve)return In(p,n,d.parent,c);if(i(p)){for(var h=Xe(p)||[],v=new Array(h.length),m=0;m<h.length;m++)v[m]=In(h[m],n,d.parent,c);return v}}(e,p,t,n,r);var h=t.on;if(t.on=t.nativeOn,c(e.options.abstract)){var v=t.slot;t={},v&&(t.slot=v)}!function(e){for(var t=e.hook||(e.hook={}),n=0;n<Hn.length;n++){var r=Hn[n],o=t[r],i=Fn[r];o===i||o&&o._merged||(t[r]=o?Bn(i,o):i)}}(t);var g=Rn(e.options)||l;return new ve("vue-component-".concat(e.cid).concat(g?"-".concat(g):""),t,void 0,void 0,void 0,n,{Ctor:e,propsData:p,listeners:h,tag:l,children:r},d)}}}function Bn(e,t){var n=function(n,r){e(n,r),t(n,r)};return n._merged=!0,n}var Wn=P,Un=z.optionMergeStrategies;function zn(e,t,n){if(void 0===n&&(n=!0),!t)return e;for(var r,o,i,a=de?Reflect.ownKeys(t):Object.keys(t),s=0;s<a.length;s++)"__ob__"!==(r=a[s])&&(o=e[r],i=t[r],n&&T(e,r)?o!==i&&p(o)&&p(i)&&zn(o,i):Ie(e,r,i));return e}function Vn(e,t,n){return n?function(){var r=u(t)?t.call(n,n):t,o=u(e)?e.call(n,n):e;return r?zn(r,o):o}:t?e?function(){return zn(u(t)?t.call(this,this):t,u(e)?e.call(this,this):e)}:t:e}function Jn(e,t){var n=t?e?e.concat(t):i(t)?t:[t]:e;return n?function(e){for(var t=[],n=0;n<e.length;n++)-1===t.indexOf(e[n])&&t.push(e[n]);return t}(n):n}function Kn(e,t,n,r){var o=Object.create(e||null);return t?N(o,t):o}Un.data=function(e,t,n){return n?Vn(e,t,n):t&&"function"!=typeof t?e:Vn(e,t)},U.forEach((function(e){Un[e]=Jn})),W.forEach((function(e){Un[e+"s"]=Kn})),Un.watch=function(e,t,n,r){if(e===ie&&(e=void 0),t===ie&&(t=void 0),!t)return Object.create(e||null);if(!e)return t;var o={};for(var a in N(o,e),t){var s=o[a],c=t[a];s&&!i(s)&&(s=[s]),o[a]=s?s.concat(c):i(c)?c:[c]}return o},Un.props=Un.methods=Un.inject=Un.computed=function(e,t,n,r){if(!e)return t;var o=Object.create(null);return N(o,e),t&&N(o,t),o},Un.provide=function(e,t){return e?function(){var n=Object.create(null);return zn(n,u(e)?e.call(this):e),t&&zn(n,u(t)?t.call(this):t,!1),n}:t};var Xn=function(e,t){return void 0===t?e:t};function Qn(e,t,n){if(u(t)&&(t=t.options),function(e,t){var n=e.props;if(n){var r,o,a={};if(i(n))for(r=n.length;r--;)"string"==typeof(o=n[r])&&(a[E(o)]={type:null});else if(p(n))for(var s in n)o=n[s],a[E(s)]=p(o)?o:{type:o};e.props=a}}(t),function(e,t){var n=e.inject;if(n){var r=e.inject={};if(i(n))for(var o=0;o<n.length;o++)r[n[o]]={from:n[o]};else if(p(n))for(var a in n){var s=n[a];r[a]=p(s)?N({from:a},s):{from:s}}}}(t),function(e){var t=e.directives;if(t)for(var n in t){var r=t[n];u(r)&&(t[n]={bind:r,update:r})}}(t),!t._base&&(t.extends&&(e=Qn(e,t.extends,n)),t.mixins))for(var r=0,o=t.mixins.length;r<o;r++)e=Qn(e,t.mixins[r],n);var a,s={};for(a in e)c(a);for(a in t)T(e,a)||c(a);function c(r){var o=Un[r]||Xn;s[r]=o(e[r],t[r],n,r)}return s}function Gn(e,t,n,r){if("string"==typeof n){var o=e[t];if(T(o,n))return o[n];var i=E(n);if(T(o,i))return o[i];var a=$(i);return T(o,a)?o[a]:o[n]||o[i]||o[a]}}function Yn(e,t,n,r){var o=t[e],i=!T(n,e),a=n[e],s=nr(Boolean,o.type);if(s>-1)if(i&&!T(o,"default"))a=!1;else if(""===a||a===O(e)){var c=nr(String,o.type);(c<0||s<c)&&(a=!0)}if(void 0===a){a=function(e,t,n){if(!T(t,"default"))return;var r=t.default;0;if(e&&e.$options.propsData&&void 0===e.$options.propsData[n]&&void 0!==e._props[n])return e._props[n];return u(r)&&"Function"!==er(t.type)?r.call(e):r}(r,o,e);var l=Oe;je(!0),Le(a),je(l)}return a}var Zn=/^\s*function (\w+)/;function er(e){var t=e&&e.toString().match(Zn);return t?t[1]:""}function tr(e,t){return er(e)===er(t)}function nr(e,t){if(!i(t))return tr(t,e)?0:-1;for(var n=0,r=t.length;n<r;n++)if(tr(t[n],e))return n;return-1}function rr(e){this._init(e)}function or(e){e.cid=0;var t=1;e.extend=function(e){e=e||{};var n=this,r=n.cid,o=e._Ctor||(e._Ctor={});if(o[r])return o[r];var i=Rn(e)||Rn(n.options);var a=function(e){this._init(e)};return(a.prototype=Object.create(n.prototype)).constructor=a,a.cid=t++,a.options=Qn(n.options,e),a.super=n,a.options.props&&function(e){var t=e.options.props;for(var n in t)kn(e.prototype,"_props",n)}(a),a.options.computed&&function(e){var t=e.options.computed;for(var n in t)$n(e.prototype,n,t[n])}(a),a.extend=n.extend,a.mixin=n.mixin,a.use=n.use,W.forEach((function(e){a[e]=n[e]})),i&&(a.options.components[i]=a),a.superOptions=n.options,a.extendOptions=e,a.sealedOptions=N({},a.options),o[r]=a,a}}function ir(e){return e&&(Rn(e.Ctor.options)||e.tag)}function ar(e,t){return i(e)?e.indexOf(t)>-1:"string"==typeof e?e.split(",").indexOf(t)>-1:!!h(e)&&e.test(t)}function sr(e,t){var n=e.cache,r=e.keys,o=e._vnode;for(var i in n){var a=n[i];if(a){var s=a.name;s&&!t(s)&&cr(n,i,r,o)}}}function cr(e,t,n,r){var o=e[t];!o||r&&o.tag===r.tag||o.componentInstance.$destroy(),e[t]=null,w(n,t)}!function(e){e.prototype._init=function(e){var t=this;t._uid=Nn++,t._isVue=!0,t.__v_skip=!0,t._scope=new It(!0),t._scope._vm=!0,e&&e._isComponent?function(e,t){var n=e.$options=Object.create(e.constructor.options),r=t._parentVnode;n.parent=t.parent,n._parentVnode=r;var o=r.componentOptions;n.propsData=o.propsData,n._parentListeners=o.listeners,n._renderChildren=o.children,n._componentTag=o.tag,t.render&&(n.render=t.render,n.staticRenderFns=t.staticRenderFns)}(t,e):t.$options=Qn(Ln(t.constructor),e||{},t),t._renderProxy=t,t._self=t,function(e){var t=e.$options,n=t.parent;if(n&&!t.abstract){for(;n.$options.abstract&&n.$parent;)n=n.$parent;n.$children.push(e)}e.$parent=n,e.$root=n?n.$root:e,e.$children=[],e.$refs={},e._provided=n?n._provided:Object.create(null),e._watcher=null,e._inactive=null,e._directInactive=!1,e._isMounted=!1,e._isDestroyed=!1,e._isBeingDestroyed=!1}(t),function(e){e._events=Object.create(null),e._hasHookEvent=!1;var t=e.$options._parentListeners;t&&Pt(e,t)}(t),function(e){e._vnode=null,e._staticTrees=null;var t=e.$options,n=e.$vnode=t._parentVnode,r=n&&n.context;e.$slots=gt(t._renderChildren,r),e.$scopedSlots=n?_t(e.$parent,n.data.scopedSlots,e.$slots):o,e._c=function(t,n,r,o){return et(e,t,n,r,o,!1)},e.$createElement=function(t,n,r,o){return et(e,t,n,r,o,!0)};var i=n&&n.data;Pe(e,"$attrs",i&&i.attrs||o,null,!0),Pe(e,"$listeners",t._parentListeners||o,null,!0)}(t),Bt(t,"beforeCreate",void 0,!1),function(e){var t=Dn(e.$options.inject,e);t&&(je(!1),Object.keys(t).forEach((function(n){Pe(e,n,t[n])})),je(!0))}(t),Sn(t),function(e){var t=e.$options.provide;if(t){var n=u(t)?t.call(e):t;if(!f(n))return;for(var r=nn(e),o=de?Reflect.ownKeys(n):Object.keys(n),i=0;i<o.length;i++){var a=o[i];Object.defineProperty(r,a,Object.getOwnPropertyDescriptor(n,a))}}}(t),Bt(t,"created"),t.$options.el&&t.$mount(t.$options.el)}}(rr),function(e){var t={get:function(){return this._data}},n={get:function(){return this._props}};Object.defineProperty(e.prototype,"$data",t),Object.defineProperty(e.prototype,"$props",n),e.prototype.$set=Ie,e.prototype.$delete=Me,e.prototype.$watch=function(e,t,n){var r=this;if(p(t))return jn(r,e,t,n);(n=n||{}).user=!0;var o=new Cn(r,e,t,n);if(n.immediate){var i='callback for immediate watcher "'.concat(o.expression,'"');Te(),on(t,r,[o.value],r,i),ke()}return function(){o.teardown()}}}(rr),function(e){var t=/^hook:/;e.prototype.$on=function(e,n){var r=this;if(i(e))for(var o=0,a=e.length;o<a;o++)r.$on(e[o],n);else(r._events[e]||(r._events[e]=[])).push(n),t.test(e)&&(r._hasHookEvent=!0);return r},e.prototype.$once=function(e,t){var n=this;function r(){n.$off(e,r),t.apply(n,arguments)}return r.fn=t,n.$on(e,r),n},e.prototype.$off=function(e,t){var n=this;if(!arguments.length)return n._events=Object.create(null),n;if(i(e)){for(var r=0,o=e.length;r<o;r++)n.$off(e[r],t);return n}var a,s=n._events[e];if(!s)return n;if(!t)return n._events[e]=null,n;for(var c=s.length;c--;)if((a=s[c])===t||a.fn===t){s.splice(c,1);break}return n},e.prototype.$emit=function(e){var t=this,n=t._events[e];if(n){n=n.length>1?D(n):n;for(var r=D(arguments,1),o='event handler for "'.concat(e,'"'),i=0,a=n.length;i<a;i++)on(n[i],t,r,t,o)}return t}}(rr),function(e){e.prototype._update=function(e,t){var n=this,r=n.$el,o=n._vnode,i=Rt(n);n._vnode=e,n.$el=o?n.__patch__(o,e):n.__patch__(n.$el,e,t,!1),i(),r&&(r.__vue__=null),n.$el&&(n.$el.__vue__=n);for(var a=n;a&&a.$vnode&&a.$parent&&a.$vnode===a.$parent._vnode;)a.$parent.$el=a.$el,a=a.$parent},e.prototype.$forceUpdate=function(){this._watcher&&this._watcher.update()},e.prototype.$destroy=function(){var e=this;if(!e._isBeingDestroyed){Bt(e,"beforeDestroy"),e._isBeingDestroyed=!0;var t=e.$parent;!t||t._isBeingDestroyed||e.$options.abstract||w(t.$children,e),e._scope.stop(),e._data.__ob__&&e._data.__ob__.vmCount--,e._isDestroyed=!0,e.__patch__(e._vnode,null),Bt(e,"destroyed"),e.$off(),e.$el&&(e.$el.__vue__=null),e.$vnode&&(e.$vnode.parent=null)}}}(rr),function(e){mt(e.prototype),e.prototype.$nextTick=function(e){return gn(e,this)},e.prototype._render=function(){var e,t=this,n=t.$options,r=n.render,o=n._parentVnode;o&&t._isMounted&&(t.$scopedSlots=_t(t.$parent,o.data.scopedSlots,t.$slots,t.$scopedSlots),t._slotsProxy&&St(t._slotsProxy,t.$scopedSlots)),t.$vnode=o;try{he(t),At=t,e=r.call(t._renderProxy,t.$createElement)}catch(n){rn(n,t,"render"),e=t._vnode}finally{At=null,he()}return i(e)&&1===e.length&&(e=e[0]),e instanceof ve||(e=me()),e.parent=o,e}}(rr);var lr=[String,RegExp,Array],ur={name:"keep-alive",abstract:!0,props:{include:lr,exclude:lr,max:[String,Number]},methods:{cacheVNode:function(){var e=this,t=e.cache,n=e.keys,r=e.vnodeToCache,o=e.keyToCache;if(r){var i=r.tag,a=r.componentInstance,s=r.componentOptions;t[o]={name:ir(s),tag:i,componentInstance:a},n.push(o),this.max&&n.length>parseInt(this.max)&&cr(t,n[0],n,this._vnode),this.vnodeToCache=null}}},created:function(){this.cache=Object.create(null),this.keys=[]},destroyed:function(){for(var e in this.cache)cr(this.cache,e,this.keys)},mounted:function(){var e=this;this.cacheVNode(),this.$watch("include",(function(t){sr(e,(function(e){return ar(t,e)}))})),this.$watch("exclude",(function(t){sr(e,(function(e){return!ar(t,e)}))}))},updated:function(){this.cacheVNode()},render:function(){var e=this.$slots.default,t=jt(e),n=t&&t.componentOptions;if(n){var r=ir(n),o=this.include,i=this.exclude;if(o&&(!r||!ar(o,r))||i&&r&&ar(i,r))return t;var a=this.cache,s=this.keys,c=null==t.key?n.Ctor.cid+(n.tag?"::".concat(n.tag):""):t.key;a[c]?(t.componentInstance=a[c].componentInstance,w(s,c),s.push(c)):(this.vnodeToCache=t,this.keyToCache=c),t.data.keepAlive=!0}return t||e&&e[0]}},fr={KeepAlive:ur};!function(e){var t={get:function(){return z}};Object.defineProperty(e,"config",t),e.util={warn:Wn,extend:N,mergeOptions:Qn,defineReactive:Pe},e.set=Ie,e.delete=Me,e.nextTick=gn,e.observable=function(e){return Le(e),e},e.options=Object.create(null),W.forEach((function(t){e.options[t+"s"]=Object.create(null)})),e.options._base=e,N(e.options.components,fr),function(e){e.use=function(e){var t=this._installedPlugins||(this._installedPlugins=[]);if(t.indexOf(e)>-1)return this;var n=D(arguments,1);return n.unshift(this),u(e.install)?e.install.apply(e,n):u(e)&&e.apply(null,n),t.push(e),this}}(e),function(e){e.mixin=function(e){return this.options=Qn(this.options,e),this}}(e),or(e),function(e){W.forEach((function(t){e[t]=function(e,n){return n?("component"===t&&p(n)&&(n.name=n.name||e,n=this.options._base.extend(n)),"directive"===t&&u(n)&&(n={bind:n,update:n}),this.options[t+"s"][e]=n,n):this.options[t+"s"][e]}}))}(e)}(rr),Object.defineProperty(rr.prototype,"$isServer",{get:ce}),Object.defineProperty(rr.prototype,"$ssrContext",{get:function(){return this.$vnode&&this.$vnode.ssrContext}}),Object.defineProperty(rr,"FunctionalRenderContext",{value:Pn}),rr.version="2.7.15";var dr=b("style,class"),pr=b("input,textarea,option,select,progress"),hr=function(e,t,n){return"value"===n&&pr(e)&&"button"!==t||"selected"===n&&"option"===e||"checked"===n&&"input"===e||"muted"===n&&"video"===e},vr=b("contenteditable,draggable,spellcheck"),mr=b("events,caret,typing,plaintext-only"),gr=function(e,t){return wr(t)||"false"===t?"false":"contenteditable"===e&&mr(t)?t:"true"},yr=b("allowfullscreen,async,autofocus,autoplay,checked,compact,controls,declare,default,defaultchecked,defaultmuted,defaultselected,defer,disabled,enabled,formnovalidate,hidden,indeterminate,inert,ismap,itemscope,loop,multiple,muted,nohref,noresize,noshade,novalidate,nowrap,open,pauseonexit,readonly,required,reversed,scoped,seamless,selected,sortable,truespeed,typemustmatch,visible"),br="http://www.w3.org/1999/xlink",_r=function(e){return":"===e.charAt(5)&&"xlink"===e.slice(0,5)},xr=function(e){return _r(e)?e.slice(6,e.length):""},wr=function(e){return null==e||!1===e};function Cr(e){for(var t=e.data,n=e,r=e;s(r.componentInstance);)(r=r.componentInstance._vnode)&&r.data&&(t=Tr(r.data,t));for(;s(n=n.parent);)n&&n.data&&(t=Tr(t,n.data));return function(e,t){if(s(e)||s(t))return kr(e,Sr(t));return""}(t.staticClass,t.class)}function Tr(e,t){return{staticClass:kr(e.staticClass,t.staticClass),class:s(e.class)?[e.class,t.class]:t.class}}function kr(e,t){return e?t?e+" "+t:e:t||""}function Sr(e){return Array.isArray(e)?function(e){for(var t,n="",r=0,o=e.length;r<o;r++)s(t=Sr(e[r]))&&""!==t&&(n&&(n+=" "),n+=t);return n}(e):f(e)?function(e){var t="";for(var n in e)e[n]&&(t&&(t+=" "),t+=n);return t}(e):"string"==typeof e?e:""}var Er={svg:"http://www.w3.org/2000/svg",math:"http://www.w3.org/1998/Math/MathML"},$r=b("html,body,base,head,link,meta,style,title,address,article,aside,footer,header,h1,h2,h3,h4,h5,h6,hgroup,nav,section,div,dd,dl,dt,figcaption,figure,picture,hr,img,li,main,ol,p,pre,ul,a,b,abbr,bdi,bdo,br,cite,code,data,dfn,em,i,kbd,mark,q,rp,rt,rtc,ruby,s,samp,small,span,strong,sub,sup,time,u,var,wbr,area,audio,map,track,video,embed,object,param,source,canvas,script,noscript,del,ins,caption,col,colgroup,table,thead,tbody,td,th,tr,button,datalist,fieldset,form,input,label,legend,meter,optgroup,option,output,progress,select,textarea,details,dialog,menu,menuitem,summary,content,element,shadow,template,blockquote,iframe,tfoot"),Ar=b("svg,animate,circle,clippath,cursor,defs,desc,ellipse,filter,font-face,foreignobject,g,glyph,image,line,marker,mask,missing-glyph,path,pattern,polygon,polyline,rect,switch,symbol,text,textpath,tspan,use,view",!0),Or=function(e){return $r(e)||Ar(e)};function jr(e){return Ar(e)?"svg":"math"===e?"math":void 0}var Dr=Object.create(null);var Nr=b("text,number,password,search,email,tel,url");function Lr(e){if("string"==typeof e){var t=document.querySelector(e);return t||document.createElement("div")}return e}var Pr=Object.freeze({__proto__:null,createElement:function(e,t){var n=document.createElement(e);return"select"!==e||t.data&&t.data.attrs&&void 0!==t.data.attrs.multiple&&n.setAttribute("multiple","multiple"),n},createElementNS:function(e,t){return document.createElementNS(Er[e],t)},createTextNode:function(e){return document.createTextNode(e)},createComment:function(e){return document.createComment(e)},insertBefore:function(e,t,n){e.insertBefore(t,n)},removeChild:function(e,t){e.removeChild(t)},appendChild:function(e,t){e.appendChild(t)},parentNode:function(e){return e.parentNode},nextSibling:function(e){return e.nextSibling},tagName:function(e){return e.tagName},setTextContent:function(e,t){e.textContent=t},setStyleScope:function(e,t){e.setAttribute(t,"")}}),Ir={create:function(e,t){Mr(t)},update:function(e,t){e.data.ref!==t.data.ref&&(Mr(e,!0),Mr(t))},destroy:function(e){Mr(e,!0)}};function Mr(e,t){var n=e.data.ref;if(s(n)){var r=e.context,o=e.componentInstance||e.elm,a=t?null:o,c=t?void 0:o;if(u(n))on(n,r,[a],r,"template ref function");else{var l=e.data.refInFor,f="string"==typeof n||"number"==typeof n,d=Be(n),p=r.$refs;if(f||d)if(l){var h=f?p[n]:n.value;t?i(h)&&w(h,o):i(h)?h.includes(o)||h.push(o):f?(p[n]=[o],Rr(r,n,p[n])):n.value=[o]}else if(f){if(t&&p[n]!==o)return;p[n]=c,Rr(r,n,a)}else if(d){if(t&&n.value!==o)return;n.value=a}else 0}}}function Rr(e,t,n){var r=e._setupState;r&&T(r,t)&&(Be(r[t])?r[t].value=n:r[t]=n)}var Fr=new ve("",{},[]),Hr=["create","activate","update","remove","destroy"];function qr(e,t){return e.key===t.key&&e.asyncFactory===t.asyncFactory&&(e.tag===t.tag&&e.isComment===t.isComment&&s(e.data)===s(t.data)&&function(e,t){if("input"!==e.tag)return!0;var n,r=s(n=e.data)&&s(n=n.attrs)&&n.type,o=s(n=t.data)&&s(n=n.attrs)&&n.type;return r===o||Nr(r)&&Nr(o)}(e,t)||c(e.isAsyncPlaceholder)&&a(t.asyncFactory.error))}function Br(e,t,n){var r,o,i={};for(r=t;r<=n;++r)s(o=e[r].key)&&(i[o]=r);return i}var Wr={create:Ur,update:Ur,destroy:function(e){Ur(e,Fr)}};function Ur(e,t){(e.data.directives||t.data.directives)&&function(e,t){var n,r,o,i=e===Fr,a=t===Fr,s=Vr(e.data.directives,e.context),c=Vr(t.data.directives,t.context),l=[],u=[];for(n in c)r=s[n],o=c[n],r?(o.oldValue=r.value,o.oldArg=r.arg,Kr(o,"update",t,e),o.def&&o.def.componentUpdated&&u.push(o)):(Kr(o,"bind",t,e),o.def&&o.def.inserted&&l.push(o));if(l.length){var f=function(){for(var n=0;n<l.length;n++)Kr(l[n],"inserted",t,e)};i?Je(t,"insert",f):f()}u.length&&Je(t,"postpatch",(function(){for(var n=0;n<u.length;n++)Kr(u[n],"componentUpdated",t,e)}));if(!i)for(n in s)c[n]||Kr(s[n],"unbind",e,e,a)}(e,t)}var zr=Object.create(null);function Vr(e,t){var n,r,o=Object.create(null);if(!e)return o;for(n=0;n<e.length;n++){if((r=e[n]).modifiers||(r.modifiers=zr),o[Jr(r)]=r,t._setupState&&t._setupState.__sfc){var i=r.def||Gn(t,"_setupState","v-"+r.name);r.def="function"==typeof i?{bind:i,update:i}:i}r.def=r.def||Gn(t.$options,"directives",r.name)}return o}function Jr(e){return e.rawName||"".concat(e.name,".").concat(Object.keys(e.modifiers||{}).join("."))}function Kr(e,t,n,r,o){var i=e.def&&e.def[t];if(i)try{i(n.elm,e,n,r,o)}catch(r){rn(r,n.context,"directive ".concat(e.name," ").concat(t," hook"))}}var Xr=[Ir,Wr];function Qr(e,t){var n=t.componentOptions;if(!(s(n)&&!1===n.Ctor.options.inheritAttrs||a(e.data.attrs)&&a(t.data.attrs))){var r,o,i=t.elm,l=e.data.attrs||{},u=t.data.attrs||{};for(r in(s(u.__ob__)||c(u._v_attr_proxy))&&(u=t.data.attrs=N({},u)),u)o=u[r],l[r]!==o&&Gr(i,r,o,t.data.pre);for(r in(Z||te)&&u.value!==l.value&&Gr(i,"value",u.value),l)a(u[r])&&(_r(r)?i.removeAttributeNS(br,xr(r)):vr(r)||i.removeAttribute(r))}}function Gr(e,t,n,r){r||e.tagName.indexOf("-")>-1?Yr(e,t,n):yr(t)?wr(n)?e.removeAttribute(t):(n="allowfullscreen"===t&&"EMBED"===e.tagName?"true":t,e.setAttribute(t,n)):vr(t)?e.setAttribute(t,gr(t,n)):_r(t)?wr(n)?e.removeAttributeNS(br,xr(t)):e.setAttributeNS(br,t,n):Yr(e,t,n)}function Yr(e,t,n){if(wr(n))e.removeAttribute(t);else{if(Z&&!ee&&"TEXTAREA"===e.tagName&&"placeholder"===t&&""!==n&&!e.__ieph){e.addEventListener("input",(function t(n){n.stopImmediatePropagation(),e.removeEventListener("input",t)})),e.__ieph=!0}e.setAttribute(t,n)}}var Zr={create:Qr,update:Qr};function eo(e,t){var n=t.elm,r=t.data,o=e.data;if(!(a(r.staticClass)&&a(r.class)&&(a(o)||a(o.staticClass)&&a(o.class)))){var i=Cr(t),c=n._transitionClasses;s(c)&&(i=kr(i,Sr(c))),i!==n._prevClass&&(n.setAttribute("class",i),n._prevClass=i)}}var to,no,ro,oo,io,ao,so={create:eo,update:eo},co=/[\w).+\-_$\]]/;function lo(e){var t,n,r,o,i,a=!1,s=!1,c=!1,l=!1,u=0,f=0,d=0,p=0;for(r=0;r<e.length;r++)if(n=t,t=e.charCodeAt(r),a)39===t&&92!==n&&(a=!1);else if(s)34===t&&92!==n&&(s=!1);else if(c)96===t&&92!==n&&(c=!1);else if(l)47===t&&92!==n&&(l=!1);else if(124!==t||124===e.charCodeAt(r+1)||124===e.charCodeAt(r-1)||u||f||d){switch(t){case 34:s=!0;break;case 39:a=!0;break;case 96:c=!0;break;case 40:d++;break;case 41:d--;break;case 91:f++;break;case 93:f--;break;case 123:u++;break;case 125:u--}if(47===t){for(var h=r-1,v=void 0;h>=0&&" "===(v=e.charAt(h));h--);v&&co.test(v)||(l=!0)}}else void 0===o?(p=r+1,o=e.slice(0,r).trim()):m();function m(){(i||(i=[])).push(e.slice(p,r).trim()),p=r+1}if(void 0===o?o=e.slice(0,r).trim():0!==p&&m(),i)for(r=0;r<i.length;r++)o=uo(o,i[r]);return o}function uo(e,t){var n=t.indexOf("(");if(n<0)return'_f("'.concat(t,'")(').concat(e,")");var r=t.slice(0,n),o=t.slice(n+1);return'_f("'.concat(r,'")(').concat(e).concat(")"!==o?","+o:o)}function fo(e,t){console.error("[Vue compiler]: ".concat(e))}function po(e,t){return e?e.map((function(e){return e[t]})).filter((function(e){return e})):[]}function ho(e,t,n,r,o){(e.props||(e.props=[])).push(Co({name:t,value:n,dynamic:o},r)),e.plain=!1}function vo(e,t,n,r,o){(o?e.dynamicAttrs||(e.dynamicAttrs=[]):e.attrs||(e.attrs=[])).push(Co({name:t,value:n,dynamic:o},r)),e.plain=!1}function mo(e,t,n,r){e.attrsMap[t]=n,e.attrsList.push(Co({name:t,value:n},r))}function go(e,t,n,r,o,i,a,s){(e.directives||(e.directives=[])).push(Co({name:t,rawName:n,value:r,arg:o,isDynamicArg:i,modifiers:a},s)),e.plain=!1}function yo(e,t,n){return n?"_p(".concat(t,',"').concat(e,'")'):e+t}function bo(e,t,n,r,i,a,s,c){var l;(r=r||o).right?c?t="(".concat(t,")==='click'?'contextmenu':(").concat(t,")"):"click"===t&&(t="contextmenu",delete r.right):r.middle&&(c?t="(".concat(t,")==='click'?'mouseup':(").concat(t,")"):"click"===t&&(t="mouseup")),r.capture&&(delete r.capture,t=yo("!",t,c)),r.once&&(delete r.once,t=yo("~",t,c)),r.passive&&(delete r.passive,t=yo("&",t,c)),r.native?(delete r.native,l=e.nativeEvents||(e.nativeEvents={})):l=e.events||(e.events={});var u=Co({value:n.trim(),dynamic:c},s);r!==o&&(u.modifiers=r);var f=l[t];Array.isArray(f)?i?f.unshift(u):f.push(u):l[t]=f?i?[u,f]:[f,u]:u,e.plain=!1}function _o(e,t,n){var r=xo(e,":"+t)||xo(e,"v-bind:"+t);if(null!=r)return lo(r);if(!1!==n){var o=xo(e,t);if(null!=o)return JSON.stringify(o)}}function xo(e,t,n){var r;if(null!=(r=e.attrsMap[t]))for(var o=e.attrsList,i=0,a=o.length;i<a;i++)if(o[i].name===t){o.splice(i,1);break}return n&&delete e.attrsMap[t],r}function wo(e,t){for(var n=e.attrsList,r=0,o=n.length;r<o;r++){var i=n[r];if(t.test(i.name))return n.splice(r,1),i}}function Co(e,t){return t&&(null!=t.start&&(e.start=t.start),null!=t.end&&(e.end=t.end)),e}function To(e,t,n){var r=n||{},o=r.number,i="$$v",a=i;r.trim&&(a="(typeof ".concat(i," === 'string'")+"? ".concat(i,".trim()")+": ".concat(i,")")),o&&(a="_n(".concat(a,")"));var s=ko(t,a);e.model={value:"(".concat(t,")"),expression:JSON.stringify(t),callback:"function (".concat(i,") {").concat(s,"}")}}function ko(e,t){var n=function(e){if(e=e.trim(),to=e.length,e.indexOf("[")<0||e.lastIndexOf("]")<to-1)return(oo=e.lastIndexOf("."))>-1?{exp:e.slice(0,oo),key:'"'+e.slice(oo+1)+'"'}:{exp:e,key:null};no=e,oo=io=ao=0;for(;!Eo();)$o(ro=So())?Oo(ro):91===ro&&Ao(ro);return{exp:e.slice(0,io),key:e.slice(io+1,ao)}}(e);return null===n.key?"".concat(e,"=").concat(t):"$set(".concat(n.exp,", ").concat(n.key,", ").concat(t,")")}function So(){return no.charCodeAt(++oo)}function Eo(){return oo>=to}function $o(e){return 34===e||39===e}function Ao(e){var t=1;for(io=oo;!Eo();)if($o(e=So()))Oo(e);else if(91===e&&t++,93===e&&t--,0===t){ao=oo;break}}function Oo(e){for(var t=e;!Eo()&&(e=So())!==t;);}var jo,Do="__r",No="__c";function Lo(e,t,n){var r=jo;return function o(){null!==t.apply(null,arguments)&&Mo(e,o,n,r)}}var Po=ln&&!(oe&&Number(oe[1])<=53);function Io(e,t,n,r){if(Po){var o=Xt,i=t;t=i._wrapper=function(e){if(e.target===e.currentTarget||e.timeStamp>=o||e.timeStamp<=0||e.target.ownerDocument!==document)return i.apply(this,arguments)}}jo.addEventListener(e,t,ae?{capture:n,passive:r}:n)}function Mo(e,t,n,r){(r||jo).removeEventListener(e,t._wrapper||t,n)}function Ro(e,t){if(!a(e.data.on)||!a(t.data.on)){var n=t.data.on||{},r=e.data.on||{};jo=t.elm||e.elm,function(e){if(s(e[Do])){var t=Z?"change":"input";e[t]=[].concat(e[Do],e[t]||[]),delete e[Do]}s(e[No])&&(e.change=[].concat(e[No],e.change||[]),delete e[No])}(n),Ve(n,r,Io,Mo,Lo,t.context),jo=void 0}}var Fo,Ho={create:Ro,update:Ro,destroy:function(e){return Ro(e,Fr)}};function qo(e,t){if(!a(e.data.domProps)||!a(t.data.domProps)){var n,r,o=t.elm,i=e.data.domProps||{},l=t.data.domProps||{};for(n in(s(l.__ob__)||c(l._v_attr_proxy))&&(l=t.data.domProps=N({},l)),i)n in l||(o[n]="");for(n in l){if(r=l[n],"textContent"===n||"innerHTML"===n){if(t.children&&(t.children.length=0),r===i[n])continue;1===o.childNodes.length&&o.removeChild(o.childNodes[0])}if("value"===n&&"PROGRESS"!==o.tagName){o._value=r;var u=a(r)?"":String(r);Bo(o,u)&&(o.value=u)}else if("innerHTML"===n&&Ar(o.tagName)&&a(o.innerHTML)){(Fo=Fo||document.createElement("div")).innerHTML="<svg>".concat(r,"</svg>");for(var f=Fo.firstChild;o.firstChild;)o.removeChild(o.firstChild);for(;f.firstChild;)o.appendChild(f.firstChild)}else if(r!==i[n])try{o[n]=r}catch(e){}}}}function Bo(e,t){return!e.composing&&("OPTION"===e.tagName||function(e,t){var n=!0;try{n=document.activeElement!==e}catch(e){}return n&&e.value!==t}(e,t)||function(e,t){var n=e.value,r=e._vModifiers;if(s(r)){if(r.number)return y(n)!==y(t);if(r.trim)return n.trim()!==t.trim()}return n!==t}(e,t))}var Wo={create:qo,update:qo},Uo=k((function(e){var t={},n=/:(.+)/;return e.split(/;(?![^(]*\))/g).forEach((function(e){if(e){var r=e.split(n);r.length>1&&(t[r[0].trim()]=r[1].trim())}})),t}));function zo(e){var t=Vo(e.style);return e.staticStyle?N(e.staticStyle,t):t}function Vo(e){return Array.isArray(e)?L(e):"string"==typeof e?Uo(e):e}var Jo,Ko=/^--/,Xo=/\s*!important$/,Qo=function(e,t,n){if(Ko.test(t))e.style.setProperty(t,n);else if(Xo.test(n))e.style.setProperty(O(t),n.replace(Xo,""),"important");else{var r=Yo(t);if(Array.isArray(n))for(var o=0,i=n.length;o<i;o++)e.style[r]=n[o];else e.style[r]=n}},Go=["Webkit","Moz","ms"],Yo=k((function(e){if(Jo=Jo||document.createElement("div").style,"filter"!==(e=E(e))&&e in Jo)return e;for(var t=e.charAt(0).toUpperCase()+e.slice(1),n=0;n<Go.length;n++){var r=Go[n]+t;if(r in Jo)return r}}));function Zo(e,t){var n=t.data,r=e.data;if(!(a(n.staticStyle)&&a(n.style)&&a(r.staticStyle)&&a(r.style))){var o,i,c=t.elm,l=r.staticStyle,u=r.normalizedStyle||r.style||{},f=l||u,d=Vo(t.data.style)||{};t.data.normalizedStyle=s(d.__ob__)?N({},d):d;var p=function(e,t){var n,r={};if(t)for(var o=e;o.componentInstance;)(o=o.componentInstance._vnode)&&o.data&&(n=zo(o.data))&&N(r,n);(n=zo(e.data))&&N(r,n);for(var i=e;i=i.parent;)i.data&&(n=zo(i.data))&&N(r,n);return r}(t,!0);for(i in f)a(p[i])&&Qo(c,i,"");for(i in p)(o=p[i])!==f[i]&&Qo(c,i,null==o?"":o)}}var ei={create:Zo,update:Zo},ti=/\s+/;function ni(e,t){if(t&&(t=t.trim()))if(e.classList)t.indexOf(" ")>-1?t.split(ti).forEach((function(t){return e.classList.add(t)})):e.classList.add(t);else{var n=" ".concat(e.getAttribute("class")||""," ");n.indexOf(" "+t+" ")<0&&e.setAttribute("class",(n+t).trim())}}function ri(e,t){if(t&&(t=t.trim()))if(e.classList)t.indexOf(" ")>-1?t.split(ti).forEach((function(t){return e.classList.remove(t)})):e.classList.remove(t),e.classList.length||e.removeAttribute("class");else{for(var n=" ".concat(e.getAttribute("class")||""," "),r=" "+t+" ";n.indexOf(r)>=0;)n=n.replace(r," ");(n=n.trim())?e.setAttribute("class",n):e.removeAttribute("class")}}function oi(e){if(e){if("object"===t(e)){var n={};return!1!==e.css&&N(n,ii(e.name||"v")),N(n,e),n}return"string"==typeof e?ii(e):void 0}}var ii=k((function(e){return{enterClass:"".concat(e,"-enter"),enterToClass:"".concat(e,"-enter-to"),enterActiveClass:"".concat(e,"-enter-active"),leaveClass:"".concat(e,"-leave"),leaveToClass:"".concat(e,"-leave-to"),leaveActiveClass:"".concat(e,"-leave-active")}})),ai=G&&!ee,si="transition",ci="animation",li="transition",ui="transitionend",fi="animation",di="animationend";ai&&(void 0===window.ontransitionend&&void 0!==window.onwebkittransitionend&&(li="WebkitTransition",ui="webkitTransitionEnd"),void 0===window.onanimationend&&void 0!==window.onwebkitanimationend&&(fi="WebkitAnimation",di="webkitAnimationEnd"));var pi=G?window.requestAnimationFrame?window.requestAnimationFrame.bind(window):setTimeout:function(e){return e()};function hi(e){pi((function(){pi(e)}))}function vi(e,t){var n=e._transitionClasses||(e._transitionClasses=[]);n.indexOf(t)<0&&(n.push(t),ni(e,t))}function mi(e,t){e._transitionClasses&&w(e._transitionClasses,t),ri(e,t)}function gi(e,t,n){var r=bi(e,t),o=r.type,i=r.timeout,a=r.propCount;if(!o)return n();var s=o===si?ui:di,c=0,l=function(){e.removeEventListener(s,u),n()},u=function(t){t.target===e&&++c>=a&&l()};setTimeout((function(){c<a&&l()}),i+1),e.addEventListener(s,u)}var yi=/\b(transform|all)(,|$)/;function bi(e,t){var n,r=window.getComputedStyle(e),o=(r[li+"Delay"]||"").split(", "),i=(r[li+"Duration"]||"").split(", "),a=_i(o,i),s=(r[fi+"Delay"]||"").split(", "),c=(r[fi+"Duration"]||"").split(", "),l=_i(s,c),u=0,f=0;return t===si?a>0&&(n=si,u=a,f=i.length):t===ci?l>0&&(n=ci,u=l,f=c.length):f=(n=(u=Math.max(a,l))>0?a>l?si:ci:null)?n===si?i.length:c.length:0,{type:n,timeout:u,propCount:f,hasTransform:n===si&&yi.test(r[li+"Property"])}}function _i(e,t){for(;e.length<t.length;)e=e.concat(e);return Math.max.apply(null,t.map((function(t,n){return xi(t)+xi(e[n])})))}function xi(e){return 1e3*Number(e.slice(0,-1).replace(",","."))}function wi(e,t){var n=e.elm;s(n._leaveCb)&&(n._leaveCb.cancelled=!0,n._leaveCb());var r=oi(e.data.transition);if(!a(r)&&!s(n._enterCb)&&1===n.nodeType){for(var o=r.css,i=r.type,c=r.enterClass,l=r.enterToClass,d=r.enterActiveClass,p=r.appearClass,h=r.appearToClass,v=r.appearActiveClass,m=r.beforeEnter,g=r.enter,b=r.afterEnter,_=r.enterCancelled,x=r.beforeAppear,w=r.appear,C=r.afterAppear,T=r.appearCancelled,k=r.duration,S=Mt,E=Mt.$vnode;E&&E.parent;)S=E.context,E=E.parent;var $=!S._isMounted||!e.isRootInsert;if(!$||w||""===w){var A=$&&p?p:c,O=$&&v?v:d,j=$&&h?h:l,D=$&&x||m,N=$&&u(w)?w:g,L=$&&C||b,P=$&&T||_,I=y(f(k)?k.enter:k);0;var M=!1!==o&&!ee,R=ki(N),F=n._enterCb=H((function(){M&&(mi(n,j),mi(n,O)),F.cancelled?(M&&mi(n,A),P&&P(n)):L&&L(n),n._enterCb=null}));e.data.show||Je(e,"insert",(function(){var t=n.parentNode,r=t&&t._pending&&t._pending[e.key];r&&r.tag===e.tag&&r.elm._leaveCb&&r.elm._leaveCb(),N&&N(n,F)})),D&&D(n),M&&(vi(n,A),vi(n,O),hi((function(){mi(n,A),F.cancelled||(vi(n,j),R||(Ti(I)?setTimeout(F,I):gi(n,i,F)))}))),e.data.show&&(t&&t(),N&&N(n,F)),M||R||F()}}}function Ci(e,t){var n=e.elm;s(n._enterCb)&&(n._enterCb.cancelled=!0,n._enterCb());var r=oi(e.data.transition);if(a(r)||1!==n.nodeType)return t();if(!s(n._leaveCb)){var o=r.css,i=r.type,c=r.leaveClass,l=r.leaveToClass,u=r.leaveActiveClass,d=r.beforeLeave,p=r.leave,h=r.afterLeave,v=r.leaveCancelled,m=r.delayLeave,g=r.duration,b=!1!==o&&!ee,_=ki(p),x=y(f(g)?g.leave:g);0;var w=n._leaveCb=H((function(){n.parentNode&&n.parentNode._pending&&(n.parentNode._pending[e.key]=null),b&&(mi(n,l),mi(n,u)),w.cancelled?(b&&mi(n,c),v&&v(n)):(t(),h&&h(n)),n._leaveCb=null}));m?m(C):C()}function C(){w.cancelled||(!e.data.show&&n.parentNode&&((n.parentNode._pending||(n.parentNode._pending={}))[e.key]=e),d&&d(n),b&&(vi(n,c),vi(n,u),hi((function(){mi(n,c),w.cancelled||(vi(n,l),_||(Ti(x)?setTimeout(w,x):gi(n,i,w)))}))),p&&p(n,w),b||_||w())}}function Ti(e){return"number"==typeof e&&!isNaN(e)}function ki(e){if(a(e))return!1;var t=e.fns;return s(t)?ki(Array.isArray(t)?t[0]:t):(e._length||e.length)>1}function Si(e,t){!0!==t.data.show&&wi(t)}var Ei=function(e){var t,n,r={},o=e.modules,u=e.nodeOps;for(t=0;t<Hr.length;++t)for(r[Hr[t]]=[],n=0;n<o.length;++n)s(o[n][Hr[t]])&&r[Hr[t]].push(o[n][Hr[t]]);function f(e){var t=u.parentNode(e);s(t)&&u.removeChild(t,e)}function d(e,t,n,o,i,a,l){if(s(e.elm)&&s(a)&&(e=a[l]=ye(e)),e.isRootInsert=!i,!function(e,t,n,o){var i=e.data;if(s(i)){var a=s(e.componentInstance)&&i.keepAlive;if(s(i=i.hook)&&s(i=i.init)&&i(e,!1),s(e.componentInstance))return p(e,t),h(n,e.elm,o),c(a)&&function(e,t,n,o){var i,a=e;for(;a.componentInstance;)if(s(i=(a=a.componentInstance._vnode).data)&&s(i=i.transition)){for(i=0;i<r.activate.length;++i)r.activate[i](Fr,a);t.push(a);break}h(n,e.elm,o)}(e,t,n,o),!0}}(e,t,n,o)){var f=e.data,d=e.children,m=e.tag;s(m)?(e.elm=e.ns?u.createElementNS(e.ns,m):u.createElement(m,e),y(e),v(e,d,t),s(f)&&g(e,t),h(n,e.elm,o)):c(e.isComment)?(e.elm=u.createComment(e.text),h(n,e.elm,o)):(e.elm=u.createTextNode(e.text),h(n,e.elm,o))}}function p(e,t){s(e.data.pendingInsert)&&(t.push.apply(t,e.data.pendingInsert),e.data.pendingInsert=null),e.elm=e.componentInstance.$el,m(e)?(g(e,t),y(e)):(Mr(e),t.push(e))}function h(e,t,n){s(e)&&(s(n)?u.parentNode(n)===e&&u.insertBefore(e,t,n):u.appendChild(e,t))}function v(e,t,n){if(i(t)){0;for(var r=0;r<t.length;++r)d(t[r],n,e.elm,null,!0,t,r)}else l(e.text)&&u.appendChild(e.elm,u.createTextNode(String(e.text)))}function m(e){for(;e.componentInstance;)e=e.componentInstance._vnode;return s(e.tag)}function g(e,n){for(var o=0;o<r.create.length;++o)r.create[o](Fr,e);s(t=e.data.hook)&&(s(t.create)&&t.create(Fr,e),s(t.insert)&&n.push(e))}function y(e){var t;if(s(t=e.fnScopeId))u.setStyleScope(e.elm,t);else for(var n=e;n;)s(t=n.context)&&s(t=t.$options._scopeId)&&u.setStyleScope(e.elm,t),n=n.parent;s(t=Mt)&&t!==e.context&&t!==e.fnContext&&s(t=t.$options._scopeId)&&u.setStyleScope(e.elm,t)}function _(e,t,n,r,o,i){for(;r<=o;++r)d(n[r],i,e,t,!1,n,r)}function x(e){var t,n,o=e.data;if(s(o))for(s(t=o.hook)&&s(t=t.destroy)&&t(e),t=0;t<r.destroy.length;++t)r.destroy[t](e);if(s(t=e.children))for(n=0;n<e.children.length;++n)x(e.children[n])}function w(e,t,n){for(;t<=n;++t){var r=e[t];s(r)&&(s(r.tag)?(C(r),x(r)):f(r.elm))}}function C(e,t){if(s(t)||s(e.data)){var n,o=r.remove.length+1;for(s(t)?t.listeners+=o:t=function(e,t){function n(){0==--n.listeners&&f(e)}return n.listeners=t,n}(e.elm,o),s(n=e.componentInstance)&&s(n=n._vnode)&&s(n.data)&&C(n,t),n=0;n<r.remove.length;++n)r.remove[n](e,t);s(n=e.data.hook)&&s(n=n.remove)?n(e,t):t()}else f(e.elm)}function T(e,t,n,r){for(var o=n;o<r;o++){var i=t[o];if(s(i)&&qr(e,i))return o}}function k(e,t,n,o,i,l){if(e!==t){s(t.elm)&&s(o)&&(t=o[i]=ye(t));var f=t.elm=e.elm;if(c(e.isAsyncPlaceholder))s(t.asyncFactory.resolved)?$(e.elm,t,n):t.isAsyncPlaceholder=!0;else if(c(t.isStatic)&&c(e.isStatic)&&t.key===e.key&&(c(t.isCloned)||c(t.isOnce)))t.componentInstance=e.componentInstance;else{var p,h=t.data;s(h)&&s(p=h.hook)&&s(p=p.prepatch)&&p(e,t);var v=e.children,g=t.children;if(s(h)&&m(t)){for(p=0;p<r.update.length;++p)r.update[p](e,t);s(p=h.hook)&&s(p=p.update)&&p(e,t)}a(t.text)?s(v)&&s(g)?v!==g&&function(e,t,n,r,o){var i,c,l,f=0,p=0,h=t.length-1,v=t[0],m=t[h],g=n.length-1,y=n[0],b=n[g],x=!o;for(;f<=h&&p<=g;)a(v)?v=t[++f]:a(m)?m=t[--h]:qr(v,y)?(k(v,y,r,n,p),v=t[++f],y=n[++p]):qr(m,b)?(k(m,b,r,n,g),m=t[--h],b=n[--g]):qr(v,b)?(k(v,b,r,n,g),x&&u.insertBefore(e,v.elm,u.nextSibling(m.elm)),v=t[++f],b=n[--g]):qr(m,y)?(k(m,y,r,n,p),x&&u.insertBefore(e,m.elm,v.elm),m=t[--h],y=n[++p]):(a(i)&&(i=Br(t,f,h)),a(c=s(y.key)?i[y.key]:T(y,t,f,h))?d(y,r,e,v.elm,!1,n,p):qr(l=t[c],y)?(k(l,y,r,n,p),t[c]=void 0,x&&u.insertBefore(e,l.elm,v.elm)):d(y,r,e,v.elm,!1,n,p),y=n[++p]);f>h?_(e,a(n[g+1])?null:n[g+1].elm,n,p,g,r):p>g&&w(t,f,h)}(f,v,g,n,l):s(g)?(s(e.text)&&u.setTextContent(f,""),_(f,null,g,0,g.length-1,n)):s(v)?w(v,0,v.length-1):s(e.text)&&u.setTextContent(f,""):e.text!==t.text&&u.setTextContent(f,t.text),s(h)&&s(p=h.hook)&&s(p=p.postpatch)&&p(e,t)}}}function S(e,t,n){if(c(n)&&s(e.parent))e.parent.data.pendingInsert=t;else for(var r=0;r<t.length;++r)t[r].data.hook.insert(t[r])}var E=b("attrs,class,staticClass,staticStyle,key");function $(e,t,n,r){var o,i=t.tag,a=t.data,l=t.children;if(r=r||a&&a.pre,t.elm=e,c(t.isComment)&&s(t.asyncFactory))return t.isAsyncPlaceholder=!0,!0;if(s(a)&&(s(o=a.hook)&&s(o=o.init)&&o(t,!0),s(o=t.componentInstance)))return p(t,n),!0;if(s(i)){if(s(l))if(e.hasChildNodes())if(s(o=a)&&s(o=o.domProps)&&s(o=o.innerHTML)){if(o!==e.innerHTML)return!1}else{for(var u=!0,f=e.firstChild,d=0;d<l.length;d++){if(!f||!$(f,l[d],n,r)){u=!1;break}f=f.nextSibling}if(!u||f)return!1}else v(t,l,n);if(s(a)){var h=!1;for(var m in a)if(!E(m)){h=!0,g(t,n);break}!h&&a.class&&_n(a.class)}}else e.data!==t.text&&(e.data=t.text);return!0}return function(e,t,n,o){if(!a(t)){var i,l=!1,f=[];if(a(e))l=!0,d(t,f);else{var p=s(e.nodeType);if(!p&&qr(e,t))k(e,t,f,null,null,o);else{if(p){if(1===e.nodeType&&e.hasAttribute(B)&&(e.removeAttribute(B),n=!0),c(n)&&$(e,t,f))return S(t,f,!0),e;i=e,e=new ve(u.tagName(i).toLowerCase(),{},[],void 0,i)}var h=e.elm,v=u.parentNode(h);if(d(t,f,h._leaveCb?null:v,u.nextSibling(h)),s(t.parent))for(var g=t.parent,y=m(t);g;){for(var b=0;b<r.destroy.length;++b)r.destroy[b](g);if(g.elm=t.elm,y){for(var _=0;_<r.create.length;++_)r.create[_](Fr,g);var C=g.data.hook.insert;if(C.merged)for(var T=C.fns.slice(1),E=0;E<T.length;E++)T[E]()}else Mr(g);g=g.parent}s(v)?w([e],0,0):s(e.tag)&&x(e)}}return S(t,f,l),t.elm}s(e)&&x(e)}}({nodeOps:Pr,modules:[Zr,so,Ho,Wo,ei,G?{create:Si,activate:Si,remove:function(e,t){!0!==e.data.show?Ci(e,t):t()}}:{}].concat(Xr)});ee&&document.addEventListener("selectionchange",(function(){var e=document.activeElement;e&&e.vmodel&&Pi(e,"input")}));var $i={inserted:function(e,t,n,r){"select"===n.tag?(r.elm&&!r.elm._vOptions?Je(n,"postpatch",(function(){$i.componentUpdated(e,t,n)})):Ai(e,t,n.context),e._vOptions=[].map.call(e.options,Di)):("textarea"===n.tag||Nr(e.type))&&(e._vModifiers=t.modifiers,t.modifiers.lazy||(e.addEventListener("compositionstart",Ni),e.addEventListener("compositionend",Li),e.addEventListener("change",Li),ee&&(e.vmodel=!0)))},componentUpdated:function(e,t,n){if("select"===n.tag){Ai(e,t,n.context);var r=e._vOptions,o=e._vOptions=[].map.call(e.options,Di);if(o.some((function(e,t){return!R(e,r[t])})))(e.multiple?t.value.some((function(e){return ji(e,o)})):t.value!==t.oldValue&&ji(t.value,o))&&Pi(e,"change")}}};function Ai(e,t,n){Oi(e,t,n),(Z||te)&&setTimeout((function(){Oi(e,t,n)}),0)}function Oi(e,t,n){var r=t.value,o=e.multiple;if(!o||Array.isArray(r)){for(var i,a,s=0,c=e.options.length;s<c;s++)if(a=e.options[s],o)i=F(r,Di(a))>-1,a.selected!==i&&(a.selected=i);else if(R(Di(a),r))return void(e.selectedIndex!==s&&(e.selectedIndex=s));o||(e.selectedIndex=-1)}}function ji(e,t){return t.every((function(t){return!R(t,e)}))}function Di(e){return"_value"in e?e._value:e.value}function Ni(e){e.target.composing=!0}function Li(e){e.target.composing&&(e.target.composing=!1,Pi(e.target,"input"))}function Pi(e,t){var n=document.createEvent("HTMLEvents");n.initEvent(t,!0,!0),e.dispatchEvent(n)}function Ii(e){return!e.componentInstance||e.data&&e.data.transition?e:Ii(e.componentInstance._vnode)}var Mi={bind:function(e,t,n){var r=t.value,o=(n=Ii(n)).data&&n.data.transition,i=e.__vOriginalDisplay="none"===e.style.display?"":e.style.display;r&&o?(n.data.show=!0,wi(n,(function(){e.style.display=i}))):e.style.display=r?i:"none"},update:function(e,t,n){var r=t.value;!r!=!t.oldValue&&((n=Ii(n)).data&&n.data.transition?(n.data.show=!0,r?wi(n,(function(){e.style.display=e.__vOriginalDisplay})):Ci(n,(function(){e.style.display="none"}))):e.style.display=r?e.__vOriginalDisplay:"none")},unbind:function(e,t,n,r,o){o||(e.style.display=e.__vOriginalDisplay)}},Ri={model:$i,show:Mi},Fi={name:String,appear:Boolean,css:Boolean,mode:String,type:String,enterClass:String,leaveClass:String,enterToClass:String,leaveToClass:String,enterActiveClass:String,leaveActiveClass:String,appearClass:String,appearActiveClass:String,appearToClass:String,duration:[Number,String,Object]};function Hi(e){var t=e&&e.componentOptions;return t&&t.Ctor.options.abstract?Hi(jt(t.children)):e}function qi(e){var t={},n=e.$options;for(var r in n.propsData)t[r]=e[r];var o=n._parentListeners;for(var r in o)t[E(r)]=o[r];return t}function Bi(e,t){if(/\d-keep-alive$/.test(t.tag))return e("keep-alive",{props:t.componentOptions.propsData})}var Wi=function(e){return e.tag||bt(e)},Ui=function(e){return"show"===e.name},zi={name:"transition",props:Fi,abstract:!0,render:function(e){var t=this,n=this.$slots.default;if(n&&(n=n.filter(Wi)).length){0;var r=this.mode;0;var o=n[0];if(function(e){for(;e=e.parent;)if(e.data.transition)return!0}(this.$vnode))return o;var i=Hi(o);if(!i)return o;if(this._leaving)return Bi(e,o);var a="__transition-".concat(this._uid,"-");i.key=null==i.key?i.isComment?a+"comment":a+i.tag:l(i.key)?0===String(i.key).indexOf(a)?i.key:a+i.key:i.key;var s=(i.data||(i.data={})).transition=qi(this),c=this._vnode,u=Hi(c);if(i.data.directives&&i.data.directives.some(Ui)&&(i.data.show=!0),u&&u.data&&!function(e,t){return t.key===e.key&&t.tag===e.tag}(i,u)&&!bt(u)&&(!u.componentInstance||!u.componentInstance._vnode.isComment)){var f=u.data.transition=N({},s);if("out-in"===r)return this._leaving=!0,Je(f,"afterLeave",(function(){t._leaving=!1,t.$forceUpdate()})),Bi(e,o);if("in-out"===r){if(bt(i))return c;var d,p=function(){d()};Je(s,"afterEnter",p),Je(s,"enterCancelled",p),Je(f,"delayLeave",(function(e){d=e}))}}return o}}},Vi=N({tag:String,moveClass:String},Fi);delete Vi.mode;var Ji={props:Vi,beforeMount:function(){var e=this,t=this._update;this._update=function(n,r){var o=Rt(e);e.__patch__(e._vnode,e.kept,!1,!0),e._vnode=e.kept,o(),t.call(e,n,r)}},render:function(e){for(var t=this.tag||this.$vnode.data.tag||"span",n=Object.create(null),r=this.prevChildren=this.children,o=this.$slots.default||[],i=this.children=[],a=qi(this),s=0;s<o.length;s++){if((u=o[s]).tag)if(null!=u.key&&0!==String(u.key).indexOf("__vlist"))i.push(u),n[u.key]=u,(u.data||(u.data={})).transition=a;else;}if(r){var c=[],l=[];for(s=0;s<r.length;s++){var u;(u=r[s]).data.transition=a,u.data.pos=u.elm.getBoundingClientRect(),n[u.key]?c.push(u):l.push(u)}this.kept=e(t,null,c),this.removed=l}return e(t,null,i)},updated:function(){var e=this.prevChildren,t=this.moveClass||(this.name||"v")+"-move";e.length&&this.hasMove(e[0].elm,t)&&(e.forEach(Ki),e.forEach(Xi),e.forEach(Qi),this._reflow=document.body.offsetHeight,e.forEach((function(e){if(e.data.moved){var n=e.elm,r=n.style;vi(n,t),r.transform=r.WebkitTransform=r.transitionDuration="",n.addEventListener(ui,n._moveCb=function e(r){r&&r.target!==n||r&&!/transform$/.test(r.propertyName)||(n.removeEventListener(ui,e),n._moveCb=null,mi(n,t))})}})))},methods:{hasMove:function(e,t){if(!ai)return!1;if(this._hasMove)return this._hasMove;var n=e.cloneNode();e._transitionClasses&&e._transitionClasses.forEach((function(e){ri(n,e)})),ni(n,t),n.style.display="none",this.$el.appendChild(n);var r=bi(n);return this.$el.removeChild(n),this._hasMove=r.hasTransform}}};function Ki(e){e.elm._moveCb&&e.elm._moveCb(),e.elm._enterCb&&e.elm._enterCb()}function Xi(e){e.data.newPos=e.elm.getBoundingClientRect()}function Qi(e){var t=e.data.pos,n=e.data.newPos,r=t.left-n.left,o=t.top-n.top;if(r||o){e.data.moved=!0;var i=e.elm.style;i.transform=i.WebkitTransform="translate(".concat(r,"px,").concat(o,"px)"),i.transitionDuration="0s"}}var Gi={Transition:zi,TransitionGroup:Ji};rr.config.mustUseProp=hr,rr.config.isReservedTag=Or,rr.config.isReservedAttr=dr,rr.config.getTagNamespace=jr,rr.config.isUnknownElement=function(e){if(!G)return!0;if(Or(e))return!1;if(e=e.toLowerCase(),null!=Dr[e])return Dr[e];var t=document.createElement(e);return e.indexOf("-")>-1?Dr[e]=t.constructor===window.HTMLUnknownElement||t.constructor===window.HTMLElement:Dr[e]=/HTMLUnknownElement/.test(t.toString())},N(rr.options.directives,Ri),N(rr.options.components,Gi),rr.prototype.__patch__=G?Ei:P,rr.prototype.$mount=function(e,t){return function(e,t,n){var r;e.$el=t,e.$options.render||(e.$options.render=me),Bt(e,"beforeMount"),r=function(){e._update(e._render(),n)},new Cn(e,r,P,{before:function(){e._isMounted&&!e._isDestroyed&&Bt(e,"beforeUpdate")}},!0),n=!1;var o=e._preWatchers;if(o)for(var i=0;i<o.length;i++)o[i].run();return null==e.$vnode&&(e._isMounted=!0,Bt(e,"mounted")),e}(this,e=e&&G?Lr(e):void 0,t)},G&&setTimeout((function(){z.devtools&&le&&le.emit("init",rr)}),0);var Yi=/\{\{((?:.|\r?\n)+?)\}\}/g,Zi=/[-.*+?^${}()|[\]\/\\]/g,ea=k((function(e){var t=e[0].replace(Zi,"\\$&"),n=e[1].replace(Zi,"\\$&");return new RegExp(t+"((?:.|\\n)+?)"+n,"g")}));var ta={staticKeys:["staticClass"],transformNode:function(e,t){t.warn;var n=xo(e,"class");n&&(e.staticClass=JSON.stringify(n.replace(/\s+/g," ").trim()));var r=_o(e,"class",!1);r&&(e.classBinding=r)},genData:function(e){var t="";return e.staticClass&&(t+="staticClass:".concat(e.staticClass,",")),e.classBinding&&(t+="class:".concat(e.classBinding,",")),t}};var na,ra={staticKeys:["staticStyle"],transformNode:function(e,t){t.warn;var n=xo(e,"style");n&&(e.staticStyle=JSON.stringify(Uo(n)));var r=_o(e,"style",!1);r&&(e.styleBinding=r)},genData:function(e){var t="";return e.staticStyle&&(t+="staticStyle:".concat(e.staticStyle,",")),e.styleBinding&&(t+="style:(".concat(e.styleBinding,"),")),t}},oa=function(e){return(na=na||document.createElement("div")).innerHTML=e,na.textContent},ia=b("area,base,br,col,embed,frame,hr,img,input,isindex,keygen,link,meta,param,source,track,wbr"),aa=b("colgroup,dd,dt,li,options,p,td,tfoot,th,thead,tr,source"),sa=b("address,article,aside,base,blockquote,body,caption,col,colgroup,dd,details,dialog,div,dl,dt,fieldset,figcaption,figure,footer,form,h1,h2,h3,h4,h5,h6,head,header,hgroup,hr,html,legend,li,menuitem,meta,optgroup,option,param,rp,rt,source,style,summary,tbody,td,tfoot,th,thead,title,tr,track"),ca=/^\s*([^\s"'<>\/=]+)(?:\s*(=)\s*(?:"([^"]*)"+|'([^']*)'+|([^\s"'=<>`]+)))?/,la=/^\s*((?:v-[\w-]+:|@|:|#)\[[^=]+?\][^\s"'<>\/=]*)(?:\s*(=)\s*(?:"([^"]*)"+|'([^']*)'+|([^\s"'=<>`]+)))?/,ua="[a-zA-Z_][\\-\\.0-9_a-zA-Z".concat(V.source,"]*"),fa="((?:".concat(ua,"\\:)?").concat(ua,")"),da=new RegExp("^<".concat(fa)),pa=/^\s*(\/?)>/,ha=new RegExp("^<\\/".concat(fa,"[^>]*>")),va=/^<!DOCTYPE [^>]+>/i,ma=/^<!\--/,ga=/^<!\[/,ya=b("script,style,textarea",!0),ba={},_a={"&lt;":"<","&gt;":">","&quot;":'"',"&amp;":"&","&#10;":"\n","&#9;":"\t","&#39;":"'"},xa=/&(?:lt|gt|quot|amp|#39);/g,wa=/&(?:lt|gt|quot|amp|#39|#10|#9);/g,Ca=b("pre,textarea",!0),Ta=function(e,t){return e&&Ca(e)&&"\n"===t[0]};function ka(e,t){var n=t?wa:xa;return e.replace(n,(function(e){return _a[e]}))}function Sa(e,t){for(var n,r,o=[],i=t.expectHTML,a=t.isUnaryTag||I,s=t.canBeLeftOpenTag||I,c=0,l=function(){if(n=e,r&&ya(r)){var l=0,d=r.toLowerCase(),p=ba[d]||(ba[d]=new RegExp("([\\s\\S]*?)(</"+d+"[^>]*>)","i"));w=e.replace(p,(function(e,n,r){return l=r.length,ya(d)||"noscript"===d||(n=n.replace(/<!\--([\s\S]*?)-->/g,"$1").replace(/<!\[CDATA\[([\s\S]*?)]]>/g,"$1")),Ta(d,n)&&(n=n.slice(1)),t.chars&&t.chars(n),""}));c+=e.length-w.length,e=w,f(d,c-l,c)}else{var h=e.indexOf("<");if(0===h){if(ma.test(e)){var v=e.indexOf("--\x3e");if(v>=0)return t.shouldKeepComment&&t.comment&&t.comment(e.substring(4,v),c,c+v+3),u(v+3),"continue"}if(ga.test(e)){var m=e.indexOf("]>");if(m>=0)return u(m+2),"continue"}var g=e.match(va);if(g)return u(g[0].length),"continue";var y=e.match(ha);if(y){var b=c;return u(y[0].length),f(y[1],b,c),"continue"}var _=function(){var t=e.match(da);if(t){var n={tagName:t[1],attrs:[],start:c};u(t[0].length);for(var r=void 0,o=void 0;!(r=e.match(pa))&&(o=e.match(la)||e.match(ca));)o.start=c,u(o[0].length),o.end=c,n.attrs.push(o);if(r)return n.unarySlash=r[1],u(r[0].length),n.end=c,n}}();if(_)return function(e){var n=e.tagName,c=e.unarySlash;i&&("p"===r&&sa(n)&&f(r),s(n)&&r===n&&f(n));for(var l=a(n)||!!c,u=e.attrs.length,d=new Array(u),p=0;p<u;p++){var h=e.attrs[p],v=h[3]||h[4]||h[5]||"",m="a"===n&&"href"===h[1]?t.shouldDecodeNewlinesForHref:t.shouldDecodeNewlines;d[p]={name:h[1],value:ka(v,m)}}l||(o.push({tag:n,lowerCasedTag:n.toLowerCase(),attrs:d,start:e.start,end:e.end}),r=n);t.start&&t.start(n,d,l,e.start,e.end)}(_),Ta(_.tagName,e)&&u(1),"continue"}var x=void 0,w=void 0,C=void 0;if(h>=0){for(w=e.slice(h);!(ha.test(w)||da.test(w)||ma.test(w)||ga.test(w)||(C=w.indexOf("<",1))<0);)h+=C,w=e.slice(h);x=e.substring(0,h)}h<0&&(x=e),x&&u(x.length),t.chars&&x&&t.chars(x,c-x.length,c)}if(e===n)return t.chars&&t.chars(e),"break"};e;){if("break"===l())break}function u(t){c+=t,e=e.substring(t)}function f(e,n,i){var a,s;if(null==n&&(n=c),null==i&&(i=c),e)for(s=e.toLowerCase(),a=o.length-1;a>=0&&o[a].lowerCasedTag!==s;a--);else a=0;if(a>=0){for(var l=o.length-1;l>=a;l--)t.end&&t.end(o[l].tag,n,i);o.length=a,r=a&&o[a-1].tag}else"br"===s?t.start&&t.start(e,[],!0,n,i):"p"===s&&(t.start&&t.start(e,[],!1,n,i),t.end&&t.end(e,n,i))}f()}var Ea,$a,Aa,Oa,ja,Da,Na,La,Pa=/^@|^v-on:/,Ia=/^v-|^@|^:|^#/,Ma=/([\s\S]*?)\s+(?:in|of)\s+([\s\S]*)/,Ra=/,([^,\}\]]*)(?:,([^,\}\]]*))?$/,Fa=/^\(|\)$/g,Ha=/^\[.*\]$/,qa=/:(.*)$/,Ba=/^:|^\.|^v-bind:/,Wa=/\.[^.\]]+(?=[^\]]*$)/g,Ua=/^v-slot(:|$)|^#/,za=/[\r\n]/,Va=/[ \f\t\r\n]+/g,Ja=k(oa),Ka="_empty_";function Xa(e,t,n){return{type:1,tag:e,attrsList:t,attrsMap:ns(t),rawAttrsMap:{},parent:n,children:[]}}function Qa(e,t){Ea=t.warn||fo,Da=t.isPreTag||I,Na=t.mustUseProp||I,La=t.getTagNamespace||I;var n=t.isReservedTag||I;(function(e){return!(!(e.component||e.attrsMap[":is"]||e.attrsMap["v-bind:is"])&&(e.attrsMap.is?n(e.attrsMap.is):n(e.tag)))}),Aa=po(t.modules,"transformNode"),Oa=po(t.modules,"preTransformNode"),ja=po(t.modules,"postTransformNode"),$a=t.delimiters;var r,o,i=[],a=!1!==t.preserveWhitespace,s=t.whitespace,c=!1,l=!1;function u(e){if(f(e),c||e.processed||(e=Ga(e,t)),i.length||e===r||r.if&&(e.elseif||e.else)&&Za(r,{exp:e.elseif,block:e}),o&&!e.forbidden)if(e.elseif||e.else)a=e,s=function(e){for(var t=e.length;t--;){if(1===e[t].type)return e[t];e.pop()}}(o.children),s&&s.if&&Za(s,{exp:a.elseif,block:a});else{if(e.slotScope){var n=e.slotTarget||'"default"';(o.scopedSlots||(o.scopedSlots={}))[n]=e}o.children.push(e),e.parent=o}var a,s;e.children=e.children.filter((function(e){return!e.slotScope})),f(e),e.pre&&(c=!1),Da(e.tag)&&(l=!1);for(var u=0;u<ja.length;u++)ja[u](e,t)}function f(e){if(!l)for(var t=void 0;(t=e.children[e.children.length-1])&&3===t.type&&" "===t.text;)e.children.pop()}return Sa(e,{warn:Ea,expectHTML:t.expectHTML,isUnaryTag:t.isUnaryTag,canBeLeftOpenTag:t.canBeLeftOpenTag,shouldDecodeNewlines:t.shouldDecodeNewlines,shouldDecodeNewlinesForHref:t.shouldDecodeNewlinesForHref,shouldKeepComment:t.comments,outputSourceRange:t.outputSourceRange,start:function(e,n,a,s,f){var d=o&&o.ns||La(e);Z&&"svg"===d&&(n=function(e){for(var t=[],n=0;n<e.length;n++){var r=e[n];rs.test(r.name)||(r.name=r.name.replace(os,""),t.push(r))}return t}(n));var p,h=Xa(e,n,o);d&&(h.ns=d),"style"!==(p=h).tag&&("script"!==p.tag||p.attrsMap.type&&"text/javascript"!==p.attrsMap.type)||ce()||(h.forbidden=!0);for(var v=0;v<Oa.length;v++)h=Oa[v](h,t)||h;c||(!function(e){null!=xo(e,"v-pre")&&(e.pre=!0)}(h),h.pre&&(c=!0)),Da(h.tag)&&(l=!0),c?function(e){var t=e.attrsList,n=t.length;if(n)for(var r=e.attrs=new Array(n),o=0;o<n;o++)r[o]={name:t[o].name,value:JSON.stringify(t[o].value)},null!=t[o].start&&(r[o].start=t[o].start,r[o].end=t[o].end);else e.pre||(e.plain=!0)}(h):h.processed||(Ya(h),function(e){var t=xo(e,"v-if");if(t)e.if=t,Za(e,{exp:t,block:e});else{null!=xo(e,"v-else")&&(e.else=!0);var n=xo(e,"v-else-if");n&&(e.elseif=n)}}(h),function(e){var t=xo(e,"v-once");null!=t&&(e.once=!0)}(h)),r||(r=h),a?u(h):(o=h,i.push(h))},end:function(e,t,n){var r=i[i.length-1];i.length-=1,o=i[i.length-1],u(r)},chars:function(e,t,n){if(o&&(!Z||"textarea"!==o.tag||o.attrsMap.placeholder!==e)){var r,i=o.children;if(e=l||e.trim()?"script"===(r=o).tag||"style"===r.tag?e:Ja(e):i.length?s?"condense"===s&&za.test(e)?"":" ":a?" ":"":""){l||"condense"!==s||(e=e.replace(Va," "));var u=void 0,f=void 0;!c&&" "!==e&&(u=function(e,t){var n=t?ea(t):Yi;if(n.test(e)){for(var r,o,i,a=[],s=[],c=n.lastIndex=0;r=n.exec(e);){(o=r.index)>c&&(s.push(i=e.slice(c,o)),a.push(JSON.stringify(i)));var l=lo(r[1].trim());a.push("_s(".concat(l,")")),s.push({"@binding":l}),c=o+r[0].length}return c<e.length&&(s.push(i=e.slice(c)),a.push(JSON.stringify(i))),{expression:a.join("+"),tokens:s}}}(e,$a))?f={type:2,expression:u.expression,tokens:u.tokens,text:e}:" "===e&&i.length&&" "===i[i.length-1].text||(f={type:3,text:e}),f&&i.push(f)}}},comment:function(e,t,n){if(o){var r={type:3,text:e,isComment:!0};0,o.children.push(r)}}}),r}function Ga(e,t){var n;!function(e){var t=_o(e,"key");if(t){e.key=t}}(e),e.plain=!e.key&&!e.scopedSlots&&!e.attrsList.length,function(e){var t=_o(e,"ref");t&&(e.ref=t,e.refInFor=function(e){var t=e;for(;t;){if(void 0!==t.for)return!0;t=t.parent}return!1}(e))}(e),function(e){var t;"template"===e.tag?(t=xo(e,"scope"),e.slotScope=t||xo(e,"slot-scope")):(t=xo(e,"slot-scope"))&&(e.slotScope=t);var n=_o(e,"slot");n&&(e.slotTarget='""'===n?'"default"':n,e.slotTargetDynamic=!(!e.attrsMap[":slot"]&&!e.attrsMap["v-bind:slot"]),"template"===e.tag||e.slotScope||vo(e,"slot",n,function(e,t){return e.rawAttrsMap[":"+t]||e.rawAttrsMap["v-bind:"+t]||e.rawAttrsMap[t]}(e,"slot")));if("template"===e.tag){if(a=wo(e,Ua)){0;var r=es(a),o=r.name,i=r.dynamic;e.slotTarget=o,e.slotTargetDynamic=i,e.slotScope=a.value||Ka}}else{var a;if(a=wo(e,Ua)){0;var s=e.scopedSlots||(e.scopedSlots={}),c=es(a),l=c.name,u=(i=c.dynamic,s[l]=Xa("template",[],e));u.slotTarget=l,u.slotTargetDynamic=i,u.children=e.children.filter((function(e){if(!e.slotScope)return e.parent=u,!0})),u.slotScope=a.value||Ka,e.children=[],e.plain=!1}}}(e),"slot"===(n=e).tag&&(n.slotName=_o(n,"name")),function(e){var t;(t=_o(e,"is"))&&(e.component=t);null!=xo(e,"inline-template")&&(e.inlineTemplate=!0)}(e);for(var r=0;r<Aa.length;r++)e=Aa[r](e,t)||e;return function(e){var t,n,r,o,i,a,s,c,l=e.attrsList;for(t=0,n=l.length;t<n;t++){if(r=o=l[t].name,i=l[t].value,Ia.test(r))if(e.hasBindings=!0,(a=ts(r.replace(Ia,"")))&&(r=r.replace(Wa,"")),Ba.test(r))r=r.replace(Ba,""),i=lo(i),(c=Ha.test(r))&&(r=r.slice(1,-1)),a&&(a.prop&&!c&&"innerHtml"===(r=E(r))&&(r="innerHTML"),a.camel&&!c&&(r=E(r)),a.sync&&(s=ko(i,"$event"),c?bo(e,'"update:"+('.concat(r,")"),s,null,!1,0,l[t],!0):(bo(e,"update:".concat(E(r)),s,null,!1,0,l[t]),O(r)!==E(r)&&bo(e,"update:".concat(O(r)),s,null,!1,0,l[t])))),a&&a.prop||!e.component&&Na(e.tag,e.attrsMap.type,r)?ho(e,r,i,l[t],c):vo(e,r,i,l[t],c);else if(Pa.test(r))r=r.replace(Pa,""),(c=Ha.test(r))&&(r=r.slice(1,-1)),bo(e,r,i,a,!1,0,l[t],c);else{var u=(r=r.replace(Ia,"")).match(qa),f=u&&u[1];c=!1,f&&(r=r.slice(0,-(f.length+1)),Ha.test(f)&&(f=f.slice(1,-1),c=!0)),go(e,r,o,i,f,c,a,l[t])}else vo(e,r,JSON.stringify(i),l[t]),!e.component&&"muted"===r&&Na(e.tag,e.attrsMap.type,r)&&ho(e,r,"true",l[t])}}(e),e}function Ya(e){var t;if(t=xo(e,"v-for")){var n=function(e){var t=e.match(Ma);if(!t)return;var n={};n.for=t[2].trim();var r=t[1].trim().replace(Fa,""),o=r.match(Ra);o?(n.alias=r.replace(Ra,"").trim(),n.iterator1=o[1].trim(),o[2]&&(n.iterator2=o[2].trim())):n.alias=r;return n}(t);n&&N(e,n)}}function Za(e,t){e.ifConditions||(e.ifConditions=[]),e.ifConditions.push(t)}function es(e){var t=e.name.replace(Ua,"");return t||"#"!==e.name[0]&&(t="default"),Ha.test(t)?{name:t.slice(1,-1),dynamic:!0}:{name:'"'.concat(t,'"'),dynamic:!1}}function ts(e){var t=e.match(Wa);if(t){var n={};return t.forEach((function(e){n[e.slice(1)]=!0})),n}}function ns(e){for(var t={},n=0,r=e.length;n<r;n++)t[e[n].name]=e[n].value;return t}var rs=/^xmlns:NS\d+/,os=/^NS\d+:/;function is(e){return Xa(e.tag,e.attrsList.slice(),e.parent)}var as=[ta,ra,{preTransformNode:function(e,t){if("input"===e.tag){var n=e.attrsMap;if(!n["v-model"])return;var r=void 0;if((n[":type"]||n["v-bind:type"])&&(r=_o(e,"type")),n.type||r||!n["v-bind"]||(r="(".concat(n["v-bind"],").type")),r){var o=xo(e,"v-if",!0),i=o?"&&(".concat(o,")"):"",a=null!=xo(e,"v-else",!0),s=xo(e,"v-else-if",!0),c=is(e);Ya(c),mo(c,"type","checkbox"),Ga(c,t),c.processed=!0,c.if="(".concat(r,")==='checkbox'")+i,Za(c,{exp:c.if,block:c});var l=is(e);xo(l,"v-for",!0),mo(l,"type","radio"),Ga(l,t),Za(c,{exp:"(".concat(r,")==='radio'")+i,block:l});var u=is(e);return xo(u,"v-for",!0),mo(u,":type",r),Ga(u,t),Za(c,{exp:o,block:u}),a?c.else=!0:s&&(c.elseif=s),c}}}}];var ss,cs,ls={model:function(e,t,n){n;var r=t.value,o=t.modifiers,i=e.tag,a=e.attrsMap.type;if(e.component)return To(e,r,o),!1;if("select"===i)!function(e,t,n){var r=n&&n.number,o='Array.prototype.filter.call($event.target.options,function(o){return o.selected}).map(function(o){var val = "_value" in o ? o._value : o.value;'+"return ".concat(r?"_n(val)":"val","})"),i="$event.target.multiple ? $$selectedVal : $$selectedVal[0]",a="var $$selectedVal = ".concat(o,";");a="".concat(a," ").concat(ko(t,i)),bo(e,"change",a,null,!0)}(e,r,o);else if("input"===i&&"checkbox"===a)!function(e,t,n){var r=n&&n.number,o=_o(e,"value")||"null",i=_o(e,"true-value")||"true",a=_o(e,"false-value")||"false";ho(e,"checked","Array.isArray(".concat(t,")")+"?_i(".concat(t,",").concat(o,")>-1")+("true"===i?":(".concat(t,")"):":_q(".concat(t,",").concat(i,")"))),bo(e,"change","var $$a=".concat(t,",")+"$$el=$event.target,"+"$$c=$$el.checked?(".concat(i,"):(").concat(a,");")+"if(Array.isArray($$a)){"+"var $$v=".concat(r?"_n("+o+")":o,",")+"$$i=_i($$a,$$v);"+"if($$el.checked){$$i<0&&(".concat(ko(t,"$$a.concat([$$v])"),")}")+"else{$$i>-1&&(".concat(ko(t,"$$a.slice(0,$$i).concat($$a.slice($$i+1))"),")}")+"}else{".concat(ko(t,"$$c"),"}"),null,!0)}(e,r,o);else if("input"===i&&"radio"===a)!function(e,t,n){var r=n&&n.number,o=_o(e,"value")||"null";o=r?"_n(".concat(o,")"):o,ho(e,"checked","_q(".concat(t,",").concat(o,")")),bo(e,"change",ko(t,o),null,!0)}(e,r,o);else if("input"===i||"textarea"===i)!function(e,t,n){var r=e.attrsMap.type;0;var o=n||{},i=o.lazy,a=o.number,s=o.trim,c=!i&&"range"!==r,l=i?"change":"range"===r?Do:"input",u="$event.target.value";s&&(u="$event.target.value.trim()");a&&(u="_n(".concat(u,")"));var f=ko(t,u);c&&(f="if($event.target.composing)return;".concat(f));ho(e,"value","(".concat(t,")")),bo(e,l,f,null,!0),(s||a)&&bo(e,"blur","$forceUpdate()")}(e,r,o);else{if(!z.isReservedTag(i))return To(e,r,o),!1}return!0},text:function(e,t){t.value&&ho(e,"textContent","_s(".concat(t.value,")"),t)},html:function(e,t){t.value&&ho(e,"innerHTML","_s(".concat(t.value,")"),t)}},us={expectHTML:!0,modules:as,directives:ls,isPreTag:function(e){return"pre"===e},isUnaryTag:ia,mustUseProp:hr,canBeLeftOpenTag:aa,isReservedTag:Or,getTagNamespace:jr,staticKeys:function(e){return e.reduce((function(e,t){return e.concat(t.staticKeys||[])}),[]).join(",")}(as)},fs=k((function(e){return b("type,tag,attrsList,attrsMap,plain,parent,children,attrs,start,end,rawAttrsMap"+(e?","+e:""))}));function ds(e,t){e&&(ss=fs(t.staticKeys||""),cs=t.isReservedTag||I,ps(e),hs(e,!1))}function ps(e){if(e.static=function(e){if(2===e.type)return!1;if(3===e.type)return!0;return!(!e.pre&&(e.hasBindings||e.if||e.for||_(e.tag)||!cs(e.tag)||function(e){for(;e.parent;){if("template"!==(e=e.parent).tag)return!1;if(e.for)return!0}return!1}(e)||!Object.keys(e).every(ss)))}(e),1===e.type){if(!cs(e.tag)&&"slot"!==e.tag&&null==e.attrsMap["inline-template"])return;for(var t=0,n=e.children.length;t<n;t++){var r=e.children[t];ps(r),r.static||(e.static=!1)}if(e.ifConditions)for(t=1,n=e.ifConditions.length;t<n;t++){var o=e.ifConditions[t].block;ps(o),o.static||(e.static=!1)}}}function hs(e,t){if(1===e.type){if((e.static||e.once)&&(e.staticInFor=t),e.static&&e.children.length&&(1!==e.children.length||3!==e.children[0].type))return void(e.staticRoot=!0);if(e.staticRoot=!1,e.children)for(var n=0,r=e.children.length;n<r;n++)hs(e.children[n],t||!!e.for);if(e.ifConditions)for(n=1,r=e.ifConditions.length;n<r;n++)hs(e.ifConditions[n].block,t)}}var vs=/^([\w$_]+|\([^)]*?\))\s*=>|^function(?:\s+[\w$]+)?\s*\(/,ms=/\([^)]*?\);*$/,gs=/^[A-Za-z_$][\w$]*(?:\.[A-Za-z_$][\w$]*|\['[^']*?']|\["[^"]*?"]|\[\d+]|\[[A-Za-z_$][\w$]*])*$/,ys={esc:27,tab:9,enter:13,space:32,up:38,left:37,right:39,down:40,delete:[8,46]},bs={esc:["Esc","Escape"],tab:"Tab",enter:"Enter",space:[" ","Spacebar"],up:["Up","ArrowUp"],left:["Left","ArrowLeft"],right:["Right","ArrowRight"],down:["Down","ArrowDown"],delete:["Backspace","Delete","Del"]},_s=function(e){return"if(".concat(e,")return null;")},xs={stop:"$event.stopPropagation();",prevent:"$event.preventDefault();",self:_s("$event.target !== $event.currentTarget"),ctrl:_s("!$event.ctrlKey"),shift:_s("!$event.shiftKey"),alt:_s("!$event.altKey"),meta:_s("!$event.metaKey"),left:_s("'button' in $event && $event.button !== 0"),middle:_s("'button' in $event && $event.button !== 1"),right:_s("'button' in $event && $event.button !== 2")};function ws(e,t){var n=t?"nativeOn:":"on:",r="",o="";for(var i in e){var a=Cs(e[i]);e[i]&&e[i].dynamic?o+="".concat(i,",").concat(a,","):r+='"'.concat(i,'":').concat(a,",")}return r="{".concat(r.slice(0,-1),"}"),o?n+"_d(".concat(r,",[").concat(o.slice(0,-1),"])"):n+r}function Cs(e){if(!e)return"function(){}";if(Array.isArray(e))return"[".concat(e.map((function(e){return Cs(e)})).join(","),"]");var t=gs.test(e.value),n=vs.test(e.value),r=gs.test(e.value.replace(ms,""));if(e.modifiers){var o="",i="",a=[],s=function(t){if(xs[t])i+=xs[t],ys[t]&&a.push(t);else if("exact"===t){var n=e.modifiers;i+=_s(["ctrl","shift","alt","meta"].filter((function(e){return!n[e]})).map((function(e){return"$event.".concat(e,"Key")})).join("||"))}else a.push(t)};for(var c in e.modifiers)s(c);a.length&&(o+=function(e){return"if(!$event.type.indexOf('key')&&"+"".concat(e.map(Ts).join("&&"),")return null;")}(a)),i&&(o+=i);var l=t?"return ".concat(e.value,".apply(null, arguments)"):n?"return (".concat(e.value,").apply(null, arguments)"):r?"return ".concat(e.value):e.value;return"function($event){".concat(o).concat(l,"}")}return t||n?e.value:"function($event){".concat(r?"return ".concat(e.value):e.value,"}")}function Ts(e){var t=parseInt(e,10);if(t)return"$event.keyCode!==".concat(t);var n=ys[e],r=bs[e];return"_k($event.keyCode,"+"".concat(JSON.stringify(e),",")+"".concat(JSON.stringify(n),",")+"$event.key,"+"".concat(JSON.stringify(r))+")"}var ks={on:function(e,t){e.wrapListeners=function(e){return"_g(".concat(e,",").concat(t.value,")")}},bind:function(e,t){e.wrapData=function(n){return"_b(".concat(n,",'").concat(e.tag,"',").concat(t.value,",").concat(t.modifiers&&t.modifiers.prop?"true":"false").concat(t.modifiers&&t.modifiers.sync?",true":"",")")}},cloak:P},Ss=function(e){this.options=e,this.warn=e.warn||fo,this.transforms=po(e.modules,"transformCode"),this.dataGenFns=po(e.modules,"genData"),this.directives=N(N({},ks),e.directives);var t=e.isReservedTag||I;this.maybeComponent=function(e){return!!e.component||!t(e.tag)},this.onceId=0,this.staticRenderFns=[],this.pre=!1};function Es(e,t){var n=new Ss(t),r=e?"script"===e.tag?"null":$s(e,n):'_c("div")';return{render:"with(this){return ".concat(r,"}"),staticRenderFns:n.staticRenderFns}}function $s(e,t){if(e.parent&&(e.pre=e.pre||e.parent.pre),e.staticRoot&&!e.staticProcessed)return As(e,t);if(e.once&&!e.onceProcessed)return Os(e,t);if(e.for&&!e.forProcessed)return Ns(e,t);if(e.if&&!e.ifProcessed)return js(e,t);if("template"!==e.tag||e.slotTarget||t.pre){if("slot"===e.tag)return function(e,t){var n=e.slotName||'"default"',r=Ms(e,t),o="_t(".concat(n).concat(r?",function(){return ".concat(r,"}"):""),i=e.attrs||e.dynamicAttrs?Hs((e.attrs||[]).concat(e.dynamicAttrs||[]).map((function(e){return{name:E(e.name),value:e.value,dynamic:e.dynamic}}))):null,a=e.attrsMap["v-bind"];!i&&!a||r||(o+=",null");i&&(o+=",".concat(i));a&&(o+="".concat(i?"":",null",",").concat(a));return o+")"}(e,t);var n=void 0;if(e.component)n=function(e,t,n){var r=t.inlineTemplate?null:Ms(t,n,!0);return"_c(".concat(e,",").concat(Ls(t,n)).concat(r?",".concat(r):"",")")}(e.component,e,t);else{var r=void 0,o=t.maybeComponent(e);(!e.plain||e.pre&&o)&&(r=Ls(e,t));var i=void 0,a=t.options.bindings;o&&a&&!1!==a.__isScriptSetup&&(i=function(e,t){var n=E(t),r=$(n),o=function(o){return e[t]===o?t:e[n]===o?n:e[r]===o?r:void 0},i=o("setup-const")||o("setup-reactive-const");if(i)return i;var a=o("setup-let")||o("setup-ref")||o("setup-maybe-ref");if(a)return a}(a,e.tag)),i||(i="'".concat(e.tag,"'"));var s=e.inlineTemplate?null:Ms(e,t,!0);n="_c(".concat(i).concat(r?",".concat(r):"").concat(s?",".concat(s):"",")")}for(var c=0;c<t.transforms.length;c++)n=t.transforms[c](e,n);return n}return Ms(e,t)||"void 0"}function As(e,t){e.staticProcessed=!0;var n=t.pre;return e.pre&&(t.pre=e.pre),t.staticRenderFns.push("with(this){return ".concat($s(e,t),"}")),t.pre=n,"_m(".concat(t.staticRenderFns.length-1).concat(e.staticInFor?",true":"",")")}function Os(e,t){if(e.onceProcessed=!0,e.if&&!e.ifProcessed)return js(e,t);if(e.staticInFor){for(var n="",r=e.parent;r;){if(r.for){n=r.key;break}r=r.parent}return n?"_o(".concat($s(e,t),",").concat(t.onceId++,",").concat(n,")"):$s(e,t)}return As(e,t)}function js(e,t,n,r){return e.ifProcessed=!0,Ds(e.ifConditions.slice(),t,n,r)}function Ds(e,t,n,r){if(!e.length)return r||"_e()";var o=e.shift();return o.exp?"(".concat(o.exp,")?").concat(i(o.block),":").concat(Ds(e,t,n,r)):"".concat(i(o.block));function i(e){return n?n(e,t):e.once?Os(e,t):$s(e,t)}}function Ns(e,t,n,r){var o=e.for,i=e.alias,a=e.iterator1?",".concat(e.iterator1):"",s=e.iterator2?",".concat(e.iterator2):"";return e.forProcessed=!0,"".concat(r||"_l","((").concat(o,"),")+"function(".concat(i).concat(a).concat(s,"){")+"return ".concat((n||$s)(e,t))+"})"}function Ls(e,t){var n="{",r=function(e,t){var n=e.directives;if(!n)return;var r,o,i,a,s="directives:[",c=!1;for(r=0,o=n.length;r<o;r++){i=n[r],a=!0;var l=t.directives[i.name];l&&(a=!!l(e,i,t.warn)),a&&(c=!0,s+='{name:"'.concat(i.name,'",rawName:"').concat(i.rawName,'"').concat(i.value?",value:(".concat(i.value,"),expression:").concat(JSON.stringify(i.value)):"").concat(i.arg?",arg:".concat(i.isDynamicArg?i.arg:'"'.concat(i.arg,'"')):"").concat(i.modifiers?",modifiers:".concat(JSON.stringify(i.modifiers)):"","},"))}if(c)return s.slice(0,-1)+"]"}(e,t);r&&(n+=r+","),e.key&&(n+="key:".concat(e.key,",")),e.ref&&(n+="ref:".concat(e.ref,",")),e.refInFor&&(n+="refInFor:true,"),e.pre&&(n+="pre:true,"),e.component&&(n+='tag:"'.concat(e.tag,'",'));for(var o=0;o<t.dataGenFns.length;o++)n+=t.dataGenFns[o](e);if(e.attrs&&(n+="attrs:".concat(Hs(e.attrs),",")),e.props&&(n+="domProps:".concat(Hs(e.props),",")),e.events&&(n+="".concat(ws(e.events,!1),",")),e.nativeEvents&&(n+="".concat(ws(e.nativeEvents,!0),",")),e.slotTarget&&!e.slotScope&&(n+="slot:".concat(e.slotTarget,",")),e.scopedSlots&&(n+="".concat(function(e,t,n){var r=e.for||Object.keys(t).some((function(e){var n=t[e];return n.slotTargetDynamic||n.if||n.for||Ps(n)})),o=!!e.if;if(!r)for(var i=e.parent;i;){if(i.slotScope&&i.slotScope!==Ka||i.for){r=!0;break}i.if&&(o=!0),i=i.parent}var a=Object.keys(t).map((function(e){return Is(t[e],n)})).join(",");return"scopedSlots:_u([".concat(a,"]").concat(r?",null,true":"").concat(!r&&o?",null,false,".concat(function(e){var t=5381,n=e.length;for(;n;)t=33*t^e.charCodeAt(--n);return t>>>0}(a)):"",")")}(e,e.scopedSlots,t),",")),e.model&&(n+="model:{value:".concat(e.model.value,",callback:").concat(e.model.callback,",expression:").concat(e.model.expression,"},")),e.inlineTemplate){var i=function(e,t){var n=e.children[0];0;if(n&&1===n.type){var r=Es(n,t.options);return"inlineTemplate:{render:function(){".concat(r.render,"},staticRenderFns:[").concat(r.staticRenderFns.map((function(e){return"function(){".concat(e,"}")})).join(","),"]}")}}(e,t);i&&(n+="".concat(i,","))}return n=n.replace(/,$/,"")+"}",e.dynamicAttrs&&(n="_b(".concat(n,',"').concat(e.tag,'",').concat(Hs(e.dynamicAttrs),")")),e.wrapData&&(n=e.wrapData(n)),e.wrapListeners&&(n=e.wrapListeners(n)),n}function Ps(e){return 1===e.type&&("slot"===e.tag||e.children.some(Ps))}function Is(e,t){var n=e.attrsMap["slot-scope"];if(e.if&&!e.ifProcessed&&!n)return js(e,t,Is,"null");if(e.for&&!e.forProcessed)return Ns(e,t,Is);var r=e.slotScope===Ka?"":String(e.slotScope),o="function(".concat(r,"){")+"return ".concat("template"===e.tag?e.if&&n?"(".concat(e.if,")?").concat(Ms(e,t)||"undefined",":undefined"):Ms(e,t)||"undefined":$s(e,t),"}"),i=r?"":",proxy:true";return"{key:".concat(e.slotTarget||'"default"',",fn:").concat(o).concat(i,"}")}function Ms(e,t,n,r,o){var i=e.children;if(i.length){var a=i[0];if(1===i.length&&a.for&&"template"!==a.tag&&"slot"!==a.tag){var s=n?t.maybeComponent(a)?",1":",0":"";return"".concat((r||$s)(a,t)).concat(s)}var c=n?function(e,t){for(var n=0,r=0;r<e.length;r++){var o=e[r];if(1===o.type){if(Rs(o)||o.ifConditions&&o.ifConditions.some((function(e){return Rs(e.block)}))){n=2;break}(t(o)||o.ifConditions&&o.ifConditions.some((function(e){return t(e.block)})))&&(n=1)}}return n}(i,t.maybeComponent):0,l=o||Fs;return"[".concat(i.map((function(e){return l(e,t)})).join(","),"]").concat(c?",".concat(c):"")}}function Rs(e){return void 0!==e.for||"template"===e.tag||"slot"===e.tag}function Fs(e,t){return 1===e.type?$s(e,t):3===e.type&&e.isComment?function(e){return"_e(".concat(JSON.stringify(e.text),")")}(e):"_v(".concat(2===(n=e).type?n.expression:qs(JSON.stringify(n.text)),")");var n}function Hs(e){for(var t="",n="",r=0;r<e.length;r++){var o=e[r],i=qs(o.value);o.dynamic?n+="".concat(o.name,",").concat(i,","):t+='"'.concat(o.name,'":').concat(i,",")}return t="{".concat(t.slice(0,-1),"}"),n?"_d(".concat(t,",[").concat(n.slice(0,-1),"])"):t}function qs(e){return e.replace(/\u2028/g,"\\u2028").replace(/\u2029/g,"\\u2029")}new RegExp("\\b"+"do,if,for,let,new,try,var,case,else,with,await,break,catch,class,const,super,throw,while,yield,delete,export,import,return,switch,default,extends,finally,continue,debugger,function,arguments".split(",").join("\\b|\\b")+"\\b"),new RegExp("\\b"+"delete,typeof,void".split(",").join("\\s*\\([^\\)]*\\)|\\b")+"\\s*\\([^\\)]*\\)");function Bs(e,t){try{return new Function(e)}catch(n){return t.push({err:n,code:e}),P}}function Ws(e){var t=Object.create(null);return function(n,r,o){(r=N({},r)).warn;delete r.warn;var i=r.delimiters?String(r.delimiters)+n:n;if(t[i])return t[i];var a=e(n,r);var s={},c=[];return s.render=Bs(a.render,c),s.staticRenderFns=a.staticRenderFns.map((function(e){return Bs(e,c)})),t[i]=s}}var Us,zs,Vs=(Us=function(e,t){var n=Qa(e.trim(),t);!1!==t.optimize&&ds(n,t);var r=Es(n,t);return{ast:n,render:r.render,staticRenderFns:r.staticRenderFns}},function(e){function t(t,n){var r=Object.create(e),o=[],i=[];if(n)for(var a in n.modules&&(r.modules=(e.modules||[]).concat(n.modules)),n.directives&&(r.directives=N(Object.create(e.directives||null),n.directives)),n)"modules"!==a&&"directives"!==a&&(r[a]=n[a]);r.warn=function(e,t,n){(n?i:o).push(e)};var s=Us(t.trim(),r);return s.errors=o,s.tips=i,s}return{compile:t,compileToFunctions:Ws(t)}}),Js=Vs(us).compileToFunctions;function Ks(e){return(zs=zs||document.createElement("div")).innerHTML=e?'<a href="\n"/>':'<div a="\n"/>',zs.innerHTML.indexOf("&#10;")>0}var Xs=!!G&&Ks(!1),Qs=!!G&&Ks(!0),Gs=k((function(e){var t=Lr(e);return t&&t.innerHTML})),Ys=rr.prototype.$mount;rr.prototype.$mount=function(e,t){if((e=e&&Lr(e))===document.body||e===document.documentElement)return this;var n=this.$options;if(!n.render){var r=n.template;if(r)if("string"==typeof r)"#"===r.charAt(0)&&(r=Gs(r));else{if(!r.nodeType)return this;r=r.innerHTML}else e&&(r=function(e){if(e.outerHTML)return e.outerHTML;var t=document.createElement("div");return t.appendChild(e.cloneNode(!0)),t.innerHTML}(e));if(r){0;var o=Js(r,{outputSourceRange:!1,shouldDecodeNewlines:Xs,shouldDecodeNewlinesForHref:Qs,delimiters:n.delimiters,comments:n.comments},this),i=o.render,a=o.staticRenderFns;n.render=i,n.staticRenderFns=a}}return Ys.call(this,e,t)},rr.compile=Js;var Zs=n(819);function ec(t){var n=!(arguments.length>1&&void 0!==arguments[1])||arguments[1];t instanceof e||(t=e(t)),n?t.hasClass("d-none")||t.addClass("d-none"):t.hasClass("d-none")&&t.removeClass("d-none")}function tc(e,t){!function(e){if(!e&&!(e=window.event))return;e.cancelBubble=!0,e.stopPropagation&&e.stopPropagation()}(t),window.open(e)}function nc(t){e("> .card",t).removeClass("border-dark")}function rc(e){e.hasClass("border-dark")||e.addClass("border-dark")}function oc(t){var n=e("#wrapper");!function(t){var n=arguments.length>1&&void 0!==arguments[1]?arguments[1]:0,r=arguments.length>2&&void 0!==arguments[2]?arguments[2]:500;t instanceof e||(t=e(t));var o=e("html, body"),i=t.offset().top-parseFloat(o.css("margin-top"))-parseFloat(o.css("padding-top"))-parseFloat(t.css("margin-top"))-parseFloat(t.css("padding-top"))-n;o.animate({scrollTop:i},r,"swing")}(t,parseFloat(n.css("padding-top"))+parseFloat(n.css("margin-top")))}function ic(t){var n,r,o,i,a,s,c,l=arguments.length>1&&void 0!==arguments[1]?arguments[1]:void 0,u=arguments.length>2?arguments[2]:void 0,f=arguments.length>3&&void 0!==arguments[3]?arguments[3]:0,d=(new Date).getTime();r="api/monitor",o={},i=function(e){if(0===e.code){for(var n in e=e.data,t.hostname=e.hostname,t.kernel=e.kernel,t.rtc_time=e.rtc_time,t.uptime=e.uptime,t.logical_cores=e.cpus.map((function(e){return e.threads})).reduce((function(e,t){return e+t})),t.load_average=e.load_average,t.load_average_scale)Object.prototype.hasOwnProperty.call(t.load_average_scale,n)&&(t.load_average_scale[n]=100*e.load_average[n]/t.logical_cores);t.cpu=100*e.cpus_stat[0];var r=1;for(var o in e.cpus.forEach((function(t){var n=t.threads;t.cpus_stat=[];var o=r+n;o>e.length&&(o=e.length);for(var i=r;i<o;++i)t.cpus_stat.push(100*e.cpus_stat[i]);r+=n})),t.cpus=e.cpus,t.memory=e.memory,t.swap=e.swap,t.memory_scale)Object.prototype.hasOwnProperty.call(t.memory_scale,o)&&(t.memory_scale[o]=100*e.memory[o].value/e.memory.total.value);for(var i in t.swap_scale)Object.prototype.hasOwnProperty.call(t.swap_scale,i)&&(t.swap_scale[i]=100*e.swap[i].value/e.swap.total.value);t.network=e.network,e.volumes.forEach((function(e){e.scale=100*e.used.value/e.size.value})),t.volumes=e.volumes;var a=new Date;t.last_update_time=a.toLocaleString();var s=a.getTime()-d,c=0;s<u&&(c=u-s),setTimeout((function(){ic(t,l,u,0)}),c)}},a=function(){f>=10?function(){var t=arguments.length>0&&void 0!==arguments[0]?arguments[0]:"",n=arguments.length>1&&void 0!==arguments[1]?arguments[1]:void 0,r=arguments.length>2&&void 0!==arguments[2]?arguments[2]:"OK",o=arguments.length>3&&void 0!==arguments[3]?arguments[3]:void 0,i=!(arguments.length>4&&void 0!==arguments[4])||arguments[4],a=e("#alertModal"),s=e(".modal-header",a),c=e(".modal-title",s),l=e(".modal-body",a),u=e(".modal-footer button",a);"string"==typeof n?(c.text(n),ec(s,!1)):ec(s,!0),l.html(t),u.text(r),a.off("hidden.bs.modal"),"function"==typeof o&&a.on("hidden.bs.modal",o),i?a.pressEnter((function(){a.modal("hide")})):a.pressEnter((function(){})),a.modal("show")}("The monitor API can not be invoked successfully. Please refresh this page to try again.","Error","OK",(function(){var e;e="",location.href=e}),!1):(console.warn("Retry to call the monitor API in 1 second."),setTimeout((function(){ic(t,l,u,f+1)}),1e3))},s=l,"DELETE"===(n="GET")&&(r=r+"?"+e.param(o),o={}),"string"==typeof s&&(c={Authorization:s}),e.ajax({type:n,url:r,data:o,headers:c,dataType:"json",success:function(e){"function"==typeof i&&i(e)},error:function(){"function"==typeof i&&a()}})}function ac(){(0,Zs.P)("html body, code"),e("#menu-github").click((function(e){e.preventDefault(),tc("https://github.com/magiclen/m-prober")}));var t={last_update_time:"Never",kernel:"",hostname:"",rtc_time:"",uptime:{value:0,text:""},logical_cores:0,load_average:{one:0,five:0,fifteen:0},load_average_scale:{one:0,five:0,fifteen:0},cpu:0,cpus:[],memory:{total:{value:0,text:"0 B"},used:{value:0,text:"0 B"},buffer_cache:{value:0,text:"0 B"}},memory_scale:{used:0,buffer_cache:0},swap:{total:{value:0,text:"0 B"},used:{value:0,text:"0 B"},cache:{value:0,text:"0 B"}},swap_scale:{used:0,cache:0},network:[],volumes:[]};new rr({el:"#monitor",data:t,mounted:function(){var n=this.$el;e("#menu-toggle").click((function(t){t.preventDefault(),e("#wrapper").toggleClass("toggled"),nc(n)})),new rr({el:"#sidebar-wrapper",data:{},mounted:function(){var n=e("#auth-key");n=n.length>0?n.val():void 0;var r=parseInt(e("#time-interval").val());ic(t,n,r)},methods:{toLinuxInformation:function(){nc(n);var t=e("> #linux-information",n);oc(t),rc(t)},toLoadAverage:function(){nc(n);var t=e("> #load-average",n);oc(t),rc(t)},toCPUs:function(){nc(n);var t=e("> #cpus",n);oc(t),rc(t)},toMemory:function(){nc(n);var t=e("> #memory",n);oc(t),rc(t)},toNetworks:function(){nc(n);var t=e("> #networks",n);oc(t),rc(t)},toVolumes:function(){nc(n);var t=e("> #volumes",n);oc(t),rc(t)}}})}})}e.fn.pressEnter=function(t){return this.each((function(){e(this).keypress((function(e){13===(e.keyCode?e.keyCode:e.which)&&(t.call(this,e),e.preventDefault())}))}))}})(),r})()));
\ No newline at end of file
+!function(e,t){if("object"==typeof exports&&"object"==typeof module)module.exports=t();else if("function"==typeof define&&define.amd)define([],t);else{var n=t();for(var r in n)("object"==typeof exports?exports:e)[r]=n[r]}}(self,(()=>(()=>{var e={252:function(e,t,n){var r,o,i,a;function s(e){return s="function"==typeof Symbol&&"symbol"==typeof Symbol.iterator?function(e){return typeof e}:function(e){return e&&"function"==typeof Symbol&&e.constructor===Symbol&&e!==Symbol.prototype?"symbol":typeof e},s(e)}a=function(e,t){"use strict";function n(e){return e&&"object"===s(e)&&"default"in e?e:{default:e}}var r=n(e),o=n(t);function i(e,t){for(var n=0;n<t.length;n++){var r=t[n];r.enumerable=r.enumerable||!1,r.configurable=!0,"value"in r&&(r.writable=!0),Object.defineProperty(e,r.key,r)}}function a(){return a=Object.assign?Object.assign.bind():function(e){for(var t=1;t<arguments.length;t++){var n=arguments[t];for(var r in n)Object.prototype.hasOwnProperty.call(n,r)&&(e[r]=n[r])}return e},a.apply(this,arguments)}var c="collapse",l="bs.collapse",u="."+l,f=r.default.fn[c],d="show",p="collapse",h="collapsing",v="collapsed",m="width",g="show"+u,y="shown"+u,b="hide"+u,_="hidden"+u,x="click"+u+".data-api",w='[data-toggle="collapse"]',C={toggle:!0,parent:""},T={toggle:"boolean",parent:"(string|element)"},k=function(){function e(e,t){this._isTransitioning=!1,this._element=e,this._config=this._getConfig(t),this._triggerArray=[].slice.call(document.querySelectorAll('[data-toggle="collapse"][href="#'+e.id+'"],[data-toggle="collapse"][data-target="#'+e.id+'"]'));for(var n=[].slice.call(document.querySelectorAll(w)),r=0,i=n.length;r<i;r++){var a=n[r],s=o.default.getSelectorFromElement(a),c=[].slice.call(document.querySelectorAll(s)).filter((function(t){return t===e}));null!==s&&c.length>0&&(this._selector=s,this._triggerArray.push(a))}this._parent=this._config.parent?this._getParent():null,this._config.parent||this._addAriaAndCollapsedClass(this._element,this._triggerArray),this._config.toggle&&this.toggle()}var t,n,u,f=e.prototype;return f.toggle=function(){r.default(this._element).hasClass(d)?this.hide():this.show()},f.show=function(){var t,n,i=this;if(!(this._isTransitioning||r.default(this._element).hasClass(d)||(this._parent&&0===(t=[].slice.call(this._parent.querySelectorAll(".show, .collapsing")).filter((function(e){return"string"==typeof i._config.parent?e.getAttribute("data-parent")===i._config.parent:e.classList.contains(p)}))).length&&(t=null),t&&(n=r.default(t).not(this._selector).data(l))&&n._isTransitioning))){var a=r.default.Event(g);if(r.default(this._element).trigger(a),!a.isDefaultPrevented()){t&&(e._jQueryInterface.call(r.default(t).not(this._selector),"hide"),n||r.default(t).data(l,null));var s=this._getDimension();r.default(this._element).removeClass(p).addClass(h),this._element.style[s]=0,this._triggerArray.length&&r.default(this._triggerArray).removeClass(v).attr("aria-expanded",!0),this.setTransitioning(!0);var c="scroll"+(s[0].toUpperCase()+s.slice(1)),u=o.default.getTransitionDurationFromElement(this._element);r.default(this._element).one(o.default.TRANSITION_END,(function(){r.default(i._element).removeClass(h).addClass(p+" "+d),i._element.style[s]="",i.setTransitioning(!1),r.default(i._element).trigger(y)})).emulateTransitionEnd(u),this._element.style[s]=this._element[c]+"px"}}},f.hide=function(){var e=this;if(!this._isTransitioning&&r.default(this._element).hasClass(d)){var t=r.default.Event(b);if(r.default(this._element).trigger(t),!t.isDefaultPrevented()){var n=this._getDimension();this._element.style[n]=this._element.getBoundingClientRect()[n]+"px",o.default.reflow(this._element),r.default(this._element).addClass(h).removeClass(p+" "+d);var i=this._triggerArray.length;if(i>0)for(var a=0;a<i;a++){var s=this._triggerArray[a],c=o.default.getSelectorFromElement(s);null!==c&&(r.default([].slice.call(document.querySelectorAll(c))).hasClass(d)||r.default(s).addClass(v).attr("aria-expanded",!1))}this.setTransitioning(!0),this._element.style[n]="";var l=o.default.getTransitionDurationFromElement(this._element);r.default(this._element).one(o.default.TRANSITION_END,(function(){e.setTransitioning(!1),r.default(e._element).removeClass(h).addClass(p).trigger(_)})).emulateTransitionEnd(l)}}},f.setTransitioning=function(e){this._isTransitioning=e},f.dispose=function(){r.default.removeData(this._element,l),this._config=null,this._parent=null,this._element=null,this._triggerArray=null,this._isTransitioning=null},f._getConfig=function(e){return(e=a({},C,e)).toggle=Boolean(e.toggle),o.default.typeCheckConfig(c,e,T),e},f._getDimension=function(){return r.default(this._element).hasClass(m)?m:"height"},f._getParent=function(){var t,n=this;o.default.isElement(this._config.parent)?(t=this._config.parent,void 0!==this._config.parent.jquery&&(t=this._config.parent[0])):t=document.querySelector(this._config.parent);var i='[data-toggle="collapse"][data-parent="'+this._config.parent+'"]',a=[].slice.call(t.querySelectorAll(i));return r.default(a).each((function(t,r){n._addAriaAndCollapsedClass(e._getTargetFromElement(r),[r])})),t},f._addAriaAndCollapsedClass=function(e,t){var n=r.default(e).hasClass(d);t.length&&r.default(t).toggleClass(v,!n).attr("aria-expanded",n)},e._getTargetFromElement=function(e){var t=o.default.getSelectorFromElement(e);return t?document.querySelector(t):null},e._jQueryInterface=function(t){return this.each((function(){var n=r.default(this),o=n.data(l),i=a({},C,n.data(),"object"===s(t)&&t?t:{});if(!o&&i.toggle&&"string"==typeof t&&/show|hide/.test(t)&&(i.toggle=!1),o||(o=new e(this,i),n.data(l,o)),"string"==typeof t){if(void 0===o[t])throw new TypeError('No method named "'+t+'"');o[t]()}}))},t=e,u=[{key:"VERSION",get:function(){return"4.6.2"}},{key:"Default",get:function(){return C}}],(n=null)&&i(t.prototype,n),u&&i(t,u),Object.defineProperty(t,"prototype",{writable:!1}),e}();return r.default(document).on(x,w,(function(e){"A"===e.currentTarget.tagName&&e.preventDefault();var t=r.default(this),n=o.default.getSelectorFromElement(this),i=[].slice.call(document.querySelectorAll(n));r.default(i).each((function(){var e=r.default(this),n=e.data(l)?"toggle":t.data();k._jQueryInterface.call(e,n)}))})),r.default.fn[c]=k._jQueryInterface,r.default.fn[c].Constructor=k,r.default.fn[c].noConflict=function(){return r.default.fn[c]=f,k._jQueryInterface},k},"object"===s(t)?e.exports=a(n(25),n(555)):(o=[n(25),n(555)],void 0===(i="function"==typeof(r=a)?r.apply(t,o):r)||(e.exports=i))},202:function(e,t,n){var r,o,i,a;function s(e){return s="function"==typeof Symbol&&"symbol"==typeof Symbol.iterator?function(e){return typeof e}:function(e){return e&&"function"==typeof Symbol&&e.constructor===Symbol&&e!==Symbol.prototype?"symbol":typeof e},s(e)}a=function(e,t){"use strict";function n(e){return e&&"object"===s(e)&&"default"in e?e:{default:e}}var r=n(e),o=n(t);function i(e,t){for(var n=0;n<t.length;n++){var r=t[n];r.enumerable=r.enumerable||!1,r.configurable=!0,"value"in r&&(r.writable=!0),Object.defineProperty(e,r.key,r)}}function a(){return a=Object.assign?Object.assign.bind():function(e){for(var t=1;t<arguments.length;t++){var n=arguments[t];for(var r in n)Object.prototype.hasOwnProperty.call(n,r)&&(e[r]=n[r])}return e},a.apply(this,arguments)}var c="modal",l="bs.modal",u="."+l,f=r.default.fn[c],d="modal-open",p="fade",h="show",v="modal-static",m="hide"+u,g="hidePrevented"+u,y="hidden"+u,b="show"+u,_="shown"+u,x="focusin"+u,w="resize"+u,C="click.dismiss"+u,T="keydown.dismiss"+u,k="mouseup.dismiss"+u,S="mousedown.dismiss"+u,E="click"+u+".data-api",$=".fixed-top, .fixed-bottom, .is-fixed, .sticky-top",A=".sticky-top",O={backdrop:!0,keyboard:!0,focus:!0,show:!0},j={backdrop:"(boolean|string)",keyboard:"boolean",focus:"boolean",show:"boolean"},D=function(){function e(e,t){this._config=this._getConfig(t),this._element=e,this._dialog=e.querySelector(".modal-dialog"),this._backdrop=null,this._isShown=!1,this._isBodyOverflowing=!1,this._ignoreBackdropClick=!1,this._isTransitioning=!1,this._scrollbarWidth=0}var t,n,f,E=e.prototype;return E.toggle=function(e){return this._isShown?this.hide():this.show(e)},E.show=function(e){var t=this;if(!this._isShown&&!this._isTransitioning){var n=r.default.Event(b,{relatedTarget:e});r.default(this._element).trigger(n),n.isDefaultPrevented()||(this._isShown=!0,r.default(this._element).hasClass(p)&&(this._isTransitioning=!0),this._checkScrollbar(),this._setScrollbar(),this._adjustDialog(),this._setEscapeEvent(),this._setResizeEvent(),r.default(this._element).on(C,'[data-dismiss="modal"]',(function(e){return t.hide(e)})),r.default(this._dialog).on(S,(function(){r.default(t._element).one(k,(function(e){r.default(e.target).is(t._element)&&(t._ignoreBackdropClick=!0)}))})),this._showBackdrop((function(){return t._showElement(e)})))}},E.hide=function(e){var t=this;if(e&&e.preventDefault(),this._isShown&&!this._isTransitioning){var n=r.default.Event(m);if(r.default(this._element).trigger(n),this._isShown&&!n.isDefaultPrevented()){this._isShown=!1;var i=r.default(this._element).hasClass(p);if(i&&(this._isTransitioning=!0),this._setEscapeEvent(),this._setResizeEvent(),r.default(document).off(x),r.default(this._element).removeClass(h),r.default(this._element).off(C),r.default(this._dialog).off(S),i){var a=o.default.getTransitionDurationFromElement(this._element);r.default(this._element).one(o.default.TRANSITION_END,(function(e){return t._hideModal(e)})).emulateTransitionEnd(a)}else this._hideModal()}}},E.dispose=function(){[window,this._element,this._dialog].forEach((function(e){return r.default(e).off(u)})),r.default(document).off(x),r.default.removeData(this._element,l),this._config=null,this._element=null,this._dialog=null,this._backdrop=null,this._isShown=null,this._isBodyOverflowing=null,this._ignoreBackdropClick=null,this._isTransitioning=null,this._scrollbarWidth=null},E.handleUpdate=function(){this._adjustDialog()},E._getConfig=function(e){return e=a({},O,e),o.default.typeCheckConfig(c,e,j),e},E._triggerBackdropTransition=function(){var e=this,t=r.default.Event(g);if(r.default(this._element).trigger(t),!t.isDefaultPrevented()){var n=this._element.scrollHeight>document.documentElement.clientHeight;n||(this._element.style.overflowY="hidden"),this._element.classList.add(v);var i=o.default.getTransitionDurationFromElement(this._dialog);r.default(this._element).off(o.default.TRANSITION_END),r.default(this._element).one(o.default.TRANSITION_END,(function(){e._element.classList.remove(v),n||r.default(e._element).one(o.default.TRANSITION_END,(function(){e._element.style.overflowY=""})).emulateTransitionEnd(e._element,i)})).emulateTransitionEnd(i),this._element.focus()}},E._showElement=function(e){var t=this,n=r.default(this._element).hasClass(p),i=this._dialog?this._dialog.querySelector(".modal-body"):null;this._element.parentNode&&this._element.parentNode.nodeType===Node.ELEMENT_NODE||document.body.appendChild(this._element),this._element.style.display="block",this._element.removeAttribute("aria-hidden"),this._element.setAttribute("aria-modal",!0),this._element.setAttribute("role","dialog"),r.default(this._dialog).hasClass("modal-dialog-scrollable")&&i?i.scrollTop=0:this._element.scrollTop=0,n&&o.default.reflow(this._element),r.default(this._element).addClass(h),this._config.focus&&this._enforceFocus();var a=r.default.Event(_,{relatedTarget:e}),s=function(){t._config.focus&&t._element.focus(),t._isTransitioning=!1,r.default(t._element).trigger(a)};if(n){var c=o.default.getTransitionDurationFromElement(this._dialog);r.default(this._dialog).one(o.default.TRANSITION_END,s).emulateTransitionEnd(c)}else s()},E._enforceFocus=function(){var e=this;r.default(document).off(x).on(x,(function(t){document!==t.target&&e._element!==t.target&&0===r.default(e._element).has(t.target).length&&e._element.focus()}))},E._setEscapeEvent=function(){var e=this;this._isShown?r.default(this._element).on(T,(function(t){e._config.keyboard&&27===t.which?(t.preventDefault(),e.hide()):e._config.keyboard||27!==t.which||e._triggerBackdropTransition()})):this._isShown||r.default(this._element).off(T)},E._setResizeEvent=function(){var e=this;this._isShown?r.default(window).on(w,(function(t){return e.handleUpdate(t)})):r.default(window).off(w)},E._hideModal=function(){var e=this;this._element.style.display="none",this._element.setAttribute("aria-hidden",!0),this._element.removeAttribute("aria-modal"),this._element.removeAttribute("role"),this._isTransitioning=!1,this._showBackdrop((function(){r.default(document.body).removeClass(d),e._resetAdjustments(),e._resetScrollbar(),r.default(e._element).trigger(y)}))},E._removeBackdrop=function(){this._backdrop&&(r.default(this._backdrop).remove(),this._backdrop=null)},E._showBackdrop=function(e){var t=this,n=r.default(this._element).hasClass(p)?p:"";if(this._isShown&&this._config.backdrop){if(this._backdrop=document.createElement("div"),this._backdrop.className="modal-backdrop",n&&this._backdrop.classList.add(n),r.default(this._backdrop).appendTo(document.body),r.default(this._element).on(C,(function(e){t._ignoreBackdropClick?t._ignoreBackdropClick=!1:e.target===e.currentTarget&&("static"===t._config.backdrop?t._triggerBackdropTransition():t.hide())})),n&&o.default.reflow(this._backdrop),r.default(this._backdrop).addClass(h),!e)return;if(!n)return void e();var i=o.default.getTransitionDurationFromElement(this._backdrop);r.default(this._backdrop).one(o.default.TRANSITION_END,e).emulateTransitionEnd(i)}else if(!this._isShown&&this._backdrop){r.default(this._backdrop).removeClass(h);var a=function(){t._removeBackdrop(),e&&e()};if(r.default(this._element).hasClass(p)){var s=o.default.getTransitionDurationFromElement(this._backdrop);r.default(this._backdrop).one(o.default.TRANSITION_END,a).emulateTransitionEnd(s)}else a()}else e&&e()},E._adjustDialog=function(){var e=this._element.scrollHeight>document.documentElement.clientHeight;!this._isBodyOverflowing&&e&&(this._element.style.paddingLeft=this._scrollbarWidth+"px"),this._isBodyOverflowing&&!e&&(this._element.style.paddingRight=this._scrollbarWidth+"px")},E._resetAdjustments=function(){this._element.style.paddingLeft="",this._element.style.paddingRight=""},E._checkScrollbar=function(){var e=document.body.getBoundingClientRect();this._isBodyOverflowing=Math.round(e.left+e.right)<window.innerWidth,this._scrollbarWidth=this._getScrollbarWidth()},E._setScrollbar=function(){var e=this;if(this._isBodyOverflowing){var t=[].slice.call(document.querySelectorAll($)),n=[].slice.call(document.querySelectorAll(A));r.default(t).each((function(t,n){var o=n.style.paddingRight,i=r.default(n).css("padding-right");r.default(n).data("padding-right",o).css("padding-right",parseFloat(i)+e._scrollbarWidth+"px")})),r.default(n).each((function(t,n){var o=n.style.marginRight,i=r.default(n).css("margin-right");r.default(n).data("margin-right",o).css("margin-right",parseFloat(i)-e._scrollbarWidth+"px")}));var o=document.body.style.paddingRight,i=r.default(document.body).css("padding-right");r.default(document.body).data("padding-right",o).css("padding-right",parseFloat(i)+this._scrollbarWidth+"px")}r.default(document.body).addClass(d)},E._resetScrollbar=function(){var e=[].slice.call(document.querySelectorAll($));r.default(e).each((function(e,t){var n=r.default(t).data("padding-right");r.default(t).removeData("padding-right"),t.style.paddingRight=n||""}));var t=[].slice.call(document.querySelectorAll(""+A));r.default(t).each((function(e,t){var n=r.default(t).data("margin-right");void 0!==n&&r.default(t).css("margin-right",n).removeData("margin-right")}));var n=r.default(document.body).data("padding-right");r.default(document.body).removeData("padding-right"),document.body.style.paddingRight=n||""},E._getScrollbarWidth=function(){var e=document.createElement("div");e.className="modal-scrollbar-measure",document.body.appendChild(e);var t=e.getBoundingClientRect().width-e.clientWidth;return document.body.removeChild(e),t},e._jQueryInterface=function(t,n){return this.each((function(){var o=r.default(this).data(l),i=a({},O,r.default(this).data(),"object"===s(t)&&t?t:{});if(o||(o=new e(this,i),r.default(this).data(l,o)),"string"==typeof t){if(void 0===o[t])throw new TypeError('No method named "'+t+'"');o[t](n)}else i.show&&o.show(n)}))},t=e,f=[{key:"VERSION",get:function(){return"4.6.2"}},{key:"Default",get:function(){return O}}],(n=null)&&i(t.prototype,n),f&&i(t,f),Object.defineProperty(t,"prototype",{writable:!1}),e}();return r.default(document).on(E,'[data-toggle="modal"]',(function(e){var t,n=this,i=o.default.getSelectorFromElement(this);i&&(t=document.querySelector(i));var s=r.default(t).data(l)?"toggle":a({},r.default(t).data(),r.default(this).data());"A"!==this.tagName&&"AREA"!==this.tagName||e.preventDefault();var c=r.default(t).one(b,(function(e){e.isDefaultPrevented()||c.one(y,(function(){r.default(n).is(":visible")&&n.focus()}))}));D._jQueryInterface.call(r.default(t),s,this)})),r.default.fn[c]=D._jQueryInterface,r.default.fn[c].Constructor=D,r.default.fn[c].noConflict=function(){return r.default.fn[c]=f,D._jQueryInterface},D},"object"===s(t)?e.exports=a(n(25),n(555)):(o=[n(25),n(555)],void 0===(i="function"==typeof(r=a)?r.apply(t,o):r)||(e.exports=i))},555:function(e,t,n){var r,o,i,a;function s(e){return s="function"==typeof Symbol&&"symbol"==typeof Symbol.iterator?function(e){return typeof e}:function(e){return e&&"function"==typeof Symbol&&e.constructor===Symbol&&e!==Symbol.prototype?"symbol":typeof e},s(e)}a=function(e){"use strict";function t(e){return e&&"object"===s(e)&&"default"in e?e:{default:e}}var n=t(e),r="transitionend";function o(e){var t=this,r=!1;return n.default(this).one(i.TRANSITION_END,(function(){r=!0})),setTimeout((function(){r||i.triggerTransitionEnd(t)}),e),this}var i={TRANSITION_END:"bsTransitionEnd",getUID:function(e){do{e+=~~(1e6*Math.random())}while(document.getElementById(e));return e},getSelectorFromElement:function(e){var t=e.getAttribute("data-target");if(!t||"#"===t){var n=e.getAttribute("href");t=n&&"#"!==n?n.trim():""}try{return document.querySelector(t)?t:null}catch(e){return null}},getTransitionDurationFromElement:function(e){if(!e)return 0;var t=n.default(e).css("transition-duration"),r=n.default(e).css("transition-delay"),o=parseFloat(t),i=parseFloat(r);return o||i?(t=t.split(",")[0],r=r.split(",")[0],1e3*(parseFloat(t)+parseFloat(r))):0},reflow:function(e){return e.offsetHeight},triggerTransitionEnd:function(e){n.default(e).trigger(r)},supportsTransitionEnd:function(){return Boolean(r)},isElement:function(e){return(e[0]||e).nodeType},typeCheckConfig:function(e,t,n){for(var r in n)if(Object.prototype.hasOwnProperty.call(n,r)){var o=n[r],a=t[r],s=a&&i.isElement(a)?"element":null==(c=a)?""+c:{}.toString.call(c).match(/\s([a-z]+)/i)[1].toLowerCase();if(!new RegExp(o).test(s))throw new Error(e.toUpperCase()+': Option "'+r+'" provided type "'+s+'" but expected type "'+o+'".')}var c},findShadowRoot:function(e){if(!document.documentElement.attachShadow)return null;if("function"==typeof e.getRootNode){var t=e.getRootNode();return t instanceof ShadowRoot?t:null}return e instanceof ShadowRoot?e:e.parentNode?i.findShadowRoot(e.parentNode):null},jQueryDetection:function(){if(void 0===n.default)throw new TypeError("Bootstrap's JavaScript requires jQuery. jQuery must be included before Bootstrap's JavaScript.");var e=n.default.fn.jquery.split(" ")[0].split(".");if(e[0]<2&&e[1]<9||1===e[0]&&9===e[1]&&e[2]<1||e[0]>=4)throw new Error("Bootstrap's JavaScript requires at least jQuery v1.9.1 but less than v4.0.0")}};return i.jQueryDetection(),n.default.fn.emulateTransitionEnd=o,n.default.event.special[i.TRANSITION_END]={bindType:r,delegateType:r,handle:function(e){if(n.default(e.target).is(this))return e.handleObj.handler.apply(this,arguments)}},i},"object"===s(t)?e.exports=a(n(25)):(o=[n(25)],void 0===(i="function"==typeof(r=a)?r.apply(t,o):r)||(e.exports=i))},25:function(e,t,n){var r;function o(e){return o="function"==typeof Symbol&&"symbol"==typeof Symbol.iterator?function(e){return typeof e}:function(e){return e&&"function"==typeof Symbol&&e.constructor===Symbol&&e!==Symbol.prototype?"symbol":typeof e},o(e)}e=n.nmd(e),function(t,n){"use strict";"object"===o(e)&&"object"===o(e.exports)?e.exports=t.document?n(t,!0):function(e){if(!e.document)throw new Error("jQuery requires a window with a document");return n(e)}:n(t)}("undefined"!=typeof window?window:this,(function(n,i){"use strict";var a=[],s=Object.getPrototypeOf,c=a.slice,l=a.flat?function(e){return a.flat.call(e)}:function(e){return a.concat.apply([],e)},u=a.push,f=a.indexOf,d={},p=d.toString,h=d.hasOwnProperty,v=h.toString,m=v.call(Object),g={},y=function(e){return"function"==typeof e&&"number"!=typeof e.nodeType&&"function"!=typeof e.item},b=function(e){return null!=e&&e===e.window},_=n.document,x={type:!0,src:!0,nonce:!0,noModule:!0};function w(e,t,n){var r,o,i=(n=n||_).createElement("script");if(i.text=e,t)for(r in x)(o=t[r]||t.getAttribute&&t.getAttribute(r))&&i.setAttribute(r,o);n.head.appendChild(i).parentNode.removeChild(i)}function C(e){return null==e?e+"":"object"===o(e)||"function"==typeof e?d[p.call(e)]||"object":o(e)}var T="3.7.1",k=/HTML$/i,S=function e(t,n){return new e.fn.init(t,n)};function E(e){var t=!!e&&"length"in e&&e.length,n=C(e);return!y(e)&&!b(e)&&("array"===n||0===t||"number"==typeof t&&t>0&&t-1 in e)}function $(e,t){return e.nodeName&&e.nodeName.toLowerCase()===t.toLowerCase()}S.fn=S.prototype={jquery:T,constructor:S,length:0,toArray:function(){return c.call(this)},get:function(e){return null==e?c.call(this):e<0?this[e+this.length]:this[e]},pushStack:function(e){var t=S.merge(this.constructor(),e);return t.prevObject=this,t},each:function(e){return S.each(this,e)},map:function(e){return this.pushStack(S.map(this,(function(t,n){return e.call(t,n,t)})))},slice:function(){return this.pushStack(c.apply(this,arguments))},first:function(){return this.eq(0)},last:function(){return this.eq(-1)},even:function(){return this.pushStack(S.grep(this,(function(e,t){return(t+1)%2})))},odd:function(){return this.pushStack(S.grep(this,(function(e,t){return t%2})))},eq:function(e){var t=this.length,n=+e+(e<0?t:0);return this.pushStack(n>=0&&n<t?[this[n]]:[])},end:function(){return this.prevObject||this.constructor()},push:u,sort:a.sort,splice:a.splice},S.extend=S.fn.extend=function(){var e,t,n,r,i,a,s=arguments[0]||{},c=1,l=arguments.length,u=!1;for("boolean"==typeof s&&(u=s,s=arguments[c]||{},c++),"object"===o(s)||y(s)||(s={}),c===l&&(s=this,c--);c<l;c++)if(null!=(e=arguments[c]))for(t in e)r=e[t],"__proto__"!==t&&s!==r&&(u&&r&&(S.isPlainObject(r)||(i=Array.isArray(r)))?(n=s[t],a=i&&!Array.isArray(n)?[]:i||S.isPlainObject(n)?n:{},i=!1,s[t]=S.extend(u,a,r)):void 0!==r&&(s[t]=r));return s},S.extend({expando:"jQuery"+(T+Math.random()).replace(/\D/g,""),isReady:!0,error:function(e){throw new Error(e)},noop:function(){},isPlainObject:function(e){var t,n;return!(!e||"[object Object]"!==p.call(e))&&(!(t=s(e))||"function"==typeof(n=h.call(t,"constructor")&&t.constructor)&&v.call(n)===m)},isEmptyObject:function(e){var t;for(t in e)return!1;return!0},globalEval:function(e,t,n){w(e,{nonce:t&&t.nonce},n)},each:function(e,t){var n,r=0;if(E(e))for(n=e.length;r<n&&!1!==t.call(e[r],r,e[r]);r++);else for(r in e)if(!1===t.call(e[r],r,e[r]))break;return e},text:function(e){var t,n="",r=0,o=e.nodeType;if(!o)for(;t=e[r++];)n+=S.text(t);return 1===o||11===o?e.textContent:9===o?e.documentElement.textContent:3===o||4===o?e.nodeValue:n},makeArray:function(e,t){var n=t||[];return null!=e&&(E(Object(e))?S.merge(n,"string"==typeof e?[e]:e):u.call(n,e)),n},inArray:function(e,t,n){return null==t?-1:f.call(t,e,n)},isXMLDoc:function(e){var t=e&&e.namespaceURI,n=e&&(e.ownerDocument||e).documentElement;return!k.test(t||n&&n.nodeName||"HTML")},merge:function(e,t){for(var n=+t.length,r=0,o=e.length;r<n;r++)e[o++]=t[r];return e.length=o,e},grep:function(e,t,n){for(var r=[],o=0,i=e.length,a=!n;o<i;o++)!t(e[o],o)!==a&&r.push(e[o]);return r},map:function(e,t,n){var r,o,i=0,a=[];if(E(e))for(r=e.length;i<r;i++)null!=(o=t(e[i],i,n))&&a.push(o);else for(i in e)null!=(o=t(e[i],i,n))&&a.push(o);return l(a)},guid:1,support:g}),"function"==typeof Symbol&&(S.fn[Symbol.iterator]=a[Symbol.iterator]),S.each("Boolean Number String Function Array Date RegExp Object Error Symbol".split(" "),(function(e,t){d["[object "+t+"]"]=t.toLowerCase()}));var A=a.pop,O=a.sort,j=a.splice,D="[\\x20\\t\\r\\n\\f]",N=new RegExp("^"+D+"+|((?:^|[^\\\\])(?:\\\\.)*)"+D+"+$","g");S.contains=function(e,t){var n=t&&t.parentNode;return e===n||!(!n||1!==n.nodeType||!(e.contains?e.contains(n):e.compareDocumentPosition&&16&e.compareDocumentPosition(n)))};var L=/([\0-\x1f\x7f]|^-?\d)|^-$|[^\x80-\uFFFF\w-]/g;function P(e,t){return t?"\0"===e?"�":e.slice(0,-1)+"\\"+e.charCodeAt(e.length-1).toString(16)+" ":"\\"+e}S.escapeSelector=function(e){return(e+"").replace(L,P)};var I=_,M=u;!function(){var e,t,r,o,i,s,l,u,d,p,v=M,m=S.expando,y=0,b=0,_=ee(),x=ee(),w=ee(),C=ee(),T=function(e,t){return e===t&&(i=!0),0},k="checked|selected|async|autofocus|autoplay|controls|defer|disabled|hidden|ismap|loop|multiple|open|readonly|required|scoped",E="(?:\\\\[\\da-fA-F]{1,6}"+D+"?|\\\\[^\\r\\n\\f]|[\\w-]|[^\0-\\x7f])+",L="\\["+D+"*("+E+")(?:"+D+"*([*^$|!~]?=)"+D+"*(?:'((?:\\\\.|[^\\\\'])*)'|\"((?:\\\\.|[^\\\\\"])*)\"|("+E+"))|)"+D+"*\\]",P=":("+E+")(?:\\((('((?:\\\\.|[^\\\\'])*)'|\"((?:\\\\.|[^\\\\\"])*)\")|((?:\\\\.|[^\\\\()[\\]]|"+L+")*)|.*)\\)|)",R=new RegExp(D+"+","g"),F=new RegExp("^"+D+"*,"+D+"*"),H=new RegExp("^"+D+"*([>+~]|"+D+")"+D+"*"),q=new RegExp(D+"|>"),B=new RegExp(P),W=new RegExp("^"+E+"$"),U={ID:new RegExp("^#("+E+")"),CLASS:new RegExp("^\\.("+E+")"),TAG:new RegExp("^("+E+"|[*])"),ATTR:new RegExp("^"+L),PSEUDO:new RegExp("^"+P),CHILD:new RegExp("^:(only|first|last|nth|nth-last)-(child|of-type)(?:\\("+D+"*(even|odd|(([+-]|)(\\d*)n|)"+D+"*(?:([+-]|)"+D+"*(\\d+)|))"+D+"*\\)|)","i"),bool:new RegExp("^(?:"+k+")$","i"),needsContext:new RegExp("^"+D+"*[>+~]|:(even|odd|eq|gt|lt|nth|first|last)(?:\\("+D+"*((?:-\\d)?\\d*)"+D+"*\\)|)(?=[^-]|$)","i")},z=/^(?:input|select|textarea|button)$/i,V=/^h\d$/i,J=/^(?:#([\w-]+)|(\w+)|\.([\w-]+))$/,K=/[+~]/,X=new RegExp("\\\\[\\da-fA-F]{1,6}"+D+"?|\\\\([^\\r\\n\\f])","g"),Q=function(e,t){var n="0x"+e.slice(1)-65536;return t||(n<0?String.fromCharCode(n+65536):String.fromCharCode(n>>10|55296,1023&n|56320))},G=function(){ce()},Y=de((function(e){return!0===e.disabled&&$(e,"fieldset")}),{dir:"parentNode",next:"legend"});try{v.apply(a=c.call(I.childNodes),I.childNodes),a[I.childNodes.length].nodeType}catch(e){v={apply:function(e,t){M.apply(e,c.call(t))},call:function(e){M.apply(e,c.call(arguments,1))}}}function Z(e,t,n,r){var o,i,a,c,l,f,p,h=t&&t.ownerDocument,y=t?t.nodeType:9;if(n=n||[],"string"!=typeof e||!e||1!==y&&9!==y&&11!==y)return n;if(!r&&(ce(t),t=t||s,u)){if(11!==y&&(l=J.exec(e)))if(o=l[1]){if(9===y){if(!(a=t.getElementById(o)))return n;if(a.id===o)return v.call(n,a),n}else if(h&&(a=h.getElementById(o))&&Z.contains(t,a)&&a.id===o)return v.call(n,a),n}else{if(l[2])return v.apply(n,t.getElementsByTagName(e)),n;if((o=l[3])&&t.getElementsByClassName)return v.apply(n,t.getElementsByClassName(o)),n}if(!(C[e+" "]||d&&d.test(e))){if(p=e,h=t,1===y&&(q.test(e)||H.test(e))){for((h=K.test(e)&&se(t.parentNode)||t)==t&&g.scope||((c=t.getAttribute("id"))?c=S.escapeSelector(c):t.setAttribute("id",c=m)),i=(f=ue(e)).length;i--;)f[i]=(c?"#"+c:":scope")+" "+fe(f[i]);p=f.join(",")}try{return v.apply(n,h.querySelectorAll(p)),n}catch(t){C(e,!0)}finally{c===m&&t.removeAttribute("id")}}}return ye(e.replace(N,"$1"),t,n,r)}function ee(){var e=[];return function n(r,o){return e.push(r+" ")>t.cacheLength&&delete n[e.shift()],n[r+" "]=o}}function te(e){return e[m]=!0,e}function ne(e){var t=s.createElement("fieldset");try{return!!e(t)}catch(e){return!1}finally{t.parentNode&&t.parentNode.removeChild(t),t=null}}function re(e){return function(t){return $(t,"input")&&t.type===e}}function oe(e){return function(t){return($(t,"input")||$(t,"button"))&&t.type===e}}function ie(e){return function(t){return"form"in t?t.parentNode&&!1===t.disabled?"label"in t?"label"in t.parentNode?t.parentNode.disabled===e:t.disabled===e:t.isDisabled===e||t.isDisabled!==!e&&Y(t)===e:t.disabled===e:"label"in t&&t.disabled===e}}function ae(e){return te((function(t){return t=+t,te((function(n,r){for(var o,i=e([],n.length,t),a=i.length;a--;)n[o=i[a]]&&(n[o]=!(r[o]=n[o]))}))}))}function se(e){return e&&void 0!==e.getElementsByTagName&&e}function ce(e){var n,r=e?e.ownerDocument||e:I;return r!=s&&9===r.nodeType&&r.documentElement?(l=(s=r).documentElement,u=!S.isXMLDoc(s),p=l.matches||l.webkitMatchesSelector||l.msMatchesSelector,l.msMatchesSelector&&I!=s&&(n=s.defaultView)&&n.top!==n&&n.addEventListener("unload",G),g.getById=ne((function(e){return l.appendChild(e).id=S.expando,!s.getElementsByName||!s.getElementsByName(S.expando).length})),g.disconnectedMatch=ne((function(e){return p.call(e,"*")})),g.scope=ne((function(){return s.querySelectorAll(":scope")})),g.cssHas=ne((function(){try{return s.querySelector(":has(*,:jqfake)"),!1}catch(e){return!0}})),g.getById?(t.filter.ID=function(e){var t=e.replace(X,Q);return function(e){return e.getAttribute("id")===t}},t.find.ID=function(e,t){if(void 0!==t.getElementById&&u){var n=t.getElementById(e);return n?[n]:[]}}):(t.filter.ID=function(e){var t=e.replace(X,Q);return function(e){var n=void 0!==e.getAttributeNode&&e.getAttributeNode("id");return n&&n.value===t}},t.find.ID=function(e,t){if(void 0!==t.getElementById&&u){var n,r,o,i=t.getElementById(e);if(i){if((n=i.getAttributeNode("id"))&&n.value===e)return[i];for(o=t.getElementsByName(e),r=0;i=o[r++];)if((n=i.getAttributeNode("id"))&&n.value===e)return[i]}return[]}}),t.find.TAG=function(e,t){return void 0!==t.getElementsByTagName?t.getElementsByTagName(e):t.querySelectorAll(e)},t.find.CLASS=function(e,t){if(void 0!==t.getElementsByClassName&&u)return t.getElementsByClassName(e)},d=[],ne((function(e){var t;l.appendChild(e).innerHTML="<a id='"+m+"' href='' disabled='disabled'></a><select id='"+m+"-\r\\' disabled='disabled'><option selected=''></option></select>",e.querySelectorAll("[selected]").length||d.push("\\["+D+"*(?:value|"+k+")"),e.querySelectorAll("[id~="+m+"-]").length||d.push("~="),e.querySelectorAll("a#"+m+"+*").length||d.push(".#.+[+~]"),e.querySelectorAll(":checked").length||d.push(":checked"),(t=s.createElement("input")).setAttribute("type","hidden"),e.appendChild(t).setAttribute("name","D"),l.appendChild(e).disabled=!0,2!==e.querySelectorAll(":disabled").length&&d.push(":enabled",":disabled"),(t=s.createElement("input")).setAttribute("name",""),e.appendChild(t),e.querySelectorAll("[name='']").length||d.push("\\["+D+"*name"+D+"*="+D+"*(?:''|\"\")")})),g.cssHas||d.push(":has"),d=d.length&&new RegExp(d.join("|")),T=function(e,t){if(e===t)return i=!0,0;var n=!e.compareDocumentPosition-!t.compareDocumentPosition;return n||(1&(n=(e.ownerDocument||e)==(t.ownerDocument||t)?e.compareDocumentPosition(t):1)||!g.sortDetached&&t.compareDocumentPosition(e)===n?e===s||e.ownerDocument==I&&Z.contains(I,e)?-1:t===s||t.ownerDocument==I&&Z.contains(I,t)?1:o?f.call(o,e)-f.call(o,t):0:4&n?-1:1)},s):s}for(e in Z.matches=function(e,t){return Z(e,null,null,t)},Z.matchesSelector=function(e,t){if(ce(e),u&&!C[t+" "]&&(!d||!d.test(t)))try{var n=p.call(e,t);if(n||g.disconnectedMatch||e.document&&11!==e.document.nodeType)return n}catch(e){C(t,!0)}return Z(t,s,null,[e]).length>0},Z.contains=function(e,t){return(e.ownerDocument||e)!=s&&ce(e),S.contains(e,t)},Z.attr=function(e,n){(e.ownerDocument||e)!=s&&ce(e);var r=t.attrHandle[n.toLowerCase()],o=r&&h.call(t.attrHandle,n.toLowerCase())?r(e,n,!u):void 0;return void 0!==o?o:e.getAttribute(n)},Z.error=function(e){throw new Error("Syntax error, unrecognized expression: "+e)},S.uniqueSort=function(e){var t,n=[],r=0,a=0;if(i=!g.sortStable,o=!g.sortStable&&c.call(e,0),O.call(e,T),i){for(;t=e[a++];)t===e[a]&&(r=n.push(a));for(;r--;)j.call(e,n[r],1)}return o=null,e},S.fn.uniqueSort=function(){return this.pushStack(S.uniqueSort(c.apply(this)))},t=S.expr={cacheLength:50,createPseudo:te,match:U,attrHandle:{},find:{},relative:{">":{dir:"parentNode",first:!0}," ":{dir:"parentNode"},"+":{dir:"previousSibling",first:!0},"~":{dir:"previousSibling"}},preFilter:{ATTR:function(e){return e[1]=e[1].replace(X,Q),e[3]=(e[3]||e[4]||e[5]||"").replace(X,Q),"~="===e[2]&&(e[3]=" "+e[3]+" "),e.slice(0,4)},CHILD:function(e){return e[1]=e[1].toLowerCase(),"nth"===e[1].slice(0,3)?(e[3]||Z.error(e[0]),e[4]=+(e[4]?e[5]+(e[6]||1):2*("even"===e[3]||"odd"===e[3])),e[5]=+(e[7]+e[8]||"odd"===e[3])):e[3]&&Z.error(e[0]),e},PSEUDO:function(e){var t,n=!e[6]&&e[2];return U.CHILD.test(e[0])?null:(e[3]?e[2]=e[4]||e[5]||"":n&&B.test(n)&&(t=ue(n,!0))&&(t=n.indexOf(")",n.length-t)-n.length)&&(e[0]=e[0].slice(0,t),e[2]=n.slice(0,t)),e.slice(0,3))}},filter:{TAG:function(e){var t=e.replace(X,Q).toLowerCase();return"*"===e?function(){return!0}:function(e){return $(e,t)}},CLASS:function(e){var t=_[e+" "];return t||(t=new RegExp("(^|"+D+")"+e+"("+D+"|$)"))&&_(e,(function(e){return t.test("string"==typeof e.className&&e.className||void 0!==e.getAttribute&&e.getAttribute("class")||"")}))},ATTR:function(e,t,n){return function(r){var o=Z.attr(r,e);return null==o?"!="===t:!t||(o+="","="===t?o===n:"!="===t?o!==n:"^="===t?n&&0===o.indexOf(n):"*="===t?n&&o.indexOf(n)>-1:"$="===t?n&&o.slice(-n.length)===n:"~="===t?(" "+o.replace(R," ")+" ").indexOf(n)>-1:"|="===t&&(o===n||o.slice(0,n.length+1)===n+"-"))}},CHILD:function(e,t,n,r,o){var i="nth"!==e.slice(0,3),a="last"!==e.slice(-4),s="of-type"===t;return 1===r&&0===o?function(e){return!!e.parentNode}:function(t,n,c){var l,u,f,d,p,h=i!==a?"nextSibling":"previousSibling",v=t.parentNode,g=s&&t.nodeName.toLowerCase(),b=!c&&!s,_=!1;if(v){if(i){for(;h;){for(f=t;f=f[h];)if(s?$(f,g):1===f.nodeType)return!1;p=h="only"===e&&!p&&"nextSibling"}return!0}if(p=[a?v.firstChild:v.lastChild],a&&b){for(_=(d=(l=(u=v[m]||(v[m]={}))[e]||[])[0]===y&&l[1])&&l[2],f=d&&v.childNodes[d];f=++d&&f&&f[h]||(_=d=0)||p.pop();)if(1===f.nodeType&&++_&&f===t){u[e]=[y,d,_];break}}else if(b&&(_=d=(l=(u=t[m]||(t[m]={}))[e]||[])[0]===y&&l[1]),!1===_)for(;(f=++d&&f&&f[h]||(_=d=0)||p.pop())&&(!(s?$(f,g):1===f.nodeType)||!++_||(b&&((u=f[m]||(f[m]={}))[e]=[y,_]),f!==t)););return(_-=o)===r||_%r==0&&_/r>=0}}},PSEUDO:function(e,n){var r,o=t.pseudos[e]||t.setFilters[e.toLowerCase()]||Z.error("unsupported pseudo: "+e);return o[m]?o(n):o.length>1?(r=[e,e,"",n],t.setFilters.hasOwnProperty(e.toLowerCase())?te((function(e,t){for(var r,i=o(e,n),a=i.length;a--;)e[r=f.call(e,i[a])]=!(t[r]=i[a])})):function(e){return o(e,0,r)}):o}},pseudos:{not:te((function(e){var t=[],n=[],r=ge(e.replace(N,"$1"));return r[m]?te((function(e,t,n,o){for(var i,a=r(e,null,o,[]),s=e.length;s--;)(i=a[s])&&(e[s]=!(t[s]=i))})):function(e,o,i){return t[0]=e,r(t,null,i,n),t[0]=null,!n.pop()}})),has:te((function(e){return function(t){return Z(e,t).length>0}})),contains:te((function(e){return e=e.replace(X,Q),function(t){return(t.textContent||S.text(t)).indexOf(e)>-1}})),lang:te((function(e){return W.test(e||"")||Z.error("unsupported lang: "+e),e=e.replace(X,Q).toLowerCase(),function(t){var n;do{if(n=u?t.lang:t.getAttribute("xml:lang")||t.getAttribute("lang"))return(n=n.toLowerCase())===e||0===n.indexOf(e+"-")}while((t=t.parentNode)&&1===t.nodeType);return!1}})),target:function(e){var t=n.location&&n.location.hash;return t&&t.slice(1)===e.id},root:function(e){return e===l},focus:function(e){return e===function(){try{return s.activeElement}catch(e){}}()&&s.hasFocus()&&!!(e.type||e.href||~e.tabIndex)},enabled:ie(!1),disabled:ie(!0),checked:function(e){return $(e,"input")&&!!e.checked||$(e,"option")&&!!e.selected},selected:function(e){return e.parentNode&&e.parentNode.selectedIndex,!0===e.selected},empty:function(e){for(e=e.firstChild;e;e=e.nextSibling)if(e.nodeType<6)return!1;return!0},parent:function(e){return!t.pseudos.empty(e)},header:function(e){return V.test(e.nodeName)},input:function(e){return z.test(e.nodeName)},button:function(e){return $(e,"input")&&"button"===e.type||$(e,"button")},text:function(e){var t;return $(e,"input")&&"text"===e.type&&(null==(t=e.getAttribute("type"))||"text"===t.toLowerCase())},first:ae((function(){return[0]})),last:ae((function(e,t){return[t-1]})),eq:ae((function(e,t,n){return[n<0?n+t:n]})),even:ae((function(e,t){for(var n=0;n<t;n+=2)e.push(n);return e})),odd:ae((function(e,t){for(var n=1;n<t;n+=2)e.push(n);return e})),lt:ae((function(e,t,n){var r;for(r=n<0?n+t:n>t?t:n;--r>=0;)e.push(r);return e})),gt:ae((function(e,t,n){for(var r=n<0?n+t:n;++r<t;)e.push(r);return e}))}},t.pseudos.nth=t.pseudos.eq,{radio:!0,checkbox:!0,file:!0,password:!0,image:!0})t.pseudos[e]=re(e);for(e in{submit:!0,reset:!0})t.pseudos[e]=oe(e);function le(){}function ue(e,n){var r,o,i,a,s,c,l,u=x[e+" "];if(u)return n?0:u.slice(0);for(s=e,c=[],l=t.preFilter;s;){for(a in r&&!(o=F.exec(s))||(o&&(s=s.slice(o[0].length)||s),c.push(i=[])),r=!1,(o=H.exec(s))&&(r=o.shift(),i.push({value:r,type:o[0].replace(N," ")}),s=s.slice(r.length)),t.filter)!(o=U[a].exec(s))||l[a]&&!(o=l[a](o))||(r=o.shift(),i.push({value:r,type:a,matches:o}),s=s.slice(r.length));if(!r)break}return n?s.length:s?Z.error(e):x(e,c).slice(0)}function fe(e){for(var t=0,n=e.length,r="";t<n;t++)r+=e[t].value;return r}function de(e,t,n){var r=t.dir,o=t.next,i=o||r,a=n&&"parentNode"===i,s=b++;return t.first?function(t,n,o){for(;t=t[r];)if(1===t.nodeType||a)return e(t,n,o);return!1}:function(t,n,c){var l,u,f=[y,s];if(c){for(;t=t[r];)if((1===t.nodeType||a)&&e(t,n,c))return!0}else for(;t=t[r];)if(1===t.nodeType||a)if(u=t[m]||(t[m]={}),o&&$(t,o))t=t[r]||t;else{if((l=u[i])&&l[0]===y&&l[1]===s)return f[2]=l[2];if(u[i]=f,f[2]=e(t,n,c))return!0}return!1}}function pe(e){return e.length>1?function(t,n,r){for(var o=e.length;o--;)if(!e[o](t,n,r))return!1;return!0}:e[0]}function he(e,t,n,r,o){for(var i,a=[],s=0,c=e.length,l=null!=t;s<c;s++)(i=e[s])&&(n&&!n(i,r,o)||(a.push(i),l&&t.push(s)));return a}function ve(e,t,n,r,o,i){return r&&!r[m]&&(r=ve(r)),o&&!o[m]&&(o=ve(o,i)),te((function(i,a,s,c){var l,u,d,p,h=[],m=[],g=a.length,y=i||function(e,t,n){for(var r=0,o=t.length;r<o;r++)Z(e,t[r],n);return n}(t||"*",s.nodeType?[s]:s,[]),b=!e||!i&&t?y:he(y,h,e,s,c);if(n?n(b,p=o||(i?e:g||r)?[]:a,s,c):p=b,r)for(l=he(p,m),r(l,[],s,c),u=l.length;u--;)(d=l[u])&&(p[m[u]]=!(b[m[u]]=d));if(i){if(o||e){if(o){for(l=[],u=p.length;u--;)(d=p[u])&&l.push(b[u]=d);o(null,p=[],l,c)}for(u=p.length;u--;)(d=p[u])&&(l=o?f.call(i,d):h[u])>-1&&(i[l]=!(a[l]=d))}}else p=he(p===a?p.splice(g,p.length):p),o?o(null,a,p,c):v.apply(a,p)}))}function me(e){for(var n,o,i,a=e.length,s=t.relative[e[0].type],c=s||t.relative[" "],l=s?1:0,u=de((function(e){return e===n}),c,!0),d=de((function(e){return f.call(n,e)>-1}),c,!0),p=[function(e,t,o){var i=!s&&(o||t!=r)||((n=t).nodeType?u(e,t,o):d(e,t,o));return n=null,i}];l<a;l++)if(o=t.relative[e[l].type])p=[de(pe(p),o)];else{if((o=t.filter[e[l].type].apply(null,e[l].matches))[m]){for(i=++l;i<a&&!t.relative[e[i].type];i++);return ve(l>1&&pe(p),l>1&&fe(e.slice(0,l-1).concat({value:" "===e[l-2].type?"*":""})).replace(N,"$1"),o,l<i&&me(e.slice(l,i)),i<a&&me(e=e.slice(i)),i<a&&fe(e))}p.push(o)}return pe(p)}function ge(e,n){var o,i=[],a=[],c=w[e+" "];if(!c){for(n||(n=ue(e)),o=n.length;o--;)(c=me(n[o]))[m]?i.push(c):a.push(c);c=w(e,function(e,n){var o=n.length>0,i=e.length>0,a=function(a,c,l,f,d){var p,h,m,g=0,b="0",_=a&&[],x=[],w=r,C=a||i&&t.find.TAG("*",d),T=y+=null==w?1:Math.random()||.1,k=C.length;for(d&&(r=c==s||c||d);b!==k&&null!=(p=C[b]);b++){if(i&&p){for(h=0,c||p.ownerDocument==s||(ce(p),l=!u);m=e[h++];)if(m(p,c||s,l)){v.call(f,p);break}d&&(y=T)}o&&((p=!m&&p)&&g--,a&&_.push(p))}if(g+=b,o&&b!==g){for(h=0;m=n[h++];)m(_,x,c,l);if(a){if(g>0)for(;b--;)_[b]||x[b]||(x[b]=A.call(f));x=he(x)}v.apply(f,x),d&&!a&&x.length>0&&g+n.length>1&&S.uniqueSort(f)}return d&&(y=T,r=w),_};return o?te(a):a}(a,i)),c.selector=e}return c}function ye(e,n,r,o){var i,a,s,c,l,f="function"==typeof e&&e,d=!o&&ue(e=f.selector||e);if(r=r||[],1===d.length){if((a=d[0]=d[0].slice(0)).length>2&&"ID"===(s=a[0]).type&&9===n.nodeType&&u&&t.relative[a[1].type]){if(!(n=(t.find.ID(s.matches[0].replace(X,Q),n)||[])[0]))return r;f&&(n=n.parentNode),e=e.slice(a.shift().value.length)}for(i=U.needsContext.test(e)?0:a.length;i--&&(s=a[i],!t.relative[c=s.type]);)if((l=t.find[c])&&(o=l(s.matches[0].replace(X,Q),K.test(a[0].type)&&se(n.parentNode)||n))){if(a.splice(i,1),!(e=o.length&&fe(a)))return v.apply(r,o),r;break}}return(f||ge(e,d))(o,n,!u,r,!n||K.test(e)&&se(n.parentNode)||n),r}le.prototype=t.filters=t.pseudos,t.setFilters=new le,g.sortStable=m.split("").sort(T).join("")===m,ce(),g.sortDetached=ne((function(e){return 1&e.compareDocumentPosition(s.createElement("fieldset"))})),S.find=Z,S.expr[":"]=S.expr.pseudos,S.unique=S.uniqueSort,Z.compile=ge,Z.select=ye,Z.setDocument=ce,Z.tokenize=ue,Z.escape=S.escapeSelector,Z.getText=S.text,Z.isXML=S.isXMLDoc,Z.selectors=S.expr,Z.support=S.support,Z.uniqueSort=S.uniqueSort}();var R=function(e,t,n){for(var r=[],o=void 0!==n;(e=e[t])&&9!==e.nodeType;)if(1===e.nodeType){if(o&&S(e).is(n))break;r.push(e)}return r},F=function(e,t){for(var n=[];e;e=e.nextSibling)1===e.nodeType&&e!==t&&n.push(e);return n},H=S.expr.match.needsContext,q=/^<([a-z][^\/\0>:\x20\t\r\n\f]*)[\x20\t\r\n\f]*\/?>(?:<\/\1>|)$/i;function B(e,t,n){return y(t)?S.grep(e,(function(e,r){return!!t.call(e,r,e)!==n})):t.nodeType?S.grep(e,(function(e){return e===t!==n})):"string"!=typeof t?S.grep(e,(function(e){return f.call(t,e)>-1!==n})):S.filter(t,e,n)}S.filter=function(e,t,n){var r=t[0];return n&&(e=":not("+e+")"),1===t.length&&1===r.nodeType?S.find.matchesSelector(r,e)?[r]:[]:S.find.matches(e,S.grep(t,(function(e){return 1===e.nodeType})))},S.fn.extend({find:function(e){var t,n,r=this.length,o=this;if("string"!=typeof e)return this.pushStack(S(e).filter((function(){for(t=0;t<r;t++)if(S.contains(o[t],this))return!0})));for(n=this.pushStack([]),t=0;t<r;t++)S.find(e,o[t],n);return r>1?S.uniqueSort(n):n},filter:function(e){return this.pushStack(B(this,e||[],!1))},not:function(e){return this.pushStack(B(this,e||[],!0))},is:function(e){return!!B(this,"string"==typeof e&&H.test(e)?S(e):e||[],!1).length}});var W,U=/^(?:\s*(<[\w\W]+>)[^>]*|#([\w-]+))$/;(S.fn.init=function(e,t,n){var r,o;if(!e)return this;if(n=n||W,"string"==typeof e){if(!(r="<"===e[0]&&">"===e[e.length-1]&&e.length>=3?[null,e,null]:U.exec(e))||!r[1]&&t)return!t||t.jquery?(t||n).find(e):this.constructor(t).find(e);if(r[1]){if(t=t instanceof S?t[0]:t,S.merge(this,S.parseHTML(r[1],t&&t.nodeType?t.ownerDocument||t:_,!0)),q.test(r[1])&&S.isPlainObject(t))for(r in t)y(this[r])?this[r](t[r]):this.attr(r,t[r]);return this}return(o=_.getElementById(r[2]))&&(this[0]=o,this.length=1),this}return e.nodeType?(this[0]=e,this.length=1,this):y(e)?void 0!==n.ready?n.ready(e):e(S):S.makeArray(e,this)}).prototype=S.fn,W=S(_);var z=/^(?:parents|prev(?:Until|All))/,V={children:!0,contents:!0,next:!0,prev:!0};function J(e,t){for(;(e=e[t])&&1!==e.nodeType;);return e}S.fn.extend({has:function(e){var t=S(e,this),n=t.length;return this.filter((function(){for(var e=0;e<n;e++)if(S.contains(this,t[e]))return!0}))},closest:function(e,t){var n,r=0,o=this.length,i=[],a="string"!=typeof e&&S(e);if(!H.test(e))for(;r<o;r++)for(n=this[r];n&&n!==t;n=n.parentNode)if(n.nodeType<11&&(a?a.index(n)>-1:1===n.nodeType&&S.find.matchesSelector(n,e))){i.push(n);break}return this.pushStack(i.length>1?S.uniqueSort(i):i)},index:function(e){return e?"string"==typeof e?f.call(S(e),this[0]):f.call(this,e.jquery?e[0]:e):this[0]&&this[0].parentNode?this.first().prevAll().length:-1},add:function(e,t){return this.pushStack(S.uniqueSort(S.merge(this.get(),S(e,t))))},addBack:function(e){return this.add(null==e?this.prevObject:this.prevObject.filter(e))}}),S.each({parent:function(e){var t=e.parentNode;return t&&11!==t.nodeType?t:null},parents:function(e){return R(e,"parentNode")},parentsUntil:function(e,t,n){return R(e,"parentNode",n)},next:function(e){return J(e,"nextSibling")},prev:function(e){return J(e,"previousSibling")},nextAll:function(e){return R(e,"nextSibling")},prevAll:function(e){return R(e,"previousSibling")},nextUntil:function(e,t,n){return R(e,"nextSibling",n)},prevUntil:function(e,t,n){return R(e,"previousSibling",n)},siblings:function(e){return F((e.parentNode||{}).firstChild,e)},children:function(e){return F(e.firstChild)},contents:function(e){return null!=e.contentDocument&&s(e.contentDocument)?e.contentDocument:($(e,"template")&&(e=e.content||e),S.merge([],e.childNodes))}},(function(e,t){S.fn[e]=function(n,r){var o=S.map(this,t,n);return"Until"!==e.slice(-5)&&(r=n),r&&"string"==typeof r&&(o=S.filter(r,o)),this.length>1&&(V[e]||S.uniqueSort(o),z.test(e)&&o.reverse()),this.pushStack(o)}}));var K=/[^\x20\t\r\n\f]+/g;function X(e){return e}function Q(e){throw e}function G(e,t,n,r){var o;try{e&&y(o=e.promise)?o.call(e).done(t).fail(n):e&&y(o=e.then)?o.call(e,t,n):t.apply(void 0,[e].slice(r))}catch(e){n.apply(void 0,[e])}}S.Callbacks=function(e){e="string"==typeof e?function(e){var t={};return S.each(e.match(K)||[],(function(e,n){t[n]=!0})),t}(e):S.extend({},e);var t,n,r,o,i=[],a=[],s=-1,c=function(){for(o=o||e.once,r=t=!0;a.length;s=-1)for(n=a.shift();++s<i.length;)!1===i[s].apply(n[0],n[1])&&e.stopOnFalse&&(s=i.length,n=!1);e.memory||(n=!1),t=!1,o&&(i=n?[]:"")},l={add:function(){return i&&(n&&!t&&(s=i.length-1,a.push(n)),function t(n){S.each(n,(function(n,r){y(r)?e.unique&&l.has(r)||i.push(r):r&&r.length&&"string"!==C(r)&&t(r)}))}(arguments),n&&!t&&c()),this},remove:function(){return S.each(arguments,(function(e,t){for(var n;(n=S.inArray(t,i,n))>-1;)i.splice(n,1),n<=s&&s--})),this},has:function(e){return e?S.inArray(e,i)>-1:i.length>0},empty:function(){return i&&(i=[]),this},disable:function(){return o=a=[],i=n="",this},disabled:function(){return!i},lock:function(){return o=a=[],n||t||(i=n=""),this},locked:function(){return!!o},fireWith:function(e,n){return o||(n=[e,(n=n||[]).slice?n.slice():n],a.push(n),t||c()),this},fire:function(){return l.fireWith(this,arguments),this},fired:function(){return!!r}};return l},S.extend({Deferred:function(e){var t=[["notify","progress",S.Callbacks("memory"),S.Callbacks("memory"),2],["resolve","done",S.Callbacks("once memory"),S.Callbacks("once memory"),0,"resolved"],["reject","fail",S.Callbacks("once memory"),S.Callbacks("once memory"),1,"rejected"]],r="pending",i={state:function(){return r},always:function(){return a.done(arguments).fail(arguments),this},catch:function(e){return i.then(null,e)},pipe:function(){var e=arguments;return S.Deferred((function(n){S.each(t,(function(t,r){var o=y(e[r[4]])&&e[r[4]];a[r[1]]((function(){var e=o&&o.apply(this,arguments);e&&y(e.promise)?e.promise().progress(n.notify).done(n.resolve).fail(n.reject):n[r[0]+"With"](this,o?[e]:arguments)}))})),e=null})).promise()},then:function(e,r,i){var a=0;function s(e,t,r,i){return function(){var c=this,l=arguments,u=function(){var n,u;if(!(e<a)){if((n=r.apply(c,l))===t.promise())throw new TypeError("Thenable self-resolution");u=n&&("object"===o(n)||"function"==typeof n)&&n.then,y(u)?i?u.call(n,s(a,t,X,i),s(a,t,Q,i)):(a++,u.call(n,s(a,t,X,i),s(a,t,Q,i),s(a,t,X,t.notifyWith))):(r!==X&&(c=void 0,l=[n]),(i||t.resolveWith)(c,l))}},f=i?u:function(){try{u()}catch(n){S.Deferred.exceptionHook&&S.Deferred.exceptionHook(n,f.error),e+1>=a&&(r!==Q&&(c=void 0,l=[n]),t.rejectWith(c,l))}};e?f():(S.Deferred.getErrorHook?f.error=S.Deferred.getErrorHook():S.Deferred.getStackHook&&(f.error=S.Deferred.getStackHook()),n.setTimeout(f))}}return S.Deferred((function(n){t[0][3].add(s(0,n,y(i)?i:X,n.notifyWith)),t[1][3].add(s(0,n,y(e)?e:X)),t[2][3].add(s(0,n,y(r)?r:Q))})).promise()},promise:function(e){return null!=e?S.extend(e,i):i}},a={};return S.each(t,(function(e,n){var o=n[2],s=n[5];i[n[1]]=o.add,s&&o.add((function(){r=s}),t[3-e][2].disable,t[3-e][3].disable,t[0][2].lock,t[0][3].lock),o.add(n[3].fire),a[n[0]]=function(){return a[n[0]+"With"](this===a?void 0:this,arguments),this},a[n[0]+"With"]=o.fireWith})),i.promise(a),e&&e.call(a,a),a},when:function(e){var t=arguments.length,n=t,r=Array(n),o=c.call(arguments),i=S.Deferred(),a=function(e){return function(n){r[e]=this,o[e]=arguments.length>1?c.call(arguments):n,--t||i.resolveWith(r,o)}};if(t<=1&&(G(e,i.done(a(n)).resolve,i.reject,!t),"pending"===i.state()||y(o[n]&&o[n].then)))return i.then();for(;n--;)G(o[n],a(n),i.reject);return i.promise()}});var Y=/^(Eval|Internal|Range|Reference|Syntax|Type|URI)Error$/;S.Deferred.exceptionHook=function(e,t){n.console&&n.console.warn&&e&&Y.test(e.name)&&n.console.warn("jQuery.Deferred exception: "+e.message,e.stack,t)},S.readyException=function(e){n.setTimeout((function(){throw e}))};var Z=S.Deferred();function ee(){_.removeEventListener("DOMContentLoaded",ee),n.removeEventListener("load",ee),S.ready()}S.fn.ready=function(e){return Z.then(e).catch((function(e){S.readyException(e)})),this},S.extend({isReady:!1,readyWait:1,ready:function(e){(!0===e?--S.readyWait:S.isReady)||(S.isReady=!0,!0!==e&&--S.readyWait>0||Z.resolveWith(_,[S]))}}),S.ready.then=Z.then,"complete"===_.readyState||"loading"!==_.readyState&&!_.documentElement.doScroll?n.setTimeout(S.ready):(_.addEventListener("DOMContentLoaded",ee),n.addEventListener("load",ee));var te=function e(t,n,r,o,i,a,s){var c=0,l=t.length,u=null==r;if("object"===C(r))for(c in i=!0,r)e(t,n,c,r[c],!0,a,s);else if(void 0!==o&&(i=!0,y(o)||(s=!0),u&&(s?(n.call(t,o),n=null):(u=n,n=function(e,t,n){return u.call(S(e),n)})),n))for(;c<l;c++)n(t[c],r,s?o:o.call(t[c],c,n(t[c],r)));return i?t:u?n.call(t):l?n(t[0],r):a},ne=/^-ms-/,re=/-([a-z])/g;function oe(e,t){return t.toUpperCase()}function ie(e){return e.replace(ne,"ms-").replace(re,oe)}var ae=function(e){return 1===e.nodeType||9===e.nodeType||!+e.nodeType};function se(){this.expando=S.expando+se.uid++}se.uid=1,se.prototype={cache:function(e){var t=e[this.expando];return t||(t={},ae(e)&&(e.nodeType?e[this.expando]=t:Object.defineProperty(e,this.expando,{value:t,configurable:!0}))),t},set:function(e,t,n){var r,o=this.cache(e);if("string"==typeof t)o[ie(t)]=n;else for(r in t)o[ie(r)]=t[r];return o},get:function(e,t){return void 0===t?this.cache(e):e[this.expando]&&e[this.expando][ie(t)]},access:function(e,t,n){return void 0===t||t&&"string"==typeof t&&void 0===n?this.get(e,t):(this.set(e,t,n),void 0!==n?n:t)},remove:function(e,t){var n,r=e[this.expando];if(void 0!==r){if(void 0!==t){n=(t=Array.isArray(t)?t.map(ie):(t=ie(t))in r?[t]:t.match(K)||[]).length;for(;n--;)delete r[t[n]]}(void 0===t||S.isEmptyObject(r))&&(e.nodeType?e[this.expando]=void 0:delete e[this.expando])}},hasData:function(e){var t=e[this.expando];return void 0!==t&&!S.isEmptyObject(t)}};var ce=new se,le=new se,ue=/^(?:\{[\w\W]*\}|\[[\w\W]*\])$/,fe=/[A-Z]/g;function de(e,t,n){var r;if(void 0===n&&1===e.nodeType)if(r="data-"+t.replace(fe,"-$&").toLowerCase(),"string"==typeof(n=e.getAttribute(r))){try{n=function(e){return"true"===e||"false"!==e&&("null"===e?null:e===+e+""?+e:ue.test(e)?JSON.parse(e):e)}(n)}catch(e){}le.set(e,t,n)}else n=void 0;return n}S.extend({hasData:function(e){return le.hasData(e)||ce.hasData(e)},data:function(e,t,n){return le.access(e,t,n)},removeData:function(e,t){le.remove(e,t)},_data:function(e,t,n){return ce.access(e,t,n)},_removeData:function(e,t){ce.remove(e,t)}}),S.fn.extend({data:function(e,t){var n,r,i,a=this[0],s=a&&a.attributes;if(void 0===e){if(this.length&&(i=le.get(a),1===a.nodeType&&!ce.get(a,"hasDataAttrs"))){for(n=s.length;n--;)s[n]&&0===(r=s[n].name).indexOf("data-")&&(r=ie(r.slice(5)),de(a,r,i[r]));ce.set(a,"hasDataAttrs",!0)}return i}return"object"===o(e)?this.each((function(){le.set(this,e)})):te(this,(function(t){var n;if(a&&void 0===t)return void 0!==(n=le.get(a,e))||void 0!==(n=de(a,e))?n:void 0;this.each((function(){le.set(this,e,t)}))}),null,t,arguments.length>1,null,!0)},removeData:function(e){return this.each((function(){le.remove(this,e)}))}}),S.extend({queue:function(e,t,n){var r;if(e)return t=(t||"fx")+"queue",r=ce.get(e,t),n&&(!r||Array.isArray(n)?r=ce.access(e,t,S.makeArray(n)):r.push(n)),r||[]},dequeue:function(e,t){t=t||"fx";var n=S.queue(e,t),r=n.length,o=n.shift(),i=S._queueHooks(e,t);"inprogress"===o&&(o=n.shift(),r--),o&&("fx"===t&&n.unshift("inprogress"),delete i.stop,o.call(e,(function(){S.dequeue(e,t)}),i)),!r&&i&&i.empty.fire()},_queueHooks:function(e,t){var n=t+"queueHooks";return ce.get(e,n)||ce.access(e,n,{empty:S.Callbacks("once memory").add((function(){ce.remove(e,[t+"queue",n])}))})}}),S.fn.extend({queue:function(e,t){var n=2;return"string"!=typeof e&&(t=e,e="fx",n--),arguments.length<n?S.queue(this[0],e):void 0===t?this:this.each((function(){var n=S.queue(this,e,t);S._queueHooks(this,e),"fx"===e&&"inprogress"!==n[0]&&S.dequeue(this,e)}))},dequeue:function(e){return this.each((function(){S.dequeue(this,e)}))},clearQueue:function(e){return this.queue(e||"fx",[])},promise:function(e,t){var n,r=1,o=S.Deferred(),i=this,a=this.length,s=function(){--r||o.resolveWith(i,[i])};for("string"!=typeof e&&(t=e,e=void 0),e=e||"fx";a--;)(n=ce.get(i[a],e+"queueHooks"))&&n.empty&&(r++,n.empty.add(s));return s(),o.promise(t)}});var pe=/[+-]?(?:\d*\.|)\d+(?:[eE][+-]?\d+|)/.source,he=new RegExp("^(?:([+-])=|)("+pe+")([a-z%]*)$","i"),ve=["Top","Right","Bottom","Left"],me=_.documentElement,ge=function(e){return S.contains(e.ownerDocument,e)},ye={composed:!0};me.getRootNode&&(ge=function(e){return S.contains(e.ownerDocument,e)||e.getRootNode(ye)===e.ownerDocument});var be=function(e,t){return"none"===(e=t||e).style.display||""===e.style.display&&ge(e)&&"none"===S.css(e,"display")};function _e(e,t,n,r){var o,i,a=20,s=r?function(){return r.cur()}:function(){return S.css(e,t,"")},c=s(),l=n&&n[3]||(S.cssNumber[t]?"":"px"),u=e.nodeType&&(S.cssNumber[t]||"px"!==l&&+c)&&he.exec(S.css(e,t));if(u&&u[3]!==l){for(c/=2,l=l||u[3],u=+c||1;a--;)S.style(e,t,u+l),(1-i)*(1-(i=s()/c||.5))<=0&&(a=0),u/=i;u*=2,S.style(e,t,u+l),n=n||[]}return n&&(u=+u||+c||0,o=n[1]?u+(n[1]+1)*n[2]:+n[2],r&&(r.unit=l,r.start=u,r.end=o)),o}var xe={};function we(e){var t,n=e.ownerDocument,r=e.nodeName,o=xe[r];return o||(t=n.body.appendChild(n.createElement(r)),o=S.css(t,"display"),t.parentNode.removeChild(t),"none"===o&&(o="block"),xe[r]=o,o)}function Ce(e,t){for(var n,r,o=[],i=0,a=e.length;i<a;i++)(r=e[i]).style&&(n=r.style.display,t?("none"===n&&(o[i]=ce.get(r,"display")||null,o[i]||(r.style.display="")),""===r.style.display&&be(r)&&(o[i]=we(r))):"none"!==n&&(o[i]="none",ce.set(r,"display",n)));for(i=0;i<a;i++)null!=o[i]&&(e[i].style.display=o[i]);return e}S.fn.extend({show:function(){return Ce(this,!0)},hide:function(){return Ce(this)},toggle:function(e){return"boolean"==typeof e?e?this.show():this.hide():this.each((function(){be(this)?S(this).show():S(this).hide()}))}});var Te,ke,Se=/^(?:checkbox|radio)$/i,Ee=/<([a-z][^\/\0>\x20\t\r\n\f]*)/i,$e=/^$|^module$|\/(?:java|ecma)script/i;Te=_.createDocumentFragment().appendChild(_.createElement("div")),(ke=_.createElement("input")).setAttribute("type","radio"),ke.setAttribute("checked","checked"),ke.setAttribute("name","t"),Te.appendChild(ke),g.checkClone=Te.cloneNode(!0).cloneNode(!0).lastChild.checked,Te.innerHTML="<textarea>x</textarea>",g.noCloneChecked=!!Te.cloneNode(!0).lastChild.defaultValue,Te.innerHTML="<option></option>",g.option=!!Te.lastChild;var Ae={thead:[1,"<table>","</table>"],col:[2,"<table><colgroup>","</colgroup></table>"],tr:[2,"<table><tbody>","</tbody></table>"],td:[3,"<table><tbody><tr>","</tr></tbody></table>"],_default:[0,"",""]};function Oe(e,t){var n;return n=void 0!==e.getElementsByTagName?e.getElementsByTagName(t||"*"):void 0!==e.querySelectorAll?e.querySelectorAll(t||"*"):[],void 0===t||t&&$(e,t)?S.merge([e],n):n}function je(e,t){for(var n=0,r=e.length;n<r;n++)ce.set(e[n],"globalEval",!t||ce.get(t[n],"globalEval"))}Ae.tbody=Ae.tfoot=Ae.colgroup=Ae.caption=Ae.thead,Ae.th=Ae.td,g.option||(Ae.optgroup=Ae.option=[1,"<select multiple='multiple'>","</select>"]);var De=/<|&#?\w+;/;function Ne(e,t,n,r,o){for(var i,a,s,c,l,u,f=t.createDocumentFragment(),d=[],p=0,h=e.length;p<h;p++)if((i=e[p])||0===i)if("object"===C(i))S.merge(d,i.nodeType?[i]:i);else if(De.test(i)){for(a=a||f.appendChild(t.createElement("div")),s=(Ee.exec(i)||["",""])[1].toLowerCase(),c=Ae[s]||Ae._default,a.innerHTML=c[1]+S.htmlPrefilter(i)+c[2],u=c[0];u--;)a=a.lastChild;S.merge(d,a.childNodes),(a=f.firstChild).textContent=""}else d.push(t.createTextNode(i));for(f.textContent="",p=0;i=d[p++];)if(r&&S.inArray(i,r)>-1)o&&o.push(i);else if(l=ge(i),a=Oe(f.appendChild(i),"script"),l&&je(a),n)for(u=0;i=a[u++];)$e.test(i.type||"")&&n.push(i);return f}var Le=/^([^.]*)(?:\.(.+)|)/;function Pe(){return!0}function Ie(){return!1}function Me(e,t,n,r,i,a){var s,c;if("object"===o(t)){for(c in"string"!=typeof n&&(r=r||n,n=void 0),t)Me(e,c,n,r,t[c],a);return e}if(null==r&&null==i?(i=n,r=n=void 0):null==i&&("string"==typeof n?(i=r,r=void 0):(i=r,r=n,n=void 0)),!1===i)i=Ie;else if(!i)return e;return 1===a&&(s=i,i=function(e){return S().off(e),s.apply(this,arguments)},i.guid=s.guid||(s.guid=S.guid++)),e.each((function(){S.event.add(this,t,i,r,n)}))}function Re(e,t,n){n?(ce.set(e,t,!1),S.event.add(e,t,{namespace:!1,handler:function(e){var n,r=ce.get(this,t);if(1&e.isTrigger&&this[t]){if(r)(S.event.special[t]||{}).delegateType&&e.stopPropagation();else if(r=c.call(arguments),ce.set(this,t,r),this[t](),n=ce.get(this,t),ce.set(this,t,!1),r!==n)return e.stopImmediatePropagation(),e.preventDefault(),n}else r&&(ce.set(this,t,S.event.trigger(r[0],r.slice(1),this)),e.stopPropagation(),e.isImmediatePropagationStopped=Pe)}})):void 0===ce.get(e,t)&&S.event.add(e,t,Pe)}S.event={global:{},add:function(e,t,n,r,o){var i,a,s,c,l,u,f,d,p,h,v,m=ce.get(e);if(ae(e))for(n.handler&&(n=(i=n).handler,o=i.selector),o&&S.find.matchesSelector(me,o),n.guid||(n.guid=S.guid++),(c=m.events)||(c=m.events=Object.create(null)),(a=m.handle)||(a=m.handle=function(t){return void 0!==S&&S.event.triggered!==t.type?S.event.dispatch.apply(e,arguments):void 0}),l=(t=(t||"").match(K)||[""]).length;l--;)p=v=(s=Le.exec(t[l])||[])[1],h=(s[2]||"").split(".").sort(),p&&(f=S.event.special[p]||{},p=(o?f.delegateType:f.bindType)||p,f=S.event.special[p]||{},u=S.extend({type:p,origType:v,data:r,handler:n,guid:n.guid,selector:o,needsContext:o&&S.expr.match.needsContext.test(o),namespace:h.join(".")},i),(d=c[p])||((d=c[p]=[]).delegateCount=0,f.setup&&!1!==f.setup.call(e,r,h,a)||e.addEventListener&&e.addEventListener(p,a)),f.add&&(f.add.call(e,u),u.handler.guid||(u.handler.guid=n.guid)),o?d.splice(d.delegateCount++,0,u):d.push(u),S.event.global[p]=!0)},remove:function(e,t,n,r,o){var i,a,s,c,l,u,f,d,p,h,v,m=ce.hasData(e)&&ce.get(e);if(m&&(c=m.events)){for(l=(t=(t||"").match(K)||[""]).length;l--;)if(p=v=(s=Le.exec(t[l])||[])[1],h=(s[2]||"").split(".").sort(),p){for(f=S.event.special[p]||{},d=c[p=(r?f.delegateType:f.bindType)||p]||[],s=s[2]&&new RegExp("(^|\\.)"+h.join("\\.(?:.*\\.|)")+"(\\.|$)"),a=i=d.length;i--;)u=d[i],!o&&v!==u.origType||n&&n.guid!==u.guid||s&&!s.test(u.namespace)||r&&r!==u.selector&&("**"!==r||!u.selector)||(d.splice(i,1),u.selector&&d.delegateCount--,f.remove&&f.remove.call(e,u));a&&!d.length&&(f.teardown&&!1!==f.teardown.call(e,h,m.handle)||S.removeEvent(e,p,m.handle),delete c[p])}else for(p in c)S.event.remove(e,p+t[l],n,r,!0);S.isEmptyObject(c)&&ce.remove(e,"handle events")}},dispatch:function(e){var t,n,r,o,i,a,s=new Array(arguments.length),c=S.event.fix(e),l=(ce.get(this,"events")||Object.create(null))[c.type]||[],u=S.event.special[c.type]||{};for(s[0]=c,t=1;t<arguments.length;t++)s[t]=arguments[t];if(c.delegateTarget=this,!u.preDispatch||!1!==u.preDispatch.call(this,c)){for(a=S.event.handlers.call(this,c,l),t=0;(o=a[t++])&&!c.isPropagationStopped();)for(c.currentTarget=o.elem,n=0;(i=o.handlers[n++])&&!c.isImmediatePropagationStopped();)c.rnamespace&&!1!==i.namespace&&!c.rnamespace.test(i.namespace)||(c.handleObj=i,c.data=i.data,void 0!==(r=((S.event.special[i.origType]||{}).handle||i.handler).apply(o.elem,s))&&!1===(c.result=r)&&(c.preventDefault(),c.stopPropagation()));return u.postDispatch&&u.postDispatch.call(this,c),c.result}},handlers:function(e,t){var n,r,o,i,a,s=[],c=t.delegateCount,l=e.target;if(c&&l.nodeType&&!("click"===e.type&&e.button>=1))for(;l!==this;l=l.parentNode||this)if(1===l.nodeType&&("click"!==e.type||!0!==l.disabled)){for(i=[],a={},n=0;n<c;n++)void 0===a[o=(r=t[n]).selector+" "]&&(a[o]=r.needsContext?S(o,this).index(l)>-1:S.find(o,this,null,[l]).length),a[o]&&i.push(r);i.length&&s.push({elem:l,handlers:i})}return l=this,c<t.length&&s.push({elem:l,handlers:t.slice(c)}),s},addProp:function(e,t){Object.defineProperty(S.Event.prototype,e,{enumerable:!0,configurable:!0,get:y(t)?function(){if(this.originalEvent)return t(this.originalEvent)}:function(){if(this.originalEvent)return this.originalEvent[e]},set:function(t){Object.defineProperty(this,e,{enumerable:!0,configurable:!0,writable:!0,value:t})}})},fix:function(e){return e[S.expando]?e:new S.Event(e)},special:{load:{noBubble:!0},click:{setup:function(e){var t=this||e;return Se.test(t.type)&&t.click&&$(t,"input")&&Re(t,"click",!0),!1},trigger:function(e){var t=this||e;return Se.test(t.type)&&t.click&&$(t,"input")&&Re(t,"click"),!0},_default:function(e){var t=e.target;return Se.test(t.type)&&t.click&&$(t,"input")&&ce.get(t,"click")||$(t,"a")}},beforeunload:{postDispatch:function(e){void 0!==e.result&&e.originalEvent&&(e.originalEvent.returnValue=e.result)}}}},S.removeEvent=function(e,t,n){e.removeEventListener&&e.removeEventListener(t,n)},S.Event=function(e,t){if(!(this instanceof S.Event))return new S.Event(e,t);e&&e.type?(this.originalEvent=e,this.type=e.type,this.isDefaultPrevented=e.defaultPrevented||void 0===e.defaultPrevented&&!1===e.returnValue?Pe:Ie,this.target=e.target&&3===e.target.nodeType?e.target.parentNode:e.target,this.currentTarget=e.currentTarget,this.relatedTarget=e.relatedTarget):this.type=e,t&&S.extend(this,t),this.timeStamp=e&&e.timeStamp||Date.now(),this[S.expando]=!0},S.Event.prototype={constructor:S.Event,isDefaultPrevented:Ie,isPropagationStopped:Ie,isImmediatePropagationStopped:Ie,isSimulated:!1,preventDefault:function(){var e=this.originalEvent;this.isDefaultPrevented=Pe,e&&!this.isSimulated&&e.preventDefault()},stopPropagation:function(){var e=this.originalEvent;this.isPropagationStopped=Pe,e&&!this.isSimulated&&e.stopPropagation()},stopImmediatePropagation:function(){var e=this.originalEvent;this.isImmediatePropagationStopped=Pe,e&&!this.isSimulated&&e.stopImmediatePropagation(),this.stopPropagation()}},S.each({altKey:!0,bubbles:!0,cancelable:!0,changedTouches:!0,ctrlKey:!0,detail:!0,eventPhase:!0,metaKey:!0,pageX:!0,pageY:!0,shiftKey:!0,view:!0,char:!0,code:!0,charCode:!0,key:!0,keyCode:!0,button:!0,buttons:!0,clientX:!0,clientY:!0,offsetX:!0,offsetY:!0,pointerId:!0,pointerType:!0,screenX:!0,screenY:!0,targetTouches:!0,toElement:!0,touches:!0,which:!0},S.event.addProp),S.each({focus:"focusin",blur:"focusout"},(function(e,t){function n(e){if(_.documentMode){var n=ce.get(this,"handle"),r=S.event.fix(e);r.type="focusin"===e.type?"focus":"blur",r.isSimulated=!0,n(e),r.target===r.currentTarget&&n(r)}else S.event.simulate(t,e.target,S.event.fix(e))}S.event.special[e]={setup:function(){var r;if(Re(this,e,!0),!_.documentMode)return!1;(r=ce.get(this,t))||this.addEventListener(t,n),ce.set(this,t,(r||0)+1)},trigger:function(){return Re(this,e),!0},teardown:function(){var e;if(!_.documentMode)return!1;(e=ce.get(this,t)-1)?ce.set(this,t,e):(this.removeEventListener(t,n),ce.remove(this,t))},_default:function(t){return ce.get(t.target,e)},delegateType:t},S.event.special[t]={setup:function(){var r=this.ownerDocument||this.document||this,o=_.documentMode?this:r,i=ce.get(o,t);i||(_.documentMode?this.addEventListener(t,n):r.addEventListener(e,n,!0)),ce.set(o,t,(i||0)+1)},teardown:function(){var r=this.ownerDocument||this.document||this,o=_.documentMode?this:r,i=ce.get(o,t)-1;i?ce.set(o,t,i):(_.documentMode?this.removeEventListener(t,n):r.removeEventListener(e,n,!0),ce.remove(o,t))}}})),S.each({mouseenter:"mouseover",mouseleave:"mouseout",pointerenter:"pointerover",pointerleave:"pointerout"},(function(e,t){S.event.special[e]={delegateType:t,bindType:t,handle:function(e){var n,r=e.relatedTarget,o=e.handleObj;return r&&(r===this||S.contains(this,r))||(e.type=o.origType,n=o.handler.apply(this,arguments),e.type=t),n}}})),S.fn.extend({on:function(e,t,n,r){return Me(this,e,t,n,r)},one:function(e,t,n,r){return Me(this,e,t,n,r,1)},off:function(e,t,n){var r,i;if(e&&e.preventDefault&&e.handleObj)return r=e.handleObj,S(e.delegateTarget).off(r.namespace?r.origType+"."+r.namespace:r.origType,r.selector,r.handler),this;if("object"===o(e)){for(i in e)this.off(i,t,e[i]);return this}return!1!==t&&"function"!=typeof t||(n=t,t=void 0),!1===n&&(n=Ie),this.each((function(){S.event.remove(this,e,n,t)}))}});var Fe=/<script|<style|<link/i,He=/checked\s*(?:[^=]|=\s*.checked.)/i,qe=/^\s*<!\[CDATA\[|\]\]>\s*$/g;function Be(e,t){return $(e,"table")&&$(11!==t.nodeType?t:t.firstChild,"tr")&&S(e).children("tbody")[0]||e}function We(e){return e.type=(null!==e.getAttribute("type"))+"/"+e.type,e}function Ue(e){return"true/"===(e.type||"").slice(0,5)?e.type=e.type.slice(5):e.removeAttribute("type"),e}function ze(e,t){var n,r,o,i,a,s;if(1===t.nodeType){if(ce.hasData(e)&&(s=ce.get(e).events))for(o in ce.remove(t,"handle events"),s)for(n=0,r=s[o].length;n<r;n++)S.event.add(t,o,s[o][n]);le.hasData(e)&&(i=le.access(e),a=S.extend({},i),le.set(t,a))}}function Ve(e,t){var n=t.nodeName.toLowerCase();"input"===n&&Se.test(e.type)?t.checked=e.checked:"input"!==n&&"textarea"!==n||(t.defaultValue=e.defaultValue)}function Je(e,t,n,r){t=l(t);var o,i,a,s,c,u,f=0,d=e.length,p=d-1,h=t[0],v=y(h);if(v||d>1&&"string"==typeof h&&!g.checkClone&&He.test(h))return e.each((function(o){var i=e.eq(o);v&&(t[0]=h.call(this,o,i.html())),Je(i,t,n,r)}));if(d&&(i=(o=Ne(t,e[0].ownerDocument,!1,e,r)).firstChild,1===o.childNodes.length&&(o=i),i||r)){for(s=(a=S.map(Oe(o,"script"),We)).length;f<d;f++)c=o,f!==p&&(c=S.clone(c,!0,!0),s&&S.merge(a,Oe(c,"script"))),n.call(e[f],c,f);if(s)for(u=a[a.length-1].ownerDocument,S.map(a,Ue),f=0;f<s;f++)c=a[f],$e.test(c.type||"")&&!ce.access(c,"globalEval")&&S.contains(u,c)&&(c.src&&"module"!==(c.type||"").toLowerCase()?S._evalUrl&&!c.noModule&&S._evalUrl(c.src,{nonce:c.nonce||c.getAttribute("nonce")},u):w(c.textContent.replace(qe,""),c,u))}return e}function Ke(e,t,n){for(var r,o=t?S.filter(t,e):e,i=0;null!=(r=o[i]);i++)n||1!==r.nodeType||S.cleanData(Oe(r)),r.parentNode&&(n&&ge(r)&&je(Oe(r,"script")),r.parentNode.removeChild(r));return e}S.extend({htmlPrefilter:function(e){return e},clone:function(e,t,n){var r,o,i,a,s=e.cloneNode(!0),c=ge(e);if(!(g.noCloneChecked||1!==e.nodeType&&11!==e.nodeType||S.isXMLDoc(e)))for(a=Oe(s),r=0,o=(i=Oe(e)).length;r<o;r++)Ve(i[r],a[r]);if(t)if(n)for(i=i||Oe(e),a=a||Oe(s),r=0,o=i.length;r<o;r++)ze(i[r],a[r]);else ze(e,s);return(a=Oe(s,"script")).length>0&&je(a,!c&&Oe(e,"script")),s},cleanData:function(e){for(var t,n,r,o=S.event.special,i=0;void 0!==(n=e[i]);i++)if(ae(n)){if(t=n[ce.expando]){if(t.events)for(r in t.events)o[r]?S.event.remove(n,r):S.removeEvent(n,r,t.handle);n[ce.expando]=void 0}n[le.expando]&&(n[le.expando]=void 0)}}}),S.fn.extend({detach:function(e){return Ke(this,e,!0)},remove:function(e){return Ke(this,e)},text:function(e){return te(this,(function(e){return void 0===e?S.text(this):this.empty().each((function(){1!==this.nodeType&&11!==this.nodeType&&9!==this.nodeType||(this.textContent=e)}))}),null,e,arguments.length)},append:function(){return Je(this,arguments,(function(e){1!==this.nodeType&&11!==this.nodeType&&9!==this.nodeType||Be(this,e).appendChild(e)}))},prepend:function(){return Je(this,arguments,(function(e){if(1===this.nodeType||11===this.nodeType||9===this.nodeType){var t=Be(this,e);t.insertBefore(e,t.firstChild)}}))},before:function(){return Je(this,arguments,(function(e){this.parentNode&&this.parentNode.insertBefore(e,this)}))},after:function(){return Je(this,arguments,(function(e){this.parentNode&&this.parentNode.insertBefore(e,this.nextSibling)}))},empty:function(){for(var e,t=0;null!=(e=this[t]);t++)1===e.nodeType&&(S.cleanData(Oe(e,!1)),e.textContent="");return this},clone:function(e,t){return e=null!=e&&e,t=null==t?e:t,this.map((function(){return S.clone(this,e,t)}))},html:function(e){return te(this,(function(e){var t=this[0]||{},n=0,r=this.length;if(void 0===e&&1===t.nodeType)return t.innerHTML;if("string"==typeof e&&!Fe.test(e)&&!Ae[(Ee.exec(e)||["",""])[1].toLowerCase()]){e=S.htmlPrefilter(e);try{for(;n<r;n++)1===(t=this[n]||{}).nodeType&&(S.cleanData(Oe(t,!1)),t.innerHTML=e);t=0}catch(e){}}t&&this.empty().append(e)}),null,e,arguments.length)},replaceWith:function(){var e=[];return Je(this,arguments,(function(t){var n=this.parentNode;S.inArray(this,e)<0&&(S.cleanData(Oe(this)),n&&n.replaceChild(t,this))}),e)}}),S.each({appendTo:"append",prependTo:"prepend",insertBefore:"before",insertAfter:"after",replaceAll:"replaceWith"},(function(e,t){S.fn[e]=function(e){for(var n,r=[],o=S(e),i=o.length-1,a=0;a<=i;a++)n=a===i?this:this.clone(!0),S(o[a])[t](n),u.apply(r,n.get());return this.pushStack(r)}}));var Xe=new RegExp("^("+pe+")(?!px)[a-z%]+$","i"),Qe=/^--/,Ge=function(e){var t=e.ownerDocument.defaultView;return t&&t.opener||(t=n),t.getComputedStyle(e)},Ye=function(e,t,n){var r,o,i={};for(o in t)i[o]=e.style[o],e.style[o]=t[o];for(o in r=n.call(e),t)e.style[o]=i[o];return r},Ze=new RegExp(ve.join("|"),"i");function et(e,t,n){var r,o,i,a,s=Qe.test(t),c=e.style;return(n=n||Ge(e))&&(a=n.getPropertyValue(t)||n[t],s&&a&&(a=a.replace(N,"$1")||void 0),""!==a||ge(e)||(a=S.style(e,t)),!g.pixelBoxStyles()&&Xe.test(a)&&Ze.test(t)&&(r=c.width,o=c.minWidth,i=c.maxWidth,c.minWidth=c.maxWidth=c.width=a,a=n.width,c.width=r,c.minWidth=o,c.maxWidth=i)),void 0!==a?a+"":a}function tt(e,t){return{get:function(){if(!e())return(this.get=t).apply(this,arguments);delete this.get}}}!function(){function e(){if(u){l.style.cssText="position:absolute;left:-11111px;width:60px;margin-top:1px;padding:0;border:0",u.style.cssText="position:relative;display:block;box-sizing:border-box;overflow:scroll;margin:auto;border:1px;padding:1px;width:60%;top:1%",me.appendChild(l).appendChild(u);var e=n.getComputedStyle(u);r="1%"!==e.top,c=12===t(e.marginLeft),u.style.right="60%",a=36===t(e.right),o=36===t(e.width),u.style.position="absolute",i=12===t(u.offsetWidth/3),me.removeChild(l),u=null}}function t(e){return Math.round(parseFloat(e))}var r,o,i,a,s,c,l=_.createElement("div"),u=_.createElement("div");u.style&&(u.style.backgroundClip="content-box",u.cloneNode(!0).style.backgroundClip="",g.clearCloneStyle="content-box"===u.style.backgroundClip,S.extend(g,{boxSizingReliable:function(){return e(),o},pixelBoxStyles:function(){return e(),a},pixelPosition:function(){return e(),r},reliableMarginLeft:function(){return e(),c},scrollboxSize:function(){return e(),i},reliableTrDimensions:function(){var e,t,r,o;return null==s&&(e=_.createElement("table"),t=_.createElement("tr"),r=_.createElement("div"),e.style.cssText="position:absolute;left:-11111px;border-collapse:separate",t.style.cssText="box-sizing:content-box;border:1px solid",t.style.height="1px",r.style.height="9px",r.style.display="block",me.appendChild(e).appendChild(t).appendChild(r),o=n.getComputedStyle(t),s=parseInt(o.height,10)+parseInt(o.borderTopWidth,10)+parseInt(o.borderBottomWidth,10)===t.offsetHeight,me.removeChild(e)),s}}))}();var nt=["Webkit","Moz","ms"],rt=_.createElement("div").style,ot={};function it(e){var t=S.cssProps[e]||ot[e];return t||(e in rt?e:ot[e]=function(e){for(var t=e[0].toUpperCase()+e.slice(1),n=nt.length;n--;)if((e=nt[n]+t)in rt)return e}(e)||e)}var at=/^(none|table(?!-c[ea]).+)/,st={position:"absolute",visibility:"hidden",display:"block"},ct={letterSpacing:"0",fontWeight:"400"};function lt(e,t,n){var r=he.exec(t);return r?Math.max(0,r[2]-(n||0))+(r[3]||"px"):t}function ut(e,t,n,r,o,i){var a="width"===t?1:0,s=0,c=0,l=0;if(n===(r?"border":"content"))return 0;for(;a<4;a+=2)"margin"===n&&(l+=S.css(e,n+ve[a],!0,o)),r?("content"===n&&(c-=S.css(e,"padding"+ve[a],!0,o)),"margin"!==n&&(c-=S.css(e,"border"+ve[a]+"Width",!0,o))):(c+=S.css(e,"padding"+ve[a],!0,o),"padding"!==n?c+=S.css(e,"border"+ve[a]+"Width",!0,o):s+=S.css(e,"border"+ve[a]+"Width",!0,o));return!r&&i>=0&&(c+=Math.max(0,Math.ceil(e["offset"+t[0].toUpperCase()+t.slice(1)]-i-c-s-.5))||0),c+l}function ft(e,t,n){var r=Ge(e),o=(!g.boxSizingReliable()||n)&&"border-box"===S.css(e,"boxSizing",!1,r),i=o,a=et(e,t,r),s="offset"+t[0].toUpperCase()+t.slice(1);if(Xe.test(a)){if(!n)return a;a="auto"}return(!g.boxSizingReliable()&&o||!g.reliableTrDimensions()&&$(e,"tr")||"auto"===a||!parseFloat(a)&&"inline"===S.css(e,"display",!1,r))&&e.getClientRects().length&&(o="border-box"===S.css(e,"boxSizing",!1,r),(i=s in e)&&(a=e[s])),(a=parseFloat(a)||0)+ut(e,t,n||(o?"border":"content"),i,r,a)+"px"}function dt(e,t,n,r,o){return new dt.prototype.init(e,t,n,r,o)}S.extend({cssHooks:{opacity:{get:function(e,t){if(t){var n=et(e,"opacity");return""===n?"1":n}}}},cssNumber:{animationIterationCount:!0,aspectRatio:!0,borderImageSlice:!0,columnCount:!0,flexGrow:!0,flexShrink:!0,fontWeight:!0,gridArea:!0,gridColumn:!0,gridColumnEnd:!0,gridColumnStart:!0,gridRow:!0,gridRowEnd:!0,gridRowStart:!0,lineHeight:!0,opacity:!0,order:!0,orphans:!0,scale:!0,widows:!0,zIndex:!0,zoom:!0,fillOpacity:!0,floodOpacity:!0,stopOpacity:!0,strokeMiterlimit:!0,strokeOpacity:!0},cssProps:{},style:function(e,t,n,r){if(e&&3!==e.nodeType&&8!==e.nodeType&&e.style){var i,a,s,c=ie(t),l=Qe.test(t),u=e.style;if(l||(t=it(c)),s=S.cssHooks[t]||S.cssHooks[c],void 0===n)return s&&"get"in s&&void 0!==(i=s.get(e,!1,r))?i:u[t];"string"===(a=o(n))&&(i=he.exec(n))&&i[1]&&(n=_e(e,t,i),a="number"),null!=n&&n==n&&("number"!==a||l||(n+=i&&i[3]||(S.cssNumber[c]?"":"px")),g.clearCloneStyle||""!==n||0!==t.indexOf("background")||(u[t]="inherit"),s&&"set"in s&&void 0===(n=s.set(e,n,r))||(l?u.setProperty(t,n):u[t]=n))}},css:function(e,t,n,r){var o,i,a,s=ie(t);return Qe.test(t)||(t=it(s)),(a=S.cssHooks[t]||S.cssHooks[s])&&"get"in a&&(o=a.get(e,!0,n)),void 0===o&&(o=et(e,t,r)),"normal"===o&&t in ct&&(o=ct[t]),""===n||n?(i=parseFloat(o),!0===n||isFinite(i)?i||0:o):o}}),S.each(["height","width"],(function(e,t){S.cssHooks[t]={get:function(e,n,r){if(n)return!at.test(S.css(e,"display"))||e.getClientRects().length&&e.getBoundingClientRect().width?ft(e,t,r):Ye(e,st,(function(){return ft(e,t,r)}))},set:function(e,n,r){var o,i=Ge(e),a=!g.scrollboxSize()&&"absolute"===i.position,s=(a||r)&&"border-box"===S.css(e,"boxSizing",!1,i),c=r?ut(e,t,r,s,i):0;return s&&a&&(c-=Math.ceil(e["offset"+t[0].toUpperCase()+t.slice(1)]-parseFloat(i[t])-ut(e,t,"border",!1,i)-.5)),c&&(o=he.exec(n))&&"px"!==(o[3]||"px")&&(e.style[t]=n,n=S.css(e,t)),lt(0,n,c)}}})),S.cssHooks.marginLeft=tt(g.reliableMarginLeft,(function(e,t){if(t)return(parseFloat(et(e,"marginLeft"))||e.getBoundingClientRect().left-Ye(e,{marginLeft:0},(function(){return e.getBoundingClientRect().left})))+"px"})),S.each({margin:"",padding:"",border:"Width"},(function(e,t){S.cssHooks[e+t]={expand:function(n){for(var r=0,o={},i="string"==typeof n?n.split(" "):[n];r<4;r++)o[e+ve[r]+t]=i[r]||i[r-2]||i[0];return o}},"margin"!==e&&(S.cssHooks[e+t].set=lt)})),S.fn.extend({css:function(e,t){return te(this,(function(e,t,n){var r,o,i={},a=0;if(Array.isArray(t)){for(r=Ge(e),o=t.length;a<o;a++)i[t[a]]=S.css(e,t[a],!1,r);return i}return void 0!==n?S.style(e,t,n):S.css(e,t)}),e,t,arguments.length>1)}}),S.Tween=dt,dt.prototype={constructor:dt,init:function(e,t,n,r,o,i){this.elem=e,this.prop=n,this.easing=o||S.easing._default,this.options=t,this.start=this.now=this.cur(),this.end=r,this.unit=i||(S.cssNumber[n]?"":"px")},cur:function(){var e=dt.propHooks[this.prop];return e&&e.get?e.get(this):dt.propHooks._default.get(this)},run:function(e){var t,n=dt.propHooks[this.prop];return this.options.duration?this.pos=t=S.easing[this.easing](e,this.options.duration*e,0,1,this.options.duration):this.pos=t=e,this.now=(this.end-this.start)*t+this.start,this.options.step&&this.options.step.call(this.elem,this.now,this),n&&n.set?n.set(this):dt.propHooks._default.set(this),this}},dt.prototype.init.prototype=dt.prototype,dt.propHooks={_default:{get:function(e){var t;return 1!==e.elem.nodeType||null!=e.elem[e.prop]&&null==e.elem.style[e.prop]?e.elem[e.prop]:(t=S.css(e.elem,e.prop,""))&&"auto"!==t?t:0},set:function(e){S.fx.step[e.prop]?S.fx.step[e.prop](e):1!==e.elem.nodeType||!S.cssHooks[e.prop]&&null==e.elem.style[it(e.prop)]?e.elem[e.prop]=e.now:S.style(e.elem,e.prop,e.now+e.unit)}}},dt.propHooks.scrollTop=dt.propHooks.scrollLeft={set:function(e){e.elem.nodeType&&e.elem.parentNode&&(e.elem[e.prop]=e.now)}},S.easing={linear:function(e){return e},swing:function(e){return.5-Math.cos(e*Math.PI)/2},_default:"swing"},S.fx=dt.prototype.init,S.fx.step={};var pt,ht,vt=/^(?:toggle|show|hide)$/,mt=/queueHooks$/;function gt(){ht&&(!1===_.hidden&&n.requestAnimationFrame?n.requestAnimationFrame(gt):n.setTimeout(gt,S.fx.interval),S.fx.tick())}function yt(){return n.setTimeout((function(){pt=void 0})),pt=Date.now()}function bt(e,t){var n,r=0,o={height:e};for(t=t?1:0;r<4;r+=2-t)o["margin"+(n=ve[r])]=o["padding"+n]=e;return t&&(o.opacity=o.width=e),o}function _t(e,t,n){for(var r,o=(xt.tweeners[t]||[]).concat(xt.tweeners["*"]),i=0,a=o.length;i<a;i++)if(r=o[i].call(n,t,e))return r}function xt(e,t,n){var r,o,i=0,a=xt.prefilters.length,s=S.Deferred().always((function(){delete c.elem})),c=function(){if(o)return!1;for(var t=pt||yt(),n=Math.max(0,l.startTime+l.duration-t),r=1-(n/l.duration||0),i=0,a=l.tweens.length;i<a;i++)l.tweens[i].run(r);return s.notifyWith(e,[l,r,n]),r<1&&a?n:(a||s.notifyWith(e,[l,1,0]),s.resolveWith(e,[l]),!1)},l=s.promise({elem:e,props:S.extend({},t),opts:S.extend(!0,{specialEasing:{},easing:S.easing._default},n),originalProperties:t,originalOptions:n,startTime:pt||yt(),duration:n.duration,tweens:[],createTween:function(t,n){var r=S.Tween(e,l.opts,t,n,l.opts.specialEasing[t]||l.opts.easing);return l.tweens.push(r),r},stop:function(t){var n=0,r=t?l.tweens.length:0;if(o)return this;for(o=!0;n<r;n++)l.tweens[n].run(1);return t?(s.notifyWith(e,[l,1,0]),s.resolveWith(e,[l,t])):s.rejectWith(e,[l,t]),this}}),u=l.props;for(!function(e,t){var n,r,o,i,a;for(n in e)if(o=t[r=ie(n)],i=e[n],Array.isArray(i)&&(o=i[1],i=e[n]=i[0]),n!==r&&(e[r]=i,delete e[n]),(a=S.cssHooks[r])&&"expand"in a)for(n in i=a.expand(i),delete e[r],i)n in e||(e[n]=i[n],t[n]=o);else t[r]=o}(u,l.opts.specialEasing);i<a;i++)if(r=xt.prefilters[i].call(l,e,u,l.opts))return y(r.stop)&&(S._queueHooks(l.elem,l.opts.queue).stop=r.stop.bind(r)),r;return S.map(u,_t,l),y(l.opts.start)&&l.opts.start.call(e,l),l.progress(l.opts.progress).done(l.opts.done,l.opts.complete).fail(l.opts.fail).always(l.opts.always),S.fx.timer(S.extend(c,{elem:e,anim:l,queue:l.opts.queue})),l}S.Animation=S.extend(xt,{tweeners:{"*":[function(e,t){var n=this.createTween(e,t);return _e(n.elem,e,he.exec(t),n),n}]},tweener:function(e,t){y(e)?(t=e,e=["*"]):e=e.match(K);for(var n,r=0,o=e.length;r<o;r++)n=e[r],xt.tweeners[n]=xt.tweeners[n]||[],xt.tweeners[n].unshift(t)},prefilters:[function(e,t,n){var r,o,i,a,s,c,l,u,f="width"in t||"height"in t,d=this,p={},h=e.style,v=e.nodeType&&be(e),m=ce.get(e,"fxshow");for(r in n.queue||(null==(a=S._queueHooks(e,"fx")).unqueued&&(a.unqueued=0,s=a.empty.fire,a.empty.fire=function(){a.unqueued||s()}),a.unqueued++,d.always((function(){d.always((function(){a.unqueued--,S.queue(e,"fx").length||a.empty.fire()}))}))),t)if(o=t[r],vt.test(o)){if(delete t[r],i=i||"toggle"===o,o===(v?"hide":"show")){if("show"!==o||!m||void 0===m[r])continue;v=!0}p[r]=m&&m[r]||S.style(e,r)}if((c=!S.isEmptyObject(t))||!S.isEmptyObject(p))for(r in f&&1===e.nodeType&&(n.overflow=[h.overflow,h.overflowX,h.overflowY],null==(l=m&&m.display)&&(l=ce.get(e,"display")),"none"===(u=S.css(e,"display"))&&(l?u=l:(Ce([e],!0),l=e.style.display||l,u=S.css(e,"display"),Ce([e]))),("inline"===u||"inline-block"===u&&null!=l)&&"none"===S.css(e,"float")&&(c||(d.done((function(){h.display=l})),null==l&&(u=h.display,l="none"===u?"":u)),h.display="inline-block")),n.overflow&&(h.overflow="hidden",d.always((function(){h.overflow=n.overflow[0],h.overflowX=n.overflow[1],h.overflowY=n.overflow[2]}))),c=!1,p)c||(m?"hidden"in m&&(v=m.hidden):m=ce.access(e,"fxshow",{display:l}),i&&(m.hidden=!v),v&&Ce([e],!0),d.done((function(){for(r in v||Ce([e]),ce.remove(e,"fxshow"),p)S.style(e,r,p[r])}))),c=_t(v?m[r]:0,r,d),r in m||(m[r]=c.start,v&&(c.end=c.start,c.start=0))}],prefilter:function(e,t){t?xt.prefilters.unshift(e):xt.prefilters.push(e)}}),S.speed=function(e,t,n){var r=e&&"object"===o(e)?S.extend({},e):{complete:n||!n&&t||y(e)&&e,duration:e,easing:n&&t||t&&!y(t)&&t};return S.fx.off?r.duration=0:"number"!=typeof r.duration&&(r.duration in S.fx.speeds?r.duration=S.fx.speeds[r.duration]:r.duration=S.fx.speeds._default),null!=r.queue&&!0!==r.queue||(r.queue="fx"),r.old=r.complete,r.complete=function(){y(r.old)&&r.old.call(this),r.queue&&S.dequeue(this,r.queue)},r},S.fn.extend({fadeTo:function(e,t,n,r){return this.filter(be).css("opacity",0).show().end().animate({opacity:t},e,n,r)},animate:function(e,t,n,r){var o=S.isEmptyObject(e),i=S.speed(t,n,r),a=function(){var t=xt(this,S.extend({},e),i);(o||ce.get(this,"finish"))&&t.stop(!0)};return a.finish=a,o||!1===i.queue?this.each(a):this.queue(i.queue,a)},stop:function(e,t,n){var r=function(e){var t=e.stop;delete e.stop,t(n)};return"string"!=typeof e&&(n=t,t=e,e=void 0),t&&this.queue(e||"fx",[]),this.each((function(){var t=!0,o=null!=e&&e+"queueHooks",i=S.timers,a=ce.get(this);if(o)a[o]&&a[o].stop&&r(a[o]);else for(o in a)a[o]&&a[o].stop&&mt.test(o)&&r(a[o]);for(o=i.length;o--;)i[o].elem!==this||null!=e&&i[o].queue!==e||(i[o].anim.stop(n),t=!1,i.splice(o,1));!t&&n||S.dequeue(this,e)}))},finish:function(e){return!1!==e&&(e=e||"fx"),this.each((function(){var t,n=ce.get(this),r=n[e+"queue"],o=n[e+"queueHooks"],i=S.timers,a=r?r.length:0;for(n.finish=!0,S.queue(this,e,[]),o&&o.stop&&o.stop.call(this,!0),t=i.length;t--;)i[t].elem===this&&i[t].queue===e&&(i[t].anim.stop(!0),i.splice(t,1));for(t=0;t<a;t++)r[t]&&r[t].finish&&r[t].finish.call(this);delete n.finish}))}}),S.each(["toggle","show","hide"],(function(e,t){var n=S.fn[t];S.fn[t]=function(e,r,o){return null==e||"boolean"==typeof e?n.apply(this,arguments):this.animate(bt(t,!0),e,r,o)}})),S.each({slideDown:bt("show"),slideUp:bt("hide"),slideToggle:bt("toggle"),fadeIn:{opacity:"show"},fadeOut:{opacity:"hide"},fadeToggle:{opacity:"toggle"}},(function(e,t){S.fn[e]=function(e,n,r){return this.animate(t,e,n,r)}})),S.timers=[],S.fx.tick=function(){var e,t=0,n=S.timers;for(pt=Date.now();t<n.length;t++)(e=n[t])()||n[t]!==e||n.splice(t--,1);n.length||S.fx.stop(),pt=void 0},S.fx.timer=function(e){S.timers.push(e),S.fx.start()},S.fx.interval=13,S.fx.start=function(){ht||(ht=!0,gt())},S.fx.stop=function(){ht=null},S.fx.speeds={slow:600,fast:200,_default:400},S.fn.delay=function(e,t){return e=S.fx&&S.fx.speeds[e]||e,t=t||"fx",this.queue(t,(function(t,r){var o=n.setTimeout(t,e);r.stop=function(){n.clearTimeout(o)}}))},function(){var e=_.createElement("input"),t=_.createElement("select").appendChild(_.createElement("option"));e.type="checkbox",g.checkOn=""!==e.value,g.optSelected=t.selected,(e=_.createElement("input")).value="t",e.type="radio",g.radioValue="t"===e.value}();var wt,Ct=S.expr.attrHandle;S.fn.extend({attr:function(e,t){return te(this,S.attr,e,t,arguments.length>1)},removeAttr:function(e){return this.each((function(){S.removeAttr(this,e)}))}}),S.extend({attr:function(e,t,n){var r,o,i=e.nodeType;if(3!==i&&8!==i&&2!==i)return void 0===e.getAttribute?S.prop(e,t,n):(1===i&&S.isXMLDoc(e)||(o=S.attrHooks[t.toLowerCase()]||(S.expr.match.bool.test(t)?wt:void 0)),void 0!==n?null===n?void S.removeAttr(e,t):o&&"set"in o&&void 0!==(r=o.set(e,n,t))?r:(e.setAttribute(t,n+""),n):o&&"get"in o&&null!==(r=o.get(e,t))?r:null==(r=S.find.attr(e,t))?void 0:r)},attrHooks:{type:{set:function(e,t){if(!g.radioValue&&"radio"===t&&$(e,"input")){var n=e.value;return e.setAttribute("type",t),n&&(e.value=n),t}}}},removeAttr:function(e,t){var n,r=0,o=t&&t.match(K);if(o&&1===e.nodeType)for(;n=o[r++];)e.removeAttribute(n)}}),wt={set:function(e,t,n){return!1===t?S.removeAttr(e,n):e.setAttribute(n,n),n}},S.each(S.expr.match.bool.source.match(/\w+/g),(function(e,t){var n=Ct[t]||S.find.attr;Ct[t]=function(e,t,r){var o,i,a=t.toLowerCase();return r||(i=Ct[a],Ct[a]=o,o=null!=n(e,t,r)?a:null,Ct[a]=i),o}}));var Tt=/^(?:input|select|textarea|button)$/i,kt=/^(?:a|area)$/i;function St(e){return(e.match(K)||[]).join(" ")}function Et(e){return e.getAttribute&&e.getAttribute("class")||""}function $t(e){return Array.isArray(e)?e:"string"==typeof e&&e.match(K)||[]}S.fn.extend({prop:function(e,t){return te(this,S.prop,e,t,arguments.length>1)},removeProp:function(e){return this.each((function(){delete this[S.propFix[e]||e]}))}}),S.extend({prop:function(e,t,n){var r,o,i=e.nodeType;if(3!==i&&8!==i&&2!==i)return 1===i&&S.isXMLDoc(e)||(t=S.propFix[t]||t,o=S.propHooks[t]),void 0!==n?o&&"set"in o&&void 0!==(r=o.set(e,n,t))?r:e[t]=n:o&&"get"in o&&null!==(r=o.get(e,t))?r:e[t]},propHooks:{tabIndex:{get:function(e){var t=S.find.attr(e,"tabindex");return t?parseInt(t,10):Tt.test(e.nodeName)||kt.test(e.nodeName)&&e.href?0:-1}}},propFix:{for:"htmlFor",class:"className"}}),g.optSelected||(S.propHooks.selected={get:function(e){var t=e.parentNode;return t&&t.parentNode&&t.parentNode.selectedIndex,null},set:function(e){var t=e.parentNode;t&&(t.selectedIndex,t.parentNode&&t.parentNode.selectedIndex)}}),S.each(["tabIndex","readOnly","maxLength","cellSpacing","cellPadding","rowSpan","colSpan","useMap","frameBorder","contentEditable"],(function(){S.propFix[this.toLowerCase()]=this})),S.fn.extend({addClass:function(e){var t,n,r,o,i,a;return y(e)?this.each((function(t){S(this).addClass(e.call(this,t,Et(this)))})):(t=$t(e)).length?this.each((function(){if(r=Et(this),n=1===this.nodeType&&" "+St(r)+" "){for(i=0;i<t.length;i++)o=t[i],n.indexOf(" "+o+" ")<0&&(n+=o+" ");a=St(n),r!==a&&this.setAttribute("class",a)}})):this},removeClass:function(e){var t,n,r,o,i,a;return y(e)?this.each((function(t){S(this).removeClass(e.call(this,t,Et(this)))})):arguments.length?(t=$t(e)).length?this.each((function(){if(r=Et(this),n=1===this.nodeType&&" "+St(r)+" "){for(i=0;i<t.length;i++)for(o=t[i];n.indexOf(" "+o+" ")>-1;)n=n.replace(" "+o+" "," ");a=St(n),r!==a&&this.setAttribute("class",a)}})):this:this.attr("class","")},toggleClass:function(e,t){var n,r,i,a,s=o(e),c="string"===s||Array.isArray(e);return y(e)?this.each((function(n){S(this).toggleClass(e.call(this,n,Et(this),t),t)})):"boolean"==typeof t&&c?t?this.addClass(e):this.removeClass(e):(n=$t(e),this.each((function(){if(c)for(a=S(this),i=0;i<n.length;i++)r=n[i],a.hasClass(r)?a.removeClass(r):a.addClass(r);else void 0!==e&&"boolean"!==s||((r=Et(this))&&ce.set(this,"__className__",r),this.setAttribute&&this.setAttribute("class",r||!1===e?"":ce.get(this,"__className__")||""))})))},hasClass:function(e){var t,n,r=0;for(t=" "+e+" ";n=this[r++];)if(1===n.nodeType&&(" "+St(Et(n))+" ").indexOf(t)>-1)return!0;return!1}});var At=/\r/g;S.fn.extend({val:function(e){var t,n,r,o=this[0];return arguments.length?(r=y(e),this.each((function(n){var o;1===this.nodeType&&(null==(o=r?e.call(this,n,S(this).val()):e)?o="":"number"==typeof o?o+="":Array.isArray(o)&&(o=S.map(o,(function(e){return null==e?"":e+""}))),(t=S.valHooks[this.type]||S.valHooks[this.nodeName.toLowerCase()])&&"set"in t&&void 0!==t.set(this,o,"value")||(this.value=o))}))):o?(t=S.valHooks[o.type]||S.valHooks[o.nodeName.toLowerCase()])&&"get"in t&&void 0!==(n=t.get(o,"value"))?n:"string"==typeof(n=o.value)?n.replace(At,""):null==n?"":n:void 0}}),S.extend({valHooks:{option:{get:function(e){var t=S.find.attr(e,"value");return null!=t?t:St(S.text(e))}},select:{get:function(e){var t,n,r,o=e.options,i=e.selectedIndex,a="select-one"===e.type,s=a?null:[],c=a?i+1:o.length;for(r=i<0?c:a?i:0;r<c;r++)if(((n=o[r]).selected||r===i)&&!n.disabled&&(!n.parentNode.disabled||!$(n.parentNode,"optgroup"))){if(t=S(n).val(),a)return t;s.push(t)}return s},set:function(e,t){for(var n,r,o=e.options,i=S.makeArray(t),a=o.length;a--;)((r=o[a]).selected=S.inArray(S.valHooks.option.get(r),i)>-1)&&(n=!0);return n||(e.selectedIndex=-1),i}}}}),S.each(["radio","checkbox"],(function(){S.valHooks[this]={set:function(e,t){if(Array.isArray(t))return e.checked=S.inArray(S(e).val(),t)>-1}},g.checkOn||(S.valHooks[this].get=function(e){return null===e.getAttribute("value")?"on":e.value})}));var Ot=n.location,jt={guid:Date.now()},Dt=/\?/;S.parseXML=function(e){var t,r;if(!e||"string"!=typeof e)return null;try{t=(new n.DOMParser).parseFromString(e,"text/xml")}catch(e){}return r=t&&t.getElementsByTagName("parsererror")[0],t&&!r||S.error("Invalid XML: "+(r?S.map(r.childNodes,(function(e){return e.textContent})).join("\n"):e)),t};var Nt=/^(?:focusinfocus|focusoutblur)$/,Lt=function(e){e.stopPropagation()};S.extend(S.event,{trigger:function(e,t,r,i){var a,s,c,l,u,f,d,p,v=[r||_],m=h.call(e,"type")?e.type:e,g=h.call(e,"namespace")?e.namespace.split("."):[];if(s=p=c=r=r||_,3!==r.nodeType&&8!==r.nodeType&&!Nt.test(m+S.event.triggered)&&(m.indexOf(".")>-1&&(g=m.split("."),m=g.shift(),g.sort()),u=m.indexOf(":")<0&&"on"+m,(e=e[S.expando]?e:new S.Event(m,"object"===o(e)&&e)).isTrigger=i?2:3,e.namespace=g.join("."),e.rnamespace=e.namespace?new RegExp("(^|\\.)"+g.join("\\.(?:.*\\.|)")+"(\\.|$)"):null,e.result=void 0,e.target||(e.target=r),t=null==t?[e]:S.makeArray(t,[e]),d=S.event.special[m]||{},i||!d.trigger||!1!==d.trigger.apply(r,t))){if(!i&&!d.noBubble&&!b(r)){for(l=d.delegateType||m,Nt.test(l+m)||(s=s.parentNode);s;s=s.parentNode)v.push(s),c=s;c===(r.ownerDocument||_)&&v.push(c.defaultView||c.parentWindow||n)}for(a=0;(s=v[a++])&&!e.isPropagationStopped();)p=s,e.type=a>1?l:d.bindType||m,(f=(ce.get(s,"events")||Object.create(null))[e.type]&&ce.get(s,"handle"))&&f.apply(s,t),(f=u&&s[u])&&f.apply&&ae(s)&&(e.result=f.apply(s,t),!1===e.result&&e.preventDefault());return e.type=m,i||e.isDefaultPrevented()||d._default&&!1!==d._default.apply(v.pop(),t)||!ae(r)||u&&y(r[m])&&!b(r)&&((c=r[u])&&(r[u]=null),S.event.triggered=m,e.isPropagationStopped()&&p.addEventListener(m,Lt),r[m](),e.isPropagationStopped()&&p.removeEventListener(m,Lt),S.event.triggered=void 0,c&&(r[u]=c)),e.result}},simulate:function(e,t,n){var r=S.extend(new S.Event,n,{type:e,isSimulated:!0});S.event.trigger(r,null,t)}}),S.fn.extend({trigger:function(e,t){return this.each((function(){S.event.trigger(e,t,this)}))},triggerHandler:function(e,t){var n=this[0];if(n)return S.event.trigger(e,t,n,!0)}});var Pt=/\[\]$/,It=/\r?\n/g,Mt=/^(?:submit|button|image|reset|file)$/i,Rt=/^(?:input|select|textarea|keygen)/i;function Ft(e,t,n,r){var i;if(Array.isArray(t))S.each(t,(function(t,i){n||Pt.test(e)?r(e,i):Ft(e+"["+("object"===o(i)&&null!=i?t:"")+"]",i,n,r)}));else if(n||"object"!==C(t))r(e,t);else for(i in t)Ft(e+"["+i+"]",t[i],n,r)}S.param=function(e,t){var n,r=[],o=function(e,t){var n=y(t)?t():t;r[r.length]=encodeURIComponent(e)+"="+encodeURIComponent(null==n?"":n)};if(null==e)return"";if(Array.isArray(e)||e.jquery&&!S.isPlainObject(e))S.each(e,(function(){o(this.name,this.value)}));else for(n in e)Ft(n,e[n],t,o);return r.join("&")},S.fn.extend({serialize:function(){return S.param(this.serializeArray())},serializeArray:function(){return this.map((function(){var e=S.prop(this,"elements");return e?S.makeArray(e):this})).filter((function(){var e=this.type;return this.name&&!S(this).is(":disabled")&&Rt.test(this.nodeName)&&!Mt.test(e)&&(this.checked||!Se.test(e))})).map((function(e,t){var n=S(this).val();return null==n?null:Array.isArray(n)?S.map(n,(function(e){return{name:t.name,value:e.replace(It,"\r\n")}})):{name:t.name,value:n.replace(It,"\r\n")}})).get()}});var Ht=/%20/g,qt=/#.*$/,Bt=/([?&])_=[^&]*/,Wt=/^(.*?):[ \t]*([^\r\n]*)$/gm,Ut=/^(?:GET|HEAD)$/,zt=/^\/\//,Vt={},Jt={},Kt="*/".concat("*"),Xt=_.createElement("a");function Qt(e){return function(t,n){"string"!=typeof t&&(n=t,t="*");var r,o=0,i=t.toLowerCase().match(K)||[];if(y(n))for(;r=i[o++];)"+"===r[0]?(r=r.slice(1)||"*",(e[r]=e[r]||[]).unshift(n)):(e[r]=e[r]||[]).push(n)}}function Gt(e,t,n,r){var o={},i=e===Jt;function a(s){var c;return o[s]=!0,S.each(e[s]||[],(function(e,s){var l=s(t,n,r);return"string"!=typeof l||i||o[l]?i?!(c=l):void 0:(t.dataTypes.unshift(l),a(l),!1)})),c}return a(t.dataTypes[0])||!o["*"]&&a("*")}function Yt(e,t){var n,r,o=S.ajaxSettings.flatOptions||{};for(n in t)void 0!==t[n]&&((o[n]?e:r||(r={}))[n]=t[n]);return r&&S.extend(!0,e,r),e}Xt.href=Ot.href,S.extend({active:0,lastModified:{},etag:{},ajaxSettings:{url:Ot.href,type:"GET",isLocal:/^(?:about|app|app-storage|.+-extension|file|res|widget):$/.test(Ot.protocol),global:!0,processData:!0,async:!0,contentType:"application/x-www-form-urlencoded; charset=UTF-8",accepts:{"*":Kt,text:"text/plain",html:"text/html",xml:"application/xml, text/xml",json:"application/json, text/javascript"},contents:{xml:/\bxml\b/,html:/\bhtml/,json:/\bjson\b/},responseFields:{xml:"responseXML",text:"responseText",json:"responseJSON"},converters:{"* text":String,"text html":!0,"text json":JSON.parse,"text xml":S.parseXML},flatOptions:{url:!0,context:!0}},ajaxSetup:function(e,t){return t?Yt(Yt(e,S.ajaxSettings),t):Yt(S.ajaxSettings,e)},ajaxPrefilter:Qt(Vt),ajaxTransport:Qt(Jt),ajax:function(e,t){"object"===o(e)&&(t=e,e=void 0),t=t||{};var r,i,a,s,c,l,u,f,d,p,h=S.ajaxSetup({},t),v=h.context||h,m=h.context&&(v.nodeType||v.jquery)?S(v):S.event,g=S.Deferred(),y=S.Callbacks("once memory"),b=h.statusCode||{},x={},w={},C="canceled",T={readyState:0,getResponseHeader:function(e){var t;if(u){if(!s)for(s={};t=Wt.exec(a);)s[t[1].toLowerCase()+" "]=(s[t[1].toLowerCase()+" "]||[]).concat(t[2]);t=s[e.toLowerCase()+" "]}return null==t?null:t.join(", ")},getAllResponseHeaders:function(){return u?a:null},setRequestHeader:function(e,t){return null==u&&(e=w[e.toLowerCase()]=w[e.toLowerCase()]||e,x[e]=t),this},overrideMimeType:function(e){return null==u&&(h.mimeType=e),this},statusCode:function(e){var t;if(e)if(u)T.always(e[T.status]);else for(t in e)b[t]=[b[t],e[t]];return this},abort:function(e){var t=e||C;return r&&r.abort(t),k(0,t),this}};if(g.promise(T),h.url=((e||h.url||Ot.href)+"").replace(zt,Ot.protocol+"//"),h.type=t.method||t.type||h.method||h.type,h.dataTypes=(h.dataType||"*").toLowerCase().match(K)||[""],null==h.crossDomain){l=_.createElement("a");try{l.href=h.url,l.href=l.href,h.crossDomain=Xt.protocol+"//"+Xt.host!=l.protocol+"//"+l.host}catch(e){h.crossDomain=!0}}if(h.data&&h.processData&&"string"!=typeof h.data&&(h.data=S.param(h.data,h.traditional)),Gt(Vt,h,t,T),u)return T;for(d in(f=S.event&&h.global)&&0==S.active++&&S.event.trigger("ajaxStart"),h.type=h.type.toUpperCase(),h.hasContent=!Ut.test(h.type),i=h.url.replace(qt,""),h.hasContent?h.data&&h.processData&&0===(h.contentType||"").indexOf("application/x-www-form-urlencoded")&&(h.data=h.data.replace(Ht,"+")):(p=h.url.slice(i.length),h.data&&(h.processData||"string"==typeof h.data)&&(i+=(Dt.test(i)?"&":"?")+h.data,delete h.data),!1===h.cache&&(i=i.replace(Bt,"$1"),p=(Dt.test(i)?"&":"?")+"_="+jt.guid+++p),h.url=i+p),h.ifModified&&(S.lastModified[i]&&T.setRequestHeader("If-Modified-Since",S.lastModified[i]),S.etag[i]&&T.setRequestHeader("If-None-Match",S.etag[i])),(h.data&&h.hasContent&&!1!==h.contentType||t.contentType)&&T.setRequestHeader("Content-Type",h.contentType),T.setRequestHeader("Accept",h.dataTypes[0]&&h.accepts[h.dataTypes[0]]?h.accepts[h.dataTypes[0]]+("*"!==h.dataTypes[0]?", "+Kt+"; q=0.01":""):h.accepts["*"]),h.headers)T.setRequestHeader(d,h.headers[d]);if(h.beforeSend&&(!1===h.beforeSend.call(v,T,h)||u))return T.abort();if(C="abort",y.add(h.complete),T.done(h.success),T.fail(h.error),r=Gt(Jt,h,t,T)){if(T.readyState=1,f&&m.trigger("ajaxSend",[T,h]),u)return T;h.async&&h.timeout>0&&(c=n.setTimeout((function(){T.abort("timeout")}),h.timeout));try{u=!1,r.send(x,k)}catch(e){if(u)throw e;k(-1,e)}}else k(-1,"No Transport");function k(e,t,o,s){var l,d,p,_,x,w=t;u||(u=!0,c&&n.clearTimeout(c),r=void 0,a=s||"",T.readyState=e>0?4:0,l=e>=200&&e<300||304===e,o&&(_=function(e,t,n){for(var r,o,i,a,s=e.contents,c=e.dataTypes;"*"===c[0];)c.shift(),void 0===r&&(r=e.mimeType||t.getResponseHeader("Content-Type"));if(r)for(o in s)if(s[o]&&s[o].test(r)){c.unshift(o);break}if(c[0]in n)i=c[0];else{for(o in n){if(!c[0]||e.converters[o+" "+c[0]]){i=o;break}a||(a=o)}i=i||a}if(i)return i!==c[0]&&c.unshift(i),n[i]}(h,T,o)),!l&&S.inArray("script",h.dataTypes)>-1&&S.inArray("json",h.dataTypes)<0&&(h.converters["text script"]=function(){}),_=function(e,t,n,r){var o,i,a,s,c,l={},u=e.dataTypes.slice();if(u[1])for(a in e.converters)l[a.toLowerCase()]=e.converters[a];for(i=u.shift();i;)if(e.responseFields[i]&&(n[e.responseFields[i]]=t),!c&&r&&e.dataFilter&&(t=e.dataFilter(t,e.dataType)),c=i,i=u.shift())if("*"===i)i=c;else if("*"!==c&&c!==i){if(!(a=l[c+" "+i]||l["* "+i]))for(o in l)if((s=o.split(" "))[1]===i&&(a=l[c+" "+s[0]]||l["* "+s[0]])){!0===a?a=l[o]:!0!==l[o]&&(i=s[0],u.unshift(s[1]));break}if(!0!==a)if(a&&e.throws)t=a(t);else try{t=a(t)}catch(e){return{state:"parsererror",error:a?e:"No conversion from "+c+" to "+i}}}return{state:"success",data:t}}(h,_,T,l),l?(h.ifModified&&((x=T.getResponseHeader("Last-Modified"))&&(S.lastModified[i]=x),(x=T.getResponseHeader("etag"))&&(S.etag[i]=x)),204===e||"HEAD"===h.type?w="nocontent":304===e?w="notmodified":(w=_.state,d=_.data,l=!(p=_.error))):(p=w,!e&&w||(w="error",e<0&&(e=0))),T.status=e,T.statusText=(t||w)+"",l?g.resolveWith(v,[d,w,T]):g.rejectWith(v,[T,w,p]),T.statusCode(b),b=void 0,f&&m.trigger(l?"ajaxSuccess":"ajaxError",[T,h,l?d:p]),y.fireWith(v,[T,w]),f&&(m.trigger("ajaxComplete",[T,h]),--S.active||S.event.trigger("ajaxStop")))}return T},getJSON:function(e,t,n){return S.get(e,t,n,"json")},getScript:function(e,t){return S.get(e,void 0,t,"script")}}),S.each(["get","post"],(function(e,t){S[t]=function(e,n,r,o){return y(n)&&(o=o||r,r=n,n=void 0),S.ajax(S.extend({url:e,type:t,dataType:o,data:n,success:r},S.isPlainObject(e)&&e))}})),S.ajaxPrefilter((function(e){var t;for(t in e.headers)"content-type"===t.toLowerCase()&&(e.contentType=e.headers[t]||"")})),S._evalUrl=function(e,t,n){return S.ajax({url:e,type:"GET",dataType:"script",cache:!0,async:!1,global:!1,converters:{"text script":function(){}},dataFilter:function(e){S.globalEval(e,t,n)}})},S.fn.extend({wrapAll:function(e){var t;return this[0]&&(y(e)&&(e=e.call(this[0])),t=S(e,this[0].ownerDocument).eq(0).clone(!0),this[0].parentNode&&t.insertBefore(this[0]),t.map((function(){for(var e=this;e.firstElementChild;)e=e.firstElementChild;return e})).append(this)),this},wrapInner:function(e){return y(e)?this.each((function(t){S(this).wrapInner(e.call(this,t))})):this.each((function(){var t=S(this),n=t.contents();n.length?n.wrapAll(e):t.append(e)}))},wrap:function(e){var t=y(e);return this.each((function(n){S(this).wrapAll(t?e.call(this,n):e)}))},unwrap:function(e){return this.parent(e).not("body").each((function(){S(this).replaceWith(this.childNodes)})),this}}),S.expr.pseudos.hidden=function(e){return!S.expr.pseudos.visible(e)},S.expr.pseudos.visible=function(e){return!!(e.offsetWidth||e.offsetHeight||e.getClientRects().length)},S.ajaxSettings.xhr=function(){try{return new n.XMLHttpRequest}catch(e){}};var Zt={0:200,1223:204},en=S.ajaxSettings.xhr();g.cors=!!en&&"withCredentials"in en,g.ajax=en=!!en,S.ajaxTransport((function(e){var t,r;if(g.cors||en&&!e.crossDomain)return{send:function(o,i){var a,s=e.xhr();if(s.open(e.type,e.url,e.async,e.username,e.password),e.xhrFields)for(a in e.xhrFields)s[a]=e.xhrFields[a];for(a in e.mimeType&&s.overrideMimeType&&s.overrideMimeType(e.mimeType),e.crossDomain||o["X-Requested-With"]||(o["X-Requested-With"]="XMLHttpRequest"),o)s.setRequestHeader(a,o[a]);t=function(e){return function(){t&&(t=r=s.onload=s.onerror=s.onabort=s.ontimeout=s.onreadystatechange=null,"abort"===e?s.abort():"error"===e?"number"!=typeof s.status?i(0,"error"):i(s.status,s.statusText):i(Zt[s.status]||s.status,s.statusText,"text"!==(s.responseType||"text")||"string"!=typeof s.responseText?{binary:s.response}:{text:s.responseText},s.getAllResponseHeaders()))}},s.onload=t(),r=s.onerror=s.ontimeout=t("error"),void 0!==s.onabort?s.onabort=r:s.onreadystatechange=function(){4===s.readyState&&n.setTimeout((function(){t&&r()}))},t=t("abort");try{s.send(e.hasContent&&e.data||null)}catch(e){if(t)throw e}},abort:function(){t&&t()}}})),S.ajaxPrefilter((function(e){e.crossDomain&&(e.contents.script=!1)})),S.ajaxSetup({accepts:{script:"text/javascript, application/javascript, application/ecmascript, application/x-ecmascript"},contents:{script:/\b(?:java|ecma)script\b/},converters:{"text script":function(e){return S.globalEval(e),e}}}),S.ajaxPrefilter("script",(function(e){void 0===e.cache&&(e.cache=!1),e.crossDomain&&(e.type="GET")})),S.ajaxTransport("script",(function(e){var t,n;if(e.crossDomain||e.scriptAttrs)return{send:function(r,o){t=S("<script>").attr(e.scriptAttrs||{}).prop({charset:e.scriptCharset,src:e.url}).on("load error",n=function(e){t.remove(),n=null,e&&o("error"===e.type?404:200,e.type)}),_.head.appendChild(t[0])},abort:function(){n&&n()}}}));var tn,nn=[],rn=/(=)\?(?=&|$)|\?\?/;S.ajaxSetup({jsonp:"callback",jsonpCallback:function(){var e=nn.pop()||S.expando+"_"+jt.guid++;return this[e]=!0,e}}),S.ajaxPrefilter("json jsonp",(function(e,t,r){var o,i,a,s=!1!==e.jsonp&&(rn.test(e.url)?"url":"string"==typeof e.data&&0===(e.contentType||"").indexOf("application/x-www-form-urlencoded")&&rn.test(e.data)&&"data");if(s||"jsonp"===e.dataTypes[0])return o=e.jsonpCallback=y(e.jsonpCallback)?e.jsonpCallback():e.jsonpCallback,s?e[s]=e[s].replace(rn,"$1"+o):!1!==e.jsonp&&(e.url+=(Dt.test(e.url)?"&":"?")+e.jsonp+"="+o),e.converters["script json"]=function(){return a||S.error(o+" was not called"),a[0]},e.dataTypes[0]="json",i=n[o],n[o]=function(){a=arguments},r.always((function(){void 0===i?S(n).removeProp(o):n[o]=i,e[o]&&(e.jsonpCallback=t.jsonpCallback,nn.push(o)),a&&y(i)&&i(a[0]),a=i=void 0})),"script"})),g.createHTMLDocument=((tn=_.implementation.createHTMLDocument("").body).innerHTML="<form></form><form></form>",2===tn.childNodes.length),S.parseHTML=function(e,t,n){return"string"!=typeof e?[]:("boolean"==typeof t&&(n=t,t=!1),t||(g.createHTMLDocument?((r=(t=_.implementation.createHTMLDocument("")).createElement("base")).href=_.location.href,t.head.appendChild(r)):t=_),i=!n&&[],(o=q.exec(e))?[t.createElement(o[1])]:(o=Ne([e],t,i),i&&i.length&&S(i).remove(),S.merge([],o.childNodes)));var r,o,i},S.fn.load=function(e,t,n){var r,i,a,s=this,c=e.indexOf(" ");return c>-1&&(r=St(e.slice(c)),e=e.slice(0,c)),y(t)?(n=t,t=void 0):t&&"object"===o(t)&&(i="POST"),s.length>0&&S.ajax({url:e,type:i||"GET",dataType:"html",data:t}).done((function(e){a=arguments,s.html(r?S("<div>").append(S.parseHTML(e)).find(r):e)})).always(n&&function(e,t){s.each((function(){n.apply(this,a||[e.responseText,t,e])}))}),this},S.expr.pseudos.animated=function(e){return S.grep(S.timers,(function(t){return e===t.elem})).length},S.offset={setOffset:function(e,t,n){var r,o,i,a,s,c,l=S.css(e,"position"),u=S(e),f={};"static"===l&&(e.style.position="relative"),s=u.offset(),i=S.css(e,"top"),c=S.css(e,"left"),("absolute"===l||"fixed"===l)&&(i+c).indexOf("auto")>-1?(a=(r=u.position()).top,o=r.left):(a=parseFloat(i)||0,o=parseFloat(c)||0),y(t)&&(t=t.call(e,n,S.extend({},s))),null!=t.top&&(f.top=t.top-s.top+a),null!=t.left&&(f.left=t.left-s.left+o),"using"in t?t.using.call(e,f):u.css(f)}},S.fn.extend({offset:function(e){if(arguments.length)return void 0===e?this:this.each((function(t){S.offset.setOffset(this,e,t)}));var t,n,r=this[0];return r?r.getClientRects().length?(t=r.getBoundingClientRect(),n=r.ownerDocument.defaultView,{top:t.top+n.pageYOffset,left:t.left+n.pageXOffset}):{top:0,left:0}:void 0},position:function(){if(this[0]){var e,t,n,r=this[0],o={top:0,left:0};if("fixed"===S.css(r,"position"))t=r.getBoundingClientRect();else{for(t=this.offset(),n=r.ownerDocument,e=r.offsetParent||n.documentElement;e&&(e===n.body||e===n.documentElement)&&"static"===S.css(e,"position");)e=e.parentNode;e&&e!==r&&1===e.nodeType&&((o=S(e).offset()).top+=S.css(e,"borderTopWidth",!0),o.left+=S.css(e,"borderLeftWidth",!0))}return{top:t.top-o.top-S.css(r,"marginTop",!0),left:t.left-o.left-S.css(r,"marginLeft",!0)}}},offsetParent:function(){return this.map((function(){for(var e=this.offsetParent;e&&"static"===S.css(e,"position");)e=e.offsetParent;return e||me}))}}),S.each({scrollLeft:"pageXOffset",scrollTop:"pageYOffset"},(function(e,t){var n="pageYOffset"===t;S.fn[e]=function(r){return te(this,(function(e,r,o){var i;if(b(e)?i=e:9===e.nodeType&&(i=e.defaultView),void 0===o)return i?i[t]:e[r];i?i.scrollTo(n?i.pageXOffset:o,n?o:i.pageYOffset):e[r]=o}),e,r,arguments.length)}})),S.each(["top","left"],(function(e,t){S.cssHooks[t]=tt(g.pixelPosition,(function(e,n){if(n)return n=et(e,t),Xe.test(n)?S(e).position()[t]+"px":n}))})),S.each({Height:"height",Width:"width"},(function(e,t){S.each({padding:"inner"+e,content:t,"":"outer"+e},(function(n,r){S.fn[r]=function(o,i){var a=arguments.length&&(n||"boolean"!=typeof o),s=n||(!0===o||!0===i?"margin":"border");return te(this,(function(t,n,o){var i;return b(t)?0===r.indexOf("outer")?t["inner"+e]:t.document.documentElement["client"+e]:9===t.nodeType?(i=t.documentElement,Math.max(t.body["scroll"+e],i["scroll"+e],t.body["offset"+e],i["offset"+e],i["client"+e])):void 0===o?S.css(t,n,s):S.style(t,n,o,s)}),t,a?o:void 0,a)}}))})),S.each(["ajaxStart","ajaxStop","ajaxComplete","ajaxError","ajaxSuccess","ajaxSend"],(function(e,t){S.fn[t]=function(e){return this.on(t,e)}})),S.fn.extend({bind:function(e,t,n){return this.on(e,null,t,n)},unbind:function(e,t){return this.off(e,null,t)},delegate:function(e,t,n,r){return this.on(t,e,n,r)},undelegate:function(e,t,n){return 1===arguments.length?this.off(e,"**"):this.off(t,e||"**",n)},hover:function(e,t){return this.on("mouseenter",e).on("mouseleave",t||e)}}),S.each("blur focus focusin focusout resize scroll click dblclick mousedown mouseup mousemove mouseover mouseout mouseenter mouseleave change select submit keydown keypress keyup contextmenu".split(" "),(function(e,t){S.fn[t]=function(e,n){return arguments.length>0?this.on(t,null,e,n):this.trigger(t)}}));var on=/^[\s\uFEFF\xA0]+|([^\s\uFEFF\xA0])[\s\uFEFF\xA0]+$/g;S.proxy=function(e,t){var n,r,o;if("string"==typeof t&&(n=e[t],t=e,e=n),y(e))return r=c.call(arguments,2),o=function(){return e.apply(t||this,r.concat(c.call(arguments)))},o.guid=e.guid=e.guid||S.guid++,o},S.holdReady=function(e){e?S.readyWait++:S.ready(!0)},S.isArray=Array.isArray,S.parseJSON=JSON.parse,S.nodeName=$,S.isFunction=y,S.isWindow=b,S.camelCase=ie,S.type=C,S.now=Date.now,S.isNumeric=function(e){var t=S.type(e);return("number"===t||"string"===t)&&!isNaN(e-parseFloat(e))},S.trim=function(e){return null==e?"":(e+"").replace(on,"$1")},void 0===(r=function(){return S}.apply(t,[]))||(e.exports=r);var an=n.jQuery,sn=n.$;return S.noConflict=function(e){return n.$===S&&(n.$=sn),e&&n.jQuery===S&&(n.jQuery=an),S},void 0===i&&(n.jQuery=n.$=S),S}))},601:(e,t)=>{"use strict";function n(e,t){var n="undefined"!=typeof Symbol&&e[Symbol.iterator]||e["@@iterator"];if(!n){if(Array.isArray(e)||(n=function(e,t){if(!e)return;if("string"==typeof e)return r(e,t);var n=Object.prototype.toString.call(e).slice(8,-1);"Object"===n&&e.constructor&&(n=e.constructor.name);if("Map"===n||"Set"===n)return Array.from(e);if("Arguments"===n||/^(?:Ui|I)nt(?:8|16|32)(?:Clamped)?Array$/.test(n))return r(e,t)}(e))||t&&e&&"number"==typeof e.length){n&&(e=n);var o=0,i=function(){};return{s:i,n:function(){return o>=e.length?{done:!0}:{done:!1,value:e[o++]}},e:function(e){throw e},f:i}}throw new TypeError("Invalid attempt to iterate non-iterable instance.\nIn order to be iterable, non-array objects must have a [Symbol.iterator]() method.")}var a,s=!0,c=!1;return{s:function(){n=n.call(e)},n:function(){var e=n.next();return s=e.done,e},e:function(e){c=!0,a=e},f:function(){try{s||null==n.return||n.return()}finally{if(c)throw a}}}}function r(e,t){(null==t||t>e.length)&&(t=e.length);for(var n=0,r=new Array(t);n<t;n++)r[n]=e[n];return r}var o,i;Object.defineProperty(t,"__esModule",{value:!0}),t.apply=t.addFontCSS=t.getWebPageLang=t.Fallback=t.WebFontLang=void 0,function(e){e.English="en",e.Chinese="zh",e.TraditionChinese="zh-hant",e.SimpleChinese="zh-hans",e.Japanese="ja"}(o||(t.WebFontLang=o={})),function(e){e.Serif="serif",e.SansSerif="sans-serif",e.Monospace="monospace"}(i||(t.Fallback=i={}));t.getWebPageLang=function(){var e=document.documentElement.lang;if("en"===(e=e.trim().toLowerCase())||e.startsWith("en-"))return o.English;if("zh"===e)return o.Chinese;if(!e.startsWith("zh-"))return e.startsWith("ja-")?o.Japanese:e;switch(e.substring(3)){case"tw":case"cht":case"hk":case"hant":return o.TraditionChinese;case"cn":case"chs":case"hans":return o.SimpleChinese;default:return o.Chinese}};t.addFontCSS=function(e){for(var t=new URL("".concat(arguments.length>1&&void 0!==arguments[1]?arguments[1]:"css/","font-").concat(e,".min.css"),window.location.origin).href,n=document.getElementsByTagName("head")[0],r=n.children.length-1;r>=0;r--){var o=n.children[r];if("LINK"===o.tagName){var i=o;if("stylesheet"===i.rel&&t.length===i.href.length&&t.endsWith(i.href))return}}var a=document.createElement("link");a.href=t,a.rel="stylesheet",n.appendChild(a)};t.apply=function(){var e,r=arguments.length>0&&void 0!==arguments[0]?arguments[0]:document.body,o=arguments.length>1?arguments[1]:void 0,a=arguments.length>2?arguments[2]:void 0,s=arguments.length>3?arguments[3]:void 0,c=arguments.length>4&&void 0!==arguments[4]?arguments[4]:i.SansSerif,l=arguments.length>5?arguments[5]:void 0;if((0,t.addFontCSS)(a,o),e=void 0!==l?Array.isArray(l)?'"'.concat(s,'", "').concat(l.join('", "'),'", ').concat(c):'"'.concat(s,'", "').concat(l,'", ').concat(c):'"'.concat(s,'", ').concat(c),"string"==typeof r){var u=document.createElement("style");u.innerHTML="".concat(r," { font-family: ").concat(e," !important; }"),document.getElementsByTagName("head")[0].appendChild(u)}else{var f=[];if(r instanceof HTMLElement)f.push(r);else{var d,p=n(r);try{for(p.s();!(d=p.n()).done;){var h=d.value;f.push(h)}}catch(e){p.e(e)}finally{p.f()}}f.forEach((function(t){t.style.fontFamily=e}))}}},819:(e,t,n)=>{"use strict";t.P=void 0;var r=n(601);t.P=function(){var e=arguments.length>0&&void 0!==arguments[0]?arguments[0]:document.body,t=arguments.length>1?arguments[1]:void 0;(0,r.apply)(e,t,"roboto-mono","ROBOTO MONO",r.Fallback.Monospace)}}},t={};function n(r){var o=t[r];if(void 0!==o)return o.exports;var i=t[r]={id:r,loaded:!1,exports:{}};return e[r].call(i.exports,i,i.exports,n),i.loaded=!0,i.exports}n.d=(e,t)=>{for(var r in t)n.o(t,r)&&!n.o(e,r)&&Object.defineProperty(e,r,{enumerable:!0,get:t[r]})},n.g=function(){if("object"==typeof globalThis)return globalThis;try{return this||new Function("return this")()}catch(e){if("object"==typeof window)return window}}(),n.o=(e,t)=>Object.prototype.hasOwnProperty.call(e,t),n.r=e=>{"undefined"!=typeof Symbol&&Symbol.toStringTag&&Object.defineProperty(e,Symbol.toStringTag,{value:"Module"}),Object.defineProperty(e,"__esModule",{value:!0})},n.nmd=e=>(e.paths=[],e.children||(e.children=[]),e);var r={};return(()=>{"use strict";n.r(r),n.d(r,{monitorInit:()=>ac});var e=n(25);n(252),n(202);function t(e){return t="function"==typeof Symbol&&"symbol"==typeof Symbol.iterator?function(e){return typeof e}:function(e){return e&&"function"==typeof Symbol&&e.constructor===Symbol&&e!==Symbol.prototype?"symbol":typeof e},t(e)}var o=Object.freeze({}),i=Array.isArray;function a(e){return null==e}function s(e){return null!=e}function c(e){return!0===e}function l(e){return"string"==typeof e||"number"==typeof e||"symbol"===t(e)||"boolean"==typeof e}function u(e){return"function"==typeof e}function f(e){return null!==e&&"object"===t(e)}var d=Object.prototype.toString;function p(e){return"[object Object]"===d.call(e)}function h(e){return"[object RegExp]"===d.call(e)}function v(e){var t=parseFloat(String(e));return t>=0&&Math.floor(t)===t&&isFinite(e)}function m(e){return s(e)&&"function"==typeof e.then&&"function"==typeof e.catch}function g(e){return null==e?"":Array.isArray(e)||p(e)&&e.toString===d?JSON.stringify(e,null,2):String(e)}function y(e){var t=parseFloat(e);return isNaN(t)?e:t}function b(e,t){for(var n=Object.create(null),r=e.split(","),o=0;o<r.length;o++)n[r[o]]=!0;return t?function(e){return n[e.toLowerCase()]}:function(e){return n[e]}}var _=b("slot,component",!0),x=b("key,ref,slot,slot-scope,is");function w(e,t){var n=e.length;if(n){if(t===e[n-1])return void(e.length=n-1);var r=e.indexOf(t);if(r>-1)return e.splice(r,1)}}var C=Object.prototype.hasOwnProperty;function T(e,t){return C.call(e,t)}function k(e){var t=Object.create(null);return function(n){return t[n]||(t[n]=e(n))}}var S=/-(\w)/g,E=k((function(e){return e.replace(S,(function(e,t){return t?t.toUpperCase():""}))})),$=k((function(e){return e.charAt(0).toUpperCase()+e.slice(1)})),A=/\B([A-Z])/g,O=k((function(e){return e.replace(A,"-$1").toLowerCase()}));var j=Function.prototype.bind?function(e,t){return e.bind(t)}:function(e,t){function n(n){var r=arguments.length;return r?r>1?e.apply(t,arguments):e.call(t,n):e.call(t)}return n._length=e.length,n};function D(e,t){t=t||0;for(var n=e.length-t,r=new Array(n);n--;)r[n]=e[n+t];return r}function N(e,t){for(var n in t)e[n]=t[n];return e}function L(e){for(var t={},n=0;n<e.length;n++)e[n]&&N(t,e[n]);return t}function P(e,t,n){}var I=function(e,t,n){return!1},M=function(e){return e};function R(e,t){if(e===t)return!0;var n=f(e),r=f(t);if(!n||!r)return!n&&!r&&String(e)===String(t);try{var o=Array.isArray(e),i=Array.isArray(t);if(o&&i)return e.length===t.length&&e.every((function(e,n){return R(e,t[n])}));if(e instanceof Date&&t instanceof Date)return e.getTime()===t.getTime();if(o||i)return!1;var a=Object.keys(e),s=Object.keys(t);return a.length===s.length&&a.every((function(n){return R(e[n],t[n])}))}catch(e){return!1}}function F(e,t){for(var n=0;n<e.length;n++)if(R(e[n],t))return n;return-1}function H(e){var t=!1;return function(){t||(t=!0,e.apply(this,arguments))}}function q(e,t){return e===t?0===e&&1/e!=1/t:e==e||t==t}var B="data-server-rendered",W=["component","directive","filter"],U=["beforeCreate","created","beforeMount","mounted","beforeUpdate","updated","beforeDestroy","destroyed","activated","deactivated","errorCaptured","serverPrefetch","renderTracked","renderTriggered"],z={optionMergeStrategies:Object.create(null),silent:!1,productionTip:!1,devtools:!1,performance:!1,errorHandler:null,warnHandler:null,ignoredElements:[],keyCodes:Object.create(null),isReservedTag:I,isReservedAttr:I,isUnknownElement:I,getTagNamespace:P,parsePlatformTagName:M,mustUseProp:I,async:!0,_lifecycleHooks:U},V=/a-zA-Z\u00B7\u00C0-\u00D6\u00D8-\u00F6\u00F8-\u037D\u037F-\u1FFF\u200C-\u200D\u203F-\u2040\u2070-\u218F\u2C00-\u2FEF\u3001-\uD7FF\uF900-\uFDCF\uFDF0-\uFFFD/;function J(e){var t=(e+"").charCodeAt(0);return 36===t||95===t}function K(e,t,n,r){Object.defineProperty(e,t,{value:n,enumerable:!!r,writable:!0,configurable:!0})}var X=new RegExp("[^".concat(V.source,".$_\\d]"));var Q="__proto__"in{},G="undefined"!=typeof window,Y=G&&window.navigator.userAgent.toLowerCase(),Z=Y&&/msie|trident/.test(Y),ee=Y&&Y.indexOf("msie 9.0")>0,te=Y&&Y.indexOf("edge/")>0;Y&&Y.indexOf("android");var ne=Y&&/iphone|ipad|ipod|ios/.test(Y);Y&&/chrome\/\d+/.test(Y),Y&&/phantomjs/.test(Y);var re,oe=Y&&Y.match(/firefox\/(\d+)/),ie={}.watch,ae=!1;if(G)try{var se={};Object.defineProperty(se,"passive",{get:function(){ae=!0}}),window.addEventListener("test-passive",null,se)}catch(e){}var ce=function(){return void 0===re&&(re=!G&&void 0!==n.g&&(n.g.process&&"server"===n.g.process.env.VUE_ENV)),re},le=G&&window.__VUE_DEVTOOLS_GLOBAL_HOOK__;function ue(e){return"function"==typeof e&&/native code/.test(e.toString())}var fe,de="undefined"!=typeof Symbol&&ue(Symbol)&&"undefined"!=typeof Reflect&&ue(Reflect.ownKeys);fe="undefined"!=typeof Set&&ue(Set)?Set:function(){function e(){this.set=Object.create(null)}return e.prototype.has=function(e){return!0===this.set[e]},e.prototype.add=function(e){this.set[e]=!0},e.prototype.clear=function(){this.set=Object.create(null)},e}();var pe=null;function he(e){void 0===e&&(e=null),e||pe&&pe._scope.off(),pe=e,e&&e._scope.on()}var ve=function(){function e(e,t,n,r,o,i,a,s){this.tag=e,this.data=t,this.children=n,this.text=r,this.elm=o,this.ns=void 0,this.context=i,this.fnContext=void 0,this.fnOptions=void 0,this.fnScopeId=void 0,this.key=t&&t.key,this.componentOptions=a,this.componentInstance=void 0,this.parent=void 0,this.raw=!1,this.isStatic=!1,this.isRootInsert=!0,this.isComment=!1,this.isCloned=!1,this.isOnce=!1,this.asyncFactory=s,this.asyncMeta=void 0,this.isAsyncPlaceholder=!1}return Object.defineProperty(e.prototype,"child",{get:function(){return this.componentInstance},enumerable:!1,configurable:!0}),e}(),me=function(e){void 0===e&&(e="");var t=new ve;return t.text=e,t.isComment=!0,t};function ge(e){return new ve(void 0,void 0,void 0,String(e))}function ye(e){var t=new ve(e.tag,e.data,e.children&&e.children.slice(),e.text,e.elm,e.context,e.componentOptions,e.asyncFactory);return t.ns=e.ns,t.isStatic=e.isStatic,t.key=e.key,t.isComment=e.isComment,t.fnContext=e.fnContext,t.fnOptions=e.fnOptions,t.fnScopeId=e.fnScopeId,t.asyncMeta=e.asyncMeta,t.isCloned=!0,t}var be=0,_e=[],xe=function(){for(var e=0;e<_e.length;e++){var t=_e[e];t.subs=t.subs.filter((function(e){return e})),t._pending=!1}_e.length=0},we=function(){function e(){this._pending=!1,this.id=be++,this.subs=[]}return e.prototype.addSub=function(e){this.subs.push(e)},e.prototype.removeSub=function(e){this.subs[this.subs.indexOf(e)]=null,this._pending||(this._pending=!0,_e.push(this))},e.prototype.depend=function(t){e.target&&e.target.addDep(this)},e.prototype.notify=function(e){var t=this.subs.filter((function(e){return e}));for(var n=0,r=t.length;n<r;n++){0,t[n].update()}},e}();we.target=null;var Ce=[];function Te(e){Ce.push(e),we.target=e}function ke(){Ce.pop(),we.target=Ce[Ce.length-1]}var Se=Array.prototype,Ee=Object.create(Se);["push","pop","shift","unshift","splice","sort","reverse"].forEach((function(e){var t=Se[e];K(Ee,e,(function(){for(var n=[],r=0;r<arguments.length;r++)n[r]=arguments[r];var o,i=t.apply(this,n),a=this.__ob__;switch(e){case"push":case"unshift":o=n;break;case"splice":o=n.slice(2)}return o&&a.observeArray(o),a.dep.notify(),i}))}));var $e=Object.getOwnPropertyNames(Ee),Ae={},Oe=!0;function je(e){Oe=e}var De={notify:P,depend:P,addSub:P,removeSub:P},Ne=function(){function e(e,t,n){if(void 0===t&&(t=!1),void 0===n&&(n=!1),this.value=e,this.shallow=t,this.mock=n,this.dep=n?De:new we,this.vmCount=0,K(e,"__ob__",this),i(e)){if(!n)if(Q)e.__proto__=Ee;else for(var r=0,o=$e.length;r<o;r++){K(e,s=$e[r],Ee[s])}t||this.observeArray(e)}else{var a=Object.keys(e);for(r=0;r<a.length;r++){var s;Pe(e,s=a[r],Ae,void 0,t,n)}}}return e.prototype.observeArray=function(e){for(var t=0,n=e.length;t<n;t++)Le(e[t],!1,this.mock)},e}();function Le(e,t,n){return e&&T(e,"__ob__")&&e.__ob__ instanceof Ne?e.__ob__:!Oe||!n&&ce()||!i(e)&&!p(e)||!Object.isExtensible(e)||e.__v_skip||Be(e)||e instanceof ve?void 0:new Ne(e,t,n)}function Pe(e,t,n,r,o,a){var s=new we,c=Object.getOwnPropertyDescriptor(e,t);if(!c||!1!==c.configurable){var l=c&&c.get,u=c&&c.set;l&&!u||n!==Ae&&2!==arguments.length||(n=e[t]);var f=!o&&Le(n,!1,a);return Object.defineProperty(e,t,{enumerable:!0,configurable:!0,get:function(){var t=l?l.call(e):n;return we.target&&(s.depend(),f&&(f.dep.depend(),i(t)&&Re(t))),Be(t)&&!o?t.value:t},set:function(t){var r=l?l.call(e):n;if(q(r,t)){if(u)u.call(e,t);else{if(l)return;if(!o&&Be(r)&&!Be(t))return void(r.value=t);n=t}f=!o&&Le(t,!1,a),s.notify()}}}),s}}function Ie(e,t,n){if(!qe(e)){var r=e.__ob__;return i(e)&&v(t)?(e.length=Math.max(e.length,t),e.splice(t,1,n),r&&!r.shallow&&r.mock&&Le(n,!1,!0),n):t in e&&!(t in Object.prototype)?(e[t]=n,n):e._isVue||r&&r.vmCount?n:r?(Pe(r.value,t,n,void 0,r.shallow,r.mock),r.dep.notify(),n):(e[t]=n,n)}}function Me(e,t){if(i(e)&&v(t))e.splice(t,1);else{var n=e.__ob__;e._isVue||n&&n.vmCount||qe(e)||T(e,t)&&(delete e[t],n&&n.dep.notify())}}function Re(e){for(var t=void 0,n=0,r=e.length;n<r;n++)(t=e[n])&&t.__ob__&&t.__ob__.dep.depend(),i(t)&&Re(t)}function Fe(e){return He(e,!0),K(e,"__v_isShallow",!0),e}function He(e,t){if(!qe(e)){Le(e,t,ce());0}}function qe(e){return!(!e||!e.__v_isReadonly)}function Be(e){return!(!e||!0!==e.__v_isRef)}function We(e,t,n){Object.defineProperty(e,n,{enumerable:!0,configurable:!0,get:function(){var e=t[n];if(Be(e))return e.value;var r=e&&e.__ob__;return r&&r.dep.depend(),e},set:function(e){var r=t[n];Be(r)&&!Be(e)?r.value=e:t[n]=e}})}var Ue=k((function(e){var t="&"===e.charAt(0),n="~"===(e=t?e.slice(1):e).charAt(0),r="!"===(e=n?e.slice(1):e).charAt(0);return{name:e=r?e.slice(1):e,once:n,capture:r,passive:t}}));function ze(e,t){function n(){var e=n.fns;if(!i(e))return on(e,null,arguments,t,"v-on handler");for(var r=e.slice(),o=0;o<r.length;o++)on(r[o],null,arguments,t,"v-on handler")}return n.fns=e,n}function Ve(e,t,n,r,o,i){var s,l,u,f;for(s in e)l=e[s],u=t[s],f=Ue(s),a(l)||(a(u)?(a(l.fns)&&(l=e[s]=ze(l,i)),c(f.once)&&(l=e[s]=o(f.name,l,f.capture)),n(f.name,l,f.capture,f.passive,f.params)):l!==u&&(u.fns=l,e[s]=u));for(s in t)a(e[s])&&r((f=Ue(s)).name,t[s],f.capture)}function Je(e,t,n){var r;e instanceof ve&&(e=e.data.hook||(e.data.hook={}));var o=e[t];function i(){n.apply(this,arguments),w(r.fns,i)}a(o)?r=ze([i]):s(o.fns)&&c(o.merged)?(r=o).fns.push(i):r=ze([o,i]),r.merged=!0,e[t]=r}function Ke(e,t,n,r,o){if(s(t)){if(T(t,n))return e[n]=t[n],o||delete t[n],!0;if(T(t,r))return e[n]=t[r],o||delete t[r],!0}return!1}function Xe(e){return l(e)?[ge(e)]:i(e)?Ge(e):void 0}function Qe(e){return s(e)&&s(e.text)&&!1===e.isComment}function Ge(e,t){var n,r,o,u,f=[];for(n=0;n<e.length;n++)a(r=e[n])||"boolean"==typeof r||(u=f[o=f.length-1],i(r)?r.length>0&&(Qe((r=Ge(r,"".concat(t||"","_").concat(n)))[0])&&Qe(u)&&(f[o]=ge(u.text+r[0].text),r.shift()),f.push.apply(f,r)):l(r)?Qe(u)?f[o]=ge(u.text+r):""!==r&&f.push(ge(r)):Qe(r)&&Qe(u)?f[o]=ge(u.text+r.text):(c(e._isVList)&&s(r.tag)&&a(r.key)&&s(t)&&(r.key="__vlist".concat(t,"_").concat(n,"__")),f.push(r)));return f}var Ye=1,Ze=2;function et(e,t,n,r,o,a){return(i(n)||l(n))&&(o=r,r=n,n=void 0),c(a)&&(o=Ze),function(e,t,n,r,o){if(s(n)&&s(n.__ob__))return me();s(n)&&s(n.is)&&(t=n.is);if(!t)return me();0;i(r)&&u(r[0])&&((n=n||{}).scopedSlots={default:r[0]},r.length=0);o===Ze?r=Xe(r):o===Ye&&(r=function(e){for(var t=0;t<e.length;t++)if(i(e[t]))return Array.prototype.concat.apply([],e);return e}(r));var a,c;if("string"==typeof t){var l=void 0;c=e.$vnode&&e.$vnode.ns||z.getTagNamespace(t),a=z.isReservedTag(t)?new ve(z.parsePlatformTagName(t),n,r,void 0,void 0,e):n&&n.pre||!s(l=Gn(e.$options,"components",t))?new ve(t,n,r,void 0,void 0,e):qn(l,n,e,r,t)}else a=qn(t,n,e,r);return i(a)?a:s(a)?(s(c)&&tt(a,c),s(n)&&function(e){f(e.style)&&_n(e.style);f(e.class)&&_n(e.class)}(n),a):me()}(e,t,n,r,o)}function tt(e,t,n){if(e.ns=t,"foreignObject"===e.tag&&(t=void 0,n=!0),s(e.children))for(var r=0,o=e.children.length;r<o;r++){var i=e.children[r];s(i.tag)&&(a(i.ns)||c(n)&&"svg"!==i.tag)&&tt(i,t,n)}}function nt(e,t){var n,r,o,a,c=null;if(i(e)||"string"==typeof e)for(c=new Array(e.length),n=0,r=e.length;n<r;n++)c[n]=t(e[n],n);else if("number"==typeof e)for(c=new Array(e),n=0;n<e;n++)c[n]=t(n+1,n);else if(f(e))if(de&&e[Symbol.iterator]){c=[];for(var l=e[Symbol.iterator](),u=l.next();!u.done;)c.push(t(u.value,c.length)),u=l.next()}else for(o=Object.keys(e),c=new Array(o.length),n=0,r=o.length;n<r;n++)a=o[n],c[n]=t(e[a],a,n);return s(c)||(c=[]),c._isVList=!0,c}function rt(e,t,n,r){var o,i=this.$scopedSlots[e];i?(n=n||{},r&&(n=N(N({},r),n)),o=i(n)||(u(t)?t():t)):o=this.$slots[e]||(u(t)?t():t);var a=n&&n.slot;return a?this.$createElement("template",{slot:a},o):o}function ot(e){return Gn(this.$options,"filters",e,!0)||M}function it(e,t){return i(e)?-1===e.indexOf(t):e!==t}function at(e,t,n,r,o){var i=z.keyCodes[t]||n;return o&&r&&!z.keyCodes[t]?it(o,r):i?it(i,e):r?O(r)!==t:void 0===e}function st(e,t,n,r,o){if(n)if(f(n)){i(n)&&(n=L(n));var a=void 0,s=function(i){if("class"===i||"style"===i||x(i))a=e;else{var s=e.attrs&&e.attrs.type;a=r||z.mustUseProp(t,s,i)?e.domProps||(e.domProps={}):e.attrs||(e.attrs={})}var c=E(i),l=O(i);c in a||l in a||(a[i]=n[i],o&&((e.on||(e.on={}))["update:".concat(i)]=function(e){n[i]=e}))};for(var c in n)s(c)}else;return e}function ct(e,t){var n=this._staticTrees||(this._staticTrees=[]),r=n[e];return r&&!t||ut(r=n[e]=this.$options.staticRenderFns[e].call(this._renderProxy,this._c,this),"__static__".concat(e),!1),r}function lt(e,t,n){return ut(e,"__once__".concat(t).concat(n?"_".concat(n):""),!0),e}function ut(e,t,n){if(i(e))for(var r=0;r<e.length;r++)e[r]&&"string"!=typeof e[r]&&ft(e[r],"".concat(t,"_").concat(r),n);else ft(e,t,n)}function ft(e,t,n){e.isStatic=!0,e.key=t,e.isOnce=n}function dt(e,t){if(t)if(p(t)){var n=e.on=e.on?N({},e.on):{};for(var r in t){var o=n[r],i=t[r];n[r]=o?[].concat(o,i):i}}else;return e}function pt(e,t,n,r){t=t||{$stable:!n};for(var o=0;o<e.length;o++){var a=e[o];i(a)?pt(a,t,n):a&&(a.proxy&&(a.fn.proxy=!0),t[a.key]=a.fn)}return r&&(t.$key=r),t}function ht(e,t){for(var n=0;n<t.length;n+=2){var r=t[n];"string"==typeof r&&r&&(e[t[n]]=t[n+1])}return e}function vt(e,t){return"string"==typeof e?t+e:e}function mt(e){e._o=lt,e._n=y,e._s=g,e._l=nt,e._t=rt,e._q=R,e._i=F,e._m=ct,e._f=ot,e._k=at,e._b=st,e._v=ge,e._e=me,e._u=pt,e._g=dt,e._d=ht,e._p=vt}function gt(e,t){if(!e||!e.length)return{};for(var n={},r=0,o=e.length;r<o;r++){var i=e[r],a=i.data;if(a&&a.attrs&&a.attrs.slot&&delete a.attrs.slot,i.context!==t&&i.fnContext!==t||!a||null==a.slot)(n.default||(n.default=[])).push(i);else{var s=a.slot,c=n[s]||(n[s]=[]);"template"===i.tag?c.push.apply(c,i.children||[]):c.push(i)}}for(var l in n)n[l].every(yt)&&delete n[l];return n}function yt(e){return e.isComment&&!e.asyncFactory||" "===e.text}function bt(e){return e.isComment&&e.asyncFactory}function _t(e,t,n,r){var i,a=Object.keys(n).length>0,s=t?!!t.$stable:!a,c=t&&t.$key;if(t){if(t._normalized)return t._normalized;if(s&&r&&r!==o&&c===r.$key&&!a&&!r.$hasNormal)return r;for(var l in i={},t)t[l]&&"$"!==l[0]&&(i[l]=xt(e,n,l,t[l]))}else i={};for(var u in n)u in i||(i[u]=wt(n,u));return t&&Object.isExtensible(t)&&(t._normalized=i),K(i,"$stable",s),K(i,"$key",c),K(i,"$hasNormal",a),i}function xt(e,n,r,o){var a=function(){var n=pe;he(e);var r=arguments.length?o.apply(null,arguments):o({}),a=(r=r&&"object"===t(r)&&!i(r)?[r]:Xe(r))&&r[0];return he(n),r&&(!a||1===r.length&&a.isComment&&!bt(a))?void 0:r};return o.proxy&&Object.defineProperty(n,r,{get:a,enumerable:!0,configurable:!0}),a}function wt(e,t){return function(){return e[t]}}function Ct(e){return{get attrs(){if(!e._attrsProxy){var t=e._attrsProxy={};K(t,"_v_attr_proxy",!0),Tt(t,e.$attrs,o,e,"$attrs")}return e._attrsProxy},get listeners(){e._listenersProxy||Tt(e._listenersProxy={},e.$listeners,o,e,"$listeners");return e._listenersProxy},get slots(){return function(e){e._slotsProxy||St(e._slotsProxy={},e.$scopedSlots);return e._slotsProxy}(e)},emit:j(e.$emit,e),expose:function(t){t&&Object.keys(t).forEach((function(n){return We(e,t,n)}))}}}function Tt(e,t,n,r,o){var i=!1;for(var a in t)a in e?t[a]!==n[a]&&(i=!0):(i=!0,kt(e,a,r,o));for(var a in e)a in t||(i=!0,delete e[a]);return i}function kt(e,t,n,r){Object.defineProperty(e,t,{enumerable:!0,configurable:!0,get:function(){return n[r][t]}})}function St(e,t){for(var n in t)e[n]=t[n];for(var n in e)n in t||delete e[n]}var Et,$t,At=null;function Ot(e,t){return(e.__esModule||de&&"Module"===e[Symbol.toStringTag])&&(e=e.default),f(e)?t.extend(e):e}function jt(e){if(i(e))for(var t=0;t<e.length;t++){var n=e[t];if(s(n)&&(s(n.componentOptions)||bt(n)))return n}}function Dt(e,t){Et.$on(e,t)}function Nt(e,t){Et.$off(e,t)}function Lt(e,t){var n=Et;return function r(){null!==t.apply(null,arguments)&&n.$off(e,r)}}function Pt(e,t,n){Et=e,Ve(t,n||{},Dt,Nt,Lt,e),Et=void 0}var It=function(){function e(e){void 0===e&&(e=!1),this.detached=e,this.active=!0,this.effects=[],this.cleanups=[],this.parent=$t,!e&&$t&&(this.index=($t.scopes||($t.scopes=[])).push(this)-1)}return e.prototype.run=function(e){if(this.active){var t=$t;try{return $t=this,e()}finally{$t=t}}else 0},e.prototype.on=function(){$t=this},e.prototype.off=function(){$t=this.parent},e.prototype.stop=function(e){if(this.active){var t=void 0,n=void 0;for(t=0,n=this.effects.length;t<n;t++)this.effects[t].teardown();for(t=0,n=this.cleanups.length;t<n;t++)this.cleanups[t]();if(this.scopes)for(t=0,n=this.scopes.length;t<n;t++)this.scopes[t].stop(!0);if(!this.detached&&this.parent&&!e){var r=this.parent.scopes.pop();r&&r!==this&&(this.parent.scopes[this.index]=r,r.index=this.index)}this.parent=void 0,this.active=!1}},e}();var Mt=null;function Rt(e){var t=Mt;return Mt=e,function(){Mt=t}}function Ft(e){for(;e&&(e=e.$parent);)if(e._inactive)return!0;return!1}function Ht(e,t){if(t){if(e._directInactive=!1,Ft(e))return}else if(e._directInactive)return;if(e._inactive||null===e._inactive){e._inactive=!1;for(var n=0;n<e.$children.length;n++)Ht(e.$children[n]);Bt(e,"activated")}}function qt(e,t){if(!(t&&(e._directInactive=!0,Ft(e))||e._inactive)){e._inactive=!0;for(var n=0;n<e.$children.length;n++)qt(e.$children[n]);Bt(e,"deactivated")}}function Bt(e,t,n,r){void 0===r&&(r=!0),Te();var o=pe,i=$t;r&&he(e);var a=e.$options[t],s="".concat(t," hook");if(a)for(var c=0,l=a.length;c<l;c++)on(a[c],e,n||null,e,s);e._hasHookEvent&&e.$emit("hook:"+t),r&&(he(o),i&&i.on()),ke()}var Wt=[],Ut=[],zt={},Vt=!1,Jt=!1,Kt=0;var Xt=0,Qt=Date.now;if(G&&!Z){var Gt=window.performance;Gt&&"function"==typeof Gt.now&&Qt()>document.createEvent("Event").timeStamp&&(Qt=function(){return Gt.now()})}var Yt=function(e,t){if(e.post){if(!t.post)return 1}else if(t.post)return-1;return e.id-t.id};function Zt(){var e,t;for(Xt=Qt(),Jt=!0,Wt.sort(Yt),Kt=0;Kt<Wt.length;Kt++)(e=Wt[Kt]).before&&e.before(),t=e.id,zt[t]=null,e.run();var n=Ut.slice(),r=Wt.slice();Kt=Wt.length=Ut.length=0,zt={},Vt=Jt=!1,function(e){for(var t=0;t<e.length;t++)e[t]._inactive=!0,Ht(e[t],!0)}(n),function(e){var t=e.length;for(;t--;){var n=e[t],r=n.vm;r&&r._watcher===n&&r._isMounted&&!r._isDestroyed&&Bt(r,"updated")}}(r),xe(),le&&z.devtools&&le.emit("flush")}function en(e){var t=e.id;if(null==zt[t]&&(e!==we.target||!e.noRecurse)){if(zt[t]=!0,Jt){for(var n=Wt.length-1;n>Kt&&Wt[n].id>e.id;)n--;Wt.splice(n+1,0,e)}else Wt.push(e);Vt||(Vt=!0,gn(Zt))}}var tn="watcher";"".concat(tn," callback"),"".concat(tn," getter"),"".concat(tn," cleanup");function nn(e){var t=e._provided,n=e.$parent&&e.$parent._provided;return n===t?e._provided=Object.create(n):t}function rn(e,t,n){Te();try{if(t)for(var r=t;r=r.$parent;){var o=r.$options.errorCaptured;if(o)for(var i=0;i<o.length;i++)try{if(!1===o[i].call(r,e,t,n))return}catch(e){an(e,r,"errorCaptured hook")}}an(e,t,n)}finally{ke()}}function on(e,t,n,r,o){var i;try{(i=n?e.apply(t,n):e.call(t))&&!i._isVue&&m(i)&&!i._handled&&(i.catch((function(e){return rn(e,r,o+" (Promise/async)")})),i._handled=!0)}catch(e){rn(e,r,o)}return i}function an(e,t,n){if(z.errorHandler)try{return z.errorHandler.call(null,e,t,n)}catch(t){t!==e&&sn(t,null,"config.errorHandler")}sn(e,t,n)}function sn(e,t,n){if(!G||"undefined"==typeof console)throw e;console.error(e)}var cn,ln=!1,un=[],fn=!1;function dn(){fn=!1;var e=un.slice(0);un.length=0;for(var t=0;t<e.length;t++)e[t]()}if("undefined"!=typeof Promise&&ue(Promise)){var pn=Promise.resolve();cn=function(){pn.then(dn),ne&&setTimeout(P)},ln=!0}else if(Z||"undefined"==typeof MutationObserver||!ue(MutationObserver)&&"[object MutationObserverConstructor]"!==MutationObserver.toString())cn="undefined"!=typeof setImmediate&&ue(setImmediate)?function(){setImmediate(dn)}:function(){setTimeout(dn,0)};else{var hn=1,vn=new MutationObserver(dn),mn=document.createTextNode(String(hn));vn.observe(mn,{characterData:!0}),cn=function(){hn=(hn+1)%2,mn.data=String(hn)},ln=!0}function gn(e,t){var n;if(un.push((function(){if(e)try{e.call(t)}catch(e){rn(e,t,"nextTick")}else n&&n(t)})),fn||(fn=!0,cn()),!e&&"undefined"!=typeof Promise)return new Promise((function(e){n=e}))}function yn(e){return function(t,n){if(void 0===n&&(n=pe),n)return function(e,t,n){var r=e.$options;r[t]=Jn(r[t],n)}(n,e,t)}}yn("beforeMount"),yn("mounted"),yn("beforeUpdate"),yn("updated"),yn("beforeDestroy"),yn("destroyed"),yn("activated"),yn("deactivated"),yn("serverPrefetch"),yn("renderTracked"),yn("renderTriggered"),yn("errorCaptured");var bn=new fe;function _n(e){return xn(e,bn),bn.clear(),e}function xn(e,t){var n,r,o=i(e);if(!(!o&&!f(e)||e.__v_skip||Object.isFrozen(e)||e instanceof ve)){if(e.__ob__){var a=e.__ob__.dep.id;if(t.has(a))return;t.add(a)}if(o)for(n=e.length;n--;)xn(e[n],t);else if(Be(e))xn(e.value,t);else for(n=(r=Object.keys(e)).length;n--;)xn(e[r[n]],t)}}var wn=0,Cn=function(){function e(e,t,n,r,o){var i,a;i=this,void 0===(a=$t&&!$t._vm?$t:e?e._scope:void 0)&&(a=$t),a&&a.active&&a.effects.push(i),(this.vm=e)&&o&&(e._watcher=this),r?(this.deep=!!r.deep,this.user=!!r.user,this.lazy=!!r.lazy,this.sync=!!r.sync,this.before=r.before):this.deep=this.user=this.lazy=this.sync=!1,this.cb=n,this.id=++wn,this.active=!0,this.post=!1,this.dirty=this.lazy,this.deps=[],this.newDeps=[],this.depIds=new fe,this.newDepIds=new fe,this.expression="",u(t)?this.getter=t:(this.getter=function(e){if(!X.test(e)){var t=e.split(".");return function(e){for(var n=0;n<t.length;n++){if(!e)return;e=e[t[n]]}return e}}}(t),this.getter||(this.getter=P)),this.value=this.lazy?void 0:this.get()}return e.prototype.get=function(){var e;Te(this);var t=this.vm;try{e=this.getter.call(t,t)}catch(e){if(!this.user)throw e;rn(e,t,'getter for watcher "'.concat(this.expression,'"'))}finally{this.deep&&_n(e),ke(),this.cleanupDeps()}return e},e.prototype.addDep=function(e){var t=e.id;this.newDepIds.has(t)||(this.newDepIds.add(t),this.newDeps.push(e),this.depIds.has(t)||e.addSub(this))},e.prototype.cleanupDeps=function(){for(var e=this.deps.length;e--;){var t=this.deps[e];this.newDepIds.has(t.id)||t.removeSub(this)}var n=this.depIds;this.depIds=this.newDepIds,this.newDepIds=n,this.newDepIds.clear(),n=this.deps,this.deps=this.newDeps,this.newDeps=n,this.newDeps.length=0},e.prototype.update=function(){this.lazy?this.dirty=!0:this.sync?this.run():en(this)},e.prototype.run=function(){if(this.active){var e=this.get();if(e!==this.value||f(e)||this.deep){var t=this.value;if(this.value=e,this.user){var n='callback for watcher "'.concat(this.expression,'"');on(this.cb,this.vm,[e,t],this.vm,n)}else this.cb.call(this.vm,e,t)}}},e.prototype.evaluate=function(){this.value=this.get(),this.dirty=!1},e.prototype.depend=function(){for(var e=this.deps.length;e--;)this.deps[e].depend()},e.prototype.teardown=function(){if(this.vm&&!this.vm._isBeingDestroyed&&w(this.vm._scope.effects,this),this.active){for(var e=this.deps.length;e--;)this.deps[e].removeSub(this);this.active=!1,this.onStop&&this.onStop()}},e}(),Tn={enumerable:!0,configurable:!0,get:P,set:P};function kn(e,t,n){Tn.get=function(){return this[t][n]},Tn.set=function(e){this[t][n]=e},Object.defineProperty(e,n,Tn)}function Sn(e){var t=e.$options;if(t.props&&function(e,t){var n=e.$options.propsData||{},r=e._props=Fe({}),o=e.$options._propKeys=[],i=!e.$parent;i||je(!1);var a=function(i){o.push(i);var a=Yn(i,t,n,e);Pe(r,i,a),i in e||kn(e,"_props",i)};for(var s in t)a(s);je(!0)}(e,t.props),function(e){var t=e.$options,n=t.setup;if(n){var r=e._setupContext=Ct(e);he(e),Te();var o=on(n,null,[e._props||Fe({}),r],e,"setup");if(ke(),he(),u(o))t.render=o;else if(f(o))if(e._setupState=o,o.__sfc){var i=e._setupProxy={};for(var a in o)"__sfc"!==a&&We(i,o,a)}else for(var a in o)J(a)||We(e,o,a)}}(e),t.methods&&function(e,t){e.$options.props;for(var n in t)e[n]="function"!=typeof t[n]?P:j(t[n],e)}(e,t.methods),t.data)!function(e){var t=e.$options.data;t=e._data=u(t)?function(e,t){Te();try{return e.call(t,t)}catch(e){return rn(e,t,"data()"),{}}finally{ke()}}(t,e):t||{},p(t)||(t={});var n=Object.keys(t),r=e.$options.props,o=(e.$options.methods,n.length);for(;o--;){var i=n[o];0,r&&T(r,i)||J(i)||kn(e,"_data",i)}var a=Le(t);a&&a.vmCount++}(e);else{var n=Le(e._data={});n&&n.vmCount++}t.computed&&function(e,t){var n=e._computedWatchers=Object.create(null),r=ce();for(var o in t){var i=t[o],a=u(i)?i:i.get;0,r||(n[o]=new Cn(e,a||P,P,En)),o in e||$n(e,o,i)}}(e,t.computed),t.watch&&t.watch!==ie&&function(e,t){for(var n in t){var r=t[n];if(i(r))for(var o=0;o<r.length;o++)jn(e,n,r[o]);else jn(e,n,r)}}(e,t.watch)}var En={lazy:!0};function $n(e,t,n){var r=!ce();u(n)?(Tn.get=r?An(t):On(n),Tn.set=P):(Tn.get=n.get?r&&!1!==n.cache?An(t):On(n.get):P,Tn.set=n.set||P),Object.defineProperty(e,t,Tn)}function An(e){return function(){var t=this._computedWatchers&&this._computedWatchers[e];if(t)return t.dirty&&t.evaluate(),we.target&&t.depend(),t.value}}function On(e){return function(){return e.call(this,this)}}function jn(e,t,n,r){return p(n)&&(r=n,n=n.handler),"string"==typeof n&&(n=e[n]),e.$watch(t,n,r)}function Dn(e,t){if(e){for(var n=Object.create(null),r=de?Reflect.ownKeys(e):Object.keys(e),o=0;o<r.length;o++){var i=r[o];if("__ob__"!==i){var a=e[i].from;if(a in t._provided)n[i]=t._provided[a];else if("default"in e[i]){var s=e[i].default;n[i]=u(s)?s.call(t):s}else 0}}return n}}var Nn=0;function Ln(e){var t=e.options;if(e.super){var n=Ln(e.super);if(n!==e.superOptions){e.superOptions=n;var r=function(e){var t,n=e.options,r=e.sealedOptions;for(var o in n)n[o]!==r[o]&&(t||(t={}),t[o]=n[o]);return t}(e);r&&N(e.extendOptions,r),(t=e.options=Qn(n,e.extendOptions)).name&&(t.components[t.name]=e)}}return t}function Pn(e,t,n,r,a){var s,l=this,u=a.options;T(r,"_uid")?(s=Object.create(r))._original=r:(s=r,r=r._original);var f=c(u._compiled),d=!f;this.data=e,this.props=t,this.children=n,this.parent=r,this.listeners=e.on||o,this.injections=Dn(u.inject,r),this.slots=function(){return l.$slots||_t(r,e.scopedSlots,l.$slots=gt(n,r)),l.$slots},Object.defineProperty(this,"scopedSlots",{enumerable:!0,get:function(){return _t(r,e.scopedSlots,this.slots())}}),f&&(this.$options=u,this.$slots=this.slots(),this.$scopedSlots=_t(r,e.scopedSlots,this.$slots)),u._scopeId?this._c=function(e,t,n,o){var a=et(s,e,t,n,o,d);return a&&!i(a)&&(a.fnScopeId=u._scopeId,a.fnContext=r),a}:this._c=function(e,t,n,r){return et(s,e,t,n,r,d)}}function In(e,t,n,r,o){var i=ye(e);return i.fnContext=n,i.fnOptions=r,t.slot&&((i.data||(i.data={})).slot=t.slot),i}function Mn(e,t){for(var n in t)e[E(n)]=t[n]}function Rn(e){return e.name||e.__name||e._componentTag}mt(Pn.prototype);var Fn={init:function(e,t){if(e.componentInstance&&!e.componentInstance._isDestroyed&&e.data.keepAlive){var n=e;Fn.prepatch(n,n)}else{(e.componentInstance=function(e,t){var n={_isComponent:!0,_parentVnode:e,parent:t},r=e.data.inlineTemplate;s(r)&&(n.render=r.render,n.staticRenderFns=r.staticRenderFns);return new e.componentOptions.Ctor(n)}(e,Mt)).$mount(t?e.elm:void 0,t)}},prepatch:function(e,t){var n=t.componentOptions;!function(e,t,n,r,i){var a=r.data.scopedSlots,s=e.$scopedSlots,c=!!(a&&!a.$stable||s!==o&&!s.$stable||a&&e.$scopedSlots.$key!==a.$key||!a&&e.$scopedSlots.$key),l=!!(i||e.$options._renderChildren||c),u=e.$vnode;e.$options._parentVnode=r,e.$vnode=r,e._vnode&&(e._vnode.parent=r),e.$options._renderChildren=i;var f=r.data.attrs||o;e._attrsProxy&&Tt(e._attrsProxy,f,u.data&&u.data.attrs||o,e,"$attrs")&&(l=!0),e.$attrs=f,n=n||o;var d=e.$options._parentListeners;if(e._listenersProxy&&Tt(e._listenersProxy,n,d||o,e,"$listeners"),e.$listeners=e.$options._parentListeners=n,Pt(e,n,d),t&&e.$options.props){je(!1);for(var p=e._props,h=e.$options._propKeys||[],v=0;v<h.length;v++){var m=h[v],g=e.$options.props;p[m]=Yn(m,g,t,e)}je(!0),e.$options.propsData=t}l&&(e.$slots=gt(i,r.context),e.$forceUpdate())}(t.componentInstance=e.componentInstance,n.propsData,n.listeners,t,n.children)},insert:function(e){var t,n=e.context,r=e.componentInstance;r._isMounted||(r._isMounted=!0,Bt(r,"mounted")),e.data.keepAlive&&(n._isMounted?((t=r)._inactive=!1,Ut.push(t)):Ht(r,!0))},destroy:function(e){var t=e.componentInstance;t._isDestroyed||(e.data.keepAlive?qt(t,!0):t.$destroy())}},Hn=Object.keys(Fn);function qn(e,t,n,r,l){if(!a(e)){var u=n.$options._base;if(f(e)&&(e=u.extend(e)),"function"==typeof e){var d;if(a(e.cid)&&(e=function(e,t){if(c(e.error)&&s(e.errorComp))return e.errorComp;if(s(e.resolved))return e.resolved;var n=At;if(n&&s(e.owners)&&-1===e.owners.indexOf(n)&&e.owners.push(n),c(e.loading)&&s(e.loadingComp))return e.loadingComp;if(n&&!s(e.owners)){var r=e.owners=[n],o=!0,i=null,l=null;n.$on("hook:destroyed",(function(){return w(r,n)}));var u=function(e){for(var t=0,n=r.length;t<n;t++)r[t].$forceUpdate();e&&(r.length=0,null!==i&&(clearTimeout(i),i=null),null!==l&&(clearTimeout(l),l=null))},d=H((function(n){e.resolved=Ot(n,t),o?r.length=0:u(!0)})),p=H((function(t){s(e.errorComp)&&(e.error=!0,u(!0))})),h=e(d,p);return f(h)&&(m(h)?a(e.resolved)&&h.then(d,p):m(h.component)&&(h.component.then(d,p),s(h.error)&&(e.errorComp=Ot(h.error,t)),s(h.loading)&&(e.loadingComp=Ot(h.loading,t),0===h.delay?e.loading=!0:i=setTimeout((function(){i=null,a(e.resolved)&&a(e.error)&&(e.loading=!0,u(!1))}),h.delay||200)),s(h.timeout)&&(l=setTimeout((function(){l=null,a(e.resolved)&&p(null)}),h.timeout)))),o=!1,e.loading?e.loadingComp:e.resolved}}(d=e,u),void 0===e))return function(e,t,n,r,o){var i=me();return i.asyncFactory=e,i.asyncMeta={data:t,context:n,children:r,tag:o},i}(d,t,n,r,l);t=t||{},Ln(e),s(t.model)&&function(e,t){var n=e.model&&e.model.prop||"value",r=e.model&&e.model.event||"input";(t.attrs||(t.attrs={}))[n]=t.model.value;var o=t.on||(t.on={}),a=o[r],c=t.model.callback;s(a)?(i(a)?-1===a.indexOf(c):a!==c)&&(o[r]=[c].concat(a)):o[r]=c}(e.options,t);var p=function(e,t,n){var r=t.options.props;if(!a(r)){var o={},i=e.attrs,c=e.props;if(s(i)||s(c))for(var l in r){var u=O(l);Ke(o,c,l,u,!0)||Ke(o,i,l,u,!1)}return o}}(t,e);if(c(e.options.functional))return function(e,t,n,r,a){var c=e.options,l={},u=c.props;if(s(u))for(var f in u)l[f]=Yn(f,u,t||o);else s(n.attrs)&&Mn(l,n.attrs),s(n.props)&&Mn(l,n.props);var d=new Pn(n,l,a,r,e),p=c.render.call(null,d._c,d);if(p instanceof ve)return In(p,n,d.parent,c);if(i(p)){for(var h=Xe(p)||[],v=new Array(h.length),m=0;m<h.length;m++)v[m]=In(h[m],n,d.parent,c);return v}}(e,p,t,n,r);var h=t.on;if(t.on=t.nativeOn,c(e.options.abstract)){var v=t.slot;t={},v&&(t.slot=v)}!function(e){for(var t=e.hook||(e.hook={}),n=0;n<Hn.length;n++){var r=Hn[n],o=t[r],i=Fn[r];o===i||o&&o._merged||(t[r]=o?Bn(i,o):i)}}(t);var g=Rn(e.options)||l;return new ve("vue-component-".concat(e.cid).concat(g?"-".concat(g):""),t,void 0,void 0,void 0,n,{Ctor:e,propsData:p,listeners:h,tag:l,children:r},d)}}}function Bn(e,t){var n=function(n,r){e(n,r),t(n,r)};return n._merged=!0,n}var Wn=P,Un=z.optionMergeStrategies;function zn(e,t,n){if(void 0===n&&(n=!0),!t)return e;for(var r,o,i,a=de?Reflect.ownKeys(t):Object.keys(t),s=0;s<a.length;s++)"__ob__"!==(r=a[s])&&(o=e[r],i=t[r],n&&T(e,r)?o!==i&&p(o)&&p(i)&&zn(o,i):Ie(e,r,i));return e}function Vn(e,t,n){return n?function(){var r=u(t)?t.call(n,n):t,o=u(e)?e.call(n,n):e;return r?zn(r,o):o}:t?e?function(){return zn(u(t)?t.call(this,this):t,u(e)?e.call(this,this):e)}:t:e}function Jn(e,t){var n=t?e?e.concat(t):i(t)?t:[t]:e;return n?function(e){for(var t=[],n=0;n<e.length;n++)-1===t.indexOf(e[n])&&t.push(e[n]);return t}(n):n}function Kn(e,t,n,r){var o=Object.create(e||null);return t?N(o,t):o}Un.data=function(e,t,n){return n?Vn(e,t,n):t&&"function"!=typeof t?e:Vn(e,t)},U.forEach((function(e){Un[e]=Jn})),W.forEach((function(e){Un[e+"s"]=Kn})),Un.watch=function(e,t,n,r){if(e===ie&&(e=void 0),t===ie&&(t=void 0),!t)return Object.create(e||null);if(!e)return t;var o={};for(var a in N(o,e),t){var s=o[a],c=t[a];s&&!i(s)&&(s=[s]),o[a]=s?s.concat(c):i(c)?c:[c]}return o},Un.props=Un.methods=Un.inject=Un.computed=function(e,t,n,r){if(!e)return t;var o=Object.create(null);return N(o,e),t&&N(o,t),o},Un.provide=function(e,t){return e?function(){var n=Object.create(null);return zn(n,u(e)?e.call(this):e),t&&zn(n,u(t)?t.call(this):t,!1),n}:t};var Xn=function(e,t){return void 0===t?e:t};function Qn(e,t,n){if(u(t)&&(t=t.options),function(e,t){var n=e.props;if(n){var r,o,a={};if(i(n))for(r=n.length;r--;)"string"==typeof(o=n[r])&&(a[E(o)]={type:null});else if(p(n))for(var s in n)o=n[s],a[E(s)]=p(o)?o:{type:o};e.props=a}}(t),function(e,t){var n=e.inject;if(n){var r=e.inject={};if(i(n))for(var o=0;o<n.length;o++)r[n[o]]={from:n[o]};else if(p(n))for(var a in n){var s=n[a];r[a]=p(s)?N({from:a},s):{from:s}}}}(t),function(e){var t=e.directives;if(t)for(var n in t){var r=t[n];u(r)&&(t[n]={bind:r,update:r})}}(t),!t._base&&(t.extends&&(e=Qn(e,t.extends,n)),t.mixins))for(var r=0,o=t.mixins.length;r<o;r++)e=Qn(e,t.mixins[r],n);var a,s={};for(a in e)c(a);for(a in t)T(e,a)||c(a);function c(r){var o=Un[r]||Xn;s[r]=o(e[r],t[r],n,r)}return s}function Gn(e,t,n,r){if("string"==typeof n){var o=e[t];if(T(o,n))return o[n];var i=E(n);if(T(o,i))return o[i];var a=$(i);return T(o,a)?o[a]:o[n]||o[i]||o[a]}}function Yn(e,t,n,r){var o=t[e],i=!T(n,e),a=n[e],s=nr(Boolean,o.type);if(s>-1)if(i&&!T(o,"default"))a=!1;else if(""===a||a===O(e)){var c=nr(String,o.type);(c<0||s<c)&&(a=!0)}if(void 0===a){a=function(e,t,n){if(!T(t,"default"))return;var r=t.default;0;if(e&&e.$options.propsData&&void 0===e.$options.propsData[n]&&void 0!==e._props[n])return e._props[n];return u(r)&&"Function"!==er(t.type)?r.call(e):r}(r,o,e);var l=Oe;je(!0),Le(a),je(l)}return a}var Zn=/^\s*function (\w+)/;function er(e){var t=e&&e.toString().match(Zn);return t?t[1]:""}function tr(e,t){return er(e)===er(t)}function nr(e,t){if(!i(t))return tr(t,e)?0:-1;for(var n=0,r=t.length;n<r;n++)if(tr(t[n],e))return n;return-1}function rr(e){this._init(e)}function or(e){e.cid=0;var t=1;e.extend=function(e){e=e||{};var n=this,r=n.cid,o=e._Ctor||(e._Ctor={});if(o[r])return o[r];var i=Rn(e)||Rn(n.options);var a=function(e){this._init(e)};return(a.prototype=Object.create(n.prototype)).constructor=a,a.cid=t++,a.options=Qn(n.options,e),a.super=n,a.options.props&&function(e){var t=e.options.props;for(var n in t)kn(e.prototype,"_props",n)}(a),a.options.computed&&function(e){var t=e.options.computed;for(var n in t)$n(e.prototype,n,t[n])}(a),a.extend=n.extend,a.mixin=n.mixin,a.use=n.use,W.forEach((function(e){a[e]=n[e]})),i&&(a.options.components[i]=a),a.superOptions=n.options,a.extendOptions=e,a.sealedOptions=N({},a.options),o[r]=a,a}}function ir(e){return e&&(Rn(e.Ctor.options)||e.tag)}function ar(e,t){return i(e)?e.indexOf(t)>-1:"string"==typeof e?e.split(",").indexOf(t)>-1:!!h(e)&&e.test(t)}function sr(e,t){var n=e.cache,r=e.keys,o=e._vnode;for(var i in n){var a=n[i];if(a){var s=a.name;s&&!t(s)&&cr(n,i,r,o)}}}function cr(e,t,n,r){var o=e[t];!o||r&&o.tag===r.tag||o.componentInstance.$destroy(),e[t]=null,w(n,t)}!function(e){e.prototype._init=function(e){var t=this;t._uid=Nn++,t._isVue=!0,t.__v_skip=!0,t._scope=new It(!0),t._scope._vm=!0,e&&e._isComponent?function(e,t){var n=e.$options=Object.create(e.constructor.options),r=t._parentVnode;n.parent=t.parent,n._parentVnode=r;var o=r.componentOptions;n.propsData=o.propsData,n._parentListeners=o.listeners,n._renderChildren=o.children,n._componentTag=o.tag,t.render&&(n.render=t.render,n.staticRenderFns=t.staticRenderFns)}(t,e):t.$options=Qn(Ln(t.constructor),e||{},t),t._renderProxy=t,t._self=t,function(e){var t=e.$options,n=t.parent;if(n&&!t.abstract){for(;n.$options.abstract&&n.$parent;)n=n.$parent;n.$children.push(e)}e.$parent=n,e.$root=n?n.$root:e,e.$children=[],e.$refs={},e._provided=n?n._provided:Object.create(null),e._watcher=null,e._inactive=null,e._directInactive=!1,e._isMounted=!1,e._isDestroyed=!1,e._isBeingDestroyed=!1}(t),function(e){e._events=Object.create(null),e._hasHookEvent=!1;var t=e.$options._parentListeners;t&&Pt(e,t)}(t),function(e){e._vnode=null,e._staticTrees=null;var t=e.$options,n=e.$vnode=t._parentVnode,r=n&&n.context;e.$slots=gt(t._renderChildren,r),e.$scopedSlots=n?_t(e.$parent,n.data.scopedSlots,e.$slots):o,e._c=function(t,n,r,o){return et(e,t,n,r,o,!1)},e.$createElement=function(t,n,r,o){return et(e,t,n,r,o,!0)};var i=n&&n.data;Pe(e,"$attrs",i&&i.attrs||o,null,!0),Pe(e,"$listeners",t._parentListeners||o,null,!0)}(t),Bt(t,"beforeCreate",void 0,!1),function(e){var t=Dn(e.$options.inject,e);t&&(je(!1),Object.keys(t).forEach((function(n){Pe(e,n,t[n])})),je(!0))}(t),Sn(t),function(e){var t=e.$options.provide;if(t){var n=u(t)?t.call(e):t;if(!f(n))return;for(var r=nn(e),o=de?Reflect.ownKeys(n):Object.keys(n),i=0;i<o.length;i++){var a=o[i];Object.defineProperty(r,a,Object.getOwnPropertyDescriptor(n,a))}}}(t),Bt(t,"created"),t.$options.el&&t.$mount(t.$options.el)}}(rr),function(e){var t={get:function(){return this._data}},n={get:function(){return this._props}};Object.defineProperty(e.prototype,"$data",t),Object.defineProperty(e.prototype,"$props",n),e.prototype.$set=Ie,e.prototype.$delete=Me,e.prototype.$watch=function(e,t,n){var r=this;if(p(t))return jn(r,e,t,n);(n=n||{}).user=!0;var o=new Cn(r,e,t,n);if(n.immediate){var i='callback for immediate watcher "'.concat(o.expression,'"');Te(),on(t,r,[o.value],r,i),ke()}return function(){o.teardown()}}}(rr),function(e){var t=/^hook:/;e.prototype.$on=function(e,n){var r=this;if(i(e))for(var o=0,a=e.length;o<a;o++)r.$on(e[o],n);else(r._events[e]||(r._events[e]=[])).push(n),t.test(e)&&(r._hasHookEvent=!0);return r},e.prototype.$once=function(e,t){var n=this;function r(){n.$off(e,r),t.apply(n,arguments)}return r.fn=t,n.$on(e,r),n},e.prototype.$off=function(e,t){var n=this;if(!arguments.length)return n._events=Object.create(null),n;if(i(e)){for(var r=0,o=e.length;r<o;r++)n.$off(e[r],t);return n}var a,s=n._events[e];if(!s)return n;if(!t)return n._events[e]=null,n;for(var c=s.length;c--;)if((a=s[c])===t||a.fn===t){s.splice(c,1);break}return n},e.prototype.$emit=function(e){var t=this,n=t._events[e];if(n){n=n.length>1?D(n):n;for(var r=D(arguments,1),o='event handler for "'.concat(e,'"'),i=0,a=n.length;i<a;i++)on(n[i],t,r,t,o)}return t}}(rr),function(e){e.prototype._update=function(e,t){var n=this,r=n.$el,o=n._vnode,i=Rt(n);n._vnode=e,n.$el=o?n.__patch__(o,e):n.__patch__(n.$el,e,t,!1),i(),r&&(r.__vue__=null),n.$el&&(n.$el.__vue__=n);for(var a=n;a&&a.$vnode&&a.$parent&&a.$vnode===a.$parent._vnode;)a.$parent.$el=a.$el,a=a.$parent},e.prototype.$forceUpdate=function(){this._watcher&&this._watcher.update()},e.prototype.$destroy=function(){var e=this;if(!e._isBeingDestroyed){Bt(e,"beforeDestroy"),e._isBeingDestroyed=!0;var t=e.$parent;!t||t._isBeingDestroyed||e.$options.abstract||w(t.$children,e),e._scope.stop(),e._data.__ob__&&e._data.__ob__.vmCount--,e._isDestroyed=!0,e.__patch__(e._vnode,null),Bt(e,"destroyed"),e.$off(),e.$el&&(e.$el.__vue__=null),e.$vnode&&(e.$vnode.parent=null)}}}(rr),function(e){mt(e.prototype),e.prototype.$nextTick=function(e){return gn(e,this)},e.prototype._render=function(){var e,t=this,n=t.$options,r=n.render,o=n._parentVnode;o&&t._isMounted&&(t.$scopedSlots=_t(t.$parent,o.data.scopedSlots,t.$slots,t.$scopedSlots),t._slotsProxy&&St(t._slotsProxy,t.$scopedSlots)),t.$vnode=o;try{he(t),At=t,e=r.call(t._renderProxy,t.$createElement)}catch(n){rn(n,t,"render"),e=t._vnode}finally{At=null,he()}return i(e)&&1===e.length&&(e=e[0]),e instanceof ve||(e=me()),e.parent=o,e}}(rr);var lr=[String,RegExp,Array],ur={name:"keep-alive",abstract:!0,props:{include:lr,exclude:lr,max:[String,Number]},methods:{cacheVNode:function(){var e=this,t=e.cache,n=e.keys,r=e.vnodeToCache,o=e.keyToCache;if(r){var i=r.tag,a=r.componentInstance,s=r.componentOptions;t[o]={name:ir(s),tag:i,componentInstance:a},n.push(o),this.max&&n.length>parseInt(this.max)&&cr(t,n[0],n,this._vnode),this.vnodeToCache=null}}},created:function(){this.cache=Object.create(null),this.keys=[]},destroyed:function(){for(var e in this.cache)cr(this.cache,e,this.keys)},mounted:function(){var e=this;this.cacheVNode(),this.$watch("include",(function(t){sr(e,(function(e){return ar(t,e)}))})),this.$watch("exclude",(function(t){sr(e,(function(e){return!ar(t,e)}))}))},updated:function(){this.cacheVNode()},render:function(){var e=this.$slots.default,t=jt(e),n=t&&t.componentOptions;if(n){var r=ir(n),o=this.include,i=this.exclude;if(o&&(!r||!ar(o,r))||i&&r&&ar(i,r))return t;var a=this.cache,s=this.keys,c=null==t.key?n.Ctor.cid+(n.tag?"::".concat(n.tag):""):t.key;a[c]?(t.componentInstance=a[c].componentInstance,w(s,c),s.push(c)):(this.vnodeToCache=t,this.keyToCache=c),t.data.keepAlive=!0}return t||e&&e[0]}},fr={KeepAlive:ur};!function(e){var t={get:function(){return z}};Object.defineProperty(e,"config",t),e.util={warn:Wn,extend:N,mergeOptions:Qn,defineReactive:Pe},e.set=Ie,e.delete=Me,e.nextTick=gn,e.observable=function(e){return Le(e),e},e.options=Object.create(null),W.forEach((function(t){e.options[t+"s"]=Object.create(null)})),e.options._base=e,N(e.options.components,fr),function(e){e.use=function(e){var t=this._installedPlugins||(this._installedPlugins=[]);if(t.indexOf(e)>-1)return this;var n=D(arguments,1);return n.unshift(this),u(e.install)?e.install.apply(e,n):u(e)&&e.apply(null,n),t.push(e),this}}(e),function(e){e.mixin=function(e){return this.options=Qn(this.options,e),this}}(e),or(e),function(e){W.forEach((function(t){e[t]=function(e,n){return n?("component"===t&&p(n)&&(n.name=n.name||e,n=this.options._base.extend(n)),"directive"===t&&u(n)&&(n={bind:n,update:n}),this.options[t+"s"][e]=n,n):this.options[t+"s"][e]}}))}(e)}(rr),Object.defineProperty(rr.prototype,"$isServer",{get:ce}),Object.defineProperty(rr.prototype,"$ssrContext",{get:function(){return this.$vnode&&this.$vnode.ssrContext}}),Object.defineProperty(rr,"FunctionalRenderContext",{value:Pn}),rr.version="2.7.15";var dr=b("style,class"),pr=b("input,textarea,option,select,progress"),hr=function(e,t,n){return"value"===n&&pr(e)&&"button"!==t||"selected"===n&&"option"===e||"checked"===n&&"input"===e||"muted"===n&&"video"===e},vr=b("contenteditable,draggable,spellcheck"),mr=b("events,caret,typing,plaintext-only"),gr=function(e,t){return wr(t)||"false"===t?"false":"contenteditable"===e&&mr(t)?t:"true"},yr=b("allowfullscreen,async,autofocus,autoplay,checked,compact,controls,declare,default,defaultchecked,defaultmuted,defaultselected,defer,disabled,enabled,formnovalidate,hidden,indeterminate,inert,ismap,itemscope,loop,multiple,muted,nohref,noresize,noshade,novalidate,nowrap,open,pauseonexit,readonly,required,reversed,scoped,seamless,selected,sortable,truespeed,typemustmatch,visible"),br="http://www.w3.org/1999/xlink",_r=function(e){return":"===e.charAt(5)&&"xlink"===e.slice(0,5)},xr=function(e){return _r(e)?e.slice(6,e.length):""},wr=function(e){return null==e||!1===e};function Cr(e){for(var t=e.data,n=e,r=e;s(r.componentInstance);)(r=r.componentInstance._vnode)&&r.data&&(t=Tr(r.data,t));for(;s(n=n.parent);)n&&n.data&&(t=Tr(t,n.data));return function(e,t){if(s(e)||s(t))return kr(e,Sr(t));return""}(t.staticClass,t.class)}function Tr(e,t){return{staticClass:kr(e.staticClass,t.staticClass),class:s(e.class)?[e.class,t.class]:t.class}}function kr(e,t){return e?t?e+" "+t:e:t||""}function Sr(e){return Array.isArray(e)?function(e){for(var t,n="",r=0,o=e.length;r<o;r++)s(t=Sr(e[r]))&&""!==t&&(n&&(n+=" "),n+=t);return n}(e):f(e)?function(e){var t="";for(var n in e)e[n]&&(t&&(t+=" "),t+=n);return t}(e):"string"==typeof e?e:""}var Er={svg:"http://www.w3.org/2000/svg",math:"http://www.w3.org/1998/Math/MathML"},$r=b("html,body,base,head,link,meta,style,title,address,article,aside,footer,header,h1,h2,h3,h4,h5,h6,hgroup,nav,section,div,dd,dl,dt,figcaption,figure,picture,hr,img,li,main,ol,p,pre,ul,a,b,abbr,bdi,bdo,br,cite,code,data,dfn,em,i,kbd,mark,q,rp,rt,rtc,ruby,s,samp,small,span,strong,sub,sup,time,u,var,wbr,area,audio,map,track,video,embed,object,param,source,canvas,script,noscript,del,ins,caption,col,colgroup,table,thead,tbody,td,th,tr,button,datalist,fieldset,form,input,label,legend,meter,optgroup,option,output,progress,select,textarea,details,dialog,menu,menuitem,summary,content,element,shadow,template,blockquote,iframe,tfoot"),Ar=b("svg,animate,circle,clippath,cursor,defs,desc,ellipse,filter,font-face,foreignobject,g,glyph,image,line,marker,mask,missing-glyph,path,pattern,polygon,polyline,rect,switch,symbol,text,textpath,tspan,use,view",!0),Or=function(e){return $r(e)||Ar(e)};function jr(e){return Ar(e)?"svg":"math"===e?"math":void 0}var Dr=Object.create(null);var Nr=b("text,number,password,search,email,tel,url");function Lr(e){if("string"==typeof e){var t=document.querySelector(e);return t||document.createElement("div")}return e}var Pr=Object.freeze({__proto__:null,createElement:function(e,t){var n=document.createElement(e);return"select"!==e||t.data&&t.data.attrs&&void 0!==t.data.attrs.multiple&&n.setAttribute("multiple","multiple"),n},createElementNS:function(e,t){return document.createElementNS(Er[e],t)},createTextNode:function(e){return document.createTextNode(e)},createComment:function(e){return document.createComment(e)},insertBefore:function(e,t,n){e.insertBefore(t,n)},removeChild:function(e,t){e.removeChild(t)},appendChild:function(e,t){e.appendChild(t)},parentNode:function(e){return e.parentNode},nextSibling:function(e){return e.nextSibling},tagName:function(e){return e.tagName},setTextContent:function(e,t){e.textContent=t},setStyleScope:function(e,t){e.setAttribute(t,"")}}),Ir={create:function(e,t){Mr(t)},update:function(e,t){e.data.ref!==t.data.ref&&(Mr(e,!0),Mr(t))},destroy:function(e){Mr(e,!0)}};function Mr(e,t){var n=e.data.ref;if(s(n)){var r=e.context,o=e.componentInstance||e.elm,a=t?null:o,c=t?void 0:o;if(u(n))on(n,r,[a],r,"template ref function");else{var l=e.data.refInFor,f="string"==typeof n||"number"==typeof n,d=Be(n),p=r.$refs;if(f||d)if(l){var h=f?p[n]:n.value;t?i(h)&&w(h,o):i(h)?h.includes(o)||h.push(o):f?(p[n]=[o],Rr(r,n,p[n])):n.value=[o]}else if(f){if(t&&p[n]!==o)return;p[n]=c,Rr(r,n,a)}else if(d){if(t&&n.value!==o)return;n.value=a}else 0}}}function Rr(e,t,n){var r=e._setupState;r&&T(r,t)&&(Be(r[t])?r[t].value=n:r[t]=n)}var Fr=new ve("",{},[]),Hr=["create","activate","update","remove","destroy"];function qr(e,t){return e.key===t.key&&e.asyncFactory===t.asyncFactory&&(e.tag===t.tag&&e.isComment===t.isComment&&s(e.data)===s(t.data)&&function(e,t){if("input"!==e.tag)return!0;var n,r=s(n=e.data)&&s(n=n.attrs)&&n.type,o=s(n=t.data)&&s(n=n.attrs)&&n.type;return r===o||Nr(r)&&Nr(o)}(e,t)||c(e.isAsyncPlaceholder)&&a(t.asyncFactory.error))}function Br(e,t,n){var r,o,i={};for(r=t;r<=n;++r)s(o=e[r].key)&&(i[o]=r);return i}var Wr={create:Ur,update:Ur,destroy:function(e){Ur(e,Fr)}};function Ur(e,t){(e.data.directives||t.data.directives)&&function(e,t){var n,r,o,i=e===Fr,a=t===Fr,s=Vr(e.data.directives,e.context),c=Vr(t.data.directives,t.context),l=[],u=[];for(n in c)r=s[n],o=c[n],r?(o.oldValue=r.value,o.oldArg=r.arg,Kr(o,"update",t,e),o.def&&o.def.componentUpdated&&u.push(o)):(Kr(o,"bind",t,e),o.def&&o.def.inserted&&l.push(o));if(l.length){var f=function(){for(var n=0;n<l.length;n++)Kr(l[n],"inserted",t,e)};i?Je(t,"insert",f):f()}u.length&&Je(t,"postpatch",(function(){for(var n=0;n<u.length;n++)Kr(u[n],"componentUpdated",t,e)}));if(!i)for(n in s)c[n]||Kr(s[n],"unbind",e,e,a)}(e,t)}var zr=Object.create(null);function Vr(e,t){var n,r,o=Object.create(null);if(!e)return o;for(n=0;n<e.length;n++){if((r=e[n]).modifiers||(r.modifiers=zr),o[Jr(r)]=r,t._setupState&&t._setupState.__sfc){var i=r.def||Gn(t,"_setupState","v-"+r.name);r.def="function"==typeof i?{bind:i,update:i}:i}r.def=r.def||Gn(t.$options,"directives",r.name)}return o}function Jr(e){return e.rawName||"".concat(e.name,".").concat(Object.keys(e.modifiers||{}).join("."))}function Kr(e,t,n,r,o){var i=e.def&&e.def[t];if(i)try{i(n.elm,e,n,r,o)}catch(r){rn(r,n.context,"directive ".concat(e.name," ").concat(t," hook"))}}var Xr=[Ir,Wr];function Qr(e,t){var n=t.componentOptions;if(!(s(n)&&!1===n.Ctor.options.inheritAttrs||a(e.data.attrs)&&a(t.data.attrs))){var r,o,i=t.elm,l=e.data.attrs||{},u=t.data.attrs||{};for(r in(s(u.__ob__)||c(u._v_attr_proxy))&&(u=t.data.attrs=N({},u)),u)o=u[r],l[r]!==o&&Gr(i,r,o,t.data.pre);for(r in(Z||te)&&u.value!==l.value&&Gr(i,"value",u.value),l)a(u[r])&&(_r(r)?i.removeAttributeNS(br,xr(r)):vr(r)||i.removeAttribute(r))}}function Gr(e,t,n,r){r||e.tagName.indexOf("-")>-1?Yr(e,t,n):yr(t)?wr(n)?e.removeAttribute(t):(n="allowfullscreen"===t&&"EMBED"===e.tagName?"true":t,e.setAttribute(t,n)):vr(t)?e.setAttribute(t,gr(t,n)):_r(t)?wr(n)?e.removeAttributeNS(br,xr(t)):e.setAttributeNS(br,t,n):Yr(e,t,n)}function Yr(e,t,n){if(wr(n))e.removeAttribute(t);else{if(Z&&!ee&&"TEXTAREA"===e.tagName&&"placeholder"===t&&""!==n&&!e.__ieph){e.addEventListener("input",(function t(n){n.stopImmediatePropagation(),e.removeEventListener("input",t)})),e.__ieph=!0}e.setAttribute(t,n)}}var Zr={create:Qr,update:Qr};function eo(e,t){var n=t.elm,r=t.data,o=e.data;if(!(a(r.staticClass)&&a(r.class)&&(a(o)||a(o.staticClass)&&a(o.class)))){var i=Cr(t),c=n._transitionClasses;s(c)&&(i=kr(i,Sr(c))),i!==n._prevClass&&(n.setAttribute("class",i),n._prevClass=i)}}var to,no,ro,oo,io,ao,so={create:eo,update:eo},co=/[\w).+\-_$\]]/;function lo(e){var t,n,r,o,i,a=!1,s=!1,c=!1,l=!1,u=0,f=0,d=0,p=0;for(r=0;r<e.length;r++)if(n=t,t=e.charCodeAt(r),a)39===t&&92!==n&&(a=!1);else if(s)34===t&&92!==n&&(s=!1);else if(c)96===t&&92!==n&&(c=!1);else if(l)47===t&&92!==n&&(l=!1);else if(124!==t||124===e.charCodeAt(r+1)||124===e.charCodeAt(r-1)||u||f||d){switch(t){case 34:s=!0;break;case 39:a=!0;break;case 96:c=!0;break;case 40:d++;break;case 41:d--;break;case 91:f++;break;case 93:f--;break;case 123:u++;break;case 125:u--}if(47===t){for(var h=r-1,v=void 0;h>=0&&" "===(v=e.charAt(h));h--);v&&co.test(v)||(l=!0)}}else void 0===o?(p=r+1,o=e.slice(0,r).trim()):m();function m(){(i||(i=[])).push(e.slice(p,r).trim()),p=r+1}if(void 0===o?o=e.slice(0,r).trim():0!==p&&m(),i)for(r=0;r<i.length;r++)o=uo(o,i[r]);return o}function uo(e,t){var n=t.indexOf("(");if(n<0)return'_f("'.concat(t,'")(').concat(e,")");var r=t.slice(0,n),o=t.slice(n+1);return'_f("'.concat(r,'")(').concat(e).concat(")"!==o?","+o:o)}function fo(e,t){console.error("[Vue compiler]: ".concat(e))}function po(e,t){return e?e.map((function(e){return e[t]})).filter((function(e){return e})):[]}function ho(e,t,n,r,o){(e.props||(e.props=[])).push(Co({name:t,value:n,dynamic:o},r)),e.plain=!1}function vo(e,t,n,r,o){(o?e.dynamicAttrs||(e.dynamicAttrs=[]):e.attrs||(e.attrs=[])).push(Co({name:t,value:n,dynamic:o},r)),e.plain=!1}function mo(e,t,n,r){e.attrsMap[t]=n,e.attrsList.push(Co({name:t,value:n},r))}function go(e,t,n,r,o,i,a,s){(e.directives||(e.directives=[])).push(Co({name:t,rawName:n,value:r,arg:o,isDynamicArg:i,modifiers:a},s)),e.plain=!1}function yo(e,t,n){return n?"_p(".concat(t,',"').concat(e,'")'):e+t}function bo(e,t,n,r,i,a,s,c){var l;(r=r||o).right?c?t="(".concat(t,")==='click'?'contextmenu':(").concat(t,")"):"click"===t&&(t="contextmenu",delete r.right):r.middle&&(c?t="(".concat(t,")==='click'?'mouseup':(").concat(t,")"):"click"===t&&(t="mouseup")),r.capture&&(delete r.capture,t=yo("!",t,c)),r.once&&(delete r.once,t=yo("~",t,c)),r.passive&&(delete r.passive,t=yo("&",t,c)),r.native?(delete r.native,l=e.nativeEvents||(e.nativeEvents={})):l=e.events||(e.events={});var u=Co({value:n.trim(),dynamic:c},s);r!==o&&(u.modifiers=r);var f=l[t];Array.isArray(f)?i?f.unshift(u):f.push(u):l[t]=f?i?[u,f]:[f,u]:u,e.plain=!1}function _o(e,t,n){var r=xo(e,":"+t)||xo(e,"v-bind:"+t);if(null!=r)return lo(r);if(!1!==n){var o=xo(e,t);if(null!=o)return JSON.stringify(o)}}function xo(e,t,n){var r;if(null!=(r=e.attrsMap[t]))for(var o=e.attrsList,i=0,a=o.length;i<a;i++)if(o[i].name===t){o.splice(i,1);break}return n&&delete e.attrsMap[t],r}function wo(e,t){for(var n=e.attrsList,r=0,o=n.length;r<o;r++){var i=n[r];if(t.test(i.name))return n.splice(r,1),i}}function Co(e,t){return t&&(null!=t.start&&(e.start=t.start),null!=t.end&&(e.end=t.end)),e}function To(e,t,n){var r=n||{},o=r.number,i="$$v",a=i;r.trim&&(a="(typeof ".concat(i," === 'string'")+"? ".concat(i,".trim()")+": ".concat(i,")")),o&&(a="_n(".concat(a,")"));var s=ko(t,a);e.model={value:"(".concat(t,")"),expression:JSON.stringify(t),callback:"function (".concat(i,") {").concat(s,"}")}}function ko(e,t){var n=function(e){if(e=e.trim(),to=e.length,e.indexOf("[")<0||e.lastIndexOf("]")<to-1)return(oo=e.lastIndexOf("."))>-1?{exp:e.slice(0,oo),key:'"'+e.slice(oo+1)+'"'}:{exp:e,key:null};no=e,oo=io=ao=0;for(;!Eo();)$o(ro=So())?Oo(ro):91===ro&&Ao(ro);return{exp:e.slice(0,io),key:e.slice(io+1,ao)}}(e);return null===n.key?"".concat(e,"=").concat(t):"$set(".concat(n.exp,", ").concat(n.key,", ").concat(t,")")}function So(){return no.charCodeAt(++oo)}function Eo(){return oo>=to}function $o(e){return 34===e||39===e}function Ao(e){var t=1;for(io=oo;!Eo();)if($o(e=So()))Oo(e);else if(91===e&&t++,93===e&&t--,0===t){ao=oo;break}}function Oo(e){for(var t=e;!Eo()&&(e=So())!==t;);}var jo,Do="__r",No="__c";function Lo(e,t,n){var r=jo;return function o(){null!==t.apply(null,arguments)&&Mo(e,o,n,r)}}var Po=ln&&!(oe&&Number(oe[1])<=53);function Io(e,t,n,r){if(Po){var o=Xt,i=t;t=i._wrapper=function(e){if(e.target===e.currentTarget||e.timeStamp>=o||e.timeStamp<=0||e.target.ownerDocument!==document)return i.apply(this,arguments)}}jo.addEventListener(e,t,ae?{capture:n,passive:r}:n)}function Mo(e,t,n,r){(r||jo).removeEventListener(e,t._wrapper||t,n)}function Ro(e,t){if(!a(e.data.on)||!a(t.data.on)){var n=t.data.on||{},r=e.data.on||{};jo=t.elm||e.elm,function(e){if(s(e[Do])){var t=Z?"change":"input";e[t]=[].concat(e[Do],e[t]||[]),delete e[Do]}s(e[No])&&(e.change=[].concat(e[No],e.change||[]),delete e[No])}(n),Ve(n,r,Io,Mo,Lo,t.context),jo=void 0}}var Fo,Ho={create:Ro,update:Ro,destroy:function(e){return Ro(e,Fr)}};function qo(e,t){if(!a(e.data.domProps)||!a(t.data.domProps)){var n,r,o=t.elm,i=e.data.domProps||{},l=t.data.domProps||{};for(n in(s(l.__ob__)||c(l._v_attr_proxy))&&(l=t.data.domProps=N({},l)),i)n in l||(o[n]="");for(n in l){if(r=l[n],"textContent"===n||"innerHTML"===n){if(t.children&&(t.children.length=0),r===i[n])continue;1===o.childNodes.length&&o.removeChild(o.childNodes[0])}if("value"===n&&"PROGRESS"!==o.tagName){o._value=r;var u=a(r)?"":String(r);Bo(o,u)&&(o.value=u)}else if("innerHTML"===n&&Ar(o.tagName)&&a(o.innerHTML)){(Fo=Fo||document.createElement("div")).innerHTML="<svg>".concat(r,"</svg>");for(var f=Fo.firstChild;o.firstChild;)o.removeChild(o.firstChild);for(;f.firstChild;)o.appendChild(f.firstChild)}else if(r!==i[n])try{o[n]=r}catch(e){}}}}function Bo(e,t){return!e.composing&&("OPTION"===e.tagName||function(e,t){var n=!0;try{n=document.activeElement!==e}catch(e){}return n&&e.value!==t}(e,t)||function(e,t){var n=e.value,r=e._vModifiers;if(s(r)){if(r.number)return y(n)!==y(t);if(r.trim)return n.trim()!==t.trim()}return n!==t}(e,t))}var Wo={create:qo,update:qo},Uo=k((function(e){var t={},n=/:(.+)/;return e.split(/;(?![^(]*\))/g).forEach((function(e){if(e){var r=e.split(n);r.length>1&&(t[r[0].trim()]=r[1].trim())}})),t}));function zo(e){var t=Vo(e.style);return e.staticStyle?N(e.staticStyle,t):t}function Vo(e){return Array.isArray(e)?L(e):"string"==typeof e?Uo(e):e}var Jo,Ko=/^--/,Xo=/\s*!important$/,Qo=function(e,t,n){if(Ko.test(t))e.style.setProperty(t,n);else if(Xo.test(n))e.style.setProperty(O(t),n.replace(Xo,""),"important");else{var r=Yo(t);if(Array.isArray(n))for(var o=0,i=n.length;o<i;o++)e.style[r]=n[o];else e.style[r]=n}},Go=["Webkit","Moz","ms"],Yo=k((function(e){if(Jo=Jo||document.createElement("div").style,"filter"!==(e=E(e))&&e in Jo)return e;for(var t=e.charAt(0).toUpperCase()+e.slice(1),n=0;n<Go.length;n++){var r=Go[n]+t;if(r in Jo)return r}}));function Zo(e,t){var n=t.data,r=e.data;if(!(a(n.staticStyle)&&a(n.style)&&a(r.staticStyle)&&a(r.style))){var o,i,c=t.elm,l=r.staticStyle,u=r.normalizedStyle||r.style||{},f=l||u,d=Vo(t.data.style)||{};t.data.normalizedStyle=s(d.__ob__)?N({},d):d;var p=function(e,t){var n,r={};if(t)for(var o=e;o.componentInstance;)(o=o.componentInstance._vnode)&&o.data&&(n=zo(o.data))&&N(r,n);(n=zo(e.data))&&N(r,n);for(var i=e;i=i.parent;)i.data&&(n=zo(i.data))&&N(r,n);return r}(t,!0);for(i in f)a(p[i])&&Qo(c,i,"");for(i in p)(o=p[i])!==f[i]&&Qo(c,i,null==o?"":o)}}var ei={create:Zo,update:Zo},ti=/\s+/;function ni(e,t){if(t&&(t=t.trim()))if(e.classList)t.indexOf(" ")>-1?t.split(ti).forEach((function(t){return e.classList.add(t)})):e.classList.add(t);else{var n=" ".concat(e.getAttribute("class")||""," ");n.indexOf(" "+t+" ")<0&&e.setAttribute("class",(n+t).trim())}}function ri(e,t){if(t&&(t=t.trim()))if(e.classList)t.indexOf(" ")>-1?t.split(ti).forEach((function(t){return e.classList.remove(t)})):e.classList.remove(t),e.classList.length||e.removeAttribute("class");else{for(var n=" ".concat(e.getAttribute("class")||""," "),r=" "+t+" ";n.indexOf(r)>=0;)n=n.replace(r," ");(n=n.trim())?e.setAttribute("class",n):e.removeAttribute("class")}}function oi(e){if(e){if("object"===t(e)){var n={};return!1!==e.css&&N(n,ii(e.name||"v")),N(n,e),n}return"string"==typeof e?ii(e):void 0}}var ii=k((function(e){return{enterClass:"".concat(e,"-enter"),enterToClass:"".concat(e,"-enter-to"),enterActiveClass:"".concat(e,"-enter-active"),leaveClass:"".concat(e,"-leave"),leaveToClass:"".concat(e,"-leave-to"),leaveActiveClass:"".concat(e,"-leave-active")}})),ai=G&&!ee,si="transition",ci="animation",li="transition",ui="transitionend",fi="animation",di="animationend";ai&&(void 0===window.ontransitionend&&void 0!==window.onwebkittransitionend&&(li="WebkitTransition",ui="webkitTransitionEnd"),void 0===window.onanimationend&&void 0!==window.onwebkitanimationend&&(fi="WebkitAnimation",di="webkitAnimationEnd"));var pi=G?window.requestAnimationFrame?window.requestAnimationFrame.bind(window):setTimeout:function(e){return e()};function hi(e){pi((function(){pi(e)}))}function vi(e,t){var n=e._transitionClasses||(e._transitionClasses=[]);n.indexOf(t)<0&&(n.push(t),ni(e,t))}function mi(e,t){e._transitionClasses&&w(e._transitionClasses,t),ri(e,t)}function gi(e,t,n){var r=bi(e,t),o=r.type,i=r.timeout,a=r.propCount;if(!o)return n();var s=o===si?ui:di,c=0,l=function(){e.removeEventListener(s,u),n()},u=function(t){t.target===e&&++c>=a&&l()};setTimeout((function(){c<a&&l()}),i+1),e.addEventListener(s,u)}var yi=/\b(transform|all)(,|$)/;function bi(e,t){var n,r=window.getComputedStyle(e),o=(r[li+"Delay"]||"").split(", "),i=(r[li+"Duration"]||"").split(", "),a=_i(o,i),s=(r[fi+"Delay"]||"").split(", "),c=(r[fi+"Duration"]||"").split(", "),l=_i(s,c),u=0,f=0;return t===si?a>0&&(n=si,u=a,f=i.length):t===ci?l>0&&(n=ci,u=l,f=c.length):f=(n=(u=Math.max(a,l))>0?a>l?si:ci:null)?n===si?i.length:c.length:0,{type:n,timeout:u,propCount:f,hasTransform:n===si&&yi.test(r[li+"Property"])}}function _i(e,t){for(;e.length<t.length;)e=e.concat(e);return Math.max.apply(null,t.map((function(t,n){return xi(t)+xi(e[n])})))}function xi(e){return 1e3*Number(e.slice(0,-1).replace(",","."))}function wi(e,t){var n=e.elm;s(n._leaveCb)&&(n._leaveCb.cancelled=!0,n._leaveCb());var r=oi(e.data.transition);if(!a(r)&&!s(n._enterCb)&&1===n.nodeType){for(var o=r.css,i=r.type,c=r.enterClass,l=r.enterToClass,d=r.enterActiveClass,p=r.appearClass,h=r.appearToClass,v=r.appearActiveClass,m=r.beforeEnter,g=r.enter,b=r.afterEnter,_=r.enterCancelled,x=r.beforeAppear,w=r.appear,C=r.afterAppear,T=r.appearCancelled,k=r.duration,S=Mt,E=Mt.$vnode;E&&E.parent;)S=E.context,E=E.parent;var $=!S._isMounted||!e.isRootInsert;if(!$||w||""===w){var A=$&&p?p:c,O=$&&v?v:d,j=$&&h?h:l,D=$&&x||m,N=$&&u(w)?w:g,L=$&&C||b,P=$&&T||_,I=y(f(k)?k.enter:k);0;var M=!1!==o&&!ee,R=ki(N),F=n._enterCb=H((function(){M&&(mi(n,j),mi(n,O)),F.cancelled?(M&&mi(n,A),P&&P(n)):L&&L(n),n._enterCb=null}));e.data.show||Je(e,"insert",(function(){var t=n.parentNode,r=t&&t._pending&&t._pending[e.key];r&&r.tag===e.tag&&r.elm._leaveCb&&r.elm._leaveCb(),N&&N(n,F)})),D&&D(n),M&&(vi(n,A),vi(n,O),hi((function(){mi(n,A),F.cancelled||(vi(n,j),R||(Ti(I)?setTimeout(F,I):gi(n,i,F)))}))),e.data.show&&(t&&t(),N&&N(n,F)),M||R||F()}}}function Ci(e,t){var n=e.elm;s(n._enterCb)&&(n._enterCb.cancelled=!0,n._enterCb());var r=oi(e.data.transition);if(a(r)||1!==n.nodeType)return t();if(!s(n._leaveCb)){var o=r.css,i=r.type,c=r.leaveClass,l=r.leaveToClass,u=r.leaveActiveClass,d=r.beforeLeave,p=r.leave,h=r.afterLeave,v=r.leaveCancelled,m=r.delayLeave,g=r.duration,b=!1!==o&&!ee,_=ki(p),x=y(f(g)?g.leave:g);0;var w=n._leaveCb=H((function(){n.parentNode&&n.parentNode._pending&&(n.parentNode._pending[e.key]=null),b&&(mi(n,l),mi(n,u)),w.cancelled?(b&&mi(n,c),v&&v(n)):(t(),h&&h(n)),n._leaveCb=null}));m?m(C):C()}function C(){w.cancelled||(!e.data.show&&n.parentNode&&((n.parentNode._pending||(n.parentNode._pending={}))[e.key]=e),d&&d(n),b&&(vi(n,c),vi(n,u),hi((function(){mi(n,c),w.cancelled||(vi(n,l),_||(Ti(x)?setTimeout(w,x):gi(n,i,w)))}))),p&&p(n,w),b||_||w())}}function Ti(e){return"number"==typeof e&&!isNaN(e)}function ki(e){if(a(e))return!1;var t=e.fns;return s(t)?ki(Array.isArray(t)?t[0]:t):(e._length||e.length)>1}function Si(e,t){!0!==t.data.show&&wi(t)}var Ei=function(e){var t,n,r={},o=e.modules,u=e.nodeOps;for(t=0;t<Hr.length;++t)for(r[Hr[t]]=[],n=0;n<o.length;++n)s(o[n][Hr[t]])&&r[Hr[t]].push(o[n][Hr[t]]);function f(e){var t=u.parentNode(e);s(t)&&u.removeChild(t,e)}function d(e,t,n,o,i,a,l){if(s(e.elm)&&s(a)&&(e=a[l]=ye(e)),e.isRootInsert=!i,!function(e,t,n,o){var i=e.data;if(s(i)){var a=s(e.componentInstance)&&i.keepAlive;if(s(i=i.hook)&&s(i=i.init)&&i(e,!1),s(e.componentInstance))return p(e,t),h(n,e.elm,o),c(a)&&function(e,t,n,o){var i,a=e;for(;a.componentInstance;)if(s(i=(a=a.componentInstance._vnode).data)&&s(i=i.transition)){for(i=0;i<r.activate.length;++i)r.activate[i](Fr,a);t.push(a);break}h(n,e.elm,o)}(e,t,n,o),!0}}(e,t,n,o)){var f=e.data,d=e.children,m=e.tag;s(m)?(e.elm=e.ns?u.createElementNS(e.ns,m):u.createElement(m,e),y(e),v(e,d,t),s(f)&&g(e,t),h(n,e.elm,o)):c(e.isComment)?(e.elm=u.createComment(e.text),h(n,e.elm,o)):(e.elm=u.createTextNode(e.text),h(n,e.elm,o))}}function p(e,t){s(e.data.pendingInsert)&&(t.push.apply(t,e.data.pendingInsert),e.data.pendingInsert=null),e.elm=e.componentInstance.$el,m(e)?(g(e,t),y(e)):(Mr(e),t.push(e))}function h(e,t,n){s(e)&&(s(n)?u.parentNode(n)===e&&u.insertBefore(e,t,n):u.appendChild(e,t))}function v(e,t,n){if(i(t)){0;for(var r=0;r<t.length;++r)d(t[r],n,e.elm,null,!0,t,r)}else l(e.text)&&u.appendChild(e.elm,u.createTextNode(String(e.text)))}function m(e){for(;e.componentInstance;)e=e.componentInstance._vnode;return s(e.tag)}function g(e,n){for(var o=0;o<r.create.length;++o)r.create[o](Fr,e);s(t=e.data.hook)&&(s(t.create)&&t.create(Fr,e),s(t.insert)&&n.push(e))}function y(e){var t;if(s(t=e.fnScopeId))u.setStyleScope(e.elm,t);else for(var n=e;n;)s(t=n.context)&&s(t=t.$options._scopeId)&&u.setStyleScope(e.elm,t),n=n.parent;s(t=Mt)&&t!==e.context&&t!==e.fnContext&&s(t=t.$options._scopeId)&&u.setStyleScope(e.elm,t)}function _(e,t,n,r,o,i){for(;r<=o;++r)d(n[r],i,e,t,!1,n,r)}function x(e){var t,n,o=e.data;if(s(o))for(s(t=o.hook)&&s(t=t.destroy)&&t(e),t=0;t<r.destroy.length;++t)r.destroy[t](e);if(s(t=e.children))for(n=0;n<e.children.length;++n)x(e.children[n])}function w(e,t,n){for(;t<=n;++t){var r=e[t];s(r)&&(s(r.tag)?(C(r),x(r)):f(r.elm))}}function C(e,t){if(s(t)||s(e.data)){var n,o=r.remove.length+1;for(s(t)?t.listeners+=o:t=function(e,t){function n(){0==--n.listeners&&f(e)}return n.listeners=t,n}(e.elm,o),s(n=e.componentInstance)&&s(n=n._vnode)&&s(n.data)&&C(n,t),n=0;n<r.remove.length;++n)r.remove[n](e,t);s(n=e.data.hook)&&s(n=n.remove)?n(e,t):t()}else f(e.elm)}function T(e,t,n,r){for(var o=n;o<r;o++){var i=t[o];if(s(i)&&qr(e,i))return o}}function k(e,t,n,o,i,l){if(e!==t){s(t.elm)&&s(o)&&(t=o[i]=ye(t));var f=t.elm=e.elm;if(c(e.isAsyncPlaceholder))s(t.asyncFactory.resolved)?$(e.elm,t,n):t.isAsyncPlaceholder=!0;else if(c(t.isStatic)&&c(e.isStatic)&&t.key===e.key&&(c(t.isCloned)||c(t.isOnce)))t.componentInstance=e.componentInstance;else{var p,h=t.data;s(h)&&s(p=h.hook)&&s(p=p.prepatch)&&p(e,t);var v=e.children,g=t.children;if(s(h)&&m(t)){for(p=0;p<r.update.length;++p)r.update[p](e,t);s(p=h.hook)&&s(p=p.update)&&p(e,t)}a(t.text)?s(v)&&s(g)?v!==g&&function(e,t,n,r,o){var i,c,l,f=0,p=0,h=t.length-1,v=t[0],m=t[h],g=n.length-1,y=n[0],b=n[g],x=!o;for(;f<=h&&p<=g;)a(v)?v=t[++f]:a(m)?m=t[--h]:qr(v,y)?(k(v,y,r,n,p),v=t[++f],y=n[++p]):qr(m,b)?(k(m,b,r,n,g),m=t[--h],b=n[--g]):qr(v,b)?(k(v,b,r,n,g),x&&u.insertBefore(e,v.elm,u.nextSibling(m.elm)),v=t[++f],b=n[--g]):qr(m,y)?(k(m,y,r,n,p),x&&u.insertBefore(e,m.elm,v.elm),m=t[--h],y=n[++p]):(a(i)&&(i=Br(t,f,h)),a(c=s(y.key)?i[y.key]:T(y,t,f,h))?d(y,r,e,v.elm,!1,n,p):qr(l=t[c],y)?(k(l,y,r,n,p),t[c]=void 0,x&&u.insertBefore(e,l.elm,v.elm)):d(y,r,e,v.elm,!1,n,p),y=n[++p]);f>h?_(e,a(n[g+1])?null:n[g+1].elm,n,p,g,r):p>g&&w(t,f,h)}(f,v,g,n,l):s(g)?(s(e.text)&&u.setTextContent(f,""),_(f,null,g,0,g.length-1,n)):s(v)?w(v,0,v.length-1):s(e.text)&&u.setTextContent(f,""):e.text!==t.text&&u.setTextContent(f,t.text),s(h)&&s(p=h.hook)&&s(p=p.postpatch)&&p(e,t)}}}function S(e,t,n){if(c(n)&&s(e.parent))e.parent.data.pendingInsert=t;else for(var r=0;r<t.length;++r)t[r].data.hook.insert(t[r])}var E=b("attrs,class,staticClass,staticStyle,key");function $(e,t,n,r){var o,i=t.tag,a=t.data,l=t.children;if(r=r||a&&a.pre,t.elm=e,c(t.isComment)&&s(t.asyncFactory))return t.isAsyncPlaceholder=!0,!0;if(s(a)&&(s(o=a.hook)&&s(o=o.init)&&o(t,!0),s(o=t.componentInstance)))return p(t,n),!0;if(s(i)){if(s(l))if(e.hasChildNodes())if(s(o=a)&&s(o=o.domProps)&&s(o=o.innerHTML)){if(o!==e.innerHTML)return!1}else{for(var u=!0,f=e.firstChild,d=0;d<l.length;d++){if(!f||!$(f,l[d],n,r)){u=!1;break}f=f.nextSibling}if(!u||f)return!1}else v(t,l,n);if(s(a)){var h=!1;for(var m in a)if(!E(m)){h=!0,g(t,n);break}!h&&a.class&&_n(a.class)}}else e.data!==t.text&&(e.data=t.text);return!0}return function(e,t,n,o){if(!a(t)){var i,l=!1,f=[];if(a(e))l=!0,d(t,f);else{var p=s(e.nodeType);if(!p&&qr(e,t))k(e,t,f,null,null,o);else{if(p){if(1===e.nodeType&&e.hasAttribute(B)&&(e.removeAttribute(B),n=!0),c(n)&&$(e,t,f))return S(t,f,!0),e;i=e,e=new ve(u.tagName(i).toLowerCase(),{},[],void 0,i)}var h=e.elm,v=u.parentNode(h);if(d(t,f,h._leaveCb?null:v,u.nextSibling(h)),s(t.parent))for(var g=t.parent,y=m(t);g;){for(var b=0;b<r.destroy.length;++b)r.destroy[b](g);if(g.elm=t.elm,y){for(var _=0;_<r.create.length;++_)r.create[_](Fr,g);var C=g.data.hook.insert;if(C.merged)for(var T=C.fns.slice(1),E=0;E<T.length;E++)T[E]()}else Mr(g);g=g.parent}s(v)?w([e],0,0):s(e.tag)&&x(e)}}return S(t,f,l),t.elm}s(e)&&x(e)}}({nodeOps:Pr,modules:[Zr,so,Ho,Wo,ei,G?{create:Si,activate:Si,remove:function(e,t){!0!==e.data.show?Ci(e,t):t()}}:{}].concat(Xr)});ee&&document.addEventListener("selectionchange",(function(){var e=document.activeElement;e&&e.vmodel&&Pi(e,"input")}));var $i={inserted:function(e,t,n,r){"select"===n.tag?(r.elm&&!r.elm._vOptions?Je(n,"postpatch",(function(){$i.componentUpdated(e,t,n)})):Ai(e,t,n.context),e._vOptions=[].map.call(e.options,Di)):("textarea"===n.tag||Nr(e.type))&&(e._vModifiers=t.modifiers,t.modifiers.lazy||(e.addEventListener("compositionstart",Ni),e.addEventListener("compositionend",Li),e.addEventListener("change",Li),ee&&(e.vmodel=!0)))},componentUpdated:function(e,t,n){if("select"===n.tag){Ai(e,t,n.context);var r=e._vOptions,o=e._vOptions=[].map.call(e.options,Di);if(o.some((function(e,t){return!R(e,r[t])})))(e.multiple?t.value.some((function(e){return ji(e,o)})):t.value!==t.oldValue&&ji(t.value,o))&&Pi(e,"change")}}};function Ai(e,t,n){Oi(e,t,n),(Z||te)&&setTimeout((function(){Oi(e,t,n)}),0)}function Oi(e,t,n){var r=t.value,o=e.multiple;if(!o||Array.isArray(r)){for(var i,a,s=0,c=e.options.length;s<c;s++)if(a=e.options[s],o)i=F(r,Di(a))>-1,a.selected!==i&&(a.selected=i);else if(R(Di(a),r))return void(e.selectedIndex!==s&&(e.selectedIndex=s));o||(e.selectedIndex=-1)}}function ji(e,t){return t.every((function(t){return!R(t,e)}))}function Di(e){return"_value"in e?e._value:e.value}function Ni(e){e.target.composing=!0}function Li(e){e.target.composing&&(e.target.composing=!1,Pi(e.target,"input"))}function Pi(e,t){var n=document.createEvent("HTMLEvents");n.initEvent(t,!0,!0),e.dispatchEvent(n)}function Ii(e){return!e.componentInstance||e.data&&e.data.transition?e:Ii(e.componentInstance._vnode)}var Mi={bind:function(e,t,n){var r=t.value,o=(n=Ii(n)).data&&n.data.transition,i=e.__vOriginalDisplay="none"===e.style.display?"":e.style.display;r&&o?(n.data.show=!0,wi(n,(function(){e.style.display=i}))):e.style.display=r?i:"none"},update:function(e,t,n){var r=t.value;!r!=!t.oldValue&&((n=Ii(n)).data&&n.data.transition?(n.data.show=!0,r?wi(n,(function(){e.style.display=e.__vOriginalDisplay})):Ci(n,(function(){e.style.display="none"}))):e.style.display=r?e.__vOriginalDisplay:"none")},unbind:function(e,t,n,r,o){o||(e.style.display=e.__vOriginalDisplay)}},Ri={model:$i,show:Mi},Fi={name:String,appear:Boolean,css:Boolean,mode:String,type:String,enterClass:String,leaveClass:String,enterToClass:String,leaveToClass:String,enterActiveClass:String,leaveActiveClass:String,appearClass:String,appearActiveClass:String,appearToClass:String,duration:[Number,String,Object]};function Hi(e){var t=e&&e.componentOptions;return t&&t.Ctor.options.abstract?Hi(jt(t.children)):e}function qi(e){var t={},n=e.$options;for(var r in n.propsData)t[r]=e[r];var o=n._parentListeners;for(var r in o)t[E(r)]=o[r];return t}function Bi(e,t){if(/\d-keep-alive$/.test(t.tag))return e("keep-alive",{props:t.componentOptions.propsData})}var Wi=function(e){return e.tag||bt(e)},Ui=function(e){return"show"===e.name},zi={name:"transition",props:Fi,abstract:!0,render:function(e){var t=this,n=this.$slots.default;if(n&&(n=n.filter(Wi)).length){0;var r=this.mode;0;var o=n[0];if(function(e){for(;e=e.parent;)if(e.data.transition)return!0}(this.$vnode))return o;var i=Hi(o);if(!i)return o;if(this._leaving)return Bi(e,o);var a="__transition-".concat(this._uid,"-");i.key=null==i.key?i.isComment?a+"comment":a+i.tag:l(i.key)?0===String(i.key).indexOf(a)?i.key:a+i.key:i.key;var s=(i.data||(i.data={})).transition=qi(this),c=this._vnode,u=Hi(c);if(i.data.directives&&i.data.directives.some(Ui)&&(i.data.show=!0),u&&u.data&&!function(e,t){return t.key===e.key&&t.tag===e.tag}(i,u)&&!bt(u)&&(!u.componentInstance||!u.componentInstance._vnode.isComment)){var f=u.data.transition=N({},s);if("out-in"===r)return this._leaving=!0,Je(f,"afterLeave",(function(){t._leaving=!1,t.$forceUpdate()})),Bi(e,o);if("in-out"===r){if(bt(i))return c;var d,p=function(){d()};Je(s,"afterEnter",p),Je(s,"enterCancelled",p),Je(f,"delayLeave",(function(e){d=e}))}}return o}}},Vi=N({tag:String,moveClass:String},Fi);delete Vi.mode;var Ji={props:Vi,beforeMount:function(){var e=this,t=this._update;this._update=function(n,r){var o=Rt(e);e.__patch__(e._vnode,e.kept,!1,!0),e._vnode=e.kept,o(),t.call(e,n,r)}},render:function(e){for(var t=this.tag||this.$vnode.data.tag||"span",n=Object.create(null),r=this.prevChildren=this.children,o=this.$slots.default||[],i=this.children=[],a=qi(this),s=0;s<o.length;s++){if((u=o[s]).tag)if(null!=u.key&&0!==String(u.key).indexOf("__vlist"))i.push(u),n[u.key]=u,(u.data||(u.data={})).transition=a;else;}if(r){var c=[],l=[];for(s=0;s<r.length;s++){var u;(u=r[s]).data.transition=a,u.data.pos=u.elm.getBoundingClientRect(),n[u.key]?c.push(u):l.push(u)}this.kept=e(t,null,c),this.removed=l}return e(t,null,i)},updated:function(){var e=this.prevChildren,t=this.moveClass||(this.name||"v")+"-move";e.length&&this.hasMove(e[0].elm,t)&&(e.forEach(Ki),e.forEach(Xi),e.forEach(Qi),this._reflow=document.body.offsetHeight,e.forEach((function(e){if(e.data.moved){var n=e.elm,r=n.style;vi(n,t),r.transform=r.WebkitTransform=r.transitionDuration="",n.addEventListener(ui,n._moveCb=function e(r){r&&r.target!==n||r&&!/transform$/.test(r.propertyName)||(n.removeEventListener(ui,e),n._moveCb=null,mi(n,t))})}})))},methods:{hasMove:function(e,t){if(!ai)return!1;if(this._hasMove)return this._hasMove;var n=e.cloneNode();e._transitionClasses&&e._transitionClasses.forEach((function(e){ri(n,e)})),ni(n,t),n.style.display="none",this.$el.appendChild(n);var r=bi(n);return this.$el.removeChild(n),this._hasMove=r.hasTransform}}};function Ki(e){e.elm._moveCb&&e.elm._moveCb(),e.elm._enterCb&&e.elm._enterCb()}function Xi(e){e.data.newPos=e.elm.getBoundingClientRect()}function Qi(e){var t=e.data.pos,n=e.data.newPos,r=t.left-n.left,o=t.top-n.top;if(r||o){e.data.moved=!0;var i=e.elm.style;i.transform=i.WebkitTransform="translate(".concat(r,"px,").concat(o,"px)"),i.transitionDuration="0s"}}var Gi={Transition:zi,TransitionGroup:Ji};rr.config.mustUseProp=hr,rr.config.isReservedTag=Or,rr.config.isReservedAttr=dr,rr.config.getTagNamespace=jr,rr.config.isUnknownElement=function(e){if(!G)return!0;if(Or(e))return!1;if(e=e.toLowerCase(),null!=Dr[e])return Dr[e];var t=document.createElement(e);return e.indexOf("-")>-1?Dr[e]=t.constructor===window.HTMLUnknownElement||t.constructor===window.HTMLElement:Dr[e]=/HTMLUnknownElement/.test(t.toString())},N(rr.options.directives,Ri),N(rr.options.components,Gi),rr.prototype.__patch__=G?Ei:P,rr.prototype.$mount=function(e,t){return function(e,t,n){var r;e.$el=t,e.$options.render||(e.$options.render=me),Bt(e,"beforeMount"),r=function(){e._update(e._render(),n)},new Cn(e,r,P,{before:function(){e._isMounted&&!e._isDestroyed&&Bt(e,"beforeUpdate")}},!0),n=!1;var o=e._preWatchers;if(o)for(var i=0;i<o.length;i++)o[i].run();return null==e.$vnode&&(e._isMounted=!0,Bt(e,"mounted")),e}(this,e=e&&G?Lr(e):void 0,t)},G&&setTimeout((function(){z.devtools&&le&&le.emit("init",rr)}),0);var Yi=/\{\{((?:.|\r?\n)+?)\}\}/g,Zi=/[-.*+?^${}()|[\]\/\\]/g,ea=k((function(e){var t=e[0].replace(Zi,"\\$&"),n=e[1].replace(Zi,"\\$&");return new RegExp(t+"((?:.|\\n)+?)"+n,"g")}));var ta={staticKeys:["staticClass"],transformNode:function(e,t){t.warn;var n=xo(e,"class");n&&(e.staticClass=JSON.stringify(n.replace(/\s+/g," ").trim()));var r=_o(e,"class",!1);r&&(e.classBinding=r)},genData:function(e){var t="";return e.staticClass&&(t+="staticClass:".concat(e.staticClass,",")),e.classBinding&&(t+="class:".concat(e.classBinding,",")),t}};var na,ra={staticKeys:["staticStyle"],transformNode:function(e,t){t.warn;var n=xo(e,"style");n&&(e.staticStyle=JSON.stringify(Uo(n)));var r=_o(e,"style",!1);r&&(e.styleBinding=r)},genData:function(e){var t="";return e.staticStyle&&(t+="staticStyle:".concat(e.staticStyle,",")),e.styleBinding&&(t+="style:(".concat(e.styleBinding,"),")),t}},oa=function(e){return(na=na||document.createElement("div")).innerHTML=e,na.textContent},ia=b("area,base,br,col,embed,frame,hr,img,input,isindex,keygen,link,meta,param,source,track,wbr"),aa=b("colgroup,dd,dt,li,options,p,td,tfoot,th,thead,tr,source"),sa=b("address,article,aside,base,blockquote,body,caption,col,colgroup,dd,details,dialog,div,dl,dt,fieldset,figcaption,figure,footer,form,h1,h2,h3,h4,h5,h6,head,header,hgroup,hr,html,legend,li,menuitem,meta,optgroup,option,param,rp,rt,source,style,summary,tbody,td,tfoot,th,thead,title,tr,track"),ca=/^\s*([^\s"'<>\/=]+)(?:\s*(=)\s*(?:"([^"]*)"+|'([^']*)'+|([^\s"'=<>`]+)))?/,la=/^\s*((?:v-[\w-]+:|@|:|#)\[[^=]+?\][^\s"'<>\/=]*)(?:\s*(=)\s*(?:"([^"]*)"+|'([^']*)'+|([^\s"'=<>`]+)))?/,ua="[a-zA-Z_][\\-\\.0-9_a-zA-Z".concat(V.source,"]*"),fa="((?:".concat(ua,"\\:)?").concat(ua,")"),da=new RegExp("^<".concat(fa)),pa=/^\s*(\/?)>/,ha=new RegExp("^<\\/".concat(fa,"[^>]*>")),va=/^<!DOCTYPE [^>]+>/i,ma=/^<!\--/,ga=/^<!\[/,ya=b("script,style,textarea",!0),ba={},_a={"&lt;":"<","&gt;":">","&quot;":'"',"&amp;":"&","&#10;":"\n","&#9;":"\t","&#39;":"'"},xa=/&(?:lt|gt|quot|amp|#39);/g,wa=/&(?:lt|gt|quot|amp|#39|#10|#9);/g,Ca=b("pre,textarea",!0),Ta=function(e,t){return e&&Ca(e)&&"\n"===t[0]};function ka(e,t){var n=t?wa:xa;return e.replace(n,(function(e){return _a[e]}))}function Sa(e,t){for(var n,r,o=[],i=t.expectHTML,a=t.isUnaryTag||I,s=t.canBeLeftOpenTag||I,c=0,l=function(){if(n=e,r&&ya(r)){var l=0,d=r.toLowerCase(),p=ba[d]||(ba[d]=new RegExp("([\\s\\S]*?)(</"+d+"[^>]*>)","i"));w=e.replace(p,(function(e,n,r){return l=r.length,ya(d)||"noscript"===d||(n=n.replace(/<!\--([\s\S]*?)-->/g,"$1").replace(/<!\[CDATA\[([\s\S]*?)]]>/g,"$1")),Ta(d,n)&&(n=n.slice(1)),t.chars&&t.chars(n),""}));c+=e.length-w.length,e=w,f(d,c-l,c)}else{var h=e.indexOf("<");if(0===h){if(ma.test(e)){var v=e.indexOf("--\x3e");if(v>=0)return t.shouldKeepComment&&t.comment&&t.comment(e.substring(4,v),c,c+v+3),u(v+3),"continue"}if(ga.test(e)){var m=e.indexOf("]>");if(m>=0)return u(m+2),"continue"}var g=e.match(va);if(g)return u(g[0].length),"continue";var y=e.match(ha);if(y){var b=c;return u(y[0].length),f(y[1],b,c),"continue"}var _=function(){var t=e.match(da);if(t){var n={tagName:t[1],attrs:[],start:c};u(t[0].length);for(var r=void 0,o=void 0;!(r=e.match(pa))&&(o=e.match(la)||e.match(ca));)o.start=c,u(o[0].length),o.end=c,n.attrs.push(o);if(r)return n.unarySlash=r[1],u(r[0].length),n.end=c,n}}();if(_)return function(e){var n=e.tagName,c=e.unarySlash;i&&("p"===r&&sa(n)&&f(r),s(n)&&r===n&&f(n));for(var l=a(n)||!!c,u=e.attrs.length,d=new Array(u),p=0;p<u;p++){var h=e.attrs[p],v=h[3]||h[4]||h[5]||"",m="a"===n&&"href"===h[1]?t.shouldDecodeNewlinesForHref:t.shouldDecodeNewlines;d[p]={name:h[1],value:ka(v,m)}}l||(o.push({tag:n,lowerCasedTag:n.toLowerCase(),attrs:d,start:e.start,end:e.end}),r=n);t.start&&t.start(n,d,l,e.start,e.end)}(_),Ta(_.tagName,e)&&u(1),"continue"}var x=void 0,w=void 0,C=void 0;if(h>=0){for(w=e.slice(h);!(ha.test(w)||da.test(w)||ma.test(w)||ga.test(w)||(C=w.indexOf("<",1))<0);)h+=C,w=e.slice(h);x=e.substring(0,h)}h<0&&(x=e),x&&u(x.length),t.chars&&x&&t.chars(x,c-x.length,c)}if(e===n)return t.chars&&t.chars(e),"break"};e;){if("break"===l())break}function u(t){c+=t,e=e.substring(t)}function f(e,n,i){var a,s;if(null==n&&(n=c),null==i&&(i=c),e)for(s=e.toLowerCase(),a=o.length-1;a>=0&&o[a].lowerCasedTag!==s;a--);else a=0;if(a>=0){for(var l=o.length-1;l>=a;l--)t.end&&t.end(o[l].tag,n,i);o.length=a,r=a&&o[a-1].tag}else"br"===s?t.start&&t.start(e,[],!0,n,i):"p"===s&&(t.start&&t.start(e,[],!1,n,i),t.end&&t.end(e,n,i))}f()}var Ea,$a,Aa,Oa,ja,Da,Na,La,Pa=/^@|^v-on:/,Ia=/^v-|^@|^:|^#/,Ma=/([\s\S]*?)\s+(?:in|of)\s+([\s\S]*)/,Ra=/,([^,\}\]]*)(?:,([^,\}\]]*))?$/,Fa=/^\(|\)$/g,Ha=/^\[.*\]$/,qa=/:(.*)$/,Ba=/^:|^\.|^v-bind:/,Wa=/\.[^.\]]+(?=[^\]]*$)/g,Ua=/^v-slot(:|$)|^#/,za=/[\r\n]/,Va=/[ \f\t\r\n]+/g,Ja=k(oa),Ka="_empty_";function Xa(e,t,n){return{type:1,tag:e,attrsList:t,attrsMap:ns(t),rawAttrsMap:{},parent:n,children:[]}}function Qa(e,t){Ea=t.warn||fo,Da=t.isPreTag||I,Na=t.mustUseProp||I,La=t.getTagNamespace||I;var n=t.isReservedTag||I;(function(e){return!(!(e.component||e.attrsMap[":is"]||e.attrsMap["v-bind:is"])&&(e.attrsMap.is?n(e.attrsMap.is):n(e.tag)))}),Aa=po(t.modules,"transformNode"),Oa=po(t.modules,"preTransformNode"),ja=po(t.modules,"postTransformNode"),$a=t.delimiters;var r,o,i=[],a=!1!==t.preserveWhitespace,s=t.whitespace,c=!1,l=!1;function u(e){if(f(e),c||e.processed||(e=Ga(e,t)),i.length||e===r||r.if&&(e.elseif||e.else)&&Za(r,{exp:e.elseif,block:e}),o&&!e.forbidden)if(e.elseif||e.else)a=e,s=function(e){for(var t=e.length;t--;){if(1===e[t].type)return e[t];e.pop()}}(o.children),s&&s.if&&Za(s,{exp:a.elseif,block:a});else{if(e.slotScope){var n=e.slotTarget||'"default"';(o.scopedSlots||(o.scopedSlots={}))[n]=e}o.children.push(e),e.parent=o}var a,s;e.children=e.children.filter((function(e){return!e.slotScope})),f(e),e.pre&&(c=!1),Da(e.tag)&&(l=!1);for(var u=0;u<ja.length;u++)ja[u](e,t)}function f(e){if(!l)for(var t=void 0;(t=e.children[e.children.length-1])&&3===t.type&&" "===t.text;)e.children.pop()}return Sa(e,{warn:Ea,expectHTML:t.expectHTML,isUnaryTag:t.isUnaryTag,canBeLeftOpenTag:t.canBeLeftOpenTag,shouldDecodeNewlines:t.shouldDecodeNewlines,shouldDecodeNewlinesForHref:t.shouldDecodeNewlinesForHref,shouldKeepComment:t.comments,outputSourceRange:t.outputSourceRange,start:function(e,n,a,s,f){var d=o&&o.ns||La(e);Z&&"svg"===d&&(n=function(e){for(var t=[],n=0;n<e.length;n++){var r=e[n];rs.test(r.name)||(r.name=r.name.replace(os,""),t.push(r))}return t}(n));var p,h=Xa(e,n,o);d&&(h.ns=d),"style"!==(p=h).tag&&("script"!==p.tag||p.attrsMap.type&&"text/javascript"!==p.attrsMap.type)||ce()||(h.forbidden=!0);for(var v=0;v<Oa.length;v++)h=Oa[v](h,t)||h;c||(!function(e){null!=xo(e,"v-pre")&&(e.pre=!0)}(h),h.pre&&(c=!0)),Da(h.tag)&&(l=!0),c?function(e){var t=e.attrsList,n=t.length;if(n)for(var r=e.attrs=new Array(n),o=0;o<n;o++)r[o]={name:t[o].name,value:JSON.stringify(t[o].value)},null!=t[o].start&&(r[o].start=t[o].start,r[o].end=t[o].end);else e.pre||(e.plain=!0)}(h):h.processed||(Ya(h),function(e){var t=xo(e,"v-if");if(t)e.if=t,Za(e,{exp:t,block:e});else{null!=xo(e,"v-else")&&(e.else=!0);var n=xo(e,"v-else-if");n&&(e.elseif=n)}}(h),function(e){var t=xo(e,"v-once");null!=t&&(e.once=!0)}(h)),r||(r=h),a?u(h):(o=h,i.push(h))},end:function(e,t,n){var r=i[i.length-1];i.length-=1,o=i[i.length-1],u(r)},chars:function(e,t,n){if(o&&(!Z||"textarea"!==o.tag||o.attrsMap.placeholder!==e)){var r,i=o.children;if(e=l||e.trim()?"script"===(r=o).tag||"style"===r.tag?e:Ja(e):i.length?s?"condense"===s&&za.test(e)?"":" ":a?" ":"":""){l||"condense"!==s||(e=e.replace(Va," "));var u=void 0,f=void 0;!c&&" "!==e&&(u=function(e,t){var n=t?ea(t):Yi;if(n.test(e)){for(var r,o,i,a=[],s=[],c=n.lastIndex=0;r=n.exec(e);){(o=r.index)>c&&(s.push(i=e.slice(c,o)),a.push(JSON.stringify(i)));var l=lo(r[1].trim());a.push("_s(".concat(l,")")),s.push({"@binding":l}),c=o+r[0].length}return c<e.length&&(s.push(i=e.slice(c)),a.push(JSON.stringify(i))),{expression:a.join("+"),tokens:s}}}(e,$a))?f={type:2,expression:u.expression,tokens:u.tokens,text:e}:" "===e&&i.length&&" "===i[i.length-1].text||(f={type:3,text:e}),f&&i.push(f)}}},comment:function(e,t,n){if(o){var r={type:3,text:e,isComment:!0};0,o.children.push(r)}}}),r}function Ga(e,t){var n;!function(e){var t=_o(e,"key");if(t){e.key=t}}(e),e.plain=!e.key&&!e.scopedSlots&&!e.attrsList.length,function(e){var t=_o(e,"ref");t&&(e.ref=t,e.refInFor=function(e){var t=e;for(;t;){if(void 0!==t.for)return!0;t=t.parent}return!1}(e))}(e),function(e){var t;"template"===e.tag?(t=xo(e,"scope"),e.slotScope=t||xo(e,"slot-scope")):(t=xo(e,"slot-scope"))&&(e.slotScope=t);var n=_o(e,"slot");n&&(e.slotTarget='""'===n?'"default"':n,e.slotTargetDynamic=!(!e.attrsMap[":slot"]&&!e.attrsMap["v-bind:slot"]),"template"===e.tag||e.slotScope||vo(e,"slot",n,function(e,t){return e.rawAttrsMap[":"+t]||e.rawAttrsMap["v-bind:"+t]||e.rawAttrsMap[t]}(e,"slot")));if("template"===e.tag){if(a=wo(e,Ua)){0;var r=es(a),o=r.name,i=r.dynamic;e.slotTarget=o,e.slotTargetDynamic=i,e.slotScope=a.value||Ka}}else{var a;if(a=wo(e,Ua)){0;var s=e.scopedSlots||(e.scopedSlots={}),c=es(a),l=c.name,u=(i=c.dynamic,s[l]=Xa("template",[],e));u.slotTarget=l,u.slotTargetDynamic=i,u.children=e.children.filter((function(e){if(!e.slotScope)return e.parent=u,!0})),u.slotScope=a.value||Ka,e.children=[],e.plain=!1}}}(e),"slot"===(n=e).tag&&(n.slotName=_o(n,"name")),function(e){var t;(t=_o(e,"is"))&&(e.component=t);null!=xo(e,"inline-template")&&(e.inlineTemplate=!0)}(e);for(var r=0;r<Aa.length;r++)e=Aa[r](e,t)||e;return function(e){var t,n,r,o,i,a,s,c,l=e.attrsList;for(t=0,n=l.length;t<n;t++){if(r=o=l[t].name,i=l[t].value,Ia.test(r))if(e.hasBindings=!0,(a=ts(r.replace(Ia,"")))&&(r=r.replace(Wa,"")),Ba.test(r))r=r.replace(Ba,""),i=lo(i),(c=Ha.test(r))&&(r=r.slice(1,-1)),a&&(a.prop&&!c&&"innerHtml"===(r=E(r))&&(r="innerHTML"),a.camel&&!c&&(r=E(r)),a.sync&&(s=ko(i,"$event"),c?bo(e,'"update:"+('.concat(r,")"),s,null,!1,0,l[t],!0):(bo(e,"update:".concat(E(r)),s,null,!1,0,l[t]),O(r)!==E(r)&&bo(e,"update:".concat(O(r)),s,null,!1,0,l[t])))),a&&a.prop||!e.component&&Na(e.tag,e.attrsMap.type,r)?ho(e,r,i,l[t],c):vo(e,r,i,l[t],c);else if(Pa.test(r))r=r.replace(Pa,""),(c=Ha.test(r))&&(r=r.slice(1,-1)),bo(e,r,i,a,!1,0,l[t],c);else{var u=(r=r.replace(Ia,"")).match(qa),f=u&&u[1];c=!1,f&&(r=r.slice(0,-(f.length+1)),Ha.test(f)&&(f=f.slice(1,-1),c=!0)),go(e,r,o,i,f,c,a,l[t])}else vo(e,r,JSON.stringify(i),l[t]),!e.component&&"muted"===r&&Na(e.tag,e.attrsMap.type,r)&&ho(e,r,"true",l[t])}}(e),e}function Ya(e){var t;if(t=xo(e,"v-for")){var n=function(e){var t=e.match(Ma);if(!t)return;var n={};n.for=t[2].trim();var r=t[1].trim().replace(Fa,""),o=r.match(Ra);o?(n.alias=r.replace(Ra,"").trim(),n.iterator1=o[1].trim(),o[2]&&(n.iterator2=o[2].trim())):n.alias=r;return n}(t);n&&N(e,n)}}function Za(e,t){e.ifConditions||(e.ifConditions=[]),e.ifConditions.push(t)}function es(e){var t=e.name.replace(Ua,"");return t||"#"!==e.name[0]&&(t="default"),Ha.test(t)?{name:t.slice(1,-1),dynamic:!0}:{name:'"'.concat(t,'"'),dynamic:!1}}function ts(e){var t=e.match(Wa);if(t){var n={};return t.forEach((function(e){n[e.slice(1)]=!0})),n}}function ns(e){for(var t={},n=0,r=e.length;n<r;n++)t[e[n].name]=e[n].value;return t}var rs=/^xmlns:NS\d+/,os=/^NS\d+:/;function is(e){return Xa(e.tag,e.attrsList.slice(),e.parent)}var as=[ta,ra,{preTransformNode:function(e,t){if("input"===e.tag){var n=e.attrsMap;if(!n["v-model"])return;var r=void 0;if((n[":type"]||n["v-bind:type"])&&(r=_o(e,"type")),n.type||r||!n["v-bind"]||(r="(".concat(n["v-bind"],").type")),r){var o=xo(e,"v-if",!0),i=o?"&&(".concat(o,")"):"",a=null!=xo(e,"v-else",!0),s=xo(e,"v-else-if",!0),c=is(e);Ya(c),mo(c,"type","checkbox"),Ga(c,t),c.processed=!0,c.if="(".concat(r,")==='checkbox'")+i,Za(c,{exp:c.if,block:c});var l=is(e);xo(l,"v-for",!0),mo(l,"type","radio"),Ga(l,t),Za(c,{exp:"(".concat(r,")==='radio'")+i,block:l});var u=is(e);return xo(u,"v-for",!0),mo(u,":type",r),Ga(u,t),Za(c,{exp:o,block:u}),a?c.else=!0:s&&(c.elseif=s),c}}}}];var ss,cs,ls={model:function(e,t,n){n;var r=t.value,o=t.modifiers,i=e.tag,a=e.attrsMap.type;if(e.component)return To(e,r,o),!1;if("select"===i)!function(e,t,n){var r=n&&n.number,o='Array.prototype.filter.call($event.target.options,function(o){return o.selected}).map(function(o){var val = "_value" in o ? o._value : o.value;'+"return ".concat(r?"_n(val)":"val","})"),i="$event.target.multiple ? $$selectedVal : $$selectedVal[0]",a="var $$selectedVal = ".concat(o,";");a="".concat(a," ").concat(ko(t,i)),bo(e,"change",a,null,!0)}(e,r,o);else if("input"===i&&"checkbox"===a)!function(e,t,n){var r=n&&n.number,o=_o(e,"value")||"null",i=_o(e,"true-value")||"true",a=_o(e,"false-value")||"false";ho(e,"checked","Array.isArray(".concat(t,")")+"?_i(".concat(t,",").concat(o,")>-1")+("true"===i?":(".concat(t,")"):":_q(".concat(t,",").concat(i,")"))),bo(e,"change","var $$a=".concat(t,",")+"$$el=$event.target,"+"$$c=$$el.checked?(".concat(i,"):(").concat(a,");")+"if(Array.isArray($$a)){"+"var $$v=".concat(r?"_n("+o+")":o,",")+"$$i=_i($$a,$$v);"+"if($$el.checked){$$i<0&&(".concat(ko(t,"$$a.concat([$$v])"),")}")+"else{$$i>-1&&(".concat(ko(t,"$$a.slice(0,$$i).concat($$a.slice($$i+1))"),")}")+"}else{".concat(ko(t,"$$c"),"}"),null,!0)}(e,r,o);else if("input"===i&&"radio"===a)!function(e,t,n){var r=n&&n.number,o=_o(e,"value")||"null";o=r?"_n(".concat(o,")"):o,ho(e,"checked","_q(".concat(t,",").concat(o,")")),bo(e,"change",ko(t,o),null,!0)}(e,r,o);else if("input"===i||"textarea"===i)!function(e,t,n){var r=e.attrsMap.type;0;var o=n||{},i=o.lazy,a=o.number,s=o.trim,c=!i&&"range"!==r,l=i?"change":"range"===r?Do:"input",u="$event.target.value";s&&(u="$event.target.value.trim()");a&&(u="_n(".concat(u,")"));var f=ko(t,u);c&&(f="if($event.target.composing)return;".concat(f));ho(e,"value","(".concat(t,")")),bo(e,l,f,null,!0),(s||a)&&bo(e,"blur","$forceUpdate()")}(e,r,o);else{if(!z.isReservedTag(i))return To(e,r,o),!1}return!0},text:function(e,t){t.value&&ho(e,"textContent","_s(".concat(t.value,")"),t)},html:function(e,t){t.value&&ho(e,"innerHTML","_s(".concat(t.value,")"),t)}},us={expectHTML:!0,modules:as,directives:ls,isPreTag:function(e){return"pre"===e},isUnaryTag:ia,mustUseProp:hr,canBeLeftOpenTag:aa,isReservedTag:Or,getTagNamespace:jr,staticKeys:function(e){return e.reduce((function(e,t){return e.concat(t.staticKeys||[])}),[]).join(",")}(as)},fs=k((function(e){return b("type,tag,attrsList,attrsMap,plain,parent,children,attrs,start,end,rawAttrsMap"+(e?","+e:""))}));function ds(e,t){e&&(ss=fs(t.staticKeys||""),cs=t.isReservedTag||I,ps(e),hs(e,!1))}function ps(e){if(e.static=function(e){if(2===e.type)return!1;if(3===e.type)return!0;return!(!e.pre&&(e.hasBindings||e.if||e.for||_(e.tag)||!cs(e.tag)||function(e){for(;e.parent;){if("template"!==(e=e.parent).tag)return!1;if(e.for)return!0}return!1}(e)||!Object.keys(e).every(ss)))}(e),1===e.type){if(!cs(e.tag)&&"slot"!==e.tag&&null==e.attrsMap["inline-template"])return;for(var t=0,n=e.children.length;t<n;t++){var r=e.children[t];ps(r),r.static||(e.static=!1)}if(e.ifConditions)for(t=1,n=e.ifConditions.length;t<n;t++){var o=e.ifConditions[t].block;ps(o),o.static||(e.static=!1)}}}function hs(e,t){if(1===e.type){if((e.static||e.once)&&(e.staticInFor=t),e.static&&e.children.length&&(1!==e.children.length||3!==e.children[0].type))return void(e.staticRoot=!0);if(e.staticRoot=!1,e.children)for(var n=0,r=e.children.length;n<r;n++)hs(e.children[n],t||!!e.for);if(e.ifConditions)for(n=1,r=e.ifConditions.length;n<r;n++)hs(e.ifConditions[n].block,t)}}var vs=/^([\w$_]+|\([^)]*?\))\s*=>|^function(?:\s+[\w$]+)?\s*\(/,ms=/\([^)]*?\);*$/,gs=/^[A-Za-z_$][\w$]*(?:\.[A-Za-z_$][\w$]*|\['[^']*?']|\["[^"]*?"]|\[\d+]|\[[A-Za-z_$][\w$]*])*$/,ys={esc:27,tab:9,enter:13,space:32,up:38,left:37,right:39,down:40,delete:[8,46]},bs={esc:["Esc","Escape"],tab:"Tab",enter:"Enter",space:[" ","Spacebar"],up:["Up","ArrowUp"],left:["Left","ArrowLeft"],right:["Right","ArrowRight"],down:["Down","ArrowDown"],delete:["Backspace","Delete","Del"]},_s=function(e){return"if(".concat(e,")return null;")},xs={stop:"$event.stopPropagation();",prevent:"$event.preventDefault();",self:_s("$event.target !== $event.currentTarget"),ctrl:_s("!$event.ctrlKey"),shift:_s("!$event.shiftKey"),alt:_s("!$event.altKey"),meta:_s("!$event.metaKey"),left:_s("'button' in $event && $event.button !== 0"),middle:_s("'button' in $event && $event.button !== 1"),right:_s("'button' in $event && $event.button !== 2")};function ws(e,t){var n=t?"nativeOn:":"on:",r="",o="";for(var i in e){var a=Cs(e[i]);e[i]&&e[i].dynamic?o+="".concat(i,",").concat(a,","):r+='"'.concat(i,'":').concat(a,",")}return r="{".concat(r.slice(0,-1),"}"),o?n+"_d(".concat(r,",[").concat(o.slice(0,-1),"])"):n+r}function Cs(e){if(!e)return"function(){}";if(Array.isArray(e))return"[".concat(e.map((function(e){return Cs(e)})).join(","),"]");var t=gs.test(e.value),n=vs.test(e.value),r=gs.test(e.value.replace(ms,""));if(e.modifiers){var o="",i="",a=[],s=function(t){if(xs[t])i+=xs[t],ys[t]&&a.push(t);else if("exact"===t){var n=e.modifiers;i+=_s(["ctrl","shift","alt","meta"].filter((function(e){return!n[e]})).map((function(e){return"$event.".concat(e,"Key")})).join("||"))}else a.push(t)};for(var c in e.modifiers)s(c);a.length&&(o+=function(e){return"if(!$event.type.indexOf('key')&&"+"".concat(e.map(Ts).join("&&"),")return null;")}(a)),i&&(o+=i);var l=t?"return ".concat(e.value,".apply(null, arguments)"):n?"return (".concat(e.value,").apply(null, arguments)"):r?"return ".concat(e.value):e.value;return"function($event){".concat(o).concat(l,"}")}return t||n?e.value:"function($event){".concat(r?"return ".concat(e.value):e.value,"}")}function Ts(e){var t=parseInt(e,10);if(t)return"$event.keyCode!==".concat(t);var n=ys[e],r=bs[e];return"_k($event.keyCode,"+"".concat(JSON.stringify(e),",")+"".concat(JSON.stringify(n),",")+"$event.key,"+"".concat(JSON.stringify(r))+")"}var ks={on:function(e,t){e.wrapListeners=function(e){return"_g(".concat(e,",").concat(t.value,")")}},bind:function(e,t){e.wrapData=function(n){return"_b(".concat(n,",'").concat(e.tag,"',").concat(t.value,",").concat(t.modifiers&&t.modifiers.prop?"true":"false").concat(t.modifiers&&t.modifiers.sync?",true":"",")")}},cloak:P},Ss=function(e){this.options=e,this.warn=e.warn||fo,this.transforms=po(e.modules,"transformCode"),this.dataGenFns=po(e.modules,"genData"),this.directives=N(N({},ks),e.directives);var t=e.isReservedTag||I;this.maybeComponent=function(e){return!!e.component||!t(e.tag)},this.onceId=0,this.staticRenderFns=[],this.pre=!1};function Es(e,t){var n=new Ss(t),r=e?"script"===e.tag?"null":$s(e,n):'_c("div")';return{render:"with(this){return ".concat(r,"}"),staticRenderFns:n.staticRenderFns}}function $s(e,t){if(e.parent&&(e.pre=e.pre||e.parent.pre),e.staticRoot&&!e.staticProcessed)return As(e,t);if(e.once&&!e.onceProcessed)return Os(e,t);if(e.for&&!e.forProcessed)return Ns(e,t);if(e.if&&!e.ifProcessed)return js(e,t);if("template"!==e.tag||e.slotTarget||t.pre){if("slot"===e.tag)return function(e,t){var n=e.slotName||'"default"',r=Ms(e,t),o="_t(".concat(n).concat(r?",function(){return ".concat(r,"}"):""),i=e.attrs||e.dynamicAttrs?Hs((e.attrs||[]).concat(e.dynamicAttrs||[]).map((function(e){return{name:E(e.name),value:e.value,dynamic:e.dynamic}}))):null,a=e.attrsMap["v-bind"];!i&&!a||r||(o+=",null");i&&(o+=",".concat(i));a&&(o+="".concat(i?"":",null",",").concat(a));return o+")"}(e,t);var n=void 0;if(e.component)n=function(e,t,n){var r=t.inlineTemplate?null:Ms(t,n,!0);return"_c(".concat(e,",").concat(Ls(t,n)).concat(r?",".concat(r):"",")")}(e.component,e,t);else{var r=void 0,o=t.maybeComponent(e);(!e.plain||e.pre&&o)&&(r=Ls(e,t));var i=void 0,a=t.options.bindings;o&&a&&!1!==a.__isScriptSetup&&(i=function(e,t){var n=E(t),r=$(n),o=function(o){return e[t]===o?t:e[n]===o?n:e[r]===o?r:void 0},i=o("setup-const")||o("setup-reactive-const");if(i)return i;var a=o("setup-let")||o("setup-ref")||o("setup-maybe-ref");if(a)return a}(a,e.tag)),i||(i="'".concat(e.tag,"'"));var s=e.inlineTemplate?null:Ms(e,t,!0);n="_c(".concat(i).concat(r?",".concat(r):"").concat(s?",".concat(s):"",")")}for(var c=0;c<t.transforms.length;c++)n=t.transforms[c](e,n);return n}return Ms(e,t)||"void 0"}function As(e,t){e.staticProcessed=!0;var n=t.pre;return e.pre&&(t.pre=e.pre),t.staticRenderFns.push("with(this){return ".concat($s(e,t),"}")),t.pre=n,"_m(".concat(t.staticRenderFns.length-1).concat(e.staticInFor?",true":"",")")}function Os(e,t){if(e.onceProcessed=!0,e.if&&!e.ifProcessed)return js(e,t);if(e.staticInFor){for(var n="",r=e.parent;r;){if(r.for){n=r.key;break}r=r.parent}return n?"_o(".concat($s(e,t),",").concat(t.onceId++,",").concat(n,")"):$s(e,t)}return As(e,t)}function js(e,t,n,r){return e.ifProcessed=!0,Ds(e.ifConditions.slice(),t,n,r)}function Ds(e,t,n,r){if(!e.length)return r||"_e()";var o=e.shift();return o.exp?"(".concat(o.exp,")?").concat(i(o.block),":").concat(Ds(e,t,n,r)):"".concat(i(o.block));function i(e){return n?n(e,t):e.once?Os(e,t):$s(e,t)}}function Ns(e,t,n,r){var o=e.for,i=e.alias,a=e.iterator1?",".concat(e.iterator1):"",s=e.iterator2?",".concat(e.iterator2):"";return e.forProcessed=!0,"".concat(r||"_l","((").concat(o,"),")+"function(".concat(i).concat(a).concat(s,"){")+"return ".concat((n||$s)(e,t))+"})"}function Ls(e,t){var n="{",r=function(e,t){var n=e.directives;if(!n)return;var r,o,i,a,s="directives:[",c=!1;for(r=0,o=n.length;r<o;r++){i=n[r],a=!0;var l=t.directives[i.name];l&&(a=!!l(e,i,t.warn)),a&&(c=!0,s+='{name:"'.concat(i.name,'",rawName:"').concat(i.rawName,'"').concat(i.value?",value:(".concat(i.value,"),expression:").concat(JSON.stringify(i.value)):"").concat(i.arg?",arg:".concat(i.isDynamicArg?i.arg:'"'.concat(i.arg,'"')):"").concat(i.modifiers?",modifiers:".concat(JSON.stringify(i.modifiers)):"","},"))}if(c)return s.slice(0,-1)+"]"}(e,t);r&&(n+=r+","),e.key&&(n+="key:".concat(e.key,",")),e.ref&&(n+="ref:".concat(e.ref,",")),e.refInFor&&(n+="refInFor:true,"),e.pre&&(n+="pre:true,"),e.component&&(n+='tag:"'.concat(e.tag,'",'));for(var o=0;o<t.dataGenFns.length;o++)n+=t.dataGenFns[o](e);if(e.attrs&&(n+="attrs:".concat(Hs(e.attrs),",")),e.props&&(n+="domProps:".concat(Hs(e.props),",")),e.events&&(n+="".concat(ws(e.events,!1),",")),e.nativeEvents&&(n+="".concat(ws(e.nativeEvents,!0),",")),e.slotTarget&&!e.slotScope&&(n+="slot:".concat(e.slotTarget,",")),e.scopedSlots&&(n+="".concat(function(e,t,n){var r=e.for||Object.keys(t).some((function(e){var n=t[e];return n.slotTargetDynamic||n.if||n.for||Ps(n)})),o=!!e.if;if(!r)for(var i=e.parent;i;){if(i.slotScope&&i.slotScope!==Ka||i.for){r=!0;break}i.if&&(o=!0),i=i.parent}var a=Object.keys(t).map((function(e){return Is(t[e],n)})).join(",");return"scopedSlots:_u([".concat(a,"]").concat(r?",null,true":"").concat(!r&&o?",null,false,".concat(function(e){var t=5381,n=e.length;for(;n;)t=33*t^e.charCodeAt(--n);return t>>>0}(a)):"",")")}(e,e.scopedSlots,t),",")),e.model&&(n+="model:{value:".concat(e.model.value,",callback:").concat(e.model.callback,",expression:").concat(e.model.expression,"},")),e.inlineTemplate){var i=function(e,t){var n=e.children[0];0;if(n&&1===n.type){var r=Es(n,t.options);return"inlineTemplate:{render:function(){".concat(r.render,"},staticRenderFns:[").concat(r.staticRenderFns.map((function(e){return"function(){".concat(e,"}")})).join(","),"]}")}}(e,t);i&&(n+="".concat(i,","))}return n=n.replace(/,$/,"")+"}",e.dynamicAttrs&&(n="_b(".concat(n,',"').concat(e.tag,'",').concat(Hs(e.dynamicAttrs),")")),e.wrapData&&(n=e.wrapData(n)),e.wrapListeners&&(n=e.wrapListeners(n)),n}function Ps(e){return 1===e.type&&("slot"===e.tag||e.children.some(Ps))}function Is(e,t){var n=e.attrsMap["slot-scope"];if(e.if&&!e.ifProcessed&&!n)return js(e,t,Is,"null");if(e.for&&!e.forProcessed)return Ns(e,t,Is);var r=e.slotScope===Ka?"":String(e.slotScope),o="function(".concat(r,"){")+"return ".concat("template"===e.tag?e.if&&n?"(".concat(e.if,")?").concat(Ms(e,t)||"undefined",":undefined"):Ms(e,t)||"undefined":$s(e,t),"}"),i=r?"":",proxy:true";return"{key:".concat(e.slotTarget||'"default"',",fn:").concat(o).concat(i,"}")}function Ms(e,t,n,r,o){var i=e.children;if(i.length){var a=i[0];if(1===i.length&&a.for&&"template"!==a.tag&&"slot"!==a.tag){var s=n?t.maybeComponent(a)?",1":",0":"";return"".concat((r||$s)(a,t)).concat(s)}var c=n?function(e,t){for(var n=0,r=0;r<e.length;r++){var o=e[r];if(1===o.type){if(Rs(o)||o.ifConditions&&o.ifConditions.some((function(e){return Rs(e.block)}))){n=2;break}(t(o)||o.ifConditions&&o.ifConditions.some((function(e){return t(e.block)})))&&(n=1)}}return n}(i,t.maybeComponent):0,l=o||Fs;return"[".concat(i.map((function(e){return l(e,t)})).join(","),"]").concat(c?",".concat(c):"")}}function Rs(e){return void 0!==e.for||"template"===e.tag||"slot"===e.tag}function Fs(e,t){return 1===e.type?$s(e,t):3===e.type&&e.isComment?function(e){return"_e(".concat(JSON.stringify(e.text),")")}(e):"_v(".concat(2===(n=e).type?n.expression:qs(JSON.stringify(n.text)),")");var n}function Hs(e){for(var t="",n="",r=0;r<e.length;r++){var o=e[r],i=qs(o.value);o.dynamic?n+="".concat(o.name,",").concat(i,","):t+='"'.concat(o.name,'":').concat(i,",")}return t="{".concat(t.slice(0,-1),"}"),n?"_d(".concat(t,",[").concat(n.slice(0,-1),"])"):t}function qs(e){return e.replace(/\u2028/g,"\\u2028").replace(/\u2029/g,"\\u2029")}new RegExp("\\b"+"do,if,for,let,new,try,var,case,else,with,await,break,catch,class,const,super,throw,while,yield,delete,export,import,return,switch,default,extends,finally,continue,debugger,function,arguments".split(",").join("\\b|\\b")+"\\b"),new RegExp("\\b"+"delete,typeof,void".split(",").join("\\s*\\([^\\)]*\\)|\\b")+"\\s*\\([^\\)]*\\)");function Bs(e,t){try{return new Function(e)}catch(n){return t.push({err:n,code:e}),P}}function Ws(e){var t=Object.create(null);return function(n,r,o){(r=N({},r)).warn;delete r.warn;var i=r.delimiters?String(r.delimiters)+n:n;if(t[i])return t[i];var a=e(n,r);var s={},c=[];return s.render=Bs(a.render,c),s.staticRenderFns=a.staticRenderFns.map((function(e){return Bs(e,c)})),t[i]=s}}var Us,zs,Vs=(Us=function(e,t){var n=Qa(e.trim(),t);!1!==t.optimize&&ds(n,t);var r=Es(n,t);return{ast:n,render:r.render,staticRenderFns:r.staticRenderFns}},function(e){function t(t,n){var r=Object.create(e),o=[],i=[];if(n)for(var a in n.modules&&(r.modules=(e.modules||[]).concat(n.modules)),n.directives&&(r.directives=N(Object.create(e.directives||null),n.directives)),n)"modules"!==a&&"directives"!==a&&(r[a]=n[a]);r.warn=function(e,t,n){(n?i:o).push(e)};var s=Us(t.trim(),r);return s.errors=o,s.tips=i,s}return{compile:t,compileToFunctions:Ws(t)}}),Js=Vs(us).compileToFunctions;function Ks(e){return(zs=zs||document.createElement("div")).innerHTML=e?'<a href="\n"/>':'<div a="\n"/>',zs.innerHTML.indexOf("&#10;")>0}var Xs=!!G&&Ks(!1),Qs=!!G&&Ks(!0),Gs=k((function(e){var t=Lr(e);return t&&t.innerHTML})),Ys=rr.prototype.$mount;rr.prototype.$mount=function(e,t){if((e=e&&Lr(e))===document.body||e===document.documentElement)return this;var n=this.$options;if(!n.render){var r=n.template;if(r)if("string"==typeof r)"#"===r.charAt(0)&&(r=Gs(r));else{if(!r.nodeType)return this;r=r.innerHTML}else e&&(r=function(e){if(e.outerHTML)return e.outerHTML;var t=document.createElement("div");return t.appendChild(e.cloneNode(!0)),t.innerHTML}(e));if(r){0;var o=Js(r,{outputSourceRange:!1,shouldDecodeNewlines:Xs,shouldDecodeNewlinesForHref:Qs,delimiters:n.delimiters,comments:n.comments},this),i=o.render,a=o.staticRenderFns;n.render=i,n.staticRenderFns=a}}return Ys.call(this,e,t)},rr.compile=Js;var Zs=n(819);function ec(t){var n=!(arguments.length>1&&void 0!==arguments[1])||arguments[1];t instanceof e||(t=e(t)),n?t.hasClass("d-none")||t.addClass("d-none"):t.hasClass("d-none")&&t.removeClass("d-none")}function tc(e,t){!function(e){if(!e&&!(e=window.event))return;e.cancelBubble=!0,e.stopPropagation&&e.stopPropagation()}(t),window.open(e)}function nc(t){e("> .card",t).removeClass("border-dark")}function rc(e){e.hasClass("border-dark")||e.addClass("border-dark")}function oc(t){var n=e("#wrapper");!function(t){var n=arguments.length>1&&void 0!==arguments[1]?arguments[1]:0,r=arguments.length>2&&void 0!==arguments[2]?arguments[2]:500;t instanceof e||(t=e(t));var o=e("html, body"),i=t.offset().top-parseFloat(o.css("margin-top"))-parseFloat(o.css("padding-top"))-parseFloat(t.css("margin-top"))-parseFloat(t.css("padding-top"))-n;o.animate({scrollTop:i},r,"swing")}(t,parseFloat(n.css("padding-top"))+parseFloat(n.css("margin-top")))}function ic(t){var n,r,o,i,a,s,c,l=arguments.length>1&&void 0!==arguments[1]?arguments[1]:void 0,u=arguments.length>2?arguments[2]:void 0,f=arguments.length>3&&void 0!==arguments[3]?arguments[3]:0,d=(new Date).getTime();r="api/monitor",o={},i=function(e){if(0===e.code){for(var n in e=e.data,t.hostname=e.hostname,t.kernel=e.kernel,t.rtc_time=e.rtc_time,t.uptime=e.uptime,t.logical_cores=e.cpus.map((function(e){return e.threads})).reduce((function(e,t){return e+t})),t.load_average=e.load_average,t.load_average_scale)Object.prototype.hasOwnProperty.call(t.load_average_scale,n)&&(t.load_average_scale[n]=100*e.load_average[n]/t.logical_cores);t.cpu=100*e.cpus_stat[0];var r=1;for(var o in e.cpus.forEach((function(t){var n=t.threads;t.cpus_stat=[];var o=r+n;o>e.length&&(o=e.length);for(var i=r;i<o;++i)t.cpus_stat.push(100*e.cpus_stat[i]);r+=n})),t.cpus=e.cpus,t.memory=e.memory,t.swap=e.swap,t.memory_scale)Object.prototype.hasOwnProperty.call(t.memory_scale,o)&&(t.memory_scale[o]=100*e.memory[o].value/e.memory.total.value);for(var i in t.swap_scale)Object.prototype.hasOwnProperty.call(t.swap_scale,i)&&(t.swap_scale[i]=100*e.swap[i].value/e.swap.total.value);t.network=e.network,e.volumes.forEach((function(e){e.scale=100*e.used.value/e.size.value})),t.volumes=e.volumes;var a=new Date;t.last_update_time=a.toLocaleString();var s=a.getTime()-d,c=0;s<u&&(c=u-s),setTimeout((function(){ic(t,l,u,0)}),c)}},a=function(){f>=10?function(){var t=arguments.length>0&&void 0!==arguments[0]?arguments[0]:"",n=arguments.length>1&&void 0!==arguments[1]?arguments[1]:void 0,r=arguments.length>2&&void 0!==arguments[2]?arguments[2]:"OK",o=arguments.length>3&&void 0!==arguments[3]?arguments[3]:void 0,i=!(arguments.length>4&&void 0!==arguments[4])||arguments[4],a=e("#alertModal"),s=e(".modal-header",a),c=e(".modal-title",s),l=e(".modal-body",a),u=e(".modal-footer button",a);"string"==typeof n?(c.text(n),ec(s,!1)):ec(s,!0),l.html(t),u.text(r),a.off("hidden.bs.modal"),"function"==typeof o&&a.on("hidden.bs.modal",o),i?a.pressEnter((function(){a.modal("hide")})):a.pressEnter((function(){})),a.modal("show")}("The monitor API can not be invoked successfully. Please refresh this page to try again.","Error","OK",(function(){var e;e="",location.href=e}),!1):(console.warn("Retry to call the monitor API in 1 second."),setTimeout((function(){ic(t,l,u,f+1)}),1e3))},s=l,"DELETE"===(n="GET")&&(r=r+"?"+e.param(o),o={}),"string"==typeof s&&(c={Authorization:s}),e.ajax({type:n,url:r,data:o,headers:c,dataType:"json",success:function(e){"function"==typeof i&&i(e)},error:function(e){"function"==typeof a&&a(e)}})}function Fc(e){for(var t=["B","KiB","MiB","GiB","TiB","PiB"],n=0;e>=1024&&n<t.length-1;)e/=1024,++n;return e.toFixed(1)+" "+t[n]}function Pc(t){var n,r=arguments.length>1&&void 0!==arguments[1]?arguments[1]:void 0,o=arguments.length>2?arguments[2]:void 0,i=arguments.length>3&&void 0!==arguments[3]?arguments[3]:0,a=(new Date).getTime(),s={};for(var c in t.process_query)if(Object.prototype.hasOwnProperty.call(t.process_query,c)){var l=t.process_query[c];""!==l&&null!==l&&void 0!==l&&(s[c]=l)}var u=function(e){var n=(new Date).getTime()-a,i=0;n<o&&(i=o-n),setTimeout((function(){Pc(t,r,o,e)}),i)};"string"==typeof r&&(n={Authorization:r}),e.ajax({type:"GET",url:"api/process",data:s,headers:n,dataType:"json",success:function(e){0===e.code&&(e.data.forEach((function(e){e.vsz_text=Fc(e.vsz),e.rss_text=Fc(e.rss),e.anon_text=Fc(e.anon),e.start_time_text=new Date(e.start_time).toLocaleString(),"number"!=typeof e.cpu&&(e.cpu=0),e.cpu*=100})),t.processes=e.data,t.process_error=!1,t.process_detecting=!1,u(0))},error:function(e){400===e.status?(t.processes=[],t.process_error=!0,t.process_detecting=!1,u(0)):i>=10?console.error("The process API can not be invoked successfully."):(console.warn("Retry to call the process API in 1 second."),setTimeout((function(){Pc(t,r,o,i+1)}),1e3))}})}function ac(){(0,Zs.P)("html body, code"),e("#menu-github").click((function(e){e.preventDefault(),tc("https://github.com/magiclen/m-prober")}));var t={last_update_time:"Never",kernel:"",hostname:"",rtc_time:"",uptime:{value:0,text:""},logical_cores:0,load_average:{one:0,five:0,fifteen:0},load_average_scale:{one:0,five:0,fifteen:0},cpu:0,cpus:[],memory:{total:{value:0,text:"0 B"},used:{value:0,text:"0 B"},buffer_cache:{value:0,text:"0 B"}},memory_scale:{used:0,buffer_cache:0},swap:{total:{value:0,text:"0 B"},used:{value:0,text:"0 B"},cache:{value:0,text:"0 B"}},swap_scale:{used:0,cache:0},network:[],volumes:[],process_query:{top:10,user_filter:"",group_filter:"",program_filter:"",tty_filter:"",pid_filter:""},processes:[],process_error:!1,process_detecting:!0};new rr({el:"#monitor",data:t,mounted:function(){var n=this.$el;e("#menu-toggle").click((function(t){t.preventDefault(),e("#wrapper").toggleClass("toggled"),nc(n)})),new rr({el:"#sidebar-wrapper",data:{},mounted:function(){var n=e("#auth-key");n=n.length>0?n.val():void 0;var r=parseInt(e("#time-interval").val());ic(t,n,r),Pc(t,n,r)},methods:{toLinuxInformation:function(){nc(n);var t=e("> #linux-information",n);oc(t),rc(t)},toLoadAverage:function(){nc(n);var t=e("> #load-average",n);oc(t),rc(t)},toCPUs:function(){nc(n);var t=e("> #cpus",n);oc(t),rc(t)},toMemory:function(){nc(n);var t=e("> #memory",n);oc(t),rc(t)},toNetworks:function(){nc(n);var t=e("> #networks",n);oc(t),rc(t)},toVolumes:function(){nc(n);var t=e("> #volumes",n);oc(t),rc(t)},toProcesses:function(){nc(n);var t=e("> #processes",n);oc(t),rc(t)}}})}})}e.fn.pressEnter=function(t){return this.each((function(){e(this).keypress((function(e){13===(e.keyCode?e.keyCode:e.which)&&(t.call(this,e),e.preventDefault())}))}))}})(),r})()));
\ No newline at end of file
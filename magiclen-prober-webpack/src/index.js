@@ -51,9 +51,9 @@ function api(type, url, data, success, error, key) {
                 success(response);
             }
         },
-        error: function () {
-            if (typeof success === "function") {
-                error();
+        error: function (xhr) {
+            if (typeof error === "function") {
+                error(xhr);
             }
         },
     });
@@ -265,6 +265,90 @@ function callMonitorAPI(vueData, authKey = undefined, interval, retryCount = 0)
     }, authKey);
 }
 
+function formatBytes(bytes) {
+    const units = ["B", "KiB", "MiB", "GiB", "TiB", "PiB"];
+
+    let i = 0;
+
+    while (bytes >= 1024 && i < units.length - 1) {
+        bytes /= 1024;
+        ++i;
+    }
+
+    return bytes.toFixed(1) + " " + units[i];
+}
+
+function callProcessAPI(vueData, authKey = undefined, interval, retryCount = 0) {
+    let t = new Date().getTime();
+
+    let query = {};
+
+    for (let field in vueData.process_query) {
+        if (Object.prototype.hasOwnProperty.call(vueData.process_query, field)) {
+            let value = vueData.process_query[field];
+
+            if (value !== "" && value !== null && value !== undefined) {
+                query[field] = value;
+            }
+        }
+    }
+
+    let next = function (retryCount) {
+        let d = new Date().getTime() - t;
+
+        let timeout = 0;
+
+        if (d < interval) {
+            timeout = interval - d;
+        }
+
+        setTimeout(function () {
+            callProcessAPI(vueData, authKey, interval, retryCount);
+        }, timeout);
+    };
+
+    api("GET", "api/process", query, function (data) {
+        if (data.code !== 0) {
+            return;
+        }
+
+        data.data.forEach(function (process) {
+            process.vsz_text = formatBytes(process.vsz);
+            process.rss_text = formatBytes(process.rss);
+            process.anon_text = formatBytes(process.anon);
+            process.start_time_text = new Date(process.start_time).toLocaleString();
+
+            if (typeof process.cpu !== "number") {
+                process.cpu = 0;
+            }
+
+            process.cpu *= 100;
+        });
+
+        vueData.processes = data.data;
+        vueData.process_error = false;
+        vueData.process_detecting = false;
+
+        next(0);
+    }, function (xhr) {
+        if (xhr.status === 400) {
+            vueData.processes = [];
+            vueData.process_error = true;
+            vueData.process_detecting = false;
+
+            next(0);
+        } else if (retryCount >= 10) {
+            console.error("The process API can not be invoked successfully.");
+        } else {
+            console.warn("Retry to call the process API in 1 second.");
+
+            setTimeout(function () {
+                callProcessAPI(vueData, authKey, interval, retryCount + 1);
+            }, 1000);
+        }
+    }, authKey);
+}
+
 export function monitorInit() {
     applyRobotoMono("html body, code");
 
@@ -333,6 +417,17 @@ export function monitorInit() {
         },
         network: [],
         volumes: [],
+        process_query: {
+            top: 10,
+            user_filter: "",
+            group_filter: "",
+            program_filter: "",
+            tty_filter: "",
+            pid_filter: "",
+        },
+        processes: [],
+        process_error: false,
+        process_detecting: true,
     };
 
     new Vue({
@@ -362,6 +457,7 @@ export function monitorInit() {
                     let timeInterval = parseInt($("#time-interval").val());
 
                     callMonitorAPI(monitorData, authKey, timeInterval);
+                    callProcessAPI(monitorData, authKey, timeInterval);
                 },
                 methods: {
                     toLinuxInformation: function () {
@@ -409,6 +505,14 @@ export function monitorInit() {
 
                         let el = $("> #volumes", monitorEL);
 
+                        toTag(el);
+                        addBorderDark(el);
+                    },
+                    toProcesses: function () {
+                        removeCardsBorderDark(monitorEL);
+
+                        let el = $("> #processes", monitorEL);
+
                         toTag(el);
                         addBorderDark(el);
                     },
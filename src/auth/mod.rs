@@ -0,0 +1,370 @@
+mod session;
+
+use std::{fmt, fs, path::Path, str::FromStr};
+
+use anyhow::{anyhow, Context};
+use chrono::{DateTime, Utc};
+use rand::RngCore;
+use serde::Deserialize;
+pub use session::*;
+use sha2::{Digest, Sha256};
+use subtle::ConstantTimeEq;
+
+const HASH_ALGORITHM: &str = "sha256";
+const SALT_LENGTH: usize = 16;
+const KEY_LENGTH: usize = 32;
+/// The length of a generated key in hex. Keys are hashed with a single round of SHA-256, which only resists brute force when the key itself is long and random.
+const MIN_KEY_LENGTH: usize = KEY_LENGTH * 2;
+
+/// What an auth key is allowed to access.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Scope {
+    /// The stats of the system except for processes.
+    ReadBasic,
+    ReadProcess,
+    ReadMetrics,
+    /// Pushing snapshots to `/api/ingest`.
+    WriteIngest,
+    /// Everything.
+    Admin,
+}
+
+impl Scope {
+    #[inline]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Scope::ReadBasic => "read:basic",
+            Scope::ReadProcess => "read:process",
+            Scope::ReadMetrics => "read:metrics",
+            Scope::WriteIngest => "write:ingest",
+            Scope::Admin => "admin",
+        }
+    }
+}
+
+impl FromStr for Scope {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "read:basic" => Ok(Scope::ReadBasic),
+            "read:process" => Ok(Scope::ReadProcess),
+            "read:metrics" => Ok(Scope::ReadMetrics),
+            "write:ingest" => Ok(Scope::WriteIngest),
+            "admin" => Ok(Scope::Admin),
+            _ => Err(anyhow!(
+                "Unknown scope `{s}`. It needs to be read:basic, read:process, read:metrics, \
+                 write:ingest or admin."
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Scope {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A named auth key. Only the salted hash of the key is kept.
+#[derive(Debug, Clone)]
+pub struct AuthKey {
+    pub name:    String,
+    salt:        Vec<u8>,
+    hash:        Vec<u8>,
+    pub scopes:  Vec<Scope>,
+    pub expires: Option<DateTime<Utc>>,
+}
+
+impl AuthKey {
+    /// Hash `key` with a random salt.
+    pub fn new<S: Into<String>>(
+        name: S,
+        key: &str,
+        scopes: Vec<Scope>,
+        expires: Option<DateTime<Utc>>,
+    ) -> AuthKey {
+        let mut salt = vec![0; SALT_LENGTH];
+
+        rand::thread_rng().fill_bytes(&mut salt);
+
+        let hash = hash_key(&salt, key);
+
+        AuthKey {
+            name: name.into(),
+            salt,
+            hash,
+            scopes,
+            expires,
+        }
+    }
+
+    /// Generate a random key which can be used in the `Authorization` header.
+    pub fn generate_key() -> String {
+        let mut key = [0; KEY_LENGTH];
+
+        rand::thread_rng().fill_bytes(&mut key);
+
+        hex::encode(key)
+    }
+
+    /// Check whether `key` is at least as long as a generated key, so that it can be stored in a key file.
+    pub fn check_key(key: &str) -> anyhow::Result<()> {
+        if key.chars().count() < MIN_KEY_LENGTH {
+            return Err(anyhow!(
+                "The key needs to have at least {MIN_KEY_LENGTH} characters. Omit `--key` to \
+                 generate a random one."
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Whether `key` is this key. The hashes are compared in constant time.
+    #[inline]
+    fn matches(&self, key: &str) -> bool {
+        hash_key(&self.salt, key).ct_eq(&self.hash).into()
+    }
+
+    #[inline]
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires.map(|expires| expires <= now).unwrap_or(false)
+    }
+
+    /// Whether this key is allowed to access something which needs `scope`. The `admin` scope allows everything.
+    #[inline]
+    pub fn allows(&self, scope: Scope) -> bool {
+        self.scopes.iter().any(|s| *s == scope || *s == Scope::Admin)
+    }
+
+    /// The `sha256$<SALT>$<HASH>` string stored in key files.
+    #[inline]
+    pub fn hash_string(&self) -> String {
+        format!("{HASH_ALGORITHM}${}${}", hex::encode(&self.salt), hex::encode(&self.hash))
+    }
+
+    /// Format this key as an entry of key files.
+    pub fn to_toml(&self) -> String {
+        let mut output = String::from("[[keys]]\n");
+
+        output.push_str(&format!("name = {}\n", toml::Value::String(self.name.clone())));
+        output.push_str(&format!("hash = \"{}\"\n", self.hash_string()));
+        output.push_str(&format!(
+            "scopes = [{}]\n",
+            self.scopes.iter().map(|scope| format!("\"{scope}\"")).collect::<Vec<_>>().join(", ")
+        ));
+
+        if let Some(expires) = self.expires {
+            output.push_str(&format!("expires = {}\n", expires.to_rfc3339()));
+        }
+
+        output
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct KeyFile {
+    #[serde(default)]
+    keys: Vec<KeyEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct KeyEntry {
+    name:    String,
+    hash:    String,
+    scopes:  Vec<String>,
+    expires: Option<toml::value::Datetime>,
+}
+
+impl TryFrom<KeyEntry> for AuthKey {
+    type Error = anyhow::Error;
+
+    fn try_from(entry: KeyEntry) -> Result<Self, Self::Error> {
+        let name = entry.name;
+
+        let mut parts = entry.hash.split('$');
+
+        let (salt, hash) = match (parts.next(), parts.next(), parts.next(), parts.next()) {
+            (Some(HASH_ALGORITHM), Some(salt), Some(hash), None) => (
+                hex::decode(salt).ok().filter(|salt| !salt.is_empty()),
+                hex::decode(hash).ok().filter(|hash| hash.len() == Sha256::output_size()),
+            ),
+            _ => (None, None),
+        };
+
+        let (salt, hash) = match (salt, hash) {
+            (Some(salt), Some(hash)) => (salt, hash),
+            _ => {
+                return Err(anyhow!(
+                    "The hash of the key `{name}` needs to be in the form of \
+                     `{HASH_ALGORITHM}$<SALT>$<HASH>`."
+                ))
+            },
+        };
+
+        let scopes = entry
+            .scopes
+            .iter()
+            .map(|scope| scope.parse())
+            .collect::<anyhow::Result<Vec<Scope>>>()
+            .with_context(|| format!("The key `{name}` has an invalid scope."))?;
+
+        let expires = match entry.expires {
+            Some(expires) => Some(
+                DateTime::parse_from_rfc3339(&expires.to_string())
+                    .map_err(|_| {
+                        anyhow!(
+                            "The expiry of the key `{name}` needs to be a date-time with a time \
+                             zone offset."
+                        )
+                    })?
+                    .with_timezone(&Utc),
+            ),
+            None => None,
+        };
+
+        Ok(AuthKey {
+            name,
+            salt,
+            hash,
+            scopes,
+            expires,
+        })
+    }
+}
+
+/// The auth keys accepted by the HTTP service. If there are no keys, no authorization is needed.
+#[derive(Debug, Clone, Default)]
+pub struct AuthKeys(Vec<AuthKey>);
+
+impl AuthKeys {
+    #[cfg(test)]
+    #[inline]
+    pub fn new(keys: Vec<AuthKey>) -> AuthKeys {
+        AuthKeys(keys)
+    }
+
+    /// Load a TOML file made of `[[keys]]` tables which have `name`, `hash`, `scopes` and an optional `expires`.
+    pub fn load(path: &Path) -> anyhow::Result<AuthKeys> {
+        let content = fs::read_to_string(path).with_context(|| format!("Cannot read {path:?}."))?;
+
+        let key_file: KeyFile = toml::from_str(&content)
+            .with_context(|| format!("{path:?} is not a valid key file."))?;
+
+        let mut auth_keys = AuthKeys::default();
+
+        for entry in key_file.keys {
+            AuthKey::try_from(entry)
+                .and_then(|auth_key| auth_keys.push(auth_key))
+                .with_context(|| format!("{path:?} is not a valid key file."))?;
+        }
+
+        Ok(auth_keys)
+    }
+
+    /// Add a key. The names of keys need to be unique because sessions refer to keys by their names.
+    pub fn push(&mut self, key: AuthKey) -> anyhow::Result<()> {
+        if self.0.iter().any(|auth_key| auth_key.name == key.name) {
+            return Err(anyhow!("The name of the key `{}` is duplicated.", key.name));
+        }
+
+        self.0.push(key);
+
+        Ok(())
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Find the unexpired key named `name`.
+    #[inline]
+    pub fn find(&self, name: &str, now: DateTime<Utc>) -> Option<&AuthKey> {
+        self.0.iter().find(|auth_key| auth_key.name == name && !auth_key.is_expired(now))
+    }
+
+    /// Find the unexpired key which is `key`. Every key is checked so that the time taken does not depend on which key matches.
+    pub fn verify(&self, key: &str, now: DateTime<Utc>) -> Option<&AuthKey> {
+        self.0
+            .iter()
+            .fold(None, |found, auth_key| match auth_key.matches(key) {
+                true => found.or(Some(auth_key)),
+                false => found,
+            })
+            .filter(|auth_key| !auth_key.is_expired(now))
+    }
+}
+
+#[inline]
+fn hash_key(salt: &[u8], key: &str) -> Vec<u8> {
+    let mut hasher = Sha256::new();
+
+    hasher.update(salt);
+    hasher.update(key.as_bytes());
+
+    hasher.finalize().to_vec()
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::Duration;
+
+    use super::*;
+
+    #[test]
+    fn test_verify() {
+        let now = Utc::now();
+
+        let keys = AuthKeys::new(vec![
+            AuthKey::new("dashboard", "magic", vec![Scope::ReadBasic], None),
+            AuthKey::new("old", "expired", vec![Scope::Admin], Some(now - Duration::hours(1))),
+        ]);
+
+        let key = keys.verify("magic", now).unwrap();
+
+        assert_eq!("dashboard", key.name);
+        assert!(key.allows(Scope::ReadBasic));
+        assert!(!key.allows(Scope::ReadProcess));
+
+        assert!(keys.verify("magic!", now).is_none());
+        assert!(keys.verify("expired", now).is_none());
+    }
+
+    #[test]
+    fn test_check_key() {
+        assert!(AuthKey::check_key(&AuthKey::generate_key()).is_ok());
+        assert!(AuthKey::check_key("magic").is_err());
+        assert!(AuthKey::check_key(&"a".repeat(MIN_KEY_LENGTH - 1)).is_err());
+    }
+
+    #[test]
+    fn test_key_file() {
+        let key = AuthKey::new(
+            "ops",
+            "magic",
+            vec![Scope::ReadMetrics, Scope::Admin],
+            Some("2100-01-01T00:00:00Z".parse().unwrap()),
+        );
+
+        let key_file: KeyFile = toml::from_str(&key.to_toml()).unwrap();
+
+        let keys = AuthKeys::new(
+            key_file.keys.into_iter().map(|entry| AuthKey::try_from(entry).unwrap()).collect(),
+        );
+
+        let key = keys.verify("magic", Utc::now()).unwrap();
+
+        assert_eq!("ops", key.name);
+        assert!(key.allows(Scope::ReadProcess));
+        assert!(key.expires.is_some());
+
+        assert!(toml::from_str::<KeyFile>("[[keys]]\nname = \"a\"\nhash = \"magic\"\nscopes = []")
+            .map_err(anyhow::Error::from)
+            .and_then(|key_file| AuthKey::try_from(key_file.keys.into_iter().next().unwrap()))
+            .is_err());
+    }
+}
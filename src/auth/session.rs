@@ -0,0 +1,193 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use rand::RngCore;
+use sha2::Sha256;
+use subtle::ConstantTimeEq;
+
+/// The cookie which carries the session of the web page.
+pub const SESSION_COOKIE_NAME: &str = "mprober_session";
+/// The header which requests authorized by a session cookie need to carry the CSRF token in, unless they are safe (GET or HEAD).
+pub const CSRF_TOKEN_HEADER_NAME: &str = "X-CSRF-Token";
+
+const SECRET_LENGTH: usize = 32;
+const CSRF_TOKEN_LENGTH: usize = 16;
+
+/// A login session of the web page.
+#[derive(Debug, Clone)]
+pub struct Session {
+    /// The name of the auth key which was used to log in.
+    pub key_name:   String,
+    pub expires:    DateTime<Utc>,
+    /// The token which forms posted by the web page need to carry.
+    pub csrf_token: String,
+}
+
+/// Issue and verify session cookies, which are signed by a secret generated at startup. Sessions are invalidated when the program restarts, or one by one when they are revoked by logging out.
+#[derive(Debug, Clone)]
+pub struct Sessions {
+    secret:  Vec<u8>,
+    ttl:     Duration,
+    /// The CSRF tokens of the revoked sessions which have not expired yet, with their expiry. The token is random for every session, so it identifies the session.
+    revoked: Arc<Mutex<HashMap<String, DateTime<Utc>>>>,
+}
+
+impl Sessions {
+    pub fn new(ttl: Duration) -> Sessions {
+        Sessions {
+            secret: random_bytes(SECRET_LENGTH),
+            ttl,
+            revoked: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    #[inline]
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Make a new session for the key named `key_name` and the value of its cookie.
+    pub fn issue(&self, key_name: &str, now: DateTime<Utc>) -> (Session, String) {
+        let session = Session {
+            key_name:   key_name.to_string(),
+            expires:    now + chrono::Duration::from_std(self.ttl).unwrap_or(chrono::Duration::MAX),
+            csrf_token: hex::encode(random_bytes(CSRF_TOKEN_LENGTH)),
+        };
+
+        let payload = format!(
+            "{}.{}.{}",
+            hex::encode(&session.key_name),
+            session.expires.timestamp(),
+            session.csrf_token
+        );
+
+        let signature = hex::encode(self.mac(&payload).finalize().into_bytes());
+
+        (session, format!("{payload}.{signature}"))
+    }
+
+    /// Make `session` unusable even if its cookie is kept somewhere. Revoked sessions are forgotten after they expire.
+    pub fn revoke(&self, session: &Session, now: DateTime<Utc>) {
+        let mut revoked = self.revoked.lock().unwrap();
+
+        revoked.retain(|_, expires| *expires > now);
+
+        revoked.insert(session.csrf_token.clone(), session.expires);
+    }
+
+    /// Check the signature, the expiry and the revocation of the value of a session cookie.
+    pub fn verify(&self, value: &str, now: DateTime<Utc>) -> Option<Session> {
+        let (payload, signature) = value.rsplit_once('.')?;
+
+        self.mac(payload).verify_slice(&hex::decode(signature).ok()?).ok()?;
+
+        let mut parts = payload.split('.');
+
+        let key_name = String::from_utf8(hex::decode(parts.next()?).ok()?).ok()?;
+        let expires = DateTime::from_timestamp(parts.next()?.parse().ok()?, 0)?;
+        let csrf_token = parts.next()?.to_string();
+
+        if parts.next().is_some() || expires <= now {
+            return None;
+        }
+
+        if self.revoked.lock().unwrap().contains_key(&csrf_token) {
+            return None;
+        }
+
+        Some(Session {
+            key_name,
+            expires,
+            csrf_token,
+        })
+    }
+
+    #[inline]
+    fn mac(&self, payload: &str) -> Hmac<Sha256> {
+        let mut mac = Hmac::<Sha256>::new_from_slice(&self.secret).unwrap();
+
+        mac.update(payload.as_bytes());
+
+        mac
+    }
+}
+
+/// Make a random token, used to protect the login form against CSRF.
+#[inline]
+pub fn generate_csrf_token() -> String {
+    hex::encode(random_bytes(CSRF_TOKEN_LENGTH))
+}
+
+/// Check a CSRF token sent by a client against the expected one in constant time.
+#[inline]
+pub fn csrf_token_matches(expected: &str, token: &str) -> bool {
+    expected.as_bytes().ct_eq(token.as_bytes()).into()
+}
+
+#[inline]
+fn random_bytes(length: usize) -> Vec<u8> {
+    let mut bytes = vec![0; length];
+
+    rand::thread_rng().fill_bytes(&mut bytes);
+
+    bytes
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_verify() {
+        let sessions = Sessions::new(Duration::from_secs(60));
+
+        let now = Utc::now();
+
+        let (session, value) = sessions.issue("dash.board", now);
+
+        let verified = sessions.verify(&value, now).unwrap();
+
+        assert_eq!("dash.board", verified.key_name);
+        assert_eq!(session.csrf_token, verified.csrf_token);
+        assert!(csrf_token_matches(&session.csrf_token, &verified.csrf_token));
+        assert!(!csrf_token_matches(&session.csrf_token, &session.csrf_token[1..]));
+
+        // expired
+        assert!(sessions.verify(&value, now + chrono::Duration::seconds(61)).is_none());
+
+        // signed by another secret
+        assert!(Sessions::new(Duration::from_secs(60)).verify(&value, now).is_none());
+
+        // tampered
+        let tampered = value.replacen(&hex::encode("dash.board"), &hex::encode("admin"), 1);
+
+        assert!(sessions.verify(&tampered, now).is_none());
+    }
+
+    #[test]
+    fn test_revoke() {
+        let sessions = Sessions::new(Duration::from_secs(60));
+
+        let now = Utc::now();
+
+        let (session, value) = sessions.issue("dashboard", now);
+        let (_, other) = sessions.issue("dashboard", now);
+
+        // clones share the revoked sessions
+        sessions.clone().revoke(&session, now);
+
+        assert!(sessions.verify(&value, now).is_none());
+        assert!(sessions.verify(&other, now).is_some());
+
+        // forgotten after expiring
+        sessions
+            .revoke(&sessions.verify(&other, now).unwrap(), now + chrono::Duration::seconds(61));
+
+        assert_eq!(1, sessions.revoked.lock().unwrap().len());
+    }
+}
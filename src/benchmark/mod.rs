@@ -18,7 +18,6 @@ use rand::{self, Rng};
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum BenchmarkLog {
-    #[allow(unused)]
     None,
     Normal,
     Verbose,
@@ -534,23 +533,24 @@ pub fn run_benchmark(config: &BenchmarkConfig) -> Result<BenchmarkResult, Benchm
                                                                                     .device
                                                                                     .clone();
 
-                                                                                let s_len = s.len();
-
-                                                                                for _ in s_len..19 {
+                                                                                for _ in s.len()..19
+                                                                                {
                                                                                     s.push(' ');
                                                                                 }
 
                                                                                 println!("{s}: Read {read_result_string}/s, Write {write_result_string}/s");
-
-                                                                                unsafe {
-                                                                                    s.as_mut_vec()
-                                                                                        .set_len(
-                                                                                            s_len,
-                                                                                        )
-                                                                                };
-
-                                                                                volumes_result.insert(s, (read_result, write_result));
                                                                             }
+
+                                                                            // kept even when nothing is printed, e.g. with `--output json`
+                                                                            volumes_result.insert(
+                                                                                volume
+                                                                                    .device
+                                                                                    .clone(),
+                                                                                (
+                                                                                    read_result,
+                                                                                    write_result,
+                                                                                ),
+                                                                            );
                                                                         } else if config
                                                                             .print_out
                                                                             .has_stderr()
@@ -1,16 +1,29 @@
 use std::{
+    env,
     net::{AddrParseError, IpAddr},
     num::ParseIntError,
+    path::PathBuf,
     str::FromStr,
     time::Duration,
 };
 
+use anyhow::anyhow;
 use byte_unit::{Unit, UnitParseError};
-use clap::{CommandFactory, FromArgMatches, Parser, Subcommand};
+use chrono::{DateTime, Utc};
+use clap::{Command, CommandFactory, FromArgMatches, Parser, Subcommand, ValueEnum};
 use concat_with::concat_line;
 use regex::Regex;
 use terminal_size::terminal_size;
 
+use crate::{
+    auth::{AuthKey, Scope},
+    config::Config,
+    export::{ExportFormat, ExportTarget},
+    listener::SocketOwner,
+    rocket_mounts::{Agent, BasePath},
+    rules::AlertRule,
+};
+
 const APP_NAME: &str = "M Prober";
 const CARGO_PKG_VERSION: &str = env!("CARGO_PKG_VERSION");
 const CARGO_PKG_AUTHORS: &str = env!("CARGO_PKG_AUTHORS");
@@ -68,15 +81,45 @@ const APP_ABOUT: &str = concat!(
         "process --group-filter gp1    # Show a snapshot of the current processes which are related to a specific group",
         "process --tty-filter tty      # Show a snapshot of the current processes which are related to specific tty names matched by a regex",
         "process --program-filter ab   # Show a snapshot of the current processes which are related to specific program names or commands matched by a regex",
+        "--output json cpu -s          # Show load average and current stats of CPU cores separately in JSON",
+        "--output ndjson memory -m     # Show memory stats every second, one JSON object per line",
         "web                           # Start a HTTP service on port 8000 to monitor this computer. The default time interval is 3 seconds",
         "web -m 2                      # Start a HTTP service on port 8000 to monitor this computer. The time interval is set to 2 seconds",
         "web -p 7777                   # Start a HTTP service on port 7777 to monitor this computer",
         "web --addr 127.0.0.1          # Start a HTTP service on 127.0.0.1:8000 to monitor this computer",
+        "web --base-path /hosts/db1    # Start a HTTP service on port 8000 to monitor this computer. The web page and the APIs are under /hosts/db1",
         "web -a auth_key               # Start a HTTP service on port 8000 to monitor this computer. APIs need to be invoked with an auth key",
+        "web --auth-key-file keys.toml # Start a HTTP service on port 8000 to monitor this computer. APIs need to be invoked with an auth key in keys.toml which has the needed scope",
         "web --only-api                # Start a HTTP service on port 8000 to serve only HTTP APIs",
+        "web --history 10m             # Start a HTTP service on port 8000 to monitor this computer. Stats in the last 10 minutes can be queried",
+        "web --tls-cert a.pem --tls-key a.key # Start a HTTPS service on port 8000 to monitor this computer. Send SIGHUP to reload the certificate",
+        "web --tls-self-signed         # Start a HTTPS service on port 8000 to monitor this computer with a self-signed certificate",
+        "web --unix-socket /run/mprober.sock --unix-socket-mode 660 # Start a HTTP service on a Unix domain socket which only its owner and group can connect to",
+        "check -w 'cpu>80' -c 'cpu>90' # Exit with 1 (WARNING) if the CPU usage is over 80%, or 2 (CRITICAL) if it is over 90%. Print a status line with perfdata",
+        "check -c 'volume[/].used%>90' # Exit with 2 (CRITICAL) if more than 90% of the root volume is used",
+        "check -w 'load.five>2*cores'  # Exit with 1 (WARNING) if the 5-minute load average is over twice the number of logical CPU cores",
+        "record -o a.ndjson            # Append a snapshot of CPU, memory, network, volume and top-10 process stats to a.ndjson every second",
+        "record -o a.ndjson -i 500     # Append a snapshot to a.ndjson every 500 milliseconds",
+        "replay a.ndjson               # Show the snapshots in a.ndjson at the pace they were taken",
+        "replay a.ndjson --view cpu    # Show only CPU stats in a.ndjson",
+        "replay a.ndjson --speed 0     # Show all snapshots in a.ndjson without waiting",
+        "web --alert 'cpu>90 for 2m' --webhook http://127.0.0.1:9000/alerts # Start a HTTP service on port 8000 to monitor this computer. POST a notification if the CPU usage is over 90% for 2 minutes",
+        "hub --agent db1=http://10.0.0.1:8000 --agent db2=http://10.0.0.2:8000 # Start a HTTP service on port 8000 to monitor two computers running the web command",
+        "hub --agent-file agents.toml  # Start a HTTP service on port 8000 to monitor the computers in agents.toml",
+        "push --to http://10.0.0.100:8000 -a auth_key # Push a snapshot of this computer to the hub or web command on 10.0.0.100:8000 every 10 seconds. Snapshots are buffered while it is down",
+        "push --to http://10.0.0.100:8000 -i 1m --host db1 # Push a snapshot of this computer named db1 to 10.0.0.100:8000 every minute",
+        "export                        # Print stats of this computer in the InfluxDB line protocol every 10 seconds",
+        "export --to udp://127.0.0.1:8089 -i 1m # Send stats of this computer to the UDP listener of InfluxDB or Telegraf every minute",
+        "export --to 'http://127.0.0.1:8086/write?db=mprober' # POST stats of this computer to InfluxDB every 10 seconds",
+        "export -f graphite --to tcp://127.0.0.1:2003 --prefix servers # Send stats of this computer to Graphite every 10 seconds, as servers.<HOST>.*",
+        "export --statsd 127.0.0.1:8125 # Send gauges of load average, CPU, memory, network and volume stats of this computer to a StatsD agent every 10 seconds",
+        "export --statsd 127.0.0.1:8125 --dogstatsd --tag env:prod # Send gauges with DogStatsD tags to a DogStatsD agent every 10 seconds",
+        "auth-key grafana -s read:metrics >> keys.toml # Make a random auth key which can only access the metrics, and append its hash to keys.toml",
         "benchmark                     # Run benchmarks",
         "benchmark --disable-cpu       # Run benchmarks except for benchmarking CPU",
         "benchmark --enable-memory     # Benchmark the memory",
+        "config show                   # Show the effective configuration, merged from /etc/mprober.toml and ~/.config/mprober/config.toml",
+        "--config a.toml web           # Start a HTTP service with the default values of options supplied by a.toml",
     )
 );
 
@@ -87,10 +130,103 @@ const APP_ABOUT: &str = concat!(
 #[command(author = CARGO_PKG_AUTHORS)]
 #[command(after_help = AFTER_HELP)]
 pub struct CLIArgs {
+    #[arg(long, global = true, value_enum)]
+    #[arg(default_value = "text")]
+    #[arg(help = "Assign the output format. `ndjson` prints one JSON object per refresh in the \
+                  monitor mode")]
+    pub output:  OutputFormat,
+    #[arg(long, global = true, value_name = "FILE")]
+    #[arg(help = "Assign a TOML config file which supplies the default values of options. If it \
+                  is not assigned, /etc/mprober.toml and ~/.config/mprober/config.toml are \
+                  loaded if they exist")]
+    pub config:  Option<PathBuf>,
     #[command(subcommand)]
     pub command: CLICommands,
 }
 
+#[derive(Debug, Clone, Copy, Eq, PartialEq, ValueEnum)]
+pub enum OutputFormat {
+    Text,
+    Json,
+    Ndjson,
+}
+
+impl OutputFormat {
+    #[inline]
+    pub fn is_json(self) -> bool {
+        !matches!(self, OutputFormat::Text)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, ValueEnum)]
+pub enum ReplayView {
+    Cpu,
+    Memory,
+    Network,
+    Volume,
+    Process,
+}
+
+/// The key to sort processes. Sizes, counts and CPU usage are sorted in descending order, and the others in ascending order.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, ValueEnum)]
+pub enum ProcessSort {
+    /// CPU usage, and then virtual memory size
+    Cpu,
+    /// Resident set size
+    Rss,
+    /// Virtual memory size
+    Vsz,
+    /// Anonymous resident set size
+    Anon,
+    /// Process ID
+    Pid,
+    /// User name
+    User,
+    /// Start time, the oldest first
+    Start,
+    /// CPU time consumed since the process started
+    Time,
+    /// Number of threads
+    Threads,
+    /// Program name
+    Name,
+}
+
+impl ProcessSort {
+    #[inline]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ProcessSort::Cpu => "cpu",
+            ProcessSort::Rss => "rss",
+            ProcessSort::Vsz => "vsz",
+            ProcessSort::Anon => "anon",
+            ProcessSort::Pid => "pid",
+            ProcessSort::User => "user",
+            ProcessSort::Start => "start",
+            ProcessSort::Time => "time",
+            ProcessSort::Threads => "threads",
+            ProcessSort::Name => "name",
+        }
+    }
+
+    /// The next key, which is used to cycle the keys in the monitor mode.
+    #[inline]
+    pub fn next(self) -> ProcessSort {
+        let variants = ProcessSort::value_variants();
+
+        let index = variants.iter().position(|sort| *sort == self).unwrap();
+
+        variants[(index + 1) % variants.len()]
+    }
+}
+
+#[derive(Debug, Subcommand)]
+pub enum ConfigCommands {
+    #[command(about = "Print the effective configuration")]
+    #[command(after_help = AFTER_HELP)]
+    Show,
+}
+
 #[derive(Debug, Subcommand)]
 pub enum CLICommands {
     #[command(aliases = ["h", "host", "name", "servername"])]
@@ -245,6 +381,14 @@ pub enum CLICommands {
         #[arg(long, value_name = "MAX_NUMBER_OF_PROCESSES")]
         #[arg(help = "Sets the max number of processes shown on the screen")]
         top:              Option<usize>,
+        #[arg(long, value_enum, value_name = "KEY")]
+        #[arg(default_value = "cpu")]
+        #[arg(help = "Sort processes by a specific key. In the monitor mode, press 's' to cycle \
+                      the keys")]
+        sort:             ProcessSort,
+        #[arg(short, long)]
+        #[arg(help = "Reverse the order of processes. In the monitor mode, press 'r' to toggle it")]
+        reverse:          bool,
         #[arg(long, value_name = "LENGTH")]
         #[arg(default_value = "7")]
         #[arg(help = "Truncate the user name, the group name and the program name of processes. \
@@ -253,6 +397,10 @@ pub enum CLICommands {
         #[arg(short = 't', long)]
         #[arg(help = "Show when the progresses start")]
         start_time:       bool,
+        #[arg(long, alias = "forest")]
+        #[arg(help = "Show processes as a tree in which child processes are nested under their \
+                      parents")]
+        tree:             bool,
         #[arg(long, alias = "filter-user", value_name = "USER_NAME")]
         #[arg(help = "Show only processes which are related to a specific user")]
         user_filter:      Option<String>,
@@ -280,23 +428,345 @@ pub enum CLICommands {
         #[arg(default_value = "3")]
         #[arg(value_parser = parse_duration_sec)]
         #[arg(help = "Automatically refresh every N seconds")]
-        monitor:     Duration,
+        monitor:           Duration,
         #[arg(long, visible_alias = "addr")]
         #[cfg_attr(debug_assertions, arg(default_value = "127.0.0.1"))]
         #[cfg_attr(not(debug_assertions), arg(default_value = "0.0.0.0"))]
         #[arg(value_parser = parse_ip_addr)]
         #[arg(help = "Assign the address that M Prober binds")]
-        address:     IpAddr,
+        address:           IpAddr,
         #[arg(short = 'p', long, visible_alias = "port")]
         #[arg(default_value = "8000")]
         #[arg(help = "Assign a TCP port for the HTTP service")]
-        listen_port: u16,
+        listen_port:       u16,
+        #[arg(long, value_name = "PATH")]
+        #[arg(default_value = "/")]
+        #[arg(value_parser = parse_base_path)]
+        #[arg(help = "Assign the path which the web page and the APIs are served under (e.g. \
+                      /hosts/db1) when M Prober is behind a reverse proxy")]
+        base_path:         BasePath,
         #[arg(short, long)]
-        #[arg(help = "Assign an auth key")]
-        auth_key:    Option<String>,
+        #[arg(hide_default_value = true)]
+        #[arg(help = "Assign an auth key which is allowed to access everything. The web page \
+                      asks for a key to log in")]
+        auth_key:          Option<String>,
+        #[arg(long, value_name = "FILE")]
+        #[arg(help = "Assign a TOML file of named auth keys with scopes. See the auth-key \
+                      command to make a key")]
+        auth_key_file:     Option<PathBuf>,
+        #[arg(long, value_name = "DURATION")]
+        #[arg(default_value = "1h")]
+        #[arg(value_parser = parse_human_duration)]
+        #[arg(help = "Assign how long a login session of the web page lasts (e.g. 30m, 1h)")]
+        session_ttl:       Duration,
         #[arg(long, aliases = ["only-apis"])]
         #[arg(help = "Disable the web page")]
-        only_api:    bool,
+        only_api:          bool,
+        #[arg(long, value_name = "DURATION")]
+        #[arg(default_value = "1h")]
+        #[arg(value_parser = parse_human_duration)]
+        #[arg(help = "Keep the stats within a period of time in memory for the history API \
+                      (e.g. 30m, 1h). Set to 0s to disable")]
+        history:           Duration,
+        #[arg(long, value_name = "RULE")]
+        #[arg(value_parser = parse_alert_rule)]
+        #[arg(help = "Add an alert rule like 'cpu>90 for 2m', which fires if it is met for a \
+                      while and resolves once it is not met, or once it has not been met for a \
+                      while with 'recover after 5m'. See the check command for the rule syntax")]
+        alert:             Vec<AlertRule>,
+        #[arg(long, value_name = "URL")]
+        #[arg(hide_default_value = true)]
+        #[arg(help = "POST a JSON notification to a URL when an alert fires or resolves")]
+        webhook:           Option<String>,
+        #[arg(long, value_name = "FILE")]
+        #[arg(help = "Assign a PEM certificate chain to serve HTTPS. Send SIGHUP to reload the \
+                      certificate chain and the private key")]
+        tls_cert:          Option<PathBuf>,
+        #[arg(long, value_name = "FILE")]
+        #[arg(help = "Assign the PEM private key of the certificate chain")]
+        tls_key:           Option<PathBuf>,
+        #[arg(long)]
+        #[arg(conflicts_with_all = ["tls_cert", "tls_key"])]
+        #[arg(help = "Serve HTTPS with a self-signed certificate generated at startup")]
+        tls_self_signed:   bool,
+        #[arg(long, value_name = "PATH")]
+        #[arg(help = "Listen on a Unix domain socket instead of the address and the port")]
+        unix_socket:       Option<PathBuf>,
+        #[arg(long, value_name = "MODE")]
+        #[arg(requires = "unix_socket")]
+        #[arg(value_parser = parse_file_mode)]
+        #[arg(help = "Assign the permissions of the Unix domain socket in octal (e.g. 660)")]
+        unix_socket_mode:  Option<u32>,
+        #[arg(long, value_name = "USER[:GROUP]")]
+        #[arg(requires = "unix_socket")]
+        #[arg(value_parser = parse_socket_owner)]
+        #[arg(help = "Assign the owner and the group of the Unix domain socket")]
+        unix_socket_owner: Option<SocketOwner>,
+    },
+    #[command(about = "Start a HTTP service to monitor the computers running the web command")]
+    #[command(after_help = AFTER_HELP)]
+    Hub {
+        #[arg(short, long, value_name = "SECONDS")]
+        #[arg(default_value = "3")]
+        #[arg(value_parser = parse_duration_sec)]
+        #[arg(help = "Poll the agents every N seconds")]
+        monitor:       Duration,
+        #[arg(long, value_name = "DURATION")]
+        #[arg(default_value = "5s")]
+        #[arg(value_parser = parse_human_duration)]
+        #[arg(help = "Assign how long to wait for an agent before it is regarded as unreachable")]
+        timeout:       Duration,
+        #[arg(long, value_name = "[NAME=]URL")]
+        #[arg(hide_default_value = true)]
+        #[arg(value_parser = parse_agent)]
+        #[arg(help = "Add an agent, which is the URL of a web command. The name defaults to the \
+                      host of the URL")]
+        agent:         Vec<Agent>,
+        #[arg(long, value_name = "FILE")]
+        #[arg(help = "Assign a TOML file of agents with names, URLs and auth keys")]
+        agent_file:    Option<PathBuf>,
+        #[arg(long, visible_alias = "addr")]
+        #[cfg_attr(debug_assertions, arg(default_value = "127.0.0.1"))]
+        #[cfg_attr(not(debug_assertions), arg(default_value = "0.0.0.0"))]
+        #[arg(value_parser = parse_ip_addr)]
+        #[arg(help = "Assign the address that M Prober binds")]
+        address:       IpAddr,
+        #[arg(short = 'p', long, visible_alias = "port")]
+        #[arg(default_value = "8000")]
+        #[arg(help = "Assign a TCP port for the HTTP service")]
+        listen_port:   u16,
+        #[arg(long, value_name = "PATH")]
+        #[arg(default_value = "/")]
+        #[arg(value_parser = parse_base_path)]
+        #[arg(help = "Assign the path which the dashboard and the APIs are served under")]
+        base_path:     BasePath,
+        #[arg(short, long)]
+        #[arg(hide_default_value = true)]
+        #[arg(help = "Assign an auth key which is allowed to access everything. The dashboard \
+                      asks for a key to log in")]
+        auth_key:      Option<String>,
+        #[arg(long, value_name = "FILE")]
+        #[arg(help = "Assign a TOML file of named auth keys with scopes. See the auth-key \
+                      command to make a key")]
+        auth_key_file: Option<PathBuf>,
+        #[arg(long, value_name = "DURATION")]
+        #[arg(default_value = "1h")]
+        #[arg(value_parser = parse_human_duration)]
+        #[arg(help = "Assign how long a login session of the dashboard lasts (e.g. 30m, 1h)")]
+        session_ttl:   Duration,
+    },
+    #[command(about = "Push snapshots of this computer to the web or hub command of a central \
+                       collector periodically")]
+    #[command(after_help = AFTER_HELP)]
+    Push {
+        #[arg(long, value_name = "URL")]
+        #[arg(value_parser = parse_push_url)]
+        #[arg(help = "Assign the URL of the collector, which is a web or hub command")]
+        to:       String,
+        #[arg(short, long, value_name = "DURATION")]
+        #[arg(default_value = "10s")]
+        #[arg(value_parser = parse_push_interval)]
+        #[arg(help = "Push a snapshot every DURATION (e.g. 10s, 1m)")]
+        interval: Duration,
+        #[arg(short, long)]
+        #[arg(hide_default_value = true)]
+        #[arg(help = "Assign the auth key of the collector, which needs the write:ingest scope")]
+        auth_key: Option<String>,
+        #[arg(long, value_name = "NAME")]
+        #[arg(help = "Assign the name of this computer on the collector. The hostname is used \
+                      by default")]
+        host:     Option<String>,
+        #[arg(long, value_name = "NUMBER_OF_SNAPSHOTS")]
+        #[arg(default_value = "360")]
+        #[arg(
+            help = "Keep at most N snapshots which are not pushed yet while the collector is down"
+        )]
+        buffer:   usize,
+        #[arg(long, value_name = "DURATION")]
+        #[arg(default_value = "10s")]
+        #[arg(value_parser = parse_human_duration)]
+        #[arg(help = "Assign how long to wait for the collector before retrying later")]
+        timeout:  Duration,
+    },
+    #[command(about = "Export stats of this computer in the InfluxDB line protocol or the \
+                       Graphite plaintext protocol periodically")]
+    #[command(after_help = AFTER_HELP)]
+    Export {
+        #[arg(short, long)]
+        #[arg(value_enum, default_value_t = ExportFormat::Influx)]
+        #[arg(help = "Assign the format of metrics")]
+        format:        ExportFormat,
+        #[arg(long, value_name = "TARGET")]
+        #[arg(default_value = "-")]
+        #[arg(value_parser = parse_export_target)]
+        #[arg(help = "Assign where metrics are written to. It can be `-` (stdout), a file, \
+                      `udp://HOST:PORT`, `tcp://HOST:PORT` or an HTTP URL which metrics are \
+                      POSTed to")]
+        to:            ExportTarget,
+        #[arg(long, value_name = "HOST:PORT")]
+        #[arg(conflicts_with_all = ["format", "to"])]
+        #[arg(help = "Send gauges to a StatsD agent. It is the same as `-f statsd --to \
+                      udp://HOST:PORT`")]
+        statsd:        Option<String>,
+        #[arg(long)]
+        #[arg(help = "Send StatsD gauges with DogStatsD tags instead of putting the host and \
+                      the tags into the names of gauges")]
+        dogstatsd:     bool,
+        #[arg(long, value_name = "KEY:VALUE")]
+        #[arg(requires = "dogstatsd")]
+        #[arg(help = "Add a DogStatsD tag to every gauge")]
+        tag:           Vec<String>,
+        #[arg(short, long, value_name = "DURATION")]
+        #[arg(default_value = "10s")]
+        #[arg(value_parser = parse_push_interval)]
+        #[arg(help = "Export metrics every DURATION (e.g. 10s, 1m)")]
+        interval:      Duration,
+        #[arg(long, value_name = "PREFIX")]
+        #[arg(default_value = "mprober")]
+        #[arg(help = "Assign the prefix of measurements (InfluxDB) or metric paths (Graphite). \
+                      It can be empty")]
+        prefix:        String,
+        #[arg(long, value_name = "NAME")]
+        #[arg(help = "Assign the host tag of metrics. The hostname is used by default")]
+        host:          Option<String>,
+        #[arg(long, value_name = "VALUE")]
+        #[arg(hide_default_value = true)]
+        #[arg(help = "Assign the Authorization header for an HTTP target (e.g. \"Token \
+                      <TOKEN>\" for InfluxDB 2)")]
+        authorization: Option<String>,
+        #[arg(long, value_name = "DURATION")]
+        #[arg(default_value = "5s")]
+        #[arg(value_parser = parse_human_duration)]
+        #[arg(help = "Assign how long to wait for a TCP or HTTP target")]
+        timeout:       Duration,
+        #[arg(short = 'n', long, value_name = "NUMBER_OF_EXPORTS")]
+        #[arg(help = "Stop after exporting N times")]
+        count:         Option<usize>,
+    },
+    #[command(aliases = ["key", "keygen"])]
+    #[command(about = "Make an auth key for the key file of the web command")]
+    #[command(after_help = concat!(
+        "SCOPES:\n",
+        "    read:basic      APIs of stats except for processes\n",
+        "    read:process    APIs of processes\n",
+        "    read:metrics    The Prometheus metrics\n",
+        "    write:ingest    Pushing snapshots by the push command\n",
+        "    admin           Everything\n\n",
+        "Enjoy it! https://magiclen.org"
+    ))]
+    AuthKey {
+        #[arg(value_name = "NAME")]
+        #[arg(help = "Assign the name of the key")]
+        name:    String,
+        #[arg(short, long, value_name = "SCOPE")]
+        #[arg(value_parser = parse_scope, num_args = 1.., value_delimiter = ',')]
+        #[arg(default_value = "read:basic")]
+        #[arg(help = "Assign what the key is allowed to access")]
+        scope:   Vec<Scope>,
+        #[arg(long, value_name = "TIME")]
+        #[arg(value_parser = parse_date_time)]
+        #[arg(help = "Make the key expire at a specific time (RFC 3339 or UNIX timestamp)")]
+        expires: Option<DateTime<Utc>>,
+        #[arg(long, value_name = "KEY")]
+        #[arg(hide_default_value = true)]
+        #[arg(value_parser = parse_key)]
+        #[arg(help = "Hash an existing key, which needs to be as long as a generated one (64 \
+                      characters), instead of generating a random one")]
+        key:     Option<String>,
+    },
+    #[command(aliases = ["nagios", "icinga", "alert"])]
+    #[command(about = "Check stats against threshold rules like a Nagios plugin. The exit code \
+                       is 0, 1, 2 or 3 for OK, WARNING, CRITICAL or UNKNOWN")]
+    #[command(after_help = concat!(
+        "RULES:\n",
+        "    <METRIC><OPERATOR><THRESHOLD>, e.g. cpu>90, memory.used%>85, volume[/].used%>90, load.five>2*cores\n\n",
+        "METRICS:\n",
+        "    cpu, cpu[N]                   CPU usage in percentage\n",
+        "    load.one|five|fifteen         Load average\n",
+        "    memory.total|used|free|shared|buffers|cache|available[%]\n",
+        "    swap.total|used|free|cache[%]\n",
+        "    volume[MOUNT_POINT|DEVICE].size|used|free[%]\n\n",
+        "    Metrics suffixed with % are in percentage of the total. Otherwise, they are in bytes.\n\n",
+        "OPERATORS:\n",
+        "    >, >=, <, <=\n\n",
+        "THRESHOLDS:\n",
+        "    A number (90), a size (512MiB), or a multiple of the number of logical CPU cores (2*cores)\n\n",
+        "Enjoy it! https://magiclen.org"
+    ))]
+    Check {
+        #[arg(short, long, value_name = "RULE")]
+        #[arg(help = "Add a rule which results in WARNING if it is met")]
+        warning:  Vec<String>,
+        #[arg(short, long, value_name = "RULE")]
+        #[arg(help = "Add a rule which results in CRITICAL if it is met")]
+        critical: Vec<String>,
+        #[arg(short, long, value_name = "MILLI_SECONDS")]
+        #[arg(default_value = "1000")]
+        #[arg(value_parser = parse_duration)]
+        #[arg(help = "Measure the CPU usage within N milliseconds")]
+        interval: Duration,
+    },
+    #[command(aliases = ["rec"])]
+    #[command(about = "Append snapshots of CPU, memory, network, volume and process stats to a \
+                       file periodically")]
+    #[command(after_help = AFTER_HELP)]
+    Record {
+        #[arg(short, long, value_name = "FILE")]
+        #[arg(help = "Assign the file to append snapshots to")]
+        out:      PathBuf,
+        #[arg(short, long, value_name = "MILLI_SECONDS")]
+        #[arg(default_value = "1000")]
+        #[arg(value_parser = parse_duration)]
+        #[arg(help = "Take a snapshot every N milliseconds")]
+        interval: Duration,
+        #[arg(long, value_name = "MAX_NUMBER_OF_PROCESSES")]
+        #[arg(default_value = "10")]
+        #[arg(help = "Sets the max number of processes in a snapshot")]
+        top:      usize,
+        #[arg(short = 'n', long, value_name = "NUMBER_OF_SNAPSHOTS")]
+        #[arg(help = "Stop after taking N snapshots")]
+        count:    Option<usize>,
+    },
+    #[command(aliases = ["play", "playback"])]
+    #[command(about = "Show the snapshots in a file made by the record command")]
+    #[command(after_help = AFTER_HELP)]
+    Replay {
+        #[arg(value_name = "FILE")]
+        #[arg(help = "Assign the file made by the record command")]
+        file:     PathBuf,
+        #[arg(short, long)]
+        #[arg(help = "No colors")]
+        plain:    bool,
+        #[arg(short, long)]
+        #[arg(help = "Darker colors")]
+        light:    bool,
+        #[arg(short, long)]
+        #[arg(value_parser = parse_unit)]
+        #[arg(help = "Forces to use a fixed unit")]
+        unit:     Option<Unit>,
+        #[arg(long, value_name = "TIME")]
+        #[arg(value_parser = parse_date_time)]
+        #[arg(help = "Skip snapshots taken before a specific time (RFC 3339 or UNIX timestamp)")]
+        from:     Option<DateTime<Utc>>,
+        #[arg(long, value_name = "TIME")]
+        #[arg(value_parser = parse_date_time)]
+        #[arg(help = "Skip snapshots taken after a specific time (RFC 3339 or UNIX timestamp)")]
+        to:       Option<DateTime<Utc>>,
+        #[arg(long, value_name = "VIEW")]
+        #[arg(value_enum, num_args = 1.., value_delimiter = ',')]
+        #[arg(default_value = "cpu,memory,network,volume,process")]
+        #[arg(help = "Assign the stats to show")]
+        view:     Vec<ReplayView>,
+        #[arg(long, value_name = "RATE")]
+        #[arg(default_value = "1")]
+        #[arg(help = "Replay faster or slower than the snapshots were taken. Set '0' to show \
+                      all snapshots without waiting")]
+        speed:    f64,
+        #[arg(long, value_name = "LENGTH")]
+        #[arg(default_value = "7")]
+        #[arg(help = "Truncate the user name, the group name and the program name of processes. \
+                      Set '0' to disable")]
+        truncate: usize,
     },
     #[command(aliases = ["b", "bench", "performance"])]
     #[command(about = "Run benchmarks to measure the performance of this environment")]
@@ -340,6 +810,13 @@ pub enum CLICommands {
         #[arg(help = "Allow to benchmark volumes (disables others by default)")]
         enable_volume:       bool,
     },
+    #[command(aliases = ["conf", "cfg"])]
+    #[command(about = "Inspect the configuration loaded from config files")]
+    #[command(after_help = AFTER_HELP)]
+    Config {
+        #[command(subcommand)]
+        command: ConfigCommands,
+    },
 }
 
 #[inline]
@@ -352,6 +829,79 @@ fn parse_duration_sec(arg: &str) -> Result<Duration, ParseIntError> {
     Ok(Duration::from_secs(arg.parse()?))
 }
 
+#[inline]
+fn parse_human_duration(arg: &str) -> Result<Duration, humantime::DurationError> {
+    humantime::parse_duration(arg)
+}
+
+fn parse_date_time(arg: &str) -> Result<DateTime<Utc>, chrono::ParseError> {
+    if let Some(date_time) = arg.parse().ok().and_then(|secs| DateTime::from_timestamp(secs, 0)) {
+        return Ok(date_time);
+    }
+
+    Ok(DateTime::parse_from_rfc3339(arg)?.with_timezone(&Utc))
+}
+
+#[inline]
+fn parse_alert_rule(arg: &str) -> anyhow::Result<AlertRule> {
+    arg.parse()
+}
+
+#[inline]
+fn parse_scope(arg: &str) -> anyhow::Result<Scope> {
+    arg.parse()
+}
+
+#[inline]
+fn parse_key(arg: &str) -> anyhow::Result<String> {
+    AuthKey::check_key(arg).map(|_| arg.to_string())
+}
+
+#[inline]
+fn parse_base_path(arg: &str) -> anyhow::Result<BasePath> {
+    arg.parse()
+}
+
+#[inline]
+fn parse_agent(arg: &str) -> anyhow::Result<Agent> {
+    arg.parse()
+}
+
+#[inline]
+fn parse_push_url(arg: &str) -> anyhow::Result<String> {
+    if !arg.starts_with("http://") && !arg.starts_with("https://") {
+        return Err(anyhow!("The URL needs to start with `http://` or `https://`."));
+    }
+
+    Ok(arg.trim_end_matches('/').to_string())
+}
+
+#[inline]
+fn parse_push_interval(arg: &str) -> anyhow::Result<Duration> {
+    let interval = humantime::parse_duration(arg)?;
+
+    if interval < Duration::from_secs(1) {
+        return Err(anyhow!("The interval needs to be at least 1 second."));
+    }
+
+    Ok(interval)
+}
+
+#[inline]
+fn parse_export_target(arg: &str) -> anyhow::Result<ExportTarget> {
+    arg.parse()
+}
+
+#[inline]
+fn parse_file_mode(arg: &str) -> Result<u32, ParseIntError> {
+    u32::from_str_radix(arg, 8)
+}
+
+#[inline]
+fn parse_socket_owner(arg: &str) -> anyhow::Result<SocketOwner> {
+    arg.parse()
+}
+
 #[inline]
 fn parse_unit(arg: &str) -> Result<Unit, UnitParseError> {
     Unit::from_str(arg)
@@ -367,17 +917,29 @@ fn parse_ip_addr(arg: &str) -> Result<IpAddr, AddrParseError> {
     IpAddr::from_str(arg)
 }
 
-pub fn get_args() -> CLIArgs {
-    let args = CLIArgs::command();
+/// Build the command whose default values of options are supplied by the config files.
+pub fn get_command() -> (Command, Config) {
+    let mut config = Config::load(env::args_os());
 
     let about = format!("{APP_NAME} {CARGO_PKG_VERSION}\n{CARGO_PKG_AUTHORS}\n{APP_ABOUT}");
 
-    let args = args.about(about);
+    let command = config.apply(CLIArgs::command().about(about));
+
+    (command, config)
+}
 
-    let matches = args.get_matches();
+pub fn get_args() -> anyhow::Result<CLIArgs> {
+    let (command, mut config) = get_command();
+
+    let matches = command.get_matches();
+
+    // the config command shows the files which cannot be loaded instead of failing with them
+    if matches.subcommand_name() != Some("config") {
+        config.check()?;
+    }
 
     match CLIArgs::from_arg_matches(&matches) {
-        Ok(args) => args,
+        Ok(args) => Ok(args),
         Err(err) => {
             err.exit();
         },
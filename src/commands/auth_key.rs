@@ -0,0 +1,40 @@
+use serde_json::json;
+
+use crate::{auth::AuthKey, output::print_json, CLIArgs, CLICommands};
+
+#[inline]
+pub fn handle_auth_key(args: CLIArgs) -> anyhow::Result<()> {
+    debug_assert!(matches!(args.command, CLICommands::AuthKey { .. }));
+
+    if let CLICommands::AuthKey {
+        name,
+        scope,
+        expires,
+        key,
+    } = args.command
+    {
+        let key = key.unwrap_or_else(AuthKey::generate_key);
+
+        let auth_key = AuthKey::new(name, &key, scope, expires);
+
+        if args.output.is_json() {
+            return print_json(
+                args.output,
+                &json!({
+                    "name": auth_key.name,
+                    "key": key,
+                    "hash": auth_key.hash_string(),
+                    "scopes": auth_key.scopes.iter().map(|scope| scope.as_str()).collect::<Vec<_>>(),
+                    "expires": auth_key.expires.map(|expires| expires.to_rfc3339()),
+                }),
+            );
+        }
+
+        // only the hash goes to stdout so that it can be appended to a key file directly
+        eprintln!("Key: {key}");
+
+        print!("{}", auth_key.to_toml());
+    }
+
+    Ok(())
+}
@@ -1,4 +1,11 @@
-use crate::{benchmark, benchmark::BenchmarkLog, CLIArgs, CLICommands};
+use serde_json::{json, Map, Value};
+
+use crate::{
+    benchmark,
+    benchmark::{BenchmarkLog, BenchmarkResult},
+    output::print_json,
+    CLIArgs, CLICommands,
+};
 
 #[inline]
 pub fn handle_benchmark(args: CLIArgs) -> anyhow::Result<()> {
@@ -16,7 +23,13 @@ pub fn handle_benchmark(args: CLIArgs) -> anyhow::Result<()> {
         enable_volume,
     } = args.command
     {
-        let print_out = if verbose { BenchmarkLog::Verbose } else { BenchmarkLog::Normal };
+        let print_out = if args.output.is_json() {
+            BenchmarkLog::None
+        } else if verbose {
+            BenchmarkLog::Verbose
+        } else {
+            BenchmarkLog::Normal
+        };
 
         let default = !(enable_cpu || enable_memory || enable_volume);
 
@@ -35,8 +48,38 @@ pub fn handle_benchmark(args: CLIArgs) -> anyhow::Result<()> {
             volume,
         };
 
-        benchmark::run_benchmark(&benchmark_config)?;
+        let benchmark_result = benchmark::run_benchmark(&benchmark_config)?;
+
+        if args.output.is_json() {
+            print_json(args.output, &benchmark_result_json(&benchmark_result))?;
+        }
     }
 
     Ok(())
 }
+
+/// Scores are `null` if they are not benchmarked. Volume scores are in bytes per second.
+fn benchmark_result_json(benchmark_result: &BenchmarkResult) -> Value {
+    let volumes = benchmark_result.volumes.as_ref().map(|volumes| {
+        let mut json_volumes = Map::with_capacity(volumes.len());
+
+        for (device, (read, write)) in volumes {
+            json_volumes.insert(
+                device.clone(),
+                json!({
+                    "read": read,
+                    "write": write
+                }),
+            );
+        }
+
+        Value::Object(json_volumes)
+    });
+
+    json!({
+        "cpu_multi_thread": benchmark_result.cpu_multi_thread,
+        "cpu_single_thread": benchmark_result.cpu_single_thread,
+        "memory": benchmark_result.memory,
+        "volumes": volumes
+    })
+}
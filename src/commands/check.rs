@@ -0,0 +1,429 @@
+use std::{fmt::Write as _, process, time::Duration};
+
+use anyhow::anyhow;
+use mprober_lib::{cpu, load_average, memory, volume};
+use serde_json::{json, Value};
+
+use crate::{
+    output::print_json,
+    rules::{Metric, Rule, Stats, Unit},
+    CLIArgs, CLICommands,
+};
+
+const SERVICE_NAME: &str = "MPROBER";
+
+/// The states of Nagios plugins. The discriminants are used as exit codes.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
+enum Status {
+    Ok       = 0,
+    Warning  = 1,
+    Critical = 2,
+    Unknown  = 3,
+}
+
+impl Status {
+    #[inline]
+    fn as_str(self) -> &'static str {
+        match self {
+            Status::Ok => "OK",
+            Status::Warning => "WARNING",
+            Status::Critical => "CRITICAL",
+            Status::Unknown => "UNKNOWN",
+        }
+    }
+}
+
+struct Check {
+    rule:      Rule,
+    /// `Warning` or `Critical`.
+    level:     Status,
+    /// `None` if the metric cannot be read.
+    value:     Option<f64>,
+    threshold: f64,
+    triggered: bool,
+}
+
+#[inline]
+pub fn handle_check(args: CLIArgs) -> anyhow::Result<()> {
+    debug_assert!(matches!(args.command, CLICommands::Check { .. }));
+
+    if let CLICommands::Check {
+        warning,
+        critical,
+        interval,
+    } = args.command
+    {
+        let (status, checks, summary, perfdata) = match run_checks(&warning, &critical, interval) {
+            Ok((checks, stats)) => {
+                let status = aggregate(&checks);
+
+                let summary = summarize(&checks);
+                let perfdata = perfdata(&checks, &stats);
+
+                (status, checks, summary, perfdata)
+            },
+            Err(error) => (Status::Unknown, Vec::new(), error.to_string(), String::new()),
+        };
+
+        if args.output.is_json() {
+            print_json(args.output, &check_json(status, &checks, &summary))?;
+        } else {
+            println!("{}", status_line(status, &summary, &perfdata));
+        }
+
+        process::exit(status as i32);
+    }
+
+    Ok(())
+}
+
+fn run_checks(
+    warning: &[String],
+    critical: &[String],
+    interval: Duration,
+) -> anyhow::Result<(Vec<Check>, Stats)> {
+    let rules = parse_rules(warning, critical)?;
+
+    let stats = read_stats(&rules, interval)?;
+
+    Ok((evaluate(rules, &stats), stats))
+}
+
+fn parse_rules(warning: &[String], critical: &[String]) -> anyhow::Result<Vec<(Rule, Status)>> {
+    let mut rules = Vec::with_capacity(warning.len() + critical.len());
+
+    for (rules_str, level) in [(warning, Status::Warning), (critical, Status::Critical)] {
+        for rule in rules_str {
+            rules.push((rule.parse::<Rule>()?, level));
+        }
+    }
+
+    if rules.is_empty() {
+        return Err(anyhow!("No rules. Use `--warning` or `--critical` to add rules."));
+    }
+
+    Ok(rules)
+}
+
+/// Read only the stats which the rules need.
+fn read_stats(rules: &[(Rule, Status)], interval: Duration) -> anyhow::Result<Stats> {
+    Ok(Stats {
+        cpus_stat:    if rules.iter().any(|(rule, _)| rule.metric.needs_cpus_stat()) {
+            cpu::get_all_cpu_utilization_in_percentage(true, interval)?
+        } else {
+            Vec::new()
+        },
+        load_average: load_average::get_load_average()?,
+        free:         memory::free()?,
+        volumes:      if rules.iter().any(|(rule, _)| rule.metric.needs_volumes()) {
+            volume::get_volumes()?
+        } else {
+            Vec::new()
+        },
+        cores:        if rules.iter().any(|(rule, _)| rule.threshold.needs_cores()) {
+            cpu::get_cpus()?.iter().map(|cpu| cpu.siblings).sum()
+        } else {
+            0
+        },
+    })
+}
+
+fn evaluate(rules: Vec<(Rule, Status)>, stats: &Stats) -> Vec<Check> {
+    rules
+        .into_iter()
+        .map(|(rule, level)| {
+            let value = rule.metric.value(stats);
+            let threshold = rule.threshold.resolve(stats);
+            let triggered = rule.is_triggered(stats).unwrap_or(false);
+
+            Check {
+                rule,
+                level,
+                value,
+                threshold,
+                triggered,
+            }
+        })
+        .collect()
+}
+
+/// The worst level of the triggered checks. A metric which cannot be read makes the result unknown.
+fn aggregate(checks: &[Check]) -> Status {
+    checks.iter().fold(Status::Ok, |status, check| {
+        if check.value.is_none() {
+            Status::Unknown
+        } else if check.triggered {
+            status.max(check.level)
+        } else {
+            status
+        }
+    })
+}
+
+/// The output of a Nagios plugin, like `MPROBER OK - cpu=12.00% | cpu=12.00%;80;90;0;100`.
+fn status_line(status: Status, summary: &str, perfdata: &str) -> String {
+    if perfdata.is_empty() {
+        format!("{SERVICE_NAME} {} - {summary}", status.as_str())
+    } else {
+        format!("{SERVICE_NAME} {} - {summary} | {perfdata}", status.as_str())
+    }
+}
+
+fn format_value(metric: &Metric, value: f64) -> String {
+    match metric.unit() {
+        Unit::Bytes => format!("{value:.0}B"),
+        unit => format!("{value:.2}{}", unit.as_uom()),
+    }
+}
+
+/// List the problems. If there is no problem, list the values of all metrics instead.
+fn summarize(checks: &[Check]) -> String {
+    let mut summary = String::new();
+
+    let unknown = checks.iter().filter(|check| check.value.is_none()).collect::<Vec<_>>();
+
+    if !unknown.is_empty() {
+        for check in unknown {
+            if !summary.is_empty() {
+                summary.push_str(", ");
+            }
+
+            write!(summary, "{} is not available", check.rule.metric).unwrap();
+        }
+
+        return summary;
+    }
+
+    let triggered = checks.iter().filter(|check| check.triggered).collect::<Vec<_>>();
+
+    if !triggered.is_empty() {
+        for check in triggered {
+            if !summary.is_empty() {
+                summary.push_str(", ");
+            }
+
+            write!(
+                summary,
+                "{}={} ({})",
+                check.rule.metric,
+                format_value(&check.rule.metric, check.value.unwrap()),
+                check.rule
+            )
+            .unwrap();
+        }
+
+        return summary;
+    }
+
+    for (metric, value) in metric_values(checks) {
+        if !summary.is_empty() {
+            summary.push_str(", ");
+        }
+
+        write!(summary, "{metric}={}", format_value(metric, value)).unwrap();
+    }
+
+    summary
+}
+
+/// The distinct metrics in order with their values.
+fn metric_values(checks: &[Check]) -> Vec<(&Metric, f64)> {
+    let mut metric_values: Vec<(&Metric, f64)> = Vec::new();
+
+    for check in checks {
+        if let Some(value) = check.value {
+            if !metric_values.iter().any(|(metric, _)| *metric == &check.rule.metric) {
+                metric_values.push((&check.rule.metric, value));
+            }
+        }
+    }
+
+    metric_values
+}
+
+/// Format perfdata as `'label'=value[UOM];[warn];[crit];[min];[max]`.
+fn perfdata(checks: &[Check], stats: &Stats) -> String {
+    let mut perfdata = String::new();
+
+    for (metric, value) in metric_values(checks) {
+        let range = |level: Status| {
+            checks
+                .iter()
+                .find(|check| check.level == level && &check.rule.metric == metric)
+                .map(|check| check.rule.perfdata_range(stats))
+                .unwrap_or_default()
+        };
+
+        let label = metric.to_string();
+
+        let label = if label.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.') {
+            label
+        } else {
+            // a quote in a quoted label is doubled
+            format!("'{}'", label.replace('\'', "''"))
+        };
+
+        let unit = metric.unit();
+
+        let (value, max) = match unit {
+            Unit::Percentage => (format!("{value:.2}"), "100"),
+            Unit::Bytes => (format!("{value:.0}"), ""),
+            Unit::None => (format!("{value:.2}"), ""),
+        };
+
+        if !perfdata.is_empty() {
+            perfdata.push(' ');
+        }
+
+        write!(
+            perfdata,
+            "{label}={value}{};{};{};0;{max}",
+            unit.as_uom(),
+            range(Status::Warning),
+            range(Status::Critical)
+        )
+        .unwrap();
+    }
+
+    perfdata
+}
+
+fn check_json(status: Status, checks: &[Check], summary: &str) -> Value {
+    let mut json_checks = Vec::with_capacity(checks.len());
+
+    for check in checks {
+        json_checks.push(json!({
+            "rule": check.rule.to_string(),
+            "level": check.level.as_str(),
+            "metric": check.rule.metric.to_string(),
+            "value": check.value,
+            "threshold": check.threshold,
+            "triggered": check.triggered
+        }));
+    }
+
+    json!({
+        "status": status.as_str(),
+        "code": status as i32,
+        "summary": summary,
+        "checks": json_checks
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use mprober_lib::{
+        load_average::LoadAverage,
+        memory::{Free, Mem, Swap},
+        volume::{Volume, VolumeStat},
+    };
+
+    use super::*;
+
+    fn stats() -> Stats {
+        Stats {
+            cpus_stat:    vec![0.5, 0.25, 0.75],
+            load_average: LoadAverage {
+                one: 1.0, five: 3.0, fifteen: 0.5
+            },
+            free:         Free {
+                mem:  Mem {
+                    total:     1000,
+                    used:      900,
+                    free:      50,
+                    shared:    0,
+                    buffers:   20,
+                    cache:     30,
+                    available: 100,
+                },
+                swap: Swap {
+                    total: 0, used: 0, free: 0, cache: 0
+                },
+            },
+            volumes:      vec![Volume {
+                device: "sdb1".to_string(),
+                stat:   VolumeStat {
+                    read_bytes: 0, write_bytes: 0
+                },
+                size:   1000,
+                used:   250,
+                points: vec!["/mnt/My Disk".to_string()],
+            }],
+            cores:        2,
+        }
+    }
+
+    fn check(warning: &[&str], critical: &[&str]) -> (Status, String, String) {
+        let to_strings = |rules: &[&str]| rules.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+
+        let stats = stats();
+
+        let rules = parse_rules(&to_strings(warning), &to_strings(critical)).unwrap();
+
+        let checks = evaluate(rules, &stats);
+
+        (aggregate(&checks), summarize(&checks), perfdata(&checks, &stats))
+    }
+
+    #[test]
+    fn test_ok() {
+        let (status, summary, perfdata) = check(&["cpu>80", "memory.used%>95"], &["cpu>90"]);
+
+        assert_eq!(Status::Ok, status);
+        assert_eq!(0, status as i32);
+        assert_eq!("cpu=50.00%, memory.used%=90.00%", summary);
+        assert_eq!("cpu=50.00%;80;90;0;100 'memory.used%'=90.00%;95;;0;100", perfdata);
+        assert_eq!(
+            "MPROBER OK - cpu=50.00%, memory.used%=90.00% | cpu=50.00%;80;90;0;100 \
+             'memory.used%'=90.00%;95;;0;100",
+            status_line(status, &summary, &perfdata)
+        );
+    }
+
+    #[test]
+    fn test_warning_and_critical() {
+        let (status, summary, perfdata) = check(&["load.five>1*cores"], &[]);
+
+        assert_eq!(Status::Warning, status);
+        assert_eq!(1, status as i32);
+        assert_eq!("load.five=3.00 (load.five>1*cores)", summary);
+        assert_eq!("load.five=3.00;2;;0;", perfdata);
+
+        let (status, summary, _) =
+            check(&["load.five>1*cores", "cpu[1]>10"], &["memory.available<200B"]);
+
+        assert_eq!(Status::Critical, status);
+        assert_eq!(2, status as i32);
+        assert_eq!(
+            "load.five=3.00 (load.five>1*cores), cpu[1]=75.00% (cpu[1]>10), memory.available=100B \
+             (memory.available<200B)",
+            summary
+        );
+    }
+
+    #[test]
+    fn test_unknown() {
+        let (status, summary, perfdata) = check(&["cpu[7]>10"], &["cpu>10"]);
+
+        // a metric which cannot be read is worse than any triggered rule
+        assert_eq!(Status::Unknown, status);
+        assert_eq!(3, status as i32);
+        assert_eq!("cpu[7] is not available", summary);
+        assert_eq!("cpu=50.00%;;10;0;100", perfdata);
+    }
+
+    #[test]
+    fn test_quoted_label() {
+        let (status, summary, perfdata) = check(&["volume[/mnt/My Disk].used% > 20"], &[]);
+
+        assert_eq!(Status::Warning, status);
+        assert_eq!("volume[/mnt/My Disk].used%=25.00% (volume[/mnt/My Disk].used%>20)", summary);
+        assert_eq!("'volume[/mnt/My Disk].used%'=25.00%;20;;0;100", perfdata);
+    }
+
+    #[test]
+    fn test_no_rules() {
+        assert!(parse_rules(&[], &[]).is_err());
+        assert!(parse_rules(&["memory.used>85%".to_string()], &[]).is_err());
+    }
+}
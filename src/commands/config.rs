@@ -0,0 +1,30 @@
+use crate::{
+    config::{show_json, show_toml},
+    get_command,
+    output::print_json,
+    CLIArgs, CLICommands, ConfigCommands,
+};
+
+#[inline]
+pub fn handle_config(args: CLIArgs) -> anyhow::Result<()> {
+    debug_assert!(matches!(args.command, CLICommands::Config { .. }));
+
+    if let CLICommands::Config {
+        command,
+    } = args.command
+    {
+        match command {
+            ConfigCommands::Show => {
+                let (command, config) = get_command();
+
+                if args.output.is_json() {
+                    return print_json(args.output, &show_json(&command, &config));
+                }
+
+                print!("{}", show_toml(&command, &config));
+            },
+        }
+    }
+
+    Ok(())
+}
@@ -1,10 +1,18 @@
 use byte_unit::{Byte, Unit, UnitType};
 use mprober_lib::{cpu, load_average};
+use serde_json::{json, Value};
 
-use crate::{terminal::*, CLIArgs, CLICommands};
+use crate::{output::json_handler, terminal::*, CLIArgs, CLICommands};
+
+pub struct CPUInfo {
+    pub load_average: load_average::LoadAverage,
+    pub cpus:         Vec<cpu::CPU>,
+    /// The first value is the average usage of all cores. The remaining values are the usage for each logical CPU core (only measured in the separate mode). It is empty if only the information is needed.
+    pub cpus_stat:    Vec<f64>,
+}
 
 #[inline]
-pub fn handle_cpu(args: CLIArgs) {
+pub fn handle_cpu(args: CLIArgs) -> anyhow::Result<()> {
     debug_assert!(matches!(args.command, CLICommands::Cpu { .. }));
 
     if let CLICommands::Cpu {
@@ -15,25 +23,86 @@ pub fn handle_cpu(args: CLIArgs) {
         only_information,
     } = args.command
     {
+        if args.output.is_json() {
+            return json_handler(args.output, monitor, !only_information, || {
+                Ok(cpu_info_json(
+                    &get_cpu_info(monitor, separate, only_information)?,
+                    only_information,
+                ))
+            });
+        }
+
         set_color_mode(plain, light);
 
         monitor_handler!(
             monitor,
-            draw_cpu_info(monitor, separate, only_information),
-            draw_cpu_info(None, separate, only_information),
+            draw_cpu_info(
+                &get_cpu_info(monitor, separate, only_information)?,
+                separate,
+                only_information
+            ),
+            draw_cpu_info(
+                &get_cpu_info(None, separate, only_information)?,
+                separate,
+                only_information
+            ),
             only_information
         );
     }
+
+    Ok(())
+}
+
+pub fn get_cpu_info(
+    monitor: Option<Duration>,
+    separate: bool,
+    only_information: bool,
+) -> anyhow::Result<CPUInfo> {
+    let interval = match monitor {
+        Some(monitor) => monitor,
+        None => DEFAULT_INTERVAL,
+    };
+
+    let cpus_stat = if only_information {
+        Vec::new()
+    } else if separate {
+        cpu::get_all_cpu_utilization_in_percentage(true, interval)?
+    } else {
+        vec![cpu::get_average_cpu_utilization_in_percentage(interval)?]
+    };
+
+    let cpus = cpu::get_cpus()?;
+
+    let load_average = load_average::get_load_average()?;
+
+    Ok(CPUInfo {
+        load_average,
+        cpus,
+        cpus_stat,
+    })
+}
+
+pub fn cpu_info_json(cpu_info: &CPUInfo, only_information: bool) -> Value {
+    let mut json_cpu_info = json!({
+        "load_average": crate::json::load_average(&cpu_info.load_average),
+        "cpus": crate::json::cpus(&cpu_info.cpus),
+    });
+
+    if !only_information {
+        json_cpu_info["cpus_stat"] = json!(cpu_info.cpus_stat);
+    }
+
+    json_cpu_info
 }
 
-fn draw_cpu_info(monitor: Option<Duration>, separate: bool, only_information: bool) {
+pub fn draw_cpu_info(cpu_info: &CPUInfo, separate: bool, only_information: bool) {
     let output = get_stdout_output();
     let mut stdout = output.buffer();
 
     let terminal_width = get_term_width();
 
     let mut draw_load_average = |cpus: &[cpu::CPU]| {
-        let load_average = load_average::get_load_average().unwrap();
+        let load_average = &cpu_info.load_average;
 
         let logical_cores_number: usize = cpus.iter().map(|cpu| cpu.siblings).sum();
         let logical_cores_number_f64 = logical_cores_number as f64;
@@ -204,25 +273,18 @@ fn draw_cpu_info(monitor: Option<Duration>, separate: bool, only_information: bo
     };
 
     if separate {
-        let all_percentage: Vec<f64> = if only_information {
-            Vec::new()
-        } else {
-            cpu::get_all_cpu_utilization_in_percentage(false, match monitor {
-                Some(monitor) => monitor,
-                None => DEFAULT_INTERVAL,
-            })
-            .unwrap()
-        };
+        let all_percentage: &[f64] =
+            if only_information { &[] } else { cpu_info.cpus_stat.get(1..).unwrap_or(&[]) };
 
-        let cpus = cpu::get_cpus().unwrap();
+        let cpus = &cpu_info.cpus;
 
-        draw_load_average(&cpus);
+        draw_load_average(cpus);
 
         let mut i = 0;
 
         let cpus_len_dec = cpus.len() - 1;
 
-        for (cpu_index, cpu) in cpus.into_iter().enumerate() {
+        for (cpu_index, cpu) in cpus.iter().enumerate() {
             stdout.set_color(&COLOR_NORMAL_TEXT).unwrap();
             stdout.write_all(cpu.model_name.as_bytes()).unwrap();
 
@@ -348,21 +410,16 @@ fn draw_cpu_info(monitor: Option<Duration>, separate: bool, only_information: bo
         let (average_percentage, average_percentage_string) = if only_information {
             (0f64, "".to_string())
         } else {
-            let average_percentage =
-                cpu::get_average_cpu_utilization_in_percentage(match monitor {
-                    Some(monitor) => monitor,
-                    None => DEFAULT_INTERVAL,
-                })
-                .unwrap();
+            let average_percentage = cpu_info.cpus_stat.first().copied().unwrap_or(0f64);
 
             let average_percentage_string = format!("{:.2}%", average_percentage * 100f64);
 
             (average_percentage, average_percentage_string)
         };
 
-        let cpus = cpu::get_cpus().unwrap();
+        let cpus = &cpu_info.cpus;
 
-        draw_load_average(&cpus);
+        draw_load_average(cpus);
 
         for cpu in cpus {
             stdout.set_color(&COLOR_NORMAL_TEXT).unwrap();
@@ -0,0 +1,165 @@
+use std::fmt::Display;
+
+use anyhow::{anyhow, Context};
+use chrono::Utc;
+
+use crate::{
+    export::{self, ExportFormat},
+    failure_log::FailureLog,
+    rocket_mounts::{CPUsStatCollector, NetworkStatCollector, Sampler, VolumesStatCollector},
+    CLIArgs, CLICommands,
+};
+
+#[inline]
+pub fn handle_export(args: CLIArgs) -> anyhow::Result<()> {
+    debug_assert!(matches!(args.command, CLICommands::Export { .. }));
+
+    if let CLICommands::Export {
+        format,
+        to,
+        statsd,
+        dogstatsd,
+        tag,
+        interval,
+        prefix,
+        host,
+        authorization,
+        timeout,
+        count,
+    } = args.command
+    {
+        let (format, to) = match statsd {
+            Some(statsd) => (ExportFormat::Statsd, format!("udp://{statsd}").parse()?),
+            None => (format, to),
+        };
+
+        if format != ExportFormat::Influx && to.is_http() {
+            return Err(anyhow!(
+                "Only metrics in the InfluxDB line protocol can be POSTed to an HTTP target."
+            ));
+        }
+
+        if dogstatsd && format != ExportFormat::Statsd {
+            return Err(anyhow!("`--dogstatsd` needs to be used with `-f statsd` or `--statsd`."));
+        }
+
+        let dogstatsd_tags = if dogstatsd { Some(tag) } else { None };
+
+        let host = match host {
+            Some(host) => host,
+            None => mprober_lib::hostname::get_hostname().context("Cannot get the hostname.")?,
+        };
+
+        let mut sink = to.open(timeout, authorization)?;
+
+        let sampler = Sampler::start(interval);
+
+        let mut generation = 0;
+        let mut exported = 0;
+        let mut send_failures = FailureLog::default();
+        let mut load_failures = FailureLog::default();
+        let mut memory_failures = FailureLog::default();
+
+        while count.map(|count| exported < count).unwrap_or(true) {
+            let (next_generation, cpus_stat) =
+                sampler.sample::<CPUsStatCollector>().get_next(generation);
+
+            generation = next_generation;
+
+            let time = Utc::now();
+
+            // the collectors log their failures by themselves
+            let cpus_stat = cpus_stat.ok();
+            let load_average = read(
+                &mut load_failures,
+                "load average",
+                mprober_lib::load_average::get_load_average(),
+            );
+            let free = read(&mut memory_failures, "memory", mprober_lib::memory::free());
+            let network_stat = sampler.sample::<NetworkStatCollector>().wait().ok();
+            let volumes_stat = sampler.sample::<VolumesStatCollector>().wait().ok();
+
+            let points = export::points(
+                cpus_stat.as_deref().map(Vec::as_slice),
+                load_average.as_ref(),
+                free.as_ref(),
+                network_stat.as_deref().map(Vec::as_slice),
+                volumes_stat.as_deref().map(Vec::as_slice),
+            );
+
+            let mut lines = String::new();
+
+            match format {
+                ExportFormat::Influx => {
+                    export::write_influx(&mut lines, &points, &prefix, &host, time)
+                },
+                ExportFormat::Graphite => {
+                    export::write_graphite(&mut lines, &points, &prefix, &host, time)
+                },
+                ExportFormat::Statsd => export::write_statsd(
+                    &mut lines,
+                    &points,
+                    &prefix,
+                    &host,
+                    dogstatsd_tags.as_deref(),
+                ),
+            }
+
+            // nothing is sent if none of the stats can be read
+            let result = if lines.is_empty() { Ok(()) } else { sink.send(&lines) };
+
+            // a remote target may be down for a while, so only the changes of the state are logged
+            match result {
+                Ok(()) => send_failures.succeed("Metrics are exported again."),
+                Err(error) if sink.is_remote() => send_failures.fail(format_args!(
+                    "Cannot export metrics: {error:#}. Metrics are dropped until it succeeds."
+                )),
+                Err(error) => return Err(error),
+            }
+
+            exported += 1;
+        }
+    }
+
+    Ok(())
+}
+
+/// Take the stats which are read successfully. The stats which cannot be read are skipped, and only the changes of their state are logged, so that the other stats are still exported.
+fn read<T, E: Display>(failures: &mut FailureLog, name: &str, result: Result<T, E>) -> Option<T> {
+    match result {
+        Ok(stats) => {
+            failures.succeed(format_args!("The {name} stats are exported again."));
+
+            Some(stats)
+        },
+        Err(error) => {
+            failures.fail(format_args!(
+                "Cannot read the {name} stats: {error}. They are skipped until they can be read."
+            ));
+
+            None
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_read() {
+        let mut failures = FailureLog::default();
+
+        // only the first failure of a source is logged
+        for _ in 0..3 {
+            assert_eq!(None, read::<u8, _>(&mut failures, "memory", Err("No such file")));
+        }
+
+        assert_eq!(1, failures.logged());
+
+        assert_eq!(Some(1), read::<_, &str>(&mut failures, "memory", Ok(1)));
+        assert_eq!(Some(2), read::<_, &str>(&mut failures, "memory", Ok(2)));
+
+        assert_eq!(2, failures.logged());
+    }
+}
@@ -1,8 +1,17 @@
 use mprober_lib::hostname;
+use serde_json::json;
+
+use crate::{output::print_json, CLIArgs};
 
 #[inline]
-pub fn handle_hostname() {
+pub fn handle_hostname(args: CLIArgs) -> anyhow::Result<()> {
     let hostname = hostname::get_hostname().unwrap();
 
+    if args.output.is_json() {
+        return print_json(args.output, &json!(hostname));
+    }
+
     println!("{hostname}");
+
+    Ok(())
 }
@@ -0,0 +1,45 @@
+use crate::{
+    auth::Sessions,
+    commands::load_auth_keys,
+    rocket_mounts::{self, Hub},
+    CLIArgs, CLICommands,
+};
+
+#[inline]
+pub fn handle_hub(args: CLIArgs) -> anyhow::Result<()> {
+    debug_assert!(matches!(args.command, CLICommands::Hub { .. }));
+
+    if let CLICommands::Hub {
+        monitor,
+        timeout,
+        agent,
+        agent_file,
+        address,
+        listen_port,
+        base_path,
+        auth_key,
+        auth_key_file,
+        session_ttl,
+    } = args.command
+    {
+        let agents = rocket_mounts::load_agents(agent_file.as_ref(), agent)?;
+
+        let auth_keys = load_auth_keys(auth_key.as_deref(), auth_key_file.as_deref())?;
+
+        let hub = Hub::start(agents, monitor, timeout);
+
+        let rocket = rocket_mounts::create_hub(
+            hub,
+            monitor,
+            address,
+            listen_port,
+            base_path,
+            auth_keys,
+            Sessions::new(session_ttl),
+        );
+
+        rocket::execute(rocket.launch())?;
+    }
+
+    Ok(())
+}
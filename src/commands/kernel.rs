@@ -1,8 +1,17 @@
 use mprober_lib::kernel;
+use serde_json::json;
+
+use crate::{output::print_json, CLIArgs};
 
 #[inline]
-pub fn handle_kernel() {
+pub fn handle_kernel(args: CLIArgs) -> anyhow::Result<()> {
     let kernel_version = kernel::get_kernel_version().unwrap();
 
+    if args.output.is_json() {
+        return print_json(args.output, &json!(kernel_version));
+    }
+
     println!("{kernel_version}");
+
+    Ok(())
 }
@@ -1,10 +1,11 @@
 use byte_unit::{Byte, Unit, UnitType};
 use mprober_lib::memory;
+use serde_json::{json, Value};
 
-use crate::{terminal::*, CLIArgs, CLICommands};
+use crate::{output::json_handler, terminal::*, CLIArgs, CLICommands};
 
 #[inline]
-pub fn handle_memory(args: CLIArgs) {
+pub fn handle_memory(args: CLIArgs) -> anyhow::Result<()> {
     debug_assert!(matches!(args.command, CLICommands::Memory { .. }));
 
     if let CLICommands::Memory {
@@ -14,15 +15,26 @@ pub fn handle_memory(args: CLIArgs) {
         unit,
     } = args.command
     {
+        if args.output.is_json() {
+            return json_handler(args.output, monitor, false, || Ok(memory_json(&memory::free()?)));
+        }
+
         set_color_mode(plain, light);
 
-        monitor_handler!(monitor, draw_memory(unit));
+        monitor_handler!(monitor, draw_memory(&memory::free()?, unit));
     }
+
+    Ok(())
 }
 
-fn draw_memory(unit: Option<Unit>) {
-    let free = memory::free().unwrap();
+pub fn memory_json(free: &memory::Free) -> Value {
+    json!({
+        "memory": crate::json::memory(&free.mem),
+        "swap": crate::json::swap(&free.swap)
+    })
+}
 
+pub fn draw_memory(free: &memory::Free, unit: Option<Unit>) {
     let output = get_stdout_output();
     let mut stdout = output.buffer();
 
@@ -1,22 +1,38 @@
+mod auth_key;
 mod benchmark;
+mod check;
+mod config;
 mod cpu;
+mod export;
 mod hostname;
+mod hub;
 mod kernel;
 mod memory;
 mod network;
 mod process;
+mod push;
+mod record;
+mod replay;
 mod time;
 mod uptime;
 mod volume;
 mod web;
 
+pub use auth_key::*;
 pub use benchmark::*;
+pub use check::*;
+pub use config::*;
 pub use cpu::*;
+pub use export::*;
 pub use hostname::*;
+pub use hub::*;
 pub use kernel::*;
 pub use memory::*;
 pub use network::*;
 pub use process::*;
+pub use push::*;
+pub use record::*;
+pub use replay::*;
 pub use time::*;
 pub use uptime::*;
 pub use volume::*;
@@ -1,10 +1,10 @@
 use byte_unit::{Byte, Unit, UnitType};
 use mprober_lib::network;
 
-use crate::{terminal::*, CLIArgs, CLICommands};
+use crate::{output::json_handler, terminal::*, CLIArgs, CLICommands};
 
 #[inline]
-pub fn handle_network(args: CLIArgs) {
+pub fn handle_network(args: CLIArgs) -> anyhow::Result<()> {
     debug_assert!(matches!(args.command, CLICommands::Network { .. }));
 
     if let CLICommands::Network {
@@ -14,19 +14,40 @@ pub fn handle_network(args: CLIArgs) {
         unit,
     } = args.command
     {
+        if args.output.is_json() {
+            return json_handler(args.output, monitor, true, || {
+                Ok(crate::json::networks_with_speed(&get_networks_with_speed(monitor)))
+            });
+        }
+
         set_color_mode(plain, light);
 
-        monitor_handler!(monitor, draw_network(monitor, unit), draw_network(None, unit), false);
+        monitor_handler!(
+            monitor,
+            draw_network(&get_networks_with_speed(monitor), unit),
+            draw_network(&get_networks_with_speed(None), unit),
+            false
+        );
     }
+
+    Ok(())
 }
 
-fn draw_network(monitor: Option<Duration>, unit: Option<Unit>) {
-    let networks_with_speed = network::get_networks_with_speed(match monitor {
+#[inline]
+pub fn get_networks_with_speed(
+    monitor: Option<Duration>,
+) -> Vec<(network::Network, network::NetworkSpeed)> {
+    network::get_networks_with_speed(match monitor {
         Some(monitor) => monitor,
         None => DEFAULT_INTERVAL,
     })
-    .unwrap();
+    .unwrap()
+}
 
+pub fn draw_network(
+    networks_with_speed: &[(network::Network, network::NetworkSpeed)],
+    unit: Option<Unit>,
+) {
     let networks_with_speed_len = networks_with_speed.len();
 
     let output = get_stdout_output();
@@ -110,7 +131,7 @@ fn draw_network(monitor: Option<Duration>, unit: Option<Unit>) {
     let mut downloads_iter = downloads.into_iter();
     let mut downloads_total_iter = downloads_total.into_iter();
 
-    for (network, _) in networks_with_speed.into_iter() {
+    for (network, _) in networks_with_speed.iter() {
         let upload = uploads_iter.next().unwrap();
         let upload_total = uploads_total_iter.next().unwrap();
 
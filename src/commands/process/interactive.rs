@@ -0,0 +1,568 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    io,
+    ops::ControlFlow,
+    sync::{Arc, Mutex},
+    thread,
+    time::Instant,
+};
+
+use libc::c_int;
+use regex::Regex;
+use users::UsersCache;
+
+use super::{ProcessTable, View};
+use crate::{terminal::*, ProcessSort};
+
+/// The signals which can be sent by their names.
+const SIGNALS: [(&str, c_int); 10] = [
+    ("HUP", libc::SIGHUP),
+    ("INT", libc::SIGINT),
+    ("QUIT", libc::SIGQUIT),
+    ("KILL", libc::SIGKILL),
+    ("USR1", libc::SIGUSR1),
+    ("USR2", libc::SIGUSR2),
+    ("TERM", libc::SIGTERM),
+    ("CONT", libc::SIGCONT),
+    ("STOP", libc::SIGSTOP),
+    ("TSTP", libc::SIGTSTP),
+];
+
+const HELP: &str =
+    "j/k: select, t/K/h/x: signal, n: renice, /: filter, p: pause, s: sort, r: reverse, q: quit";
+
+/// Parse a signal by its name, with or without the `SIG` prefix, or by its number.
+fn parse_signal(s: &str) -> Option<c_int> {
+    let s = s.trim().to_ascii_uppercase();
+    let name = s.strip_prefix("SIG").unwrap_or(&s);
+
+    match SIGNALS.iter().find(|(signal_name, _)| *signal_name == name) {
+        Some((_, signal)) => Some(*signal),
+        None => name.parse().ok().filter(|signal| *signal > 0),
+    }
+}
+
+fn signal_name(signal: c_int) -> String {
+    match SIGNALS.iter().find(|(_, s)| *s == signal) {
+        Some((name, _)) => format!("SIG{name}"),
+        None => format!("the signal {signal}"),
+    }
+}
+
+/// Only positive PIDs are accepted, because the others stand for process groups or the calling process.
+fn to_pid(pid: u32) -> io::Result<libc::pid_t> {
+    libc::pid_t::try_from(pid)
+        .ok()
+        .filter(|pid| *pid > 0)
+        .ok_or_else(|| io::Error::from(io::ErrorKind::InvalidInput))
+}
+
+fn send_signal(pid: u32, signal: c_int) -> io::Result<()> {
+    if unsafe { libc::kill(to_pid(pid)?, signal) } == 0 {
+        Ok(())
+    } else {
+        Err(io::Error::last_os_error())
+    }
+}
+
+fn renice(pid: u32, nice: c_int) -> io::Result<()> {
+    let pid = to_pid(pid)? as libc::id_t;
+
+    if unsafe { libc::setpriority(libc::PRIO_PROCESS, pid, nice) } == 0 {
+        Ok(())
+    } else {
+        Err(io::Error::last_os_error())
+    }
+}
+
+/// Edit the input of a prompt with a key. Return `true` if the input is submitted.
+fn edit(input: &mut String, key: u8) -> bool {
+    match key {
+        b'\n' | b'\r' => return true,
+        // DEL and BS
+        0x7F | 0x08 => {
+            input.pop();
+        },
+        0x20..=0x7E => input.push(key as char),
+        _ => (),
+    }
+
+    false
+}
+
+/// The process which actions are applied to.
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct Target {
+    pid:     u32,
+    program: String,
+}
+
+impl Display for Target {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.pid, self.program)
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+enum Prompt {
+    /// Wait for `y` to send the signal.
+    Signal(Target, c_int),
+    /// Input a signal to send.
+    CustomSignal(Target, String),
+    /// Input a nice value.
+    Nice(Target, String),
+    /// Input a regex to filter programs. An empty regex removes the filter.
+    Filter(String),
+}
+
+/// The state of the monitor mode of the `process` command. It is shared by the thread reading keys and the thread refreshing processes, and both of them draw the screen.
+pub(super) struct Interactive {
+    sort:           ProcessSort,
+    reverse:        bool,
+    program_filter: Option<Regex>,
+    paused:         bool,
+    /// The PID of the selected row. The first row is selected if the process is not shown.
+    selected:       Option<u32>,
+    prompt:         Option<Prompt>,
+    /// The result of the last action. It is cleared by the next key.
+    message:        Option<String>,
+    table:          Option<ProcessTable>,
+    view:           View,
+    user_cache:     UsersCache,
+}
+
+impl Interactive {
+    pub(super) fn new(
+        sort: ProcessSort,
+        reverse: bool,
+        program_filter: Option<Regex>,
+        view: View,
+    ) -> Interactive {
+        Interactive {
+            sort,
+            reverse,
+            program_filter,
+            paused: false,
+            selected: None,
+            prompt: None,
+            message: None,
+            table: None,
+            view,
+            user_cache: UsersCache::new(),
+        }
+    }
+
+    fn target(&self) -> Option<Target> {
+        let processes = &self.table.as_ref()?.processes;
+
+        let process = self
+            .selected
+            .and_then(|pid| processes.iter().find(|process| process.pid == pid))
+            .or_else(|| processes.first())?;
+
+        Some(Target {
+            pid: process.pid, program: process.program.clone()
+        })
+    }
+
+    fn move_cursor(&mut self, step: isize) {
+        let processes = match self.table.as_ref() {
+            Some(table) if !table.processes.is_empty() => &table.processes,
+            _ => return,
+        };
+
+        let index = self
+            .selected
+            .and_then(|pid| processes.iter().position(|process| process.pid == pid))
+            .unwrap_or(0);
+
+        let index = index.saturating_add_signed(step).min(processes.len() - 1);
+
+        self.selected = Some(processes[index].pid);
+    }
+
+    fn prompt_with_target(&mut self, prompt: impl FnOnce(Target) -> Prompt) {
+        match self.target() {
+            Some(target) => self.prompt = Some(prompt(target)),
+            None => self.message = Some("No process is selected.".to_string()),
+        }
+    }
+
+    /// Handle a key. Break if the program should quit.
+    fn on_key(&mut self, key: u8) -> ControlFlow<()> {
+        self.message = None;
+
+        match self.prompt.take() {
+            Some(prompt) => self.on_prompt_key(prompt, key),
+            None => match key {
+                b'q' => return ControlFlow::Break(()),
+                b'j' => self.move_cursor(1),
+                b'k' => self.move_cursor(-1),
+                b't' => self.prompt_with_target(|target| Prompt::Signal(target, libc::SIGTERM)),
+                b'K' => self.prompt_with_target(|target| Prompt::Signal(target, libc::SIGKILL)),
+                b'h' => self.prompt_with_target(|target| Prompt::Signal(target, libc::SIGHUP)),
+                b'x' => {
+                    self.prompt_with_target(|target| Prompt::CustomSignal(target, String::new()))
+                },
+                b'n' => self.prompt_with_target(|target| Prompt::Nice(target, String::new())),
+                b'/' => {
+                    let input = self.program_filter.as_ref().map(|regex| regex.to_string());
+
+                    self.prompt = Some(Prompt::Filter(input.unwrap_or_default()));
+                },
+                b'p' | b' ' => self.paused = !self.paused,
+                b's' => self.sort = self.sort.next(),
+                b'r' => self.reverse = !self.reverse,
+                _ => (),
+            },
+        }
+
+        ControlFlow::Continue(())
+    }
+
+    fn on_prompt_key(&mut self, prompt: Prompt, key: u8) {
+        match prompt {
+            Prompt::Signal(target, signal) => {
+                let message = if key == b'y' || key == b'Y' {
+                    match send_signal(target.pid, signal) {
+                        Ok(()) => format!("Sent {} to {target}.", signal_name(signal)),
+                        Err(error) => {
+                            format!("Cannot send {} to {target}: {error}", signal_name(signal))
+                        },
+                    }
+                } else {
+                    "Canceled.".to_string()
+                };
+
+                self.message = Some(message);
+            },
+            Prompt::CustomSignal(target, mut input) => {
+                if !edit(&mut input, key) {
+                    self.prompt = Some(Prompt::CustomSignal(target, input));
+                } else if input.trim().is_empty() {
+                    self.message = Some("Canceled.".to_string());
+                } else {
+                    match parse_signal(&input) {
+                        Some(signal) => self.prompt = Some(Prompt::Signal(target, signal)),
+                        None => self.message = Some(format!("Unknown signal {:?}.", input.trim())),
+                    }
+                }
+            },
+            Prompt::Nice(target, mut input) => {
+                if !edit(&mut input, key) {
+                    self.prompt = Some(Prompt::Nice(target, input));
+                } else if input.trim().is_empty() {
+                    self.message = Some("Canceled.".to_string());
+                } else {
+                    let message = match input.trim().parse::<c_int>() {
+                        Ok(nice) if (-20..=19).contains(&nice) => match renice(target.pid, nice) {
+                            Ok(()) => format!("Set the nice value of {target} to {nice}."),
+                            Err(error) => format!("Cannot renice {target}: {error}"),
+                        },
+                        _ => "The nice value must be an integer from -20 to 19.".to_string(),
+                    };
+
+                    self.message = Some(message);
+                }
+            },
+            Prompt::Filter(mut input) => {
+                if !edit(&mut input, key) {
+                    self.prompt = Some(Prompt::Filter(input));
+                } else if input.is_empty() {
+                    self.program_filter = None;
+                    self.message = Some("The filter is removed.".to_string());
+                } else {
+                    match Regex::new(&input) {
+                        Ok(regex) => {
+                            self.program_filter = Some(regex);
+                            self.message = Some(format!("Filtered by {input:?}."));
+                        },
+                        Err(error) => self.message = Some(format!("Invalid regex: {error}")),
+                    }
+                }
+            },
+        }
+    }
+
+    /// The first status line, which shows the prompt, the result of the last action or the state.
+    fn status(&self) -> String {
+        if let Some(prompt) = self.prompt.as_ref() {
+            return match prompt {
+                Prompt::Signal(target, signal) => {
+                    format!("Send {} to {target}? [y/N]", signal_name(*signal))
+                },
+                Prompt::CustomSignal(target, input) => {
+                    format!("Signal to send to {target}: {input}")
+                },
+                Prompt::Nice(target, input) => format!("Nice value of {target}: {input}"),
+                Prompt::Filter(input) => format!("Filter programs by a regex: {input}"),
+            };
+        }
+
+        if let Some(message) = self.message.as_ref() {
+            return message.clone();
+        }
+
+        let mut status = String::new();
+
+        if let Some(target) = self.target() {
+            status.push_str(&format!("{target} is selected. "));
+        }
+
+        status.push_str(&format!("Sorted by {}", self.sort.as_str()));
+
+        if self.reverse {
+            status.push_str(" (reversed)");
+        }
+
+        status.push('.');
+
+        if let Some(regex) = self.program_filter.as_ref() {
+            status.push_str(&format!(" Filtered by {:?}.", regex.as_str()));
+        }
+
+        if self.paused {
+            status.push_str(" Paused.");
+        }
+
+        status
+    }
+
+    fn draw(&self) {
+        io::stdout().write_all(&CLEAR_SCREEN_DATA).unwrap();
+
+        if let Some(table) = self.table.as_ref() {
+            self.view.draw(table, self.target().map(|target| target.pid), &self.user_cache);
+        }
+
+        let terminal_width = get_term_width();
+
+        // a line must not be wrapped, or the table is scrolled out
+        let fit = |s: &str| -> String {
+            s.split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" ")
+                .chars()
+                .take(terminal_width)
+                .collect()
+        };
+
+        let output = get_stdout_output();
+        let mut stdout = output.buffer();
+
+        stdout.set_color(&COLOR_BOLD_TEXT).unwrap();
+        writeln!(&mut stdout, "{}", fit(&self.status())).unwrap();
+
+        stdout.set_color(&COLOR_LABEL).unwrap();
+        write!(&mut stdout, "{}", fit(HELP)).unwrap();
+
+        stdout.set_color(&COLOR_DEFAULT).unwrap();
+
+        output.print(&stdout).unwrap();
+    }
+}
+
+/// Run the monitor mode. Keys are handled instantly, and the processes are refreshed every `monitor` duration unless it is paused.
+pub(super) fn run(
+    interactive: Interactive,
+    monitor: Duration,
+    mut fetch: impl FnMut(
+        Option<Duration>,
+        ProcessSort,
+        bool,
+        Option<&Regex>,
+    ) -> anyhow::Result<ProcessTable>,
+) -> anyhow::Result<()> {
+    let interactive = Arc::new(Mutex::new(interactive));
+
+    {
+        let interactive = interactive.clone();
+
+        thread::spawn(move || {
+            loop {
+                let key = match ::getch::Getch::new().getch() {
+                    Ok(key) if key != 0 => key,
+                    // stdin is closed, so only Ctrl+C works
+                    _ => return,
+                };
+
+                let mut interactive = interactive.lock().unwrap();
+
+                if interactive.on_key(key).is_break() {
+                    break;
+                }
+
+                interactive.draw();
+            }
+
+            // the last status line is not ended
+            println!();
+
+            ::std::process::exit(0);
+        });
+    }
+
+    // the first refresh is faster, like the other monitor modes
+    let mut interval = DEFAULT_INTERVAL;
+
+    loop {
+        let started = Instant::now();
+
+        let (sort, reverse, program_filter, paused) = {
+            let interactive = interactive.lock().unwrap();
+
+            (
+                interactive.sort,
+                interactive.reverse,
+                interactive.program_filter.clone(),
+                interactive.paused,
+            )
+        };
+
+        if !paused {
+            let table = fetch(Some(interval), sort, reverse, program_filter.as_ref())?;
+
+            let mut interactive = interactive.lock().unwrap();
+
+            // the refresh is dropped if it is paused during the detection
+            if !interactive.paused {
+                interactive.table = Some(table);
+                interactive.draw();
+            }
+        }
+
+        // the CPU usage is detected in the `interval`, but the other stats are not
+        if let Some(remaining) = interval.checked_sub(started.elapsed()) {
+            thread::sleep(remaining);
+        }
+
+        interval = monitor;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::BTreeMap;
+
+    use chrono::Utc;
+    use mprober_lib::process::{Process, ProcessState};
+
+    use super::*;
+
+    fn create_process(pid: u32, program: &str) -> Process {
+        Process {
+            pid,
+            effective_uid: 0,
+            effective_gid: 0,
+            state: ProcessState::Sleeping,
+            ppid: 1,
+            program: program.to_string(),
+            cmdline: program.to_string(),
+            tty: None,
+            priority: 20,
+            real_time_priority: None,
+            nice: 0,
+            threads: 1,
+            vsz: 0,
+            rss: 0,
+            rss_shared: 0,
+            rss_anon: 0,
+            start_time: Utc::now(),
+        }
+    }
+
+    fn create_interactive() -> Interactive {
+        let mut interactive = Interactive::new(ProcessSort::Cpu, false, None, View {
+            truncate:         7,
+            unit:             None,
+            only_information: false,
+            start_time:       false,
+        });
+
+        interactive.table = Some(ProcessTable {
+            processes:  vec![create_process(10, "bash"), create_process(20, "vim")],
+            percentage: BTreeMap::new(),
+            nodes:      None,
+        });
+
+        interactive
+    }
+
+    fn press(interactive: &mut Interactive, keys: &[u8]) {
+        for key in keys.iter().copied() {
+            assert!(interactive.on_key(key).is_continue());
+        }
+    }
+
+    #[test]
+    fn test_parse_signal() {
+        assert_eq!(Some(libc::SIGTERM), parse_signal("term"));
+        assert_eq!(Some(libc::SIGUSR1), parse_signal(" SIGUSR1 "));
+        assert_eq!(Some(9), parse_signal("9"));
+        assert_eq!(None, parse_signal("0"));
+        assert_eq!(None, parse_signal("SIGFOO"));
+    }
+
+    #[test]
+    fn test_cursor() {
+        let mut interactive = create_interactive();
+
+        assert_eq!(10, interactive.target().unwrap().pid);
+
+        press(&mut interactive, b"jjj");
+
+        assert_eq!(20, interactive.target().unwrap().pid);
+
+        press(&mut interactive, b"k");
+
+        assert_eq!(10, interactive.target().unwrap().pid);
+    }
+
+    #[test]
+    fn test_prompts() {
+        let mut interactive = create_interactive();
+
+        // a signal is sent only if it is confirmed
+        press(&mut interactive, b"jt");
+
+        assert_eq!(
+            Some(Prompt::Signal(
+                Target {
+                    pid: 20, program: "vim".to_string()
+                },
+                libc::SIGTERM
+            )),
+            interactive.prompt
+        );
+
+        press(&mut interactive, b"n");
+
+        assert_eq!(None, interactive.prompt);
+        assert_eq!(Some("Canceled."), interactive.message.as_deref());
+
+        press(&mut interactive, b"xusr2\n");
+
+        assert!(matches!(interactive.prompt, Some(Prompt::Signal(_, libc::SIGUSR2))));
+
+        press(&mut interactive, b"n");
+
+        press(&mut interactive, b"n99\n");
+
+        assert_eq!(
+            Some("The nice value must be an integer from -20 to 19."),
+            interactive.message.as_deref()
+        );
+
+        // `q` is a part of the input, not quitting
+        press(&mut interactive, b"/q\x7fba\n");
+
+        assert_eq!(Some("ba"), interactive.program_filter.as_ref().map(|regex| regex.as_str()));
+
+        press(&mut interactive, b"/\x7f\x7f\n");
+
+        assert!(interactive.program_filter.is_none());
+
+        assert!(interactive.on_key(b'q').is_break());
+    }
+}
@@ -1,14 +1,57 @@
-use std::{cmp::Ordering, collections::BTreeMap, sync::Arc};
+mod interactive;
+
+use std::{
+    borrow::Cow,
+    cmp::Ordering,
+    collections::{BTreeMap, HashMap},
+    ffi::OsString,
+    sync::Arc,
+};
 
 use anyhow::anyhow;
 use byte_unit::{Byte, Unit, UnitType};
 use chrono::SecondsFormat;
+use interactive::Interactive;
 use mprober_lib::process;
 use regex::Regex;
 use terminal_size::terminal_size;
 use users::{Group, Groups, User, Users, UsersCache};
 
-use crate::{terminal::*, CLIArgs, CLICommands};
+use crate::{output::json_handler, terminal::*, CLIArgs, CLICommands, ProcessSort};
+
+/// The options to draw the table of processes.
+#[derive(Debug, Clone, Copy)]
+struct View {
+    truncate:         usize,
+    unit:             Option<Unit>,
+    only_information: bool,
+    start_time:       bool,
+}
+
+impl View {
+    #[inline]
+    fn draw(&self, table: &ProcessTable, selected: Option<u32>, user_cache: &UsersCache) {
+        draw_process(
+            &table.processes,
+            &table.percentage,
+            self.truncate,
+            self.unit,
+            self.only_information,
+            self.start_time,
+            table.nodes.as_deref(),
+            selected,
+            user_cache,
+        )
+    }
+}
+
+/// The processes to draw, with their positions in the tree if the tree is shown.
+#[derive(Debug)]
+struct ProcessTable {
+    processes:  Vec<process::Process>,
+    percentage: BTreeMap<u32, f64>,
+    nodes:      Option<Vec<TreeNode>>,
+}
 
 #[inline]
 pub fn handle_process(args: CLIArgs) -> anyhow::Result<()> {
@@ -21,8 +64,11 @@ pub fn handle_process(args: CLIArgs) -> anyhow::Result<()> {
         unit,
         only_information,
         top,
+        sort,
+        reverse,
         truncate,
         start_time,
+        tree,
         user_filter,
         group_filter,
         program_filter,
@@ -35,80 +81,130 @@ pub fn handle_process(args: CLIArgs) -> anyhow::Result<()> {
         let program_filter = program_filter.as_ref();
         let tty_filter = tty_filter.as_ref();
 
+        if args.output.is_json() {
+            if tree {
+                return Err(anyhow!("The tree of processes cannot be shown in JSON."));
+            }
+
+            let user_cache = UsersCache::new();
+
+            return json_handler(args.output, monitor, !only_information, || {
+                let (processes, percentage) = get_processes(
+                    monitor,
+                    top,
+                    sort,
+                    reverse,
+                    only_information,
+                    user_filter,
+                    group_filter,
+                    program_filter,
+                    tty_filter,
+                    pid_filter,
+                    &user_cache,
+                )?;
+
+                Ok(crate::json::processes(&processes, &percentage, &user_cache))
+            });
+        }
+
         set_color_mode(plain, light);
 
-        monitor_handler!(
-            monitor,
-            draw_process(
-                monitor,
-                top,
-                truncate,
-                unit,
-                only_information,
-                start_time,
-                user_filter,
-                group_filter,
-                program_filter,
-                tty_filter,
-                pid_filter,
-            )?,
-            draw_process(
-                Some(DEFAULT_INTERVAL),
-                top,
-                truncate,
-                unit,
+        let view = View {
+            truncate,
+            unit,
+            only_information,
+            start_time,
+        };
+
+        let user_cache = UsersCache::new();
+
+        let fetch = |interval: Option<Duration>,
+                     sort: ProcessSort,
+                     reverse: bool,
+                     program_filter: Option<&Regex>|
+         -> anyhow::Result<ProcessTable> {
+            let top = get_top(monitor.is_some(), top);
+
+            // the rows are limited after the tree is built, so that no parent is missing
+            let (processes, percentage) = get_processes(
+                interval,
+                if tree { None } else { top },
+                sort,
+                reverse,
                 only_information,
-                start_time,
                 user_filter,
                 group_filter,
                 program_filter,
                 tty_filter,
                 pid_filter,
-            )?,
-            only_information
-        );
+                &user_cache,
+            )?;
+
+            let (processes, nodes) = if tree {
+                let (processes, nodes) = process_tree(processes, &percentage, sort, reverse, top);
+
+                (processes, Some(nodes))
+            } else {
+                (processes, None)
+            };
+
+            Ok(ProcessTable {
+                processes,
+                percentage,
+                nodes,
+            })
+        };
+
+        match monitor {
+            Some(monitor) => {
+                let interactive = Interactive::new(sort, reverse, program_filter.cloned(), view);
+
+                interactive::run(interactive, monitor, fetch)?;
+            },
+            None => {
+                let table = fetch(None, sort, reverse, program_filter)?;
+
+                view.draw(&table, None, &user_cache);
+            },
+        }
     }
 
     Ok(())
 }
 
-#[allow(unused_variables)]
-#[allow(unused_mut)]
+/// In the monitor mode, the number of processes is limited by the height of the terminal, leaving lines for the header and the status lines.
+fn get_top(monitor: bool, top: Option<usize>) -> Option<usize> {
+    if !monitor {
+        return top;
+    }
+
+    match terminal_size() {
+        Some((_, height)) => {
+            let height = (height.0 as usize).max(3) - 3;
+
+            match top {
+                Some(top) => Some(top.min(height)),
+                None => Some(height),
+            }
+        },
+        None => top,
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
-fn draw_process(
+pub fn get_processes(
     monitor: Option<Duration>,
-    mut top: Option<usize>,
-    truncate: usize,
-    unit: Option<Unit>,
+    top: Option<usize>,
+    sort: ProcessSort,
+    reverse: bool,
     only_information: bool,
-    start_time: bool,
     user_filter: Option<&str>,
     group_filter: Option<&str>,
     program_filter: Option<&Regex>,
     tty_filter: Option<&Regex>,
     pid_filter: Option<u32>,
-) -> anyhow::Result<()> {
-    let output = get_stdout_output();
-    let mut stdout = output.buffer();
-
-    let terminal_width = match terminal_size() {
-        Some((width, height)) => {
-            if monitor.is_some() {
-                let height = (height.0 as usize).max(2) - 2;
-
-                top = match top {
-                    Some(top) => Some(top.min(height)),
-                    None => Some(height),
-                };
-            }
-
-            (width.0 as usize).max(MIN_TERMINAL_WIDTH)
-        },
-        None => DEFAULT_TERMINAL_WIDTH,
-    };
-
-    let user_cache = UsersCache::new();
-
+    user_cache: &UsersCache,
+) -> anyhow::Result<(Vec<process::Process>, BTreeMap<u32, f64>)> {
     let uid_filter = match user_filter {
         Some(user_filter) => match user_cache.get_user_by_name(user_filter) {
             Some(user) => Some(user.uid()),
@@ -137,78 +233,278 @@ fn draw_process(
         tty_filter,
     };
 
-    let (processes, percentage): (Vec<process::Process>, BTreeMap<u32, f64>) = if only_information {
-        let mut processes_with_stats = process::get_processes_with_stat(&process_filter).unwrap();
+    let mut processes_with_percentage = if only_information {
+        process::get_processes_with_stat(&process_filter)?
+            .into_iter()
+            .map(|(process, _)| (process, 0.0))
+            .collect()
+    } else {
+        process::get_processes_with_cpu_utilization_in_percentage(&process_filter, match monitor {
+            Some(monitor) => monitor,
+            None => DEFAULT_INTERVAL,
+        })?
+    };
 
-        processes_with_stats.sort_unstable_by(|(a, _), (b, _)| b.vsz.cmp(&a.vsz));
+    // reading the CPU time costs a file per process, so it is only read for sorting by it
+    let cpu_times: HashMap<u32, u64> = match sort {
+        ProcessSort::Time => processes_with_percentage
+            .iter()
+            .map(|(process, _)| {
+                let cpu_time = process::get_process_time_stat(process.pid)
+                    .map(|stat| stat.utime as u64 + stat.stime as u64)
+                    .unwrap_or(0);
+
+                (process.pid, cpu_time)
+            })
+            .collect(),
+        _ => HashMap::new(),
+    };
 
-        if let Some(top) = top {
-            if top < processes_with_stats.len() {
-                unsafe {
-                    processes_with_stats.set_len(top);
-                }
-            }
-        }
+    let user_names: HashMap<u32, Option<OsString>> = match sort {
+        ProcessSort::User => processes_with_percentage
+            .iter()
+            .map(|(process, _)| {
+                let uid = process.effective_uid;
 
-        (processes_with_stats.into_iter().map(|(process, _)| process).collect(), BTreeMap::new())
-    } else {
-        let mut processes_with_percentage =
-            process::get_processes_with_cpu_utilization_in_percentage(
-                &process_filter,
-                match monitor {
-                    Some(monitor) => monitor,
-                    None => DEFAULT_INTERVAL,
-                },
-            )
-            .unwrap();
-
-        processes_with_percentage.sort_unstable_by(
-            |(process_a, percentage_a), (process_b, percentage_b)| {
-                let percentage_a = *percentage_a;
-                let percentage_b = *percentage_b;
-
-                if percentage_a > 0.01 {
-                    if percentage_a > percentage_b {
-                        Ordering::Less
-                    } else if percentage_b > 0.01
-                    // percentage_a == percentage_b hardly happens
-                    {
-                        Ordering::Greater
-                    } else {
+                (uid, user_cache.get_user_by_uid(uid).map(|user| user.name().to_os_string()))
+            })
+            .collect(),
+        _ => HashMap::new(),
+    };
+
+    processes_with_percentage.sort_unstable_by(
+        |(process_a, percentage_a), (process_b, percentage_b)| {
+            let ordering = match sort {
+                ProcessSort::Cpu => {
+                    if only_information {
                         process_b.vsz.cmp(&process_a.vsz)
-                    }
-                } else if percentage_b > 0.01 {
-                    if percentage_b > percentage_a {
-                        Ordering::Greater
                     } else {
-                        process_b.vsz.cmp(&process_a.vsz)
+                        compare_cpu(process_a, *percentage_a, process_b, *percentage_b)
                     }
-                } else {
-                    process_b.vsz.cmp(&process_a.vsz)
-                }
-            },
-        );
+                },
+                ProcessSort::Rss => process_b.rss.cmp(&process_a.rss),
+                ProcessSort::Vsz => process_b.vsz.cmp(&process_a.vsz),
+                ProcessSort::Anon => process_b.rss_anon.cmp(&process_a.rss_anon),
+                ProcessSort::Pid => process_a.pid.cmp(&process_b.pid),
+                ProcessSort::User => {
+                    user_names[&process_a.effective_uid].cmp(&user_names[&process_b.effective_uid])
+                },
+                ProcessSort::Start => process_a.start_time.cmp(&process_b.start_time),
+                ProcessSort::Time => cpu_times[&process_b.pid].cmp(&cpu_times[&process_a.pid]),
+                ProcessSort::Threads => process_b.threads.cmp(&process_a.threads),
+                ProcessSort::Name => process_a.program.cmp(&process_b.program),
+            };
 
-        if let Some(top) = top {
-            if top < processes_with_percentage.len() {
-                unsafe {
-                    processes_with_percentage.set_len(top);
-                }
+            ordering.then(process_a.pid.cmp(&process_b.pid))
+        },
+    );
+
+    if reverse {
+        processes_with_percentage.reverse();
+    }
+
+    if let Some(top) = top {
+        processes_with_percentage.truncate(top);
+    }
+
+    let mut processes = Vec::with_capacity(processes_with_percentage.len());
+    let mut processes_percentage = BTreeMap::new();
+
+    for (process, percentage) in processes_with_percentage {
+        if !only_information {
+            processes_percentage.insert(process.pid, percentage);
+        }
+
+        processes.push(process);
+    }
+
+    Ok((processes, processes_percentage))
+}
+
+/// Processes using more than 1% CPU come first and are ordered by the CPU usage. The others are ordered by VSZ.
+fn compare_cpu(
+    process_a: &process::Process,
+    percentage_a: f64,
+    process_b: &process::Process,
+    percentage_b: f64,
+) -> Ordering {
+    match (percentage_a > 0.01, percentage_b > 0.01) {
+        (true, true) => percentage_b.total_cmp(&percentage_a),
+        (true, false) => Ordering::Less,
+        (false, true) => Ordering::Greater,
+        (false, false) => process_b.vsz.cmp(&process_a.vsz),
+    }
+}
+
+/// The position of a process in the tree of processes, and the stats of the subtree rooted at it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TreeNode {
+    /// The indentation guides drawn before the command, e.g. `|  `- `.
+    pub guides: String,
+    /// The CPU utilization of the process and all of its descendants.
+    pub cpu:    f64,
+    /// The resident set size of the process and all of its descendants.
+    pub rss:    usize,
+}
+
+/// Arrange processes into a tree by their PPIDs, in the pre-order. A process whose parent is not in `processes` becomes a root, so the process matched by `--pid-filter` is the root of its subtree. Siblings are ordered by the CPU utilization or the RSS of their subtrees if `sort` is one of them, or else they keep the order of `processes`. Only the first `top` rows are kept.
+pub fn process_tree(
+    processes: Vec<process::Process>,
+    percentage: &BTreeMap<u32, f64>,
+    sort: ProcessSort,
+    reverse: bool,
+    top: Option<usize>,
+) -> (Vec<process::Process>, Vec<TreeNode>) {
+    let entries = processes
+        .iter()
+        .map(|process| {
+            (
+                process.pid,
+                process.ppid,
+                percentage.get(&process.pid).copied().unwrap_or(0.0),
+                process.rss,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let mut processes = processes.into_iter().map(Some).collect::<Vec<_>>();
+
+    tree_order(&entries, sort, reverse)
+        .into_iter()
+        .take(top.unwrap_or(usize::MAX))
+        .map(|(index, node)| (processes[index].take().unwrap(), node))
+        .unzip()
+}
+
+/// `entries` are `(pid, ppid, cpu, rss)`. Return the indices of `entries` in the pre-order of the tree with their nodes.
+fn tree_order(
+    entries: &[(u32, u32, f64, usize)],
+    sort: ProcessSort,
+    reverse: bool,
+) -> Vec<(usize, TreeNode)> {
+    struct Tree<'a> {
+        children: Vec<Vec<usize>>,
+        totals:   Vec<(f64, usize)>,
+        order:    Vec<(usize, TreeNode)>,
+        entries:  &'a [(u32, u32, f64, usize)],
+    }
+
+    impl Tree<'_> {
+        fn sum(&mut self, index: usize) -> (f64, usize) {
+            let (_, _, mut cpu, mut rss) = self.entries[index];
+
+            for i in 0..self.children[index].len() {
+                let (child_cpu, child_rss) = self.sum(self.children[index][i]);
+
+                cpu += child_cpu;
+                rss += child_rss;
             }
+
+            self.totals[index] = (cpu, rss);
+
+            (cpu, rss)
         }
 
-        let mut processes = Vec::with_capacity(processes_with_percentage.len());
-        let mut processes_percentage = BTreeMap::new();
+        fn walk(&mut self, index: usize, guides: String, continuation: &str) {
+            let (cpu, rss) = self.totals[index];
 
-        for (process, percentage) in processes_with_percentage {
-            processes_percentage.insert(process.pid, percentage);
+            self.order.push((index, TreeNode {
+                guides,
+                cpu,
+                rss,
+            }));
+
+            let children_len = self.children[index].len();
+
+            for i in 0..children_len {
+                let (guide, next_continuation) =
+                    if i + 1 == children_len { ("`- ", "   ") } else { ("|- ", "|  ") };
 
-            processes.push(process);
+                self.walk(
+                    self.children[index][i],
+                    format!("{continuation}{guide}"),
+                    &format!("{continuation}{next_continuation}"),
+                );
+            }
+        }
+    }
+
+    let indices = entries
+        .iter()
+        .enumerate()
+        .map(|(index, entry)| (entry.0, index))
+        .collect::<HashMap<_, _>>();
+
+    let mut tree = Tree {
+        children: vec![Vec::new(); entries.len()],
+        totals: vec![(0.0, 0); entries.len()],
+        order: Vec::with_capacity(entries.len()),
+        entries,
+    };
+
+    let mut roots = Vec::new();
+
+    for (index, &(pid, ppid, ..)) in entries.iter().enumerate() {
+        match indices.get(&ppid) {
+            Some(&parent) if ppid != pid => tree.children[parent].push(index),
+            _ => roots.push(index),
         }
+    }
+
+    for &root in roots.iter() {
+        tree.sum(root);
+    }
+
+    let totals = &tree.totals;
 
-        (processes, processes_percentage)
+    let compare = |a: &usize, b: &usize| {
+        let (cpu_a, rss_a) = totals[*a];
+        let (cpu_b, rss_b) = totals[*b];
+
+        let ordering = match sort {
+            ProcessSort::Cpu => cpu_b.total_cmp(&cpu_a).then(rss_b.cmp(&rss_a)),
+            ProcessSort::Rss => rss_b.cmp(&rss_a),
+            // the entries have been sorted by the other keys, and the sort is stable
+            _ => return Ordering::Equal,
+        };
+
+        if reverse {
+            ordering.reverse()
+        } else {
+            ordering
+        }
     };
 
+    roots.sort_by(compare);
+
+    for children in tree.children.iter_mut() {
+        children.sort_by(compare);
+    }
+
+    for root in roots {
+        tree.walk(root, String::new(), "");
+    }
+
+    tree.order
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn draw_process(
+    processes: &[process::Process],
+    percentage: &BTreeMap<u32, f64>,
+    truncate: usize,
+    unit: Option<Unit>,
+    only_information: bool,
+    start_time: bool,
+    tree: Option<&[TreeNode]>,
+    selected: Option<u32>,
+    user_cache: &UsersCache,
+) {
+    let output = get_stdout_output();
+    let mut stdout = output.buffer();
+
+    let terminal_width = get_term_width();
+
     let processes_len = processes.len();
 
     let mut pid: Vec<String> = Vec::with_capacity(processes_len);
@@ -222,6 +518,7 @@ fn draw_process(
     let mut group: Vec<Arc<Group>> = Vec::with_capacity(processes_len);
     let mut program: Vec<&str> = Vec::with_capacity(processes_len);
     let mut state: Vec<&'static str> = Vec::with_capacity(processes_len);
+    let mut tree_rss: Vec<String> = Vec::new();
 
     for process in processes.iter() {
         pid.push(process.pid.to_string());
@@ -263,12 +560,24 @@ fn draw_process(
         state.push(process.state.as_str());
     }
 
+    if let Some(tree) = tree {
+        for node in tree {
+            let p_rss = Byte::from(node.rss);
+
+            tree_rss.push(match unit {
+                Some(byte_unit) => format!("{:.1}", p_rss.get_adjusted_unit(byte_unit)),
+                None => format!("{:.1}", p_rss.get_appropriate_unit(UnitType::Binary)),
+            });
+        }
+    }
+
     let truncate_inc = if truncate == 0 { usize::MAX } else { truncate + 1 };
 
     let pid_len = pid.iter().map(|s| s.len()).max().map(|s| s.max(5)).unwrap_or(0);
     let ppid_len = ppid.iter().map(|s| s.len()).max().map(|s| s.max(5)).unwrap_or(0);
     let vsz_len = vsz.iter().map(|s| s.len()).max().map(|s| s.max(9)).unwrap_or(0);
     let rss_len = rss.iter().map(|s| s.len()).max().map(|s| s.max(9)).unwrap_or(0);
+    let tree_rss_len = tree_rss.iter().map(|s| s.len()).max().map(|s| s.max(9)).unwrap_or(0);
     let anon_len = anon.iter().map(|s| s.len()).max().map(|s| s.max(9)).unwrap_or(0);
     let thd_len = thd.iter().map(|s| s.len()).max().map(|s| s.max(3)).unwrap_or(0);
     let tty_len = tty.iter().map(|s| s.len()).max().map(|s| s.max(4)).unwrap_or(0);
@@ -343,6 +652,15 @@ fn draw_process(
 
             write!(&mut stdout, " %CPU").unwrap(); // 5
             width += 5;
+
+            if tree.is_some() {
+                if width + 6 > terminal_width {
+                    break;
+                }
+
+                write!(&mut stdout, " T%CPU").unwrap(); // 6
+                width += 6;
+            }
         }
 
         if width + 1 + vsz_len > terminal_width {
@@ -369,6 +687,20 @@ fn draw_process(
         write!(&mut stdout, "RSS").unwrap(); // 3
         width += 3;
 
+        if tree.is_some() {
+            if width + 1 + tree_rss_len > terminal_width {
+                break;
+            }
+
+            for _ in 3..tree_rss_len {
+                write!(&mut stdout, " ").unwrap(); // 1
+                width += 1;
+            }
+
+            write!(&mut stdout, "TRSS").unwrap(); // 4
+            width += 4;
+        }
+
         if width + 1 + anon_len > terminal_width {
             break;
         }
@@ -491,9 +823,15 @@ fn draw_process(
     let mut program_iter = program.into_iter();
     let mut state_iter = state.into_iter();
 
-    for process in processes.iter() {
+    for (i, process) in processes.iter().enumerate() {
         let mut width = 0;
 
+        let (color_bold_text, color_normal_text) = if selected == Some(process.pid) {
+            (&*COLOR_SELECTED, &*COLOR_SELECTED)
+        } else {
+            (&*COLOR_BOLD_TEXT, &*COLOR_NORMAL_TEXT)
+        };
+
         if width + pid_len > terminal_width {
             stdout.set_color(&COLOR_DEFAULT).unwrap();
             writeln!(&mut stdout).unwrap();
@@ -503,7 +841,7 @@ fn draw_process(
 
         let pid = pid_iter.next().unwrap();
 
-        stdout.set_color(&COLOR_BOLD_TEXT).unwrap();
+        stdout.set_color(color_bold_text).unwrap();
         write!(&mut stdout, "{1:>0$}", pid_len, pid).unwrap();
         width += pid_len;
 
@@ -511,7 +849,7 @@ fn draw_process(
             continue;
         }
 
-        stdout.set_color(&COLOR_NORMAL_TEXT).unwrap();
+        stdout.set_color(color_normal_text).unwrap();
 
         let ppid = ppid_iter.next().unwrap();
 
@@ -557,6 +895,18 @@ fn draw_process(
 
             write!(&mut stdout, " {:>4.1}", percentage.get(&process.pid).unwrap() * 100.0).unwrap();
             width += 5;
+
+            if let Some(tree) = tree {
+                if width + 6 > terminal_width {
+                    stdout.set_color(&COLOR_DEFAULT).unwrap();
+                    writeln!(&mut stdout).unwrap();
+
+                    continue;
+                }
+
+                write!(&mut stdout, " {:>5.1}", tree[i].cpu * 100.0).unwrap();
+                width += 6;
+            }
         }
 
         if width + 1 + vsz_len > terminal_width {
@@ -593,6 +943,25 @@ fn draw_process(
         stdout.write_all(rss.as_bytes()).unwrap();
         width += rss.len();
 
+        if tree.is_some() {
+            if width + 1 + tree_rss_len > terminal_width {
+                stdout.set_color(&COLOR_DEFAULT).unwrap();
+                writeln!(&mut stdout).unwrap();
+
+                continue;
+            }
+
+            let tree_rss = tree_rss[i].as_str();
+
+            for _ in 0..=(tree_rss_len - tree_rss.len()) {
+                write!(&mut stdout, " ").unwrap(); // 1
+                width += 1;
+            }
+
+            stdout.write_all(tree_rss.as_bytes()).unwrap();
+            width += tree_rss.len();
+        }
+
         if width + 1 + anon_len > terminal_width {
             stdout.set_color(&COLOR_DEFAULT).unwrap();
             writeln!(&mut stdout).unwrap();
@@ -663,7 +1032,7 @@ fn draw_process(
             let s = user.name().to_str().unwrap();
 
             if s.len() > truncate_inc {
-                stdout.write_all(s[..(truncate_inc - 1)].as_bytes()).unwrap();
+                stdout.write_all(truncate_str(s, truncate_inc - 1).as_bytes()).unwrap();
                 write!(&mut stdout, "+").unwrap(); // 1
                 width += truncate_inc;
 
@@ -698,7 +1067,7 @@ fn draw_process(
             let s = group.name().to_str().unwrap();
 
             if s.len() > truncate_inc {
-                stdout.write_all(s[..(truncate_inc - 1)].as_bytes()).unwrap();
+                stdout.write_all(truncate_str(s, truncate_inc - 1).as_bytes()).unwrap();
                 write!(&mut stdout, "+").unwrap(); // 1
                 width += truncate_inc;
 
@@ -730,7 +1099,7 @@ fn draw_process(
         width += 1;
 
         if program.len() > truncate_inc {
-            stdout.write_all(program[..(truncate_inc - 1)].as_bytes()).unwrap();
+            stdout.write_all(truncate_str(program, truncate_inc - 1).as_bytes()).unwrap();
             write!(&mut stdout, "+").unwrap(); // 1
             width += truncate_inc;
 
@@ -797,14 +1166,16 @@ fn draw_process(
 
         let remain_width = terminal_width - width;
 
-        if process.cmdline.len() > remain_width {
-            let cmdline =
-                String::from_utf8_lossy(&process.cmdline.as_bytes()[..(remain_width - 1)]);
+        let cmdline = match tree {
+            Some(tree) => Cow::Owned(format!("{}{}", tree[i].guides, process.cmdline)),
+            None => Cow::Borrowed(process.cmdline.as_str()),
+        };
 
-            stdout.write_all(cmdline.as_bytes()).unwrap();
+        if cmdline.len() > remain_width {
+            stdout.write_all(truncate_str(&cmdline, remain_width - 1).as_bytes()).unwrap();
             write!(&mut stdout, "+").unwrap(); // 1
         } else {
-            stdout.write_all(process.cmdline.as_bytes()).unwrap();
+            stdout.write_all(cmdline.as_bytes()).unwrap();
         }
 
         stdout.set_color(&COLOR_DEFAULT).unwrap();
@@ -812,6 +1183,77 @@ fn draw_process(
     }
 
     output.print(&stdout).unwrap();
+}
 
-    Ok(())
+/// Cut `s` to at most `len` characters.
+fn truncate_str(s: &str, len: usize) -> &str {
+    match s.char_indices().nth(len) {
+        Some((index, _)) => &s[..index],
+        None => s,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_truncate_str() {
+        assert_eq!("mprober", truncate_str("mprober", 7));
+        assert_eq!("mpro", truncate_str("mprober", 4));
+        // "é" takes two bytes
+        assert_eq!("café", truncate_str("café", 4));
+        assert_eq!("caf", truncate_str("café", 3));
+        assert_eq!("日", truncate_str("日本", 1));
+    }
+
+    #[test]
+    fn test_tree_order() {
+        // (pid, ppid, cpu, rss)
+        let entries = [
+            (1, 0, 0.0, 10),
+            (20, 1, 0.1, 100),
+            (21, 20, 0.0, 5),
+            (30, 1, 0.3, 50),
+            (31, 30, 0.0, 1),
+            (32, 30, 0.0, 2),
+            // the parent is filtered out
+            (40, 9, 0.0, 7),
+        ];
+
+        let order = tree_order(&entries, ProcessSort::Cpu, false)
+            .into_iter()
+            .map(|(index, node)| (entries[index].0, node.guides, node.rss))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![
+                (1, String::new(), 168),
+                (30, "|- ".to_string(), 53),
+                (32, "|  |- ".to_string(), 2),
+                (31, "|  `- ".to_string(), 1),
+                (20, "`- ".to_string(), 105),
+                (21, "   `- ".to_string(), 5),
+                (40, String::new(), 7),
+            ],
+            order
+        );
+    }
+
+    #[test]
+    fn test_tree_order_by_other_keys() {
+        // (pid, ppid, cpu, rss), sorted by PIDs
+        let entries = [(1, 0, 0.0, 10), (20, 1, 0.1, 100), (30, 1, 0.3, 50), (40, 1, 0.0, 70)];
+
+        let pids = |sort, reverse| {
+            tree_order(&entries, sort, reverse)
+                .into_iter()
+                .map(|(index, _)| entries[index].0)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(vec![1, 20, 30, 40], pids(ProcessSort::Pid, false));
+        assert_eq!(vec![1, 20, 40, 30], pids(ProcessSort::Rss, false));
+        assert_eq!(vec![1, 40, 20, 30], pids(ProcessSort::Cpu, true));
+    }
 }
@@ -0,0 +1,33 @@
+use anyhow::Context;
+
+use crate::{push::Pusher, rocket_mounts::Sampler, CLIArgs, CLICommands};
+
+#[inline]
+pub fn handle_push(args: CLIArgs) -> anyhow::Result<()> {
+    debug_assert!(matches!(args.command, CLICommands::Push { .. }));
+
+    if let CLICommands::Push {
+        to,
+        interval,
+        auth_key,
+        host,
+        buffer,
+        timeout,
+    } = args.command
+    {
+        let host = match host {
+            Some(host) => host,
+            None => mprober_lib::hostname::get_hostname().context("Cannot get the hostname.")?,
+        };
+
+        let pusher = Pusher::new(&to, auth_key, host, interval, timeout, buffer);
+
+        rocket::execute(async move {
+            let sampler = Sampler::start(interval);
+
+            pusher.run(sampler).await;
+        });
+    }
+
+    Ok(())
+}
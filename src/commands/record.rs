@@ -0,0 +1,37 @@
+use std::fs::OpenOptions;
+
+use anyhow::Context;
+use users::UsersCache;
+
+use crate::{recording::Snapshot, CLIArgs, CLICommands};
+
+#[inline]
+pub fn handle_record(args: CLIArgs) -> anyhow::Result<()> {
+    debug_assert!(matches!(args.command, CLICommands::Record { .. }));
+
+    if let CLICommands::Record {
+        out,
+        interval,
+        top,
+        count,
+    } = args.command
+    {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&out)
+            .with_context(|| format!("Cannot open {out:?}."))?;
+
+        let user_cache = UsersCache::new();
+
+        let mut taken = 0;
+
+        while count.map(|count| taken < count).unwrap_or(true) {
+            Snapshot::take(interval, top, &user_cache)?.write_to(&mut file, &user_cache)?;
+
+            taken += 1;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,160 @@
+use std::{fs::File, io::BufReader, thread};
+
+use anyhow::{anyhow, Context};
+use byte_unit::Unit;
+use chrono::{DateTime, Utc};
+use users::UsersCache;
+
+use crate::{
+    commands::{draw_cpu_info, draw_memory, draw_network, draw_process, draw_volume, Volumes},
+    output::print_json,
+    recording::{read_snapshots, Snapshot},
+    terminal::*,
+    CLIArgs, CLICommands, ReplayView,
+};
+
+#[inline]
+pub fn handle_replay(args: CLIArgs) -> anyhow::Result<()> {
+    debug_assert!(matches!(args.command, CLICommands::Replay { .. }));
+
+    if let CLICommands::Replay {
+        file,
+        plain,
+        light,
+        unit,
+        from,
+        to,
+        view,
+        speed,
+        truncate,
+    } = args.command
+    {
+        if !(speed >= 0.0 && speed.is_finite()) {
+            return Err(anyhow!("The speed needs to be a non-negative number."));
+        }
+
+        let reader =
+            BufReader::new(File::open(&file).with_context(|| format!("Cannot open {file:?}."))?);
+
+        let snapshots = read_snapshots(reader).filter(|snapshot| match snapshot {
+            Ok(snapshot) => {
+                from.map(|from| snapshot.time >= from).unwrap_or(true)
+                    && to.map(|to| snapshot.time <= to).unwrap_or(true)
+            },
+            Err(_) => true,
+        });
+
+        let user_cache = UsersCache::new();
+
+        if args.output.is_json() {
+            for snapshot in snapshots {
+                print_json(args.output, &snapshot?.to_json(&user_cache))?;
+            }
+
+            return Ok(());
+        }
+
+        set_color_mode(plain, light);
+
+        let paced = speed > 0.0;
+
+        if paced {
+            thread::spawn(move || {
+                loop {
+                    let key = getch::Getch::new().getch().unwrap();
+
+                    if let b'q' = key {
+                        break;
+                    }
+                }
+
+                std::process::exit(0);
+            });
+        }
+
+        let mut previous_time: Option<DateTime<Utc>> = None;
+
+        for snapshot in snapshots {
+            let snapshot = snapshot?;
+
+            if paced {
+                if let Some(previous_time) = previous_time {
+                    let elapsed = (snapshot.time - previous_time).to_std().unwrap_or_default();
+
+                    thread::sleep(elapsed.div_f64(speed));
+                }
+
+                std::io::stdout().write_all(&CLEAR_SCREEN_DATA)?;
+            } else if previous_time.is_some() {
+                println!();
+            }
+
+            previous_time = Some(snapshot.time);
+
+            draw_snapshot(snapshot, &view, unit, truncate, &user_cache);
+        }
+    }
+
+    Ok(())
+}
+
+fn draw_snapshot(
+    snapshot: Snapshot,
+    views: &[ReplayView],
+    unit: Option<Unit>,
+    truncate: usize,
+    user_cache: &UsersCache,
+) {
+    draw_snapshot_time(&snapshot);
+
+    let has_volumes = !snapshot.volumes_with_speed.is_empty();
+    let volumes = Volumes::WithSpeed(snapshot.volumes_with_speed);
+
+    for view in views {
+        println!();
+
+        match view {
+            ReplayView::Cpu => draw_cpu_info(&snapshot.cpu_info, true, false),
+            ReplayView::Memory => draw_memory(&snapshot.free, unit),
+            ReplayView::Network => {
+                if !snapshot.networks_with_speed.is_empty() {
+                    draw_network(&snapshot.networks_with_speed, unit)
+                }
+            },
+            ReplayView::Volume => {
+                if has_volumes {
+                    draw_volume(&volumes, unit, false)
+                }
+            },
+            ReplayView::Process => draw_process(
+                &snapshot.processes,
+                &snapshot.processes_percentage,
+                truncate,
+                unit,
+                false,
+                false,
+                None,
+                None,
+                user_cache,
+            ),
+        }
+    }
+}
+
+fn draw_snapshot_time(snapshot: &Snapshot) {
+    let output = get_stdout_output();
+    let mut stdout = output.buffer();
+
+    stdout.set_color(&COLOR_LABEL).unwrap();
+    write!(&mut stdout, "Recorded at").unwrap();
+
+    write!(&mut stdout, " ").unwrap();
+
+    stdout.set_color(&COLOR_BOLD_TEXT).unwrap();
+    write!(&mut stdout, "{}", snapshot.time.format("%Y-%m-%d %H:%M:%S%.3f UTC")).unwrap();
+
+    stdout.set_color(&COLOR_DEFAULT).unwrap();
+    writeln!(&mut stdout).unwrap();
+
+    output.print(&stdout).unwrap();
+}
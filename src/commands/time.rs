@@ -1,9 +1,10 @@
+use chrono::NaiveDateTime;
 use mprober_lib::rtc_time;
 
-use crate::{terminal::*, CLIArgs, CLICommands};
+use crate::{output::json_handler, terminal::*, CLIArgs, CLICommands};
 
 #[inline]
-pub fn handle_time(args: CLIArgs) {
+pub fn handle_time(args: CLIArgs) -> anyhow::Result<()> {
     debug_assert!(matches!(args.command, CLICommands::Time { .. }));
 
     if let CLICommands::Time {
@@ -12,15 +13,24 @@ pub fn handle_time(args: CLIArgs) {
         monitor,
     } = args.command
     {
+        if args.output.is_json() {
+            return json_handler(
+                args.output,
+                monitor.then_some(Duration::from_millis(1000)),
+                false,
+                || Ok(crate::json::rtc_time(&rtc_time::get_rtc_date_time().unwrap())),
+            );
+        }
+
         set_color_mode(plain, light);
 
-        monitor_handler!(monitor, 1000, draw_time());
+        monitor_handler!(monitor, 1000, draw_time(&rtc_time::get_rtc_date_time().unwrap()));
     }
-}
 
-fn draw_time() {
-    let rtc_date_time = rtc_time::get_rtc_date_time().unwrap();
+    Ok(())
+}
 
+pub fn draw_time(rtc_date_time: &NaiveDateTime) {
     let output = get_stdout_output();
     let mut stdout = output.buffer();
 
@@ -1,9 +1,10 @@
 use mprober_lib::{format_duration, uptime};
+use serde_json::json;
 
-use crate::{terminal::*, CLIArgs, CLICommands};
+use crate::{output::json_handler, terminal::*, CLIArgs, CLICommands};
 
 #[inline]
-pub fn handle_uptime(args: CLIArgs) {
+pub fn handle_uptime(args: CLIArgs) -> anyhow::Result<()> {
     debug_assert!(matches!(args.command, CLICommands::Uptime { .. }));
 
     if let CLICommands::Uptime {
@@ -13,15 +14,28 @@ pub fn handle_uptime(args: CLIArgs) {
         second,
     } = args.command
     {
+        if args.output.is_json() {
+            return json_handler(
+                args.output,
+                monitor.then_some(Duration::from_millis(1000)),
+                false,
+                || Ok(json!(uptime::get_uptime().unwrap().total_uptime.as_secs())),
+            );
+        }
+
         set_color_mode(plain, light);
 
-        monitor_handler!(monitor, 1000, draw_uptime(second));
+        monitor_handler!(
+            monitor,
+            1000,
+            draw_uptime(uptime::get_uptime().unwrap().total_uptime, second)
+        );
     }
-}
 
-fn draw_uptime(second: bool) {
-    let uptime = uptime::get_uptime().unwrap().total_uptime;
+    Ok(())
+}
 
+pub fn draw_uptime(uptime: Duration, second: bool) {
     let output = get_stdout_output();
     let mut stdout = output.buffer();
 
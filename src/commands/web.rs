@@ -1,4 +1,29 @@
-use crate::{rocket_mounts, CLIArgs, CLICommands};
+use std::{
+    future,
+    net::{IpAddr, Ipv4Addr, SocketAddr},
+    path::Path,
+    sync::Arc,
+};
+
+use anyhow::anyhow;
+use rocket::{
+    fairing::AdHoc,
+    tokio::{
+        self,
+        signal::unix::{signal, SignalKind},
+        sync::oneshot,
+    },
+    Build, Rocket,
+};
+
+use crate::{
+    auth::{AuthKey, AuthKeys, Scope, Sessions},
+    forwarder::{self, Forwarder},
+    listener::Listener,
+    rocket_mounts,
+    tls::{self, CertResolver, TlsSource},
+    CLIArgs, CLICommands,
+};
 
 #[inline]
 pub fn handle_web(args: CLIArgs) -> anyhow::Result<()> {
@@ -8,13 +33,183 @@ pub fn handle_web(args: CLIArgs) -> anyhow::Result<()> {
         monitor,
         address,
         listen_port,
+        base_path,
         auth_key,
+        auth_key_file,
+        session_ttl,
         only_api,
+        history,
+        alert,
+        webhook,
+        tls_cert,
+        tls_key,
+        tls_self_signed,
+        unix_socket,
+        unix_socket_mode,
+        unix_socket_owner,
     } = args.command
     {
-        let rocket = rocket_mounts::create(monitor, address, listen_port, auth_key, only_api);
+        let tls = match (tls_cert, tls_key) {
+            _ if tls_self_signed => Some(TlsSource::self_signed(address)?),
+            (Some(cert), Some(key)) => Some(TlsSource::Files {
+                cert,
+                key,
+            }),
+            (None, None) => None,
+            _ => return Err(anyhow!("`--tls-cert` and `--tls-key` need to be used together.")),
+        };
+
+        let listener = match (Listener::from_systemd()?, unix_socket) {
+            (Some(_), Some(_)) => {
+                return Err(anyhow!(
+                    "`--unix-socket` cannot be used with a socket passed by systemd socket \
+                     activation."
+                ))
+            },
+            (Some(listener), None) => Some(listener),
+            (None, Some(unix_socket)) => {
+                Some(Listener::bind_unix(&unix_socket, unix_socket_mode, unix_socket_owner)?)
+            },
+            (None, None) => None,
+        };
+
+        let auth_keys = load_auth_keys(auth_key.as_deref(), auth_key_file.as_deref())?;
+
+        let sessions = Sessions::new(session_ttl);
+
+        let services = rocket_mounts::Services::start(monitor, history, alert, webhook);
+
+        let https = tls.is_some();
+
+        let create = |address, listen_port| {
+            rocket_mounts::create(
+                &services,
+                monitor,
+                address,
+                listen_port,
+                base_path.clone(),
+                auth_keys.clone(),
+                sessions.clone(),
+                only_api,
+                https,
+            )
+        };
+
+        rocket::execute(serve(create, address, listen_port, tls, listener))?;
+    }
+
+    Ok(())
+}
+
+/// The keys of the key file, and the key of the `--auth-key` option which is allowed to access everything.
+pub(crate) fn load_auth_keys(
+    auth_key: Option<&str>,
+    auth_key_file: Option<&Path>,
+) -> anyhow::Result<AuthKeys> {
+    let mut auth_keys = match auth_key_file {
+        Some(auth_key_file) => AuthKeys::load(auth_key_file)?,
+        None => AuthKeys::default(),
+    };
+
+    if let Some(auth_key) = auth_key {
+        auth_keys.push(AuthKey::new("auth-key", auth_key, vec![Scope::Admin], None))?;
+    }
+
+    Ok(auth_keys)
+}
+
+/// Launch the HTTP service. With HTTPS, a Unix domain socket or a socket passed by systemd, the connections are accepted on `listener` (or the listening address) and forwarded to the HTTP service on the loopback address, because Rocket can neither change its certificate without relaunching nor serve a socket it did not bind. If the certificate comes from files, it is swapped with the reloaded one every time SIGHUP is received, without closing the listener, or kept if the reloaded one cannot be used.
+async fn serve<F: Fn(IpAddr, u16) -> Rocket<Build>>(
+    create: F,
+    address: IpAddr,
+    listen_port: u16,
+    tls: Option<TlsSource>,
+    listener: Option<Listener>,
+) -> anyhow::Result<()> {
+    if tls.is_none() && listener.is_none() {
+        create(address, listen_port).launch().await?;
+
+        return Ok(());
+    }
+
+    let resolver = match tls.as_ref() {
+        Some(tls) => Some(Arc::new(CertResolver::new(tls.load()?))),
+        None => None,
+    };
+
+    let listener = match listener {
+        Some(listener) => listener,
+        None => Listener::bind_tcp(address, listen_port)?,
+    };
+
+    let secret = forwarder::generate_secret();
+
+    let (sender, receiver) = oneshot::channel();
+
+    let rocket = forwarder::guard(create(IpAddr::V4(Ipv4Addr::LOCALHOST), 0), secret.clone())
+        .attach(AdHoc::on_liftoff("Backend Port", |rocket| {
+            Box::pin(async move {
+                let _ = sender.send(rocket.config().port);
+            })
+        }));
+
+    let launch = rocket.launch();
+    tokio::pin!(launch);
+
+    let backend = tokio::select! {
+        result = &mut launch => {
+            result?;
+
+            return Ok(());
+        },
+        port = receiver => SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), port?),
+    };
+
+    let forwarder = Forwarder::new(backend, &secret, resolver.clone().map(tls::acceptor));
+
+    eprintln!("{} is served on {listener}", if tls.is_some() { "HTTPS" } else { "HTTP" });
+
+    let forward = listener.forward(forwarder);
+    tokio::pin!(forward);
+
+    let reload = async {
+        match (tls, resolver) {
+            (Some(tls), Some(resolver)) => reload_on_hangup(tls, &resolver).await,
+            _ => future::pending().await,
+        }
+    };
+    tokio::pin!(reload);
+
+    tokio::select! {
+        result = &mut launch => {
+            result?;
+
+            Ok(())
+        },
+        result = &mut forward => result,
+        result = &mut reload => result,
+    }
+}
+
+/// Reload the certificate every time SIGHUP is received. It returns only when the signal cannot be received.
+async fn reload_on_hangup(tls: TlsSource, resolver: &CertResolver) -> anyhow::Result<()> {
+    let mut hangup = signal(SignalKind::hangup())?;
+
+    while hangup.recv().await.is_some() {
+        if !tls.is_reloadable() {
+            eprintln!("A self-signed certificate cannot be reloaded.");
+
+            continue;
+        }
+
+        match tls.load() {
+            Ok(certified_key) => {
+                resolver.swap(certified_key);
 
-        rocket::execute(rocket.launch())?;
+                eprintln!("The certificate is reloaded.");
+            },
+            Err(err) => eprintln!("Cannot reload the certificate: {err:#}"),
+        }
     }
 
     Ok(())
@@ -0,0 +1,389 @@
+mod target;
+
+use std::fmt::Write;
+
+use chrono::{DateTime, Utc};
+use clap::ValueEnum;
+use mprober_lib::{
+    load_average::LoadAverage,
+    memory::Free,
+    network::{Network, NetworkSpeed},
+    volume::{Volume, VolumeSpeed},
+};
+pub use target::*;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, ValueEnum)]
+pub enum ExportFormat {
+    /// InfluxDB line protocol
+    Influx,
+    /// Graphite plaintext protocol
+    Graphite,
+    /// StatsD gauges
+    Statsd,
+}
+
+/// The fields which are sent as StatsD gauges. Counters of bytes are left out because StatsD aggregates gauges by their latest values.
+const STATSD_FIELDS: [(&str, &str); 10] = [
+    ("cpu", "usage"),
+    ("load", "one"),
+    ("load", "five"),
+    ("load", "fifteen"),
+    ("memory", "used"),
+    ("swap", "used"),
+    ("network", "receive_rate"),
+    ("network", "transmit_rate"),
+    ("volume", "read_rate"),
+    ("volume", "write_rate"),
+];
+
+/// A measurement with its tags and fields, which is a line of the InfluxDB line protocol, or a line per field of the Graphite plaintext protocol.
+#[derive(Debug)]
+pub struct Point {
+    measurement: &'static str,
+    tags:        Vec<(&'static str, String)>,
+    fields:      Vec<(&'static str, f64)>,
+}
+
+impl Point {
+    #[inline]
+    fn new(measurement: &'static str) -> Point {
+        Point {
+            measurement,
+            tags: Vec::new(),
+            fields: Vec::new(),
+        }
+    }
+
+    #[inline]
+    fn tag<S: Into<String>>(mut self, key: &'static str, value: S) -> Point {
+        self.tags.push((key, value.into()));
+
+        self
+    }
+
+    #[inline]
+    fn field<V: Into<f64>>(mut self, key: &'static str, value: V) -> Point {
+        self.fields.push((key, value.into()));
+
+        self
+    }
+}
+
+/// Turn the samples of the collectors into points. The stats which cannot be read are `None` and have no points.
+pub fn points(
+    cpus_stat: Option<&[f64]>,
+    load_average: Option<&LoadAverage>,
+    free: Option<&Free>,
+    network_stat: Option<&[(Network, NetworkSpeed)]>,
+    volumes_stat: Option<&[(Volume, VolumeSpeed)]>,
+) -> Vec<Point> {
+    let mut points = Vec::new();
+
+    for (i, cpu_stat) in cpus_stat.unwrap_or_default().iter().copied().enumerate() {
+        let cpu = if i == 0 { "all".to_string() } else { (i - 1).to_string() };
+
+        points.push(Point::new("cpu").tag("cpu", cpu).field("usage", cpu_stat));
+    }
+
+    if let Some(load_average) = load_average {
+        points.push(
+            Point::new("load")
+                .field("one", load_average.one)
+                .field("five", load_average.five)
+                .field("fifteen", load_average.fifteen),
+        );
+    }
+
+    if let Some(free) = free {
+        points.push(
+            Point::new("memory")
+                .field("total", free.mem.total as f64)
+                .field("used", free.mem.used as f64)
+                .field("free", free.mem.free as f64)
+                .field("shared", free.mem.shared as f64)
+                .field("buffers", free.mem.buffers as f64)
+                .field("cache", free.mem.cache as f64)
+                .field("available", free.mem.available as f64),
+        );
+
+        points.push(
+            Point::new("swap")
+                .field("total", free.swap.total as f64)
+                .field("used", free.swap.used as f64)
+                .field("free", free.swap.free as f64)
+                .field("cache", free.swap.cache as f64),
+        );
+    }
+
+    for (network, network_speed) in network_stat.unwrap_or_default() {
+        points.push(
+            Point::new("network")
+                .tag("interface", network.interface.trim())
+                .field("receive_bytes", network.stat.receive_bytes as f64)
+                .field("transmit_bytes", network.stat.transmit_bytes as f64)
+                .field("receive_rate", network_speed.receive)
+                .field("transmit_rate", network_speed.transmit),
+        );
+    }
+
+    for (volume, volume_speed) in volumes_stat.unwrap_or_default() {
+        points.push(
+            Point::new("volume")
+                .tag("device", volume.device.as_str())
+                .field("size", volume.size as f64)
+                .field("used", volume.used as f64)
+                .field("read_bytes", volume.stat.read_bytes as f64)
+                .field("write_bytes", volume.stat.write_bytes as f64)
+                .field("read_rate", volume_speed.read)
+                .field("write_rate", volume_speed.write),
+        );
+    }
+
+    points
+}
+
+/// Write the points in the InfluxDB line protocol. The name of a measurement is `<prefix>_<measurement>`, or just the measurement if `prefix` is empty, and every point is tagged with `host`.
+pub fn write_influx(
+    buffer: &mut String,
+    points: &[Point],
+    prefix: &str,
+    host: &str,
+    time: DateTime<Utc>,
+) {
+    let timestamp = time.timestamp_nanos_opt().unwrap_or_default();
+
+    for point in points {
+        // NaN and infinity cannot be written
+        let mut fields = point.fields.iter().filter(|(_, value)| value.is_finite()).peekable();
+
+        if fields.peek().is_none() {
+            continue;
+        }
+
+        if !prefix.is_empty() {
+            escape_influx(buffer, prefix, ", ");
+            buffer.push('_');
+        }
+
+        escape_influx(buffer, point.measurement, ", ");
+
+        buffer.push_str(",host=");
+        escape_influx(buffer, host, ",= ");
+
+        for (key, value) in point.tags.iter() {
+            write!(buffer, ",{key}=").unwrap();
+            escape_influx(buffer, value, ",= ");
+        }
+
+        for (i, (key, value)) in fields.enumerate() {
+            buffer.push(if i == 0 { ' ' } else { ',' });
+
+            write!(buffer, "{key}={value}").unwrap();
+        }
+
+        writeln!(buffer, " {timestamp}").unwrap();
+    }
+}
+
+fn escape_influx(buffer: &mut String, value: &str, special_characters: &str) {
+    for c in value.chars() {
+        if c == '\\' || special_characters.contains(c) {
+            buffer.push('\\');
+        }
+
+        buffer.push(c);
+    }
+}
+
+/// Write the points in the Graphite plaintext protocol. The path of a metric is `<prefix>.<host>.<measurement>[.<tag values>].<field>`.
+pub fn write_graphite(
+    buffer: &mut String,
+    points: &[Point],
+    prefix: &str,
+    host: &str,
+    time: DateTime<Utc>,
+) {
+    let timestamp = time.timestamp();
+
+    for point in points {
+        for (key, value) in point.fields.iter().filter(|(_, value)| value.is_finite()) {
+            write_graphite_path(buffer, prefix, Some(host), point);
+
+            writeln!(buffer, ".{key} {value} {timestamp}").unwrap();
+        }
+    }
+}
+
+/// Write `<prefix>.[<host>.]<measurement>[.<tag values>]`.
+fn write_graphite_path(buffer: &mut String, prefix: &str, host: Option<&str>, point: &Point) {
+    for node in prefix.split('.').filter(|node| !node.is_empty()) {
+        escape_graphite(buffer, node);
+        buffer.push('.');
+    }
+
+    if let Some(host) = host {
+        escape_graphite(buffer, host);
+        buffer.push('.');
+    }
+
+    buffer.push_str(point.measurement);
+
+    if host.is_some() {
+        for (_, value) in point.tags.iter() {
+            buffer.push('.');
+            escape_graphite(buffer, value);
+        }
+    }
+}
+
+/// Write the points as StatsD gauges. Without DogStatsD tags, the name of a gauge is the same as the path of the metric in the Graphite plaintext protocol. With DogStatsD tags, the name is `<prefix>.<measurement>.<field>`, and the host, the tags of the point and `extra_tags` become tags.
+pub fn write_statsd(
+    buffer: &mut String,
+    points: &[Point],
+    prefix: &str,
+    host: &str,
+    dogstatsd_tags: Option<&[String]>,
+) {
+    for point in points {
+        for (key, value) in point.fields.iter().filter(|(key, value)| {
+            value.is_finite() && STATSD_FIELDS.contains(&(point.measurement, key))
+        }) {
+            match dogstatsd_tags {
+                Some(extra_tags) => {
+                    write_graphite_path(buffer, prefix, None, point);
+
+                    write!(buffer, ".{key}:{value}|g|#host:").unwrap();
+                    escape_dogstatsd_tag(buffer, host);
+
+                    for (tag_key, tag_value) in point.tags.iter() {
+                        write!(buffer, ",{tag_key}:").unwrap();
+                        escape_dogstatsd_tag(buffer, tag_value);
+                    }
+
+                    for tag in extra_tags {
+                        buffer.push(',');
+                        escape_dogstatsd_tag(buffer, tag);
+                    }
+
+                    buffer.push('\n');
+                },
+                None => {
+                    write_graphite_path(buffer, prefix, Some(host), point);
+
+                    writeln!(buffer, ".{key}:{value}|g").unwrap();
+                },
+            }
+        }
+    }
+}
+
+/// Replace the characters which separate tags or the parts of a DogStatsD datagram.
+fn escape_dogstatsd_tag(buffer: &mut String, tag: &str) {
+    for c in tag.chars() {
+        if ",|#\n".contains(c) {
+            buffer.push('_');
+        } else {
+            buffer.push(c);
+        }
+    }
+}
+
+/// Replace the characters which cannot be in a node of a Graphite path.
+fn escape_graphite(buffer: &mut String, node: &str) {
+    for c in node.chars() {
+        if c.is_ascii_alphanumeric() || "-_".contains(c) {
+            buffer.push(c);
+        } else {
+            buffer.push('_');
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn create_points() -> Vec<Point> {
+        vec![
+            Point::new("cpu").tag("cpu", "all").field("usage", 0.5),
+            Point::new("volume")
+                .tag("device", "my disk,1")
+                .field("size", 1024.0)
+                .field("used", 256.0),
+        ]
+    }
+
+    #[test]
+    fn test_points() {
+        let points = points(Some(&[50.0, 40.0, 60.0]), None, None, None, None);
+
+        assert_eq!(3, points.len());
+        assert_eq!(("cpu", "1".to_string()), points[2].tags[0]);
+
+        assert!(super::points(None, None, None, None, None).is_empty());
+    }
+
+    #[test]
+    fn test_write_influx() {
+        let time = DateTime::from_timestamp(1_500_000_000, 0).unwrap();
+
+        let mut buffer = String::new();
+
+        write_influx(&mut buffer, &create_points(), "mprober", "db 1", time);
+
+        assert_eq!(
+            "mprober_cpu,host=db\\ 1,cpu=all usage=0.5 \
+             1500000000000000000\nmprober_volume,host=db\\ 1,device=my\\ disk\\,1 \
+             size=1024,used=256 1500000000000000000\n",
+            buffer
+        );
+
+        let mut buffer = String::new();
+
+        write_influx(&mut buffer, &create_points()[..1], "", "db1", time);
+
+        assert_eq!("cpu,host=db1,cpu=all usage=0.5 1500000000000000000\n", buffer);
+    }
+
+    #[test]
+    fn test_write_graphite() {
+        let time = DateTime::from_timestamp(1_500_000_000, 0).unwrap();
+
+        let mut buffer = String::new();
+
+        write_graphite(&mut buffer, &create_points(), "servers.mprober", "db1.example.com", time);
+
+        assert_eq!(
+            "servers.mprober.db1_example_com.cpu.all.usage 0.5 \
+             1500000000\nservers.mprober.db1_example_com.volume.my_disk_1.size 1024 \
+             1500000000\nservers.mprober.db1_example_com.volume.my_disk_1.used 256 1500000000\n",
+            buffer
+        );
+    }
+
+    #[test]
+    fn test_write_statsd() {
+        let mut points = create_points();
+
+        points[1].fields.push(("read_rate", 512.0));
+
+        let mut buffer = String::new();
+
+        write_statsd(&mut buffer, &points, "mprober", "db1", None);
+
+        assert_eq!(
+            "mprober.db1.cpu.all.usage:0.5|g\nmprober.db1.volume.my_disk_1.read_rate:512|g\n",
+            buffer
+        );
+
+        let mut buffer = String::new();
+
+        write_statsd(&mut buffer, &points, "", "db1", Some(&["env:prod".to_string()]));
+
+        assert_eq!(
+            "cpu.usage:0.5|g|#host:db1,cpu:all,env:prod\nvolume.read_rate:512|g|#host:db1,device:\
+             my disk_1,env:prod\n",
+            buffer
+        );
+    }
+}
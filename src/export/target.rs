@@ -0,0 +1,326 @@
+use std::{
+    fs::{File, OpenOptions},
+    io::{self, Write},
+    net::{SocketAddr, TcpStream, ToSocketAddrs, UdpSocket},
+    path::PathBuf,
+    str::FromStr,
+    time::Duration,
+};
+
+use anyhow::{anyhow, Context};
+
+/// The maximum size of a UDP datagram, which fits in the usual MTU of 1500 bytes.
+const MAX_DATAGRAM_SIZE: usize = 1400;
+
+/// Where exported metrics are written to.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ExportTarget {
+    Stdout,
+    File(PathBuf),
+    Udp(String),
+    Tcp(String),
+    Http(String),
+}
+
+impl ExportTarget {
+    #[inline]
+    pub fn is_http(&self) -> bool {
+        matches!(self, ExportTarget::Http(_))
+    }
+
+    /// Open the target. Sockets are connected lazily when the metrics are sent.
+    pub fn open(self, timeout: Duration, authorization: Option<String>) -> anyhow::Result<Sink> {
+        let sink = match self {
+            ExportTarget::Stdout => Sink::Stdout,
+            ExportTarget::File(path) => Sink::File(
+                OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(&path)
+                    .with_context(|| format!("Cannot open {path:?}."))?,
+            ),
+            ExportTarget::Udp(address) => Sink::Udp {
+                address,
+                socket: None,
+            },
+            ExportTarget::Tcp(address) => Sink::Tcp {
+                address,
+                timeout,
+                stream: None,
+            },
+            ExportTarget::Http(url) => Sink::Http {
+                url,
+                timeout,
+                authorization,
+            },
+        };
+
+        Ok(sink)
+    }
+}
+
+impl FromStr for ExportTarget {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "-" {
+            return Ok(ExportTarget::Stdout);
+        }
+
+        if s.starts_with("http://") || s.starts_with("https://") {
+            return Ok(ExportTarget::Http(s.to_string()));
+        }
+
+        let (target, address): (fn(String) -> ExportTarget, &str) =
+            if let Some(address) = s.strip_prefix("udp://") {
+                (ExportTarget::Udp, address)
+            } else if let Some(address) = s.strip_prefix("tcp://") {
+                (ExportTarget::Tcp, address)
+            } else if s.contains("://") {
+                return Err(anyhow!(
+                    "The target needs to be `-`, a file, `udp://HOST:PORT`, `tcp://HOST:PORT` or \
+                     an HTTP URL."
+                ));
+            } else {
+                return Ok(ExportTarget::File(PathBuf::from(s)));
+            };
+
+        match address.rsplit_once(':') {
+            Some((host, port)) if !host.is_empty() && port.parse::<u16>().is_ok() => {
+                Ok(target(address.to_string()))
+            },
+            _ => Err(anyhow!("{s:?} needs a host and a port.")),
+        }
+    }
+}
+
+/// An opened target.
+#[derive(Debug)]
+pub enum Sink {
+    Stdout,
+    File(File),
+    Udp { address: String, socket: Option<UdpSocket> },
+    Tcp { address: String, timeout: Duration, stream: Option<TcpStream> },
+    Http { url: String, timeout: Duration, authorization: Option<String> },
+}
+
+impl Sink {
+    /// Whether errors of the sink can be recovered by sending again later.
+    #[inline]
+    pub fn is_remote(&self) -> bool {
+        !matches!(self, Sink::Stdout | Sink::File(_))
+    }
+
+    /// Send lines of metrics. A broken TCP connection is reconnected once before an error is returned.
+    pub fn send(&mut self, lines: &str) -> anyhow::Result<()> {
+        match self {
+            Sink::Stdout => {
+                let mut stdout = io::stdout().lock();
+
+                stdout.write_all(lines.as_bytes())?;
+                stdout.flush()?;
+            },
+            Sink::File(file) => file.write_all(lines.as_bytes())?,
+            Sink::Udp {
+                address,
+                socket,
+            } => {
+                let socket = match socket {
+                    Some(socket) => socket,
+                    None => socket.insert(connect_udp(address)?),
+                };
+
+                for datagram in split_datagrams(lines) {
+                    socket.send(datagram.as_bytes())?;
+                }
+            },
+            Sink::Tcp {
+                address,
+                timeout,
+                stream,
+            } => {
+                if let Some(connected) = stream.as_mut() {
+                    if connected.write_all(lines.as_bytes()).is_ok() {
+                        return Ok(());
+                    }
+                }
+
+                let connected = stream.insert(connect_tcp(address, *timeout)?);
+
+                if let Err(error) = connected.write_all(lines.as_bytes()) {
+                    stream.take();
+
+                    return Err(error.into());
+                }
+            },
+            Sink::Http {
+                url,
+                timeout,
+                authorization,
+            } => {
+                let mut request =
+                    ureq::post(url).timeout(*timeout).set("Content-Type", "text/plain");
+
+                if let Some(authorization) = authorization.as_ref() {
+                    request = request.set("Authorization", authorization);
+                }
+
+                request.send_string(lines).map_err(Box::new)?;
+            },
+        }
+
+        Ok(())
+    }
+}
+
+fn resolve(address: &str) -> anyhow::Result<SocketAddr> {
+    address
+        .to_socket_addrs()
+        .with_context(|| format!("Cannot resolve {address:?}."))?
+        .next()
+        .ok_or_else(|| anyhow!("Cannot resolve {address:?}."))
+}
+
+fn connect_udp(address: &str) -> anyhow::Result<UdpSocket> {
+    let address = resolve(address)?;
+
+    let socket =
+        if address.is_ipv4() { UdpSocket::bind("0.0.0.0:0")? } else { UdpSocket::bind("[::]:0")? };
+
+    socket.connect(address)?;
+
+    Ok(socket)
+}
+
+fn connect_tcp(address: &str, timeout: Duration) -> anyhow::Result<TcpStream> {
+    let stream = TcpStream::connect_timeout(&resolve(address)?, timeout)
+        .with_context(|| format!("Cannot connect to {address}."))?;
+
+    stream.set_write_timeout(Some(timeout))?;
+
+    Ok(stream)
+}
+
+/// Split lines into datagrams which are not larger than `MAX_DATAGRAM_SIZE` unless a line is.
+fn split_datagrams(lines: &str) -> Vec<&str> {
+    let mut datagrams = Vec::new();
+
+    let mut start = 0;
+    let mut end = 0;
+
+    for line in lines.split_inclusive('\n') {
+        if end > start && end - start + line.len() > MAX_DATAGRAM_SIZE {
+            datagrams.push(&lines[start..end]);
+
+            start = end;
+        }
+
+        end += line.len();
+    }
+
+    if end > start {
+        datagrams.push(&lines[start..end]);
+    }
+
+    datagrams
+}
+
+#[cfg(test)]
+mod test {
+    use std::{io::Read, net::TcpListener, thread};
+
+    use super::*;
+    use crate::http_stub;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(ExportTarget::Stdout, "-".parse().unwrap());
+        assert_eq!(ExportTarget::File(PathBuf::from("a.txt")), "a.txt".parse().unwrap());
+        assert_eq!(
+            ExportTarget::Udp("127.0.0.1:8089".to_string()),
+            "udp://127.0.0.1:8089".parse().unwrap()
+        );
+        assert_eq!(
+            ExportTarget::Tcp("graphite:2003".to_string()),
+            "tcp://graphite:2003".parse().unwrap()
+        );
+        assert!("http://127.0.0.1:8086/write?db=a".parse::<ExportTarget>().unwrap().is_http());
+
+        assert!("tcp://graphite".parse::<ExportTarget>().is_err());
+        assert!("ftp://a".parse::<ExportTarget>().is_err());
+    }
+
+    #[test]
+    fn test_split_datagrams() {
+        let line = format!("{}\n", "a".repeat(599));
+
+        let lines = line.repeat(5);
+
+        let datagrams = split_datagrams(&lines);
+
+        assert_eq!(vec![1200, 1200, 600], datagrams.iter().map(|d| d.len()).collect::<Vec<_>>());
+        assert_eq!(lines, datagrams.concat());
+    }
+
+    #[test]
+    fn test_send_udp() {
+        let server = UdpSocket::bind("127.0.0.1:0").unwrap();
+
+        let mut sink = ExportTarget::Udp(server.local_addr().unwrap().to_string())
+            .open(Duration::from_secs(1), None)
+            .unwrap();
+
+        sink.send("cpu,host=a usage=0.5 1\n").unwrap();
+
+        let mut buffer = [0; MAX_DATAGRAM_SIZE];
+
+        let size = server.recv(&mut buffer).unwrap();
+
+        assert_eq!(b"cpu,host=a usage=0.5 1\n", &buffer[..size]);
+    }
+
+    #[test]
+    fn test_send_tcp() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+
+            let mut received = String::new();
+            stream.read_to_string(&mut received).unwrap();
+
+            received
+        });
+
+        let mut sink =
+            ExportTarget::Tcp(address.to_string()).open(Duration::from_secs(1), None).unwrap();
+
+        sink.send("a.cpu.all.usage 0.5 1\n").unwrap();
+        sink.send("a.cpu.all.usage 0.6 2\n").unwrap();
+
+        drop(sink);
+
+        assert_eq!("a.cpu.all.usage 0.5 1\na.cpu.all.usage 0.6 2\n", server.join().unwrap());
+    }
+
+    #[test]
+    fn test_send_http() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+
+        let server = http_stub::serve(listener, vec![(204, String::new())]);
+
+        let mut sink = ExportTarget::Http(format!("http://{address}/write?db=a"))
+            .open(Duration::from_secs(1), Some("Token magic".to_string()))
+            .unwrap();
+
+        sink.send("cpu,host=a usage=0.5 1\n").unwrap();
+
+        let requests = server.join().unwrap();
+
+        assert!(requests[0].head.starts_with("POST /write?db=a "));
+        assert!(requests[0].head.to_ascii_lowercase().contains("authorization: token magic\r\n"));
+        assert_eq!(b"cpu,host=a usage=0.5 1\n", requests[0].body.as_slice());
+    }
+}
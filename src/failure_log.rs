@@ -0,0 +1,47 @@
+use std::fmt::Display;
+
+/// Log only when something which is retried repeatedly starts failing and when it recovers, rather than every failure.
+#[derive(Debug, Default)]
+pub struct FailureLog {
+    failing: bool,
+    logged:  usize,
+}
+
+impl FailureLog {
+    /// Whether the last attempt failed.
+    #[inline]
+    pub fn is_failing(&self) -> bool {
+        self.failing
+    }
+
+    /// How many messages have been logged.
+    #[cfg(test)]
+    #[inline]
+    pub fn logged(&self) -> usize {
+        self.logged
+    }
+
+    /// Record a failed attempt. `message` is logged if the previous attempt succeeded.
+    #[inline]
+    pub fn fail<D: Display>(&mut self, message: D) {
+        if !self.failing {
+            eprintln!("{message}");
+
+            self.logged += 1;
+
+            self.failing = true;
+        }
+    }
+
+    /// Record a successful attempt. `message` is logged if the previous attempt failed.
+    #[inline]
+    pub fn succeed<D: Display>(&mut self, message: D) {
+        if self.failing {
+            eprintln!("{message}");
+
+            self.logged += 1;
+
+            self.failing = false;
+        }
+    }
+}
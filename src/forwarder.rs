@@ -0,0 +1,280 @@
+use std::{
+    convert::Infallible,
+    net::{IpAddr, SocketAddr},
+    sync::Arc,
+    time::Duration,
+};
+
+use hyper::{
+    client::HttpConnector,
+    header::{self, HeaderName, HeaderValue},
+    server::conn::Http,
+    service::service_fn,
+    Body, Client, Request, Response, StatusCode, Uri,
+};
+use rocket::{
+    fairing::AdHoc,
+    http::{uri::Origin, Method, Status},
+    tokio::{
+        io::{AsyncRead, AsyncWrite},
+        time,
+    },
+    Build, Rocket,
+};
+use subtle::ConstantTimeEq;
+use tokio_rustls::TlsAcceptor;
+
+use crate::auth::AuthKey;
+
+/// The header which proves that a request comes from the forwarder.
+const SECRET_HEADER: &str = "x-mprober-forwarder";
+/// The header which Rocket takes as the address of the client by default.
+const REAL_IP_HEADER: &str = "x-real-ip";
+/// The route which answers the requests not coming from the forwarder.
+const FORBIDDEN_PATH: &str = "/mprober-forwarder-only";
+
+/// How long a client can take to finish the TLS handshake.
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The headers which only concern one connection, so they are not passed on.
+const HOP_BY_HOP_HEADERS: [HeaderName; 5] =
+    [header::CONNECTION, header::TE, header::TRAILER, header::TRANSFER_ENCODING, header::UPGRADE];
+
+/// Serve connections which the HTTP service cannot accept by itself, and pass every request to the HTTP service on the loopback address.
+///
+/// The HTTP service on the loopback address can be connected to by any local user, so it is guarded by a secret which only the forwarder sends, and answers the other requests with 403. The address of a TCP client is passed in the `X-Real-IP` header.
+#[derive(Clone)]
+pub struct Forwarder {
+    backend:  SocketAddr,
+    secret:   HeaderValue,
+    client:   Client<HttpConnector>,
+    acceptor: Option<TlsAcceptor>,
+}
+
+impl Forwarder {
+    /// Forward to the HTTP service which was guarded by `guard` with `secret` and is listening on `backend`. With `acceptor`, the connections are decrypted first.
+    pub fn new(backend: SocketAddr, secret: &str, acceptor: Option<TlsAcceptor>) -> Forwarder {
+        Forwarder {
+            backend,
+            secret: HeaderValue::from_str(secret).unwrap(),
+            client: Client::new(),
+            acceptor,
+        }
+    }
+
+    /// Serve a connection from `peer` until it is closed. `peer` is `None` for a Unix domain socket, whose client is a local reverse proxy which passes the address by itself. Handshake failures and disconnections are ignored because they concern only that client.
+    pub async fn serve<IO: AsyncRead + AsyncWrite + Unpin + Send + 'static>(
+        &self,
+        io: IO,
+        peer: Option<IpAddr>,
+    ) {
+        match &self.acceptor {
+            Some(acceptor) => {
+                if let Ok(Ok(io)) = time::timeout(HANDSHAKE_TIMEOUT, acceptor.accept(io)).await {
+                    self.serve_http(io, peer).await
+                }
+            },
+            None => self.serve_http(io, peer).await,
+        }
+    }
+
+    async fn serve_http<IO: AsyncRead + AsyncWrite + Unpin + Send + 'static>(
+        &self,
+        io: IO,
+        peer: Option<IpAddr>,
+    ) {
+        let service = service_fn(|request| {
+            let forwarder = self.clone();
+
+            async move { Ok::<_, Infallible>(forwarder.forward(request, peer).await) }
+        });
+
+        let _ = Http::new().http1_only(true).serve_connection(io, service).await;
+    }
+
+    async fn forward(&self, request: Request<Body>, peer: Option<IpAddr>) -> Response<Body> {
+        let (mut parts, body) = request.into_parts();
+
+        let path_and_query = parts.uri.path_and_query().map(|p| p.as_str()).unwrap_or("/");
+
+        parts.uri = match format!("http://{}{path_and_query}", self.backend).parse::<Uri>() {
+            Ok(uri) => uri,
+            Err(_) => return status_response(StatusCode::BAD_REQUEST),
+        };
+
+        let headers = &mut parts.headers;
+
+        for name in HOP_BY_HOP_HEADERS.iter() {
+            headers.remove(name);
+        }
+
+        if let Some(peer) = peer {
+            headers.insert(REAL_IP_HEADER, HeaderValue::from_str(&peer.to_string()).unwrap());
+        }
+
+        headers.insert(SECRET_HEADER, self.secret.clone());
+
+        match self.client.request(Request::from_parts(parts, body)).await {
+            Ok(mut response) => {
+                for name in HOP_BY_HOP_HEADERS.iter() {
+                    response.headers_mut().remove(name);
+                }
+
+                response
+            },
+            Err(_) => status_response(StatusCode::BAD_GATEWAY),
+        }
+    }
+}
+
+/// Generate a secret for `guard` and `Forwarder::new`.
+#[inline]
+pub fn generate_secret() -> String {
+    AuthKey::generate_key()
+}
+
+/// Make `rocket` answer the requests without `secret` with 403, so that only the forwarder can use it.
+pub fn guard(rocket: Rocket<Build>, secret: String) -> Rocket<Build> {
+    let secret = Arc::new(secret);
+
+    rocket
+        .attach(AdHoc::on_request("Forwarder Only", move |request, _| {
+            let secret = secret.clone();
+
+            Box::pin(async move {
+                let forwarded = request
+                    .headers()
+                    .get_one(SECRET_HEADER)
+                    .map(|value| bool::from(value.as_bytes().ct_eq(secret.as_bytes())))
+                    .unwrap_or(false);
+
+                if !forwarded {
+                    request.set_method(Method::Get);
+                    request.set_uri(Origin::parse(FORBIDDEN_PATH).unwrap());
+                }
+            })
+        }))
+        .mount("/", routes![forbidden])
+}
+
+#[get("/mprober-forwarder-only")]
+fn forbidden() -> Status {
+    Status::Forbidden
+}
+
+#[inline]
+fn status_response(status: StatusCode) -> Response<Body> {
+    let mut response = Response::new(Body::empty());
+
+    *response.status_mut() = status;
+
+    response
+}
+
+#[cfg(test)]
+pub(crate) mod test_backend {
+    use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+
+    use rocket::{
+        config::Shutdown,
+        fairing::AdHoc,
+        log::LogLevel,
+        tokio::{sync::oneshot, task},
+        Config,
+    };
+
+    #[get("/ip")]
+    fn ip(ip: Option<IpAddr>) -> String {
+        ip.map(|ip| ip.to_string()).unwrap_or_default()
+    }
+
+    /// Launch an HTTP service guarded with `secret` on the loopback address. `GET /ip` responds with the address of the client.
+    pub async fn launch(secret: &str) -> SocketAddr {
+        let config = Config {
+            address: IpAddr::V4(Ipv4Addr::LOCALHOST),
+            port: 0,
+            log_level: LogLevel::Off,
+            shutdown: Shutdown {
+                ctrlc: false,
+                ..Shutdown::default()
+            },
+            ..Config::debug_default()
+        };
+
+        let (sender, receiver) = oneshot::channel();
+
+        let rocket =
+            super::guard(rocket::custom(config).mount("/", routes![ip]), secret.to_string())
+                .attach(AdHoc::on_liftoff("Backend Port", |rocket| {
+                    Box::pin(async move {
+                        let _ = sender.send(rocket.config().port);
+                    })
+                }));
+
+        task::spawn(rocket.launch());
+
+        SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), receiver.await.unwrap())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::net::Ipv4Addr;
+
+    use rocket::tokio::{net::TcpListener, task};
+
+    use super::*;
+
+    async fn get(address: SocketAddr, real_ip: &str) -> (StatusCode, String) {
+        let request = Request::get(format!("http://{address}/ip"))
+            .header(REAL_IP_HEADER, real_ip)
+            .body(Body::empty())
+            .unwrap();
+
+        let response = Client::new().request(request).await.unwrap();
+        let status = response.status();
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+
+        (status, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    #[rocket::async_test]
+    async fn test_forward() {
+        let secret = generate_secret();
+
+        let backend = test_backend::launch(&secret).await;
+
+        // connecting to the loopback address directly
+        assert_eq!(StatusCode::FORBIDDEN, get(backend, "10.0.0.1").await.0);
+
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).await.unwrap();
+        let address = listener.local_addr().unwrap();
+
+        let forwarder = Forwarder::new(backend, &secret, None);
+
+        task::spawn(async move {
+            loop {
+                let (stream, peer) = listener.accept().await.unwrap();
+
+                let forwarder = forwarder.clone();
+
+                task::spawn(async move { forwarder.serve(stream, Some(peer.ip())).await });
+            }
+        });
+
+        // the spoofed address is replaced with the address of the client
+        assert_eq!((StatusCode::OK, "127.0.0.1".to_string()), get(address, "10.0.0.1").await);
+
+        let wrong_secret = Forwarder::new(backend, &generate_secret(), None);
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).await.unwrap();
+        let address = listener.local_addr().unwrap();
+
+        task::spawn(async move {
+            let (stream, peer) = listener.accept().await.unwrap();
+
+            wrong_secret.serve(stream, Some(peer.ip())).await
+        });
+
+        assert_eq!(StatusCode::FORBIDDEN, get(address, "10.0.0.1").await.0);
+    }
+}
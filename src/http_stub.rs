@@ -0,0 +1,76 @@
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::TcpListener,
+    thread,
+};
+
+use serde_json::Value;
+
+/// A request received by the stub.
+#[derive(Debug)]
+pub struct StubRequest {
+    /// The request line and the headers, each of which ends with CRLF.
+    pub head: String,
+    pub body: Vec<u8>,
+}
+
+impl StubRequest {
+    #[inline]
+    pub fn json(&self) -> Value {
+        serde_json::from_slice(&self.body).unwrap()
+    }
+}
+
+/// Accept one connection for each of `responses`, and respond to its request with the status and the body in order. The received requests are returned when all of the responses are sent.
+pub fn serve(
+    listener: TcpListener,
+    responses: Vec<(u16, String)>,
+) -> thread::JoinHandle<Vec<StubRequest>> {
+    thread::spawn(move || {
+        let mut requests = Vec::with_capacity(responses.len());
+
+        for (status, body) in responses {
+            let (stream, _) = listener.accept().unwrap();
+
+            let mut reader = BufReader::new(stream);
+
+            let mut head = String::new();
+            let mut content_length = 0;
+
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+
+                if line == "\r\n" {
+                    break;
+                }
+
+                if let Some((name, value)) = line.split_once(':') {
+                    if name.eq_ignore_ascii_case("content-length") {
+                        content_length = value.trim().parse().unwrap();
+                    }
+                }
+
+                head.push_str(&line);
+            }
+
+            let mut request_body = vec![0; content_length];
+            reader.read_exact(&mut request_body).unwrap();
+
+            write!(
+                reader.get_mut(),
+                "HTTP/1.1 {status} Stub\r\nContent-Type: application/json\r\nContent-Length: \
+                 {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+
+            requests.push(StubRequest {
+                head,
+                body: request_body,
+            });
+        }
+
+        requests
+    })
+}
@@ -0,0 +1,167 @@
+use std::collections::BTreeMap;
+
+use chrono::{NaiveDateTime, SecondsFormat};
+use mprober_lib::{
+    cpu::CPU,
+    load_average::LoadAverage,
+    memory::{Mem, Swap},
+    network::{Network, NetworkSpeed},
+    process::Process,
+    volume::{Volume, VolumeSpeed},
+};
+use serde_json::{json, Value};
+use users::{Groups, Users, UsersCache};
+
+#[inline]
+pub fn rtc_time(rtc_date_time: &NaiveDateTime) -> Value {
+    json!({
+        "date": rtc_date_time.date().to_string(),
+        "time": rtc_date_time.time().to_string()
+    })
+}
+
+#[inline]
+pub fn load_average(load_average: &LoadAverage) -> Value {
+    json!({
+        "one": load_average.one,
+        "five": load_average.five,
+        "fifteen": load_average.fifteen
+    })
+}
+
+pub fn cpus(cpus: &[CPU]) -> Value {
+    let mut json_cpus = Vec::with_capacity(cpus.len());
+
+    for cpu in cpus {
+        json_cpus.push(json!({
+            "model_name": cpu.model_name,
+            "cores": cpu.cpu_cores,
+            "threads": cpu.siblings,
+            "mhz": cpu.cpus_mhz
+        }));
+    }
+
+    Value::Array(json_cpus)
+}
+
+#[inline]
+pub fn memory(mem: &Mem) -> Value {
+    json!({
+        "total": mem.total,
+        "used": mem.used,
+        "free": mem.free,
+        "shared": mem.shared,
+        "buffers": mem.buffers,
+        "cache": mem.cache,
+        "available": mem.available
+    })
+}
+
+#[inline]
+pub fn swap(swap: &Swap) -> Value {
+    json!({
+        "total": swap.total,
+        "used": swap.used,
+        "free": swap.free,
+        "cache": swap.cache
+    })
+}
+
+pub fn networks_with_speed(networks_with_speed: &[(Network, NetworkSpeed)]) -> Value {
+    let mut json_network = Vec::with_capacity(networks_with_speed.len());
+
+    for (network, network_speed) in networks_with_speed {
+        json_network.push(json!({
+            "interface": network.interface,
+            "upload_total": network.stat.transmit_bytes,
+            "download_total": network.stat.receive_bytes,
+            "upload_rate": network_speed.transmit,
+            "download_rate": network_speed.receive
+        }));
+    }
+
+    Value::Array(json_network)
+}
+
+pub fn volumes(volumes: &[Volume]) -> Value {
+    let mut json_volumes = Vec::with_capacity(volumes.len());
+
+    for volume in volumes {
+        json_volumes.push(json!({
+            "device": volume.device,
+            "size": volume.size,
+            "used": volume.used,
+            "read_total": volume.stat.read_bytes,
+            "write_total": volume.stat.write_bytes,
+            "mount_points": volume.points
+        }));
+    }
+
+    Value::Array(json_volumes)
+}
+
+pub fn volumes_with_speed(volumes_with_speed: &[(Volume, VolumeSpeed)]) -> Value {
+    let mut json_volumes = Vec::with_capacity(volumes_with_speed.len());
+
+    for (volume, volume_speed) in volumes_with_speed {
+        json_volumes.push(json!({
+            "device": volume.device,
+            "size": volume.size,
+            "used": volume.used,
+            "read_total": volume.stat.read_bytes,
+            "write_total": volume.stat.write_bytes,
+            "read_rate": volume_speed.read,
+            "write_rate": volume_speed.write,
+            "mount_points": volume.points
+        }));
+    }
+
+    Value::Array(json_volumes)
+}
+
+/// The `cpu` field only exists if the CPU utilization of the process has been measured.
+pub fn processes(
+    processes: &[Process],
+    percentage: &BTreeMap<u32, f64>,
+    user_cache: &UsersCache,
+) -> Value {
+    let mut json_processes = Vec::with_capacity(processes.len());
+
+    for process in processes {
+        let user = user_cache
+            .get_user_by_uid(process.effective_uid)
+            .map(|user| user.name().to_string_lossy().into_owned());
+        let group = user_cache
+            .get_group_by_gid(process.effective_gid)
+            .map(|group| group.name().to_string_lossy().into_owned());
+
+        let mut json_process = json!({
+            "pid": process.pid,
+            "ppid": process.ppid,
+            "uid": process.effective_uid,
+            "user": user,
+            "gid": process.effective_gid,
+            "group": group,
+            "program": process.program,
+            "cmdline": process.cmdline,
+            "state": process.state.as_str(),
+            "tty": process.tty,
+            "priority": process.priority,
+            "real_time_priority": process.real_time_priority,
+            "nice": process.nice,
+            "threads": process.threads,
+            "vsz": process.vsz,
+            "rss": process.rss,
+            "anon": process.rss_anon,
+            "start_time": process.start_time.to_rfc3339_opts(SecondsFormat::Secs, true)
+        });
+
+        if let Some(percentage) = percentage.get(&process.pid) {
+            json_process["cpu"] = json!(percentage);
+        }
+
+        json_processes.push(json_process);
+    }
+
+    Value::Array(json_processes)
+}
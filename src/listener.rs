@@ -0,0 +1,308 @@
+use std::{
+    env,
+    fmt::{self, Display, Formatter},
+    fs,
+    io::ErrorKind,
+    net::{self, IpAddr, SocketAddr},
+    os::{
+        fd::{FromRawFd, IntoRawFd},
+        unix::{
+            fs::{chown, FileTypeExt, PermissionsExt},
+            net as unix_net,
+        },
+    },
+    path::{Path, PathBuf},
+    process,
+    str::FromStr,
+};
+
+use anyhow::{anyhow, Context};
+use rocket::tokio::{
+    net::{TcpListener, UnixListener},
+    task,
+};
+
+use crate::forwarder::Forwarder;
+
+/// The first file descriptor passed by systemd socket activation.
+const SD_LISTEN_FDS_START: i32 = 3;
+
+/// The owner and the group of a Unix domain socket, in the form of `USER[:GROUP]` or `:GROUP`.
+#[derive(Debug, Clone, Copy)]
+pub struct SocketOwner {
+    uid: Option<u32>,
+    gid: Option<u32>,
+}
+
+impl FromStr for SocketOwner {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (user, group) = match s.split_once(':') {
+            Some((user, group)) => (user, Some(group)),
+            None => (s, None),
+        };
+
+        let uid = match user {
+            "" => None,
+            user => Some(match user.parse() {
+                Ok(uid) => uid,
+                Err(_) => users::get_user_by_name(user)
+                    .ok_or_else(|| anyhow!("The user `{user}` does not exist."))?
+                    .uid(),
+            }),
+        };
+
+        let gid = match group {
+            None | Some("") => None,
+            Some(group) => Some(match group.parse() {
+                Ok(gid) => gid,
+                Err(_) => users::get_group_by_name(group)
+                    .ok_or_else(|| anyhow!("The group `{group}` does not exist."))?
+                    .gid(),
+            }),
+        };
+
+        if uid.is_none() && gid.is_none() {
+            return Err(anyhow!("Assign a user, a group, or both."));
+        }
+
+        Ok(SocketOwner {
+            uid,
+            gid,
+        })
+    }
+}
+
+/// A socket file which is removed when it is dropped.
+#[derive(Debug)]
+pub struct SocketFile(PathBuf);
+
+impl Drop for SocketFile {
+    #[inline]
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.0);
+    }
+}
+
+/// A listener which Rocket cannot bind by itself, or which needs the TLS connections to be decrypted first. The connections accepted from it are served by a `Forwarder`.
+#[derive(Debug)]
+pub enum Listener {
+    Tcp(net::TcpListener),
+    /// `file` is `None` if the socket was not bound by this program.
+    Unix {
+        listener: unix_net::UnixListener,
+        file:     Option<SocketFile>,
+    },
+}
+
+impl Listener {
+    /// Take the socket passed by systemd socket activation (`LISTEN_PID` and `LISTEN_FDS`), if any.
+    pub fn from_systemd() -> anyhow::Result<Option<Listener>> {
+        let pid = env::var("LISTEN_PID").ok().and_then(|pid| pid.parse::<u32>().ok());
+        let fds = env::var("LISTEN_FDS").ok().and_then(|fds| fds.parse::<u32>().ok());
+
+        if pid != Some(process::id()) || fds.unwrap_or(0) == 0 {
+            return Ok(None);
+        }
+
+        if fds != Some(1) {
+            return Err(anyhow!("Only one socket can be passed by systemd socket activation."));
+        }
+
+        // the file descriptor must not be inherited by child processes
+        env::remove_var("LISTEN_PID");
+        env::remove_var("LISTEN_FDS");
+        env::remove_var("LISTEN_FDNAMES");
+
+        // the type of the socket is unknown, so try it as a Unix domain socket first
+        let listener = unsafe { unix_net::UnixListener::from_raw_fd(SD_LISTEN_FDS_START) };
+
+        if listener.local_addr().is_ok() {
+            return Ok(Some(Listener::Unix {
+                listener,
+                file: None,
+            }));
+        }
+
+        let listener = unsafe { net::TcpListener::from_raw_fd(listener.into_raw_fd()) };
+
+        listener.local_addr().context(
+            "The socket passed by systemd socket activation is not a TCP or Unix socket.",
+        )?;
+
+        Ok(Some(Listener::Tcp(listener)))
+    }
+
+    #[inline]
+    pub fn bind_tcp(address: IpAddr, listen_port: u16) -> anyhow::Result<Listener> {
+        let address = SocketAddr::new(address, listen_port);
+
+        Ok(Listener::Tcp(
+            net::TcpListener::bind(address)
+                .with_context(|| format!("Cannot listen on {address}."))?,
+        ))
+    }
+
+    /// Bind a Unix domain socket. A stale socket file left by a previous run is replaced.
+    pub fn bind_unix(
+        path: &Path,
+        mode: Option<u32>,
+        owner: Option<SocketOwner>,
+    ) -> anyhow::Result<Listener> {
+        if let Ok(metadata) = fs::symlink_metadata(path) {
+            if !metadata.file_type().is_socket() {
+                return Err(anyhow!("{path:?} exists and is not a socket."));
+            }
+
+            match unix_net::UnixStream::connect(path) {
+                Ok(_) => return Err(anyhow!("{path:?} is being listened on.")),
+                Err(err) if err.kind() == ErrorKind::ConnectionRefused => {
+                    fs::remove_file(path).with_context(|| format!("Cannot remove {path:?}."))?
+                },
+                Err(err) => return Err(err).with_context(|| format!("Cannot check {path:?}.")),
+            }
+        }
+
+        let listener =
+            unix_net::UnixListener::bind(path).with_context(|| format!("Cannot bind {path:?}."))?;
+
+        let file = SocketFile(path.to_path_buf());
+
+        if let Some(mode) = mode {
+            fs::set_permissions(path, fs::Permissions::from_mode(mode))
+                .with_context(|| format!("Cannot change the permissions of {path:?}."))?;
+        }
+
+        if let Some(owner) = owner {
+            chown(path, owner.uid, owner.gid)
+                .with_context(|| format!("Cannot change the owner of {path:?}."))?;
+        }
+
+        Ok(Listener::Unix {
+            listener,
+            file: Some(file),
+        })
+    }
+
+    /// Accept connections and serve them with `forwarder`. It returns only when accepting fails. The socket file is removed when the returned future is dropped.
+    pub async fn forward(self, forwarder: Forwarder) -> anyhow::Result<()> {
+        match self {
+            Listener::Tcp(listener) => {
+                listener.set_nonblocking(true)?;
+
+                let listener = TcpListener::from_std(listener)?;
+
+                loop {
+                    let (stream, peer) =
+                        listener.accept().await.context("Cannot accept a connection.")?;
+
+                    let forwarder = forwarder.clone();
+
+                    task::spawn(async move { forwarder.serve(stream, Some(peer.ip())).await });
+                }
+            },
+            Listener::Unix {
+                listener,
+                file: _file,
+            } => {
+                listener.set_nonblocking(true)?;
+
+                let listener = UnixListener::from_std(listener)?;
+
+                loop {
+                    let (stream, _) =
+                        listener.accept().await.context("Cannot accept a connection.")?;
+
+                    let forwarder = forwarder.clone();
+
+                    task::spawn(async move { forwarder.serve(stream, None).await });
+                }
+            },
+        }
+    }
+}
+
+impl Display for Listener {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Listener::Tcp(listener) => match listener.local_addr() {
+                Ok(address) => Display::fmt(&address, f),
+                Err(_) => f.write_str("a TCP socket"),
+            },
+            Listener::Unix {
+                file: Some(file), ..
+            } => Display::fmt(&file.0.display(), f),
+            Listener::Unix {
+                file: None, ..
+            } => f.write_str("a Unix domain socket"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use hyper::{client::conn, Body, Request, StatusCode};
+    use rocket::tokio::net::UnixStream;
+
+    use super::*;
+    use crate::forwarder::{self, test_backend};
+
+    #[test]
+    fn test_bind_unix() {
+        let path = env::temp_dir().join(format!("mprober-test-{}.sock", process::id()));
+
+        let owner: SocketOwner = format!(":{}", users::get_current_gid()).parse().unwrap();
+
+        assert!("".parse::<SocketOwner>().is_err());
+
+        let listener = Listener::bind_unix(&path, Some(0o660), Some(owner)).unwrap();
+
+        assert_eq!(0o660, fs::metadata(&path).unwrap().permissions().mode() & 0o777);
+
+        // the socket is still being listened on
+        assert!(Listener::bind_unix(&path, None, None).is_err());
+
+        drop(listener);
+
+        // the socket file is removed with the listener
+        assert!(!path.exists());
+
+        // a stale socket file is replaced
+        drop(unix_net::UnixListener::bind(&path).unwrap());
+
+        drop(Listener::bind_unix(&path, None, None).unwrap());
+    }
+
+    #[rocket::async_test]
+    async fn test_forward_unix() {
+        let secret = forwarder::generate_secret();
+
+        let backend = test_backend::launch(&secret).await;
+
+        let path = env::temp_dir().join(format!("mprober-test-{}-forward.sock", process::id()));
+
+        let listener = Listener::bind_unix(&path, None, None).unwrap();
+
+        let forwarding = task::spawn(listener.forward(Forwarder::new(backend, &secret, None)));
+
+        let (mut sender, connection) =
+            conn::handshake(UnixStream::connect(&path).await.unwrap()).await.unwrap();
+
+        task::spawn(connection);
+
+        // the reverse proxy on the other side of the socket passes the address of the client
+        let request =
+            Request::get("/ip").header("X-Real-IP", "10.0.0.1").body(Body::empty()).unwrap();
+
+        let response = sender.send_request(request).await.unwrap();
+
+        assert_eq!(StatusCode::OK, response.status());
+        assert_eq!(&b"10.0.0.1"[..], hyper::body::to_bytes(response.into_body()).await.unwrap());
+
+        forwarding.abort();
+        let _ = forwarding.await;
+
+        assert!(!path.exists());
+    }
+}
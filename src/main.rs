@@ -7,47 +7,85 @@ extern crate rocket_include_static_resources;
 #[macro_use]
 extern crate rocket_include_handlebars;
 
+mod auth;
 mod benchmark;
 mod cli;
 mod commands;
+mod config;
+mod export;
+mod failure_log;
+mod forwarder;
+#[cfg(test)]
+mod http_stub;
+mod json;
+mod listener;
+mod output;
+mod push;
+mod recording;
 mod rocket_mounts;
+mod rules;
 mod terminal;
+mod tls;
 
 use cli::*;
 use commands::*;
 fn main() -> anyhow::Result<()> {
-    let args = get_args();
+    let args = get_args()?;
 
     match &args.command {
-        CLICommands::Hostname => handle_hostname(),
-        CLICommands::Kernel => handle_kernel(),
+        CLICommands::Hostname => handle_hostname(args)?,
+        CLICommands::Kernel => handle_kernel(args)?,
         CLICommands::Uptime {
             ..
-        } => handle_uptime(args),
+        } => handle_uptime(args)?,
         CLICommands::Time {
             ..
-        } => handle_time(args),
+        } => handle_time(args)?,
         CLICommands::Cpu {
             ..
-        } => handle_cpu(args),
+        } => handle_cpu(args)?,
         CLICommands::Memory {
             ..
-        } => handle_memory(args),
+        } => handle_memory(args)?,
         CLICommands::Network {
             ..
-        } => handle_network(args),
+        } => handle_network(args)?,
         CLICommands::Volume {
             ..
-        } => handle_volume(args),
+        } => handle_volume(args)?,
         CLICommands::Process {
             ..
         } => handle_process(args)?,
+        CLICommands::Hub {
+            ..
+        } => handle_hub(args)?,
+        CLICommands::Push {
+            ..
+        } => handle_push(args)?,
+        CLICommands::Export {
+            ..
+        } => handle_export(args)?,
+        CLICommands::AuthKey {
+            ..
+        } => handle_auth_key(args)?,
+        CLICommands::Check {
+            ..
+        } => handle_check(args)?,
+        CLICommands::Record {
+            ..
+        } => handle_record(args)?,
+        CLICommands::Replay {
+            ..
+        } => handle_replay(args)?,
         CLICommands::Web {
             ..
         } => handle_web(args)?,
         CLICommands::Benchmark {
             ..
         } => handle_benchmark(args)?,
+        CLICommands::Config {
+            ..
+        } => handle_config(args)?,
     }
 
     Ok(())
@@ -0,0 +1,49 @@
+use std::{
+    io::{self, Write},
+    thread,
+    time::Duration,
+};
+
+use anyhow::anyhow;
+use serde_json::Value;
+
+use crate::OutputFormat;
+
+pub fn print_json(output: OutputFormat, value: &Value) -> anyhow::Result<()> {
+    let mut stdout = io::stdout().lock();
+
+    match output {
+        OutputFormat::Json => serde_json::to_writer_pretty(&mut stdout, value)?,
+        _ => serde_json::to_writer(&mut stdout, value)?,
+    }
+
+    writeln!(stdout)?;
+    stdout.flush()?;
+
+    Ok(())
+}
+
+/// Print the value produced by `f` once, or print a new value every refresh in the monitor mode. If `self_sleep` is `true`, `f` is expected to take the whole interval to measure something so there is no need to sleep between two refreshes.
+pub fn json_handler<F: FnMut() -> anyhow::Result<Value>>(
+    output: OutputFormat,
+    monitor: Option<Duration>,
+    self_sleep: bool,
+    mut f: F,
+) -> anyhow::Result<()> {
+    match monitor {
+        Some(monitor) => {
+            if output != OutputFormat::Ndjson {
+                return Err(anyhow!("The monitor mode needs to be used with `--output ndjson`."));
+            }
+
+            loop {
+                print_json(output, &f()?)?;
+
+                if !self_sleep {
+                    thread::sleep(monitor);
+                }
+            }
+        },
+        None => print_json(output, &f()?),
+    }
+}
@@ -0,0 +1,194 @@
+use std::{collections::VecDeque, time::Duration};
+
+use chrono::{SecondsFormat, Utc};
+use rocket::tokio::task;
+
+use crate::{
+    failure_log::FailureLog,
+    rocket_mounts::{Push, Sampler},
+};
+
+/// Push snapshots of `/api/all` to the `/api/ingest` of a collector. Snapshots which cannot be pushed are buffered and pushed again, oldest first, after the next snapshot is taken.
+#[derive(Debug)]
+pub struct Pusher {
+    url:      String,
+    auth_key: Option<String>,
+    host:     String,
+    interval: Duration,
+    timeout:  Duration,
+    buffer:   VecDeque<Push>,
+    capacity: usize,
+    failures: FailureLog,
+}
+
+impl Pusher {
+    /// `to` is the URL of the collector without a trailing slash. At most `capacity` snapshots are buffered, and the oldest ones are dropped when it is full.
+    pub fn new(
+        to: &str,
+        auth_key: Option<String>,
+        host: String,
+        interval: Duration,
+        timeout: Duration,
+        capacity: usize,
+    ) -> Pusher {
+        Pusher {
+            url: format!("{to}/api/ingest"),
+            auth_key,
+            host,
+            interval,
+            timeout,
+            buffer: VecDeque::new(),
+            capacity: capacity.max(1),
+            failures: FailureLog::default(),
+        }
+    }
+
+    fn buffer(&mut self, data: serde_json::Value) {
+        if self.buffer.len() == self.capacity {
+            self.buffer.pop_front();
+        }
+
+        self.buffer.push_back(Push {
+            host: self.host.clone(),
+            time: Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true),
+            interval: self.interval.as_secs().max(1),
+            data,
+        });
+    }
+
+    /// Push the buffered snapshots in order until one of them fails to be pushed, and return how many snapshots are pushed. A snapshot rejected by the collector for being invalid is dropped instead of being retried.
+    fn flush(&mut self) -> usize {
+        let mut pushed = 0;
+
+        while let Some(push) = self.buffer.front() {
+            match self.post(push) {
+                Ok(()) => {
+                    self.buffer.pop_front();
+
+                    pushed += 1;
+                },
+                Err(error) if is_rejected(&error) => {
+                    eprintln!("The collector {} rejects a snapshot: {error}", self.url);
+
+                    self.buffer.pop_front();
+                },
+                Err(error) => {
+                    self.failures.fail(format_args!(
+                        "Cannot push to {}: {error}. Snapshots are buffered until it is back.",
+                        self.url
+                    ));
+
+                    return pushed;
+                },
+            }
+        }
+
+        self.failures.succeed(format_args!(
+            "The collector {} is back. {pushed} buffered snapshots are pushed.",
+            self.url
+        ));
+
+        pushed
+    }
+
+    fn post(&self, push: &Push) -> Result<(), Box<ureq::Error>> {
+        let mut request =
+            ureq::post(&self.url).timeout(self.timeout).set("Content-Type", "application/json");
+
+        if let Some(auth_key) = self.auth_key.as_ref() {
+            request = request.set("Authorization", auth_key);
+        }
+
+        request.send_string(&serde_json::to_string(push).unwrap())?;
+
+        Ok(())
+    }
+
+    /// Push every new snapshot of the sampler, forever.
+    pub async fn run(mut self, sampler: Sampler) {
+        let mut receiver = sampler.subscribe();
+
+        while receiver.changed().await.is_ok() {
+            let snapshot = receiver.borrow_and_update().clone();
+
+            if let Some(snapshot) = snapshot {
+                self.buffer(snapshot.as_ref().clone());
+
+                self = task::spawn_blocking(move || {
+                    self.flush();
+
+                    self
+                })
+                .await
+                .unwrap();
+            }
+        }
+    }
+}
+
+/// Whether the collector responds with an error which will not go away by retrying.
+#[inline]
+fn is_rejected(error: &ureq::Error) -> bool {
+    match error {
+        ureq::Error::Status(code, _) => {
+            (400..500).contains(code) && ![401, 403, 408, 429].contains(code)
+        },
+        ureq::Error::Transport(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::net::TcpListener;
+
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::http_stub;
+
+    #[test]
+    fn test_flush() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+
+        let mut pusher = Pusher::new(
+            &format!("http://{address}"),
+            None,
+            "db1".to_string(),
+            Duration::from_secs(10),
+            Duration::from_secs(5),
+            2,
+        );
+
+        for i in 0..3 {
+            pusher.buffer(json!({ "uptime": i }));
+        }
+
+        // the oldest snapshot is dropped
+        assert_eq!(2, pusher.buffer.len());
+
+        let collector = http_stub::serve(listener.try_clone().unwrap(), vec![(503, String::new())]);
+        assert_eq!(0, pusher.flush());
+        collector.join().unwrap();
+
+        assert!(pusher.failures.is_failing());
+        assert_eq!(1, pusher.failures.logged());
+        assert_eq!(2, pusher.buffer.len());
+
+        // the rejected snapshot is not counted as pushed
+        let collector =
+            http_stub::serve(listener, vec![(400, String::new()), (204, String::new())]);
+        assert_eq!(1, pusher.flush());
+        let bodies: Vec<Value> =
+            collector.join().unwrap().iter().map(|request| request.json()).collect();
+
+        assert!(!pusher.failures.is_failing());
+        assert_eq!(2, pusher.failures.logged());
+        assert!(pusher.buffer.is_empty());
+
+        assert_eq!("db1", bodies[0]["host"]);
+        assert_eq!(10, bodies[0]["interval"]);
+        assert_eq!(json!({ "uptime": 1 }), bodies[0]["data"]);
+        assert_eq!(json!({ "uptime": 2 }), bodies[1]["data"]);
+    }
+}
@@ -0,0 +1,516 @@
+use std::{
+    collections::BTreeMap,
+    io::{BufRead, Write},
+    iter, thread,
+    time::Duration,
+};
+
+use anyhow::anyhow;
+use chrono::{DateTime, SecondsFormat, Utc};
+use mprober_lib::{
+    cpu::CPU,
+    load_average::LoadAverage,
+    memory::{Free, Mem, Swap},
+    network::{Network, NetworkSpeed, NetworkStat},
+    process::{Process, ProcessState},
+    volume::{Volume, VolumeSpeed, VolumeStat},
+};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use users::UsersCache;
+
+use crate::{
+    commands::{get_cpu_info, get_processes, CPUInfo},
+    ProcessSort,
+};
+
+/// One snapshot of all collectors. A recording is a file with a snapshot in JSON per line.
+pub struct Snapshot {
+    pub time:                 DateTime<Utc>,
+    /// The CPU stats are always measured separately.
+    pub cpu_info:             CPUInfo,
+    pub free:                 Free,
+    pub networks_with_speed:  Vec<(Network, NetworkSpeed)>,
+    pub volumes_with_speed:   Vec<(Volume, VolumeSpeed)>,
+    pub processes:            Vec<Process>,
+    pub processes_percentage: BTreeMap<u32, f64>,
+}
+
+impl Snapshot {
+    /// Take a snapshot. The rates are measured concurrently within `interval`.
+    pub fn take(
+        interval: Duration,
+        top: usize,
+        user_cache: &UsersCache,
+    ) -> anyhow::Result<Snapshot> {
+        let time = Utc::now();
+
+        thread::scope(|s| {
+            let cpu_info = s.spawn(|| get_cpu_info(Some(interval), true, false));
+            let networks_with_speed =
+                s.spawn(|| mprober_lib::network::get_networks_with_speed(interval));
+            let volumes_with_speed =
+                s.spawn(|| mprober_lib::volume::get_volumes_with_speed(interval));
+
+            // `UsersCache` cannot be shared between threads, so processes are measured on the current thread
+            let (processes, processes_percentage) = get_processes(
+                Some(interval),
+                Some(top),
+                ProcessSort::Cpu,
+                false,
+                false,
+                None,
+                None,
+                None,
+                None,
+                None,
+                user_cache,
+            )?;
+
+            Ok(Snapshot {
+                time,
+                cpu_info: join(cpu_info, "CPU")??,
+                free: mprober_lib::memory::free()?,
+                networks_with_speed: join(networks_with_speed, "network")??,
+                volumes_with_speed: join(volumes_with_speed, "volume")??,
+                processes,
+                processes_percentage,
+            })
+        })
+    }
+
+    pub fn to_json(&self, user_cache: &UsersCache) -> Value {
+        json!({
+            "time": self.time.to_rfc3339_opts(SecondsFormat::Millis, true),
+            "load_average": crate::json::load_average(&self.cpu_info.load_average),
+            "cpus": crate::json::cpus(&self.cpu_info.cpus),
+            "cpus_stat": self.cpu_info.cpus_stat,
+            "memory": crate::json::memory(&self.free.mem),
+            "swap": crate::json::swap(&self.free.swap),
+            "network": crate::json::networks_with_speed(&self.networks_with_speed),
+            "volumes": crate::json::volumes_with_speed(&self.volumes_with_speed),
+            "processes": crate::json::processes(&self.processes, &self.processes_percentage, user_cache)
+        })
+    }
+
+    /// Append this snapshot to a recording as one line.
+    pub fn write_to<W: Write>(
+        &self,
+        writer: &mut W,
+        user_cache: &UsersCache,
+    ) -> anyhow::Result<()> {
+        serde_json::to_writer(&mut *writer, &self.to_json(user_cache))?;
+
+        writeln!(writer)?;
+        writer.flush()?;
+
+        Ok(())
+    }
+
+    fn from_recorded(recorded: RecordedSnapshot) -> anyhow::Result<Snapshot> {
+        let time = DateTime::parse_from_rfc3339(&recorded.time)?.with_timezone(&Utc);
+
+        let cpus = recorded
+            .cpus
+            .into_iter()
+            .enumerate()
+            .map(|(physical_id, cpu)| CPU {
+                physical_id,
+                model_name: cpu.model_name,
+                cpus_mhz: cpu.mhz,
+                siblings: cpu.threads,
+                cpu_cores: cpu.cores,
+            })
+            .collect();
+
+        let networks_with_speed = recorded
+            .network
+            .into_iter()
+            .map(|network| {
+                (
+                    Network {
+                        interface: network.interface,
+                        stat:      NetworkStat {
+                            receive_bytes:  network.download_total,
+                            transmit_bytes: network.upload_total,
+                        },
+                    },
+                    NetworkSpeed {
+                        receive: network.download_rate, transmit: network.upload_rate
+                    },
+                )
+            })
+            .collect();
+
+        let volumes_with_speed = recorded
+            .volumes
+            .into_iter()
+            .map(|volume| {
+                (
+                    Volume {
+                        device: volume.device,
+                        stat:   VolumeStat {
+                            read_bytes:  volume.read_total,
+                            write_bytes: volume.write_total,
+                        },
+                        size:   volume.size,
+                        used:   volume.used,
+                        points: volume.mount_points,
+                    },
+                    VolumeSpeed {
+                        read: volume.read_rate, write: volume.write_rate
+                    },
+                )
+            })
+            .collect();
+
+        let mut processes = Vec::with_capacity(recorded.processes.len());
+        let mut processes_percentage = BTreeMap::new();
+
+        for process in recorded.processes {
+            if let Some(cpu) = process.cpu {
+                processes_percentage.insert(process.pid, cpu);
+            }
+
+            processes.push(Process {
+                pid:                process.pid,
+                effective_uid:      process.uid,
+                effective_gid:      process.gid,
+                state:              parse_process_state(&process.state)
+                    .ok_or_else(|| anyhow!("Unknown process state {:?}.", process.state))?,
+                ppid:               process.ppid,
+                program:            process.program,
+                cmdline:            process.cmdline,
+                tty:                process.tty,
+                priority:           process.priority,
+                real_time_priority: process.real_time_priority,
+                nice:               process.nice,
+                threads:            process.threads,
+                vsz:                process.vsz,
+                rss:                process.rss,
+                // not recorded
+                rss_shared:         0,
+                rss_anon:           process.anon,
+                start_time:         DateTime::parse_from_rfc3339(&process.start_time)?
+                    .with_timezone(&Utc),
+            });
+        }
+
+        Ok(Snapshot {
+            time,
+            cpu_info: CPUInfo {
+                load_average: recorded.load_average,
+                cpus,
+                cpus_stat: recorded.cpus_stat,
+            },
+            free: Free {
+                mem: recorded.memory, swap: recorded.swap
+            },
+            networks_with_speed,
+            volumes_with_speed,
+            processes,
+            processes_percentage,
+        })
+    }
+}
+
+/// Wait for a thread measuring the `stats`. A panic of the thread becomes an error.
+fn join<T>(handle: thread::ScopedJoinHandle<T>, stats: &str) -> anyhow::Result<T> {
+    handle.join().map_err(|_| anyhow!("The thread measuring the {stats} stats panicked."))
+}
+
+/// Read the snapshots of a recording line by line. If the last line is not terminated and cannot be parsed, the recorder was stopped while writing it, so it is skipped instead of failing the whole recording.
+pub fn read_snapshots<R: BufRead>(mut reader: R) -> impl Iterator<Item = anyhow::Result<Snapshot>> {
+    let mut number = 0;
+    let mut line = Vec::new();
+
+    iter::from_fn(move || loop {
+        line.clear();
+        number += 1;
+
+        match reader.read_until(b'\n', &mut line) {
+            Ok(0) => return None,
+            Ok(_) => (),
+            Err(error) => return Some(Err(error.into())),
+        }
+
+        if line.iter().all(u8::is_ascii_whitespace) {
+            continue;
+        }
+
+        let result = serde_json::from_slice(&line)
+            .map_err(anyhow::Error::from)
+            .and_then(Snapshot::from_recorded);
+
+        return match result {
+            Ok(snapshot) => Some(Ok(snapshot)),
+            Err(_) if line.last() != Some(&b'\n') => {
+                eprintln!("Line {number} is an incomplete snapshot and is skipped.");
+
+                None
+            },
+            Err(error) => {
+                Some(Err(error.context(format!("Line {number} is not a valid snapshot."))))
+            },
+        };
+    })
+}
+
+/// The inverse of `ProcessState::as_str`.
+fn parse_process_state(s: &str) -> Option<ProcessState> {
+    [
+        ProcessState::Running,
+        ProcessState::Sleeping,
+        ProcessState::Waiting,
+        ProcessState::Zombie,
+        ProcessState::Stopped,
+        ProcessState::TracingStop,
+        ProcessState::PagingOrWaking,
+        ProcessState::Dead,
+        ProcessState::Wakekill,
+        ProcessState::Parked,
+        ProcessState::Idle,
+    ]
+    .into_iter()
+    .find(|state| state.as_str() == s)
+}
+
+#[derive(Deserialize)]
+#[serde(remote = "LoadAverage")]
+struct LoadAverageDef {
+    one:     f64,
+    five:    f64,
+    fifteen: f64,
+}
+
+#[derive(Deserialize)]
+#[serde(remote = "Mem")]
+struct MemDef {
+    total:     usize,
+    used:      usize,
+    free:      usize,
+    shared:    usize,
+    buffers:   usize,
+    cache:     usize,
+    available: usize,
+}
+
+#[derive(Deserialize)]
+#[serde(remote = "Swap")]
+struct SwapDef {
+    total: usize,
+    used:  usize,
+    free:  usize,
+    cache: usize,
+}
+
+#[derive(Deserialize)]
+struct RecordedSnapshot {
+    time:         String,
+    #[serde(with = "LoadAverageDef")]
+    load_average: LoadAverage,
+    cpus:         Vec<RecordedCPU>,
+    cpus_stat:    Vec<f64>,
+    #[serde(with = "MemDef")]
+    memory:       Mem,
+    #[serde(with = "SwapDef")]
+    swap:         Swap,
+    network:      Vec<RecordedNetwork>,
+    volumes:      Vec<RecordedVolume>,
+    processes:    Vec<RecordedProcess>,
+}
+
+#[derive(Deserialize)]
+struct RecordedCPU {
+    model_name: String,
+    cores:      usize,
+    threads:    usize,
+    mhz:        Vec<f64>,
+}
+
+#[derive(Deserialize)]
+struct RecordedNetwork {
+    interface:      String,
+    upload_total:   u64,
+    download_total: u64,
+    upload_rate:    f64,
+    download_rate:  f64,
+}
+
+#[derive(Deserialize)]
+struct RecordedVolume {
+    device:       String,
+    size:         u64,
+    used:         u64,
+    read_total:   u64,
+    write_total:  u64,
+    read_rate:    f64,
+    write_rate:   f64,
+    mount_points: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct RecordedProcess {
+    pid:                u32,
+    ppid:               u32,
+    uid:                u32,
+    gid:                u32,
+    program:            String,
+    cmdline:            String,
+    state:              String,
+    tty:                Option<String>,
+    priority:           i8,
+    real_time_priority: Option<u8>,
+    nice:               i8,
+    threads:            usize,
+    vsz:                usize,
+    rss:                usize,
+    anon:               usize,
+    start_time:         String,
+    cpu:                Option<f64>,
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Cursor;
+
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn snapshot(second: u32) -> Snapshot {
+        let time = Utc.with_ymd_and_hms(2024, 1, 2, 3, 4, second).unwrap();
+
+        Snapshot {
+            time,
+            cpu_info: CPUInfo {
+                load_average: LoadAverage {
+                    one: 0.5, five: 0.25, fifteen: 0.125
+                },
+                cpus:         vec![CPU {
+                    physical_id: 0,
+                    model_name:  "Test CPU".to_string(),
+                    cpus_mhz:    vec![1000.0, 1500.0],
+                    siblings:    2,
+                    cpu_cores:   1,
+                }],
+                cpus_stat:    vec![0.5, 0.25, 0.75],
+            },
+            free: Free {
+                mem:  Mem {
+                    total:     1024,
+                    used:      512,
+                    free:      256,
+                    shared:    8,
+                    buffers:   64,
+                    cache:     128,
+                    available: 384,
+                },
+                swap: Swap {
+                    total: 100, used: 10, free: 90, cache: 1
+                },
+            },
+            networks_with_speed: vec![(
+                Network {
+                    interface: "eth0".to_string(),
+                    stat:      NetworkStat {
+                        receive_bytes: 1000, transmit_bytes: 2000
+                    },
+                },
+                NetworkSpeed {
+                    receive: 10.0, transmit: 20.0
+                },
+            )],
+            volumes_with_speed: vec![(
+                Volume {
+                    device: "sda1".to_string(),
+                    stat:   VolumeStat {
+                        read_bytes: 3000, write_bytes: 4000
+                    },
+                    size:   10000,
+                    used:   5000,
+                    points: vec!["/".to_string(), "/mnt/a b".to_string()],
+                },
+                VolumeSpeed {
+                    read: 30.0, write: 40.0
+                },
+            )],
+            processes: vec![Process {
+                pid:                1,
+                effective_uid:      0,
+                effective_gid:      0,
+                state:              ProcessState::Sleeping,
+                ppid:               0,
+                program:            "init".to_string(),
+                cmdline:            "/sbin/init splash".to_string(),
+                tty:                None,
+                priority:           20,
+                real_time_priority: None,
+                nice:               0,
+                threads:            1,
+                vsz:                4096,
+                rss:                2048,
+                rss_shared:         0,
+                rss_anon:           1024,
+                start_time:         time,
+            }],
+            processes_percentage: BTreeMap::from([(1, 0.5)]),
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let user_cache = UsersCache::new();
+
+        let mut recording = Vec::new();
+
+        snapshot(5).write_to(&mut recording, &user_cache).unwrap();
+        snapshot(6).write_to(&mut recording, &user_cache).unwrap();
+
+        let snapshots =
+            read_snapshots(Cursor::new(recording)).collect::<Result<Vec<_>, _>>().unwrap();
+
+        assert_eq!(2, snapshots.len());
+        assert_eq!(snapshot(5).to_json(&user_cache), snapshots[0].to_json(&user_cache));
+        assert_eq!(snapshot(6).to_json(&user_cache), snapshots[1].to_json(&user_cache));
+    }
+
+    #[test]
+    fn test_truncated_last_line() {
+        let user_cache = UsersCache::new();
+
+        let mut recording = Vec::new();
+
+        snapshot(5).write_to(&mut recording, &user_cache).unwrap();
+
+        let complete = recording.len();
+
+        snapshot(6).write_to(&mut recording, &user_cache).unwrap();
+
+        // the recorder is stopped while writing the second snapshot
+        recording.truncate(complete + (recording.len() - complete) / 2);
+
+        let snapshots =
+            read_snapshots(Cursor::new(recording)).collect::<Result<Vec<_>, _>>().unwrap();
+
+        assert_eq!(1, snapshots.len());
+        assert_eq!(snapshot(5).to_json(&user_cache), snapshots[0].to_json(&user_cache));
+    }
+
+    #[test]
+    fn test_invalid_line() {
+        let user_cache = UsersCache::new();
+
+        let mut recording = b"\n{\"time\": 1}\n".to_vec();
+
+        snapshot(5).write_to(&mut recording, &user_cache).unwrap();
+
+        let mut snapshots = read_snapshots(Cursor::new(recording));
+
+        let error = snapshots.next().unwrap().err().unwrap();
+
+        assert_eq!("Line 2 is not a valid snapshot.", error.to_string());
+        assert!(snapshots.next().unwrap().is_ok());
+        assert!(snapshots.next().is_none());
+    }
+}
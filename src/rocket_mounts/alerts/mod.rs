@@ -0,0 +1,391 @@
+use std::{
+    sync::{mpsc, Arc, Mutex},
+    thread,
+    time::Duration,
+};
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use rocket::{http::Status, Build, Rocket, State};
+use rocket_cache_response::CacheResponse;
+use rocket_json_response::{json_gettext::JSONGetTextValue, JSONResponse};
+use serde_json::{json, Value};
+
+use super::{
+    api::{Auth, OrUnavailable, ReadBasic, Subsystem, Unavailable},
+    sampler::{CPUsStatCollector, SampleResult, Sampler, VolumesStatCollector},
+};
+use crate::rules::{AlertRule, Stats};
+
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum AlertState {
+    Inactive,
+    /// The rule is met but not for long enough.
+    Pending,
+    Firing,
+}
+
+impl AlertState {
+    #[inline]
+    fn as_str(self) -> &'static str {
+        match self {
+            AlertState::Inactive => "inactive",
+            AlertState::Pending => "pending",
+            AlertState::Firing => "firing",
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Alert {
+    alert_rule:       AlertRule,
+    state:            AlertState,
+    value:            Option<f64>,
+    threshold:        Option<f64>,
+    /// When the rule started to be met.
+    active_since:     Option<DateTime<Utc>>,
+    fired_at:         Option<DateTime<Utc>>,
+    /// When the rule stopped being met while the alert was firing.
+    recovering_since: Option<DateTime<Utc>>,
+}
+
+/// Alerts evaluated against the samples of the sampler.
+#[derive(Debug)]
+pub struct Alerts {
+    alerts:   Mutex<Vec<Alert>>,
+    hostname: String,
+}
+
+impl Alerts {
+    fn new(alert_rules: Vec<AlertRule>, hostname: String) -> Alerts {
+        let alerts = alert_rules
+            .into_iter()
+            .map(|alert_rule| Alert {
+                alert_rule,
+                state: AlertState::Inactive,
+                value: None,
+                threshold: None,
+                active_since: None,
+                fired_at: None,
+                recovering_since: None,
+            })
+            .collect();
+
+        Alerts {
+            alerts: Mutex::new(alerts),
+            hostname,
+        }
+    }
+
+    /// Start evaluating the alert rules every time the CPU stats are updated. Notifications are POSTed to `webhook` in order.
+    pub fn start(
+        sampler: &Sampler,
+        alert_rules: Vec<AlertRule>,
+        webhook: Option<String>,
+    ) -> Arc<Alerts> {
+        let alerts = Arc::new(Alerts::new(
+            alert_rules,
+            mprober_lib::hostname::get_hostname().unwrap_or_default(),
+        ));
+
+        if alerts.alerts.lock().unwrap().is_empty() {
+            return alerts;
+        }
+
+        let sender = webhook.map(|webhook| {
+            let (sender, receiver) = mpsc::channel::<Value>();
+
+            thread::spawn(move || {
+                for notification in receiver {
+                    if let Err(error) = post_webhook(&webhook, &notification) {
+                        eprintln!("Cannot send the alert notification to {webhook}: {error}");
+                    }
+                }
+            });
+
+            sender
+        });
+
+        {
+            let alerts = alerts.clone();
+
+            let cpus_stat = sampler.sample::<CPUsStatCollector>();
+            let volumes_stat = sampler.sample::<VolumesStatCollector>();
+
+            thread::spawn(move || {
+                let cores = mprober_lib::cpu::get_cpus()
+                    .map(|cpus| cpus.iter().map(|cpu| cpu.siblings).sum())
+                    .unwrap_or(1);
+
+                let mut generation = 0;
+
+                loop {
+                    let (next_generation, cpus_stat) = cpus_stat.get_next(generation);
+
+                    generation = next_generation;
+
+                    // the alerts stay in their states until the stats can be read again
+                    let stats = match read_stats(cpus_stat, volumes_stat.wait(), cores) {
+                        Ok(stats) => stats,
+                        Err(_) => continue,
+                    };
+
+                    let notifications = alerts.evaluate(&stats, Utc::now());
+
+                    if let Some(sender) = sender.as_ref() {
+                        for notification in notifications {
+                            sender.send(notification).unwrap();
+                        }
+                    }
+                }
+            });
+        }
+
+        alerts
+    }
+
+    /// Update the states of alerts and return the notifications of the alerts which just fired or resolved.
+    fn evaluate(&self, stats: &Stats, now: DateTime<Utc>) -> Vec<Value> {
+        let mut alerts = self.alerts.lock().unwrap();
+
+        let mut notifications = Vec::new();
+
+        for alert in alerts.iter_mut() {
+            let rule = &alert.alert_rule.rule;
+
+            alert.value = rule.metric.value(stats);
+            alert.threshold = Some(rule.threshold.resolve(stats));
+
+            if rule.is_triggered(stats).unwrap_or(false) {
+                alert.recovering_since = None;
+
+                let active_since = *alert.active_since.get_or_insert(now);
+
+                if alert.state != AlertState::Firing {
+                    let elapsed = (now - active_since).to_std().unwrap_or_default();
+
+                    if elapsed >= alert.alert_rule.duration {
+                        alert.state = AlertState::Firing;
+                        alert.fired_at = Some(now);
+
+                        notifications.push(self.notification(alert, now));
+                    } else {
+                        alert.state = AlertState::Pending;
+                    }
+                }
+            } else {
+                let resolved = alert.state == AlertState::Firing;
+
+                if resolved {
+                    let recovering_since = *alert.recovering_since.get_or_insert(now);
+
+                    let elapsed = (now - recovering_since).to_std().unwrap_or_default();
+
+                    // keep firing until the rule has not been met for the recovery duration
+                    if elapsed < alert.alert_rule.recovery {
+                        continue;
+                    }
+                }
+
+                alert.state = AlertState::Inactive;
+
+                if resolved {
+                    notifications.push(self.notification(alert, now));
+                }
+
+                alert.active_since = None;
+                alert.fired_at = None;
+                alert.recovering_since = None;
+            }
+        }
+
+        notifications
+    }
+
+    fn notification(&self, alert: &Alert, now: DateTime<Utc>) -> Value {
+        json!({
+            "status": match alert.state {
+                AlertState::Firing => "firing",
+                _ => "resolved",
+            },
+            "host": self.hostname,
+            "rule": alert.alert_rule.to_string(),
+            "metric": alert.alert_rule.rule.metric.to_string(),
+            "value": alert.value,
+            "threshold": alert.threshold,
+            "since": alert.active_since.map(|time| time.to_rfc3339_opts(SecondsFormat::Secs, true)),
+            "time": now.to_rfc3339_opts(SecondsFormat::Secs, true)
+        })
+    }
+
+    fn to_json(&self) -> Value {
+        let alerts = self.alerts.lock().unwrap();
+
+        let mut json_alerts = Vec::with_capacity(alerts.len());
+
+        for alert in alerts.iter() {
+            json_alerts.push(json!({
+                "rule": alert.alert_rule.to_string(),
+                "metric": alert.alert_rule.rule.metric.to_string(),
+                "state": alert.state.as_str(),
+                "value": alert.value,
+                "threshold": alert.threshold,
+                "since": alert.active_since.map(|time| time.to_rfc3339_opts(SecondsFormat::Secs, true)),
+                "fired_at": alert.fired_at.map(|time| time.to_rfc3339_opts(SecondsFormat::Secs, true))
+            }));
+        }
+
+        Value::Array(json_alerts)
+    }
+}
+
+/// Gather the stats which the rules are evaluated against.
+fn read_stats(
+    cpus_stat: SampleResult<Vec<f64>>,
+    volumes_stat: SampleResult<
+        Vec<(mprober_lib::volume::Volume, mprober_lib::volume::VolumeSpeed)>,
+    >,
+    cores: usize,
+) -> Result<Stats, Unavailable> {
+    Ok(Stats {
+        cpus_stat: cpus_stat?.to_vec(),
+        load_average: mprober_lib::load_average::get_load_average()
+            .or_unavailable(Subsystem::LoadAverage)?,
+        free: mprober_lib::memory::free().or_unavailable(Subsystem::Memory)?,
+        volumes: volumes_stat?.iter().map(|(volume, _)| volume.clone()).collect(),
+        cores,
+    })
+}
+
+fn post_webhook(url: &str, notification: &Value) -> Result<(), Box<ureq::Error>> {
+    ureq::post(url)
+        .timeout(WEBHOOK_TIMEOUT)
+        .set("Content-Type", "application/json")
+        .send_string(&notification.to_string())?;
+
+    Ok(())
+}
+
+#[get("/alerts")]
+fn alerts(
+    _auth: Auth<ReadBasic>,
+    alerts: &State<Arc<Alerts>>,
+) -> CacheResponse<JSONResponse<'static>> {
+    CacheResponse::NoStore(JSONResponse::ok(JSONGetTextValue::from_json_value(alerts.to_json())))
+}
+
+#[get("/alerts", rank = 2)]
+fn alerts_401() -> Status {
+    Status::Unauthorized
+}
+
+pub fn rocket_handler(rocket: Rocket<Build>, base_path: &super::BasePath) -> Rocket<Build> {
+    rocket.mount(base_path.join("/api"), routes![alerts, alerts_401])
+}
+
+#[cfg(test)]
+mod test {
+    use std::net::TcpListener;
+
+    use mprober_lib::{load_average::LoadAverage, memory::Free};
+
+    use super::*;
+    use crate::http_stub;
+
+    fn create_stats(cpu: f64) -> Stats {
+        Stats {
+            cpus_stat:    vec![cpu],
+            load_average: LoadAverage {
+                one: 0.0, five: 0.0, fifteen: 0.0
+            },
+            free:         Free::default(),
+            volumes:      Vec::new(),
+            cores:        1,
+        }
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let alerts = Alerts::new(vec!["cpu>90 for 2m".parse().unwrap()], "localhost".to_string());
+
+        let start = Utc::now();
+
+        assert!(alerts.evaluate(&create_stats(0.95), start).is_empty());
+        assert_eq!("pending", alerts.to_json()[0]["state"]);
+
+        let notifications =
+            alerts.evaluate(&create_stats(0.95), start + chrono::Duration::seconds(120));
+
+        assert_eq!(1, notifications.len());
+        assert_eq!("firing", notifications[0]["status"]);
+        assert_eq!("firing", alerts.to_json()[0]["state"]);
+
+        // no duplicated notifications
+        assert!(alerts
+            .evaluate(&create_stats(0.99), start + chrono::Duration::seconds(121))
+            .is_empty());
+
+        let notifications =
+            alerts.evaluate(&create_stats(0.1), start + chrono::Duration::seconds(122));
+
+        assert_eq!(1, notifications.len());
+        assert_eq!("resolved", notifications[0]["status"]);
+        assert_eq!("inactive", alerts.to_json()[0]["state"]);
+    }
+
+    #[test]
+    fn test_evaluate_recovery() {
+        let alerts =
+            Alerts::new(vec!["cpu>90 recover after 1m".parse().unwrap()], "localhost".to_string());
+
+        let start = Utc::now();
+        let at = |seconds| start + chrono::Duration::seconds(seconds);
+
+        assert_eq!(1, alerts.evaluate(&create_stats(0.95), at(0)).len());
+
+        // a value dropping below the threshold for a while does not resolve the alert
+        assert!(alerts.evaluate(&create_stats(0.85), at(10)).is_empty());
+        assert!(alerts.evaluate(&create_stats(0.85), at(50)).is_empty());
+        assert_eq!("firing", alerts.to_json()[0]["state"]);
+
+        // being met again restarts the recovery, without a new notification
+        assert!(alerts.evaluate(&create_stats(0.95), at(60)).is_empty());
+        assert!(alerts.evaluate(&create_stats(0.85), at(70)).is_empty());
+        assert!(alerts.evaluate(&create_stats(0.85), at(120)).is_empty());
+        assert_eq!("firing", alerts.to_json()[0]["state"]);
+
+        let notifications = alerts.evaluate(&create_stats(0.85), at(130));
+
+        assert_eq!(1, notifications.len());
+        assert_eq!("resolved", notifications[0]["status"]);
+        assert_eq!(start.to_rfc3339_opts(SecondsFormat::Secs, true), notifications[0]["since"]);
+        assert_eq!("inactive", alerts.to_json()[0]["state"]);
+
+        // a pending alert is not affected by the recovery duration
+        let alerts = Alerts::new(
+            vec!["cpu>90 for 1m recover after 1m".parse().unwrap()],
+            "localhost".to_string(),
+        );
+
+        assert!(alerts.evaluate(&create_stats(0.95), at(0)).is_empty());
+        assert_eq!("pending", alerts.to_json()[0]["state"]);
+        assert!(alerts.evaluate(&create_stats(0.85), at(10)).is_empty());
+        assert_eq!("inactive", alerts.to_json()[0]["state"]);
+    }
+
+    #[test]
+    fn test_webhook() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+
+        let stub = http_stub::serve(listener, vec![(204, String::new())]);
+
+        post_webhook(&format!("http://{address}/hook"), &json!({ "status": "firing" })).unwrap();
+
+        let requests = stub.join().unwrap();
+
+        assert!(requests[0].head.starts_with("POST /hook "));
+        assert_eq!(json!({ "status": "firing" }), requests[0].json());
+    }
+}
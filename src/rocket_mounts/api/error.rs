@@ -0,0 +1,139 @@
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+use rocket::{
+    http::Status,
+    request::Request,
+    response::{self, Responder},
+};
+use rocket_json_response::{json_gettext::JSONGetTextValue, JSONResponse};
+use serde_json::{json, Value};
+
+/// A kind of stats which is read from `/proc`, `/sys` or `/dev` separately.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Subsystem {
+    Hostname,
+    Kernel,
+    Uptime,
+    RtcTime,
+    LoadAverage,
+    Cpu,
+    Memory,
+    Network,
+    Volume,
+    Process,
+}
+
+impl Subsystem {
+    #[inline]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Subsystem::Hostname => "hostname",
+            Subsystem::Kernel => "kernel",
+            Subsystem::Uptime => "uptime",
+            Subsystem::RtcTime => "rtc_time",
+            Subsystem::LoadAverage => "load_average",
+            Subsystem::Cpu => "cpu",
+            Subsystem::Memory => "memory",
+            Subsystem::Network => "network",
+            Subsystem::Volume => "volume",
+            Subsystem::Process => "process",
+        }
+    }
+}
+
+/// The stats of a subsystem cannot be read, e.g. the file of it does not exist in a container.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Unavailable {
+    pub subsystem: Subsystem,
+    pub reason:    String,
+}
+
+impl Unavailable {
+    #[inline]
+    pub fn to_json(&self) -> Value {
+        json!({
+            "subsystem": self.subsystem.as_str(),
+            "reason": self.reason,
+        })
+    }
+}
+
+impl Display for Unavailable {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "The {} stats are unavailable: {}", self.subsystem.as_str(), self.reason)
+    }
+}
+
+impl Error for Unavailable {}
+
+/// An error of the APIs. It is responded as `{"code": <HTTP status code>, "data": {"reason": ...}}` with the same HTTP status code.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ApiError {
+    Unavailable(Unavailable),
+    BadRequest(String),
+}
+
+impl ApiError {
+    #[inline]
+    pub fn status(&self) -> Status {
+        match self {
+            ApiError::Unavailable(_) => Status::ServiceUnavailable,
+            ApiError::BadRequest(_) => Status::BadRequest,
+        }
+    }
+
+    #[inline]
+    pub fn to_json(&self) -> Value {
+        match self {
+            ApiError::Unavailable(unavailable) => unavailable.to_json(),
+            ApiError::BadRequest(reason) => json!({ "reason": reason }),
+        }
+    }
+}
+
+impl From<Unavailable> for ApiError {
+    #[inline]
+    fn from(unavailable: Unavailable) -> Self {
+        ApiError::Unavailable(unavailable)
+    }
+}
+
+impl Display for ApiError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ApiError::Unavailable(unavailable) => Display::fmt(unavailable, f),
+            ApiError::BadRequest(reason) => f.write_str(reason),
+        }
+    }
+}
+
+impl Error for ApiError {}
+
+impl<'r> Responder<'r, 'static> for ApiError {
+    fn respond_to(self, request: &'r Request<'_>) -> response::Result<'static> {
+        let status = self.status();
+
+        (status, JSONResponse::err(status.code, JSONGetTextValue::from_json_value(self.to_json())))
+            .respond_to(request)
+    }
+}
+
+/// Turn an error of reading stats into `Unavailable`.
+pub trait OrUnavailable<T> {
+    fn or_unavailable(self, subsystem: Subsystem) -> Result<T, Unavailable>;
+}
+
+impl<T, E: Display> OrUnavailable<T> for Result<T, E> {
+    #[inline]
+    fn or_unavailable(self, subsystem: Subsystem) -> Result<T, Unavailable> {
+        self.map_err(|error| Unavailable {
+            subsystem,
+            reason: error.to_string(),
+        })
+    }
+}
@@ -1,243 +1,128 @@
-use std::{
-    collections::linked_list::LinkedList,
-    sync::{
-        atomic::{AtomicBool, Ordering},
-        Mutex,
-    },
-    thread,
-    time::{Duration, Instant},
-};
+mod error;
+mod v1;
+
+use std::{collections::linked_list::LinkedList, marker::PhantomData};
 
 use byte_unit::{Byte, Unit, UnitType};
-use once_cell::sync::Lazy;
-use rocket::{http::Status, request::Request, Build, Rocket, State};
+use chrono::Utc;
+pub use error::*;
+use regex::Regex;
+use rocket::{
+    http::{Method, Status},
+    request::Request,
+    response::stream::{Event, EventStream},
+    tokio::{select, task},
+    Build, Rocket, Shutdown, State,
+};
 use rocket_cache_response::CacheResponse;
 use rocket_json_response::{json_gettext::JSONGetTextValue, JSONResponse};
 use rocket_simple_authorization::{authorizer, SimpleAuthorization};
-use serde_json::json;
-
-static CPUS_STAT_DOING: AtomicBool = AtomicBool::new(false);
-static NETWORK_STAT_DOING: AtomicBool = AtomicBool::new(false);
-static VOLUMES_STAT_DOING: AtomicBool = AtomicBool::new(false);
-
-static DELAY_DURATION: Duration = Duration::from_millis(33);
-
-static CPUS_STAT_LATEST_DETECT: Lazy<Mutex<Option<Instant>>> =
-    Lazy::new(|| Mutex::new(Some(Instant::now())));
-static NETWORK_STAT_LATEST_DETECT: Lazy<Mutex<Option<Instant>>> =
-    Lazy::new(|| Mutex::new(Some(Instant::now())));
-static VOLUMES_STAT_LATEST_DETECT: Lazy<Mutex<Option<Instant>>> =
-    Lazy::new(|| Mutex::new(Some(Instant::now())));
-
-static CPUS_STAT: Lazy<Mutex<Option<Vec<f64>>>> = Lazy::new(|| Mutex::new(None));
-
-#[allow(clippy::type_complexity)]
-static NETWORK_STAT: Lazy<
-    Mutex<Option<Vec<(mprober_lib::network::Network, mprober_lib::network::NetworkSpeed)>>>,
-> = Lazy::new(|| Mutex::new(None));
-
-#[allow(clippy::type_complexity)]
-static VOLUMES_STAT: Lazy<
-    Mutex<Option<Vec<(mprober_lib::volume::Volume, mprober_lib::volume::VolumeSpeed)>>>,
-> = Lazy::new(|| Mutex::new(None));
+use serde_json::{json, Value};
+use users::UsersCache;
+pub use v1::operation_scope;
 
-pub struct Auth;
+use super::sampler::{
+    CPUsStatCollector, NetworkStatCollector, SampleResult, Sampler, VolumesStatCollector,
+};
+use crate::auth::{
+    csrf_token_matches, AuthKey, AuthKeys, Scope, Sessions, CSRF_TOKEN_HEADER_NAME,
+    SESSION_COOKIE_NAME,
+};
 
-#[async_trait]
-impl<'r> SimpleAuthorization<'r> for Auth {
-    async fn authorizing(request: &'r Request<'_>, authorization: Option<&'r str>) -> Option<Self> {
-        let auth_key = request.rocket().state::<super::AuthKey>().unwrap();
-
-        match auth_key.get_value() {
-            Some(auth_key) => match authorization {
-                Some(authorization) => {
-                    if authorization.eq(auth_key) {
-                        Some(Auth)
-                    } else {
-                        None
-                    }
-                },
-                None => None,
-            },
-            None => Some(Auth),
-        }
-    }
+/// A scope which routes can require with `Auth`.
+pub trait RequiredScope: Send + Sync + 'static {
+    const SCOPE: Scope;
 }
 
-authorizer!(Auth);
+pub struct ReadBasic;
 
-#[inline]
-fn detect_cpus_stat_sleep(detect_interval: Duration, strict: bool) {
-    let now = Instant::now();
-
-    let latest = CPUS_STAT_LATEST_DETECT.lock().unwrap().unwrap();
-
-    if now > latest {
-        let d: Duration = now - latest;
-        let detect_interval = detect_interval + DELAY_DURATION;
-
-        if d < detect_interval {
-            thread::sleep(detect_interval - d);
-        }
-    }
-
-    if strict {
-        loop {
-            let cont = CPUS_STAT.lock().unwrap().is_none();
-
-            if cont {
-                thread::sleep(DELAY_DURATION);
-            } else {
-                break;
-            }
-        }
-    }
+impl RequiredScope for ReadBasic {
+    const SCOPE: Scope = Scope::ReadBasic;
 }
 
-#[inline]
-fn detect_network_stat_sleep(detect_interval: Duration, strict: bool) {
-    let now = Instant::now();
-
-    let latest = NETWORK_STAT_LATEST_DETECT.lock().unwrap().unwrap();
-
-    if now > latest {
-        let d: Duration = now - latest;
-        let detect_interval = detect_interval + DELAY_DURATION;
-
-        if d < detect_interval {
-            thread::sleep(detect_interval - d);
-        }
-    }
+pub struct ReadProcess;
 
-    if strict {
-        loop {
-            let cont = NETWORK_STAT.lock().unwrap().is_none();
-
-            if cont {
-                thread::sleep(DELAY_DURATION);
-            } else {
-                break;
-            }
-        }
-    }
+impl RequiredScope for ReadProcess {
+    const SCOPE: Scope = Scope::ReadProcess;
 }
 
-#[inline]
-fn detect_volumes_stat_sleep(detect_interval: Duration, strict: bool) {
-    let now = Instant::now();
-
-    let latest = VOLUMES_STAT_LATEST_DETECT.lock().unwrap().unwrap();
+pub struct ReadMetrics;
 
-    if now > latest {
-        let d: Duration = now - latest;
-        let detect_interval = detect_interval + DELAY_DURATION;
-
-        if d < detect_interval {
-            thread::sleep(detect_interval - d);
-        }
-    }
-
-    if strict {
-        loop {
-            let cont = VOLUMES_STAT.lock().unwrap().is_none();
-
-            if cont {
-                thread::sleep(DELAY_DURATION);
-            } else {
-                break;
-            }
-        }
-    }
+impl RequiredScope for ReadMetrics {
+    const SCOPE: Scope = Scope::ReadMetrics;
 }
 
-#[inline]
-fn detect_all_sleep(detect_interval: Duration, strict: bool) {
-    let now = Instant::now();
+pub struct WriteIngest;
 
-    let latest = CPUS_STAT_LATEST_DETECT
-        .lock()
-        .unwrap()
-        .unwrap()
-        .max(NETWORK_STAT_LATEST_DETECT.lock().unwrap().unwrap())
-        .max(VOLUMES_STAT_LATEST_DETECT.lock().unwrap().unwrap());
-
-    if now > latest {
-        let d: Duration = now - latest;
-        let detect_interval = detect_interval + DELAY_DURATION;
-
-        if d < detect_interval {
-            thread::sleep(detect_interval - d);
-        }
-    }
-
-    if strict {
-        loop {
-            let cont = CPUS_STAT.lock().unwrap().is_none()
-                || NETWORK_STAT.lock().unwrap().is_none()
-                || VOLUMES_STAT.lock().unwrap().is_none();
-
-            if cont {
-                thread::sleep(DELAY_DURATION);
-            } else {
-                break;
-            }
-        }
-    }
+impl RequiredScope for WriteIngest {
+    const SCOPE: Scope = Scope::WriteIngest;
 }
 
-fn fetch_cpus_stat(detect_interval: Duration) {
-    if CPUS_STAT_DOING.compare_exchange(false, true, Ordering::Relaxed, Ordering::Relaxed).is_ok() {
-        CPUS_STAT_LATEST_DETECT.lock().unwrap().replace(Instant::now());
-        thread::spawn(move || {
-            let cpus_stat =
-                mprober_lib::cpu::get_all_cpu_utilization_in_percentage(true, detect_interval)
-                    .unwrap();
+/// A request guard which succeeds if the request has an auth key (in the `Authorization` header or a session cookie) allowed to access `S`, or if no auth keys are assigned.
+pub struct Auth<S: RequiredScope>(PhantomData<S>);
 
-            CPUS_STAT.lock().unwrap().replace(cpus_stat);
-
-            CPUS_STAT_DOING.swap(false, Ordering::Relaxed);
-        });
+#[async_trait]
+impl<'r, S: RequiredScope> SimpleAuthorization<'r> for Auth<S> {
+    async fn authorizing(request: &'r Request<'_>, authorization: Option<&'r str>) -> Option<Self> {
+        if authorize(request, authorization, S::SCOPE) {
+            Some(Auth(PhantomData))
+        } else {
+            None
+        }
     }
 }
 
-fn fetch_network_stat(detect_interval: Duration) {
-    if NETWORK_STAT_DOING
-        .compare_exchange(false, true, Ordering::Relaxed, Ordering::Relaxed)
-        .is_ok()
-    {
-        NETWORK_STAT_LATEST_DETECT.lock().unwrap().replace(Instant::now());
-        thread::spawn(move || {
-            let network_stat =
-                mprober_lib::network::get_networks_with_speed(detect_interval).unwrap();
-
-            NETWORK_STAT.lock().unwrap().replace(network_stat);
+/// Whether the request has an auth key (in `authorization`, the value of the `Authorization` header, or a session cookie) allowed to access `scope`, or no auth keys are assigned.
+pub fn authorize(request: &Request<'_>, authorization: Option<&str>, scope: Scope) -> bool {
+    let auth_keys = request.rocket().state::<AuthKeys>().unwrap();
+
+    auth_keys.is_empty()
+        || find_auth_key(request, auth_keys, authorization)
+            .map(|auth_key| auth_key.allows(scope))
+            .unwrap_or(false)
+}
+
+fn find_auth_key<'a>(
+    request: &Request<'_>,
+    auth_keys: &'a AuthKeys,
+    authorization: Option<&str>,
+) -> Option<&'a AuthKey> {
+    let now = Utc::now();
+
+    match authorization {
+        Some(authorization) => auth_keys.verify(authorization, now),
+        None => {
+            let sessions = request.rocket().state::<Sessions>().unwrap();
+
+            let session =
+                sessions.verify(request.cookies().get(SESSION_COOKIE_NAME)?.value(), now)?;
+
+            // cookies are sent by browsers automatically, so unsafe requests need to prove they come from the web page
+            if !matches!(request.method(), Method::Get | Method::Head)
+                && !request
+                    .headers()
+                    .get_one(CSRF_TOKEN_HEADER_NAME)
+                    .map(|token| csrf_token_matches(&session.csrf_token, token))
+                    .unwrap_or(false)
+            {
+                return None;
+            }
 
-            NETWORK_STAT_DOING.swap(false, Ordering::Relaxed);
-        });
+            auth_keys.find(&session.key_name, now)
+        },
     }
 }
 
-fn fetch_volumes_stat(detect_interval: Duration) {
-    if VOLUMES_STAT_DOING
-        .compare_exchange(false, true, Ordering::Relaxed, Ordering::Relaxed)
-        .is_ok()
-    {
-        VOLUMES_STAT_LATEST_DETECT.lock().unwrap().replace(Instant::now());
-        thread::spawn(move || {
-            let volume_stat = mprober_lib::volume::get_volumes_with_speed(detect_interval).unwrap();
-
-            VOLUMES_STAT.lock().unwrap().replace(volume_stat);
-
-            VOLUMES_STAT_DOING.swap(false, Ordering::Relaxed);
-        });
-    }
-}
+authorizer!(Auth<ReadBasic>);
+authorizer!(Auth<ReadProcess>);
+authorizer!(Auth<ReadMetrics>);
+authorizer!(Auth<WriteIngest>);
 
 #[get("/hostname")]
-fn hostname(_auth: Auth) -> CacheResponse<JSONResponse<'static>> {
-    CacheResponse::NoStore(JSONResponse::ok(JSONGetTextValue::from_string(
-        mprober_lib::hostname::get_hostname().unwrap(),
-    )))
+fn hostname(_auth: Auth<ReadBasic>) -> Result<CacheResponse<JSONResponse<'static>>, ApiError> {
+    Ok(CacheResponse::NoStore(JSONResponse::ok(JSONGetTextValue::from_string(
+        mprober_lib::hostname::get_hostname().or_unavailable(Subsystem::Hostname)?,
+    ))))
 }
 
 #[get("/hostname", rank = 2)]
@@ -246,10 +131,10 @@ fn hostname_401() -> Status {
 }
 
 #[get("/kernel")]
-fn kernel(_auth: Auth) -> CacheResponse<JSONResponse<'static>> {
-    CacheResponse::NoStore(JSONResponse::ok(JSONGetTextValue::from_string(
-        mprober_lib::kernel::get_kernel_version().unwrap(),
-    )))
+fn kernel(_auth: Auth<ReadBasic>) -> Result<CacheResponse<JSONResponse<'static>>, ApiError> {
+    Ok(CacheResponse::NoStore(JSONResponse::ok(JSONGetTextValue::from_string(
+        mprober_lib::kernel::get_kernel_version().or_unavailable(Subsystem::Kernel)?,
+    ))))
 }
 
 #[get("/kernel", rank = 2)]
@@ -258,10 +143,10 @@ fn kernel_401() -> Status {
 }
 
 #[get("/uptime")]
-fn uptime(_auth: Auth) -> CacheResponse<JSONResponse<'static>> {
-    CacheResponse::NoStore(JSONResponse::ok(JSONGetTextValue::from_u64(
-        mprober_lib::uptime::get_uptime().unwrap().total_uptime.as_secs(),
-    )))
+fn uptime(_auth: Auth<ReadBasic>) -> Result<CacheResponse<JSONResponse<'static>>, ApiError> {
+    Ok(CacheResponse::NoStore(JSONResponse::ok(JSONGetTextValue::from_u64(
+        mprober_lib::uptime::get_uptime().or_unavailable(Subsystem::Uptime)?.total_uptime.as_secs(),
+    ))))
 }
 
 #[get("/uptime", rank = 2)]
@@ -270,15 +155,13 @@ fn uptime_401() -> Status {
 }
 
 #[get("/time")]
-fn time(_auth: Auth) -> CacheResponse<JSONResponse<'static>> {
-    let rtc_date_time = mprober_lib::rtc_time::get_rtc_date_time().unwrap();
-
-    let json_rtc_date_time = json!({
-        "date": rtc_date_time.date().to_string(),
-        "time": rtc_date_time.time().to_string()
-    });
+fn time(_auth: Auth<ReadBasic>) -> Result<CacheResponse<JSONResponse<'static>>, ApiError> {
+    let rtc_date_time =
+        mprober_lib::rtc_time::get_rtc_date_time().or_unavailable(Subsystem::RtcTime)?;
 
-    CacheResponse::NoStore(JSONResponse::ok(JSONGetTextValue::from_json_value(json_rtc_date_time)))
+    Ok(CacheResponse::NoStore(JSONResponse::ok(JSONGetTextValue::from_json_value(
+        crate::json::rtc_time(&rtc_date_time),
+    ))))
 }
 
 #[get("/time", rank = 2)]
@@ -287,36 +170,20 @@ fn time_401() -> Status {
 }
 
 #[get("/cpu")]
-fn cpu(_auth: Auth) -> CacheResponse<JSONResponse<'static>> {
-    let cpus = mprober_lib::cpu::get_cpus().unwrap();
-
-    let load_average = mprober_lib::load_average::get_load_average().unwrap();
+fn cpu(_auth: Auth<ReadBasic>) -> Result<CacheResponse<JSONResponse<'static>>, ApiError> {
+    let cpus = mprober_lib::cpu::get_cpus().or_unavailable(Subsystem::Cpu)?;
 
-    let json_cpus = {
-        let mut json_cpus = Vec::with_capacity(cpus.len());
-
-        for cpu in cpus {
-            json_cpus.push(json!({
-                "model_name": cpu.model_name,
-                "cores": cpu.cpu_cores,
-                "threads": cpu.siblings,
-                "mhz": cpu.cpus_mhz
-            }));
-        }
+    let load_average =
+        mprober_lib::load_average::get_load_average().or_unavailable(Subsystem::LoadAverage)?;
 
-        json_cpus
-    };
+    let json_cpus = crate::json::cpus(&cpus);
 
-    let json_load_average = json!({
-        "one": load_average.one,
-        "five": load_average.five,
-        "fifteen": load_average.fifteen
-    });
+    let json_load_average = crate::json::load_average(&load_average);
 
-    CacheResponse::NoStore(JSONResponse::ok(JSONGetTextValue::from_json_value(json!({
+    Ok(CacheResponse::NoStore(JSONResponse::ok(JSONGetTextValue::from_json_value(json!({
         "load_average": json_load_average,
         "cpus": json_cpus
-    }))))
+    })))))
 }
 
 #[get("/cpu", rank = 2)]
@@ -326,47 +193,25 @@ fn cpu_401() -> Status {
 
 #[get("/cpu-detect")]
 fn cpu_detect(
-    _auth: Auth,
-    detect_interval: &State<super::DetectInterval>,
-) -> CacheResponse<JSONResponse<'static>> {
-    fetch_cpus_stat(detect_interval.get_value());
-
-    detect_cpus_stat_sleep(detect_interval.get_value(), true);
-
-    let cpus_stat = CPUS_STAT.lock().unwrap();
-
-    let cpus_stat: &[f64] = cpus_stat.as_ref().unwrap();
+    _auth: Auth<ReadBasic>,
+    sampler: &State<Sampler>,
+) -> Result<CacheResponse<JSONResponse<'static>>, ApiError> {
+    let cpus_stat = sampler.sample::<CPUsStatCollector>().get()?;
 
-    let load_average = mprober_lib::load_average::get_load_average().unwrap();
+    let load_average =
+        mprober_lib::load_average::get_load_average().or_unavailable(Subsystem::LoadAverage)?;
 
-    let cpus = mprober_lib::cpu::get_cpus().unwrap();
-
-    let json_cpus = {
-        let mut json_cpus = Vec::with_capacity(cpus.len());
-
-        for cpu in cpus {
-            json_cpus.push(json!({
-                "model_name": cpu.model_name,
-                "cores": cpu.cpu_cores,
-                "threads": cpu.siblings,
-                "mhz": cpu.cpus_mhz
-            }));
-        }
+    let cpus = mprober_lib::cpu::get_cpus().or_unavailable(Subsystem::Cpu)?;
 
-        json_cpus
-    };
+    let json_cpus = crate::json::cpus(&cpus);
 
-    let json_load_average = json!({
-        "one": load_average.one,
-        "five": load_average.five,
-        "fifteen": load_average.fifteen
-    });
+    let json_load_average = crate::json::load_average(&load_average);
 
-    CacheResponse::NoStore(JSONResponse::ok(JSONGetTextValue::from_json_value(json!({
+    Ok(CacheResponse::NoStore(JSONResponse::ok(JSONGetTextValue::from_json_value(json!({
         "load_average": json_load_average,
         "cpus": json_cpus,
-        "cpus_stat": cpus_stat
-    }))))
+        "cpus_stat": *cpus_stat
+    })))))
 }
 
 #[get("/cpu-detect", rank = 2)]
@@ -375,32 +220,21 @@ fn cpu_detect_401() -> Status {
 }
 
 #[get("/memory")]
-fn memory(_auth: Auth) -> CacheResponse<JSONResponse<'static>> {
-    let free = mprober_lib::memory::free().unwrap();
-
-    let json_memory = json!({
-        "total": free.mem.total,
-        "used": free.mem.used,
-        "free": free.mem.free,
-        "shared": free.mem.shared,
-        "buffers": free.mem.buffers,
-        "cache": free.mem.cache,
-        "available": free.mem.available
-    });
+fn memory(_auth: Auth<ReadBasic>) -> Result<CacheResponse<JSONResponse<'static>>, ApiError> {
+    let free = mprober_lib::memory::free().or_unavailable(Subsystem::Memory)?;
 
-    let json_swap = json!({
-        "total": free.swap.total,
-        "used": free.swap.used,
-        "free": free.swap.free,
-        "cache": free.swap.cache
-    });
+    let json_memory = crate::json::memory(&free.mem);
+
+    let json_swap = crate::json::swap(&free.swap);
 
     let json_free = json!({
         "memory": json_memory,
         "swap": json_swap
     });
 
-    CacheResponse::NoStore(JSONResponse::ok(JSONGetTextValue::from_json_value(json!(json_free))))
+    Ok(CacheResponse::NoStore(JSONResponse::ok(JSONGetTextValue::from_json_value(json!(
+        json_free
+    )))))
 }
 
 #[get("/memory", rank = 2)]
@@ -410,35 +244,15 @@ fn memory_401() -> Status {
 
 #[get("/network-detect")]
 fn network_detect(
-    _auth: Auth,
-    detect_interval: &State<super::DetectInterval>,
-) -> CacheResponse<JSONResponse<'static>> {
-    fetch_network_stat(detect_interval.get_value());
-
-    detect_network_stat_sleep(detect_interval.get_value(), true);
-
-    let json_network = {
-        let network_stat = NETWORK_STAT.lock().unwrap();
-
-        let network_stat: &[(mprober_lib::network::Network, mprober_lib::network::NetworkSpeed)] =
-            network_stat.as_ref().unwrap();
-
-        let mut json_network = Vec::with_capacity(network_stat.len());
-
-        for (network, network_speed) in network_stat {
-            json_network.push(json!({
-                "interface": network.interface,
-                "upload_total": network.stat.transmit_bytes,
-                "download_total": network.stat.receive_bytes,
-                "upload_rate": network_speed.transmit,
-                "download_rate": network_speed.receive
-            }));
-        }
+    _auth: Auth<ReadBasic>,
+    sampler: &State<Sampler>,
+) -> Result<CacheResponse<JSONResponse<'static>>, ApiError> {
+    let json_network =
+        crate::json::networks_with_speed(&sampler.sample::<NetworkStatCollector>().get()?);
 
+    Ok(CacheResponse::NoStore(JSONResponse::ok(JSONGetTextValue::from_json_value(json!(
         json_network
-    };
-
-    CacheResponse::NoStore(JSONResponse::ok(JSONGetTextValue::from_json_value(json!(json_network))))
+    )))))
 }
 
 #[get("/network-detect", rank = 2)]
@@ -447,27 +261,14 @@ fn network_detect_401() -> Status {
 }
 
 #[get("/volume")]
-fn volume(_auth: Auth) -> CacheResponse<JSONResponse<'static>> {
-    let json_volumes = {
-        let volumes = mprober_lib::volume::get_volumes().unwrap();
-
-        let mut json_volumes = Vec::with_capacity(volumes.len());
-
-        for volume in volumes {
-            json_volumes.push(json!({
-                "device": volume.device,
-                "size": volume.size,
-                "used": volume.used,
-                "read_total": volume.stat.read_bytes,
-                "write_total": volume.stat.write_bytes,
-                "mount_points": volume.points
-            }));
-        }
+fn volume(_auth: Auth<ReadBasic>) -> Result<CacheResponse<JSONResponse<'static>>, ApiError> {
+    let json_volumes = crate::json::volumes(
+        &mprober_lib::volume::get_volumes().or_unavailable(Subsystem::Volume)?,
+    );
 
+    Ok(CacheResponse::NoStore(JSONResponse::ok(JSONGetTextValue::from_json_value(json!(
         json_volumes
-    };
-
-    CacheResponse::NoStore(JSONResponse::ok(JSONGetTextValue::from_json_value(json!(json_volumes))))
+    )))))
 }
 
 #[get("/volume", rank = 2)]
@@ -477,38 +278,15 @@ fn volume_401() -> Status {
 
 #[get("/volume-detect")]
 fn volume_detect(
-    _auth: Auth,
-    detect_interval: &State<super::DetectInterval>,
-) -> CacheResponse<JSONResponse<'static>> {
-    fetch_volumes_stat(detect_interval.get_value());
-
-    detect_volumes_stat_sleep(detect_interval.get_value(), true);
-
-    let json_volumes = {
-        let volumes_stat = VOLUMES_STAT.lock().unwrap();
-
-        let volumes_stat: &[(mprober_lib::volume::Volume, mprober_lib::volume::VolumeSpeed)] =
-            volumes_stat.as_ref().unwrap();
-
-        let mut json_volumes = Vec::with_capacity(volumes_stat.len());
-
-        for (volume, volume_speed) in volumes_stat {
-            json_volumes.push(json!({
-                "device": volume.device,
-                "size": volume.size,
-                "used": volume.used,
-                "read_total": volume.stat.read_bytes,
-                "write_total": volume.stat.write_bytes,
-                "read_rate": volume_speed.read,
-                "write_rate": volume_speed.write,
-                "mount_points": volume.points
-            }));
-        }
+    _auth: Auth<ReadBasic>,
+    sampler: &State<Sampler>,
+) -> Result<CacheResponse<JSONResponse<'static>>, ApiError> {
+    let json_volumes =
+        crate::json::volumes_with_speed(&sampler.sample::<VolumesStatCollector>().get()?);
 
+    Ok(CacheResponse::NoStore(JSONResponse::ok(JSONGetTextValue::from_json_value(json!(
         json_volumes
-    };
-
-    CacheResponse::NoStore(JSONResponse::ok(JSONGetTextValue::from_json_value(json!(json_volumes))))
+    )))))
 }
 
 #[get("/volume-detect", rank = 2)]
@@ -516,136 +294,191 @@ fn volume_detect_401() -> Status {
     Status::Unauthorized
 }
 
-#[get("/all")]
-fn all(
-    _auth: Auth,
-    detect_interval: &State<super::DetectInterval>,
-) -> CacheResponse<JSONResponse<'static>> {
-    fetch_cpus_stat(detect_interval.get_value());
-    fetch_network_stat(detect_interval.get_value());
-    fetch_volumes_stat(detect_interval.get_value());
-
-    detect_all_sleep(detect_interval.get_value(), true);
-
-    let cpus_stat = CPUS_STAT.lock().unwrap();
-
-    let cpus_stat: &[f64] = cpus_stat.as_ref().unwrap();
-
-    let load_average = mprober_lib::load_average::get_load_average().unwrap();
-
-    let cpus = mprober_lib::cpu::get_cpus().unwrap();
-
-    let free = mprober_lib::memory::free().unwrap();
-
-    let hostname = mprober_lib::hostname::get_hostname().unwrap();
-
-    let kernel = mprober_lib::kernel::get_kernel_version().unwrap();
-
-    let uptime = mprober_lib::uptime::get_uptime().unwrap().total_uptime;
-
-    let rtc_date_time = mprober_lib::rtc_time::get_rtc_date_time().unwrap();
-
-    let json_cpus = {
-        let mut json_cpus = Vec::with_capacity(cpus.len());
-
-        for cpu in cpus {
-            json_cpus.push(json!({
-                "model_name": cpu.model_name,
-                "cores": cpu.cpu_cores,
-                "threads": cpu.siblings,
-                "mhz": cpu.cpus_mhz
-            }));
-        }
-
-        json_cpus
+#[derive(Debug, FromForm)]
+struct ProcessQuery {
+    top:            Option<usize>,
+    user_filter:    Option<String>,
+    group_filter:   Option<String>,
+    program_filter: Option<String>,
+    tty_filter:     Option<String>,
+    pid_filter:     Option<u32>,
+}
+
+/// Get the processes matching the query. Invalid filters are bad requests, and failures of reading `/proc` mean the processes are unavailable. Measuring the CPU utilization sleeps for `detect_interval`, so it runs on a blocking thread instead of holding an async worker.
+async fn get_processes(
+    detect_interval: std::time::Duration,
+    query: ProcessQuery,
+) -> Result<
+    (Vec<mprober_lib::process::Process>, std::collections::BTreeMap<u32, f64>, UsersCache),
+    ApiError,
+> {
+    let program_filter = match query.program_filter.as_deref() {
+        Some(program_filter) => Some(
+            Regex::new(program_filter).map_err(|error| ApiError::BadRequest(error.to_string()))?,
+        ),
+        None => None,
     };
 
-    let json_load_average = json!({
-        "one": load_average.one,
-        "five": load_average.five,
-        "fifteen": load_average.fifteen
-    });
-
-    let json_memory = json!({
-        "total": free.mem.total,
-        "used": free.mem.used,
-        "free": free.mem.free,
-        "shared": free.mem.shared,
-        "buffers": free.mem.buffers,
-        "cache": free.mem.cache,
-        "available": free.mem.available
-    });
-
-    let json_swap = json!({
-        "total": free.swap.total,
-        "used": free.swap.used,
-        "free": free.swap.free,
-        "cache": free.swap.cache
-    });
-
-    let json_network = {
-        let network_stat = NETWORK_STAT.lock().unwrap();
-
-        let network_stat: &[(mprober_lib::network::Network, mprober_lib::network::NetworkSpeed)] =
-            network_stat.as_ref().unwrap();
-
-        let mut json_network = Vec::with_capacity(network_stat.len());
-
-        for (network, network_speed) in network_stat {
-            json_network.push(json!({
-                "interface": network.interface,
-                "upload_total": network.stat.transmit_bytes,
-                "download_total": network.stat.receive_bytes,
-                "upload_rate": network_speed.transmit,
-                "download_rate": network_speed.receive
-            }));
-        }
-
-        json_network
+    let tty_filter = match query.tty_filter.as_deref() {
+        Some(tty_filter) => {
+            Some(Regex::new(tty_filter).map_err(|error| ApiError::BadRequest(error.to_string()))?)
+        },
+        None => None,
     };
 
-    let json_volumes = {
-        let volumes_stat = VOLUMES_STAT.lock().unwrap();
+    task::spawn_blocking(move || {
+        let user_cache = UsersCache::new();
+
+        let (processes, percentage) = crate::commands::get_processes(
+            Some(detect_interval),
+            query.top,
+            crate::ProcessSort::Cpu,
+            false,
+            false,
+            query.user_filter.as_deref(),
+            query.group_filter.as_deref(),
+            program_filter.as_ref(),
+            tty_filter.as_ref(),
+            query.pid_filter,
+            &user_cache,
+        )
+        .map_err(|error| {
+            if error.is::<mprober_lib::ScannerError>() {
+                ApiError::Unavailable(Unavailable {
+                    subsystem: Subsystem::Process,
+                    reason:    error.to_string(),
+                })
+            } else {
+                ApiError::BadRequest(error.to_string())
+            }
+        })?;
+
+        Ok((processes, percentage, user_cache))
+    })
+    .await
+    .map_err(|error| {
+        ApiError::Unavailable(Unavailable {
+            subsystem: Subsystem::Process,
+            reason:    error.to_string(),
+        })
+    })?
+}
+
+#[get("/process?<query..>")]
+async fn process(
+    _auth: Auth<ReadProcess>,
+    detect_interval: &State<super::DetectInterval>,
+    query: ProcessQuery,
+) -> Result<CacheResponse<JSONResponse<'static>>, ApiError> {
+    let (processes, percentage, user_cache) =
+        get_processes(detect_interval.get_value(), query).await?;
 
-        let volumes_stat: &[(mprober_lib::volume::Volume, mprober_lib::volume::VolumeSpeed)] =
-            volumes_stat.as_ref().unwrap();
+    let json_processes = crate::json::processes(&processes, &percentage, &user_cache);
 
-        let mut json_volumes = Vec::with_capacity(volumes_stat.len());
+    Ok(CacheResponse::NoStore(JSONResponse::ok(JSONGetTextValue::from_json_value(json_processes))))
+}
 
-        for (volume, volume_speed) in volumes_stat {
-            json_volumes.push(json!({
-                "device": volume.device,
-                "size": volume.size,
-                "used": volume.used,
-                "read_total": volume.stat.read_bytes,
-                "write_total": volume.stat.write_bytes,
-                "read_rate": volume_speed.read,
-                "write_rate": volume_speed.write,
-                "mount_points": volume.points
-            }));
-        }
+#[get("/process", rank = 2)]
+fn process_401() -> Status {
+    Status::Unauthorized
+}
 
-        json_volumes
+/// Build the data of `/api/all` with the given samples. The stats which cannot be read are `null`, and their errors are listed in `errors`.
+pub(super) fn all_json(
+    cpus_stat: SampleResult<Vec<f64>>,
+    network_stat: SampleResult<
+        Vec<(mprober_lib::network::Network, mprober_lib::network::NetworkSpeed)>,
+    >,
+    volumes_stat: SampleResult<
+        Vec<(mprober_lib::volume::Volume, mprober_lib::volume::VolumeSpeed)>,
+    >,
+) -> Value {
+    let mut errors = Vec::new();
+
+    let mut partial = |result: Result<Value, Unavailable>| match result {
+        Ok(json) => json,
+        Err(error) => {
+            errors.push(error.to_json());
+
+            Value::Null
+        },
     };
 
-    let json_rtc_date_time = json!({
-        "date": rtc_date_time.date().to_string(),
-        "time": rtc_date_time.time().to_string()
-    });
-
-    CacheResponse::NoStore(JSONResponse::ok(JSONGetTextValue::from_json_value(json!({
+    let hostname = partial(
+        mprober_lib::hostname::get_hostname().map(Value::from).or_unavailable(Subsystem::Hostname),
+    );
+
+    let kernel = partial(
+        mprober_lib::kernel::get_kernel_version()
+            .map(Value::from)
+            .or_unavailable(Subsystem::Kernel),
+    );
+
+    let uptime = partial(
+        mprober_lib::uptime::get_uptime()
+            .map(|uptime| json!(uptime.total_uptime.as_secs()))
+            .or_unavailable(Subsystem::Uptime),
+    );
+
+    let json_rtc_date_time = partial(
+        mprober_lib::rtc_time::get_rtc_date_time()
+            .map(|rtc_date_time| crate::json::rtc_time(&rtc_date_time))
+            .or_unavailable(Subsystem::RtcTime),
+    );
+
+    let json_load_average = partial(
+        mprober_lib::load_average::get_load_average()
+            .map(|load_average| crate::json::load_average(&load_average))
+            .or_unavailable(Subsystem::LoadAverage),
+    );
+
+    let json_cpus = partial(
+        mprober_lib::cpu::get_cpus()
+            .map(|cpus| json!(crate::json::cpus(&cpus)))
+            .or_unavailable(Subsystem::Cpu),
+    );
+
+    let json_cpus_stat = partial(cpus_stat.map(|cpus_stat| json!(*cpus_stat)));
+
+    let (json_memory, json_swap) =
+        match mprober_lib::memory::free().or_unavailable(Subsystem::Memory) {
+            Ok(free) => (crate::json::memory(&free.mem), crate::json::swap(&free.swap)),
+            Err(error) => (partial(Err(error)), Value::Null),
+        };
+
+    let json_network = partial(
+        network_stat.map(|network_stat| json!(crate::json::networks_with_speed(&network_stat))),
+    );
+
+    let json_volumes = partial(
+        volumes_stat.map(|volumes_stat| json!(crate::json::volumes_with_speed(&volumes_stat))),
+    );
+
+    json!({
         "hostname": hostname,
         "kernel": kernel,
-        "uptime": uptime.as_secs(),
+        "uptime": uptime,
         "rtc_time": json_rtc_date_time,
         "load_average": json_load_average,
         "cpus": json_cpus,
-        "cpus_stat": cpus_stat,
+        "cpus_stat": json_cpus_stat,
         "memory": json_memory,
         "swap": json_swap,
         "network": json_network,
         "volumes": json_volumes,
-    }))))
+        "errors": errors,
+    })
+}
+
+#[get("/all")]
+fn all(_auth: Auth<ReadBasic>, sampler: &State<Sampler>) -> CacheResponse<JSONResponse<'static>> {
+    let json = all_json(
+        sampler.sample::<CPUsStatCollector>().get(),
+        sampler.sample::<NetworkStatCollector>().get(),
+        sampler.sample::<VolumesStatCollector>().get(),
+    );
+
+    CacheResponse::NoStore(JSONResponse::ok(JSONGetTextValue::from_json_value(json)))
 }
 
 #[get("/all", rank = 2)]
@@ -653,34 +486,64 @@ fn all_401() -> Status {
     Status::Unauthorized
 }
 
-#[get("/monitor")]
-fn monitor(
-    _auth: Auth,
-    detect_interval: &State<super::DetectInterval>,
-) -> CacheResponse<JSONResponse<'static>> {
-    fetch_cpus_stat(detect_interval.get_value());
-    fetch_network_stat(detect_interval.get_value());
-    fetch_volumes_stat(detect_interval.get_value());
+#[get("/stream")]
+fn stream(
+    _auth: Auth<ReadBasic>,
+    sampler: &State<Sampler>,
+    mut shutdown: Shutdown,
+) -> EventStream![] {
+    let mut receiver = sampler.subscribe();
+
+    EventStream! {
+        loop {
+            let snapshot = receiver.borrow_and_update().clone();
+
+            if let Some(snapshot) = snapshot {
+                yield Event::data(snapshot.to_string());
+            }
 
-    detect_all_sleep(detect_interval.get_value(), false);
+            select! {
+                changed = receiver.changed() => {
+                    if changed.is_err() {
+                        break;
+                    }
+                },
+                _ = &mut shutdown => break,
+            }
+        }
+    }
+}
 
-    let load_average = mprober_lib::load_average::get_load_average().unwrap();
+#[get("/stream", rank = 2)]
+fn stream_401() -> Status {
+    Status::Unauthorized
+}
 
-    let cpus = mprober_lib::cpu::get_cpus().unwrap();
+#[get("/monitor")]
+fn monitor(
+    _auth: Auth<ReadBasic>,
+    sampler: &State<Sampler>,
+) -> Result<CacheResponse<JSONResponse<'static>>, ApiError> {
+    let load_average =
+        mprober_lib::load_average::get_load_average().or_unavailable(Subsystem::LoadAverage)?;
 
-    let memory = mprober_lib::memory::free().unwrap();
+    let cpus = mprober_lib::cpu::get_cpus().or_unavailable(Subsystem::Cpu)?;
 
-    let hostname = mprober_lib::hostname::get_hostname().unwrap();
+    let memory = mprober_lib::memory::free().or_unavailable(Subsystem::Memory)?;
 
-    let kernel = mprober_lib::kernel::get_kernel_version().unwrap();
+    let hostname = mprober_lib::hostname::get_hostname().or_unavailable(Subsystem::Hostname)?;
 
-    let uptime = mprober_lib::uptime::get_uptime().unwrap().total_uptime;
+    let kernel = mprober_lib::kernel::get_kernel_version().or_unavailable(Subsystem::Kernel)?;
 
-    let time = mprober_lib::rtc_time::get_rtc_date_time().unwrap();
+    let uptime = mprober_lib::uptime::get_uptime().or_unavailable(Subsystem::Uptime)?.total_uptime;
 
-    let cpus_stat = CPUS_STAT.lock().unwrap();
+    // the RTC is usually not accessible in containers, so it is left empty instead of failing the whole page
+    let rtc_time = match mprober_lib::rtc_time::get_rtc_date_time() {
+        Ok(time) => format!("{} {}", time.date(), time.time()),
+        Err(_) => String::new(),
+    };
 
-    let cpus_stat: &[f64] = cpus_stat.as_ref().unwrap();
+    let cpus_stat = sampler.sample::<CPUsStatCollector>().get()?;
 
     let uptime_string = mprober_lib::format_duration(uptime);
 
@@ -759,14 +622,11 @@ fn monitor(
         format!("{:.2}", Byte::from(memory.swap.cache).get_appropriate_unit(UnitType::Binary));
 
     let json_network = {
-        let network_stat = NETWORK_STAT.lock().unwrap();
-
-        let network_stat: &[(mprober_lib::network::Network, mprober_lib::network::NetworkSpeed)] =
-            network_stat.as_ref().unwrap();
+        let network_stat = sampler.sample::<NetworkStatCollector>().get()?;
 
         let mut json_network = Vec::with_capacity(network_stat.len());
 
-        for (network, network_speed) in network_stat {
+        for (network, network_speed) in network_stat.iter() {
             let upload_total_string = format!(
                 "{:.2}",
                 Byte::from(network.stat.transmit_bytes).get_appropriate_unit(UnitType::Decimal)
@@ -827,14 +687,11 @@ fn monitor(
     };
 
     let json_volumes = {
-        let volumes_stat = VOLUMES_STAT.lock().unwrap();
-
-        let volumes_stat: &[(mprober_lib::volume::Volume, mprober_lib::volume::VolumeSpeed)] =
-            volumes_stat.as_ref().unwrap();
+        let volumes_stat = sampler.sample::<VolumesStatCollector>().get()?;
 
         let mut json_volumes = Vec::with_capacity(volumes_stat.len());
 
-        for (volume, volume_speed) in volumes_stat {
+        for (volume, volume_speed) in volumes_stat.iter() {
             let size_string =
                 format!("{:.2}", Byte::from(volume.size).get_appropriate_unit(UnitType::Decimal));
             let used_string =
@@ -908,21 +765,21 @@ fn monitor(
         json_volumes
     };
 
-    CacheResponse::NoStore(JSONResponse::ok(JSONGetTextValue::JSONValue(json!({
+    Ok(CacheResponse::NoStore(JSONResponse::ok(JSONGetTextValue::JSONValue(json!({
         "hostname": hostname,
         "kernel": kernel,
         "uptime": {
             "value": uptime.as_secs(),
             "text": uptime_string
         },
-        "rtc_time": format!("{} {}", time.date(), time.time()),
+        "rtc_time": rtc_time,
         "load_average": {
             "one": load_average.one,
             "five": load_average.five,
             "fifteen": load_average.fifteen
         },
         "cpus": json_cpus,
-        "cpus_stat": cpus_stat,
+        "cpus_stat": *cpus_stat,
         "memory": {
             "total": {
                 "value": memory.mem.total,
@@ -953,7 +810,7 @@ fn monitor(
         },
         "network": json_network,
         "volumes": json_volumes,
-    }))))
+    })))))
 }
 
 #[get("/monitor", rank = 2)]
@@ -961,20 +818,26 @@ fn monitor_401() -> Status {
     Status::Unauthorized
 }
 
-pub fn mounts(rocket: Rocket<Build>) -> Rocket<Build> {
-    rocket
-        .mount("/api", routes![hostname, hostname_401])
-        .mount("/api", routes![kernel, kernel_401])
-        .mount("/api", routes![uptime, uptime_401])
-        .mount("/api", routes![time, time_401])
-        .mount("/api", routes![cpu, cpu_401])
-        .mount("/api", routes![cpu_detect, cpu_detect_401])
-        .mount("/api", routes![memory, memory_401])
-        .mount("/api", routes![network_detect, network_detect_401])
-        .mount("/api", routes![volume, volume_401])
-        .mount("/api", routes![volume_detect, volume_detect_401])
-        .mount("/api", routes![all, all_401])
-        .mount("/api", routes![monitor, monitor_401])
+pub fn mounts(rocket: Rocket<Build>, base_path: &super::BasePath) -> Rocket<Build> {
+    let api = base_path.join("/api");
+
+    let rocket = rocket
+        .mount(&api, routes![hostname, hostname_401])
+        .mount(&api, routes![kernel, kernel_401])
+        .mount(&api, routes![uptime, uptime_401])
+        .mount(&api, routes![time, time_401])
+        .mount(&api, routes![cpu, cpu_401])
+        .mount(&api, routes![cpu_detect, cpu_detect_401])
+        .mount(&api, routes![memory, memory_401])
+        .mount(&api, routes![network_detect, network_detect_401])
+        .mount(&api, routes![volume, volume_401])
+        .mount(&api, routes![volume_detect, volume_detect_401])
+        .mount(&api, routes![process, process_401])
+        .mount(&api, routes![all, all_401])
+        .mount(&api, routes![stream, stream_401])
+        .mount(&api, routes![monitor, monitor_401]);
+
+    v1::mounts(rocket, base_path)
 }
 
 #[cfg(test)]
@@ -984,18 +847,18 @@ mod test {
     use rocket::{http::Header, local::blocking::Client};
 
     use super::*;
+    use crate::rocket_mounts::test_auth::{manage_test_auth, TEST_AUTH_KEY};
 
     const TEST_DETECT_INTERVAL: u64 = 1000;
-    const TEST_AUTH_KEY: &str = "magic";
 
     fn create_basic_rocket(has_auth_key: bool) -> Rocket<Build> {
         let rocket = rocket::build()
             .manage(super::super::DetectInterval(Duration::from_millis(TEST_DETECT_INTERVAL)));
 
         if has_auth_key {
-            rocket.manage(super::super::AuthKey(Some(TEST_AUTH_KEY.to_string())))
+            manage_test_auth(rocket, vec![Scope::ReadBasic])
         } else {
-            rocket.manage(super::super::AuthKey(None))
+            rocket.manage(Sessions::new(Duration::from_secs(60))).manage(AuthKeys::default())
         }
     }
 
@@ -1048,4 +911,96 @@ mod test {
             assert_eq!(Status::Unauthorized, res.status());
         }
     }
+
+    #[test]
+    fn test_need_scope() {
+        let rocket = create_basic_rocket(true).mount("/api", routes![process, process_401]);
+
+        let client = Client::tracked(rocket).unwrap();
+
+        let mut req = client.get("/api/process");
+
+        req.add_header(Header::new("Authorization", TEST_AUTH_KEY));
+
+        let res = req.dispatch();
+
+        assert_eq!(Status::Unauthorized, res.status());
+    }
+
+    #[test]
+    fn test_process_bad_filter() {
+        let rocket = create_basic_rocket(false).mount("/api", routes![process, process_401]);
+
+        let client = Client::tracked(rocket).unwrap();
+
+        let res = client.get("/api/process?program_filter=(").dispatch();
+
+        assert_eq!(Status::BadRequest, res.status());
+    }
+
+    #[get("/unavailable")]
+    fn unavailable() -> Result<Status, ApiError> {
+        Err(ApiError::Unavailable(Unavailable {
+            subsystem: Subsystem::RtcTime,
+            reason:    "No such file or directory (os error 2)".to_string(),
+        }))
+    }
+
+    #[test]
+    fn test_unavailable() {
+        let client = Client::tracked(rocket::build().mount("/api", routes![unavailable])).unwrap();
+
+        let res = client.get("/api/unavailable").dispatch();
+
+        assert_eq!(Status::ServiceUnavailable, res.status());
+
+        let body: Value = serde_json::from_str(&res.into_string().unwrap()).unwrap();
+
+        assert_eq!(
+            json!({
+                "code": 503,
+                "data": {
+                    "subsystem": "rtc_time",
+                    "reason": "No such file or directory (os error 2)"
+                }
+            }),
+            body
+        );
+    }
+
+    #[test]
+    fn test_all_json_partial() {
+        let unavailable = Unavailable {
+            subsystem: Subsystem::Cpu,
+            reason:    "No such file or directory (os error 2)".to_string(),
+        };
+
+        let json = all_json(
+            Err(unavailable.clone()),
+            Ok(std::sync::Arc::new(Vec::new())),
+            Ok(std::sync::Arc::new(Vec::new())),
+        );
+
+        assert!(json["cpus_stat"].is_null());
+        assert_eq!(json!([]), json["network"]);
+        assert!(json["errors"].as_array().unwrap().contains(&unavailable.to_json()));
+    }
+
+    #[test]
+    fn test_stream() {
+        let rocket = create_basic_rocket(false)
+            .manage(Sampler::with_snapshot(json!({ "hostname": "magic" })))
+            .mount("/api", routes![stream, stream_401]);
+
+        let client = Client::tracked(rocket).unwrap();
+
+        let res = client.get("/api/stream").dispatch();
+
+        assert_eq!(Status::Ok, res.status());
+        // heartbeats (comment lines) may be sent at any time
+        let body = res.into_string().unwrap();
+        let data = body.lines().filter(|line| !line.starts_with(':')).collect::<Vec<_>>();
+
+        assert_eq!(vec!["data:{\"hostname\":\"magic\"}", ""], data);
+    }
 }
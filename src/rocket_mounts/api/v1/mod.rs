@@ -0,0 +1,667 @@
+mod models;
+mod schema;
+
+use models::*;
+use rocket::{
+    fairing::AdHoc,
+    http::{ContentType, Status},
+    Build, Rocket, State,
+};
+use rocket_cache_response::CacheResponse;
+use rocket_json_response::{json_gettext::JSONGetTextValue, JSONResponse};
+use schema::ApiSchema;
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+
+use super::{
+    ApiError, Auth, OrUnavailable, ProcessQuery, ReadBasic, ReadProcess, Subsystem, Unavailable,
+};
+use crate::{
+    auth::{Scope, SESSION_COOKIE_NAME},
+    rocket_mounts::{
+        sampler::{CPUsStatCollector, NetworkStatCollector, Sampler, VolumesStatCollector},
+        BasePath, DetectInterval,
+    },
+};
+
+/// The OpenAPI document, which is generated once the routes are mounted.
+struct OpenApi(String);
+
+/// A route of `/api/v1` described in the OpenAPI document.
+struct Operation {
+    path:       &'static str,
+    summary:    &'static str,
+    scope:      Scope,
+    /// The names, the types and the descriptions of the query parameters.
+    parameters: &'static [(&'static str, &'static str, &'static str)],
+    data:       fn(&mut Map<String, Value>) -> Value,
+}
+
+const OPERATIONS: [Operation; 10] = [
+    Operation {
+        path:       "hostname",
+        summary:    "Get the hostname",
+        scope:      Scope::ReadBasic,
+        parameters: &[],
+        data:       String::schema,
+    },
+    Operation {
+        path:       "kernel",
+        summary:    "Get the kernel version",
+        scope:      Scope::ReadBasic,
+        parameters: &[],
+        data:       String::schema,
+    },
+    Operation {
+        path:       "uptime",
+        summary:    "Get the uptime in seconds",
+        scope:      Scope::ReadBasic,
+        parameters: &[],
+        data:       u64::schema,
+    },
+    Operation {
+        path:       "time",
+        summary:    "Get the RTC time in RFC 3339",
+        scope:      Scope::ReadBasic,
+        parameters: &[],
+        data:       String::schema,
+    },
+    Operation {
+        path:       "cpu",
+        summary:    "Get the CPU stats",
+        scope:      Scope::ReadBasic,
+        parameters: &[],
+        data:       CpuStats::schema,
+    },
+    Operation {
+        path:       "memory",
+        summary:    "Get the memory stats",
+        scope:      Scope::ReadBasic,
+        parameters: &[],
+        data:       MemoryStats::schema,
+    },
+    Operation {
+        path:       "network",
+        summary:    "Get the stats of the network interfaces",
+        scope:      Scope::ReadBasic,
+        parameters: &[],
+        data:       Vec::<NetworkInterface>::schema,
+    },
+    Operation {
+        path:       "volume",
+        summary:    "Get the stats of the volumes",
+        scope:      Scope::ReadBasic,
+        parameters: &[],
+        data:       Vec::<Volume>::schema,
+    },
+    Operation {
+        path:       "process",
+        summary:    "Get the processes, ordered by CPU and memory usage",
+        scope:      Scope::ReadProcess,
+        parameters: &[
+            ("top", "integer", "The max number of processes."),
+            ("user_filter", "string", "Only the processes of the user."),
+            ("group_filter", "string", "Only the processes of the group."),
+            ("program_filter", "string", "Only the processes whose programs match the regex."),
+            ("tty_filter", "string", "Only the processes whose terminals match the regex."),
+            ("pid_filter", "integer", "Only the process and its descendants."),
+        ],
+        data:       Vec::<Process>::schema,
+    },
+    Operation {
+        path:       "all",
+        summary:    "Get all stats except for processes",
+        scope:      Scope::ReadBasic,
+        parameters: &[],
+        data:       All::schema,
+    },
+];
+
+/// The routes under `/api` which are replaced by `/api/v1`, and the paths of their successors.
+const DEPRECATED_PATHS: [(&str, &str); 12] = [
+    ("hostname", "hostname"),
+    ("kernel", "kernel"),
+    ("uptime", "uptime"),
+    ("time", "time"),
+    ("cpu", "cpu"),
+    ("cpu-detect", "cpu"),
+    ("memory", "memory"),
+    ("network-detect", "network"),
+    ("volume", "volume"),
+    ("volume-detect", "volume"),
+    ("process", "process"),
+    ("all", "all"),
+];
+
+/// The scope which a route under `/api` needs, looked up in the operations of `/api/v1` and the deprecated routes replaced by them. `path` is relative to `/api`.
+pub fn operation_scope(path: &str) -> Option<Scope> {
+    let path = match path.strip_prefix("v1/") {
+        Some(path) => path,
+        None => DEPRECATED_PATHS.iter().find(|(deprecated_path, _)| *deprecated_path == path)?.1,
+    };
+
+    OPERATIONS.iter().find(|operation| operation.path == path).map(|operation| operation.scope)
+}
+
+fn openapi_document(base_path: &BasePath) -> Value {
+    let mut components = Map::new();
+    let mut paths = Map::new();
+
+    for operation in OPERATIONS.iter() {
+        let parameters: Vec<Value> = operation
+            .parameters
+            .iter()
+            .map(|(name, parameter_type, description)| {
+                json!({
+                    "name": name,
+                    "in": "query",
+                    "required": false,
+                    "description": description,
+                    "schema": { "type": parameter_type },
+                })
+            })
+            .collect();
+
+        let data = (operation.data)(&mut components);
+
+        let mut responses = json!({
+            "200": {
+                "description": "OK",
+                "content": {
+                    "application/json": {
+                        "schema": {
+                            "type": "object",
+                            "properties": {
+                                "code": { "const": 0 },
+                                "data": data,
+                            },
+                            "required": ["code", "data"],
+                        }
+                    }
+                }
+            },
+            "401": {
+                "description": "The auth key is missing or not allowed to access the scope."
+            },
+        });
+
+        if !operation.parameters.is_empty() {
+            responses["400"] = error_response(
+                "A query parameter is invalid.",
+                400,
+                json!({
+                    "type": "object",
+                    "properties": { "reason": { "type": "string" } },
+                    "required": ["reason"],
+                }),
+            );
+        }
+
+        // `/api/v1/all` responds partial stats instead
+        if operation.path != "all" {
+            responses["503"] = error_response(
+                "The stats cannot be read, e.g. the file of them does not exist in a container.",
+                503,
+                SubsystemError::schema(&mut components),
+            );
+        }
+
+        paths.insert(
+            format!("/api/v1/{}", operation.path),
+            json!({
+                "get": {
+                    "operationId": format!("get_{}", operation.path),
+                    "summary": operation.summary,
+                    "description": format!("Needs the `{}` scope.", operation.scope.as_str()),
+                    "security": [{ "authKey": [] }, { "session": [] }],
+                    "parameters": parameters,
+                    "responses": responses,
+                }
+            }),
+        );
+    }
+
+    for (deprecated_path, path) in DEPRECATED_PATHS {
+        paths.insert(
+            format!("/api/{deprecated_path}"),
+            json!({
+                "get": {
+                    "operationId": format!("get_{}_deprecated", deprecated_path.replace('-', "_")),
+                    "summary": format!("Use `/api/v1/{path}` instead"),
+                    "deprecated": true,
+                    "security": [{ "authKey": [] }, { "session": [] }],
+                    "responses": {
+                        "200": { "description": "OK" },
+                    },
+                }
+            }),
+        );
+    }
+
+    json!({
+        "openapi": "3.1.0",
+        "info": {
+            "title": "M Prober",
+            "version": env!("CARGO_PKG_VERSION"),
+            "description": "Responses are `{\"code\": 0, \"data\": ...}` if they succeed. Errors \
+                            are `{\"code\": <HTTP status code>, \"data\": ...}`.",
+        },
+        "servers": [{ "url": base_path.mount_point() }],
+        "paths": paths,
+        "components": {
+            "schemas": components,
+            "securitySchemes": {
+                "authKey": { "type": "apiKey", "in": "header", "name": "Authorization" },
+                "session": { "type": "apiKey", "in": "cookie", "name": SESSION_COOKIE_NAME },
+            },
+        },
+    })
+}
+
+/// An error response whose `code` is the same as the HTTP status code.
+fn error_response(description: &str, code: u16, data: Value) -> Value {
+    json!({
+        "description": description,
+        "content": {
+            "application/json": {
+                "schema": {
+                    "type": "object",
+                    "properties": {
+                        "code": { "const": code },
+                        "data": data,
+                    },
+                    "required": ["code", "data"],
+                }
+            }
+        }
+    })
+}
+
+#[inline]
+fn ok<T: Serialize>(data: T) -> CacheResponse<JSONResponse<'static>> {
+    CacheResponse::NoStore(JSONResponse::ok(JSONGetTextValue::from_json_value(
+        serde_json::to_value(data).unwrap(),
+    )))
+}
+
+#[get("/openapi.json")]
+fn openapi(openapi: &State<OpenApi>) -> (ContentType, &str) {
+    (ContentType::JSON, openapi.0.as_str())
+}
+
+#[get("/hostname")]
+fn hostname(_auth: Auth<ReadBasic>) -> Result<CacheResponse<JSONResponse<'static>>, ApiError> {
+    Ok(ok(mprober_lib::hostname::get_hostname().or_unavailable(Subsystem::Hostname)?))
+}
+
+#[get("/hostname", rank = 2)]
+fn hostname_401() -> Status {
+    Status::Unauthorized
+}
+
+#[get("/kernel")]
+fn kernel(_auth: Auth<ReadBasic>) -> Result<CacheResponse<JSONResponse<'static>>, ApiError> {
+    Ok(ok(mprober_lib::kernel::get_kernel_version().or_unavailable(Subsystem::Kernel)?))
+}
+
+#[get("/kernel", rank = 2)]
+fn kernel_401() -> Status {
+    Status::Unauthorized
+}
+
+#[get("/uptime")]
+fn uptime(_auth: Auth<ReadBasic>) -> Result<CacheResponse<JSONResponse<'static>>, ApiError> {
+    Ok(ok(get_uptime()?))
+}
+
+#[get("/uptime", rank = 2)]
+fn uptime_401() -> Status {
+    Status::Unauthorized
+}
+
+#[get("/time")]
+fn time(_auth: Auth<ReadBasic>) -> Result<CacheResponse<JSONResponse<'static>>, ApiError> {
+    Ok(ok(get_rtc_time()?))
+}
+
+#[get("/time", rank = 2)]
+fn time_401() -> Status {
+    Status::Unauthorized
+}
+
+#[get("/cpu")]
+fn cpu(
+    _auth: Auth<ReadBasic>,
+    sampler: &State<Sampler>,
+) -> Result<CacheResponse<JSONResponse<'static>>, ApiError> {
+    Ok(ok(cpu_stats(sampler)?))
+}
+
+#[get("/cpu", rank = 2)]
+fn cpu_401() -> Status {
+    Status::Unauthorized
+}
+
+#[get("/memory")]
+fn memory(_auth: Auth<ReadBasic>) -> Result<CacheResponse<JSONResponse<'static>>, ApiError> {
+    Ok(ok(memory_stats()?))
+}
+
+#[get("/memory", rank = 2)]
+fn memory_401() -> Status {
+    Status::Unauthorized
+}
+
+#[get("/network")]
+fn network(
+    _auth: Auth<ReadBasic>,
+    sampler: &State<Sampler>,
+) -> Result<CacheResponse<JSONResponse<'static>>, ApiError> {
+    Ok(ok(network_interfaces(sampler)?))
+}
+
+#[get("/network", rank = 2)]
+fn network_401() -> Status {
+    Status::Unauthorized
+}
+
+#[get("/volume")]
+fn volume(
+    _auth: Auth<ReadBasic>,
+    sampler: &State<Sampler>,
+) -> Result<CacheResponse<JSONResponse<'static>>, ApiError> {
+    Ok(ok(volumes(sampler)?))
+}
+
+#[get("/volume", rank = 2)]
+fn volume_401() -> Status {
+    Status::Unauthorized
+}
+
+#[get("/process?<query..>")]
+async fn process(
+    _auth: Auth<ReadProcess>,
+    detect_interval: &State<DetectInterval>,
+    query: ProcessQuery,
+) -> Result<CacheResponse<JSONResponse<'static>>, ApiError> {
+    let (processes, percentage, user_cache) =
+        super::get_processes(detect_interval.get_value(), query).await?;
+
+    Ok(ok(processes
+        .iter()
+        .map(|process| Process::new(process, &percentage, &user_cache))
+        .collect::<Vec<_>>()))
+}
+
+#[get("/process", rank = 2)]
+fn process_401() -> Status {
+    Status::Unauthorized
+}
+
+#[get("/all")]
+fn all(_auth: Auth<ReadBasic>, sampler: &State<Sampler>) -> CacheResponse<JSONResponse<'static>> {
+    let mut errors = Vec::new();
+
+    ok(All {
+        hostname: partial(
+            mprober_lib::hostname::get_hostname().or_unavailable(Subsystem::Hostname),
+            &mut errors,
+        ),
+        kernel: partial(
+            mprober_lib::kernel::get_kernel_version().or_unavailable(Subsystem::Kernel),
+            &mut errors,
+        ),
+        uptime: partial(get_uptime(), &mut errors),
+        rtc_time: partial(get_rtc_time(), &mut errors),
+        cpu: partial(cpu_stats(sampler), &mut errors),
+        memory: partial(memory_stats(), &mut errors),
+        network: partial(network_interfaces(sampler), &mut errors),
+        volumes: partial(volumes(sampler), &mut errors),
+        errors,
+    })
+}
+
+#[get("/all", rank = 2)]
+fn all_401() -> Status {
+    Status::Unauthorized
+}
+
+/// Take the data of a part of `/api/v1/all`, or record why it is unavailable.
+#[inline]
+fn partial<T>(result: Result<T, Unavailable>, errors: &mut Vec<SubsystemError>) -> Option<T> {
+    match result {
+        Ok(data) => Some(data),
+        Err(unavailable) => {
+            errors.push(unavailable.into());
+
+            None
+        },
+    }
+}
+
+#[inline]
+fn get_uptime() -> Result<u64, Unavailable> {
+    Ok(mprober_lib::uptime::get_uptime().or_unavailable(Subsystem::Uptime)?.total_uptime.as_secs())
+}
+
+#[inline]
+fn get_rtc_time() -> Result<String, Unavailable> {
+    Ok(rtc_time(&mprober_lib::rtc_time::get_rtc_date_time().or_unavailable(Subsystem::RtcTime)?))
+}
+
+#[inline]
+fn cpu_stats(sampler: &Sampler) -> Result<CpuStats, Unavailable> {
+    Ok(CpuStats::new(
+        &mprober_lib::load_average::get_load_average().or_unavailable(Subsystem::LoadAverage)?,
+        &mprober_lib::cpu::get_cpus().or_unavailable(Subsystem::Cpu)?,
+        &sampler.sample::<CPUsStatCollector>().get()?,
+    ))
+}
+
+#[inline]
+fn memory_stats() -> Result<MemoryStats, Unavailable> {
+    Ok(MemoryStats::from(&mprober_lib::memory::free().or_unavailable(Subsystem::Memory)?))
+}
+
+#[inline]
+fn network_interfaces(sampler: &Sampler) -> Result<Vec<NetworkInterface>, Unavailable> {
+    Ok(sampler
+        .sample::<NetworkStatCollector>()
+        .get()?
+        .iter()
+        .map(|(network, network_speed)| NetworkInterface::new(network, network_speed))
+        .collect())
+}
+
+#[inline]
+fn volumes(sampler: &Sampler) -> Result<Vec<Volume>, Unavailable> {
+    Ok(sampler
+        .sample::<VolumesStatCollector>()
+        .get()?
+        .iter()
+        .map(|(volume, volume_speed)| Volume::new(volume, volume_speed))
+        .collect())
+}
+
+/// Mark the responses of the deprecated routes with the `Deprecation` header, and link to their successors.
+fn deprecation(base_path: &BasePath) -> AdHoc {
+    let api = base_path.join("/api/");
+
+    AdHoc::on_response("Deprecated APIs", move |request, response| {
+        let link = request
+            .uri()
+            .path()
+            .as_str()
+            .strip_prefix(api.as_str())
+            .and_then(|path| {
+                DEPRECATED_PATHS.iter().find(|(deprecated_path, _)| *deprecated_path == path)
+            })
+            .map(|(_, path)| format!("<{api}v1/{path}>; rel=\"successor-version\""));
+
+        Box::pin(async move {
+            if let Some(link) = link {
+                response.set_raw_header("Deprecation", "true");
+                response.adjoin_raw_header("Link", link);
+            }
+        })
+    })
+}
+
+pub fn mounts(rocket: Rocket<Build>, base_path: &BasePath) -> Rocket<Build> {
+    let api = base_path.join("/api/v1");
+
+    rocket
+        .manage(OpenApi(openapi_document(base_path).to_string()))
+        .attach(deprecation(base_path))
+        .mount(&api, routes![openapi])
+        .mount(&api, routes![hostname, hostname_401])
+        .mount(&api, routes![kernel, kernel_401])
+        .mount(&api, routes![uptime, uptime_401])
+        .mount(&api, routes![time, time_401])
+        .mount(&api, routes![cpu, cpu_401])
+        .mount(&api, routes![memory, memory_401])
+        .mount(&api, routes![network, network_401])
+        .mount(&api, routes![volume, volume_401])
+        .mount(&api, routes![process, process_401])
+        .mount(&api, routes![all, all_401])
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use rocket::{http::Header, local::blocking::Client};
+
+    use super::*;
+    use crate::rocket_mounts::test_auth::{manage_test_auth, TEST_AUTH_KEY};
+
+    fn create_client(base_path: &str) -> Client {
+        let base_path: BasePath = base_path.parse().unwrap();
+
+        let rocket = manage_test_auth(
+            rocket::build()
+                .manage(DetectInterval(Duration::from_millis(1000)))
+                .manage(Sampler::with_snapshot(Value::Null)),
+            vec![Scope::ReadBasic],
+        );
+
+        Client::tracked(super::super::mounts(rocket, &base_path)).unwrap()
+    }
+
+    #[test]
+    fn test_memory() {
+        let client = create_client("/");
+
+        let mut req = client.get("/api/v1/memory");
+
+        req.add_header(Header::new("Authorization", TEST_AUTH_KEY));
+
+        let res = req.dispatch();
+
+        assert_eq!(Status::Ok, res.status());
+        assert!(res.headers().get_one("Deprecation").is_none());
+
+        let body: Value = serde_json::from_str(&res.into_string().unwrap()).unwrap();
+
+        assert!(body["data"]["memory"]["total"].is_u64());
+        assert!(body["data"]["swap"]["cache"].is_u64());
+
+        assert_eq!(Status::Unauthorized, client.get("/api/v1/memory").dispatch().status());
+    }
+
+    #[test]
+    fn test_unavailable() {
+        let client = create_client("/");
+
+        let mut req = client.get("/api/v1/network");
+
+        req.add_header(Header::new("Authorization", TEST_AUTH_KEY));
+
+        let res = req.dispatch();
+
+        assert_eq!(Status::ServiceUnavailable, res.status());
+
+        let body: Value = serde_json::from_str(&res.into_string().unwrap()).unwrap();
+
+        assert_eq!(503, body["code"]);
+        assert_eq!("network", body["data"]["subsystem"]);
+
+        let mut req = client.get("/api/v1/all");
+
+        req.add_header(Header::new("Authorization", TEST_AUTH_KEY));
+
+        let res = req.dispatch();
+
+        assert_eq!(Status::Ok, res.status());
+
+        let body: Value = serde_json::from_str(&res.into_string().unwrap()).unwrap();
+
+        assert!(body["data"]["memory"]["memory"]["total"].is_u64());
+        assert!(body["data"]["network"].is_null());
+        assert!(body["data"]["volumes"].is_null());
+
+        let subsystems = body["data"]["errors"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|error| error["subsystem"].as_str().unwrap())
+            .collect::<Vec<_>>();
+
+        assert!(subsystems.contains(&"network"));
+        assert!(subsystems.contains(&"volume"));
+    }
+
+    #[test]
+    fn test_deprecation() {
+        let client = create_client("/hosts/db1");
+
+        let mut req = client.get("/hosts/db1/api/volume");
+
+        req.add_header(Header::new("Authorization", TEST_AUTH_KEY));
+
+        let res = req.dispatch();
+
+        assert_eq!(Status::Ok, res.status());
+        assert_eq!(Some("true"), res.headers().get_one("Deprecation"));
+        assert_eq!(
+            Some("</hosts/db1/api/v1/volume>; rel=\"successor-version\""),
+            res.headers().get_one("Link")
+        );
+    }
+
+    #[test]
+    fn test_openapi() {
+        let client = create_client("/hosts/db1");
+
+        let res = client.get("/hosts/db1/api/v1/openapi.json").dispatch();
+
+        assert_eq!(Status::Ok, res.status());
+        assert_eq!(Some(ContentType::JSON), res.content_type());
+
+        let document: Value = serde_json::from_str(&res.into_string().unwrap()).unwrap();
+
+        assert_eq!("/hosts/db1", document["servers"][0]["url"]);
+
+        // every reference can be resolved
+        let schemas = document["components"]["schemas"].as_object().unwrap();
+
+        let text = document.to_string();
+
+        for reference in text.split("\"#/components/schemas/").skip(1) {
+            let name = &reference[..reference.find('"').unwrap()];
+
+            assert!(schemas.contains_key(name), "{name}");
+        }
+
+        assert_eq!(
+            json!({ "$ref": "#/components/schemas/All" }),
+            document["paths"]["/api/v1/all"]["get"]["responses"]["200"]["content"]
+                ["application/json"]["schema"]["properties"]["data"]
+        );
+        assert_eq!(true, document["paths"]["/api/all"]["get"]["deprecated"]);
+        assert_eq!(
+            "Needs the `read:process` scope.",
+            document["paths"]["/api/v1/process"]["get"]["description"]
+        );
+    }
+}
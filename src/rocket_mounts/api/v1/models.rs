@@ -0,0 +1,353 @@
+use std::collections::BTreeMap;
+
+use chrono::{NaiveDateTime, SecondsFormat};
+use users::{Groups, Users, UsersCache};
+
+use super::schema::api_object;
+use crate::rocket_mounts::api::Unavailable;
+
+api_object! {
+    /// The load average.
+    pub struct LoadAverage {
+        /// In the last minute.
+        pub one:     f64,
+        /// In the last 5 minutes.
+        pub five:    f64,
+        /// In the last 15 minutes.
+        pub fifteen: f64,
+    }
+}
+
+impl From<&mprober_lib::load_average::LoadAverage> for LoadAverage {
+    #[inline]
+    fn from(load_average: &mprober_lib::load_average::LoadAverage) -> Self {
+        LoadAverage {
+            one:     load_average.one,
+            five:    load_average.five,
+            fifteen: load_average.fifteen,
+        }
+    }
+}
+
+api_object! {
+    /// A physical CPU.
+    pub struct Cpu {
+        /// The model name.
+        pub model_name: String,
+        /// The number of cores.
+        pub cores:      usize,
+        /// The number of threads (logical cores).
+        pub threads:    usize,
+        /// The frequency of every thread in MHz.
+        pub mhz:        Vec<f64>,
+    }
+}
+
+impl From<&mprober_lib::cpu::CPU> for Cpu {
+    #[inline]
+    fn from(cpu: &mprober_lib::cpu::CPU) -> Self {
+        Cpu {
+            model_name: cpu.model_name.clone(),
+            cores:      cpu.cpu_cores,
+            threads:    cpu.siblings,
+            mhz:        cpu.cpus_mhz.clone(),
+        }
+    }
+}
+
+api_object! {
+    /// The CPU stats.
+    pub struct CpuStats {
+        /// The load average.
+        pub load_average: LoadAverage,
+        /// The physical CPUs.
+        pub cpus:         Vec<Cpu>,
+        /// The utilization of all threads in the latest detection interval, ranged from 0 to 1.
+        pub usage:        f64,
+        /// The utilization of every thread in the latest detection interval, ranged from 0 to 1.
+        pub thread_usage: Vec<f64>,
+    }
+}
+
+impl CpuStats {
+    /// `cpus_stat` is the utilization of all threads followed by the utilization of every thread.
+    pub fn new(
+        load_average: &mprober_lib::load_average::LoadAverage,
+        cpus: &[mprober_lib::cpu::CPU],
+        cpus_stat: &[f64],
+    ) -> CpuStats {
+        CpuStats {
+            load_average: load_average.into(),
+            cpus:         cpus.iter().map(Cpu::from).collect(),
+            usage:        cpus_stat.first().copied().unwrap_or_default(),
+            thread_usage: cpus_stat.iter().skip(1).copied().collect(),
+        }
+    }
+}
+
+api_object! {
+    /// The memory usage in bytes.
+    pub struct Memory {
+        /// The total memory.
+        pub total:     usize,
+        /// The used memory, excluding buffers and cache.
+        pub used:      usize,
+        /// The unused memory.
+        pub free:      usize,
+        /// The memory shared by processes, mostly by tmpfs.
+        pub shared:    usize,
+        /// The memory used by kernel buffers.
+        pub buffers:   usize,
+        /// The memory used by the page cache and slabs.
+        pub cache:     usize,
+        /// The memory available for starting new applications without swapping.
+        pub available: usize,
+    }
+}
+
+api_object! {
+    /// The swap usage in bytes.
+    pub struct Swap {
+        /// The total swap.
+        pub total: usize,
+        /// The used swap.
+        pub used:  usize,
+        /// The unused swap.
+        pub free:  usize,
+        /// The swap cache.
+        pub cache: usize,
+    }
+}
+
+api_object! {
+    /// The memory stats.
+    pub struct MemoryStats {
+        /// The memory usage.
+        pub memory: Memory,
+        /// The swap usage.
+        pub swap:   Swap,
+    }
+}
+
+impl From<&mprober_lib::memory::Free> for MemoryStats {
+    fn from(free: &mprober_lib::memory::Free) -> Self {
+        MemoryStats {
+            memory: Memory {
+                total:     free.mem.total,
+                used:      free.mem.used,
+                free:      free.mem.free,
+                shared:    free.mem.shared,
+                buffers:   free.mem.buffers,
+                cache:     free.mem.cache,
+                available: free.mem.available,
+            },
+            swap:   Swap {
+                total: free.swap.total,
+                used:  free.swap.used,
+                free:  free.swap.free,
+                cache: free.swap.cache,
+            },
+        }
+    }
+}
+
+api_object! {
+    /// The stats of a network interface.
+    pub struct NetworkInterface {
+        /// The name of the interface.
+        pub interface:      String,
+        /// The total transmitted bytes.
+        pub upload_total:   u64,
+        /// The total received bytes.
+        pub download_total: u64,
+        /// The transmitting rate in bytes per second in the latest detection interval.
+        pub upload_rate:    f64,
+        /// The receiving rate in bytes per second in the latest detection interval.
+        pub download_rate:  f64,
+    }
+}
+
+impl NetworkInterface {
+    #[inline]
+    pub fn new(
+        network: &mprober_lib::network::Network,
+        network_speed: &mprober_lib::network::NetworkSpeed,
+    ) -> NetworkInterface {
+        NetworkInterface {
+            interface:      network.interface.trim().to_string(),
+            upload_total:   network.stat.transmit_bytes,
+            download_total: network.stat.receive_bytes,
+            upload_rate:    network_speed.transmit,
+            download_rate:  network_speed.receive,
+        }
+    }
+}
+
+api_object! {
+    /// The stats of a volume.
+    pub struct Volume {
+        /// The name of the block device.
+        pub device:       String,
+        /// The size in bytes.
+        pub size:         u64,
+        /// The used space in bytes.
+        pub used:         u64,
+        /// The total read bytes.
+        pub read_total:   u64,
+        /// The total written bytes.
+        pub write_total:  u64,
+        /// The reading rate in bytes per second in the latest detection interval.
+        pub read_rate:    f64,
+        /// The writing rate in bytes per second in the latest detection interval.
+        pub write_rate:   f64,
+        /// The mount points.
+        pub mount_points: Vec<String>,
+    }
+}
+
+impl Volume {
+    #[inline]
+    pub fn new(
+        volume: &mprober_lib::volume::Volume,
+        volume_speed: &mprober_lib::volume::VolumeSpeed,
+    ) -> Volume {
+        Volume {
+            device:       volume.device.clone(),
+            size:         volume.size,
+            used:         volume.used,
+            read_total:   volume.stat.read_bytes,
+            write_total:  volume.stat.write_bytes,
+            read_rate:    volume_speed.read,
+            write_rate:   volume_speed.write,
+            mount_points: volume.points.clone(),
+        }
+    }
+}
+
+api_object! {
+    /// A process.
+    pub struct Process {
+        /// The process ID.
+        pub pid:                u32,
+        /// The parent process ID.
+        pub ppid:               u32,
+        /// The effective user ID.
+        pub uid:                u32,
+        /// The name of the effective user.
+        pub user:               Option<String>,
+        /// The effective group ID.
+        pub gid:                u32,
+        /// The name of the effective group.
+        pub group:              Option<String>,
+        /// The program name.
+        pub program:            String,
+        /// The command line.
+        pub cmdline:            String,
+        /// The state, e.g. `Running` or `Sleeping`.
+        pub state:              String,
+        /// The controlling terminal.
+        pub tty:                Option<String>,
+        /// The scheduling priority.
+        pub priority:           i8,
+        /// The real-time scheduling priority.
+        pub real_time_priority: Option<u8>,
+        /// The nice value.
+        pub nice:               i8,
+        /// The number of threads.
+        pub threads:            usize,
+        /// The virtual memory size in bytes.
+        pub vsz:                usize,
+        /// The resident set size in bytes.
+        pub rss:                usize,
+        /// The anonymous resident memory in bytes.
+        pub anon:               usize,
+        /// The start time in RFC 3339.
+        pub start_time:         String,
+        /// The CPU utilization in the latest detection interval, ranged from 0 to the number of threads. It is null if it has not been measured.
+        pub cpu:                Option<f64>,
+    }
+}
+
+impl Process {
+    pub fn new(
+        process: &mprober_lib::process::Process,
+        percentage: &BTreeMap<u32, f64>,
+        user_cache: &UsersCache,
+    ) -> Process {
+        Process {
+            pid:                process.pid,
+            ppid:               process.ppid,
+            uid:                process.effective_uid,
+            user:               user_cache
+                .get_user_by_uid(process.effective_uid)
+                .map(|user| user.name().to_string_lossy().into_owned()),
+            gid:                process.effective_gid,
+            group:              user_cache
+                .get_group_by_gid(process.effective_gid)
+                .map(|group| group.name().to_string_lossy().into_owned()),
+            program:            process.program.clone(),
+            cmdline:            process.cmdline.clone(),
+            state:              process.state.as_str().to_string(),
+            tty:                process.tty.clone(),
+            priority:           process.priority,
+            real_time_priority: process.real_time_priority,
+            nice:               process.nice,
+            threads:            process.threads,
+            vsz:                process.vsz,
+            rss:                process.rss,
+            anon:               process.rss_anon,
+            start_time:         process.start_time.to_rfc3339_opts(SecondsFormat::Secs, true),
+            cpu:                percentage.get(&process.pid).copied(),
+        }
+    }
+}
+
+api_object! {
+    /// A subsystem whose stats cannot be read.
+    pub struct SubsystemError {
+        /// The subsystem, e.g. `memory` or `rtc_time`.
+        pub subsystem: String,
+        /// Why the stats cannot be read.
+        pub reason:    String,
+    }
+}
+
+impl From<Unavailable> for SubsystemError {
+    #[inline]
+    fn from(unavailable: Unavailable) -> Self {
+        SubsystemError {
+            subsystem: unavailable.subsystem.as_str().to_string(),
+            reason:    unavailable.reason,
+        }
+    }
+}
+
+api_object! {
+    /// All stats except for processes. The stats which cannot be read are null.
+    pub struct All {
+        /// The hostname.
+        pub hostname: Option<String>,
+        /// The kernel version.
+        pub kernel:   Option<String>,
+        /// The uptime in seconds.
+        pub uptime:   Option<u64>,
+        /// The RTC time in RFC 3339.
+        pub rtc_time: Option<String>,
+        /// The CPU stats.
+        pub cpu:      Option<CpuStats>,
+        /// The memory stats.
+        pub memory:   Option<MemoryStats>,
+        /// The network interfaces.
+        pub network:  Option<Vec<NetworkInterface>>,
+        /// The volumes.
+        pub volumes:  Option<Vec<Volume>>,
+        /// The subsystems whose stats cannot be read.
+        pub errors:   Vec<SubsystemError>,
+    }
+}
+
+/// Format the RTC time, which is in UTC, in RFC 3339.
+#[inline]
+pub fn rtc_time(rtc_date_time: &NaiveDateTime) -> String {
+    rtc_date_time.and_utc().to_rfc3339_opts(SecondsFormat::Secs, true)
+}
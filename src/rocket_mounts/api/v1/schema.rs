@@ -0,0 +1,136 @@
+use serde_json::{json, Map, Value};
+
+/// A type of the responses of `/api/v1`, which describes itself in JSON Schema for the OpenAPI document.
+pub trait ApiSchema {
+    /// The schema of the type. Objects are put into `components` and referred to by name.
+    fn schema(components: &mut Map<String, Value>) -> Value;
+}
+
+macro_rules! impl_api_schema {
+    ($schema:tt, $($ty:ty),+) => {
+        $(
+            impl ApiSchema for $ty {
+                #[inline]
+                fn schema(_components: &mut Map<String, Value>) -> Value {
+                    json!($schema)
+                }
+            }
+        )+
+    };
+}
+
+impl_api_schema!({ "type": "integer", "minimum": 0 }, u8, u32, u64, usize);
+impl_api_schema!({ "type": "integer" }, i8, i32, i64);
+impl_api_schema!({ "type": "number" }, f64);
+impl_api_schema!({ "type": "boolean" }, bool);
+impl_api_schema!({ "type": "string" }, String);
+
+impl<T: ApiSchema> ApiSchema for Option<T> {
+    #[inline]
+    fn schema(components: &mut Map<String, Value>) -> Value {
+        json!({ "anyOf": [T::schema(components), { "type": "null" }] })
+    }
+}
+
+impl<T: ApiSchema> ApiSchema for Vec<T> {
+    #[inline]
+    fn schema(components: &mut Map<String, Value>) -> Value {
+        json!({ "type": "array", "items": T::schema(components) })
+    }
+}
+
+/// Define a struct of a response which is serialized as a JSON object, and generate its schema from the fields and the doc comments.
+macro_rules! api_object {
+    (
+        $(#[doc = $doc:literal])+
+        pub struct $name:ident {
+            $(
+                $(#[doc = $field_doc:literal])+
+                pub $field:ident: $ty:ty,
+            )+
+        }
+    ) => {
+        $(#[doc = $doc])+
+        #[derive(Debug, Clone, serde::Serialize)]
+        pub struct $name {
+            $(
+                $(#[doc = $field_doc])+
+                pub $field: $ty,
+            )+
+        }
+
+        impl $crate::rocket_mounts::api::v1::schema::ApiSchema for $name {
+            fn schema(components: &mut serde_json::Map<String, serde_json::Value>) -> serde_json::Value {
+                if !components.contains_key(stringify!($name)) {
+                    let mut properties = serde_json::Map::new();
+
+                    $(
+                        let mut schema = <$ty as $crate::rocket_mounts::api::v1::schema::ApiSchema>::schema(components);
+
+                        schema["description"] = serde_json::Value::String(
+                            [$($field_doc.trim()),+].join(" ")
+                        );
+
+                        properties.insert(stringify!($field).to_string(), schema);
+                    )+
+
+                    let description = [$($doc.trim()),+].join(" ");
+
+                    components.insert(stringify!($name).to_string(), serde_json::json!({
+                        "type": "object",
+                        "description": description,
+                        "properties": properties,
+                        "required": [$(stringify!($field)),+],
+                    }));
+                }
+
+                serde_json::json!({ "$ref": concat!("#/components/schemas/", stringify!($name)) })
+            }
+        }
+    };
+}
+
+pub(super) use api_object;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    api_object! {
+        /// A test object.
+        pub struct TestObject {
+            /// A number.
+            pub number: u64,
+            /// Some texts
+            /// in two lines.
+            pub texts:  Vec<Option<String>>,
+        }
+    }
+
+    #[test]
+    fn test_schema() {
+        let mut components = Map::new();
+
+        assert_eq!(
+            json!({ "$ref": "#/components/schemas/TestObject" }),
+            TestObject::schema(&mut components)
+        );
+
+        assert_eq!(
+            json!({
+                "type": "object",
+                "description": "A test object.",
+                "properties": {
+                    "number": { "type": "integer", "minimum": 0, "description": "A number." },
+                    "texts": {
+                        "type": "array",
+                        "items": { "anyOf": [{ "type": "string" }, { "type": "null" }] },
+                        "description": "Some texts in two lines."
+                    }
+                },
+                "required": ["number", "texts"]
+            }),
+            components["TestObject"]
+        );
+    }
+}
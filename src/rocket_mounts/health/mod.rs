@@ -0,0 +1,237 @@
+use std::{
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::Instant,
+};
+
+use mprober_lib::{process::ProcessTimeStat, ScannerError};
+use rocket::{fairing::AdHoc, http::Status, Build, Rocket, State};
+use rocket_cache_response::CacheResponse;
+use rocket_json_response::{json_gettext::JSONGetTextValue, JSONResponse};
+use serde_json::{json, Value};
+
+use super::{
+    api::{ApiError, Auth, OrUnavailable, ReadMetrics, Subsystem},
+    sampler::Sampler,
+};
+
+/// The stats of this program itself.
+#[derive(Debug)]
+pub struct SelfStats {
+    started_at: Instant,
+    /// The numbers of the responses by the class of their status codes, from 1xx to 5xx.
+    responses:  [AtomicU64; 5],
+    /// The CPU time of this process and the total CPU time of all CPUs at startup. `None` if they could not be read.
+    cpu_time:   Option<(ProcessTimeStat, u64)>,
+}
+
+impl SelfStats {
+    pub fn new() -> Arc<SelfStats> {
+        Arc::new(SelfStats {
+            started_at: Instant::now(),
+            responses:  Default::default(),
+            cpu_time:   read_cpu_time().ok(),
+        })
+    }
+
+    #[inline]
+    fn count_response(&self, status: Status) {
+        let class = (status.code / 100) as usize;
+
+        if let Some(counter) = class.checked_sub(1).and_then(|i| self.responses.get(i)) {
+            counter.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// The average CPU utilization of this process since startup, ranged from 0 to 1. It does not depend on when or by whom it was read last time.
+    fn cpu_usage(&self) -> Result<f64, ScannerError> {
+        let (process_time_stat, total_cpu_time) = read_cpu_time()?;
+
+        let usage = match self.cpu_time.as_ref() {
+            Some((started_process_time_stat, started_total_cpu_time)) => started_process_time_stat
+                .compute_cpu_utilization_in_percentage(
+                    &process_time_stat,
+                    total_cpu_time.saturating_sub(*started_total_cpu_time) as f64,
+                ),
+            None => 0.0,
+        };
+
+        Ok(usage)
+    }
+}
+
+fn read_cpu_time() -> Result<(ProcessTimeStat, u64), ScannerError> {
+    let process_time_stat = mprober_lib::process::get_process_time_stat(std::process::id())?;
+
+    let total_cpu_time =
+        mprober_lib::cpu::get_average_cpu_stat()?.compute_cpu_time().get_total_time();
+
+    Ok((process_time_stat, total_cpu_time))
+}
+
+/// The states of the collectors of the sampler.
+fn samplers_json(sampler: &Sampler) -> Vec<Value> {
+    sampler
+        .states()
+        .into_iter()
+        .map(|(subsystem, state)| match state {
+            Some(state) => json!({
+                "collector": subsystem.as_str(),
+                "generation": state.generation,
+                "lag": state.lag.as_secs_f64(),
+                "error": state.error.map(|error| error.reason),
+            }),
+            None => json!({
+                "collector": subsystem.as_str(),
+                "generation": 0,
+                "lag": null,
+                "error": null,
+            }),
+        })
+        .collect()
+}
+
+#[get("/healthz")]
+fn healthz() -> CacheResponse<JSONResponse<'static>> {
+    CacheResponse::NoStore(JSONResponse::ok(JSONGetTextValue::from_str("OK")))
+}
+
+/// Ready once every collector has published a sample. Failed collections do not make it unready, because the stats of some subsystems may never be readable, e.g. in a container, but they are listed in the body.
+#[get("/readyz")]
+fn readyz(sampler: &State<Sampler>) -> (Status, CacheResponse<JSONResponse<'static>>) {
+    let collectors = samplers_json(sampler);
+
+    let ready = collectors.iter().all(|collector| collector["generation"] != 0);
+
+    let data = JSONGetTextValue::from_json_value(json!({ "collectors": collectors }));
+
+    if ready {
+        (Status::Ok, CacheResponse::NoStore(JSONResponse::ok(data)))
+    } else {
+        (Status::ServiceUnavailable, CacheResponse::NoStore(JSONResponse::err(503u16, data)))
+    }
+}
+
+#[get("/self")]
+fn self_stats(
+    _auth: Auth<ReadMetrics>,
+    self_stats: &State<Arc<SelfStats>>,
+    sampler: &State<Sampler>,
+) -> Result<CacheResponse<JSONResponse<'static>>, ApiError> {
+    let (process, _) = mprober_lib::process::get_process_with_stat(std::process::id())
+        .or_unavailable(Subsystem::Process)?;
+
+    let cpu = self_stats.cpu_usage().or_unavailable(Subsystem::Process)?;
+
+    let responses = &self_stats.responses;
+
+    let load = |i: usize| responses[i].load(Ordering::Relaxed);
+
+    Ok(CacheResponse::NoStore(JSONResponse::ok(JSONGetTextValue::from_json_value(json!({
+        "version": env!("CARGO_PKG_VERSION"),
+        "uptime": self_stats.started_at.elapsed().as_secs(),
+        "pid": process.pid,
+        "rss": process.rss,
+        "cpu": cpu,
+        "threads": process.threads,
+        "requests": {
+            "total": (0..responses.len()).map(load).sum::<u64>(),
+            "1xx": load(0),
+            "2xx": load(1),
+            "3xx": load(2),
+            "4xx": load(3),
+            "5xx": load(4),
+        },
+        "samplers": samplers_json(sampler),
+    })))))
+}
+
+#[get("/self", rank = 2)]
+fn self_stats_401() -> Status {
+    Status::Unauthorized
+}
+
+pub fn rocket_handler(rocket: Rocket<Build>, base_path: &super::BasePath) -> Rocket<Build> {
+    rocket
+        .attach(AdHoc::on_response("Request Counter", |request, response| {
+            let status = response.status();
+
+            Box::pin(async move {
+                if let Some(self_stats) = request.rocket().state::<Arc<SelfStats>>() {
+                    self_stats.count_response(status);
+                }
+            })
+        }))
+        .mount(base_path.mount_point(), routes![healthz, readyz])
+        .mount(base_path.join("/api"), routes![self_stats, self_stats_401])
+}
+
+#[cfg(test)]
+mod test {
+    use rocket::{http::Header, local::blocking::Client};
+
+    use super::*;
+    use crate::{
+        auth::Scope,
+        rocket_mounts::{
+            test_auth::{manage_test_auth, TEST_AUTH_KEY},
+            BasePath,
+        },
+    };
+
+    fn create_client() -> Client {
+        let rocket = manage_test_auth(
+            rocket::build().manage(Sampler::with_snapshot(Value::Null)).manage(SelfStats::new()),
+            vec![Scope::ReadMetrics],
+        );
+
+        Client::tracked(rocket_handler(rocket, &BasePath::default())).unwrap()
+    }
+
+    #[test]
+    fn test_probes() {
+        let client = create_client();
+
+        assert_eq!(Status::Ok, client.get("/healthz").dispatch().status());
+
+        // failed collections are reported but do not make it unready
+        let res = client.get("/readyz").dispatch();
+
+        assert_eq!(Status::Ok, res.status());
+
+        let body: Value = serde_json::from_str(&res.into_string().unwrap()).unwrap();
+
+        let collectors = body["data"]["collectors"].as_array().unwrap();
+
+        assert_eq!(3, collectors.len());
+        assert!(collectors.iter().all(|collector| collector["error"] == "Not collected."));
+    }
+
+    #[test]
+    fn test_self_stats() {
+        let client = create_client();
+
+        client.get("/healthz").dispatch();
+
+        assert_eq!(Status::Unauthorized, client.get("/api/self").dispatch().status());
+
+        let mut req = client.get("/api/self");
+
+        req.add_header(Header::new("Authorization", TEST_AUTH_KEY));
+
+        let res = req.dispatch();
+
+        assert_eq!(Status::Ok, res.status());
+
+        let body: Value = serde_json::from_str(&res.into_string().unwrap()).unwrap();
+
+        assert_eq!(std::process::id(), body["data"]["pid"]);
+        assert!(body["data"]["rss"].as_u64().unwrap() > 0);
+        // the response of this request has not been counted yet
+        assert_eq!(2, body["data"]["requests"]["total"]);
+        assert_eq!(1, body["data"]["requests"]["4xx"]);
+        assert_eq!("cpu", body["data"]["samplers"][0]["collector"]);
+    }
+}
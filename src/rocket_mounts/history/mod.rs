@@ -0,0 +1,364 @@
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex},
+    thread,
+    time::Duration,
+};
+
+use chrono::Utc;
+use mprober_lib::{
+    memory::Free,
+    network::{Network, NetworkSpeed},
+    volume::{Volume, VolumeSpeed},
+};
+use rocket::{http::Status, Build, Rocket, State};
+use rocket_cache_response::CacheResponse;
+use rocket_json_response::{json_gettext::JSONGetTextValue, JSONResponse};
+use serde_json::{json, Value};
+
+use super::{
+    api::{Auth, ReadBasic},
+    sampler::{CPUsStatCollector, NetworkStatCollector, Sampler, VolumesStatCollector},
+};
+
+/// The stats sampled at a specific time.
+#[derive(Debug)]
+struct Record {
+    time:         i64,
+    cpus_stat:    Arc<Vec<f64>>,
+    free:         Free,
+    network_stat: Arc<Vec<(Network, NetworkSpeed)>>,
+    volumes_stat: Arc<Vec<(Volume, VolumeSpeed)>>,
+}
+
+/// A ring buffer of the records within a period of time.
+#[derive(Debug)]
+pub struct History {
+    records:  Mutex<VecDeque<Record>>,
+    capacity: usize,
+    interval: Duration,
+}
+
+impl History {
+    /// Start recording the samples of the sampler. Nothing is recorded if `period` is zero.
+    pub fn start(sampler: &Sampler, interval: Duration, period: Duration) -> Arc<History> {
+        let capacity = if period.is_zero() || interval.is_zero() {
+            0
+        } else {
+            ((period.as_millis() / interval.as_millis()) as usize).max(1)
+        };
+
+        let history = Arc::new(History {
+            records: Mutex::new(VecDeque::with_capacity(capacity)),
+            capacity,
+            interval,
+        });
+
+        if history.capacity > 0 {
+            let history = history.clone();
+
+            let cpus_stat = sampler.sample::<CPUsStatCollector>();
+            let network_stat = sampler.sample::<NetworkStatCollector>();
+            let volumes_stat = sampler.sample::<VolumesStatCollector>();
+
+            thread::spawn(move || {
+                let mut generation = 0;
+
+                loop {
+                    let (next_generation, cpus_stat) = cpus_stat.get_next(generation);
+
+                    generation = next_generation;
+
+                    // nothing is recorded while any stats cannot be read, which leaves a gap in the history
+                    if let (Ok(cpus_stat), Ok(free), Ok(network_stat), Ok(volumes_stat)) = (
+                        cpus_stat,
+                        mprober_lib::memory::free(),
+                        network_stat.wait(),
+                        volumes_stat.wait(),
+                    ) {
+                        history.push(Record {
+                            time: Utc::now().timestamp(),
+                            cpus_stat,
+                            free,
+                            network_stat,
+                            volumes_stat,
+                        });
+                    }
+                }
+            });
+        }
+
+        history
+    }
+
+    fn push(&self, record: Record) {
+        let mut records = self.records.lock().unwrap();
+
+        if records.len() == self.capacity {
+            records.pop_front();
+        }
+
+        records.push_back(record);
+    }
+
+    /// Group the records since `since` into buckets of `step` seconds, and merge each bucket into one point.
+    fn query(&self, metric: Metric, since: i64, step: i64) -> Vec<Value> {
+        let records = self.records.lock().unwrap();
+
+        let mut points = Vec::new();
+
+        let mut bucket: Vec<&Record> = Vec::new();
+        let mut bucket_time = 0;
+
+        for record in records.iter().filter(|record| record.time >= since) {
+            let time = record.time - record.time.rem_euclid(step);
+
+            if time != bucket_time && !bucket.is_empty() {
+                points.push(metric.merge(bucket_time, &bucket));
+                bucket.clear();
+            }
+
+            bucket_time = time;
+            bucket.push(record);
+        }
+
+        if !bucket.is_empty() {
+            points.push(metric.merge(bucket_time, &bucket));
+        }
+
+        points
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Metric {
+    Cpu,
+    Memory,
+    Network,
+    Volume,
+}
+
+impl Metric {
+    #[inline]
+    fn from_str(metric: &str) -> Option<Metric> {
+        match metric {
+            "cpu" => Some(Metric::Cpu),
+            "memory" => Some(Metric::Memory),
+            "network" => Some(Metric::Network),
+            "volume" => Some(Metric::Volume),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn as_str(self) -> &'static str {
+        match self {
+            Metric::Cpu => "cpu",
+            Metric::Memory => "memory",
+            Metric::Network => "network",
+            Metric::Volume => "volume",
+        }
+    }
+
+    /// Rates and usages are averaged. Totals are taken from the latest record.
+    fn merge(self, time: i64, records: &[&Record]) -> Value {
+        let latest = records[records.len() - 1];
+
+        match self {
+            Metric::Cpu => {
+                let mut cpus_stat = vec![0f64; latest.cpus_stat.len()];
+
+                for record in records {
+                    for (sum, cpu_stat) in cpus_stat.iter_mut().zip(record.cpus_stat.iter()) {
+                        *sum += cpu_stat;
+                    }
+                }
+
+                for cpu_stat in cpus_stat.iter_mut() {
+                    *cpu_stat /= records.len() as f64;
+                }
+
+                json!({
+                    "time": time,
+                    "cpus_stat": cpus_stat
+                })
+            },
+            Metric::Memory => {
+                let mean = |f: fn(&Free) -> usize| {
+                    records.iter().map(|record| f(&record.free) as f64).sum::<f64>()
+                        / records.len() as f64
+                };
+
+                json!({
+                    "time": time,
+                    "memory": {
+                        "total": latest.free.mem.total,
+                        "used": mean(|free| free.mem.used),
+                        "free": mean(|free| free.mem.free),
+                        "shared": mean(|free| free.mem.shared),
+                        "buffers": mean(|free| free.mem.buffers),
+                        "cache": mean(|free| free.mem.cache),
+                        "available": mean(|free| free.mem.available)
+                    },
+                    "swap": {
+                        "total": latest.free.swap.total,
+                        "used": mean(|free| free.swap.used),
+                        "free": mean(|free| free.swap.free),
+                        "cache": mean(|free| free.swap.cache)
+                    }
+                })
+            },
+            Metric::Network => {
+                let mut json_network = Vec::with_capacity(latest.network_stat.len());
+
+                for (network, _) in latest.network_stat.iter() {
+                    let mut upload_rate = 0f64;
+                    let mut download_rate = 0f64;
+                    let mut count = 0usize;
+
+                    for record in records {
+                        if let Some((_, network_speed)) = record
+                            .network_stat
+                            .iter()
+                            .find(|(n, _)| n.interface == network.interface)
+                        {
+                            upload_rate += network_speed.transmit;
+                            download_rate += network_speed.receive;
+                            count += 1;
+                        }
+                    }
+
+                    json_network.push(json!({
+                        "interface": network.interface,
+                        "upload_total": network.stat.transmit_bytes,
+                        "download_total": network.stat.receive_bytes,
+                        "upload_rate": upload_rate / count as f64,
+                        "download_rate": download_rate / count as f64
+                    }));
+                }
+
+                json!({
+                    "time": time,
+                    "network": json_network
+                })
+            },
+            Metric::Volume => {
+                let mut json_volumes = Vec::with_capacity(latest.volumes_stat.len());
+
+                for (volume, _) in latest.volumes_stat.iter() {
+                    let mut read_rate = 0f64;
+                    let mut write_rate = 0f64;
+                    let mut count = 0usize;
+
+                    for record in records {
+                        if let Some((_, volume_speed)) =
+                            record.volumes_stat.iter().find(|(v, _)| v.device == volume.device)
+                        {
+                            read_rate += volume_speed.read;
+                            write_rate += volume_speed.write;
+                            count += 1;
+                        }
+                    }
+
+                    json_volumes.push(json!({
+                        "device": volume.device,
+                        "size": volume.size,
+                        "used": volume.used,
+                        "read_total": volume.stat.read_bytes,
+                        "write_total": volume.stat.write_bytes,
+                        "read_rate": read_rate / count as f64,
+                        "write_rate": write_rate / count as f64
+                    }));
+                }
+
+                json!({
+                    "time": time,
+                    "volumes": json_volumes
+                })
+            },
+        }
+    }
+}
+
+#[get("/history?<metric>&<since>&<step>")]
+fn history(
+    _auth: Auth<ReadBasic>,
+    history: &State<Arc<History>>,
+    metric: Option<&str>,
+    since: Option<i64>,
+    step: Option<u64>,
+) -> Result<CacheResponse<JSONResponse<'static>>, Status> {
+    let metric = metric.and_then(Metric::from_str).ok_or(Status::BadRequest)?;
+
+    let step = match step {
+        Some(step) => step,
+        None => history.interval.as_secs(),
+    }
+    .max(1);
+
+    let step = i64::try_from(step).map_err(|_| Status::BadRequest)?;
+
+    let points = history.query(metric, since.unwrap_or(i64::MIN), step);
+
+    Ok(CacheResponse::NoStore(JSONResponse::ok(JSONGetTextValue::from_json_value(json!({
+        "metric": metric.as_str(),
+        "step": step,
+        "points": points
+    })))))
+}
+
+#[get("/history", rank = 2)]
+fn history_401() -> Status {
+    Status::Unauthorized
+}
+
+pub fn rocket_handler(rocket: Rocket<Build>, base_path: &super::BasePath) -> Rocket<Build> {
+    rocket.mount(base_path.join("/api"), routes![history, history_401])
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn create_history(cpus_stats: &[(i64, f64)]) -> History {
+        let history = History {
+            records:  Mutex::new(VecDeque::new()),
+            capacity: 4,
+            interval: Duration::from_secs(1),
+        };
+
+        for (time, cpu_stat) in cpus_stats.iter().copied() {
+            history.push(Record {
+                time,
+                cpus_stat: Arc::new(vec![cpu_stat]),
+                free: Free::default(),
+                network_stat: Arc::new(Vec::new()),
+                volumes_stat: Arc::new(Vec::new()),
+            });
+        }
+
+        history
+    }
+
+    #[test]
+    fn test_query() {
+        let history = create_history(&[(99, 0.9), (100, 0.1), (101, 0.3), (102, 0.5), (110, 0.7)]);
+
+        // the first record has been dropped
+        assert_eq!(
+            vec![
+                json!({ "time": 100, "cpus_stat": [0.3] }),
+                json!({ "time": 110, "cpus_stat": [0.7] })
+            ],
+            history.query(Metric::Cpu, i64::MIN, 10)
+        );
+
+        assert_eq!(
+            vec![
+                json!({ "time": 102, "cpus_stat": [0.5] }),
+                json!({ "time": 110, "cpus_stat": [0.7] })
+            ],
+            history.query(Metric::Cpu, 102, 1)
+        );
+    }
+}
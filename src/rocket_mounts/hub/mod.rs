@@ -0,0 +1,766 @@
+use std::{
+    fs,
+    io::Read,
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::{Arc, Mutex},
+    thread,
+    time::Duration,
+};
+
+use anyhow::{anyhow, Context};
+use chrono::{DateTime, SecondsFormat, Utc};
+use rocket::{
+    data::{Data, ToByteUnit},
+    http::{uri::Origin, ContentType, Status},
+    request::Request,
+    tokio::task,
+    Build, Rocket, Route, State,
+};
+use rocket_cache_response::CacheResponse;
+use rocket_json_response::{json_gettext::JSONGetTextValue, JSONResponse};
+use rocket_simple_authorization::{authorizer, SimpleAuthorization};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use super::api::{authorize, operation_scope, Auth, ReadBasic, WriteIngest};
+use crate::auth::Scope;
+
+/// The maximum size of a response proxied from an agent.
+const PROXY_BODY_LIMIT: u64 = 16 * 1024 * 1024;
+/// The maximum size of a pushed snapshot.
+const INGEST_BODY_LIMIT: usize = 4 * 1024 * 1024;
+/// A pushed host is regarded as unreachable if it does not push in this number of its intervals.
+const PUSH_INTERVALS_BEFORE_UNREACHABLE: u32 = 3;
+
+/// Whether `name` can be the name of a host, which is used in the URLs of the hub.
+#[inline]
+fn is_valid_name(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || "-._~".contains(c))
+}
+
+/// An mprober web service which the hub polls.
+#[derive(Debug, Clone)]
+pub struct Agent {
+    pub name:     String,
+    /// The URL without the trailing slash.
+    pub url:      String,
+    pub auth_key: Option<String>,
+}
+
+impl Agent {
+    pub fn new<S: Into<String>>(
+        name: S,
+        url: &str,
+        auth_key: Option<String>,
+    ) -> anyhow::Result<Agent> {
+        let name = name.into();
+
+        if !is_valid_name(&name) {
+            return Err(anyhow!(
+                "The name of the agent `{name}` needs to be made of letters, digits, `-`, `.`, \
+                 `_` and `~`."
+            ));
+        }
+
+        if !url.starts_with("http://") && !url.starts_with("https://") {
+            return Err(anyhow!("The URL of the agent `{name}` needs to be a HTTP(S) URL."));
+        }
+
+        Ok(Agent {
+            name,
+            url: url.trim_end_matches('/').to_string(),
+            auth_key,
+        })
+    }
+}
+
+impl FromStr for Agent {
+    type Err = anyhow::Error;
+
+    /// Parse `[NAME=]URL`. The name defaults to the host (and the port) of the URL.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once('=') {
+            Some((name, url)) if !name.contains('/') => Agent::new(name, url, None),
+            _ => {
+                let host = s.split_once("://").map(|(_, rest)| rest).unwrap_or(s);
+                let host = host.split('/').next().unwrap_or_default();
+
+                Agent::new(host.replace(':', "_"), s, None)
+            },
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct AgentFile {
+    #[serde(default)]
+    agents: Vec<AgentEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct AgentEntry {
+    name:     String,
+    url:      String,
+    auth_key: Option<String>,
+}
+
+/// The agents polled by the hub.
+#[derive(Debug, Default)]
+pub struct Agents(Vec<Agent>);
+
+impl Agents {
+    /// Load a TOML file made of `[[agents]]` tables which have `name`, `url` and an optional `auth_key`.
+    pub fn load(path: &Path) -> anyhow::Result<Agents> {
+        let content = fs::read_to_string(path).with_context(|| format!("Cannot read {path:?}."))?;
+
+        let agent_file: AgentFile = toml::from_str(&content)
+            .with_context(|| format!("{path:?} is not a valid agent file."))?;
+
+        let mut agents = Agents::default();
+
+        for entry in agent_file.agents {
+            Agent::new(entry.name, &entry.url, entry.auth_key)
+                .and_then(|agent| agents.push(agent))
+                .with_context(|| format!("{path:?} is not a valid agent file."))?;
+        }
+
+        Ok(agents)
+    }
+
+    /// Add an agent. The names of agents need to be unique because they are used in the URLs of the hub.
+    pub fn push(&mut self, agent: Agent) -> anyhow::Result<()> {
+        if self.0.iter().any(|a| a.name == agent.name) {
+            return Err(anyhow!("The name of the agent `{}` is duplicated.", agent.name));
+        }
+
+        self.0.push(agent);
+
+        Ok(())
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// The agent file and the `[NAME=]URL` arguments of the hub command.
+pub fn load_agents(agent_file: Option<&PathBuf>, agents: Vec<Agent>) -> anyhow::Result<Agents> {
+    let mut all_agents = match agent_file {
+        Some(agent_file) => Agents::load(agent_file)?,
+        None => Agents::default(),
+    };
+
+    for agent in agents {
+        all_agents.push(agent)?;
+    }
+
+    if all_agents.is_empty() {
+        return Err(anyhow!("Assign at least one agent with `--agent` or `--agent-file`."));
+    }
+
+    Ok(all_agents)
+}
+
+#[derive(Debug, Default)]
+struct HostStatus {
+    /// Whether the latest poll succeeded. It is `None` before the first poll finishes.
+    reachable: Option<bool>,
+    last_seen: Option<DateTime<Utc>>,
+    error:     Option<String>,
+    /// The summary of the latest `/api/all` data, which is kept while the agent is unreachable.
+    stats:     Option<Value>,
+}
+
+#[derive(Debug)]
+struct Host {
+    agent:  Agent,
+    status: Mutex<HostStatus>,
+}
+
+/// A snapshot pushed to `/api/ingest` by the push command.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Push {
+    pub host:     String,
+    /// When the snapshot was taken, in RFC 3339.
+    pub time:     String,
+    /// How often the host pushes, in seconds.
+    pub interval: u64,
+    /// The same data as `/api/all`.
+    pub data:     Value,
+}
+
+#[derive(Debug)]
+struct PushedHost {
+    name:      String,
+    interval:  Duration,
+    time:      DateTime<Utc>,
+    last_seen: DateTime<Utc>,
+    data:      Arc<Value>,
+}
+
+impl PushedHost {
+    /// Whether the host has pushed in the last few intervals.
+    #[inline]
+    fn is_reachable(&self, now: DateTime<Utc>) -> bool {
+        now.signed_duration_since(self.last_seen).to_std().unwrap_or_default()
+            <= self.interval * PUSH_INTERVALS_BEFORE_UNREACHABLE
+    }
+}
+
+/// The hosts of the agents, the hosts which push their snapshots, and their latest status.
+#[derive(Debug)]
+pub struct Hub {
+    hosts:   Vec<Host>,
+    pushed:  Mutex<Vec<PushedHost>>,
+    timeout: Duration,
+}
+
+impl Hub {
+    fn new(agents: Agents, timeout: Duration) -> Hub {
+        let hosts = agents
+            .0
+            .into_iter()
+            .map(|agent| Host {
+                agent,
+                status: Mutex::new(HostStatus::default()),
+            })
+            .collect();
+
+        Hub {
+            hosts,
+            pushed: Mutex::new(Vec::new()),
+            timeout,
+        }
+    }
+
+    /// A hub which polls nothing and only stores the pushed snapshots, for the web command.
+    #[inline]
+    pub fn collector() -> Arc<Hub> {
+        Arc::new(Hub::new(Agents::default(), Duration::ZERO))
+    }
+
+    /// Start polling `/api/all` of every agent every `interval`. Each agent is polled by its own thread so that a slow agent does not delay the others.
+    pub fn start(agents: Agents, interval: Duration, timeout: Duration) -> Arc<Hub> {
+        let hub = Arc::new(Hub::new(agents, timeout));
+
+        for index in 0..hub.hosts.len() {
+            let hub = hub.clone();
+
+            thread::spawn(move || loop {
+                hub.poll(index, Utc::now());
+
+                thread::sleep(interval);
+            });
+        }
+
+        hub
+    }
+
+    fn poll(&self, index: usize, now: DateTime<Utc>) {
+        let host = &self.hosts[index];
+
+        let result = fetch_all(&host.agent, self.timeout);
+
+        let mut status = host.status.lock().unwrap();
+
+        match result {
+            Ok(all) => {
+                status.reachable = Some(true);
+                status.last_seen = Some(now);
+                status.error = None;
+                status.stats = Some(summarize(&all));
+            },
+            Err(error) => {
+                status.reachable = Some(false);
+                status.error = Some(error.to_string());
+            },
+        }
+    }
+
+    #[inline]
+    fn find(&self, name: &str) -> Option<&Agent> {
+        self.hosts.iter().map(|host| &host.agent).find(|agent| agent.name == name)
+    }
+
+    /// Store a pushed snapshot. Snapshots older than the stored one, which are pushed late from the buffer of the host, only refresh when the host was last seen.
+    fn ingest(&self, push: Push, now: DateTime<Utc>) -> Result<(), Status> {
+        if !is_valid_name(&push.host) || push.interval == 0 || !push.data.is_object() {
+            return Err(Status::BadRequest);
+        }
+
+        // the names of hosts are unique in the URLs
+        if self.find(&push.host).is_some() {
+            return Err(Status::Conflict);
+        }
+
+        let time = DateTime::parse_from_rfc3339(&push.time)
+            .map_err(|_| Status::BadRequest)?
+            .with_timezone(&Utc);
+
+        let interval = Duration::from_secs(push.interval);
+
+        let mut pushed = self.pushed.lock().unwrap();
+
+        match pushed.iter_mut().find(|host| host.name == push.host) {
+            Some(host) => {
+                host.last_seen = now;
+                host.interval = interval;
+
+                if time >= host.time {
+                    host.time = time;
+                    host.data = Arc::new(push.data);
+                }
+            },
+            None => pushed.push(PushedHost {
+                name: push.host,
+                interval,
+                time,
+                last_seen: now,
+                data: Arc::new(push.data),
+            }),
+        }
+
+        Ok(())
+    }
+
+    /// The latest snapshot pushed by the host named `name`.
+    #[inline]
+    fn find_pushed(&self, name: &str) -> Option<Arc<Value>> {
+        self.pushed
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|host| host.name == name)
+            .map(|host| host.data.clone())
+    }
+
+    pub fn to_json(&self, now: DateTime<Utc>) -> Value {
+        let pushed = self.pushed.lock().unwrap();
+
+        let mut json_hosts = Vec::with_capacity(self.hosts.len() + pushed.len());
+
+        for host in self.hosts.iter() {
+            let status = host.status.lock().unwrap();
+
+            json_hosts.push(json!({
+                "name": host.agent.name,
+                "url": host.agent.url,
+                "pushed": false,
+                "reachable": status.reachable,
+                "last_seen": status.last_seen.map(|t| t.to_rfc3339_opts(SecondsFormat::Secs, true)),
+                "error": status.error,
+                "stats": status.stats,
+            }));
+        }
+
+        for host in pushed.iter() {
+            let reachable = host.is_reachable(now);
+
+            json_hosts.push(json!({
+                "name": host.name,
+                "url": null,
+                "pushed": true,
+                "reachable": reachable,
+                "last_seen": host.last_seen.to_rfc3339_opts(SecondsFormat::Secs, true),
+                "error": if reachable { None } else { Some("The host has stopped pushing.") },
+                "stats": summarize(&host.data),
+            }));
+        }
+
+        Value::Array(json_hosts)
+    }
+}
+
+fn get(
+    agent: &Agent,
+    path_and_query: &str,
+    timeout: Duration,
+) -> Result<ureq::Response, Box<ureq::Error>> {
+    let mut request = ureq::get(&format!("{}{path_and_query}", agent.url)).timeout(timeout);
+
+    if let Some(auth_key) = agent.auth_key.as_deref() {
+        request = request.set("Authorization", auth_key);
+    }
+
+    Ok(request.call()?)
+}
+
+/// Fetch the data of `/api/all` of an agent.
+fn fetch_all(agent: &Agent, timeout: Duration) -> anyhow::Result<Value> {
+    let body = get(agent, "/api/all", timeout)?.into_string()?;
+
+    let mut response: Value = serde_json::from_str(&body)?;
+
+    if response["code"] != 0 {
+        return Err(anyhow!("The agent responds with an error: {}", response["message"]));
+    }
+
+    Ok(response["data"].take())
+}
+
+/// Pick what the dashboard needs from the data of `/api/all`.
+fn summarize(all: &Value) -> Value {
+    let volumes = all["volumes"]
+        .as_array()
+        .map(|volumes| {
+            volumes
+                .iter()
+                .map(|volume| {
+                    json!({
+                        "device": volume["device"],
+                        "size": volume["size"],
+                        "used": volume["used"],
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    json!({
+        "hostname": all["hostname"],
+        "kernel": all["kernel"],
+        "uptime": all["uptime"],
+        "load_average": all["load_average"],
+        "cpu": all["cpus_stat"][0],
+        "memory": {
+            "total": all["memory"]["total"],
+            "used": all["memory"]["used"],
+        },
+        "swap": {
+            "total": all["swap"]["total"],
+            "used": all["swap"]["used"],
+        },
+        "volumes": Value::Array(volumes),
+    })
+}
+
+/// Forward a GET request to `/api/<path>` of an agent.
+async fn proxy(
+    hub: &Arc<Hub>,
+    name: &str,
+    route: &Route,
+    uri: &Origin<'_>,
+) -> (Status, (ContentType, Vec<u8>)) {
+    // the path after `/api/hosts/<name>/` is the same as the path after `/api/` of the agent
+    let prefix = format!("{}/hosts/{name}/", route.uri.base().trim_end_matches('/'));
+
+    let path = match uri.path().as_str().strip_prefix(&prefix) {
+        Some(path) => path,
+        None => return (Status::NotFound, (ContentType::Plain, Vec::new())),
+    };
+
+    let agent = match hub.find(name) {
+        Some(agent) => agent.clone(),
+        None => {
+            // only the latest snapshot of a pushed host is available
+            return match hub.find_pushed(name) {
+                Some(data) if path == "all" => {
+                    let body = json!({ "code": 0, "data": *data }).to_string();
+
+                    (Status::Ok, (ContentType::JSON, body.into_bytes()))
+                },
+                _ => (Status::NotFound, (ContentType::Plain, Vec::new())),
+            };
+        },
+    };
+
+    let path_and_query = match uri.query() {
+        Some(query) => format!("/api/{path}?{query}"),
+        None => format!("/api/{path}"),
+    };
+
+    let timeout = hub.timeout;
+
+    let result = task::spawn_blocking(move || {
+        let response = match get(&agent, &path_and_query, timeout).map_err(|error| *error) {
+            Ok(response) | Err(ureq::Error::Status(_, response)) => response,
+            Err(ureq::Error::Transport(_)) => return None,
+        };
+
+        let status = Status::new(response.status());
+
+        let content_type =
+            ContentType::parse_flexible(response.content_type()).unwrap_or(ContentType::Binary);
+
+        let mut body = Vec::new();
+
+        response.into_reader().take(PROXY_BODY_LIMIT).read_to_end(&mut body).ok()?;
+
+        Some((status, (content_type, body)))
+    })
+    .await;
+
+    match result {
+        Ok(Some(response)) => response,
+        _ => (Status::BadGateway, (ContentType::Plain, Vec::new())),
+    }
+}
+
+#[get("/hosts")]
+fn hosts(_auth: Auth<ReadBasic>, hub: &State<Arc<Hub>>) -> CacheResponse<JSONResponse<'static>> {
+    CacheResponse::NoStore(JSONResponse::ok(JSONGetTextValue::from_json_value(
+        hub.to_json(Utc::now()),
+    )))
+}
+
+#[get("/hosts", rank = 2)]
+fn hosts_401() -> Status {
+    Status::Unauthorized
+}
+
+#[post("/ingest", data = "<data>")]
+async fn ingest(_auth: Auth<WriteIngest>, hub: &State<Arc<Hub>>, data: Data<'_>) -> Status {
+    let body = match data.open(INGEST_BODY_LIMIT.bytes()).into_string().await {
+        Ok(body) if body.is_complete() => body.into_inner(),
+        Ok(_) => return Status::PayloadTooLarge,
+        Err(_) => return Status::BadRequest,
+    };
+
+    let push = match serde_json::from_str(&body) {
+        Ok(push) => push,
+        Err(_) => return Status::BadRequest,
+    };
+
+    match hub.ingest(push, Utc::now()) {
+        Ok(()) => Status::NoContent,
+        Err(status) => status,
+    }
+}
+
+#[post("/ingest", rank = 2)]
+fn ingest_401() -> Status {
+    Status::Unauthorized
+}
+
+/// The scope which an agent needs for `/api/<path>`, so that forwarding a request does not allow more than the agent does. Unknown paths need the `admin` scope.
+fn required_scope(path: &Path) -> Scope {
+    let path = match path.to_str() {
+        Some(path) => path,
+        None => return Scope::Admin,
+    };
+
+    if let Some(scope) = operation_scope(path) {
+        return scope;
+    }
+
+    match path {
+        "v1/openapi.json" | "stream" | "monitor" | "history" | "alerts" => Scope::ReadBasic,
+        "self" => Scope::ReadMetrics,
+        _ => Scope::Admin,
+    }
+}
+
+/// A request guard of `/api/hosts/<name>/<path..>` which succeeds if the request is allowed to access the scope required for the path.
+pub struct ProxyAuth;
+
+#[async_trait]
+impl<'r> SimpleAuthorization<'r> for ProxyAuth {
+    async fn authorizing(request: &'r Request<'_>, authorization: Option<&'r str>) -> Option<Self> {
+        // the segments after `/hosts/<name>/`
+        let path: PathBuf = request.segments(2..).ok()?;
+
+        if authorize(request, authorization, required_scope(&path)) {
+            Some(ProxyAuth)
+        } else {
+            None
+        }
+    }
+}
+
+authorizer!(ProxyAuth);
+
+#[get("/hosts/<name>/<path..>")]
+async fn host(
+    _auth: ProxyAuth,
+    hub: &State<Arc<Hub>>,
+    name: &str,
+    path: PathBuf,
+    route: &Route,
+    uri: &Origin<'_>,
+) -> CacheResponse<(Status, (ContentType, Vec<u8>))> {
+    // the SSE stream never ends, so it cannot be forwarded as a whole response
+    if path == Path::new("stream") {
+        return CacheResponse::NoStore((Status::NotFound, (ContentType::Plain, Vec::new())));
+    }
+
+    CacheResponse::NoStore(proxy(hub, name, route, uri).await)
+}
+
+#[get("/hosts/<_name>/<_path..>", rank = 2)]
+fn host_401(_name: &str, _path: PathBuf) -> Status {
+    Status::Unauthorized
+}
+
+pub fn rocket_handler(rocket: Rocket<Build>, base_path: &super::BasePath) -> Rocket<Build> {
+    rocket.mount(base_path.join("/api"), routes![
+        hosts, hosts_401, ingest, ingest_401, host, host_401
+    ])
+}
+
+#[cfg(test)]
+mod test {
+    use std::net::TcpListener;
+
+    use rocket::{http::Header, local::blocking::Client};
+
+    use super::*;
+    use crate::{
+        http_stub,
+        rocket_mounts::{
+            test_auth::{manage_test_auth, TEST_AUTH_KEY},
+            BasePath,
+        },
+    };
+
+    #[test]
+    fn test_agent() {
+        let agent: Agent = "db1=http://10.0.0.1:8000/".parse().unwrap();
+
+        assert_eq!("db1", agent.name);
+        assert_eq!("http://10.0.0.1:8000", agent.url);
+
+        let agent: Agent = "http://10.0.0.1:8000".parse().unwrap();
+
+        assert_eq!("10.0.0.1_8000", agent.name);
+
+        assert!("db1=10.0.0.1".parse::<Agent>().is_err());
+        assert!("db/1=http://10.0.0.1".parse::<Agent>().is_err());
+    }
+
+    #[test]
+    fn test_poll() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+
+        let body = json!({
+            "code": 0,
+            "data": {
+                "hostname": "db1",
+                "cpus_stat": [0.5, 0.4, 0.6],
+                "memory": { "total": 100, "used": 40 },
+                "volumes": [{ "device": "sda1", "size": 100, "used": 90, "read_rate": 0.0 }],
+            }
+        });
+
+        let stub = http_stub::serve(listener, vec![(200, body.to_string())]);
+
+        let mut agents = Agents::default();
+
+        agents
+            .push(Agent::new("db1", &format!("http://{address}"), Some("magic".into())).unwrap())
+            .unwrap();
+        agents.push("down=http://127.0.0.1:1".parse().unwrap()).unwrap();
+
+        let hub = Hub::new(agents, Duration::from_secs(5));
+
+        hub.poll(0, Utc::now());
+        hub.poll(1, Utc::now());
+
+        let request = &stub.join().unwrap()[0].head;
+
+        assert!(request.starts_with("GET /api/all "));
+        assert!(request.to_ascii_lowercase().contains("authorization: magic\r\n"));
+
+        let json = hub.to_json(Utc::now());
+
+        assert_eq!(true, json[0]["reachable"]);
+        assert_eq!("db1", json[0]["stats"]["hostname"]);
+        assert_eq!(0.5, json[0]["stats"]["cpu"]);
+        assert_eq!(
+            json!([{ "device": "sda1", "size": 100, "used": 90 }]),
+            json[0]["stats"]["volumes"]
+        );
+
+        assert_eq!(false, json[1]["reachable"]);
+        assert!(json[1]["error"].is_string());
+        assert!(json[1]["stats"].is_null());
+    }
+
+    #[test]
+    fn test_ingest() {
+        let hub = Hub::new(
+            Agents(vec!["db1=http://127.0.0.1:1".parse().unwrap()]),
+            Duration::from_secs(1),
+        );
+
+        let push = |host: &str, time: &str, uptime: u64| Push {
+            host:     host.to_string(),
+            time:     time.to_string(),
+            interval: 10,
+            data:     json!({ "hostname": host, "uptime": uptime }),
+        };
+
+        let now = Utc::now();
+
+        assert_eq!(Err(Status::Conflict), hub.ingest(push("db1", "2024-01-01T00:00:00Z", 1), now));
+        assert_eq!(
+            Err(Status::BadRequest),
+            hub.ingest(push("web/1", "2024-01-01T00:00:00Z", 1), now)
+        );
+        assert_eq!(Err(Status::BadRequest), hub.ingest(push("web1", "yesterday", 1), now));
+
+        hub.ingest(push("web1", "2024-01-01T00:00:10Z", 2), now).unwrap();
+
+        // a late snapshot from the buffer of the host does not replace the newer one
+        hub.ingest(push("web1", "2024-01-01T00:00:00Z", 1), now).unwrap();
+
+        assert_eq!(2, hub.find_pushed("web1").unwrap()["uptime"]);
+
+        let json = hub.to_json(now);
+
+        assert_eq!("web1", json[1]["name"]);
+        assert_eq!(true, json[1]["pushed"]);
+        assert_eq!(true, json[1]["reachable"]);
+        assert_eq!(2, json[1]["stats"]["uptime"]);
+
+        let json = hub.to_json(now + chrono::Duration::seconds(31));
+
+        assert_eq!(false, json[1]["reachable"]);
+        assert!(json[1]["error"].is_string());
+    }
+
+    #[test]
+    fn test_proxy_scope() {
+        let hub = Hub::collector();
+
+        hub.ingest(
+            Push {
+                host:     "web1".to_string(),
+                time:     "2024-01-01T00:00:00Z".to_string(),
+                interval: 10,
+                data:     json!({ "hostname": "web1" }),
+            },
+            Utc::now(),
+        )
+        .unwrap();
+
+        let rocket = manage_test_auth(rocket::build().manage(hub), vec![Scope::ReadBasic]);
+
+        let client = Client::tracked(rocket_handler(rocket, &BasePath::default())).unwrap();
+
+        let get = |uri: &str| {
+            let mut req = client.get(uri.to_string());
+
+            req.add_header(Header::new("Authorization", TEST_AUTH_KEY));
+
+            req.dispatch().status()
+        };
+
+        assert_eq!(Status::Ok, get("/api/hosts/web1/all"));
+
+        // the agents need `read:process` for processes
+        assert_eq!(Status::Unauthorized, get("/api/hosts/web1/process"));
+        assert_eq!(Status::Unauthorized, get("/api/hosts/web1/v1/process"));
+        assert_eq!(Status::Unauthorized, get("/api/hosts/web1/v1//process?top=1"));
+
+        assert_eq!(Status::NotFound, get("/api/hosts/web1/stream"));
+
+        // unknown paths need the `admin` scope
+        assert_eq!(Status::Unauthorized, get("/api/hosts/web1/unknown"));
+        assert_eq!(Status::Unauthorized, get("/api/hosts/web1/v1/unknown"));
+    }
+}
@@ -0,0 +1,330 @@
+use std::fmt::Write;
+
+use rocket::{
+    http::{ContentType, Status},
+    Build, Rocket, State,
+};
+use rocket_cache_response::CacheResponse;
+
+use super::{
+    api::{Auth, ReadMetrics},
+    sampler::{CPUsStatCollector, NetworkStatCollector, Sampler, VolumesStatCollector},
+};
+
+const METRIC_PREFIX: &str = "mprober_";
+
+/// A writer of the Prometheus text exposition format (which is also accepted by OpenMetrics scrapers).
+struct MetricsWriter {
+    buffer: String,
+}
+
+impl MetricsWriter {
+    #[inline]
+    fn new() -> MetricsWriter {
+        MetricsWriter {
+            buffer: String::new()
+        }
+    }
+
+    fn family(&mut self, name: &str, metric_type: &str, help: &str) {
+        writeln!(self.buffer, "# HELP {METRIC_PREFIX}{name} {help}").unwrap();
+        writeln!(self.buffer, "# TYPE {METRIC_PREFIX}{name} {metric_type}").unwrap();
+    }
+
+    fn sample<V: Into<f64>>(&mut self, name: &str, labels: &[(&str, &str)], value: V) {
+        self.buffer.push_str(METRIC_PREFIX);
+        self.buffer.push_str(name);
+
+        if !labels.is_empty() {
+            self.buffer.push('{');
+
+            for (i, (label_name, label_value)) in labels.iter().enumerate() {
+                if i > 0 {
+                    self.buffer.push(',');
+                }
+
+                self.buffer.push_str(label_name);
+                self.buffer.push_str("=\"");
+                escape_label_value(&mut self.buffer, label_value);
+                self.buffer.push('"');
+            }
+
+            self.buffer.push('}');
+        }
+
+        writeln!(self.buffer, " {}", value.into()).unwrap();
+    }
+
+    #[inline]
+    fn into_string(self) -> String {
+        self.buffer
+    }
+}
+
+fn escape_label_value(buffer: &mut String, value: &str) {
+    for c in value.chars() {
+        match c {
+            '\\' => buffer.push_str("\\\\"),
+            '"' => buffer.push_str("\\\""),
+            '\n' => buffer.push_str("\\n"),
+            _ => buffer.push(c),
+        }
+    }
+}
+
+/// Render the metrics in the Prometheus text format. The families of the stats which cannot be read are left out.
+fn render_metrics(sampler: &Sampler) -> String {
+    let mut writer = MetricsWriter::new();
+
+    if let Ok(load_average) = mprober_lib::load_average::get_load_average() {
+        writer.family("load_average", "gauge", "System load average.");
+        writer.sample("load_average", &[("period", "1m")], load_average.one);
+        writer.sample("load_average", &[("period", "5m")], load_average.five);
+        writer.sample("load_average", &[("period", "15m")], load_average.fifteen);
+    }
+
+    if let Ok(cpus_stat) = sampler.sample::<CPUsStatCollector>().get() {
+        writer.family(
+            "cpu_utilization_ratio",
+            "gauge",
+            "CPU utilization in the latest detection interval, ranged from 0 to 1.",
+        );
+
+        for (i, cpu_stat) in cpus_stat.iter().copied().enumerate() {
+            if i == 0 {
+                writer.sample("cpu_utilization_ratio", &[("cpu", "all")], cpu_stat);
+            } else {
+                let cpu = (i - 1).to_string();
+
+                writer.sample("cpu_utilization_ratio", &[("cpu", cpu.as_str())], cpu_stat);
+            }
+        }
+    }
+
+    if let Ok(free) = mprober_lib::memory::free() {
+        writer.family("memory_bytes", "gauge", "Memory usage in bytes.");
+        writer.sample("memory_bytes", &[("type", "total")], free.mem.total as f64);
+        writer.sample("memory_bytes", &[("type", "used")], free.mem.used as f64);
+        writer.sample("memory_bytes", &[("type", "free")], free.mem.free as f64);
+        writer.sample("memory_bytes", &[("type", "shared")], free.mem.shared as f64);
+        writer.sample("memory_bytes", &[("type", "buffers")], free.mem.buffers as f64);
+        writer.sample("memory_bytes", &[("type", "cache")], free.mem.cache as f64);
+        writer.sample("memory_bytes", &[("type", "available")], free.mem.available as f64);
+
+        writer.family("swap_bytes", "gauge", "Swap usage in bytes.");
+        writer.sample("swap_bytes", &[("type", "total")], free.swap.total as f64);
+        writer.sample("swap_bytes", &[("type", "used")], free.swap.used as f64);
+        writer.sample("swap_bytes", &[("type", "free")], free.swap.free as f64);
+        writer.sample("swap_bytes", &[("type", "cache")], free.swap.cache as f64);
+    }
+
+    if let Ok(network_stat) = sampler.sample::<NetworkStatCollector>().get() {
+        writer.family(
+            "network_receive_bytes_total",
+            "counter",
+            "Total received bytes of a network interface.",
+        );
+
+        for (network, _) in network_stat.iter() {
+            writer.sample(
+                "network_receive_bytes_total",
+                &[("interface", network.interface.as_str())],
+                network.stat.receive_bytes as f64,
+            );
+        }
+
+        writer.family(
+            "network_transmit_bytes_total",
+            "counter",
+            "Total transmitted bytes of a network interface.",
+        );
+
+        for (network, _) in network_stat.iter() {
+            writer.sample(
+                "network_transmit_bytes_total",
+                &[("interface", network.interface.as_str())],
+                network.stat.transmit_bytes as f64,
+            );
+        }
+
+        writer.family(
+            "network_receive_rate_bytes_per_second",
+            "gauge",
+            "Receiving rate of a network interface in the latest detection interval.",
+        );
+
+        for (network, network_speed) in network_stat.iter() {
+            writer.sample(
+                "network_receive_rate_bytes_per_second",
+                &[("interface", network.interface.as_str())],
+                network_speed.receive,
+            );
+        }
+
+        writer.family(
+            "network_transmit_rate_bytes_per_second",
+            "gauge",
+            "Transmitting rate of a network interface in the latest detection interval.",
+        );
+
+        for (network, network_speed) in network_stat.iter() {
+            writer.sample(
+                "network_transmit_rate_bytes_per_second",
+                &[("interface", network.interface.as_str())],
+                network_speed.transmit,
+            );
+        }
+    }
+
+    if let Ok(volumes_stat) = sampler.sample::<VolumesStatCollector>().get() {
+        writer.family("volume_size_bytes", "gauge", "Size of a volume in bytes.");
+
+        for (volume, _) in volumes_stat.iter() {
+            writer.sample(
+                "volume_size_bytes",
+                &[("device", volume.device.as_str())],
+                volume.size as f64,
+            );
+        }
+
+        writer.family("volume_used_bytes", "gauge", "Used space of a volume in bytes.");
+
+        for (volume, _) in volumes_stat.iter() {
+            writer.sample(
+                "volume_used_bytes",
+                &[("device", volume.device.as_str())],
+                volume.used as f64,
+            );
+        }
+
+        writer.family("volume_read_bytes_total", "counter", "Total read bytes of a volume.");
+
+        for (volume, _) in volumes_stat.iter() {
+            writer.sample(
+                "volume_read_bytes_total",
+                &[("device", volume.device.as_str())],
+                volume.stat.read_bytes as f64,
+            );
+        }
+
+        writer.family("volume_write_bytes_total", "counter", "Total written bytes of a volume.");
+
+        for (volume, _) in volumes_stat.iter() {
+            writer.sample(
+                "volume_write_bytes_total",
+                &[("device", volume.device.as_str())],
+                volume.stat.write_bytes as f64,
+            );
+        }
+
+        writer.family(
+            "volume_read_rate_bytes_per_second",
+            "gauge",
+            "Reading rate of a volume in the latest detection interval.",
+        );
+
+        for (volume, volume_speed) in volumes_stat.iter() {
+            writer.sample(
+                "volume_read_rate_bytes_per_second",
+                &[("device", volume.device.as_str())],
+                volume_speed.read,
+            );
+        }
+
+        writer.family(
+            "volume_write_rate_bytes_per_second",
+            "gauge",
+            "Writing rate of a volume in the latest detection interval.",
+        );
+
+        for (volume, volume_speed) in volumes_stat.iter() {
+            writer.sample(
+                "volume_write_rate_bytes_per_second",
+                &[("device", volume.device.as_str())],
+                volume_speed.write,
+            );
+        }
+    }
+
+    writer.into_string()
+}
+
+#[get("/metrics")]
+fn metrics(
+    _auth: Auth<ReadMetrics>,
+    sampler: &State<Sampler>,
+) -> CacheResponse<(ContentType, String)> {
+    let content_type =
+        ContentType::new("text", "plain").with_params([("version", "0.0.4"), ("charset", "utf-8")]);
+
+    CacheResponse::NoStore((content_type, render_metrics(sampler)))
+}
+
+#[get("/metrics", rank = 2)]
+fn metrics_401() -> Status {
+    Status::Unauthorized
+}
+
+pub fn rocket_handler(rocket: Rocket<Build>, base_path: &super::BasePath) -> Rocket<Build> {
+    rocket.mount(base_path.mount_point(), routes![metrics, metrics_401])
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use rocket::{http::Header, local::blocking::Client};
+
+    use super::*;
+    use crate::{
+        auth::Scope,
+        rocket_mounts::{
+            test_auth::{manage_test_auth, TEST_AUTH_KEY},
+            BasePath,
+        },
+    };
+
+    #[test]
+    fn test_escape_label_value() {
+        let mut buffer = String::new();
+
+        escape_label_value(&mut buffer, "a\\b\"c\nd");
+
+        assert_eq!("a\\\\b\\\"c\\nd", buffer);
+    }
+
+    #[test]
+    fn test_metrics() {
+        let sampler = Sampler::start(Duration::from_millis(1000));
+
+        // the stats are unavailable until the first samples are collected
+        sampler.sample::<CPUsStatCollector>().wait().unwrap();
+        sampler.sample::<NetworkStatCollector>().wait().unwrap();
+
+        let rocket = manage_test_auth(rocket::build().manage(sampler), vec![Scope::ReadMetrics]);
+
+        let client = Client::tracked(rocket_handler(rocket, &BasePath::default())).unwrap();
+
+        {
+            let mut req = client.get("/metrics");
+
+            req.add_header(Header::new("Authorization", TEST_AUTH_KEY));
+
+            let res = req.dispatch();
+
+            assert_eq!(Status::Ok, res.status());
+
+            let body = res.into_string().unwrap();
+
+            assert!(body.contains("# TYPE mprober_load_average gauge"));
+            assert!(body.contains("mprober_cpu_utilization_ratio{cpu=\"all\"}"));
+            assert!(body.contains("# TYPE mprober_network_receive_bytes_total counter"));
+        }
+
+        {
+            let res = client.get("/metrics").dispatch();
+
+            assert_eq!(Status::Unauthorized, res.status());
+        }
+    }
+}
@@ -1,10 +1,24 @@
+mod alerts;
 mod api;
+mod health;
+mod history;
+mod hub;
+mod metrics;
 mod monitor;
+mod sampler;
 mod static_resources;
 
-use std::{net::IpAddr, ops::Deref, time::Duration};
+use std::{net::IpAddr, ops::Deref, str::FromStr, sync::Arc, time::Duration};
 
+use anyhow::anyhow;
+pub use hub::{load_agents, Agent, Hub, Push};
 use rocket::{Build, Config, Rocket};
+pub use sampler::{CPUsStatCollector, NetworkStatCollector, Sampler, VolumesStatCollector};
+
+use crate::{
+    auth::{AuthKeys, Sessions},
+    rules::AlertRule,
+};
 
 #[derive(Debug)]
 struct DetectInterval(Duration);
@@ -25,34 +39,174 @@ impl Deref for DetectInterval {
     }
 }
 
-#[derive(Debug)]
-struct AuthKey(Option<String>);
+/// The path which every route is mounted under, like `/hosts/db1`. It is empty if routes are mounted at the root.
+#[derive(Debug, Clone, Default)]
+pub struct BasePath(String);
+
+impl BasePath {
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+
+    /// Prefix `path`, which starts with a slash, with the base path.
+    #[inline]
+    pub fn join(&self, path: &str) -> String {
+        format!("{}{path}", self.0)
+    }
 
-impl AuthKey {
+    /// The path which routes at the root are mounted at.
     #[inline]
-    fn get_value(&self) -> Option<&str> {
-        self.0.as_deref()
+    fn mount_point(&self) -> &str {
+        if self.0.is_empty() {
+            "/"
+        } else {
+            self.0.as_str()
+        }
     }
 }
 
+impl FromStr for BasePath {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let path = s.trim_end_matches('/');
+
+        if !s.starts_with('/')
+            || path.contains("//")
+            || !path.chars().all(|c| c.is_ascii_alphanumeric() || "-._~/".contains(c))
+        {
+            return Err(anyhow!(
+                "The base path needs to start with a slash and be made of segments of letters, \
+                 digits, `-`, `.`, `_` and `~`."
+            ));
+        }
+
+        Ok(BasePath(path.to_string()))
+    }
+}
+
+/// The background services which the routes of the HTTP service share.
+#[derive(Debug, Clone)]
+pub struct Services {
+    sampler:    sampler::Sampler,
+    history:    Arc<history::History>,
+    alerts:     Arc<alerts::Alerts>,
+    /// Stores the snapshots pushed to `/api/ingest`.
+    hub:        Arc<Hub>,
+    self_stats: Arc<health::SelfStats>,
+}
+
+impl Services {
+    pub fn start(
+        monitor: Duration,
+        history: Duration,
+        alert_rules: Vec<AlertRule>,
+        webhook: Option<String>,
+    ) -> Services {
+        let sampler = sampler::Sampler::start(monitor);
+
+        let history = history::History::start(&sampler, monitor, history);
+
+        let alerts = alerts::Alerts::start(&sampler, alert_rules, webhook);
+
+        Services {
+            sampler,
+            history,
+            alerts,
+            hub: Hub::collector(),
+            self_stats: health::SelfStats::new(),
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn create(
+    services: &Services,
     monitor: Duration,
     address: IpAddr,
     listen_port: u16,
-    auth_key: Option<String>,
+    base_path: BasePath,
+    auth_keys: AuthKeys,
+    sessions: Sessions,
     only_api: bool,
+    https: bool,
 ) -> Rocket<Build> {
     let figment = Config::figment().merge(("address", address)).merge(("port", listen_port));
 
-    let rocket = rocket::custom(figment).manage(DetectInterval(monitor)).manage(AuthKey(auth_key));
+    let rocket = rocket::custom(figment)
+        .manage(DetectInterval(monitor))
+        .manage(auth_keys)
+        .manage(sessions)
+        .manage(services.sampler.clone())
+        .manage(services.history.clone())
+        .manage(services.alerts.clone())
+        .manage(services.hub.clone())
+        .manage(services.self_stats.clone());
+
+    let rocket = api::mounts(rocket, &base_path);
 
-    let rocket = api::mounts(rocket);
+    let rocket = health::rocket_handler(rocket, &base_path);
+
+    let rocket = history::rocket_handler(rocket, &base_path);
+
+    let rocket = alerts::rocket_handler(rocket, &base_path);
+
+    let rocket = metrics::rocket_handler(rocket, &base_path);
+
+    let rocket = hub::rocket_handler(rocket, &base_path);
 
     if only_api {
         rocket
     } else {
-        let rocket = static_resources::rocket_handler(rocket);
+        let rocket = static_resources::rocket_handler(rocket, &base_path);
+
+        monitor::rocket_handler(rocket, base_path, https)
+    }
+}
 
-        monitor::rocket_handler(rocket)
+/// Create the HTTP service of the hub command, which serves the dashboard of the hosts of the agents.
+#[allow(clippy::too_many_arguments)]
+pub fn create_hub(
+    hub: Arc<Hub>,
+    monitor: Duration,
+    address: IpAddr,
+    listen_port: u16,
+    base_path: BasePath,
+    auth_keys: AuthKeys,
+    sessions: Sessions,
+) -> Rocket<Build> {
+    let figment = Config::figment().merge(("address", address)).merge(("port", listen_port));
+
+    let rocket = rocket::custom(figment)
+        .manage(DetectInterval(monitor))
+        .manage(auth_keys)
+        .manage(sessions)
+        .manage(hub);
+
+    let rocket = hub::rocket_handler(rocket, &base_path);
+
+    let rocket = static_resources::rocket_handler(rocket, &base_path);
+
+    monitor::hub_rocket_handler(rocket, base_path)
+}
+
+/// The auth keys and the sessions which the tests of the routes guarded by `Auth` share.
+#[cfg(test)]
+mod test_auth {
+    use std::time::Duration;
+
+    use rocket::{Build, Rocket};
+
+    use crate::auth::{AuthKey, AuthKeys, Scope, Sessions};
+
+    /// The key of the auth key named `test`.
+    pub const TEST_AUTH_KEY: &str = "magic";
+
+    /// Manage the sessions and an auth key named `test` which is allowed to access `scopes`.
+    pub fn manage_test_auth(rocket: Rocket<Build>, scopes: Vec<Scope>) -> Rocket<Build> {
+        rocket
+            .manage(Sessions::new(Duration::from_secs(60)))
+            .manage(AuthKeys::new(vec![AuthKey::new("test", TEST_AUTH_KEY, scopes, None)]))
     }
 }
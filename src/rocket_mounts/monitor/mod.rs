@@ -1,40 +1,358 @@
 use std::collections::HashMap;
 
-use rocket::{Build, Rocket, State};
+use chrono::Utc;
+use rocket::{
+    form::Form,
+    http::{Cookie, CookieJar, Status},
+    response::Redirect,
+    time::Duration,
+    Build, Either, Rocket, State,
+};
 use rocket_cache_response::CacheResponse;
 use rocket_include_handlebars::{EtagIfNoneMatch, HandlebarsContextManager, HandlebarsResponse};
 use rocket_json_response::json_gettext::JSONGetTextValue;
 
+use super::BasePath;
+use crate::auth::{
+    csrf_token_matches, generate_csrf_token, AuthKeys, Scope, Session, Sessions,
+    SESSION_COOKIE_NAME,
+};
+
 const HANDLEBARS_RESOURCES_CACHE_MAX_AGE: u32 = 259_200;
 
+const LOGIN_CSRF_COOKIE_NAME: &str = "mprober_login_csrf";
+
+/// The name of the handlebars template of the index page, which is `index` for the web command and `hub` for the hub command.
+#[derive(Debug)]
+struct IndexPage(&'static str);
+
+/// Whether the cookies are only sent over HTTPS. HTTPS is served in front of Rocket, so Rocket cannot mark them by itself.
+#[derive(Debug)]
+struct SecureCookies(bool);
+
 fn handlebars_response(responder: HandlebarsResponse) -> CacheResponse<HandlebarsResponse> {
     CacheResponse::public_only_release(responder, HANDLEBARS_RESOURCES_CACHE_MAX_AGE, false)
 }
 
+/// Build a cookie which is only sent to the routes under the base path, so that the sessions of different hosts behind the same domain do not collide.
+fn build_cookie(
+    base_path: &BasePath,
+    secure_cookies: &SecureCookies,
+    name: &'static str,
+    value: String,
+) -> Cookie<'static> {
+    Cookie::build((name, value))
+        .path(base_path.mount_point().to_string())
+        .http_only(true)
+        .secure(secure_cookies.0)
+        .build()
+}
+
+/// Get the session of the request if its key is still allowed to read the stats.
+fn get_session(
+    cookies: &CookieJar<'_>,
+    auth_keys: &AuthKeys,
+    sessions: &Sessions,
+) -> Option<Session> {
+    let now = Utc::now();
+
+    let session = sessions.verify(cookies.get(SESSION_COOKIE_NAME)?.value(), now)?;
+
+    auth_keys
+        .find(&session.key_name, now)
+        .filter(|auth_key| auth_key.allows(Scope::ReadBasic))
+        .map(|_| session)
+}
+
+#[derive(Debug, FromForm)]
+struct LoginForm<'r> {
+    key:        &'r str,
+    csrf_token: &'r str,
+}
+
+#[derive(Debug, FromForm)]
+struct LogoutForm<'r> {
+    csrf_token: &'r str,
+}
+
+#[allow(clippy::too_many_arguments)]
 #[get("/")]
 fn index(
     cm: &State<HandlebarsContextManager>,
     etag_if_none_match: &EtagIfNoneMatch,
+    index_page: &State<IndexPage>,
     detect_interval: &State<super::DetectInterval>,
-    auth_key: &State<super::AuthKey>,
-) -> CacheResponse<HandlebarsResponse> {
+    base_path: &State<BasePath>,
+    auth_keys: &State<AuthKeys>,
+    sessions: &State<Sessions>,
+    cookies: &CookieJar<'_>,
+) -> Either<CacheResponse<HandlebarsResponse>, Redirect> {
     let mut map = HashMap::new();
 
     map.insert("version", JSONGetTextValue::Str(env!("CARGO_PKG_VERSION")));
 
+    map.insert("basePath", JSONGetTextValue::Str(base_path.as_str()));
+
     map.insert("timeInterval", JSONGetTextValue::from_u64(detect_interval.as_secs()));
 
-    if let Some(auth_key) = auth_key.get_value() {
-        map.insert("authKey", JSONGetTextValue::from_str(auth_key));
+    if auth_keys.is_empty() {
+        return Either::Left(handlebars_response(handlebars_response!(
+            cm,
+            etag_if_none_match,
+            index_page.0,
+            &map
+        )));
     }
 
-    handlebars_response(handlebars_response!(cm, etag_if_none_match, "index", &map))
+    let session = match get_session(cookies, auth_keys, sessions) {
+        Some(session) => session,
+        None => return Either::Right(Redirect::to(base_path.join("/login"))),
+    };
+
+    map.insert("csrfToken", JSONGetTextValue::from_string(session.csrf_token));
+
+    // the page differs between sessions
+    Either::Left(CacheResponse::NoStore(handlebars_response!(
+        cm,
+        etag_if_none_match,
+        index_page.0,
+        &map
+    )))
 }
 
-pub fn rocket_handler(rocket: Rocket<Build>) -> Rocket<Build> {
+#[get("/login?<error>")]
+fn login_page(
+    cm: &State<HandlebarsContextManager>,
+    etag_if_none_match: &EtagIfNoneMatch,
+    base_path: &State<BasePath>,
+    secure_cookies: &State<SecureCookies>,
+    auth_keys: &State<AuthKeys>,
+    cookies: &CookieJar<'_>,
+    error: Option<&str>,
+) -> Either<CacheResponse<HandlebarsResponse>, Redirect> {
+    if auth_keys.is_empty() {
+        return Either::Right(Redirect::to(base_path.join("/")));
+    }
+
+    let csrf_token = generate_csrf_token();
+
+    cookies.add(build_cookie(
+        base_path,
+        secure_cookies,
+        LOGIN_CSRF_COOKIE_NAME,
+        csrf_token.clone(),
+    ));
+
+    let mut map = HashMap::new();
+
+    map.insert("version", JSONGetTextValue::Str(env!("CARGO_PKG_VERSION")));
+
+    map.insert("basePath", JSONGetTextValue::Str(base_path.as_str()));
+
+    map.insert("csrfToken", JSONGetTextValue::from_string(csrf_token));
+
+    if error.is_some() {
+        map.insert("error", JSONGetTextValue::from_bool(true));
+    }
+
+    Either::Left(CacheResponse::NoStore(handlebars_response!(
+        cm,
+        etag_if_none_match,
+        "login",
+        &map
+    )))
+}
+
+#[post("/login", data = "<form>")]
+fn login(
+    base_path: &State<BasePath>,
+    secure_cookies: &State<SecureCookies>,
+    auth_keys: &State<AuthKeys>,
+    sessions: &State<Sessions>,
+    cookies: &CookieJar<'_>,
+    form: Form<LoginForm<'_>>,
+) -> Result<Redirect, Status> {
+    match cookies.get(LOGIN_CSRF_COOKIE_NAME) {
+        Some(cookie) if csrf_token_matches(cookie.value(), form.csrf_token) => (),
+        _ => return Err(Status::Forbidden),
+    }
+
+    cookies.remove(build_cookie(base_path, secure_cookies, LOGIN_CSRF_COOKIE_NAME, String::new()));
+
+    let now = Utc::now();
+
+    let auth_key = match auth_keys.verify(form.key, now) {
+        Some(auth_key) if auth_key.allows(Scope::ReadBasic) => auth_key,
+        _ => return Ok(Redirect::to(base_path.join("/login?error=1"))),
+    };
+
+    let (_, value) = sessions.issue(&auth_key.name, now);
+
+    let mut cookie = build_cookie(base_path, secure_cookies, SESSION_COOKIE_NAME, value);
+
+    cookie.set_max_age(Duration::seconds(sessions.ttl().as_secs() as i64));
+
+    cookies.add(cookie);
+
+    Ok(Redirect::to(base_path.join("/")))
+}
+
+#[post("/logout", data = "<form>")]
+fn logout(
+    base_path: &State<BasePath>,
+    secure_cookies: &State<SecureCookies>,
+    auth_keys: &State<AuthKeys>,
+    sessions: &State<Sessions>,
+    cookies: &CookieJar<'_>,
+    form: Form<LogoutForm<'_>>,
+) -> Result<Redirect, Status> {
+    match get_session(cookies, auth_keys, sessions) {
+        Some(session) if csrf_token_matches(&session.csrf_token, form.csrf_token) => {
+            sessions.revoke(&session, Utc::now())
+        },
+        Some(_) => return Err(Status::Forbidden),
+        None => (),
+    }
+
+    cookies.remove(build_cookie(base_path, secure_cookies, SESSION_COOKIE_NAME, String::new()));
+
+    Ok(Redirect::to(base_path.join("/login")))
+}
+
+fn mount(
+    rocket: Rocket<Build>,
+    base_path: BasePath,
+    index_page: IndexPage,
+    secure_cookies: SecureCookies,
+) -> Rocket<Build> {
+    let mount_point = base_path.mount_point().to_string();
+
     rocket
+        .manage(base_path)
+        .manage(index_page)
+        .manage(secure_cookies)
         .attach(handlebars_resources_initializer!(
-            "index" => "views/index.hbs"
+            "index" => "views/index.hbs",
+            "hub" => "views/hub.hbs",
+            "login" => "views/login.hbs"
         ))
-        .mount("/", routes![index])
+        .mount(mount_point, routes![index, login_page, login, logout])
+}
+
+/// Mount the monitor page. `https` marks the cookies as secure.
+pub fn rocket_handler(rocket: Rocket<Build>, base_path: BasePath, https: bool) -> Rocket<Build> {
+    mount(rocket, base_path, IndexPage("index"), SecureCookies(https))
+}
+
+/// Mount the dashboard of the hub command, which lists the hosts of the agents, instead of the monitor page.
+pub fn hub_rocket_handler(rocket: Rocket<Build>, base_path: BasePath) -> Rocket<Build> {
+    mount(rocket, base_path, IndexPage("hub"), SecureCookies(false))
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use rocket::{
+        http::{ContentType, Status},
+        local::blocking::Client,
+    };
+
+    use super::*;
+    use crate::rocket_mounts::test_auth::{manage_test_auth, TEST_AUTH_KEY};
+
+    #[test]
+    fn test_login() {
+        let rocket = manage_test_auth(
+            rocket::build().manage(super::super::DetectInterval(Duration::from_secs(3))),
+            vec![Scope::ReadBasic],
+        );
+
+        let client = Client::tracked(rocket_handler(rocket, BasePath::default(), false)).unwrap();
+
+        let res = client.get("/").dispatch();
+
+        assert_eq!(Status::SeeOther, res.status());
+        assert_eq!(Some("/login"), res.headers().get_one("Location"));
+
+        let csrf_token = client
+            .get("/login")
+            .dispatch()
+            .cookies()
+            .get(LOGIN_CSRF_COOKIE_NAME)
+            .unwrap()
+            .value()
+            .to_string();
+
+        // a form which is not from the login page
+        let res = client
+            .post("/login")
+            .header(ContentType::Form)
+            .body(format!("key={TEST_AUTH_KEY}&csrf_token=0"))
+            .dispatch();
+
+        assert_eq!(Status::Forbidden, res.status());
+
+        let res = client
+            .post("/login")
+            .header(ContentType::Form)
+            .body(format!("key={TEST_AUTH_KEY}&csrf_token={csrf_token}"))
+            .dispatch();
+
+        assert_eq!(Status::SeeOther, res.status());
+
+        let session_cookie = res.cookies().get(SESSION_COOKIE_NAME).unwrap().clone().into_owned();
+
+        let res = client.get("/").dispatch();
+
+        assert_eq!(Status::Ok, res.status());
+        assert!(!res.into_string().unwrap().contains(TEST_AUTH_KEY));
+
+        let session = client
+            .rocket()
+            .state::<Sessions>()
+            .unwrap()
+            .verify(session_cookie.value(), Utc::now())
+            .unwrap();
+
+        let res = client
+            .post("/logout")
+            .header(ContentType::Form)
+            .body(format!("csrf_token={}", session.csrf_token))
+            .dispatch();
+
+        assert_eq!(Status::SeeOther, res.status());
+
+        // the cookie kept from before logging out is revoked
+        let res = client.get("/").cookie(session_cookie).dispatch();
+
+        assert_eq!(Status::SeeOther, res.status());
+    }
+
+    #[test]
+    fn test_base_path() {
+        assert!("hosts".parse::<BasePath>().is_err());
+        assert!("/hosts//db1".parse::<BasePath>().is_err());
+        assert!("/hosts/<db1>".parse::<BasePath>().is_err());
+
+        let rocket = manage_test_auth(
+            rocket::build().manage(super::super::DetectInterval(Duration::from_secs(3))),
+            vec![Scope::ReadBasic],
+        );
+
+        let client =
+            Client::tracked(rocket_handler(rocket, "/hosts/db1/".parse().unwrap(), false)).unwrap();
+
+        assert_eq!(Status::NotFound, client.get("/").dispatch().status());
+
+        let res = client.get("/hosts/db1").dispatch();
+
+        assert_eq!(Status::SeeOther, res.status());
+        assert_eq!(Some("/hosts/db1/login"), res.headers().get_one("Location"));
+
+        let res = client.get("/hosts/db1/login").dispatch();
+
+        assert_eq!(Some("/hosts/db1"), res.cookies().get(LOGIN_CSRF_COOKIE_NAME).unwrap().path());
+        assert!(res.into_string().unwrap().contains("<base href=\"/hosts/db1/\">"));
+    }
 }
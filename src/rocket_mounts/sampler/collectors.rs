@@ -0,0 +1,53 @@
+use std::time::Duration;
+
+use mprober_lib::{
+    network::{Network, NetworkSpeed},
+    volume::{Volume, VolumeSpeed},
+};
+
+use super::Collector;
+use crate::rocket_mounts::api::{OrUnavailable, Subsystem, Unavailable};
+
+/// Collect the utilization of all CPUs on average, followed by each CPU.
+#[derive(Debug)]
+pub struct CPUsStatCollector;
+
+impl Collector for CPUsStatCollector {
+    type Output = Vec<f64>;
+
+    const SUBSYSTEM: Subsystem = Subsystem::Cpu;
+
+    #[inline]
+    fn collect(&mut self, interval: Duration) -> Result<Self::Output, Unavailable> {
+        mprober_lib::cpu::get_all_cpu_utilization_in_percentage(true, interval)
+            .or_unavailable(Self::SUBSYSTEM)
+    }
+}
+
+#[derive(Debug)]
+pub struct NetworkStatCollector;
+
+impl Collector for NetworkStatCollector {
+    type Output = Vec<(Network, NetworkSpeed)>;
+
+    const SUBSYSTEM: Subsystem = Subsystem::Network;
+
+    #[inline]
+    fn collect(&mut self, interval: Duration) -> Result<Self::Output, Unavailable> {
+        mprober_lib::network::get_networks_with_speed(interval).or_unavailable(Self::SUBSYSTEM)
+    }
+}
+
+#[derive(Debug)]
+pub struct VolumesStatCollector;
+
+impl Collector for VolumesStatCollector {
+    type Output = Vec<(Volume, VolumeSpeed)>;
+
+    const SUBSYSTEM: Subsystem = Subsystem::Volume;
+
+    #[inline]
+    fn collect(&mut self, interval: Duration) -> Result<Self::Output, Unavailable> {
+        mprober_lib::volume::get_volumes_with_speed(interval).or_unavailable(Self::SUBSYSTEM)
+    }
+}
@@ -0,0 +1,326 @@
+mod collectors;
+
+use std::{
+    any::Any,
+    fmt::Debug,
+    sync::{Arc, Condvar, Mutex},
+    thread,
+    time::{Duration, Instant},
+};
+
+pub use collectors::*;
+use rocket::tokio::sync::watch;
+use serde_json::Value;
+
+use super::api::{Subsystem, Unavailable};
+use crate::failure_log::FailureLog;
+
+/// A collector measures one kind of stats. Every collector is run by its own thread of the sampler.
+pub trait Collector: Send + 'static {
+    type Output: Debug + Send + Sync + 'static;
+
+    /// The subsystem which the stats are read from.
+    const SUBSYSTEM: Subsystem;
+
+    /// Collect a new sample. Collectors measuring rates are expected to block for `interval` to do the measurement.
+    fn collect(&mut self, interval: Duration) -> Result<Self::Output, Unavailable>;
+}
+
+/// The result of a collection. A failed collection is published as well, so that readers do not wait for a sample forever.
+pub type SampleResult<T> = Result<Arc<T>, Unavailable>;
+
+#[derive(Debug)]
+struct Published<T> {
+    /// Counted from 1.
+    generation: u64,
+    result:     SampleResult<T>,
+    time:       Instant,
+}
+
+/// The state of the latest sample of a collector.
+#[derive(Debug, Clone)]
+pub struct SampleState {
+    pub generation: u64,
+    /// The time elapsed since the sample was published.
+    pub lag:        Duration,
+    /// Why the latest collection failed.
+    pub error:      Option<Unavailable>,
+}
+
+/// The latest sample published by a collector.
+#[derive(Debug)]
+pub struct Sample<T> {
+    subsystem: Subsystem,
+    latest:    Mutex<Option<Published<T>>>,
+    condvar:   Condvar,
+}
+
+impl<T> Sample<T> {
+    #[inline]
+    fn new(subsystem: Subsystem) -> Sample<T> {
+        Sample {
+            subsystem,
+            latest: Mutex::new(None),
+            condvar: Condvar::new(),
+        }
+    }
+
+    fn publish(&self, result: Result<T, Unavailable>) {
+        let mut latest = self.latest.lock().unwrap();
+
+        let generation = latest.as_ref().map(|published| published.generation).unwrap_or(0) + 1;
+
+        latest.replace(Published {
+            generation,
+            result: result.map(Arc::new),
+            time: Instant::now(),
+        });
+
+        self.condvar.notify_all();
+    }
+
+    /// Get the latest sample without waiting. The stats are unavailable until the first sample is published.
+    pub fn get(&self) -> SampleResult<T> {
+        match self.latest.lock().unwrap().as_ref() {
+            Some(published) => published.result.clone(),
+            None => Err(Unavailable {
+                subsystem: self.subsystem,
+                reason:    "No sample has been collected yet.".to_string(),
+            }),
+        }
+    }
+
+    /// Get the latest sample. Only the first sample needs to be waited for, so this is for background threads rather than routes.
+    #[inline]
+    pub fn wait(&self) -> SampleResult<T> {
+        self.get_next(0).1
+    }
+
+    /// Get the state of the latest sample without waiting. `None` if nothing has been published yet.
+    pub fn state(&self) -> Option<SampleState> {
+        self.latest.lock().unwrap().as_ref().map(|published| SampleState {
+            generation: published.generation,
+            lag:        published.time.elapsed(),
+            error:      published.result.as_ref().err().cloned(),
+        })
+    }
+
+    /// Wait for a sample newer than the `previous` generation, and return it with its generation. The first sample is newer than the generation `0`.
+    pub fn get_next(&self, previous: u64) -> (u64, SampleResult<T>) {
+        let latest = self
+            .condvar
+            .wait_while(self.latest.lock().unwrap(), |published| match published {
+                Some(published) => published.generation <= previous,
+                None => true,
+            })
+            .unwrap();
+
+        let published = latest.as_ref().unwrap();
+
+        (published.generation, published.result.clone())
+    }
+}
+
+/// A sample whose output type is erased, so that the samples of all collectors can be kept together.
+trait AnySample: Debug + Send + Sync {
+    fn subsystem(&self) -> Subsystem;
+
+    fn state(&self) -> Option<SampleState>;
+
+    fn into_any(self: Arc<Self>) -> Arc<dyn Any + Send + Sync>;
+}
+
+impl<T: Debug + Send + Sync + 'static> AnySample for Sample<T> {
+    #[inline]
+    fn subsystem(&self) -> Subsystem {
+        self.subsystem
+    }
+
+    #[inline]
+    fn state(&self) -> Option<SampleState> {
+        Sample::state(self)
+    }
+
+    #[inline]
+    fn into_any(self: Arc<Self>) -> Arc<dyn Any + Send + Sync> {
+        self
+    }
+}
+
+/// Run the collector repeatedly. After a failure, the collector waits for `interval` and tries again, so that it recovers once the stats can be read.
+fn spawn_collector<C: Collector>(mut collector: C, interval: Duration) -> Arc<Sample<C::Output>> {
+    let sample = Arc::new(Sample::new(C::SUBSYSTEM));
+
+    let publisher = sample.clone();
+
+    thread::spawn(move || {
+        let mut failures = FailureLog::default();
+
+        loop {
+            let result = collector.collect(interval);
+
+            match result.as_ref() {
+                Ok(_) => failures.succeed(format_args!(
+                    "The {} stats are available again.",
+                    C::SUBSYSTEM.as_str()
+                )),
+                Err(error) => failures.fail(error),
+            }
+
+            publisher.publish(result);
+
+            if failures.is_failing() {
+                thread::sleep(interval);
+            }
+        }
+    });
+
+    sample
+}
+
+type Snapshot = Option<Arc<Value>>;
+
+/// A sampler which runs every collector periodically in the background, so that routes can read the latest samples instantly without detecting anything by themselves.
+#[derive(Debug, Clone)]
+pub struct Sampler {
+    /// The samples of the collectors in the order they are started.
+    samples:  Vec<Arc<dyn AnySample>>,
+    snapshot: watch::Receiver<Snapshot>,
+}
+
+impl Sampler {
+    pub fn start(detect_interval: Duration) -> Sampler {
+        let cpus_stat = spawn_collector(CPUsStatCollector, detect_interval);
+        let network_stat = spawn_collector(NetworkStatCollector, detect_interval);
+        let volumes_stat = spawn_collector(VolumesStatCollector, detect_interval);
+
+        let (sender, snapshot) = watch::channel(None);
+
+        let samples: Vec<Arc<dyn AnySample>> =
+            vec![cpus_stat.clone(), network_stat.clone(), volumes_stat.clone()];
+
+        // a new snapshot is made every time the CPU stats are updated
+        thread::spawn(move || {
+            let mut generation = 0;
+
+            loop {
+                let (next_generation, cpus_stat) = cpus_stat.get_next(generation);
+
+                let json =
+                    super::api::all_json(cpus_stat, network_stat.wait(), volumes_stat.wait());
+
+                sender.send_replace(Some(Arc::new(json)));
+
+                generation = next_generation;
+            }
+        });
+
+        Sampler {
+            samples,
+            snapshot,
+        }
+    }
+
+    /// Create a sampler which only has the snapshot. The stats of all collectors are unavailable.
+    #[cfg(test)]
+    pub fn with_snapshot(snapshot: Value) -> Sampler {
+        fn unavailable<C: Collector>() -> Arc<dyn AnySample> {
+            let sample = Sample::<C::Output>::new(C::SUBSYSTEM);
+
+            sample.publish(Err(Unavailable {
+                subsystem: C::SUBSYSTEM,
+                reason:    "Not collected.".to_string(),
+            }));
+
+            Arc::new(sample)
+        }
+
+        let (_, receiver) = watch::channel(Some(Arc::new(snapshot)));
+
+        Sampler {
+            samples:  vec![
+                unavailable::<CPUsStatCollector>(),
+                unavailable::<NetworkStatCollector>(),
+                unavailable::<VolumesStatCollector>(),
+            ],
+            snapshot: receiver,
+        }
+    }
+
+    /// The sample of the collector `C`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `C` is not run by this sampler.
+    pub fn sample<C: Collector>(&self) -> Arc<Sample<C::Output>> {
+        self.samples
+            .iter()
+            .filter(|sample| sample.subsystem() == C::SUBSYSTEM)
+            .find_map(|sample| sample.clone().into_any().downcast().ok())
+            .unwrap_or_else(|| panic!("The {} collector is not run.", C::SUBSYSTEM.as_str()))
+    }
+
+    /// The states of the latest samples of the collectors.
+    pub fn states(&self) -> Vec<(Subsystem, Option<SampleState>)> {
+        self.samples.iter().map(|sample| (sample.subsystem(), sample.state())).collect()
+    }
+
+    /// Subscribe the snapshots of `/api/all`. The receiver is marked as changed once a new snapshot is published.
+    #[inline]
+    pub fn subscribe(&self) -> watch::Receiver<Snapshot> {
+        self.snapshot.clone()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_sample_before_first_collection() {
+        let sample = Sample::<u64>::new(Subsystem::Cpu);
+
+        let error = sample.get().unwrap_err();
+
+        assert_eq!(Subsystem::Cpu, error.subsystem);
+        assert!(sample.state().is_none());
+
+        sample.publish(Ok(1));
+
+        assert_eq!(Ok(Arc::new(1)), sample.get());
+        assert_eq!(Ok(Arc::new(1)), sample.wait());
+    }
+
+    #[test]
+    fn test_sample_recovery() {
+        let sample = Sample::new(Subsystem::Memory);
+
+        let unavailable = Unavailable {
+            subsystem: Subsystem::Memory,
+            reason:    "No such file or directory (os error 2)".to_string(),
+        };
+
+        sample.publish(Err(unavailable.clone()));
+
+        assert_eq!((1, Err(unavailable)), sample.get_next(0));
+
+        sample.publish(Ok(1));
+
+        assert_eq!((2, Ok(Arc::new(1))), sample.get_next(1));
+        assert_eq!(Ok(Arc::new(1)), sample.get());
+    }
+
+    #[test]
+    fn test_sampler_samples() {
+        let sampler = Sampler::with_snapshot(Value::Null);
+
+        let subsystems: Vec<Subsystem> =
+            sampler.states().into_iter().map(|(subsystem, _)| subsystem).collect();
+
+        assert_eq!(vec![Subsystem::Cpu, Subsystem::Network, Subsystem::Volume], subsystems);
+
+        let error = sampler.sample::<NetworkStatCollector>().get().unwrap_err();
+
+        assert_eq!(Subsystem::Network, error.subsystem);
+    }
+}
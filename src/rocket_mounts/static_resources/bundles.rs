@@ -1,5 +1,7 @@
 use rocket::{Build, Rocket};
 
+use super::BasePath;
+
 cached_static_response_handler! {
     259_200;
     "/css/bundle.min.css" => css_bundle => "css-bundle",
@@ -17,22 +19,24 @@ cached_static_response_handler! {
     "/images/preload.svg" => preload => "preload",
 }
 
-pub fn mounts(rocket: Rocket<Build>) -> Rocket<Build> {
+pub fn mounts(rocket: Rocket<Build>, base_path: &BasePath) -> Rocket<Build> {
+    let mount_point = base_path.mount_point();
+
     rocket
-        .mount("/", routes![css_bundle, js_bundle])
-        .mount("/", routes![font_roboto_mono])
-        .mount("/", routes![
+        .mount(mount_point, routes![css_bundle, js_bundle])
+        .mount(mount_point, routes![font_roboto_mono])
+        .mount(mount_point, routes![
             roboto_mono_bold,
             roboto_mono_light,
             roboto_mono_medium,
             roboto_mono_regular
         ])
-        .mount("/", routes![
+        .mount(mount_point, routes![
             fa_solid_900_eot,
             fa_solid_900_svg,
             fa_solid_900_ttf,
             fa_solid_900_woff,
             fa_solid_900_woff2
         ])
-        .mount("/", routes![preload])
+        .mount(mount_point, routes![preload])
 }
@@ -1,5 +1,7 @@
 use rocket::{Build, Rocket};
 
+use super::BasePath;
+
 cached_static_response_handler! {
     259_200;
     "/web-app.manifest" => web_app_manifest => "web-app.manifest",
@@ -16,13 +18,15 @@ cached_static_response_handler! {
     "/mstile-70.png" => mstile_70 => "mstile-70",
 }
 
-pub fn mounts(rocket: Rocket<Build>) -> Rocket<Build> {
+pub fn mounts(rocket: Rocket<Build>, base_path: &BasePath) -> Rocket<Build> {
+    let mount_point = base_path.mount_point();
+
     rocket
-        .mount("/", routes![web_app_manifest])
-        .mount("/", routes![browser_config])
-        .mount("/", routes![favicon_monochrome])
-        .mount("/", routes![favicon])
-        .mount("/", routes![favicon_512, favicon_192, favicon_32, favicon_16])
-        .mount("/", routes![favicon_180_i])
-        .mount("/", routes![mstile_310, mstile_150, mstile_70])
+        .mount(mount_point, routes![web_app_manifest])
+        .mount(mount_point, routes![browser_config])
+        .mount(mount_point, routes![favicon_monochrome])
+        .mount(mount_point, routes![favicon])
+        .mount(mount_point, routes![favicon_512, favicon_192, favicon_32, favicon_16])
+        .mount(mount_point, routes![favicon_180_i])
+        .mount(mount_point, routes![mstile_310, mstile_150, mstile_70])
 }
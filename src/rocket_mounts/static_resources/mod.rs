@@ -3,7 +3,9 @@ mod favicons;
 
 use rocket::{Build, Rocket};
 
-pub fn rocket_handler(rocket: Rocket<Build>) -> Rocket<Build> {
+use super::BasePath;
+
+pub fn rocket_handler(rocket: Rocket<Build>, base_path: &BasePath) -> Rocket<Build> {
     let rocket = rocket.attach(static_resources_initializer!(
         "css-bundle" => "front-end/css/bundle.min.css",
         "js-bundle" => "front-end/js/bundle.min.js",
@@ -32,7 +34,7 @@ pub fn rocket_handler(rocket: Rocket<Build>) -> Rocket<Build> {
         "mstile-70" => "front-end/mstile-70.png",
     ));
 
-    let rocket = bundles::mounts(rocket);
+    let rocket = bundles::mounts(rocket, base_path);
 
-    favicons::mounts(rocket)
+    favicons::mounts(rocket, base_path)
 }
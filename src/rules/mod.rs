@@ -0,0 +1,623 @@
+use std::{fmt, str::FromStr, time::Duration};
+
+use anyhow::anyhow;
+use byte_unit::Byte;
+use mprober_lib::{load_average::LoadAverage, memory::Free, volume::Volume};
+
+/// The stats which rules are evaluated against.
+#[derive(Debug)]
+pub struct Stats {
+    /// The first value is the average usage of all cores. The remaining values are the usage for each logical CPU core. Ranged from 0 to 1.
+    pub cpus_stat:    Vec<f64>,
+    pub load_average: LoadAverage,
+    pub free:         Free,
+    pub volumes:      Vec<Volume>,
+    /// The number of logical CPU cores.
+    pub cores:        usize,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Unit {
+    Percentage,
+    Bytes,
+    None,
+}
+
+impl Unit {
+    /// The unit of measurement used in perfdata.
+    #[inline]
+    pub fn as_uom(self) -> &'static str {
+        match self {
+            Unit::Percentage => "%",
+            Unit::Bytes => "B",
+            Unit::None => "",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum LoadField {
+    One,
+    Five,
+    Fifteen,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum MemoryField {
+    Total,
+    Used,
+    Free,
+    Shared,
+    Buffers,
+    Cache,
+    Available,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum SwapField {
+    Total,
+    Used,
+    Free,
+    Cache,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum VolumeField {
+    Size,
+    Used,
+    Free,
+}
+
+/// A value which can be read from `Stats`. Fields suffixed with `%` are in percentage of the total.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Metric {
+    Cpu,
+    CpuCore(usize),
+    Load(LoadField),
+    Memory(MemoryField, bool),
+    Swap(SwapField, bool),
+    /// A volume is selected by its mount point or its device name.
+    Volume(String, VolumeField, bool),
+}
+
+impl Metric {
+    pub fn unit(&self) -> Unit {
+        match self {
+            Metric::Cpu | Metric::CpuCore(_) => Unit::Percentage,
+            Metric::Load(_) => Unit::None,
+            Metric::Memory(_, percentage)
+            | Metric::Swap(_, percentage)
+            | Metric::Volume(_, _, percentage) => {
+                if *percentage {
+                    Unit::Percentage
+                } else {
+                    Unit::Bytes
+                }
+            },
+        }
+    }
+
+    /// Read the value of this metric. `None` if the CPU core or the volume does not exist.
+    pub fn value(&self, stats: &Stats) -> Option<f64> {
+        let ratio = |value: usize, total: usize, percentage: bool| {
+            if percentage {
+                if total == 0 {
+                    0.0
+                } else {
+                    value as f64 * 100.0 / total as f64
+                }
+            } else {
+                value as f64
+            }
+        };
+
+        match self {
+            Metric::Cpu => stats.cpus_stat.first().map(|v| v * 100.0),
+            Metric::CpuCore(i) => stats.cpus_stat.get(i + 1).map(|v| v * 100.0),
+            Metric::Load(field) => Some(match field {
+                LoadField::One => stats.load_average.one,
+                LoadField::Five => stats.load_average.five,
+                LoadField::Fifteen => stats.load_average.fifteen,
+            }),
+            Metric::Memory(field, percentage) => {
+                let mem = &stats.free.mem;
+
+                let value = match field {
+                    MemoryField::Total => mem.total,
+                    MemoryField::Used => mem.used,
+                    MemoryField::Free => mem.free,
+                    MemoryField::Shared => mem.shared,
+                    MemoryField::Buffers => mem.buffers,
+                    MemoryField::Cache => mem.cache,
+                    MemoryField::Available => mem.available,
+                };
+
+                Some(ratio(value, mem.total, *percentage))
+            },
+            Metric::Swap(field, percentage) => {
+                let swap = &stats.free.swap;
+
+                let value = match field {
+                    SwapField::Total => swap.total,
+                    SwapField::Used => swap.used,
+                    SwapField::Free => swap.free,
+                    SwapField::Cache => swap.cache,
+                };
+
+                Some(ratio(value, swap.total, *percentage))
+            },
+            Metric::Volume(selector, field, percentage) => {
+                let device = selector.strip_prefix("/dev/").unwrap_or(selector);
+
+                let volume = stats.volumes.iter().find(|volume| {
+                    volume.device == device || volume.points.iter().any(|point| point == selector)
+                })?;
+
+                let value = match field {
+                    VolumeField::Size => volume.size,
+                    VolumeField::Used => volume.used,
+                    VolumeField::Free => volume.size.saturating_sub(volume.used),
+                };
+
+                Some(ratio(value as usize, volume.size as usize, *percentage))
+            },
+        }
+    }
+
+    /// Whether the CPU stats need to be measured for this metric.
+    #[inline]
+    pub fn needs_cpus_stat(&self) -> bool {
+        matches!(self, Metric::Cpu | Metric::CpuCore(_))
+    }
+
+    /// Whether the volumes need to be read for this metric.
+    #[inline]
+    pub fn needs_volumes(&self) -> bool {
+        matches!(self, Metric::Volume(..))
+    }
+}
+
+impl FromStr for Metric {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (s, percentage) = match s.strip_suffix('%') {
+            Some(s) => (s, true),
+            None => (s, false),
+        };
+
+        let metric = if s == "cpu" {
+            Metric::Cpu
+        } else if let Some(index) = s.strip_prefix("cpu[").and_then(|s| s.strip_suffix(']')) {
+            Metric::CpuCore(index.parse()?)
+        } else if let Some(field) = s.strip_prefix("load.") {
+            Metric::Load(match field {
+                "one" => LoadField::One,
+                "five" => LoadField::Five,
+                "fifteen" => LoadField::Fifteen,
+                _ => return Err(anyhow!("Unknown load average {field:?}.")),
+            })
+        } else if let Some(field) = s.strip_prefix("memory.") {
+            Metric::Memory(
+                match field {
+                    "total" => MemoryField::Total,
+                    "used" => MemoryField::Used,
+                    "free" => MemoryField::Free,
+                    "shared" => MemoryField::Shared,
+                    "buffers" => MemoryField::Buffers,
+                    "cache" => MemoryField::Cache,
+                    "available" => MemoryField::Available,
+                    _ => return Err(anyhow!("Unknown memory field {field:?}.")),
+                },
+                percentage,
+            )
+        } else if let Some(field) = s.strip_prefix("swap.") {
+            Metric::Swap(
+                match field {
+                    "total" => SwapField::Total,
+                    "used" => SwapField::Used,
+                    "free" => SwapField::Free,
+                    "cache" => SwapField::Cache,
+                    _ => return Err(anyhow!("Unknown swap field {field:?}.")),
+                },
+                percentage,
+            )
+        } else if let Some((selector, field)) =
+            s.strip_prefix("volume[").and_then(|s| s.rsplit_once("]."))
+        {
+            if selector.is_empty() {
+                return Err(anyhow!("A volume needs to be selected by a mount point or a device."));
+            }
+
+            Metric::Volume(
+                selector.to_string(),
+                match field {
+                    "size" => VolumeField::Size,
+                    "used" => VolumeField::Used,
+                    "free" => VolumeField::Free,
+                    _ => return Err(anyhow!("Unknown volume field {field:?}.")),
+                },
+                percentage,
+            )
+        } else {
+            return Err(anyhow!("Unknown metric {s:?}."));
+        };
+
+        if percentage && metric.unit() != Unit::Percentage {
+            return Err(anyhow!("{s:?} cannot be in percentage."));
+        }
+
+        Ok(metric)
+    }
+}
+
+impl fmt::Display for Metric {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let percentage = |percentage: bool| if percentage { "%" } else { "" };
+
+        match self {
+            Metric::Cpu => f.write_str("cpu"),
+            Metric::CpuCore(i) => write!(f, "cpu[{i}]"),
+            Metric::Load(field) => f.write_str(match field {
+                LoadField::One => "load.one",
+                LoadField::Five => "load.five",
+                LoadField::Fifteen => "load.fifteen",
+            }),
+            Metric::Memory(field, p) => write!(
+                f,
+                "memory.{}{}",
+                match field {
+                    MemoryField::Total => "total",
+                    MemoryField::Used => "used",
+                    MemoryField::Free => "free",
+                    MemoryField::Shared => "shared",
+                    MemoryField::Buffers => "buffers",
+                    MemoryField::Cache => "cache",
+                    MemoryField::Available => "available",
+                },
+                percentage(*p)
+            ),
+            Metric::Swap(field, p) => write!(
+                f,
+                "swap.{}{}",
+                match field {
+                    SwapField::Total => "total",
+                    SwapField::Used => "used",
+                    SwapField::Free => "free",
+                    SwapField::Cache => "cache",
+                },
+                percentage(*p)
+            ),
+            Metric::Volume(selector, field, p) => write!(
+                f,
+                "volume[{selector}].{}{}",
+                match field {
+                    VolumeField::Size => "size",
+                    VolumeField::Used => "used",
+                    VolumeField::Free => "free",
+                },
+                percentage(*p)
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Operator {
+    Greater,
+    GreaterOrEqual,
+    Less,
+    LessOrEqual,
+}
+
+impl Operator {
+    #[inline]
+    fn compare(self, value: f64, threshold: f64) -> bool {
+        match self {
+            Operator::Greater => value > threshold,
+            Operator::GreaterOrEqual => value >= threshold,
+            Operator::Less => value < threshold,
+            Operator::LessOrEqual => value <= threshold,
+        }
+    }
+}
+
+/// A threshold can be a number, a size like `500MB`, or a multiple of the number of logical CPU cores like `2*cores`. A percentage like `85%` is only accepted by `Rule`, which knows whether its metric is in percentage.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Threshold {
+    Value(f64),
+    PerCore(f64),
+}
+
+impl Threshold {
+    #[inline]
+    pub fn resolve(self, stats: &Stats) -> f64 {
+        match self {
+            Threshold::Value(v) => v,
+            Threshold::PerCore(v) => v * stats.cores as f64,
+        }
+    }
+
+    /// Whether the number of logical CPU cores needs to be read for this threshold.
+    #[inline]
+    pub fn needs_cores(self) -> bool {
+        matches!(self, Threshold::PerCore(_))
+    }
+}
+
+impl FromStr for Threshold {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(s) = s.strip_suffix("cores").and_then(|s| s.trim_end().strip_suffix('*')) {
+            return Ok(Threshold::PerCore(s.trim().parse()?));
+        }
+
+        if let Ok(v) = s.parse() {
+            return Ok(Threshold::Value(v));
+        }
+
+        match Byte::parse_str(s, true) {
+            Ok(byte) => Ok(Threshold::Value(byte.as_u64() as f64)),
+            Err(_) => Err(anyhow!("Invalid threshold {s:?}.")),
+        }
+    }
+}
+
+/// A rule like `cpu>90`, `memory.used%>85`, `volume[/].used%>90` or `load.five>2*cores`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rule {
+    pub metric:    Metric,
+    pub operator:  Operator,
+    pub threshold: Threshold,
+    text:          String,
+}
+
+impl Rule {
+    /// `None` if the metric cannot be read.
+    #[inline]
+    pub fn is_triggered(&self, stats: &Stats) -> Option<bool> {
+        let value = self.metric.value(stats)?;
+
+        Some(self.operator.compare(value, self.threshold.resolve(stats)))
+    }
+
+    /// The threshold in the range format of Nagios perfdata.
+    pub fn perfdata_range(&self, stats: &Stats) -> String {
+        let threshold = self.threshold.resolve(stats);
+
+        match self.operator {
+            Operator::Greater | Operator::GreaterOrEqual => format!("{threshold}"),
+            Operator::Less | Operator::LessOrEqual => format!("{threshold}:"),
+        }
+    }
+}
+
+impl FromStr for Rule {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let index = s
+            .find(['>', '<'])
+            .ok_or_else(|| anyhow!("{s:?} needs a comparison operator like `>` or `<`."))?;
+
+        // whitespace is only trimmed around the operator, because a mount point may contain spaces
+        let (metric_str, rest) = s.split_at(index);
+        let metric_str = metric_str.trim();
+
+        let (operator, operator_str, threshold_str) = if let Some(t) = rest.strip_prefix(">=") {
+            (Operator::GreaterOrEqual, ">=", t)
+        } else if let Some(t) = rest.strip_prefix("<=") {
+            (Operator::LessOrEqual, "<=", t)
+        } else if let Some(t) = rest.strip_prefix('>') {
+            (Operator::Greater, ">", t)
+        } else {
+            (Operator::Less, "<", &rest[1..])
+        };
+
+        let threshold_str = threshold_str.trim();
+
+        let metric: Metric = metric_str.parse()?;
+
+        let threshold = match threshold_str.strip_suffix('%') {
+            Some(threshold) => {
+                if metric.unit() != Unit::Percentage {
+                    return Err(match metric {
+                        Metric::Memory(..) | Metric::Swap(..) | Metric::Volume(..) => anyhow!(
+                            "{metric} is not in percentage. Use `{metric}%{operator_str}{}` to \
+                             compare the percentage.",
+                            threshold.trim_end()
+                        ),
+                        _ => anyhow!("{metric} is not in percentage."),
+                    });
+                }
+
+                threshold.trim_end().parse()?
+            },
+            None => threshold_str.parse()?,
+        };
+
+        Ok(Rule {
+            metric,
+            operator,
+            threshold,
+            text: format!("{metric_str}{operator_str}{threshold_str}"),
+        })
+    }
+}
+
+impl fmt::Display for Rule {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+/// A rule which needs to be met for a while, like `cpu>90 for 2m`, and optionally not be met for a while to recover, like `cpu>90 for 2m recover after 5m`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlertRule {
+    pub rule:     Rule,
+    pub duration: Duration,
+    /// How long the rule needs to be not met before a firing alert is resolved, so that a value hovering around the threshold does not flap.
+    pub recovery: Duration,
+}
+
+impl FromStr for AlertRule {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (s, recovery) = match s.rsplit_once(" recover after ") {
+            Some((s, recovery)) => (s, humantime::parse_duration(recovery.trim())?),
+            None => (s, Duration::ZERO),
+        };
+
+        let (s, duration) = match s.rsplit_once(" for ") {
+            Some((s, duration)) => (s, humantime::parse_duration(duration.trim())?),
+            None => (s, Duration::ZERO),
+        };
+
+        Ok(AlertRule {
+            rule: s.parse()?,
+            duration,
+            recovery,
+        })
+    }
+}
+
+impl fmt::Display for AlertRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.rule, f)?;
+
+        if !self.duration.is_zero() {
+            write!(f, " for {}", humantime::format_duration(self.duration))?;
+        }
+
+        if !self.recovery.is_zero() {
+            write!(f, " recover after {}", humantime::format_duration(self.recovery))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let rule: Rule = "volume[/].used% > 90".parse().unwrap();
+
+        assert_eq!(Metric::Volume("/".into(), VolumeField::Used, true), rule.metric);
+        assert_eq!(Operator::Greater, rule.operator);
+        assert_eq!(Threshold::Value(90.0), rule.threshold);
+        assert_eq!("volume[/].used%>90", rule.to_string());
+
+        let rule: Rule = "load.five>=2*cores".parse().unwrap();
+
+        assert_eq!(Metric::Load(LoadField::Five), rule.metric);
+        assert_eq!(Operator::GreaterOrEqual, rule.operator);
+        assert_eq!(Threshold::PerCore(2.0), rule.threshold);
+        assert!(rule.threshold.needs_cores());
+
+        let rule: Rule = "memory.available<512MiB".parse().unwrap();
+
+        assert_eq!(Threshold::Value(536870912.0), rule.threshold);
+        assert!(!rule.threshold.needs_cores());
+
+        let alert_rule: AlertRule = "cpu > 90 for 2m".parse().unwrap();
+
+        assert_eq!(Metric::Cpu, alert_rule.rule.metric);
+        assert_eq!(Duration::from_secs(120), alert_rule.duration);
+        assert_eq!("cpu>90 for 2m", alert_rule.to_string());
+
+        let alert_rule: AlertRule = "cpu > 90 for 2m recover after 5m".parse().unwrap();
+
+        assert_eq!(Duration::from_secs(120), alert_rule.duration);
+        assert_eq!(Duration::from_secs(300), alert_rule.recovery);
+        assert_eq!("cpu>90 for 2m recover after 5m", alert_rule.to_string());
+
+        let alert_rule: AlertRule = "cpu>90 recover after 30s".parse().unwrap();
+
+        assert_eq!(Duration::ZERO, alert_rule.duration);
+        assert_eq!(Duration::from_secs(30), alert_rule.recovery);
+
+        assert!("cpu>90 recover after soon".parse::<AlertRule>().is_err());
+
+        assert!("cpu".parse::<Rule>().is_err());
+        assert!("load.five%>1".parse::<Rule>().is_err());
+        assert!("disk>1".parse::<Rule>().is_err());
+    }
+
+    #[test]
+    fn test_parse_operators() {
+        for (s, operator, threshold) in [
+            ("cpu>90", Operator::Greater, 90.0),
+            ("cpu>=90", Operator::GreaterOrEqual, 90.0),
+            ("cpu<10.5", Operator::Less, 10.5),
+            ("cpu <= 10", Operator::LessOrEqual, 10.0),
+        ] {
+            let rule: Rule = s.parse().unwrap();
+
+            assert_eq!(Metric::Cpu, rule.metric);
+            assert_eq!(operator, rule.operator);
+            assert_eq!(Threshold::Value(threshold), rule.threshold);
+        }
+
+        let rule: Rule = "cpu[3] >= 50".parse().unwrap();
+
+        assert_eq!(Metric::CpuCore(3), rule.metric);
+        assert_eq!("cpu[3]>=50", rule.to_string());
+
+        let rule: Rule = "load.one > 1.5 * cores".parse().unwrap();
+
+        assert_eq!(Threshold::PerCore(1.5), rule.threshold);
+        assert_eq!("load.one>1.5 * cores", rule.to_string());
+
+        assert!("cpu>".parse::<Rule>().is_err());
+        assert!(">90".parse::<Rule>().is_err());
+        assert!("cpu>ninety".parse::<Rule>().is_err());
+        assert!("cpu[x]>1".parse::<Rule>().is_err());
+        assert!("memory.unknown>1".parse::<Rule>().is_err());
+        assert!("volume[].used>1".parse::<Rule>().is_err());
+    }
+
+    #[test]
+    fn test_parse_percentage() {
+        let rule: Rule = "cpu > 90%".parse().unwrap();
+
+        assert_eq!(Threshold::Value(90.0), rule.threshold);
+
+        let rule: Rule = "memory.used% > 85%".parse().unwrap();
+
+        assert_eq!(Metric::Memory(MemoryField::Used, true), rule.metric);
+        assert_eq!(Threshold::Value(85.0), rule.threshold);
+
+        // comparing bytes with a percentage would silently compare with 85 bytes
+        let error = "memory.used > 85%".parse::<Rule>().unwrap_err();
+
+        assert_eq!(
+            "memory.used is not in percentage. Use `memory.used%>85` to compare the percentage.",
+            error.to_string()
+        );
+
+        assert!("load.one>50%".parse::<Rule>().is_err());
+        assert!("memory.available<512MiB%".parse::<Rule>().is_err());
+    }
+
+    #[test]
+    fn test_parse_volume_with_spaces() {
+        let rule: Rule = "volume[/mnt/My Disk].used% > 90".parse().unwrap();
+
+        assert_eq!(Metric::Volume("/mnt/My Disk".into(), VolumeField::Used, true), rule.metric);
+        assert_eq!("volume[/mnt/My Disk].used%>90", rule.to_string());
+
+        let alert_rule: AlertRule = "volume[/mnt/My Disk].free < 1GiB for 5m".parse().unwrap();
+
+        assert_eq!(
+            Metric::Volume("/mnt/My Disk".into(), VolumeField::Free, false),
+            alert_rule.rule.metric
+        );
+        assert_eq!(Threshold::Value(1073741824.0), alert_rule.rule.threshold);
+    }
+}
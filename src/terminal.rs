@@ -120,6 +120,20 @@ pub static COLOR_BUFFERS: Lazy<ColorSpec> = Lazy::new(|| {
     color_spec
 });
 
+pub static COLOR_SELECTED: Lazy<ColorSpec> = Lazy::new(|| {
+    let mut color_spec = ColorSpec::new();
+
+    if !unsafe { FORCE_PLAIN_MODE } {
+        if unsafe { LIGHT_MODE } {
+            color_spec.set_fg(Some(BLACK_COLOR)).set_bold(true).set_underline(true);
+        } else {
+            color_spec.set_fg(Some(WHITE_COLOR)).set_bold(true).set_underline(true);
+        }
+    }
+
+    color_spec
+});
+
 pub fn set_color_mode(plain: bool, light: bool) {
     unsafe {
         if plain {
@@ -0,0 +1,205 @@
+use std::{
+    fs,
+    net::IpAddr,
+    path::PathBuf,
+    sync::{Arc, RwLock},
+};
+
+use anyhow::{anyhow, Context};
+use rustls_pemfile::Item;
+use tokio_rustls::{
+    rustls::{
+        server::{ClientHello, ResolvesServerCert},
+        sign::{self, CertifiedKey},
+        Certificate, PrivateKey, ServerConfig,
+    },
+    TlsAcceptor,
+};
+
+/// Where the certificate chain and the private key of the HTTPS service come from.
+#[derive(Debug, Clone)]
+pub enum TlsSource {
+    Files { cert: PathBuf, key: PathBuf },
+    SelfSigned { cert: String, key: String },
+}
+
+impl TlsSource {
+    /// Generate a self-signed certificate for `localhost`, the hostname and the listening address.
+    pub fn self_signed(address: IpAddr) -> anyhow::Result<TlsSource> {
+        let mut names = vec!["localhost".to_string()];
+
+        if let Ok(hostname) = mprober_lib::hostname::get_hostname() {
+            names.push(hostname);
+        }
+
+        if !address.is_unspecified() {
+            names.push(address.to_string());
+        }
+
+        let certified_key = rcgen::generate_simple_self_signed(names)
+            .map_err(|err| anyhow!("Cannot generate a self-signed certificate: {err}"))?;
+
+        Ok(TlsSource::SelfSigned {
+            cert: certified_key.cert.pem(),
+            key:  certified_key.key_pair.serialize_pem(),
+        })
+    }
+
+    /// Whether the certificate can be changed by reloading.
+    #[inline]
+    pub fn is_reloadable(&self) -> bool {
+        matches!(self, TlsSource::Files { .. })
+    }
+
+    /// Read the certificate chain and the private key. Files are read every time this is called, so replaced files take effect after reloading.
+    pub fn load(&self) -> anyhow::Result<Arc<CertifiedKey>> {
+        match self {
+            TlsSource::Files {
+                cert,
+                key,
+            } => {
+                let cert_pem = fs::read(cert).with_context(|| format!("Cannot read {cert:?}."))?;
+                let key_pem = fs::read(key).with_context(|| format!("Cannot read {key:?}."))?;
+
+                let certs = read_certs(&cert_pem)
+                    .ok_or_else(|| anyhow!("{cert:?} contains no PEM certificates."))?;
+                let private_key = read_private_key(&key_pem)
+                    .ok_or_else(|| anyhow!("{key:?} contains no PEM private keys."))?;
+
+                certified_key(certs, &private_key).with_context(|| format!("Cannot use {key:?}."))
+            },
+            TlsSource::SelfSigned {
+                cert,
+                key,
+            } => {
+                let certs = read_certs(cert.as_bytes())
+                    .ok_or_else(|| anyhow!("The self-signed certificate is broken."))?;
+                let private_key = read_private_key(key.as_bytes())
+                    .ok_or_else(|| anyhow!("The self-signed private key is broken."))?;
+
+                certified_key(certs, &private_key)
+            },
+        }
+    }
+}
+
+/// Resolve every handshake to the current certificate, which can be swapped while connections are being accepted.
+pub struct CertResolver {
+    certified_key: RwLock<Arc<CertifiedKey>>,
+}
+
+impl CertResolver {
+    #[inline]
+    pub fn new(certified_key: Arc<CertifiedKey>) -> CertResolver {
+        CertResolver {
+            certified_key: RwLock::new(certified_key)
+        }
+    }
+
+    /// Use another certificate for the following handshakes. Established connections are not affected.
+    #[inline]
+    pub fn swap(&self, certified_key: Arc<CertifiedKey>) {
+        *self.certified_key.write().unwrap() = certified_key;
+    }
+}
+
+impl ResolvesServerCert for CertResolver {
+    #[inline]
+    fn resolve(&self, _client_hello: ClientHello) -> Option<Arc<CertifiedKey>> {
+        Some(self.certified_key.read().unwrap().clone())
+    }
+}
+
+/// Accept TLS connections with the current certificate of `resolver`.
+pub fn acceptor(resolver: Arc<CertResolver>) -> TlsAcceptor {
+    let mut server_config = ServerConfig::builder()
+        .with_safe_defaults()
+        .with_no_client_auth()
+        .with_cert_resolver(resolver);
+
+    server_config.alpn_protocols = vec![b"http/1.1".to_vec()];
+
+    TlsAcceptor::from(Arc::new(server_config))
+}
+
+fn certified_key(
+    certs: Vec<Certificate>,
+    private_key: &PrivateKey,
+) -> anyhow::Result<Arc<CertifiedKey>> {
+    let signing_key = sign::any_supported_type(private_key)
+        .map_err(|err| anyhow!("The private key is not supported: {err}"))?;
+
+    Ok(Arc::new(CertifiedKey::new(certs, signing_key)))
+}
+
+fn read_certs(pem: &[u8]) -> Option<Vec<Certificate>> {
+    let certs: Vec<Certificate> = pem_items(pem)
+        .into_iter()
+        .filter_map(|item| match item {
+            Item::X509Certificate(der) => Some(Certificate(der)),
+            _ => None,
+        })
+        .collect();
+
+    if certs.is_empty() {
+        None
+    } else {
+        Some(certs)
+    }
+}
+
+fn read_private_key(pem: &[u8]) -> Option<PrivateKey> {
+    pem_items(pem).into_iter().find_map(|item| match item {
+        Item::RSAKey(der) | Item::PKCS8Key(der) | Item::ECKey(der) => Some(PrivateKey(der)),
+        _ => None,
+    })
+}
+
+#[inline]
+fn pem_items(mut pem: &[u8]) -> Vec<Item> {
+    rustls_pemfile::read_all(&mut pem).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod test {
+    use std::env;
+
+    use super::*;
+
+    #[test]
+    fn test_load() {
+        let self_signed = TlsSource::self_signed(IpAddr::from([127, 0, 0, 1])).unwrap();
+
+        assert!(self_signed.load().is_ok());
+        assert!(!self_signed.is_reloadable());
+
+        let (cert_pem, key_pem) = match &self_signed {
+            TlsSource::SelfSigned {
+                cert,
+                key,
+            } => (cert.clone(), key.clone()),
+            _ => unreachable!(),
+        };
+
+        let directory = env::temp_dir();
+        let cert = directory.join(format!("mprober-test-{}-cert.pem", std::process::id()));
+        let key = directory.join(format!("mprober-test-{}-key.pem", std::process::id()));
+
+        fs::write(&cert, cert_pem).unwrap();
+        fs::write(&key, &key_pem).unwrap();
+
+        let files = TlsSource::Files {
+            cert: cert.clone(), key: key.clone()
+        };
+
+        assert!(files.load().is_ok());
+
+        // a key is not a certificate
+        fs::write(&cert, &key_pem).unwrap();
+
+        assert!(files.load().is_err());
+
+        fs::remove_file(cert).unwrap();
+        fs::remove_file(key).unwrap();
+    }
+}